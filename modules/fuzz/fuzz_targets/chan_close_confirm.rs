@@ -0,0 +1,11 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+use ibc::ics04_channel::msgs::chan_close_confirm::MsgChannelCloseConfirm;
+use ibc_fuzz::fuzz_conversion;
+use ibc_proto::ibc::core::channel::v1::MsgChannelCloseConfirm as RawMsgChannelCloseConfirm;
+
+fuzz_target!(
+    |data: &[u8]| fuzz_conversion::<MsgChannelCloseConfirm, RawMsgChannelCloseConfirm>(data)
+);
@@ -0,0 +1,9 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+use ibc::ics04_channel::msgs::chan_open_ack::MsgChannelOpenAck;
+use ibc_fuzz::fuzz_conversion;
+use ibc_proto::ibc::core::channel::v1::MsgChannelOpenAck as RawMsgChannelOpenAck;
+
+fuzz_target!(|data: &[u8]| fuzz_conversion::<MsgChannelOpenAck, RawMsgChannelOpenAck>(data));
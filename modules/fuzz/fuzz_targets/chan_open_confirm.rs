@@ -0,0 +1,11 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+use ibc::ics04_channel::msgs::chan_open_confirm::MsgChannelOpenConfirm;
+use ibc_fuzz::fuzz_conversion;
+use ibc_proto::ibc::core::channel::v1::MsgChannelOpenConfirm as RawMsgChannelOpenConfirm;
+
+fuzz_target!(
+    |data: &[u8]| fuzz_conversion::<MsgChannelOpenConfirm, RawMsgChannelOpenConfirm>(data)
+);
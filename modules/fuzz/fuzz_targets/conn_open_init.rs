@@ -0,0 +1,11 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+use ibc::ics03_connection::msgs::conn_open_init::MsgConnectionOpenInit;
+use ibc_fuzz::fuzz_conversion;
+use ibc_proto::ibc::core::connection::v1::MsgConnectionOpenInit as RawMsgConnectionOpenInit;
+
+fuzz_target!(
+    |data: &[u8]| fuzz_conversion::<MsgConnectionOpenInit, RawMsgConnectionOpenInit>(data)
+);
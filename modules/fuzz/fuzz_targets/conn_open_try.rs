@@ -0,0 +1,9 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+use ibc::ics03_connection::msgs::conn_open_try::MsgConnectionOpenTry;
+use ibc_fuzz::fuzz_conversion;
+use ibc_proto::ibc::core::connection::v1::MsgConnectionOpenTry as RawMsgConnectionOpenTry;
+
+fuzz_target!(|data: &[u8]| fuzz_conversion::<MsgConnectionOpenTry, RawMsgConnectionOpenTry>(data));
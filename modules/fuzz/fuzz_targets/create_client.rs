@@ -0,0 +1,9 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+use ibc::ics02_client::msgs::create_client::MsgCreateAnyClient;
+use ibc_fuzz::fuzz_conversion;
+use ibc_proto::ibc::core::client::v1::MsgCreateClient as RawMsgCreateClient;
+
+fuzz_target!(|data: &[u8]| fuzz_conversion::<MsgCreateAnyClient, RawMsgCreateClient>(data));
@@ -0,0 +1,11 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+use ibc::ics02_client::msgs::misbehaviour::MsgSubmitAnyMisbehaviour;
+use ibc_fuzz::fuzz_conversion;
+use ibc_proto::ibc::core::client::v1::MsgSubmitMisbehaviour as RawMsgSubmitMisbehaviour;
+
+fuzz_target!(
+    |data: &[u8]| fuzz_conversion::<MsgSubmitAnyMisbehaviour, RawMsgSubmitMisbehaviour>(data)
+);
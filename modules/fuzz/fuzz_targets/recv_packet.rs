@@ -0,0 +1,9 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+use ibc::ics04_channel::msgs::recv_packet::MsgRecvPacket;
+use ibc_fuzz::fuzz_conversion;
+use ibc_proto::ibc::core::channel::v1::MsgRecvPacket as RawMsgRecvPacket;
+
+fuzz_target!(|data: &[u8]| fuzz_conversion::<MsgRecvPacket, RawMsgRecvPacket>(data));
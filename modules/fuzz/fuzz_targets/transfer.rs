@@ -0,0 +1,9 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+use ibc::application::ics20_fungible_token_transfer::msgs::transfer::MsgTransfer;
+use ibc_fuzz::fuzz_conversion;
+use ibc_proto::ibc::applications::transfer::v1::MsgTransfer as RawMsgTransfer;
+
+fuzz_target!(|data: &[u8]| fuzz_conversion::<MsgTransfer, RawMsgTransfer>(data));
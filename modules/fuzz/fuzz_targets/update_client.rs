@@ -0,0 +1,9 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+use ibc::ics02_client::msgs::update_client::MsgUpdateAnyClient;
+use ibc_fuzz::fuzz_conversion;
+use ibc_proto::ibc::core::client::v1::MsgUpdateClient as RawMsgUpdateClient;
+
+fuzz_target!(|data: &[u8]| fuzz_conversion::<MsgUpdateAnyClient, RawMsgUpdateClient>(data));
@@ -0,0 +1,9 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+use ibc::ics02_client::msgs::upgrade_client::MsgUpgradeAnyClient;
+use ibc_fuzz::fuzz_conversion;
+use ibc_proto::ibc::core::client::v1::MsgUpgradeClient as RawMsgUpgradeClient;
+
+fuzz_target!(|data: &[u8]| fuzz_conversion::<MsgUpgradeAnyClient, RawMsgUpgradeClient>(data));
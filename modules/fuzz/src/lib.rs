@@ -0,0 +1,26 @@
+//! Fuzzing of the conversions of the IBC domain messages from their raw (proto) counterparts.
+
+use std::convert::TryFrom;
+use std::fmt::Debug;
+
+use prost::Message;
+
+/// Decodes a raw message of type `R` out of the given bytes, and converts it into a domain message
+/// of type `T`. Whenever the conversion succeeds, the domain message must survive its conversion
+/// into a raw message and back unchanged.
+pub fn fuzz_conversion<T, R>(data: &[u8])
+where
+    T: TryFrom<R> + Clone + Debug + PartialEq,
+    <T as TryFrom<R>>::Error: Debug,
+    R: Message + Default + From<T>,
+{
+    let raw = match R::decode(data) {
+        Ok(raw) => raw,
+        Err(_) => return,
+    };
+
+    if let Ok(msg) = T::try_from(raw) {
+        let msg_back = T::try_from(R::from(msg.clone()));
+        assert_eq!(msg_back.unwrap(), msg);
+    }
+}
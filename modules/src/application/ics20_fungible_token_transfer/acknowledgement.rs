@@ -0,0 +1,73 @@
+//! The acknowledgement that a chain writes upon receiving an ICS20 packet: the standard
+//! acknowledgement envelope, with the byte `0x01` as result on success.
+
+use crate::application::ics20_fungible_token_transfer::error::{Error, Kind};
+use crate::ics04_channel::packet::Acknowledgement;
+
+/// The result of a successful transfer.
+const SUCCESS_RESULT: &[u8] = &[1];
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FungibleTokenPacketAcknowledgement {
+    /// The receiving chain credited the tokens to the receiver.
+    Success,
+    /// The receiving chain failed to process the packet; the sending chain refunds the tokens.
+    Error(String),
+}
+
+impl FungibleTokenPacketAcknowledgement {
+    pub fn is_success(&self) -> bool {
+        matches!(self, FungibleTokenPacketAcknowledgement::Success)
+    }
+
+    /// Encodes the acknowledgement into the bytes which the receiving chain writes.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let ack = match self {
+            FungibleTokenPacketAcknowledgement::Success => {
+                Acknowledgement::Result(SUCCESS_RESULT.to_vec())
+            }
+            FungibleTokenPacketAcknowledgement::Error(e) => Acknowledgement::Error(e.clone()),
+        };
+
+        ack.to_bytes()
+    }
+
+    /// Decodes an acknowledgement written by the receiving chain. Any non-empty result counts as
+    /// success, while an empty error is rejected.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        match Acknowledgement::from_bytes(bytes)
+            .map_err(|e| Kind::InvalidAcknowledgement.context(e))?
+        {
+            Acknowledgement::Result(_) => Ok(FungibleTokenPacketAcknowledgement::Success),
+            Acknowledgement::Error(e) => Ok(FungibleTokenPacketAcknowledgement::Error(e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::application::ics20_fungible_token_transfer::acknowledgement::FungibleTokenPacketAcknowledgement;
+
+    #[test]
+    fn acknowledgement_encoding() {
+        let success = FungibleTokenPacketAcknowledgement::Success;
+        assert_eq!(success.to_bytes(), br#"{"result":"AQ=="}"#.to_vec());
+        assert_eq!(
+            FungibleTokenPacketAcknowledgement::from_bytes(&success.to_bytes()).unwrap(),
+            success
+        );
+
+        let error = FungibleTokenPacketAcknowledgement::Error("insufficient funds".to_string());
+        assert_eq!(
+            error.to_bytes(),
+            br#"{"error":"insufficient funds"}"#.to_vec()
+        );
+        assert_eq!(
+            FungibleTokenPacketAcknowledgement::from_bytes(&error.to_bytes()).unwrap(),
+            error
+        );
+
+        assert!(FungibleTokenPacketAcknowledgement::from_bytes(br#"{"result":""}"#).is_err());
+        assert!(FungibleTokenPacketAcknowledgement::from_bytes(br#"{"error":""}"#).is_err());
+    }
+}
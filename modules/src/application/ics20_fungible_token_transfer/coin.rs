@@ -0,0 +1,58 @@
+use std::convert::TryFrom;
+
+use serde_derive::{Deserialize, Serialize};
+
+use ibc_proto::cosmos::base::v1beta1::Coin as RawCoin;
+
+use crate::application::ics20_fungible_token_transfer::error::{Error, Kind};
+
+/// An amount of tokens of a single denomination.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Coin {
+    pub denom: String,
+    pub amount: u64,
+}
+
+impl Coin {
+    pub fn new(denom: impl Into<String>, amount: u64) -> Self {
+        Coin {
+            denom: denom.into(),
+            amount,
+        }
+    }
+}
+
+impl TryFrom<RawCoin> for Coin {
+    type Error = Error;
+
+    fn try_from(raw: RawCoin) -> Result<Self, Self::Error> {
+        if raw.denom.trim().is_empty() {
+            return Err(Kind::InvalidToken.context("empty denomination").into());
+        }
+
+        let amount = raw
+            .amount
+            .parse()
+            .map_err(|e| Kind::InvalidToken.context(e))?;
+
+        Ok(Coin {
+            denom: raw.denom,
+            amount,
+        })
+    }
+}
+
+impl From<Coin> for RawCoin {
+    fn from(coin: Coin) -> Self {
+        RawCoin {
+            denom: coin.denom,
+            amount: coin.amount.to_string(),
+        }
+    }
+}
+
+impl std::fmt::Display for Coin {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{}", self.amount, self.denom)
+    }
+}
@@ -0,0 +1,63 @@
+//! ICS20 (fungible token transfer) context. The traits `ICS20Reader` and `ICS20Keeper` define the
+//! interface that any host chain must implement to run the transfer module, on top of the ICS4
+//! channel context. Token balances are handled by the host through the `BankKeeper` trait.
+
+use sha2::{Digest, Sha256};
+use tendermint::account::Id as AccountId;
+
+use crate::application::ics20_fungible_token_transfer::coin::Coin;
+use crate::application::ics20_fungible_token_transfer::denom::DenomTrace;
+use crate::application::ics20_fungible_token_transfer::error::Error;
+use crate::application::ics20_fungible_token_transfer::VERSION;
+use crate::ics04_channel::context::{ChannelKeeper, ChannelReader};
+use crate::ics05_port::context::PortReader;
+use crate::ics24_host::identifier::{ChannelId, PortId};
+
+/// The facility through which the transfer module moves, mints and burns tokens on the host chain.
+pub trait BankKeeper {
+    /// Transfers the given amount of tokens from account `from` to account `to`.
+    fn send_coins(&mut self, from: &AccountId, to: &AccountId, amount: &Coin) -> Result<(), Error>;
+
+    /// Creates the given amount of tokens, crediting them to `account`.
+    fn mint_coins(&mut self, account: &AccountId, amount: &Coin) -> Result<(), Error>;
+
+    /// Destroys the given amount of tokens, debiting them from `account`.
+    fn burn_coins(&mut self, account: &AccountId, amount: &Coin) -> Result<(), Error>;
+}
+
+/// A context supplying the read-only dependencies of the transfer module.
+pub trait ICS20Reader: ChannelReader + PortReader {
+    /// Returns the trace of the voucher denomination with the given hash, if the host chain ever
+    /// received such vouchers.
+    fn get_denom_trace(&self, denom_hash: &str) -> Option<DenomTrace>;
+
+    /// Returns the account which escrows the tokens sent from this chain through the given port
+    /// and channel, while they are away.
+    fn get_escrow_account(&self, port_id: &PortId, channel_id: &ChannelId) -> AccountId {
+        escrow_address(port_id, channel_id)
+    }
+}
+
+/// A context supplying the write-only dependencies of the transfer module.
+pub trait ICS20Keeper: ChannelKeeper + BankKeeper {
+    /// Stores the trace of a voucher denomination, indexed by its hash.
+    fn store_denom_trace(&mut self, denom_trace: DenomTrace) -> Result<(), Error>;
+}
+
+/// The context of a host chain running the transfer module.
+pub trait ICS20Context: ICS20Reader + ICS20Keeper {}
+
+/// Derives the escrow account of the given port and channel the same way the Cosmos SDK does,
+/// i.e., as the first 20 bytes of the SHA256 hash of the module version and `{port}/{channel}`.
+pub fn escrow_address(port_id: &PortId, channel_id: &ChannelId) -> AccountId {
+    let contents = format!("{}/{}", port_id, channel_id);
+
+    let mut hasher = Sha256::new();
+    hasher.update(VERSION.as_bytes());
+    hasher.update([0]);
+    hasher.update(contents.as_bytes());
+
+    let mut address = [0u8; 20];
+    address.copy_from_slice(&hasher.finalize()[..20]);
+    AccountId::new(address)
+}
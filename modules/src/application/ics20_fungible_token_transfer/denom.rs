@@ -0,0 +1,210 @@
+//! Denomination traces, which record the path along which a token was transferred from its
+//! origin chain, through channels, to the chain where it is held. Tokens received from another
+//! chain are represented by "voucher" denominations of the form `ibc/{hash}`, where `hash` is the
+//! hash of the full denomination path. See:
+//! https://github.com/cosmos/ics/tree/master/spec/ics-020-fungible-token-transfer#technical-specification
+
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+use serde_derive::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use subtle_encoding::{Encoding, Hex};
+use tendermint_proto::Protobuf;
+
+use ibc_proto::ibc::applications::transfer::v1::DenomTrace as RawDenomTrace;
+
+use crate::application::ics20_fungible_token_transfer::error::{Error, Kind};
+use crate::ics24_host::identifier::{ChannelId, PortId};
+
+/// The prefix of the denominations of tokens received from another chain.
+pub const DENOM_PREFIX: &str = "ibc";
+
+/// The trace of a denomination: the `path` of `{port}/{channel}` pairs through which the tokens
+/// were transferred, latest hop first, followed by the denomination on the origin chain.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct DenomTrace {
+    pub path: String,
+    pub base_denom: String,
+}
+
+impl DenomTrace {
+    pub fn new(path: impl Into<String>, base_denom: impl Into<String>) -> Self {
+        DenomTrace {
+            path: path.into(),
+            base_denom: base_denom.into(),
+        }
+    }
+
+    /// Returns the full denomination path, i.e., the path followed by the base denomination.
+    pub fn full_path(&self) -> String {
+        if self.path.is_empty() {
+            self.base_denom.clone()
+        } else {
+            format!("{}/{}", self.path, self.base_denom)
+        }
+    }
+
+    /// Returns the upper-case hex encoding of the SHA256 hash of the full denomination path.
+    pub fn hash(&self) -> String {
+        let digest = Sha256::digest(self.full_path().as_bytes());
+        Hex::upper_case().encode_to_string(digest).unwrap()
+    }
+
+    /// Returns whether the tokens with this trace are native to the chain holding them.
+    pub fn is_native(&self) -> bool {
+        self.path.is_empty()
+    }
+
+    /// Returns the denomination under which the chain holding the tokens keeps track of them:
+    /// the base denomination for native tokens, and `ibc/{hash}` for vouchers.
+    pub fn ibc_denom(&self) -> String {
+        if self.is_native() {
+            self.base_denom.clone()
+        } else {
+            format!("{}/{}", DENOM_PREFIX, self.hash())
+        }
+    }
+}
+
+impl FromStr for DenomTrace {
+    type Err = Error;
+
+    /// Parses a full denomination path into a trace. The last segment of the path is the base
+    /// denomination, while the preceding segments (if any) form the trace path.
+    fn from_str(full_path: &str) -> Result<Self, Self::Err> {
+        let (path, base_denom) = match full_path.rfind('/') {
+            Some(idx) => (&full_path[..idx], &full_path[idx + 1..]),
+            None => ("", full_path),
+        };
+
+        if base_denom.trim().is_empty() {
+            return Err(Kind::InvalidDenomTrace(full_path.to_string())
+                .context("empty base denomination")
+                .into());
+        }
+
+        Ok(DenomTrace::new(path, base_denom))
+    }
+}
+
+impl std::fmt::Display for DenomTrace {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.full_path())
+    }
+}
+
+impl Protobuf<RawDenomTrace> for DenomTrace {}
+
+impl TryFrom<RawDenomTrace> for DenomTrace {
+    type Error = Error;
+
+    fn try_from(raw: RawDenomTrace) -> Result<Self, Self::Error> {
+        if raw.base_denom.trim().is_empty() {
+            return Err(Kind::InvalidDenomTrace(raw.path)
+                .context("empty base denomination")
+                .into());
+        }
+
+        Ok(DenomTrace::new(raw.path, raw.base_denom))
+    }
+}
+
+impl From<DenomTrace> for RawDenomTrace {
+    fn from(trace: DenomTrace) -> Self {
+        RawDenomTrace {
+            path: trace.path,
+            base_denom: trace.base_denom,
+        }
+    }
+}
+
+/// Returns the `{port}/{channel}/` prefix which the chain at the other end of the channel adds to
+/// the denomination of the tokens it receives through the given port and channel.
+pub fn denom_prefix(port_id: &PortId, channel_id: &ChannelId) -> String {
+    format!("{}/{}/", port_id, channel_id)
+}
+
+/// Returns whether the chain receiving a packet sent through the given source port and channel
+/// is the origin of the tokens, i.e., whether the tokens are returning to the receiving chain.
+/// This is the case if the sending chain had prefixed the denomination upon receiving the tokens
+/// on this same channel.
+pub fn receiver_chain_is_source(
+    source_port: &PortId,
+    source_channel: &ChannelId,
+    denom: &str,
+) -> bool {
+    denom.starts_with(&denom_prefix(source_port, source_channel))
+}
+
+/// Returns whether the chain sending a packet through the given source port and channel is the
+/// origin of the tokens, as opposed to the tokens returning to their origin.
+pub fn sender_chain_is_source(
+    source_port: &PortId,
+    source_channel: &ChannelId,
+    denom: &str,
+) -> bool {
+    !receiver_chain_is_source(source_port, source_channel, denom)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::application::ics20_fungible_token_transfer::denom::{
+        receiver_chain_is_source, sender_chain_is_source, DenomTrace,
+    };
+    use crate::ics24_host::identifier::{ChannelId, PortId};
+
+    #[test]
+    fn denom_trace_parsing() {
+        let trace = DenomTrace::from_str("uatom").unwrap();
+        assert_eq!(trace, DenomTrace::new("", "uatom"));
+        assert!(trace.is_native());
+        assert_eq!(trace.ibc_denom(), "uatom");
+
+        let trace = DenomTrace::from_str("transfer/channel-1/transfer/channel-0/uatom").unwrap();
+        assert_eq!(
+            trace,
+            DenomTrace::new("transfer/channel-1/transfer/channel-0", "uatom")
+        );
+        assert_eq!(
+            trace.full_path(),
+            "transfer/channel-1/transfer/channel-0/uatom"
+        );
+
+        assert!(DenomTrace::from_str("").is_err());
+        assert!(DenomTrace::from_str("transfer/channel-0/").is_err());
+    }
+
+    #[test]
+    fn denom_trace_hashing() {
+        let trace = DenomTrace::new("transfer/channel-0", "uatom");
+        assert_eq!(
+            trace.hash(),
+            "27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2"
+        );
+        assert_eq!(
+            trace.ibc_denom(),
+            "ibc/27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2"
+        );
+    }
+
+    #[test]
+    fn denom_source_chain() {
+        let port = PortId::from_str("transfer").unwrap();
+        let channel = ChannelId::from_str("channel-0").unwrap();
+
+        assert!(sender_chain_is_source(&port, &channel, "uatom"));
+        assert!(sender_chain_is_source(
+            &port,
+            &channel,
+            "transfer/channel-1/uatom"
+        ));
+        assert!(receiver_chain_is_source(
+            &port,
+            &channel,
+            "transfer/channel-0/uatom"
+        ));
+    }
+}
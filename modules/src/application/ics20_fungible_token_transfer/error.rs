@@ -1,10 +1,70 @@
 use anomaly::{BoxError, Context};
 use thiserror::Error;
 
+use crate::ics04_channel::channel::Order;
+use crate::ics24_host::identifier::{ChannelId, PortId};
+
 pub type Error = anomaly::Error<Kind>;
 
 #[derive(Clone, Debug, Error)]
-pub enum Kind {}
+pub enum Kind {
+    #[error("invalid identifier")]
+    InvalidIdentifier,
+
+    #[error("invalid address")]
+    InvalidAddress,
+
+    #[error("invalid token")]
+    InvalidToken,
+
+    #[error("missing timeout height")]
+    MissingTimeoutHeight,
+
+    #[error("invalid timeout height")]
+    InvalidTimeoutHeight,
+
+    #[error("invalid timeout timestamp")]
+    InvalidTimeoutTimestamp,
+
+    #[error("invalid denomination trace: {0}")]
+    InvalidDenomTrace(String),
+
+    #[error("denomination trace not found for hash {0}")]
+    DenomTraceNotFound(String),
+
+    #[error("invalid fungible token packet data")]
+    InvalidPacketData,
+
+    #[error("invalid fungible token packet acknowledgement")]
+    InvalidAcknowledgement,
+
+    #[error("channel {1} on port {0} not found")]
+    ChannelNotFound(PortId, ChannelId),
+
+    #[error("counterparty channel of channel {1} on port {0} is not set")]
+    MissingCounterpartyChannel(PortId, ChannelId),
+
+    #[error("the transfer module does not own channel {1} on port {0}")]
+    ChannelNotOwned(PortId, ChannelId),
+
+    #[error("missing sequence number for sending packets on channel {1} of port {0}")]
+    MissingNextSendSeq(PortId, ChannelId),
+
+    #[error("insufficient funds: {0} {1} needed, but only {2} {1} available")]
+    InsufficientFunds(u64, String, u64),
+
+    #[error("failed to send packet")]
+    SendPacketFailure,
+
+    #[error("expected {} channel, got {}", .0.as_string(), .1.as_string())]
+    InvalidChannelOrder(Order, Order),
+
+    #[error("expected version {0}, got {1}")]
+    InvalidVersion(String, String),
+
+    #[error("transfer channels cannot be closed by users")]
+    CannotCloseChannel,
+}
 
 impl Kind {
     pub fn context(self, source: impl Into<BoxError>) -> Context<Self> {
@@ -1,4 +1,20 @@
 //! ICS 20: IBC Transfer implementation
+pub mod acknowledgement;
+pub mod coin;
+pub mod context;
+pub mod denom;
 pub mod error;
 pub mod events;
+pub mod module;
 pub mod msgs;
+pub mod packet;
+pub mod relay_application_logic;
+
+/// The port to which the transfer module binds by default.
+pub const PORT_ID: &str = "transfer";
+
+/// The identifier under which the transfer module owns capabilities.
+pub const MODULE_ID: &str = "transfer";
+
+/// The version of the channels over which tokens are transferred.
+pub const VERSION: &str = "ics20-1";
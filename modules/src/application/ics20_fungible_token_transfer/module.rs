@@ -0,0 +1,233 @@
+//! The fungible token transfer application module, which the routing module invokes for the
+//! channels and packets of the port the module is bound to (see ICS20 "Channel lifecycle
+//! management" and "Packet relay").
+
+use std::error::Error as StdError;
+
+use crate::application::ics20_fungible_token_transfer::context::ICS20Context;
+use crate::application::ics20_fungible_token_transfer::error::Kind;
+use crate::application::ics20_fungible_token_transfer::relay_application_logic::on_acknowledgement_packet::on_acknowledgement_packet;
+use crate::application::ics20_fungible_token_transfer::relay_application_logic::on_recv_packet::on_recv_packet;
+use crate::application::ics20_fungible_token_transfer::relay_application_logic::on_timeout_packet::on_timeout_packet;
+use crate::application::ics20_fungible_token_transfer::VERSION;
+use crate::ics04_channel::channel::{ChannelEnd, Order};
+use crate::ics04_channel::packet::Packet;
+use crate::ics24_host::identifier::{ChannelId, PortId};
+use crate::ics26_routing::module::Module;
+
+/// The transfer module. Its state, i.e., the balances and denomination traces, lives in the host
+/// chain context.
+#[derive(Clone, Debug, Default)]
+pub struct TransferModule;
+
+impl<Ctx> Module<Ctx> for TransferModule
+where
+    Ctx: ICS20Context,
+{
+    fn on_chan_open_init(
+        &self,
+        _ctx: &mut Ctx,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        channel_end: &ChannelEnd,
+    ) -> Result<(), Box<dyn StdError>> {
+        validate_channel(channel_end)?;
+        validate_version(&channel_end.version())?;
+        Ok(())
+    }
+
+    fn on_chan_open_try(
+        &self,
+        _ctx: &mut Ctx,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        channel_end: &ChannelEnd,
+        counterparty_version: &str,
+    ) -> Result<String, Box<dyn StdError>> {
+        validate_channel(channel_end)?;
+        validate_version(counterparty_version)?;
+        Ok(VERSION.to_string())
+    }
+
+    fn on_chan_open_ack(
+        &self,
+        _ctx: &mut Ctx,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        counterparty_version: &str,
+    ) -> Result<(), Box<dyn StdError>> {
+        validate_version(counterparty_version)?;
+        Ok(())
+    }
+
+    fn on_chan_close_init(
+        &self,
+        _ctx: &mut Ctx,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+    ) -> Result<(), Box<dyn StdError>> {
+        Err(Kind::CannotCloseChannel.into())
+    }
+
+    fn on_recv_packet(&self, ctx: &mut Ctx, packet: &Packet) -> Result<Vec<u8>, Box<dyn StdError>> {
+        Ok(on_recv_packet(ctx, packet).to_bytes())
+    }
+
+    fn on_acknowledgement_packet(
+        &self,
+        ctx: &mut Ctx,
+        packet: &Packet,
+        acknowledgement: &[u8],
+    ) -> Result<(), Box<dyn StdError>> {
+        on_acknowledgement_packet(ctx, packet, acknowledgement)?;
+        Ok(())
+    }
+
+    fn on_timeout_packet(&self, ctx: &mut Ctx, packet: &Packet) -> Result<(), Box<dyn StdError>> {
+        on_timeout_packet(ctx, packet)?;
+        Ok(())
+    }
+}
+
+/// Tokens are transferred over unordered channels only.
+fn validate_channel(channel_end: &ChannelEnd) -> Result<(), Kind> {
+    if !channel_end.order_matches(&Order::Unordered) {
+        return Err(Kind::InvalidChannelOrder(
+            Order::Unordered,
+            *channel_end.ordering(),
+        ));
+    }
+    Ok(())
+}
+
+fn validate_version(version: &str) -> Result<(), Kind> {
+    if version != VERSION {
+        return Err(Kind::InvalidVersion(
+            VERSION.to_string(),
+            version.to_string(),
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use ibc_proto::ibc::core::client::v1::Height as RawHeight;
+
+    use crate::application::ics20_fungible_token_transfer::acknowledgement::FungibleTokenPacketAcknowledgement;
+    use crate::application::ics20_fungible_token_transfer::denom::DenomTrace;
+    use crate::application::ics20_fungible_token_transfer::relay_application_logic::test_util::{
+        get_dummy_account, get_dummy_transfer_channel, get_dummy_transfer_context,
+        get_dummy_transfer_packet, get_dummy_transfer_port,
+    };
+    use crate::events::IBCEvent;
+    use crate::ics04_channel::msgs::chan_close_init::test_util::get_dummy_raw_msg_chan_close_init;
+    use crate::ics04_channel::msgs::chan_close_init::MsgChannelCloseInit;
+    use crate::ics04_channel::msgs::recv_packet::test_util::get_dummy_raw_msg_recv_packet;
+    use crate::ics04_channel::msgs::recv_packet::MsgRecvPacket;
+    use crate::ics04_channel::msgs::{ChannelMsg, PacketMsg};
+    use crate::ics26_routing::handler::dispatch;
+    use crate::ics26_routing::msgs::ICS26Envelope;
+
+    #[test]
+    fn transfer_module_callbacks() {
+        struct Test {
+            name: String,
+            msg: ICS26Envelope,
+            want_pass: bool,
+            /// Whether the acknowledgement written for a received packet is expected to be
+            /// successful.
+            want_ack_success: Option<bool>,
+            /// The expected voucher balance of the receiver.
+            want_balance: u64,
+        }
+
+        let sender = get_dummy_account(1);
+        let receiver = get_dummy_account(2);
+        let voucher_trace = DenomTrace::new("transfer/channel-0", "uatom");
+
+        // The proofs are at the height of the client of the transfer channel.
+        let recv_packet_msg = |packet| {
+            let mut raw = get_dummy_raw_msg_recv_packet(5);
+            raw.proof_height = Some(RawHeight {
+                revision_number: 1,
+                revision_height: 5,
+            });
+
+            let mut msg = MsgRecvPacket::try_from(raw).unwrap();
+            msg.packet = packet;
+            ICS26Envelope::ICS4PacketMsg(PacketMsg::RecvPacket(msg))
+        };
+
+        let mut invalid_packet = get_dummy_transfer_packet("uatom", 10, sender, receiver);
+        invalid_packet.data = b"invalid packet data".to_vec();
+
+        let mut close_init_msg =
+            MsgChannelCloseInit::try_from(get_dummy_raw_msg_chan_close_init()).unwrap();
+        close_init_msg.port_id = get_dummy_transfer_port();
+        close_init_msg.channel_id = get_dummy_transfer_channel();
+
+        let tests: Vec<Test> = vec![
+            Test {
+                name: "Received tokens are minted as vouchers".to_string(),
+                msg: recv_packet_msg(get_dummy_transfer_packet("uatom", 10, sender, receiver)),
+                want_pass: true,
+                want_ack_success: Some(true),
+                want_balance: 10,
+            },
+            Test {
+                name: "Invalid packet data is acknowledged with an error".to_string(),
+                msg: recv_packet_msg(invalid_packet),
+                want_pass: true,
+                want_ack_success: Some(false),
+                want_balance: 0,
+            },
+            Test {
+                name: "Users cannot close transfer channels".to_string(),
+                msg: ICS26Envelope::ICS4ChannelMsg(ChannelMsg::ChannelCloseInit(close_init_msg)),
+                want_pass: false,
+                want_ack_success: None,
+                want_balance: 0,
+            },
+        ];
+
+        for test in tests {
+            let mut ctx = get_dummy_transfer_context();
+            let res = dispatch(&mut ctx, test.msg.clone());
+
+            assert_eq!(
+                test.want_pass,
+                res.is_ok(),
+                "transfer module test '{}' failed for message {:?} with result: {:?}",
+                test.name,
+                test.msg,
+                res
+            );
+
+            if let Ok(output) = res {
+                let ack_success = output.events.into_iter().find_map(|e| match e {
+                    IBCEvent::WriteAcknowledgement(e) => Some(
+                        FungibleTokenPacketAcknowledgement::from_bytes(&e.ack)
+                            .unwrap()
+                            .is_success(),
+                    ),
+                    _ => None,
+                });
+                assert_eq!(
+                    test.want_ack_success, ack_success,
+                    "transfer module test '{}': unexpected acknowledgement",
+                    test.name
+                );
+            }
+
+            assert_eq!(
+                ctx.balance(&receiver, &voucher_trace.ibc_denom()),
+                test.want_balance,
+                "transfer module test '{}': unexpected balance",
+                test.name
+            );
+        }
+    }
+}
@@ -9,6 +9,7 @@ use crate::address::{account_to_string, string_to_account};
 use crate::application::ics20_fungible_token_transfer::error::{Error, Kind};
 use crate::ics02_client::height::Height;
 use crate::ics24_host::identifier::{ChannelId, PortId};
+use crate::timestamp::Timestamp;
 use crate::tx_msg::Msg;
 
 pub const TYPE_URL: &str = "/ibc.applications.transfer.v1.MsgTransfer";
@@ -31,9 +32,9 @@ pub struct MsgTransfer {
     /// Timeout height relative to the current block height.
     /// The timeout is disabled when set to 0.
     pub timeout_height: Height,
-    /// Timeout timestamp (in nanoseconds) relative to the current block timestamp.
-    /// The timeout is disabled when set to 0.
-    pub timeout_timestamp: u64,
+    /// Timeout timestamp of the packet, on the destination chain.
+    /// The timeout is disabled when not set.
+    pub timeout_timestamp: Timestamp,
 }
 
 impl Msg for MsgTransfer {
@@ -59,13 +60,26 @@ impl TryFrom<RawMsgTransfer> for MsgTransfer {
 
     fn try_from(raw_msg: RawMsgTransfer) -> Result<Self, Self::Error> {
         Ok(MsgTransfer {
-            source_port: raw_msg.source_port.parse().unwrap(),
-            source_channel: raw_msg.source_channel.parse().unwrap(),
+            source_port: raw_msg
+                .source_port
+                .parse()
+                .map_err(|e| Kind::InvalidIdentifier.context(e))?,
+            source_channel: raw_msg
+                .source_channel
+                .parse()
+                .map_err(|e| Kind::InvalidIdentifier.context(e))?,
             token: raw_msg.token,
-            sender: string_to_account(raw_msg.sender).unwrap(),
-            receiver: string_to_account(raw_msg.receiver).unwrap(),
-            timeout_height: raw_msg.timeout_height.unwrap().try_into().unwrap(),
-            timeout_timestamp: 0,
+            sender: string_to_account(raw_msg.sender)
+                .map_err(|e| Kind::InvalidAddress.context(e))?,
+            receiver: string_to_account(raw_msg.receiver)
+                .map_err(|e| Kind::InvalidAddress.context(e))?,
+            timeout_height: raw_msg
+                .timeout_height
+                .ok_or(Kind::MissingTimeoutHeight)?
+                .try_into()
+                .map_err(|e| Kind::InvalidTimeoutHeight.context(e))?,
+            timeout_timestamp: Timestamp::from_nanoseconds(raw_msg.timeout_timestamp)
+                .map_err(|e| Kind::InvalidTimeoutTimestamp.context(e))?,
         })
     }
 }
@@ -78,8 +92,82 @@ impl From<MsgTransfer> for RawMsgTransfer {
             token: domain_msg.token,
             sender: account_to_string(domain_msg.sender).unwrap(),
             receiver: account_to_string(domain_msg.receiver).unwrap(),
-            timeout_height: Some(domain_msg.timeout_height.try_into().unwrap()),
-            timeout_timestamp: 0,
+            timeout_height: Some(domain_msg.timeout_height.into()),
+            timeout_timestamp: domain_msg.timeout_timestamp.nanoseconds(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use ibc_proto::ibc::applications::transfer::v1::MsgTransfer as RawMsgTransfer;
+    use proptest::prelude::*;
+
+    use crate::application::ics20_fungible_token_transfer::error::Kind;
+    use crate::application::ics20_fungible_token_transfer::msgs::transfer::{
+        MsgTransfer, TYPE_URL,
+    };
+    use crate::ics02_client::height::Height;
+    use crate::test::test_msg_roundtrip;
+    use crate::test_utils::get_dummy_account_id;
+    use crate::timestamp::Timestamp;
+
+    #[test]
+    fn malformed_msg_transfer() {
+        let raw = RawMsgTransfer::from(MsgTransfer {
+            source_port: "transfer".parse().unwrap(),
+            source_channel: "channel-0".parse().unwrap(),
+            token: None,
+            sender: get_dummy_account_id(),
+            receiver: get_dummy_account_id(),
+            timeout_height: Height::new(0, 10),
+            timeout_timestamp: Timestamp::none(),
+        });
+        assert!(MsgTransfer::try_from(raw.clone()).is_ok());
+
+        // The conversion fails, instead of panicking, on malformed fields.
+        let err = MsgTransfer::try_from(RawMsgTransfer {
+            source_port: "p".to_string(),
+            ..raw.clone()
+        })
+        .unwrap_err();
+        assert!(matches!(err.kind(), Kind::InvalidIdentifier));
+
+        let err = MsgTransfer::try_from(RawMsgTransfer {
+            source_channel: "c".to_string(),
+            ..raw.clone()
+        })
+        .unwrap_err();
+        assert!(matches!(err.kind(), Kind::InvalidIdentifier));
+
+        let err = MsgTransfer::try_from(RawMsgTransfer {
+            sender: "not an address".to_string(),
+            ..raw.clone()
+        })
+        .unwrap_err();
+        assert!(matches!(err.kind(), Kind::InvalidAddress));
+
+        let err = MsgTransfer::try_from(RawMsgTransfer {
+            receiver: "not an address".to_string(),
+            ..raw.clone()
+        })
+        .unwrap_err();
+        assert!(matches!(err.kind(), Kind::InvalidAddress));
+
+        let err = MsgTransfer::try_from(RawMsgTransfer {
+            timeout_height: None,
+            ..raw
+        })
+        .unwrap_err();
+        assert!(matches!(err.kind(), Kind::MissingTimeoutHeight));
+    }
+
+    proptest! {
+        #[test]
+        fn roundtrip(msg in any::<MsgTransfer>()) {
+            test_msg_roundtrip::<_, RawMsgTransfer>(msg, TYPE_URL);
         }
     }
 }
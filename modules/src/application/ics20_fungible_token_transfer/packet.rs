@@ -0,0 +1,126 @@
+//! The data carried by ICS20 packets. Chains exchange it as JSON, with the keys sorted and the
+//! amount encoded as a string, which is how the Cosmos SDK encodes it.
+
+use std::convert::TryFrom;
+
+use serde_derive::{Deserialize, Serialize};
+
+use ibc_proto::ibc::applications::transfer::v1::FungibleTokenPacketData as RawFungibleTokenPacketData;
+
+use crate::application::ics20_fungible_token_transfer::error::{Error, Kind};
+
+/// The packet data of a fungible token transfer. The `denom` is the full denomination path of the
+/// tokens on the sending chain, while `sender` and `receiver` are the addresses of the accounts
+/// on the sending and receiving chain, respectively.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FungibleTokenPacketData {
+    pub denom: String,
+    pub amount: u64,
+    pub sender: String,
+    pub receiver: String,
+}
+
+/// The JSON wire format of the packet data. Fields are declared in lexicographic order, such that
+/// the encoding has sorted keys.
+#[derive(Deserialize, Serialize)]
+struct JsonFungibleTokenPacketData {
+    amount: String,
+    denom: String,
+    receiver: String,
+    sender: String,
+}
+
+impl FungibleTokenPacketData {
+    /// Encodes the packet data into the bytes to be set as the packet `data`.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let json = JsonFungibleTokenPacketData {
+            amount: self.amount.to_string(),
+            denom: self.denom.clone(),
+            receiver: self.receiver.clone(),
+            sender: self.sender.clone(),
+        };
+
+        serde_json::to_vec(&json).unwrap()
+    }
+
+    /// Decodes the packet data from the `data` of a packet, checking that it is well-formed.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let json: JsonFungibleTokenPacketData =
+            serde_json::from_slice(bytes).map_err(|e| Kind::InvalidPacketData.context(e))?;
+
+        let amount = json
+            .amount
+            .parse()
+            .map_err(|e| Kind::InvalidPacketData.context(e))?;
+
+        Self::try_from(RawFungibleTokenPacketData {
+            denom: json.denom,
+            amount,
+            sender: json.sender,
+            receiver: json.receiver,
+        })
+    }
+}
+
+impl TryFrom<RawFungibleTokenPacketData> for FungibleTokenPacketData {
+    type Error = Error;
+
+    fn try_from(raw: RawFungibleTokenPacketData) -> Result<Self, Self::Error> {
+        if raw.amount == 0 {
+            return Err(Kind::InvalidPacketData.context("zero amount").into());
+        }
+        if raw.denom.trim().is_empty() {
+            return Err(Kind::InvalidPacketData.context("empty denomination").into());
+        }
+        if raw.sender.trim().is_empty() {
+            return Err(Kind::InvalidPacketData.context("empty sender").into());
+        }
+        if raw.receiver.trim().is_empty() {
+            return Err(Kind::InvalidPacketData.context("empty receiver").into());
+        }
+
+        Ok(FungibleTokenPacketData {
+            denom: raw.denom,
+            amount: raw.amount,
+            sender: raw.sender,
+            receiver: raw.receiver,
+        })
+    }
+}
+
+impl From<FungibleTokenPacketData> for RawFungibleTokenPacketData {
+    fn from(data: FungibleTokenPacketData) -> Self {
+        RawFungibleTokenPacketData {
+            denom: data.denom,
+            amount: data.amount,
+            sender: data.sender,
+            receiver: data.receiver,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::application::ics20_fungible_token_transfer::packet::FungibleTokenPacketData;
+
+    #[test]
+    fn packet_data_encoding() {
+        let data = FungibleTokenPacketData {
+            denom: "transfer/channel-0/uatom".to_string(),
+            amount: 100,
+            sender: "cosmos1sender".to_string(),
+            receiver: "cosmos1receiver".to_string(),
+        };
+
+        let bytes = data.to_bytes();
+        assert_eq!(
+            String::from_utf8(bytes.clone()).unwrap(),
+            r#"{"amount":"100","denom":"transfer/channel-0/uatom","receiver":"cosmos1receiver","sender":"cosmos1sender"}"#
+        );
+        assert_eq!(FungibleTokenPacketData::from_bytes(&bytes).unwrap(), data);
+
+        let zero_amount = r#"{"amount":"0","denom":"uatom","receiver":"r","sender":"s"}"#;
+        assert!(FungibleTokenPacketData::from_bytes(zero_amount.as_bytes()).is_err());
+        assert!(FungibleTokenPacketData::from_bytes(b"not json").is_err());
+    }
+}
@@ -0,0 +1,160 @@
+//! The ICS20 application logic: sending transfers, and the callbacks through which the transfer
+//! module processes the packets received on, acknowledged by and timed out on its channels.
+
+use std::str::FromStr;
+
+use crate::address::string_to_account;
+use crate::application::ics20_fungible_token_transfer::coin::Coin;
+use crate::application::ics20_fungible_token_transfer::context::ICS20Context;
+use crate::application::ics20_fungible_token_transfer::denom::{
+    sender_chain_is_source, DenomTrace,
+};
+use crate::application::ics20_fungible_token_transfer::error::{Error, Kind};
+use crate::application::ics20_fungible_token_transfer::packet::FungibleTokenPacketData;
+use crate::ics04_channel::packet::Packet;
+
+pub mod on_acknowledgement_packet;
+pub mod on_recv_packet;
+pub mod on_timeout_packet;
+pub mod send_transfer;
+
+/// Returns the tokens of a packet that failed to be received to the sender, by releasing them
+/// from escrow if this chain is their source, or by minting back the burned vouchers otherwise.
+fn refund_packet_token<Ctx>(
+    ctx: &mut Ctx,
+    packet: &Packet,
+    data: &FungibleTokenPacketData,
+) -> Result<(), Error>
+where
+    Ctx: ICS20Context,
+{
+    let trace = DenomTrace::from_str(&data.denom)?;
+    let token = Coin::new(trace.ibc_denom(), data.amount);
+
+    let sender =
+        string_to_account(data.sender.clone()).map_err(|e| Kind::InvalidAddress.context(e))?;
+
+    if sender_chain_is_source(&packet.source_port, &packet.source_channel, &data.denom) {
+        let escrow = ctx.get_escrow_account(&packet.source_port, &packet.source_channel);
+        ctx.send_coins(&escrow, &sender, &token)
+    } else {
+        ctx.mint_coins(&sender, &token)
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_util {
+    use std::str::FromStr;
+
+    use tendermint::account::Id as AccountId;
+
+    use crate::address::account_to_string;
+    use crate::application::ics20_fungible_token_transfer::module::TransferModule;
+    use crate::application::ics20_fungible_token_transfer::packet::FungibleTokenPacketData;
+    use crate::ics03_connection::connection::{
+        ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
+    };
+    use crate::ics03_connection::version::get_compatible_versions;
+    use crate::ics04_channel::channel::{ChannelEnd, Counterparty, Order, State};
+    use crate::ics04_channel::packet::Packet;
+    use crate::ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId};
+    use crate::mock::context::MockContext;
+    use crate::timestamp::Timestamp;
+    use crate::Height;
+
+    pub fn get_dummy_account(byte: u8) -> AccountId {
+        AccountId::new([byte; 20])
+    }
+
+    pub fn get_dummy_transfer_port() -> PortId {
+        PortId::from_str("transfer").unwrap()
+    }
+
+    pub fn get_dummy_transfer_channel() -> ChannelId {
+        ChannelId::from_str("channel-0").unwrap()
+    }
+
+    pub fn get_dummy_counterparty_channel() -> ChannelId {
+        ChannelId::from_str("channel-1").unwrap()
+    }
+
+    /// Returns a context with an open ICS20 channel `transfer/channel-0`, whose counterparty is
+    /// `transfer/channel-1`. The transfer module is bound to port `transfer`.
+    pub fn get_dummy_transfer_context() -> MockContext {
+        let port_id = get_dummy_transfer_port();
+        let channel_id = get_dummy_transfer_channel();
+
+        let channel_end = ChannelEnd::new(
+            State::Open,
+            Order::Unordered,
+            Counterparty::new(port_id.clone(), Some(get_dummy_counterparty_channel())),
+            vec![ConnectionId::default()],
+            "ics20-1".to_string(),
+        );
+
+        let connection_end = ConnectionEnd::new(
+            ConnectionState::Open,
+            ClientId::default(),
+            ConnectionCounterparty::new(
+                ClientId::default(),
+                Some(ConnectionId::default()),
+                Default::default(),
+            ),
+            get_compatible_versions(),
+            0,
+        );
+
+        MockContext::default()
+            .with_client(&ClientId::default(), Height::new(1, 5))
+            .with_connection(ConnectionId::default(), connection_end)
+            .with_module(port_id.clone(), TransferModule)
+            .with_channel_init(port_id.clone(), channel_id.clone(), channel_end)
+            .with_send_sequence(port_id, channel_id, 1.into())
+    }
+
+    /// Returns a packet sent by the counterparty through `transfer/channel-1` to
+    /// `transfer/channel-0`, transferring `amount` of `denom` from `sender` to `receiver`.
+    pub fn get_dummy_transfer_packet(
+        denom: &str,
+        amount: u64,
+        sender: AccountId,
+        receiver: AccountId,
+    ) -> Packet {
+        let data = FungibleTokenPacketData {
+            denom: denom.to_string(),
+            amount,
+            sender: account_to_string(sender).unwrap(),
+            receiver: account_to_string(receiver).unwrap(),
+        };
+
+        Packet {
+            sequence: 1.into(),
+            source_port: get_dummy_transfer_port(),
+            source_channel: get_dummy_counterparty_channel(),
+            destination_port: get_dummy_transfer_port(),
+            destination_channel: get_dummy_transfer_channel(),
+            data: data.to_bytes(),
+            timeout_height: Height::new(1, 100),
+            timeout_timestamp: Timestamp::none(),
+        }
+    }
+
+    /// Returns a packet sent by the host chain through `transfer/channel-0` to the counterparty,
+    /// transferring `amount` of `denom` from `sender` to `receiver`.
+    pub fn get_dummy_sent_transfer_packet(
+        denom: &str,
+        amount: u64,
+        sender: AccountId,
+        receiver: AccountId,
+    ) -> Packet {
+        let packet = get_dummy_transfer_packet(denom, amount, sender, receiver);
+
+        Packet {
+            source_port: packet.destination_port,
+            source_channel: packet.destination_channel,
+            destination_port: packet.source_port,
+            destination_channel: packet.source_channel,
+            ..packet
+        }
+    }
+}
@@ -0,0 +1,132 @@
+//! Protocol logic for processing the acknowledgement of a fungible token transfer packet (ICS20
+//! `onAcknowledgePacket`).
+use crate::application::ics20_fungible_token_transfer::acknowledgement::FungibleTokenPacketAcknowledgement;
+use crate::application::ics20_fungible_token_transfer::context::ICS20Context;
+use crate::application::ics20_fungible_token_transfer::error::Error;
+use crate::application::ics20_fungible_token_transfer::packet::FungibleTokenPacketData;
+use crate::application::ics20_fungible_token_transfer::relay_application_logic::refund_packet_token;
+use crate::ics04_channel::packet::Packet;
+
+/// Refunds the tokens transferred by `packet` if the receiving chain acknowledged it with an
+/// error. Nothing remains to be done upon a successful acknowledgement.
+pub fn on_acknowledgement_packet<Ctx>(
+    ctx: &mut Ctx,
+    packet: &Packet,
+    acknowledgement: &[u8],
+) -> Result<(), Error>
+where
+    Ctx: ICS20Context,
+{
+    let data = FungibleTokenPacketData::from_bytes(&packet.data)?;
+    let acknowledgement = FungibleTokenPacketAcknowledgement::from_bytes(acknowledgement)?;
+
+    match acknowledgement {
+        FungibleTokenPacketAcknowledgement::Success => Ok(()),
+        FungibleTokenPacketAcknowledgement::Error(_) => refund_packet_token(ctx, packet, &data),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::application::ics20_fungible_token_transfer::acknowledgement::FungibleTokenPacketAcknowledgement;
+    use crate::application::ics20_fungible_token_transfer::coin::Coin;
+    use crate::application::ics20_fungible_token_transfer::context::escrow_address;
+    use crate::application::ics20_fungible_token_transfer::denom::DenomTrace;
+    use crate::application::ics20_fungible_token_transfer::relay_application_logic::on_acknowledgement_packet::on_acknowledgement_packet;
+    use crate::application::ics20_fungible_token_transfer::relay_application_logic::test_util::{
+        get_dummy_account, get_dummy_sent_transfer_packet, get_dummy_transfer_channel,
+        get_dummy_transfer_context, get_dummy_transfer_port,
+    };
+    use crate::ics04_channel::packet::Packet;
+    use crate::mock::context::MockContext;
+
+    #[test]
+    fn on_acknowledgement_packet_processing() {
+        struct Test {
+            name: String,
+            ctx: MockContext,
+            packet: Packet,
+            ack: Vec<u8>,
+            want_pass: bool,
+            /// The expected balance of the sender, in the denomination of the packet tokens.
+            want_balance: (String, u64),
+        }
+
+        let sender = get_dummy_account(1);
+        let receiver = get_dummy_account(2);
+        let escrow = escrow_address(&get_dummy_transfer_port(), &get_dummy_transfer_channel());
+
+        let voucher_trace = DenomTrace::new("transfer/channel-0", "uatom");
+
+        // The sender already sent 40 native tokens, which are in escrow, and burned 40 vouchers.
+        let ctx = get_dummy_transfer_context()
+            .with_balance(sender, Coin::new("stake", 60))
+            .with_balance(escrow, Coin::new("stake", 40))
+            .with_balance(sender, Coin::new(voucher_trace.ibc_denom(), 60))
+            .with_denom_trace(voucher_trace.clone());
+
+        let native_packet = get_dummy_sent_transfer_packet("stake", 40, sender, receiver);
+        let voucher_packet =
+            get_dummy_sent_transfer_packet(&voucher_trace.full_path(), 40, sender, receiver);
+
+        let success = FungibleTokenPacketAcknowledgement::Success.to_bytes();
+        let error = FungibleTokenPacketAcknowledgement::Error("failure".to_string()).to_bytes();
+
+        let tests: Vec<Test> = vec![
+            Test {
+                name: "Successful acknowledgement, nothing is refunded".to_string(),
+                ctx: ctx.clone(),
+                packet: native_packet.clone(),
+                ack: success,
+                want_pass: true,
+                want_balance: ("stake".to_string(), 60),
+            },
+            Test {
+                name: "Error acknowledgement, native tokens are released from escrow".to_string(),
+                ctx: ctx.clone(),
+                packet: native_packet.clone(),
+                ack: error.clone(),
+                want_pass: true,
+                want_balance: ("stake".to_string(), 100),
+            },
+            Test {
+                name: "Error acknowledgement, vouchers are minted back".to_string(),
+                ctx: ctx.clone(),
+                packet: voucher_packet,
+                ack: error,
+                want_pass: true,
+                want_balance: (voucher_trace.ibc_denom(), 100),
+            },
+            Test {
+                name: "Invalid acknowledgement".to_string(),
+                ctx,
+                packet: native_packet,
+                ack: b"invalid acknowledgement".to_vec(),
+                want_pass: false,
+                want_balance: ("stake".to_string(), 60),
+            },
+        ];
+
+        for mut test in tests {
+            let res = on_acknowledgement_packet(&mut test.ctx, &test.packet, &test.ack);
+
+            assert_eq!(
+                test.want_pass,
+                res.is_ok(),
+                "on_acknowledgement_packet: test passed but was supposed to fail for test: {}, \nparams {:?} {:?} error: {:?}",
+                test.name,
+                test.packet.clone(),
+                test.ctx.clone(),
+                res.err(),
+            );
+
+            let (denom, amount) = test.want_balance;
+            assert_eq!(
+                test.ctx.balance(&sender, &denom),
+                amount,
+                "on_acknowledgement_packet: unexpected balance for test: {}",
+                test.name
+            );
+        }
+    }
+}
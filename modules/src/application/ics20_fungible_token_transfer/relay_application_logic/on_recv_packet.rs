@@ -0,0 +1,163 @@
+//! Protocol logic for receiving a fungible token transfer packet (ICS20 `onRecvPacket`).
+use std::str::FromStr;
+
+use crate::address::string_to_account;
+use crate::application::ics20_fungible_token_transfer::acknowledgement::FungibleTokenPacketAcknowledgement;
+use crate::application::ics20_fungible_token_transfer::coin::Coin;
+use crate::application::ics20_fungible_token_transfer::context::ICS20Context;
+use crate::application::ics20_fungible_token_transfer::denom::{
+    denom_prefix, receiver_chain_is_source, DenomTrace,
+};
+use crate::application::ics20_fungible_token_transfer::error::{Error, Kind};
+use crate::application::ics20_fungible_token_transfer::packet::FungibleTokenPacketData;
+use crate::ics04_channel::packet::Packet;
+
+/// Credits the tokens transferred by `packet` to the receiver, and returns the acknowledgement to
+/// be written for the packet. Any failure results in an error acknowledgement, upon which the
+/// sending chain refunds the tokens.
+pub fn on_recv_packet<Ctx>(ctx: &mut Ctx, packet: &Packet) -> FungibleTokenPacketAcknowledgement
+where
+    Ctx: ICS20Context,
+{
+    match process(ctx, packet) {
+        Ok(()) => FungibleTokenPacketAcknowledgement::Success,
+        Err(e) => FungibleTokenPacketAcknowledgement::Error(e.to_string()),
+    }
+}
+
+fn process<Ctx>(ctx: &mut Ctx, packet: &Packet) -> Result<(), Error>
+where
+    Ctx: ICS20Context,
+{
+    let data = FungibleTokenPacketData::from_bytes(&packet.data)?;
+
+    let receiver =
+        string_to_account(data.receiver.clone()).map_err(|e| Kind::InvalidAddress.context(e))?;
+
+    if receiver_chain_is_source(&packet.source_port, &packet.source_channel, &data.denom) {
+        // The tokens return to this chain: remove the prefix which the sending chain added, and
+        // release the tokens from escrow.
+        let source_prefix = denom_prefix(&packet.source_port, &packet.source_channel);
+        let trace = DenomTrace::from_str(&data.denom[source_prefix.len()..])?;
+        let token = Coin::new(trace.ibc_denom(), data.amount);
+
+        let escrow = ctx.get_escrow_account(&packet.destination_port, &packet.destination_channel);
+        ctx.send_coins(&escrow, &receiver, &token)
+    } else {
+        // The tokens come from the sending chain: prefix the denomination with the receiving
+        // port and channel, and mint the corresponding vouchers.
+        let trace = DenomTrace::from_str(&format!(
+            "{}{}",
+            denom_prefix(&packet.destination_port, &packet.destination_channel),
+            data.denom
+        ))?;
+        let token = Coin::new(trace.ibc_denom(), data.amount);
+
+        ctx.mint_coins(&receiver, &token)?;
+
+        if ctx.get_denom_trace(&trace.hash()).is_none() {
+            ctx.store_denom_trace(trace)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::application::ics20_fungible_token_transfer::acknowledgement::FungibleTokenPacketAcknowledgement;
+    use crate::application::ics20_fungible_token_transfer::coin::Coin;
+    use crate::application::ics20_fungible_token_transfer::context::{escrow_address, ICS20Reader};
+    use crate::application::ics20_fungible_token_transfer::denom::DenomTrace;
+    use crate::application::ics20_fungible_token_transfer::relay_application_logic::on_recv_packet::on_recv_packet;
+    use crate::application::ics20_fungible_token_transfer::relay_application_logic::test_util::{
+        get_dummy_account, get_dummy_transfer_channel, get_dummy_transfer_context,
+        get_dummy_transfer_packet, get_dummy_transfer_port,
+    };
+    use crate::ics04_channel::packet::Packet;
+    use crate::mock::context::MockContext;
+
+    #[test]
+    fn on_recv_packet_processing() {
+        struct Test {
+            name: String,
+            ctx: MockContext,
+            packet: Packet,
+            want_pass: bool,
+        }
+
+        let sender = get_dummy_account(1);
+        let receiver = get_dummy_account(2);
+        let escrow = escrow_address(&get_dummy_transfer_port(), &get_dummy_transfer_channel());
+
+        let ctx = get_dummy_transfer_context().with_balance(escrow, Coin::new("stake", 100));
+
+        let voucher_trace = DenomTrace::new("transfer/channel-0", "uatom");
+
+        let tests: Vec<Test> = vec![
+            Test {
+                name: "Good parameters, returning tokens are released from escrow".to_string(),
+                ctx: ctx.clone(),
+                packet: get_dummy_transfer_packet("transfer/channel-1/stake", 40, sender, receiver),
+                want_pass: true,
+            },
+            Test {
+                name: "Good parameters, vouchers are minted".to_string(),
+                ctx: ctx.clone(),
+                packet: get_dummy_transfer_packet("uatom", 40, sender, receiver),
+                want_pass: true,
+            },
+            Test {
+                name: "Insufficient funds in escrow".to_string(),
+                ctx: ctx.clone(),
+                packet: get_dummy_transfer_packet(
+                    "transfer/channel-1/stake",
+                    101,
+                    sender,
+                    receiver,
+                ),
+                want_pass: false,
+            },
+            Test {
+                name: "Invalid packet data".to_string(),
+                ctx,
+                packet: Packet {
+                    data: b"invalid packet data".to_vec(),
+                    ..get_dummy_transfer_packet("uatom", 40, sender, receiver)
+                },
+                want_pass: false,
+            },
+        ];
+
+        for mut test in tests {
+            let ack = on_recv_packet(&mut test.ctx, &test.packet);
+
+            assert_eq!(
+                test.want_pass,
+                ack.is_success(),
+                "on_recv_packet: test passed but was supposed to fail for test: {}, \nparams {:?} {:?} ack: {:?}",
+                test.name,
+                test.packet.clone(),
+                test.ctx.clone(),
+                ack,
+            );
+
+            if let FungibleTokenPacketAcknowledgement::Error(_) = ack {
+                assert_eq!(test.ctx.balance(&receiver, "stake"), 0);
+                assert_eq!(test.ctx.balance(&receiver, &voucher_trace.ibc_denom()), 0);
+                continue;
+            }
+
+            if test.ctx.balance(&receiver, "stake") > 0 {
+                assert_eq!(test.ctx.balance(&receiver, "stake"), 40);
+                assert_eq!(test.ctx.balance(&escrow, "stake"), 60);
+            } else {
+                assert_eq!(test.ctx.balance(&receiver, &voucher_trace.ibc_denom()), 40);
+                assert_eq!(
+                    test.ctx.get_denom_trace(&voucher_trace.hash()),
+                    Some(voucher_trace.clone())
+                );
+            }
+        }
+    }
+}
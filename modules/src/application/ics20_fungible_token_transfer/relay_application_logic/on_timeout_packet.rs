@@ -0,0 +1,106 @@
+//! Protocol logic for processing the timeout of a fungible token transfer packet (ICS20
+//! `onTimeoutPacket`).
+use crate::application::ics20_fungible_token_transfer::context::ICS20Context;
+use crate::application::ics20_fungible_token_transfer::error::Error;
+use crate::application::ics20_fungible_token_transfer::packet::FungibleTokenPacketData;
+use crate::application::ics20_fungible_token_transfer::relay_application_logic::refund_packet_token;
+use crate::ics04_channel::packet::Packet;
+
+/// Refunds the tokens transferred by `packet`, which timed out before being received.
+pub fn on_timeout_packet<Ctx>(ctx: &mut Ctx, packet: &Packet) -> Result<(), Error>
+where
+    Ctx: ICS20Context,
+{
+    let data = FungibleTokenPacketData::from_bytes(&packet.data)?;
+    refund_packet_token(ctx, packet, &data)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::application::ics20_fungible_token_transfer::coin::Coin;
+    use crate::application::ics20_fungible_token_transfer::context::escrow_address;
+    use crate::application::ics20_fungible_token_transfer::denom::DenomTrace;
+    use crate::application::ics20_fungible_token_transfer::relay_application_logic::on_timeout_packet::on_timeout_packet;
+    use crate::application::ics20_fungible_token_transfer::relay_application_logic::test_util::{
+        get_dummy_account, get_dummy_sent_transfer_packet, get_dummy_transfer_channel,
+        get_dummy_transfer_context, get_dummy_transfer_port,
+    };
+    use crate::ics04_channel::packet::Packet;
+    use crate::mock::context::MockContext;
+
+    #[test]
+    fn on_timeout_packet_processing() {
+        struct Test {
+            name: String,
+            ctx: MockContext,
+            packet: Packet,
+            want_pass: bool,
+            /// The expected balance of the sender, in the denomination of the packet tokens.
+            want_balance: (String, u64),
+        }
+
+        let sender = get_dummy_account(1);
+        let receiver = get_dummy_account(2);
+        let escrow = escrow_address(&get_dummy_transfer_port(), &get_dummy_transfer_channel());
+
+        let voucher_trace = DenomTrace::new("transfer/channel-0", "uatom");
+
+        // The sender already sent 40 native tokens, which are in escrow, and burned 40 vouchers.
+        let ctx = get_dummy_transfer_context()
+            .with_balance(sender, Coin::new("stake", 60))
+            .with_balance(escrow, Coin::new("stake", 40))
+            .with_balance(sender, Coin::new(voucher_trace.ibc_denom(), 60))
+            .with_denom_trace(voucher_trace.clone());
+
+        let tests: Vec<Test> = vec![
+            Test {
+                name: "Native tokens are released from escrow".to_string(),
+                ctx: ctx.clone(),
+                packet: get_dummy_sent_transfer_packet("stake", 40, sender, receiver),
+                want_pass: true,
+                want_balance: ("stake".to_string(), 100),
+            },
+            Test {
+                name: "Vouchers are minted back".to_string(),
+                ctx: ctx.clone(),
+                packet: get_dummy_sent_transfer_packet(
+                    &voucher_trace.full_path(),
+                    40,
+                    sender,
+                    receiver,
+                ),
+                want_pass: true,
+                want_balance: (voucher_trace.ibc_denom(), 100),
+            },
+            Test {
+                name: "Insufficient funds in escrow".to_string(),
+                ctx,
+                packet: get_dummy_sent_transfer_packet("stake", 41, sender, receiver),
+                want_pass: false,
+                want_balance: ("stake".to_string(), 60),
+            },
+        ];
+
+        for mut test in tests {
+            let res = on_timeout_packet(&mut test.ctx, &test.packet);
+
+            assert_eq!(
+                test.want_pass,
+                res.is_ok(),
+                "on_timeout_packet: test passed but was supposed to fail for test: {}, \nparams {:?} {:?} error: {:?}",
+                test.name,
+                test.packet.clone(),
+                test.ctx.clone(),
+                res.err(),
+            );
+
+            let (denom, amount) = test.want_balance;
+            assert_eq!(
+                test.ctx.balance(&sender, &denom),
+                amount,
+                "on_timeout_packet: unexpected balance for test: {}",
+                test.name
+            );
+        }
+    }
+}
@@ -0,0 +1,271 @@
+//! Protocol logic for sending a fungible token transfer (ICS20 `sendFungibleTokens`), triggered by
+//! a `MsgTransfer` submitted by the owner of the tokens.
+use std::convert::TryFrom;
+
+use crate::address::account_to_string;
+use crate::application::ics20_fungible_token_transfer::coin::Coin;
+use crate::application::ics20_fungible_token_transfer::context::ICS20Context;
+use crate::application::ics20_fungible_token_transfer::denom::{
+    sender_chain_is_source, DENOM_PREFIX,
+};
+use crate::application::ics20_fungible_token_transfer::error::{Error, Kind};
+use crate::application::ics20_fungible_token_transfer::msgs::transfer::MsgTransfer;
+use crate::application::ics20_fungible_token_transfer::packet::FungibleTokenPacketData;
+use crate::application::ics20_fungible_token_transfer::MODULE_ID;
+use crate::handler::{HandlerOutput, HandlerResult};
+use crate::ics04_channel::handler::send_packet::send_packet;
+use crate::ics04_channel::packet::Packet;
+use crate::ics05_port::capabilities::{channel_capability_name, ModuleId};
+
+pub fn send_transfer<Ctx>(ctx: &mut Ctx, msg: MsgTransfer) -> HandlerResult<(), Error>
+where
+    Ctx: ICS20Context,
+{
+    let token = msg
+        .token
+        .clone()
+        .ok_or_else(|| Kind::InvalidToken.context("missing token"))
+        .map(Coin::try_from)??;
+
+    if token.amount == 0 {
+        return Err(Kind::InvalidToken.context("zero amount").into());
+    }
+
+    let source_channel_end = ctx
+        .channel_end(&(msg.source_port.clone(), msg.source_channel.clone()))
+        .ok_or_else(|| {
+            Kind::ChannelNotFound(msg.source_port.clone(), msg.source_channel.clone())
+        })?;
+
+    let destination_port = source_channel_end.counterparty().port_id().clone();
+    let destination_channel = source_channel_end
+        .counterparty()
+        .channel_id()
+        .cloned()
+        .ok_or_else(|| {
+            Kind::MissingCounterpartyChannel(msg.source_port.clone(), msg.source_channel.clone())
+        })?;
+
+    let sequence = ctx
+        .get_next_sequence_send(&(msg.source_port.clone(), msg.source_channel.clone()))
+        .ok_or_else(|| {
+            Kind::MissingNextSendSeq(msg.source_port.clone(), msg.source_channel.clone())
+        })?;
+
+    // Vouchers are sent under their full denomination path, such that the receiving chain can
+    // tell whether the tokens are returning to it.
+    let ibc_denom_prefix = format!("{}/", DENOM_PREFIX);
+    let full_denom_path = if token.denom.starts_with(&ibc_denom_prefix) {
+        let denom_hash = &token.denom[ibc_denom_prefix.len()..];
+        ctx.get_denom_trace(denom_hash)
+            .ok_or_else(|| Kind::DenomTraceNotFound(denom_hash.to_string()))?
+            .full_path()
+    } else {
+        token.denom.clone()
+    };
+
+    let data = FungibleTokenPacketData {
+        denom: full_denom_path,
+        amount: token.amount,
+        sender: account_to_string(msg.sender).map_err(|e| Kind::InvalidAddress.context(e))?,
+        receiver: account_to_string(msg.receiver).map_err(|e| Kind::InvalidAddress.context(e))?,
+    };
+
+    let packet = Packet {
+        sequence,
+        source_port: msg.source_port.clone(),
+        source_channel: msg.source_channel.clone(),
+        destination_port,
+        destination_channel,
+        data: data.to_bytes(),
+        timeout_height: msg.timeout_height,
+        timeout_timestamp: msg.timeout_timestamp,
+    };
+
+    // The transfer module can only send packets on the channels it owns.
+    let channel_cap = ctx
+        .capability_keeper()
+        .get_capability(
+            &ModuleId::new(MODULE_ID),
+            &channel_capability_name(&msg.source_port, &msg.source_channel),
+        )
+        .ok_or_else(|| {
+            Kind::ChannelNotOwned(msg.source_port.clone(), msg.source_channel.clone())
+        })?;
+
+    let output =
+        send_packet(ctx, &channel_cap, packet).map_err(|e| Kind::SendPacketFailure.context(e))?;
+
+    // Tokens native to this chain are escrowed until they return, while vouchers are burned.
+    if sender_chain_is_source(&msg.source_port, &msg.source_channel, &data.denom) {
+        let escrow = ctx.get_escrow_account(&msg.source_port, &msg.source_channel);
+        ctx.send_coins(&msg.sender, &escrow, &token)?;
+    } else {
+        ctx.burn_coins(&msg.sender, &token)?;
+    }
+
+    ctx.store_packet_result(output.result)
+        .map_err(|e| Kind::SendPacketFailure.context(e))?;
+
+    Ok(HandlerOutput::builder()
+        .with_log(output.log)
+        .with_events(output.events)
+        .with_result(()))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::application::ics20_fungible_token_transfer::coin::Coin;
+    use crate::application::ics20_fungible_token_transfer::context::escrow_address;
+    use crate::application::ics20_fungible_token_transfer::denom::DenomTrace;
+    use crate::application::ics20_fungible_token_transfer::msgs::transfer::MsgTransfer;
+    use crate::application::ics20_fungible_token_transfer::packet::FungibleTokenPacketData;
+    use crate::application::ics20_fungible_token_transfer::relay_application_logic::send_transfer::send_transfer;
+    use crate::application::ics20_fungible_token_transfer::relay_application_logic::test_util::{
+        get_dummy_account, get_dummy_transfer_channel, get_dummy_transfer_context,
+        get_dummy_transfer_port,
+    };
+    use crate::events::IBCEvent;
+    use crate::ics04_channel::context::ChannelReader;
+    use crate::ics24_host::identifier::{ChannelId, PortId};
+    use crate::mock::context::MockContext;
+    use crate::timestamp::Timestamp;
+    use crate::Height;
+
+    #[test]
+    fn send_transfer_processing() {
+        struct Test {
+            name: String,
+            ctx: MockContext,
+            msg: MsgTransfer,
+            want_pass: bool,
+        }
+
+        let sender = get_dummy_account(1);
+        let receiver = get_dummy_account(2);
+        let port_id = get_dummy_transfer_port();
+        let channel_id = get_dummy_transfer_channel();
+        let escrow = escrow_address(&port_id, &channel_id);
+
+        let voucher_trace = DenomTrace::new("transfer/channel-0", "uatom");
+
+        let ctx = get_dummy_transfer_context()
+            .with_balance(sender, Coin::new("stake", 100))
+            .with_balance(sender, Coin::new(voucher_trace.ibc_denom(), 100))
+            .with_denom_trace(voucher_trace.clone());
+
+        // A channel of the same counterparty on another port, which the transfer module is not
+        // bound to.
+        let other_port_id = PortId::from_str("other").unwrap();
+        let channel_end = ctx
+            .channel_end(&(port_id.clone(), channel_id.clone()))
+            .unwrap();
+
+        let msg = |denom: &str, amount: u64| MsgTransfer {
+            source_port: port_id.clone(),
+            source_channel: channel_id.clone(),
+            token: Some(Coin::new(denom, amount).into()),
+            sender,
+            receiver,
+            timeout_height: Height::new(1, 100),
+            timeout_timestamp: Timestamp::none(),
+        };
+
+        let tests: Vec<Test> = vec![
+            Test {
+                name: "Good parameters, native tokens are escrowed".to_string(),
+                ctx: ctx.clone(),
+                msg: msg("stake", 40),
+                want_pass: true,
+            },
+            Test {
+                name: "Good parameters, vouchers are burned".to_string(),
+                ctx: ctx.clone(),
+                msg: msg(&voucher_trace.ibc_denom(), 40),
+                want_pass: true,
+            },
+            Test {
+                name: "Voucher with unknown denomination trace".to_string(),
+                ctx: ctx.clone(),
+                msg: msg(
+                    &DenomTrace::new("transfer/channel-7", "uatom").ibc_denom(),
+                    40,
+                ),
+                want_pass: false,
+            },
+            Test {
+                name: "Insufficient funds".to_string(),
+                ctx: ctx.clone(),
+                msg: msg("stake", 101),
+                want_pass: false,
+            },
+            Test {
+                name: "Zero amount".to_string(),
+                ctx: ctx.clone(),
+                msg: msg("stake", 0),
+                want_pass: false,
+            },
+            Test {
+                name: "Channel owned by another module".to_string(),
+                ctx: ctx
+                    .clone()
+                    .with_port_capability(other_port_id.clone())
+                    .with_channel_init(other_port_id.clone(), channel_id.clone(), channel_end),
+                msg: MsgTransfer {
+                    source_port: other_port_id,
+                    ..msg("stake", 40)
+                },
+                want_pass: false,
+            },
+            Test {
+                name: "Channel does not exist".to_string(),
+                ctx,
+                msg: MsgTransfer {
+                    source_channel: ChannelId::from_str("channel-7").unwrap(),
+                    ..msg("stake", 40)
+                },
+                want_pass: false,
+            },
+        ];
+
+        for mut test in tests {
+            let res = send_transfer(&mut test.ctx, test.msg.clone());
+
+            assert_eq!(
+                test.want_pass,
+                res.is_ok(),
+                "send_transfer: test passed but was supposed to fail for test: {}, \nparams {:?} {:?} error: {:?}",
+                test.name,
+                test.msg.clone(),
+                test.ctx.clone(),
+                res.as_ref().err(),
+            );
+
+            if let Ok(output) = res {
+                let packet = match &output.events[..] {
+                    [IBCEvent::SendPacket(e)] => e.packet.clone(),
+                    _ => panic!("send_transfer: unexpected events {:?}", output.events),
+                };
+
+                let data = FungibleTokenPacketData::from_bytes(&packet.data).unwrap();
+                assert_eq!(data.amount, 40);
+                assert!(test
+                    .ctx
+                    .get_packet_commitment(&(port_id.clone(), channel_id.clone(), packet.sequence))
+                    .is_some());
+
+                if data.denom == "stake" {
+                    assert_eq!(test.ctx.balance(&sender, "stake"), 60);
+                    assert_eq!(test.ctx.balance(&escrow, "stake"), 40);
+                } else {
+                    // Vouchers travel under their full denomination path.
+                    assert_eq!(data.denom, voucher_trace.full_path());
+                    assert_eq!(test.ctx.balance(&sender, &voucher_trace.ibc_denom()), 60);
+                    assert_eq!(test.ctx.balance(&escrow, &voucher_trace.ibc_denom()), 0);
+                }
+            }
+        }
+    }
+}
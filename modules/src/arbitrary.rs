@@ -0,0 +1,653 @@
+//! Generators of arbitrary values of the domain types, as `proptest` strategies, for property-based
+//! testing of the conversions of the domain messages from and into their raw (proto) counterparts.
+//!
+//! Every generated value is valid, i.e., it is accepted by the domain type conversions: proof
+//! heights are non-zero, proofs are non-empty, and so on. Clients, their states, headers and
+//! misbehaviours are always those of the mock client.
+
+use std::convert::TryFrom;
+
+use proptest::collection::vec;
+use proptest::option;
+use proptest::prelude::*;
+use tendermint::account::Id as AccountId;
+
+use ibc_proto::cosmos::base::v1beta1::Coin;
+use ibc_proto::ibc::core::connection::v1::Version as RawVersion;
+
+use crate::application::ics20_fungible_token_transfer::msgs::transfer::MsgTransfer;
+use crate::ics02_client::client_def::{
+    AnyClientState, AnyConsensusState, AnyHeader, AnyMisbehaviour,
+};
+use crate::ics02_client::msgs::create_client::MsgCreateAnyClient;
+use crate::ics02_client::msgs::misbehaviour::MsgSubmitAnyMisbehaviour;
+use crate::ics02_client::msgs::update_client::MsgUpdateAnyClient;
+use crate::ics02_client::msgs::upgrade_client::MsgUpgradeAnyClient;
+use crate::ics03_connection::connection::Counterparty as ConnectionCounterparty;
+use crate::ics03_connection::msgs::conn_open_ack::MsgConnectionOpenAck;
+use crate::ics03_connection::msgs::conn_open_confirm::MsgConnectionOpenConfirm;
+use crate::ics03_connection::msgs::conn_open_init::MsgConnectionOpenInit;
+use crate::ics03_connection::msgs::conn_open_try::MsgConnectionOpenTry;
+use crate::ics03_connection::version::Version;
+use crate::ics04_channel::channel::{
+    ChannelEnd, Counterparty as ChannelCounterparty, Order, State,
+};
+use crate::ics04_channel::msgs::acknowledgement::MsgAcknowledgement;
+use crate::ics04_channel::msgs::chan_close_confirm::MsgChannelCloseConfirm;
+use crate::ics04_channel::msgs::chan_close_init::MsgChannelCloseInit;
+use crate::ics04_channel::msgs::chan_open_ack::MsgChannelOpenAck;
+use crate::ics04_channel::msgs::chan_open_confirm::MsgChannelOpenConfirm;
+use crate::ics04_channel::msgs::chan_open_init::MsgChannelOpenInit;
+use crate::ics04_channel::msgs::chan_open_try::MsgChannelOpenTry;
+use crate::ics04_channel::msgs::recv_packet::MsgRecvPacket;
+use crate::ics04_channel::msgs::timeout::MsgTimeout;
+use crate::ics04_channel::msgs::timeout_on_close::MsgTimeoutOnClose;
+use crate::ics04_channel::packet::{Packet, Sequence};
+use crate::ics23_commitment::commitment::{CommitmentPrefix, CommitmentProofBytes};
+use crate::ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId};
+use crate::mock::client_state::{MockClientState, MockConsensusState};
+use crate::mock::header::MockHeader;
+use crate::mock::misbehaviour::MockMisbehaviour;
+use crate::proofs::{ConsensusProof, Proofs};
+use crate::timestamp::Timestamp;
+use crate::Height;
+
+/// The characters that identifiers may comprise (see `ics24_host::validate`).
+const IDENTIFIER_CHARS: &str = "[a-zA-Z0-9._+#<>\\[\\]-]";
+
+/// Implements `Arbitrary` for a type, with the strategy built by the given expression.
+macro_rules! arbitrary {
+    ($ty:ty, $strategy:expr) => {
+        impl Arbitrary for $ty {
+            type Parameters = ();
+            type Strategy = BoxedStrategy<Self>;
+
+            fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+                $strategy.boxed()
+            }
+        }
+    };
+}
+
+/// Identifiers of the given length bounds.
+fn identifier(min: usize, max: usize) -> impl Strategy<Value = String> {
+    proptest::string::string_regex(&format!("{}{{{},{}}}", IDENTIFIER_CHARS, min, max)).unwrap()
+}
+
+arbitrary!(
+    ClientId,
+    identifier(9, 64).prop_map(|id| id.parse().unwrap())
+);
+arbitrary!(
+    ConnectionId,
+    identifier(10, 64).prop_map(|id| id.parse().unwrap())
+);
+arbitrary!(PortId, identifier(2, 64).prop_map(|id| id.parse().unwrap()));
+arbitrary!(
+    ChannelId,
+    identifier(8, 64).prop_map(|id| id.parse().unwrap())
+);
+
+arbitrary!(
+    Height,
+    (any::<u64>(), any::<u64>()).prop_map(|(number, height)| Height::new(number, height))
+);
+
+/// Heights at which proofs are taken, which cannot be zero.
+pub fn proof_height() -> impl Strategy<Value = Height> {
+    any::<Height>().prop_filter("proof heights are not zero", |height| !height.is_zero())
+}
+
+arbitrary!(
+    Timestamp,
+    prop_oneof![
+        Just(Timestamp::none()),
+        (1..=i64::MAX as u64)
+            .prop_map(|nanoseconds| Timestamp::from_nanoseconds(nanoseconds).unwrap()),
+    ]
+);
+
+arbitrary!(Sequence, any::<u64>().prop_map(Sequence::from));
+
+/// Signers, and the addresses of senders and receivers of tokens.
+pub fn account_id() -> impl Strategy<Value = AccountId> {
+    any::<[u8; 20]>().prop_map(AccountId::new)
+}
+
+// Non-empty proofs, as messages carry.
+arbitrary!(
+    CommitmentProofBytes,
+    vec(any::<u8>(), 1..128).prop_map(CommitmentProofBytes::from)
+);
+
+arbitrary!(
+    CommitmentPrefix,
+    vec(any::<u8>(), 0..32).prop_map(CommitmentPrefix::from)
+);
+
+arbitrary!(
+    ConsensusProof,
+    (any::<CommitmentProofBytes>(), proof_height())
+        .prop_map(|(proof, height)| ConsensusProof::new(proof, height).unwrap())
+);
+
+// Proofs of a single object, as most messages carry. The few messages which carry more proofs
+// build them from their parts.
+arbitrary!(
+    Proofs,
+    (any::<CommitmentProofBytes>(), proof_height())
+        .prop_map(|(proof, height)| Proofs::new(proof, None, None, None, height).unwrap())
+);
+
+/// Non-empty strings, without surrounding whitespace, for versions and their features.
+fn version_string() -> impl Strategy<Value = String> {
+    "[a-zA-Z0-9_]{1,16}"
+}
+
+arbitrary!(
+    Version,
+    (version_string(), vec(version_string(), 0..4)).prop_map(|(identifier, features)| {
+        Version::try_from(RawVersion {
+            identifier,
+            features,
+        })
+        .unwrap()
+    })
+);
+
+arbitrary!(
+    ConnectionCounterparty,
+    (
+        any::<ClientId>(),
+        option::of(any::<ConnectionId>()),
+        any::<CommitmentPrefix>()
+    )
+        .prop_map(|(client_id, connection_id, prefix)| {
+            ConnectionCounterparty::new(client_id, connection_id, prefix)
+        })
+);
+
+arbitrary!(
+    ChannelCounterparty,
+    (any::<PortId>(), option::of(any::<ChannelId>()))
+        .prop_map(|(port_id, channel_id)| ChannelCounterparty::new(port_id, channel_id))
+);
+
+// Channel ends in any state but `Uninitialized`, which stands for a missing channel end.
+arbitrary!(
+    ChannelEnd,
+    (
+        prop_oneof![
+            Just(State::Init),
+            Just(State::TryOpen),
+            Just(State::Open),
+            Just(State::Closed)
+        ],
+        prop_oneof![
+            Just(Order::None),
+            Just(Order::Unordered),
+            Just(Order::Ordered)
+        ],
+        any::<ChannelCounterparty>(),
+        vec(any::<ConnectionId>(), 0..3),
+        any::<String>(),
+    )
+        .prop_map(|(state, ordering, remote, connection_hops, version)| {
+            ChannelEnd::new(state, ordering, remote, connection_hops, version)
+        })
+);
+
+arbitrary!(
+    Packet,
+    (
+        any::<Sequence>(),
+        any::<PortId>(),
+        any::<ChannelId>(),
+        any::<PortId>(),
+        any::<ChannelId>(),
+        vec(any::<u8>(), 0..128),
+        any::<Height>(),
+        any::<Timestamp>(),
+    )
+        .prop_map(
+            |(
+                sequence,
+                source_port,
+                source_channel,
+                destination_port,
+                destination_channel,
+                data,
+                timeout_height,
+                timeout_timestamp,
+            )| Packet {
+                sequence,
+                source_port,
+                source_channel,
+                destination_port,
+                destination_channel,
+                data,
+                timeout_height,
+                timeout_timestamp,
+            }
+        )
+);
+
+arbitrary!(
+    MockHeader,
+    (any::<Height>(), any::<Timestamp>())
+        .prop_map(|(height, timestamp)| MockHeader::new(height).with_timestamp(timestamp))
+);
+
+arbitrary!(
+    AnyClientState,
+    (any::<MockHeader>(), option::of(any::<Height>())).prop_map(|(header, frozen_height)| {
+        AnyClientState::Mock(MockClientState {
+            header,
+            frozen_height,
+        })
+    })
+);
+
+arbitrary!(
+    AnyConsensusState,
+    any::<MockHeader>().prop_map(|header| AnyConsensusState::Mock(MockConsensusState::new(header)))
+);
+
+arbitrary!(AnyHeader, any::<MockHeader>().prop_map(AnyHeader::Mock));
+
+arbitrary!(
+    AnyMisbehaviour,
+    (any::<ClientId>(), any::<MockHeader>(), any::<MockHeader>()).prop_map(
+        |(client_id, header1, header2)| {
+            AnyMisbehaviour::Mock(MockMisbehaviour {
+                client_id,
+                header1,
+                header2,
+            })
+        }
+    )
+);
+
+arbitrary!(
+    MsgCreateAnyClient,
+    (
+        any::<AnyClientState>(),
+        any::<AnyConsensusState>(),
+        account_id()
+    )
+        .prop_map(|(client_state, consensus_state, signer)| {
+            MsgCreateAnyClient::new(client_state, consensus_state, signer).unwrap()
+        })
+);
+
+arbitrary!(
+    MsgUpdateAnyClient,
+    (any::<ClientId>(), any::<AnyHeader>(), account_id())
+        .prop_map(|(client_id, header, signer)| MsgUpdateAnyClient::new(client_id, header, signer))
+);
+
+arbitrary!(
+    MsgSubmitAnyMisbehaviour,
+    (any::<ClientId>(), any::<AnyMisbehaviour>(), account_id()).prop_map(
+        |(client_id, misbehaviour, signer)| {
+            MsgSubmitAnyMisbehaviour::new(client_id, misbehaviour, signer)
+        }
+    )
+);
+
+arbitrary!(
+    MsgUpgradeAnyClient,
+    (
+        any::<ClientId>(),
+        any::<AnyClientState>(),
+        any::<AnyConsensusState>(),
+        any::<CommitmentProofBytes>(),
+        any::<CommitmentProofBytes>(),
+        account_id(),
+    )
+        .prop_map(
+            |(
+                client_id,
+                client_state,
+                consensus_state,
+                proof_upgrade_client,
+                proof_upgrade_consensus_state,
+                signer,
+            )| {
+                MsgUpgradeAnyClient::new(
+                    client_id,
+                    client_state,
+                    consensus_state,
+                    proof_upgrade_client,
+                    proof_upgrade_consensus_state,
+                    signer,
+                )
+                .unwrap()
+            }
+        )
+);
+
+arbitrary!(
+    MsgConnectionOpenInit,
+    (
+        any::<ClientId>(),
+        any::<ConnectionCounterparty>(),
+        any::<Version>(),
+        any::<u64>(),
+        account_id(),
+    )
+        .prop_map(|(client_id, counterparty, version, delay_period, signer)| {
+            MsgConnectionOpenInit {
+                client_id,
+                counterparty,
+                version,
+                delay_period,
+                signer,
+            }
+        })
+);
+
+/// The proofs of the messages which also prove the state of the client of the counterparty chain,
+/// and the consensus state which it stores for the chain the message is sent to.
+fn client_and_consensus_proofs() -> impl Strategy<Value = Proofs> {
+    (
+        any::<CommitmentProofBytes>(),
+        option::of(any::<CommitmentProofBytes>()),
+        any::<ConsensusProof>(),
+        proof_height(),
+    )
+        .prop_map(|(object_proof, client_proof, consensus_proof, height)| {
+            Proofs::new(
+                object_proof,
+                client_proof,
+                Some(consensus_proof),
+                None,
+                height,
+            )
+            .unwrap()
+        })
+}
+
+arbitrary!(
+    MsgConnectionOpenTry,
+    (
+        option::of(any::<ConnectionId>()),
+        any::<ClientId>(),
+        option::of(any::<AnyClientState>()),
+        any::<ConnectionCounterparty>(),
+        vec(any::<Version>(), 1..4),
+        client_and_consensus_proofs(),
+        any::<u64>(),
+        account_id(),
+    )
+        .prop_map(
+            |(
+                previous_connection_id,
+                client_id,
+                client_state,
+                counterparty,
+                counterparty_versions,
+                proofs,
+                delay_period,
+                signer,
+            )| MsgConnectionOpenTry {
+                previous_connection_id,
+                client_id,
+                client_state,
+                counterparty,
+                counterparty_versions,
+                proofs,
+                delay_period,
+                signer,
+            }
+        )
+);
+
+arbitrary!(
+    MsgConnectionOpenAck,
+    (
+        any::<ConnectionId>(),
+        option::of(any::<ConnectionId>()),
+        option::of(any::<AnyClientState>()),
+        client_and_consensus_proofs(),
+        any::<Version>(),
+        account_id(),
+    )
+        .prop_map(
+            |(connection_id, counterparty_connection_id, client_state, proofs, version, signer)| {
+                MsgConnectionOpenAck {
+                    connection_id,
+                    counterparty_connection_id,
+                    client_state,
+                    proofs,
+                    version,
+                    signer,
+                }
+            }
+        )
+);
+
+arbitrary!(
+    MsgConnectionOpenConfirm,
+    (any::<ConnectionId>(), any::<Proofs>(), account_id()).prop_map(
+        |(connection_id, proofs, signer)| MsgConnectionOpenConfirm {
+            connection_id,
+            proofs,
+            signer,
+        }
+    )
+);
+
+arbitrary!(
+    MsgChannelOpenInit,
+    (any::<PortId>(), any::<ChannelEnd>(), account_id()).prop_map(|(port_id, channel, signer)| {
+        MsgChannelOpenInit {
+            port_id,
+            channel,
+            signer,
+        }
+    })
+);
+
+// The channel end of a `MsgChannelOpenTry` knows the channel of the counterparty.
+arbitrary!(
+    MsgChannelOpenTry,
+    (
+        any::<PortId>(),
+        option::of(any::<ChannelId>()),
+        any::<ChannelEnd>(),
+        any::<ChannelId>(),
+        any::<String>(),
+        any::<Proofs>(),
+        account_id(),
+    )
+        .prop_map(
+            |(
+                port_id,
+                previous_channel_id,
+                mut channel,
+                counterparty_channel_id,
+                counterparty_version,
+                proofs,
+                signer,
+            )| {
+                channel.set_counterparty_channel_id(counterparty_channel_id);
+                MsgChannelOpenTry {
+                    port_id,
+                    previous_channel_id,
+                    channel,
+                    counterparty_version,
+                    proofs,
+                    signer,
+                }
+            }
+        )
+);
+
+arbitrary!(
+    MsgChannelOpenAck,
+    (
+        any::<PortId>(),
+        any::<ChannelId>(),
+        any::<ChannelId>(),
+        any::<String>(),
+        any::<Proofs>(),
+        account_id(),
+    )
+        .prop_map(
+            |(
+                port_id,
+                channel_id,
+                counterparty_channel_id,
+                counterparty_version,
+                proofs,
+                signer,
+            )| {
+                MsgChannelOpenAck {
+                    port_id,
+                    channel_id,
+                    counterparty_channel_id,
+                    counterparty_version,
+                    proofs,
+                    signer,
+                }
+            }
+        )
+);
+
+arbitrary!(
+    MsgChannelOpenConfirm,
+    (
+        any::<PortId>(),
+        any::<ChannelId>(),
+        any::<Proofs>(),
+        account_id()
+    )
+        .prop_map(
+            |(port_id, channel_id, proofs, signer)| MsgChannelOpenConfirm {
+                port_id,
+                channel_id,
+                proofs,
+                signer,
+            }
+        )
+);
+
+arbitrary!(
+    MsgChannelCloseInit,
+    (any::<PortId>(), any::<ChannelId>(), account_id()).prop_map(
+        |(port_id, channel_id, signer)| MsgChannelCloseInit {
+            port_id,
+            channel_id,
+            signer,
+        }
+    )
+);
+
+arbitrary!(
+    MsgChannelCloseConfirm,
+    (
+        any::<PortId>(),
+        any::<ChannelId>(),
+        any::<Proofs>(),
+        account_id()
+    )
+        .prop_map(
+            |(port_id, channel_id, proofs, signer)| MsgChannelCloseConfirm {
+                port_id,
+                channel_id,
+                proofs,
+                signer,
+            }
+        )
+);
+
+arbitrary!(
+    MsgRecvPacket,
+    (any::<Packet>(), any::<Proofs>(), account_id()).prop_map(|(packet, proofs, signer)| {
+        MsgRecvPacket::new(packet, proofs, signer).unwrap()
+    })
+);
+
+arbitrary!(
+    MsgAcknowledgement,
+    (
+        any::<Packet>(),
+        vec(any::<u8>(), 0..128),
+        any::<Proofs>(),
+        account_id()
+    )
+        .prop_map(|(packet, acknowledgement, proofs, signer)| {
+            MsgAcknowledgement::new(packet, acknowledgement, proofs, signer).unwrap()
+        })
+);
+
+arbitrary!(
+    MsgTimeout,
+    (
+        any::<Packet>(),
+        any::<Sequence>(),
+        any::<Proofs>(),
+        account_id()
+    )
+        .prop_map(|(packet, next_sequence_recv, proofs, signer)| {
+            MsgTimeout::new(packet, next_sequence_recv, proofs, signer).unwrap()
+        })
+);
+
+// The proofs of a `MsgTimeoutOnClose` also prove that the counterparty channel is closed.
+arbitrary!(
+    MsgTimeoutOnClose,
+    (
+        any::<Packet>(),
+        any::<Sequence>(),
+        any::<CommitmentProofBytes>(),
+        any::<CommitmentProofBytes>(),
+        proof_height(),
+        account_id(),
+    )
+        .prop_map(
+            |(packet, next_sequence_recv, proof_unreceived, proof_close, height, signer)| {
+                let proofs =
+                    Proofs::new(proof_unreceived, None, None, Some(proof_close), height).unwrap();
+                MsgTimeoutOnClose::new(packet, next_sequence_recv, proofs, signer).unwrap()
+            }
+        )
+);
+
+/// Amounts of tokens, of any denomination.
+fn coin() -> impl Strategy<Value = Coin> {
+    ("[a-z][a-z0-9/]{2,64}", "[0-9]{1,20}").prop_map(|(denom, amount)| Coin { denom, amount })
+}
+
+arbitrary!(
+    MsgTransfer,
+    (
+        any::<PortId>(),
+        any::<ChannelId>(),
+        option::of(coin()),
+        account_id(),
+        account_id(),
+        any::<Height>(),
+        any::<Timestamp>(),
+    )
+        .prop_map(
+            |(
+                source_port,
+                source_channel,
+                token,
+                sender,
+                receiver,
+                timeout_height,
+                timeout_timestamp,
+            )| MsgTransfer {
+                source_port,
+                source_channel,
+                token,
+                sender,
+                receiver,
+                timeout_height,
+                timeout_timestamp,
+            }
+        )
+);
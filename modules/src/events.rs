@@ -39,6 +39,7 @@ pub enum IBCEvent {
     CreateClient(ClientEvents::CreateClient),
     UpdateClient(ClientEvents::UpdateClient),
     ClientMisbehavior(ClientEvents::ClientMisbehavior),
+    UpgradeClient(ClientEvents::UpgradeClient),
 
     OpenInitConnection(ConnectionEvents::OpenInit),
     OpenTryConnection(ConnectionEvents::OpenTry),
@@ -91,11 +92,14 @@ impl IBCEvent {
         match self {
             IBCEvent::NewBlock(ev) => &ev.height,
             IBCEvent::UpdateClient(ev) => ev.height(),
+            IBCEvent::ClientMisbehavior(ev) => ev.height(),
+            IBCEvent::UpgradeClient(ev) => ev.height(),
             IBCEvent::SendPacket(ev) => &ev.height,
             IBCEvent::ReceivePacket(ev) => &ev.height,
             IBCEvent::WriteAcknowledgement(ev) => &ev.height,
             IBCEvent::AcknowledgePacket(ev) => &ev.height,
             IBCEvent::TimeoutPacket(ev) => &ev.height,
+            IBCEvent::TimeoutOnClosePacket(ev) => &ev.height,
             IBCEvent::CloseInitChannel(ev) => ev.height(),
 
             _ => unimplemented!(),
@@ -119,6 +123,9 @@ impl IBCEvent {
             IBCEvent::TimeoutPacket(ev) => {
                 ev.height = Height::try_from(height.revision_height).unwrap()
             }
+            IBCEvent::TimeoutOnClosePacket(ev) => {
+                ev.height = Height::try_from(height.revision_height).unwrap()
+            }
             IBCEvent::CloseInitChannel(ev) => {
                 ev.set_height(Height::try_from(height.revision_height).unwrap())
             }
@@ -0,0 +1,234 @@
+//! Gas metering of the IBC handlers, for hosts to charge for the work that processing a message
+//! takes, as the Cosmos SDK does.
+//!
+//! The handlers consume gas from the meter of the host at well-defined points, according to the
+//! cost schedule of the meter:
+//! - the readers and keepers of the hosts charge every read and write of their IBC state, by the
+//!   size of the value, and every key iterated over (see `StoreHost` and `MockContext`);
+//! - the ICS2 handlers charge the verification of headers and misbehaviours, by their size;
+//! - `AnyClient` charges the verification of the proofs of the ICS3 and ICS4 messages, by their
+//!   size, whatever the type of the client;
+//! - the ICS2, ICS3 and ICS4 dispatchers charge every event emitted by the handlers.
+//!
+//! Reading the state of the host cannot fail, so the readers keep on going once the meter is out
+//! of gas. A meter which ran out of gas stays so, and the next consumption of gas fails: `deliver`
+//! checks the meter after every message, and fails with an out-of-gas error in that case.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use thiserror::Error;
+
+/// An amount of gas.
+pub type Gas = u64;
+
+/// The cost schedule of the operations that the handlers charge for. The default schedule is the
+/// one of the Cosmos SDK for the accesses to the store, and the size of a transaction for the
+/// verification of headers and proofs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct GasCosts {
+    pub read_flat: Gas,
+    pub read_per_byte: Gas,
+    pub write_flat: Gas,
+    pub write_per_byte: Gas,
+    pub iter_next_flat: Gas,
+    pub verify_header_flat: Gas,
+    pub verify_header_per_byte: Gas,
+    pub verify_proof_flat: Gas,
+    pub verify_proof_per_byte: Gas,
+    pub event_flat: Gas,
+}
+
+impl Default for GasCosts {
+    fn default() -> Self {
+        Self {
+            read_flat: 1000,
+            read_per_byte: 3,
+            write_flat: 2000,
+            write_per_byte: 30,
+            iter_next_flat: 30,
+            verify_header_flat: 1000,
+            verify_header_per_byte: 10,
+            verify_proof_flat: 1000,
+            verify_proof_per_byte: 10,
+            event_flat: 100,
+        }
+    }
+}
+
+impl GasCosts {
+    /// The cost of reading a value of `len` bytes, or of finding out that there is none.
+    pub fn read(&self, len: usize) -> Gas {
+        per_byte(self.read_flat, self.read_per_byte, len)
+    }
+
+    /// The cost of writing a value of `len` bytes.
+    pub fn write(&self, len: usize) -> Gas {
+        per_byte(self.write_flat, self.write_per_byte, len)
+    }
+
+    /// The cost of iterating over `keys` keys.
+    pub fn iter(&self, keys: usize) -> Gas {
+        self.iter_next_flat.saturating_mul(keys as Gas)
+    }
+
+    /// The cost of verifying a header (or a misbehaviour) of `len` bytes.
+    pub fn verify_header(&self, len: usize) -> Gas {
+        per_byte(self.verify_header_flat, self.verify_header_per_byte, len)
+    }
+
+    /// The cost of verifying a proof of `len` bytes.
+    pub fn verify_proof(&self, len: usize) -> Gas {
+        per_byte(self.verify_proof_flat, self.verify_proof_per_byte, len)
+    }
+
+    /// The cost of emitting `events` events.
+    pub fn events(&self, events: usize) -> Gas {
+        self.event_flat.saturating_mul(events as Gas)
+    }
+}
+
+fn per_byte(flat: Gas, per_byte: Gas, len: usize) -> Gas {
+    flat.saturating_add(per_byte.saturating_mul(len as Gas))
+}
+
+/// The error of consuming more gas than the limit of the meter.
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+#[error("out of gas in {descriptor}: {consumed} consumed, with a limit of {limit}")]
+pub struct OutOfGas {
+    /// What the gas was consumed for.
+    pub descriptor: String,
+    pub consumed: Gas,
+    pub limit: Gas,
+}
+
+/// The gas meter of a host chain. It is consumed through shared references, as the handlers only
+/// read the state of the host. Its consumption is part of the snapshots of the host taken by
+/// `deliver`, which charges the gas consumed by a failed transaction back to the host before
+/// discarding its snapshot.
+pub trait GasMeter {
+    /// Returns the cost schedule of the operations that the handlers charge for.
+    fn gas_costs(&self) -> &GasCosts;
+
+    /// Returns the gas consumed so far.
+    fn gas_consumed(&self) -> Gas;
+
+    /// Consumes `amount` of gas for the operation described by `descriptor`. Fails once more gas
+    /// than the limit of the meter has been consumed, including by this very operation, even if
+    /// `amount` is zero.
+    fn consume_gas(&self, amount: Gas, descriptor: &str) -> Result<(), OutOfGas>;
+}
+
+/// A gas meter with an optional limit.
+#[derive(Debug)]
+pub struct BasicGasMeter {
+    limit: Option<Gas>,
+    consumed: AtomicU64,
+    costs: GasCosts,
+}
+
+impl BasicGasMeter {
+    /// A meter which runs out of gas once more than `limit` is consumed.
+    pub fn new(limit: Gas, costs: GasCosts) -> Self {
+        Self {
+            limit: Some(limit),
+            consumed: AtomicU64::new(0),
+            costs,
+        }
+    }
+
+    /// A meter which only keeps track of the gas consumed, and never runs out of it.
+    pub fn infinite(costs: GasCosts) -> Self {
+        Self {
+            limit: None,
+            consumed: AtomicU64::new(0),
+            costs,
+        }
+    }
+
+    pub fn gas_limit(&self) -> Option<Gas> {
+        self.limit
+    }
+}
+
+impl Default for BasicGasMeter {
+    fn default() -> Self {
+        Self::infinite(GasCosts::default())
+    }
+}
+
+impl Clone for BasicGasMeter {
+    fn clone(&self) -> Self {
+        Self {
+            limit: self.limit,
+            consumed: AtomicU64::new(self.gas_consumed()),
+            costs: self.costs,
+        }
+    }
+}
+
+impl GasMeter for BasicGasMeter {
+    fn gas_costs(&self) -> &GasCosts {
+        &self.costs
+    }
+
+    fn gas_consumed(&self) -> Gas {
+        self.consumed.load(Ordering::SeqCst)
+    }
+
+    fn consume_gas(&self, amount: Gas, descriptor: &str) -> Result<(), OutOfGas> {
+        // The update always succeeds, since the closure always returns a value.
+        let previous = self
+            .consumed
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |consumed| {
+                Some(consumed.saturating_add(amount))
+            })
+            .unwrap_or_else(|consumed| consumed);
+        let consumed = previous.saturating_add(amount);
+
+        match self.limit {
+            Some(limit) if consumed > limit => Err(OutOfGas {
+                descriptor: descriptor.to_string(),
+                consumed,
+                limit,
+            }),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::gas::{BasicGasMeter, GasCosts, GasMeter, OutOfGas};
+
+    #[test]
+    fn gas_meter() {
+        let costs = GasCosts::default();
+        assert_eq!(costs.read(10), 1030);
+        assert_eq!(costs.write(10), 2300);
+        assert_eq!(costs.events(3), 300);
+
+        let meter = BasicGasMeter::new(100, costs);
+        assert!(meter.consume_gas(60, "first").is_ok());
+        assert!(meter.consume_gas(40, "second").is_ok());
+        assert_eq!(meter.gas_consumed(), 100);
+
+        let out_of_gas = OutOfGas {
+            descriptor: "third".to_string(),
+            consumed: 101,
+            limit: 100,
+        };
+        assert_eq!(meter.consume_gas(1, "third"), Err(out_of_gas));
+
+        // Once out of gas, a meter stays so.
+        assert!(meter.consume_gas(0, "fourth").is_err());
+
+        // A clone is a snapshot of the consumption.
+        let snapshot = meter.clone();
+        assert_eq!(snapshot.gas_consumed(), 101);
+
+        let meter = BasicGasMeter::infinite(costs);
+        assert!(meter.consume_gas(u64::MAX, "first").is_ok());
+        assert!(meter.consume_gas(u64::MAX, "second").is_ok());
+        assert_eq!(meter.gas_consumed(), u64::MAX);
+    }
+}
@@ -0,0 +1,518 @@
+//! Server of the `ibc.core.channel.v1.Query` service.
+
+use std::sync::{Arc, RwLock};
+
+use ibc_proto::cosmos::base::query::v1beta1::{PageRequest, PageResponse};
+use ibc_proto::ibc::core::channel::v1::query_server::Query;
+use ibc_proto::ibc::core::channel::v1::{
+    Channel as RawChannel, IdentifiedChannel, PacketState, QueryChannelClientStateRequest,
+    QueryChannelClientStateResponse, QueryChannelConsensusStateRequest,
+    QueryChannelConsensusStateResponse, QueryChannelRequest, QueryChannelResponse,
+    QueryChannelsRequest, QueryChannelsResponse, QueryConnectionChannelsRequest,
+    QueryConnectionChannelsResponse, QueryNextSequenceReceiveRequest,
+    QueryNextSequenceReceiveResponse, QueryPacketAcknowledgementRequest,
+    QueryPacketAcknowledgementResponse, QueryPacketAcknowledgementsRequest,
+    QueryPacketAcknowledgementsResponse, QueryPacketCommitmentRequest,
+    QueryPacketCommitmentResponse, QueryPacketCommitmentsRequest, QueryPacketCommitmentsResponse,
+    QueryPacketReceiptRequest, QueryPacketReceiptResponse, QueryUnreceivedAcksRequest,
+    QueryUnreceivedAcksResponse, QueryUnreceivedPacketsRequest, QueryUnreceivedPacketsResponse,
+};
+use ibc_proto::ibc::core::client::v1::IdentifiedClientState;
+use tonic::{Request, Response, Status};
+
+use crate::grpc::{paginate, parse, prove, read, QueryContext};
+use crate::ics02_client::context::ClientReader;
+use crate::ics04_channel::channel::{ChannelEnd, Order};
+use crate::ics04_channel::context::ChannelReader;
+use crate::ics04_channel::packet::Sequence;
+use crate::ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId};
+use crate::ics24_host::Path;
+use crate::Height;
+
+/// Serves the ICS4 queries out of the context of a host chain.
+#[derive(Debug)]
+pub struct ChannelQueryService<C> {
+    ctx: Arc<RwLock<C>>,
+}
+
+impl<C> ChannelQueryService<C> {
+    pub fn new(ctx: Arc<RwLock<C>>) -> Self {
+        Self { ctx }
+    }
+}
+
+/// Parses the port and channel identifiers of a request.
+fn port_channel_id(port_id: &str, channel_id: &str) -> Result<(PortId, ChannelId), Status> {
+    Ok((
+        parse(port_id, "port identifier")?,
+        parse(channel_id, "channel identifier")?,
+    ))
+}
+
+/// Looks up the end of the channel with the given identifiers.
+fn channel_end<C: QueryContext>(
+    ctx: &C,
+    port_channel_id: &(PortId, ChannelId),
+) -> Result<ChannelEnd, Status> {
+    ctx.channel_end(port_channel_id).ok_or_else(|| {
+        Status::not_found(format!(
+            "channel {} on port {} not found",
+            port_channel_id.1, port_channel_id.0
+        ))
+    })
+}
+
+/// Looks up the client which the channel with the given identifiers is built upon.
+fn channel_client_id<C: QueryContext>(
+    ctx: &C,
+    port_channel_id: &(PortId, ChannelId),
+) -> Result<ClientId, Status> {
+    let channel_end = channel_end(ctx, port_channel_id)?;
+    let conn_id = channel_end
+        .connection_hops()
+        .first()
+        .ok_or_else(|| Status::internal("channel without connection hops"))?;
+    let connection_end = ChannelReader::connection_end(ctx, conn_id)
+        .ok_or_else(|| Status::not_found(format!("connection {} not found", conn_id)))?;
+    Ok(connection_end.client_id().clone())
+}
+
+/// Lists a page of the channels of the host chain which satisfy `filter`.
+fn channels<C, F>(
+    ctx: &C,
+    page: Option<PageRequest>,
+    filter: F,
+) -> Result<(Vec<IdentifiedChannel>, PageResponse), Status>
+where
+    C: QueryContext,
+    F: Fn(&ChannelEnd) -> bool,
+{
+    let channels = ctx
+        .paths("channelEnds/ports/")
+        .into_iter()
+        .filter_map(|path| match &path {
+            Path::ChannelEnds(port_id, channel_id) => {
+                let port_channel_id = (port_id.clone(), channel_id.clone());
+                ctx.channel_end(&port_channel_id)
+                    .filter(|channel_end| filter(channel_end))
+                    .map(|channel_end| (path.to_string(), (port_channel_id, channel_end)))
+            }
+            _ => None,
+        })
+        .collect();
+    let (channels, pagination) = paginate(channels, page)?;
+
+    let channels = channels
+        .into_iter()
+        .map(|((port_id, channel_id), channel_end)| {
+            let raw = RawChannel::from(channel_end);
+            IdentifiedChannel {
+                state: raw.state,
+                ordering: raw.ordering,
+                counterparty: raw.counterparty,
+                connection_hops: raw.connection_hops,
+                version: raw.version,
+                port_id: port_id.to_string(),
+                channel_id: channel_id.to_string(),
+            }
+        })
+        .collect();
+
+    Ok((channels, pagination))
+}
+
+/// Lists a page of the packet commitments or acknowledgements, under `prefix`, of a channel.
+fn packet_states<C, F>(
+    ctx: &C,
+    prefix: &str,
+    (port_id, channel_id): (PortId, ChannelId),
+    page: Option<PageRequest>,
+    data: F,
+) -> Result<(Vec<PacketState>, PageResponse), Status>
+where
+    C: QueryContext,
+    F: Fn(&C, &(PortId, ChannelId, Sequence)) -> Option<Vec<u8>>,
+{
+    let sequences = ctx
+        .paths(&format!(
+            "{}/ports/{}/channels/{}/sequences/",
+            prefix, port_id, channel_id
+        ))
+        .into_iter()
+        .filter_map(|path| match path {
+            Path::Commitments { sequence, .. } | Path::Acks { sequence, .. } => {
+                Some((path.to_string(), sequence))
+            }
+            _ => None,
+        })
+        .collect();
+    let (sequences, pagination) = paginate(sequences, page)?;
+
+    let packet_states = sequences
+        .into_iter()
+        .filter_map(|sequence| {
+            data(ctx, &(port_id.clone(), channel_id.clone(), sequence)).map(|data| PacketState {
+                port_id: port_id.to_string(),
+                channel_id: channel_id.to_string(),
+                sequence: sequence.into(),
+                data,
+            })
+        })
+        .collect();
+
+    Ok((packet_states, pagination))
+}
+
+#[tonic::async_trait]
+impl<C> Query for ChannelQueryService<C>
+where
+    C: QueryContext + Send + Sync + 'static,
+{
+    async fn channel(
+        &self,
+        request: Request<QueryChannelRequest>,
+    ) -> Result<Response<QueryChannelResponse>, Status> {
+        let request = request.into_inner();
+        let (port_id, channel_id) = port_channel_id(&request.port_id, &request.channel_id)?;
+
+        let ctx = read(&self.ctx)?;
+        let channel_end = channel_end(&*ctx, &(port_id.clone(), channel_id.clone()))?;
+        let (proof, proof_height) = prove(&*ctx, Path::ChannelEnds(port_id, channel_id));
+
+        Ok(Response::new(QueryChannelResponse {
+            channel: Some(channel_end.into()),
+            proof,
+            proof_height,
+        }))
+    }
+
+    async fn channels(
+        &self,
+        request: Request<QueryChannelsRequest>,
+    ) -> Result<Response<QueryChannelsResponse>, Status> {
+        let request = request.into_inner();
+
+        let ctx = read(&self.ctx)?;
+        let (channels, pagination) = channels(&*ctx, request.pagination, |_| true)?;
+
+        Ok(Response::new(QueryChannelsResponse {
+            channels,
+            pagination: Some(pagination),
+            height: Some(ClientReader::host_height(&*ctx).into()),
+        }))
+    }
+
+    async fn connection_channels(
+        &self,
+        request: Request<QueryConnectionChannelsRequest>,
+    ) -> Result<Response<QueryConnectionChannelsResponse>, Status> {
+        let request = request.into_inner();
+        let conn_id: ConnectionId = parse(&request.connection, "connection identifier")?;
+
+        let ctx = read(&self.ctx)?;
+        let (channels, pagination) = channels(&*ctx, request.pagination, |channel_end| {
+            channel_end.connection_hops().first() == Some(&conn_id)
+        })?;
+
+        Ok(Response::new(QueryConnectionChannelsResponse {
+            channels,
+            pagination: Some(pagination),
+            height: Some(ClientReader::host_height(&*ctx).into()),
+        }))
+    }
+
+    async fn channel_client_state(
+        &self,
+        request: Request<QueryChannelClientStateRequest>,
+    ) -> Result<Response<QueryChannelClientStateResponse>, Status> {
+        let request = request.into_inner();
+        let port_channel_id = port_channel_id(&request.port_id, &request.channel_id)?;
+
+        let ctx = read(&self.ctx)?;
+        let client_id = channel_client_id(&*ctx, &port_channel_id)?;
+        let client_state = ClientReader::client_state(&*ctx, &client_id)
+            .ok_or_else(|| Status::not_found(format!("client {} not found", client_id)))?;
+        let (proof, proof_height) = prove(&*ctx, Path::ClientState(client_id.clone()));
+
+        Ok(Response::new(QueryChannelClientStateResponse {
+            identified_client_state: Some(IdentifiedClientState {
+                client_id: client_id.to_string(),
+                client_state: Some(client_state.into()),
+            }),
+            proof,
+            proof_height,
+        }))
+    }
+
+    async fn channel_consensus_state(
+        &self,
+        request: Request<QueryChannelConsensusStateRequest>,
+    ) -> Result<Response<QueryChannelConsensusStateResponse>, Status> {
+        let request = request.into_inner();
+        let port_channel_id = port_channel_id(&request.port_id, &request.channel_id)?;
+        let height = Height::new(request.revision_number, request.revision_height);
+
+        let ctx = read(&self.ctx)?;
+        let client_id = channel_client_id(&*ctx, &port_channel_id)?;
+        let consensus_state = ctx.consensus_state(&client_id, height).ok_or_else(|| {
+            Status::not_found(format!(
+                "consensus state of client {} at height {} not found",
+                client_id, height
+            ))
+        })?;
+        let (proof, proof_height) = prove(
+            &*ctx,
+            Path::ClientConsensusState {
+                client_id: client_id.clone(),
+                epoch: height.revision_number,
+                height: height.revision_height,
+            },
+        );
+
+        Ok(Response::new(QueryChannelConsensusStateResponse {
+            consensus_state: Some(consensus_state.into()),
+            client_id: client_id.to_string(),
+            proof,
+            proof_height,
+        }))
+    }
+
+    async fn packet_commitment(
+        &self,
+        request: Request<QueryPacketCommitmentRequest>,
+    ) -> Result<Response<QueryPacketCommitmentResponse>, Status> {
+        let request = request.into_inner();
+        let (port_id, channel_id) = port_channel_id(&request.port_id, &request.channel_id)?;
+        let sequence = Sequence::from(request.sequence);
+
+        let ctx = read(&self.ctx)?;
+        let commitment = ctx
+            .get_packet_commitment(&(port_id.clone(), channel_id.clone(), sequence))
+            .ok_or_else(|| {
+                Status::not_found(format!(
+                    "packet commitment {} on channel {} of port {} not found",
+                    sequence, channel_id, port_id
+                ))
+            })?;
+        let (proof, proof_height) = prove(
+            &*ctx,
+            Path::Commitments {
+                port_id,
+                channel_id,
+                sequence,
+            },
+        );
+
+        Ok(Response::new(QueryPacketCommitmentResponse {
+            commitment: commitment.into_vec(),
+            proof,
+            proof_height,
+        }))
+    }
+
+    async fn packet_commitments(
+        &self,
+        request: Request<QueryPacketCommitmentsRequest>,
+    ) -> Result<Response<QueryPacketCommitmentsResponse>, Status> {
+        let request = request.into_inner();
+        let port_channel_id = port_channel_id(&request.port_id, &request.channel_id)?;
+
+        let ctx = read(&self.ctx)?;
+        let (commitments, pagination) = packet_states(
+            &*ctx,
+            "commitments",
+            port_channel_id,
+            request.pagination,
+            |ctx, key| ctx.get_packet_commitment(key).map(|c| c.into_vec()),
+        )?;
+
+        Ok(Response::new(QueryPacketCommitmentsResponse {
+            commitments,
+            pagination: Some(pagination),
+            height: Some(ClientReader::host_height(&*ctx).into()),
+        }))
+    }
+
+    /// Proves the presence of the receipt of the packet if it was received, and its absence
+    /// otherwise.
+    async fn packet_receipt(
+        &self,
+        request: Request<QueryPacketReceiptRequest>,
+    ) -> Result<Response<QueryPacketReceiptResponse>, Status> {
+        let request = request.into_inner();
+        let (port_id, channel_id) = port_channel_id(&request.port_id, &request.channel_id)?;
+        let sequence = Sequence::from(request.sequence);
+
+        let ctx = read(&self.ctx)?;
+        let received = ctx
+            .get_packet_receipt(&(port_id.clone(), channel_id.clone(), sequence))
+            .is_some();
+        let (proof, proof_height) = prove(
+            &*ctx,
+            Path::Receipts {
+                port_id,
+                channel_id,
+                sequence,
+            },
+        );
+
+        Ok(Response::new(QueryPacketReceiptResponse {
+            received,
+            proof,
+            proof_height,
+        }))
+    }
+
+    async fn packet_acknowledgement(
+        &self,
+        request: Request<QueryPacketAcknowledgementRequest>,
+    ) -> Result<Response<QueryPacketAcknowledgementResponse>, Status> {
+        let request = request.into_inner();
+        let (port_id, channel_id) = port_channel_id(&request.port_id, &request.channel_id)?;
+        let sequence = Sequence::from(request.sequence);
+
+        let ctx = read(&self.ctx)?;
+        let acknowledgement = ctx
+            .get_packet_acknowledgement(&(port_id.clone(), channel_id.clone(), sequence))
+            .ok_or_else(|| {
+                Status::not_found(format!(
+                    "packet acknowledgement {} on channel {} of port {} not found",
+                    sequence, channel_id, port_id
+                ))
+            })?;
+        let (proof, proof_height) = prove(
+            &*ctx,
+            Path::Acks {
+                port_id,
+                channel_id,
+                sequence,
+            },
+        );
+
+        Ok(Response::new(QueryPacketAcknowledgementResponse {
+            acknowledgement: acknowledgement.into_vec(),
+            proof,
+            proof_height,
+        }))
+    }
+
+    async fn packet_acknowledgements(
+        &self,
+        request: Request<QueryPacketAcknowledgementsRequest>,
+    ) -> Result<Response<QueryPacketAcknowledgementsResponse>, Status> {
+        let request = request.into_inner();
+        let port_channel_id = port_channel_id(&request.port_id, &request.channel_id)?;
+
+        let ctx = read(&self.ctx)?;
+        let (acknowledgements, pagination) = packet_states(
+            &*ctx,
+            "acks",
+            port_channel_id,
+            request.pagination,
+            |ctx, key| ctx.get_packet_acknowledgement(key).map(|a| a.into_vec()),
+        )?;
+
+        Ok(Response::new(QueryPacketAcknowledgementsResponse {
+            acknowledgements,
+            pagination: Some(pagination),
+            height: Some(ClientReader::host_height(&*ctx).into()),
+        }))
+    }
+
+    /// Returns the sequences, out of those of the request, of the packets which the host chain
+    /// has not received yet: those at or above the next sequence to receive on ordered
+    /// channels, and those without receipt on unordered channels.
+    async fn unreceived_packets(
+        &self,
+        request: Request<QueryUnreceivedPacketsRequest>,
+    ) -> Result<Response<QueryUnreceivedPacketsResponse>, Status> {
+        let request = request.into_inner();
+        let (port_id, channel_id) = port_channel_id(&request.port_id, &request.channel_id)?;
+
+        let ctx = read(&self.ctx)?;
+        let channel_end = channel_end(&*ctx, &(port_id.clone(), channel_id.clone()))?;
+        let sequences = match channel_end.ordering() {
+            Order::Ordered => {
+                let next_sequence_recv = ctx
+                    .get_next_sequence_recv(&(port_id, channel_id))
+                    .map_or(1, u64::from);
+                request
+                    .packet_commitment_sequences
+                    .into_iter()
+                    .filter(|sequence| *sequence >= next_sequence_recv)
+                    .collect()
+            }
+            _ => request
+                .packet_commitment_sequences
+                .into_iter()
+                .filter(|sequence| {
+                    ctx.get_packet_receipt(&(
+                        port_id.clone(),
+                        channel_id.clone(),
+                        Sequence::from(*sequence),
+                    ))
+                    .is_none()
+                })
+                .collect(),
+        };
+
+        Ok(Response::new(QueryUnreceivedPacketsResponse {
+            sequences,
+            height: Some(ClientReader::host_height(&*ctx).into()),
+        }))
+    }
+
+    /// Returns the sequences, out of those of the request, of the packets sent by the host chain
+    /// whose acknowledgement it has not processed yet, i.e., whose commitment it still holds.
+    async fn unreceived_acks(
+        &self,
+        request: Request<QueryUnreceivedAcksRequest>,
+    ) -> Result<Response<QueryUnreceivedAcksResponse>, Status> {
+        let request = request.into_inner();
+        let (port_id, channel_id) = port_channel_id(&request.port_id, &request.channel_id)?;
+
+        let ctx = read(&self.ctx)?;
+        let sequences = request
+            .packet_ack_sequences
+            .into_iter()
+            .filter(|sequence| {
+                ctx.get_packet_commitment(&(
+                    port_id.clone(),
+                    channel_id.clone(),
+                    Sequence::from(*sequence),
+                ))
+                .is_some()
+            })
+            .collect();
+
+        Ok(Response::new(QueryUnreceivedAcksResponse {
+            sequences,
+            height: Some(ClientReader::host_height(&*ctx).into()),
+        }))
+    }
+
+    async fn next_sequence_receive(
+        &self,
+        request: Request<QueryNextSequenceReceiveRequest>,
+    ) -> Result<Response<QueryNextSequenceReceiveResponse>, Status> {
+        let request = request.into_inner();
+        let (port_id, channel_id) = port_channel_id(&request.port_id, &request.channel_id)?;
+
+        let ctx = read(&self.ctx)?;
+        let next_sequence_receive = ctx
+            .get_next_sequence_recv(&(port_id.clone(), channel_id.clone()))
+            .ok_or_else(|| {
+                Status::not_found(format!(
+                    "next sequence to receive on channel {} of port {} not found",
+                    channel_id, port_id
+                ))
+            })?;
+        let (proof, proof_height) = prove(&*ctx, Path::SeqRecvs(port_id, channel_id));
+
+        Ok(Response::new(QueryNextSequenceReceiveResponse {
+            next_sequence_receive: next_sequence_receive.into(),
+            proof,
+            proof_height,
+        }))
+    }
+}
@@ -0,0 +1,184 @@
+//! Server of the `ibc.core.client.v1.Query` service.
+
+use std::sync::{Arc, RwLock};
+
+use ibc_proto::ibc::core::client::v1::query_server::Query;
+use ibc_proto::ibc::core::client::v1::{
+    ConsensusStateWithHeight, IdentifiedClientState, Params, QueryClientParamsRequest,
+    QueryClientParamsResponse, QueryClientStateRequest, QueryClientStateResponse,
+    QueryClientStatesRequest, QueryClientStatesResponse, QueryConsensusStateRequest,
+    QueryConsensusStateResponse, QueryConsensusStatesRequest, QueryConsensusStatesResponse,
+};
+use tonic::{Request, Response, Status};
+
+use crate::grpc::{paginate, parse, prove, read, QueryContext};
+use crate::ics02_client::client_type::ClientType;
+use crate::ics02_client::context::ClientReader;
+use crate::ics24_host::identifier::ClientId;
+use crate::ics24_host::Path;
+use crate::Height;
+
+/// Serves the ICS2 queries out of the context of a host chain.
+#[derive(Debug)]
+pub struct ClientQueryService<C> {
+    ctx: Arc<RwLock<C>>,
+}
+
+impl<C> ClientQueryService<C> {
+    pub fn new(ctx: Arc<RwLock<C>>) -> Self {
+        Self { ctx }
+    }
+}
+
+#[tonic::async_trait]
+impl<C> Query for ClientQueryService<C>
+where
+    C: QueryContext + Send + Sync + 'static,
+{
+    async fn client_state(
+        &self,
+        request: Request<QueryClientStateRequest>,
+    ) -> Result<Response<QueryClientStateResponse>, Status> {
+        let request = request.into_inner();
+        let client_id: ClientId = parse(&request.client_id, "client identifier")?;
+
+        let ctx = read(&self.ctx)?;
+        let client_state = ClientReader::client_state(&*ctx, &client_id)
+            .ok_or_else(|| Status::not_found(format!("client {} not found", client_id)))?;
+        let (proof, proof_height) = prove(&*ctx, Path::ClientState(client_id));
+
+        Ok(Response::new(QueryClientStateResponse {
+            client_state: Some(client_state.into()),
+            proof,
+            proof_height,
+        }))
+    }
+
+    async fn client_states(
+        &self,
+        request: Request<QueryClientStatesRequest>,
+    ) -> Result<Response<QueryClientStatesResponse>, Status> {
+        let request = request.into_inner();
+
+        let ctx = read(&self.ctx)?;
+        let client_ids = ctx
+            .paths("clients/")
+            .into_iter()
+            .filter_map(|path| match path {
+                Path::ClientState(ref client_id) => Some((path.to_string(), client_id.clone())),
+                _ => None,
+            })
+            .collect();
+        let (client_ids, pagination) = paginate(client_ids, request.pagination)?;
+
+        let client_states = client_ids
+            .into_iter()
+            .filter_map(|client_id| {
+                ClientReader::client_state(&*ctx, &client_id).map(|client_state| {
+                    IdentifiedClientState {
+                        client_id: client_id.to_string(),
+                        client_state: Some(client_state.into()),
+                    }
+                })
+            })
+            .collect();
+
+        Ok(Response::new(QueryClientStatesResponse {
+            client_states,
+            pagination: Some(pagination),
+        }))
+    }
+
+    async fn consensus_state(
+        &self,
+        request: Request<QueryConsensusStateRequest>,
+    ) -> Result<Response<QueryConsensusStateResponse>, Status> {
+        let request = request.into_inner();
+        let client_id: ClientId = parse(&request.client_id, "client identifier")?;
+
+        let ctx = read(&self.ctx)?;
+        let height = if request.latest_height {
+            ClientReader::client_state(&*ctx, &client_id)
+                .ok_or_else(|| Status::not_found(format!("client {} not found", client_id)))?
+                .latest_height()
+        } else {
+            Height::new(request.revision_number, request.revision_height)
+        };
+
+        let consensus_state = ctx.consensus_state(&client_id, height).ok_or_else(|| {
+            Status::not_found(format!(
+                "consensus state of client {} at height {} not found",
+                client_id, height
+            ))
+        })?;
+        let (proof, proof_height) = prove(
+            &*ctx,
+            Path::ClientConsensusState {
+                client_id,
+                epoch: height.revision_number,
+                height: height.revision_height,
+            },
+        );
+
+        Ok(Response::new(QueryConsensusStateResponse {
+            consensus_state: Some(consensus_state.into()),
+            proof,
+            proof_height,
+        }))
+    }
+
+    async fn consensus_states(
+        &self,
+        request: Request<QueryConsensusStatesRequest>,
+    ) -> Result<Response<QueryConsensusStatesResponse>, Status> {
+        let request = request.into_inner();
+        let client_id: ClientId = parse(&request.client_id, "client identifier")?;
+
+        let ctx = read(&self.ctx)?;
+        let heights = ctx
+            .paths(&format!("clients/{}/consensusStates/", client_id))
+            .into_iter()
+            .filter_map(|path| match path {
+                Path::ClientConsensusState { epoch, height, .. } => {
+                    Some((path.to_string(), Height::new(epoch, height)))
+                }
+                _ => None,
+            })
+            .collect();
+        let (heights, pagination) = paginate(heights, request.pagination)?;
+
+        let consensus_states = heights
+            .into_iter()
+            .filter_map(|height| {
+                ctx.consensus_state(&client_id, height)
+                    .map(|consensus_state| ConsensusStateWithHeight {
+                        height: Some(height.into()),
+                        consensus_state: Some(consensus_state.into()),
+                    })
+            })
+            .collect();
+
+        Ok(Response::new(QueryConsensusStatesResponse {
+            consensus_states,
+            pagination: Some(pagination),
+        }))
+    }
+
+    async fn client_params(
+        &self,
+        _request: Request<QueryClientParamsRequest>,
+    ) -> Result<Response<QueryClientParamsResponse>, Status> {
+        let allowed_clients = [
+            ClientType::Tendermint,
+            ClientType::SoloMachine,
+            ClientType::Localhost,
+        ]
+        .iter()
+        .map(|client_type| client_type.as_string().to_string())
+        .collect();
+
+        Ok(Response::new(QueryClientParamsResponse {
+            params: Some(Params { allowed_clients }),
+        }))
+    }
+}
@@ -0,0 +1,192 @@
+//! Server of the `ibc.core.connection.v1.Query` service.
+
+use std::sync::{Arc, RwLock};
+
+use ibc_proto::ibc::core::client::v1::IdentifiedClientState;
+use ibc_proto::ibc::core::connection::v1::query_server::Query;
+use ibc_proto::ibc::core::connection::v1::{
+    ConnectionEnd as RawConnectionEnd, IdentifiedConnection, QueryClientConnectionsRequest,
+    QueryClientConnectionsResponse, QueryConnectionClientStateRequest,
+    QueryConnectionClientStateResponse, QueryConnectionConsensusStateRequest,
+    QueryConnectionConsensusStateResponse, QueryConnectionRequest, QueryConnectionResponse,
+    QueryConnectionsRequest, QueryConnectionsResponse,
+};
+use tonic::{Request, Response, Status};
+
+use crate::grpc::{paginate, parse, prove, read, QueryContext};
+use crate::ics02_client::context::ClientReader;
+use crate::ics03_connection::connection::ConnectionEnd;
+use crate::ics03_connection::context::ConnectionReader;
+use crate::ics24_host::identifier::{ClientId, ConnectionId};
+use crate::ics24_host::Path;
+use crate::Height;
+
+/// Serves the ICS3 queries out of the context of a host chain.
+#[derive(Debug)]
+pub struct ConnectionQueryService<C> {
+    ctx: Arc<RwLock<C>>,
+}
+
+impl<C> ConnectionQueryService<C> {
+    pub fn new(ctx: Arc<RwLock<C>>) -> Self {
+        Self { ctx }
+    }
+}
+
+/// Looks up the end of the connection with the given identifier.
+fn connection_end<C: QueryContext>(
+    ctx: &C,
+    conn_id: &ConnectionId,
+) -> Result<ConnectionEnd, Status> {
+    ConnectionReader::connection_end(ctx, conn_id)
+        .ok_or_else(|| Status::not_found(format!("connection {} not found", conn_id)))
+}
+
+#[tonic::async_trait]
+impl<C> Query for ConnectionQueryService<C>
+where
+    C: QueryContext + Send + Sync + 'static,
+{
+    async fn connection(
+        &self,
+        request: Request<QueryConnectionRequest>,
+    ) -> Result<Response<QueryConnectionResponse>, Status> {
+        let request = request.into_inner();
+        let conn_id: ConnectionId = parse(&request.connection_id, "connection identifier")?;
+
+        let ctx = read(&self.ctx)?;
+        let connection_end = connection_end(&*ctx, &conn_id)?;
+        let (proof, proof_height) = prove(&*ctx, Path::Connections(conn_id));
+
+        Ok(Response::new(QueryConnectionResponse {
+            connection: Some(connection_end.into()),
+            proof,
+            proof_height,
+        }))
+    }
+
+    async fn connections(
+        &self,
+        request: Request<QueryConnectionsRequest>,
+    ) -> Result<Response<QueryConnectionsResponse>, Status> {
+        let request = request.into_inner();
+
+        let ctx = read(&self.ctx)?;
+        let conn_ids = ctx
+            .paths("connections/")
+            .into_iter()
+            .filter_map(|path| match path {
+                Path::Connections(ref conn_id) => Some((path.to_string(), conn_id.clone())),
+                _ => None,
+            })
+            .collect();
+        let (conn_ids, pagination) = paginate(conn_ids, request.pagination)?;
+
+        let connections = conn_ids
+            .into_iter()
+            .filter_map(|conn_id| {
+                ConnectionReader::connection_end(&*ctx, &conn_id).map(|connection_end| {
+                    let raw = RawConnectionEnd::from(connection_end);
+                    IdentifiedConnection {
+                        id: conn_id.to_string(),
+                        client_id: raw.client_id,
+                        versions: raw.versions,
+                        state: raw.state,
+                        counterparty: raw.counterparty,
+                        delay_period: raw.delay_period,
+                    }
+                })
+            })
+            .collect();
+
+        Ok(Response::new(QueryConnectionsResponse {
+            connections,
+            pagination: Some(pagination),
+            height: Some(ClientReader::host_height(&*ctx).into()),
+        }))
+    }
+
+    async fn client_connections(
+        &self,
+        request: Request<QueryClientConnectionsRequest>,
+    ) -> Result<Response<QueryClientConnectionsResponse>, Status> {
+        let request = request.into_inner();
+        let client_id: ClientId = parse(&request.client_id, "client identifier")?;
+
+        let ctx = read(&self.ctx)?;
+        let connection_paths: Vec<String> = ctx
+            .client_connections(&client_id)
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        if connection_paths.is_empty() {
+            return Err(Status::not_found(format!(
+                "no connection found for client {}",
+                client_id
+            )));
+        }
+        let (proof, proof_height) = prove(&*ctx, Path::ClientConnections(client_id));
+
+        Ok(Response::new(QueryClientConnectionsResponse {
+            connection_paths,
+            proof,
+            proof_height,
+        }))
+    }
+
+    async fn connection_client_state(
+        &self,
+        request: Request<QueryConnectionClientStateRequest>,
+    ) -> Result<Response<QueryConnectionClientStateResponse>, Status> {
+        let request = request.into_inner();
+        let conn_id: ConnectionId = parse(&request.connection_id, "connection identifier")?;
+
+        let ctx = read(&self.ctx)?;
+        let client_id = connection_end(&*ctx, &conn_id)?.client_id().clone();
+        let client_state = ClientReader::client_state(&*ctx, &client_id)
+            .ok_or_else(|| Status::not_found(format!("client {} not found", client_id)))?;
+        let (proof, proof_height) = prove(&*ctx, Path::ClientState(client_id.clone()));
+
+        Ok(Response::new(QueryConnectionClientStateResponse {
+            identified_client_state: Some(IdentifiedClientState {
+                client_id: client_id.to_string(),
+                client_state: Some(client_state.into()),
+            }),
+            proof,
+            proof_height,
+        }))
+    }
+
+    async fn connection_consensus_state(
+        &self,
+        request: Request<QueryConnectionConsensusStateRequest>,
+    ) -> Result<Response<QueryConnectionConsensusStateResponse>, Status> {
+        let request = request.into_inner();
+        let conn_id: ConnectionId = parse(&request.connection_id, "connection identifier")?;
+        let height = Height::new(request.revision_number, request.revision_height);
+
+        let ctx = read(&self.ctx)?;
+        let client_id = connection_end(&*ctx, &conn_id)?.client_id().clone();
+        let consensus_state = ctx.consensus_state(&client_id, height).ok_or_else(|| {
+            Status::not_found(format!(
+                "consensus state of client {} at height {} not found",
+                client_id, height
+            ))
+        })?;
+        let (proof, proof_height) = prove(
+            &*ctx,
+            Path::ClientConsensusState {
+                client_id: client_id.clone(),
+                epoch: height.revision_number,
+                height: height.revision_height,
+            },
+        );
+
+        Ok(Response::new(QueryConnectionConsensusStateResponse {
+            consensus_state: Some(consensus_state.into()),
+            client_id: client_id.to_string(),
+            proof,
+            proof_height,
+        }))
+    }
+}
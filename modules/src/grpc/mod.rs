@@ -0,0 +1,222 @@
+//! Servers of the gRPC `Query` services of the ICS2 (`ibc.core.client.v1`), ICS3
+//! (`ibc.core.connection.v1`) and ICS4 (`ibc.core.channel.v1`) modules, over any host chain
+//! context. These are the queries relayers rely on, so that a host written in Rust can be relayed
+//! to as a Cosmos SDK chain is.
+//!
+//! The servers share the context with the rest of the host, behind a lock:
+//!
+//! ```ignore
+//! let ctx = Arc::new(RwLock::new(ctx));
+//! Server::builder()
+//!     .add_service(ClientQueryServer::new(ClientQueryService::new(ctx.clone())))
+//!     .add_service(ConnectionQueryServer::new(ConnectionQueryService::new(ctx.clone())))
+//!     .add_service(ChannelQueryServer::new(ChannelQueryService::new(ctx)))
+//!     .serve(addr)
+//!     .await?;
+//! ```
+
+// `tonic::Status` is the error of all the services, however large.
+#![allow(clippy::result_large_err)]
+
+use std::fmt::Display;
+use std::str::FromStr;
+use std::sync::{RwLock, RwLockReadGuard};
+
+use ibc_proto::cosmos::base::query::v1beta1::{PageRequest, PageResponse};
+use ibc_proto::ibc::core::client::v1::Height as RawHeight;
+use ibc_proto::ibc::core::connection::v1::ClientPaths;
+use prost::Message;
+use tonic::Status;
+
+use crate::ics02_client::context::ClientReader;
+use crate::ics23_commitment::commitment::CommitmentProofBytes;
+use crate::ics24_host::identifier::{ClientId, ConnectionId};
+use crate::ics24_host::store::{Store, StoreHost};
+use crate::ics24_host::Path;
+use crate::ics26_routing::context::ICS26Context;
+use crate::Height;
+
+pub mod channel;
+pub mod client;
+pub mod connection;
+
+/// The number of items in a page when the request does not set a limit, as in the Cosmos SDK.
+pub const DEFAULT_PAGE_LIMIT: u64 = 100;
+
+/// What the query servers need from a host chain on top of `ICS26Context`: listing what its state
+/// holds, and proving it.
+pub trait QueryContext: ICS26Context {
+    /// Returns the paths that hold a value in the current state of the host chain and whose string
+    /// representation starts with `prefix`, in ascending order of these strings.
+    fn paths(&self, prefix: &str) -> Vec<Path>;
+
+    /// Returns the identifiers of the connections of the given client.
+    fn client_connections(&self, client_id: &ClientId) -> Vec<ConnectionId>;
+
+    /// Proves the presence or the absence of a value at `path` in the state of the host chain
+    /// committed at `height`. Hosts which cannot prove their state return `None`, in which case
+    /// the responses carry empty proofs.
+    fn proof(&self, _height: Height, _path: &Path) -> Option<CommitmentProofBytes> {
+        None
+    }
+}
+
+/// Hosts which keep their state in a provable `Store` list it out of the keys of the store, and
+/// prove it with the store.
+impl<H: StoreHost + ICS26Context> QueryContext for H {
+    fn paths(&self, prefix: &str) -> Vec<Path> {
+        self.store()
+            .get_keys(prefix)
+            .iter()
+            .filter_map(|key| Path::from_str(key).ok())
+            .collect()
+    }
+
+    fn client_connections(&self, client_id: &ClientId) -> Vec<ConnectionId> {
+        self.store()
+            .get(&Path::ClientConnections(client_id.clone()))
+            .and_then(|bytes| ClientPaths::decode(bytes.as_slice()).ok())
+            .map_or_else(Vec::new, |connections| {
+                connections
+                    .paths
+                    .iter()
+                    .filter_map(|id| ConnectionId::from_str(id).ok())
+                    .collect()
+            })
+    }
+
+    fn proof(&self, height: Height, path: &Path) -> Option<CommitmentProofBytes> {
+        self.store().get_proof(height, path)
+    }
+}
+
+/// Locks the context of the host for reading.
+fn read<C>(ctx: &RwLock<C>) -> Result<RwLockReadGuard<'_, C>, Status> {
+    ctx.read()
+        .map_err(|_| Status::internal("the lock on the host context is poisoned"))
+}
+
+/// Parses an identifier (or any other field) of a request.
+fn parse<T>(value: &str, what: &str) -> Result<T, Status>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    value
+        .parse()
+        .map_err(|e| Status::invalid_argument(format!("invalid {} {}: {}", what, value, e)))
+}
+
+/// Proves the value at `path` at the current height of the host chain, and returns the proof
+/// along with that height, as in the responses of the queries.
+fn prove<C: QueryContext>(ctx: &C, path: Path) -> (Vec<u8>, Option<RawHeight>) {
+    let height = ClientReader::host_height(ctx);
+    let proof = ctx.proof(height, &path).map(Into::into).unwrap_or_default();
+    (proof, Some(height.into()))
+}
+
+/// Selects a page out of `items`, which are sorted by their keys, as the Cosmos SDK paginates the
+/// results of queries: a page starts either at the item with the key of the request, as returned
+/// in the previous page, or at the offset of the request. The total number of items is only
+/// counted for pages selected by their offset.
+fn paginate<T>(
+    items: Vec<(String, T)>,
+    page: Option<PageRequest>,
+) -> Result<(Vec<T>, PageResponse), Status> {
+    let page = page.unwrap_or_default();
+    if !page.key.is_empty() && page.offset > 0 {
+        return Err(Status::invalid_argument(
+            "either the offset or the key of the page is expected, got both",
+        ));
+    }
+
+    let limit = if page.limit == 0 {
+        DEFAULT_PAGE_LIMIT
+    } else {
+        page.limit
+    } as usize;
+
+    let total = items.len() as u64;
+    let start = if page.key.is_empty() {
+        page.offset as usize
+    } else {
+        let key = String::from_utf8_lossy(&page.key);
+        items
+            .iter()
+            .position(|(item_key, _)| item_key.as_str() >= key.as_ref())
+            .unwrap_or(items.len())
+    };
+
+    let mut rest = items.into_iter().skip(start);
+    let selected = rest.by_ref().take(limit).map(|(_, item)| item).collect();
+    let next_key = rest
+        .next()
+        .map_or_else(Vec::new, |(key, _)| key.into_bytes());
+
+    Ok((
+        selected,
+        PageResponse {
+            next_key,
+            total: if page.count_total && page.key.is_empty() {
+                total
+            } else {
+                0
+            },
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc_proto::cosmos::base::query::v1beta1::PageRequest;
+
+    use crate::grpc::paginate;
+
+    #[test]
+    fn pagination() {
+        let items = || -> Vec<(String, u64)> { (0..5).map(|i| (format!("key{}", i), i)).collect() };
+
+        // Without a request, a single page holds everything.
+        let (page, response) = paginate(items(), None).unwrap();
+        assert_eq!(page, vec![0, 1, 2, 3, 4]);
+        assert!(response.next_key.is_empty());
+
+        // Pages selected by offset count the items if requested.
+        let (page, response) = paginate(
+            items(),
+            Some(PageRequest {
+                offset: 1,
+                limit: 2,
+                count_total: true,
+                ..Default::default()
+            }),
+        )
+        .unwrap();
+        assert_eq!(page, vec![1, 2]);
+        assert_eq!(response.next_key, b"key3".to_vec());
+        assert_eq!(response.total, 5);
+
+        // The next page starts at the key returned with the previous one.
+        let (page, response) = paginate(
+            items(),
+            Some(PageRequest {
+                key: response.next_key,
+                limit: 2,
+                ..Default::default()
+            }),
+        )
+        .unwrap();
+        assert_eq!(page, vec![3, 4]);
+        assert!(response.next_key.is_empty());
+
+        assert!(paginate(
+            items(),
+            Some(PageRequest {
+                key: b"key1".to_vec(),
+                offset: 1,
+                ..Default::default()
+            }),
+        )
+        .is_err());
+    }
+}
@@ -5,18 +5,36 @@ use serde::Serialize;
 use tendermint_proto::Protobuf;
 
 use crate::downcast;
+use crate::gas::{GasMeter, OutOfGas};
 use crate::ics02_client::client_type::ClientType;
+use crate::ics02_client::context::ClientReader;
 use crate::ics02_client::error::{Error, Kind};
 use crate::ics02_client::header::Header;
+use crate::ics02_client::misbehaviour::Misbehaviour;
 use crate::ics02_client::state::{ClientState, ConsensusState};
 use crate::ics03_connection::connection::ConnectionEnd;
+use crate::ics03_connection::context::ConnectionReader;
 use crate::ics04_channel::channel::ChannelEnd;
+use crate::ics04_channel::commitment::PacketCommitment;
+use crate::ics04_channel::context::ChannelReader;
+use crate::ics04_channel::packet::Sequence;
+use crate::ics06_solomachine::client_def::SoloMachineClient;
+use crate::ics06_solomachine::client_state::ClientState as SoloMachineClientState;
+use crate::ics06_solomachine::consensus_state::ConsensusState as SoloMachineConsensusState;
+use crate::ics06_solomachine::header::Header as SoloMachineHeader;
+use crate::ics06_solomachine::misbehaviour::Misbehaviour as SoloMachineMisbehaviour;
 use crate::ics07_tendermint::client_def::TendermintClient;
 use crate::ics07_tendermint::client_state::ClientState as TendermintClientState;
 use crate::ics07_tendermint::consensus_state::ConsensusState as TendermintConsensusState;
 use crate::ics07_tendermint::header::Header as TendermintHeader;
+use crate::ics07_tendermint::misbehaviour::Misbehaviour as TendermintMisbehaviour;
+use crate::ics09_localhost::client_def::LocalhostClient;
+use crate::ics09_localhost::client_state::ClientState as LocalhostClientState;
+use crate::ics09_localhost::consensus_state::ConsensusState as LocalhostConsensusState;
+use crate::ics09_localhost::header::Header as LocalhostHeader;
 use crate::ics23_commitment::commitment::{CommitmentPrefix, CommitmentProofBytes, CommitmentRoot};
 use crate::ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId};
+use crate::timestamp::Timestamp;
 use crate::Height;
 
 #[cfg(any(test, feature = "mocks"))]
@@ -24,32 +42,95 @@ use crate::mock::{
     client_def::MockClient,
     client_state::{MockClientState, MockConsensusState},
     header::MockHeader,
+    misbehaviour::MockMisbehaviour,
 };
 
 pub const TENDERMINT_CLIENT_STATE_TYPE_URL: &str = "/ibc.lightclients.tendermint.v1.ClientState";
 pub const TENDERMINT_CONSENSUS_STATE_TYPE_URL: &str =
     "/ibc.lightclients.tendermint.v1.ConsensusState";
 pub const TENDERMINT_HEADER_TYPE_URL: &str = "/ibc.lightclients.tendermint.v1.Header";
+pub const TENDERMINT_MISBEHAVIOUR_TYPE_URL: &str = "/ibc.lightclients.tendermint.v1.Misbehaviour";
+
+pub const SOLOMACHINE_CLIENT_STATE_TYPE_URL: &str = "/ibc.lightclients.solomachine.v1.ClientState";
+pub const SOLOMACHINE_CONSENSUS_STATE_TYPE_URL: &str =
+    "/ibc.lightclients.solomachine.v1.ConsensusState";
+pub const SOLOMACHINE_HEADER_TYPE_URL: &str = "/ibc.lightclients.solomachine.v1.Header";
+pub const SOLOMACHINE_MISBEHAVIOUR_TYPE_URL: &str = "/ibc.lightclients.solomachine.v1.Misbehaviour";
+
+pub const LOCALHOST_CLIENT_STATE_TYPE_URL: &str = "/ibc.lightclients.localhost.v1.ClientState";
+pub const LOCALHOST_CONSENSUS_STATE_TYPE_URL: &str =
+    "/ibc.lightclients.localhost.v1.ConsensusState";
+pub const LOCALHOST_HEADER_TYPE_URL: &str = "/ibc.lightclients.localhost.v1.Header";
 
 pub const MOCK_CLIENT_STATE_TYPE_URL: &str = "/ibc.mock.ClientState";
 pub const MOCK_CONSENSUS_STATE_TYPE_URL: &str = "/ibc.mock.ConsensusState";
 pub const MOCK_HEADER_TYPE_URL: &str = "/ibc.mock.Header";
+pub const MOCK_MISBEHAVIOUR_TYPE_URL: &str = "/ibc.mock.Misbehaviour";
 
 pub trait ClientDef: Clone {
     type Header: Header;
+    type Misbehaviour: Misbehaviour;
     type ClientState: ClientState;
     type ConsensusState: ConsensusState;
 
-    /// TODO
+    /// Validates an incoming `header` against a consensus state that the client `client_id` on
+    /// the host chain (see `ctx`) already trusts, and returns the updated client state together
+    /// with the consensus state derived from the header.
     fn check_header_and_update_state(
         &self,
+        ctx: &dyn ClientReader,
+        client_id: ClientId,
         client_state: Self::ClientState,
         header: Self::Header,
     ) -> Result<(Self::ClientState, Self::ConsensusState), Box<dyn std::error::Error>>;
 
+    /// Checks that `misbehaviour` is valid evidence of the counterparty chain having misbehaved,
+    /// and returns the client state frozen at the height of the misbehaviour.
+    fn check_misbehaviour_and_update_state(
+        &self,
+        ctx: &dyn ClientReader,
+        client_id: ClientId,
+        client_state: Self::ClientState,
+        misbehaviour: Self::Misbehaviour,
+    ) -> Result<Self::ClientState, Box<dyn std::error::Error>>;
+
+    /// Verifies the proofs that the chain tracked by the client, before halting for a planned
+    /// upgrade, committed to the `upgraded_client_state` and `upgraded_consensus_state` of the
+    /// upgraded chain. Returns the client state and consensus state with which the client keeps
+    /// tracking the chain after the upgrade.
+    #[allow(clippy::too_many_arguments)]
+    fn verify_upgrade_and_update_state(
+        &self,
+        ctx: &dyn ClientReader,
+        client_id: ClientId,
+        client_state: Self::ClientState,
+        upgraded_client_state: Self::ClientState,
+        upgraded_consensus_state: Self::ConsensusState,
+        proof_upgrade_client: &CommitmentProofBytes,
+        proof_upgrade_consensus_state: &CommitmentProofBytes,
+    ) -> Result<(Self::ClientState, Self::ConsensusState), Box<dyn std::error::Error>>;
+
+    /// Returns the consensus state which the client keeps within its client state, if any. The
+    /// proofs at any height are then verified against this consensus state, rather than against
+    /// the consensus state which the host chain stores for the client at the height of the proof.
+    fn embedded_consensus_state(
+        &self,
+        _client_state: &Self::ClientState,
+    ) -> Option<Self::ConsensusState> {
+        None
+    }
+
     /// Verification functions as specified in:
     /// https://github.com/cosmos/ics/tree/master/spec/ics-002-client-semantics
     ///
+    /// The `ctx` gives read access to the state of the host chain, for clients whose counterparty
+    /// is the host chain itself (see the localhost client).
+    ///
+    /// Each verification function returns the new state of the client if verifying the proof
+    /// updates it, which the host chain must store before verifying any other proof. The solo
+    /// machine client, e.g., advances its sequence with every verified signature, so that no
+    /// signature verifies twice.
+    ///
     /// Verify a `proof` that the consensus state of a given client (at height `consensus_height`)
     /// matches the input `consensus_state`. The parameter `counterparty_height` represent the
     /// height of the counterparty chain that this proof assumes (i.e., the height at which this
@@ -57,43 +138,51 @@ pub trait ClientDef: Clone {
     #[allow(clippy::too_many_arguments)]
     fn verify_client_consensus_state(
         &self,
+        ctx: &dyn ConnectionReader,
         client_state: &Self::ClientState,
         height: Height,
         prefix: &CommitmentPrefix,
         proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
         client_id: &ClientId,
         consensus_height: Height,
         expected_consensus_state: &AnyConsensusState,
-    ) -> Result<(), Box<dyn std::error::Error>>;
+    ) -> Result<Option<Self::ClientState>, Box<dyn std::error::Error>>;
 
     /// Verify a `proof` that a connection state matches that of the input `connection_end`.
+    #[allow(clippy::too_many_arguments)]
     fn verify_connection_state(
         &self,
+        ctx: &dyn ConnectionReader,
         client_state: &Self::ClientState,
         height: Height,
         prefix: &CommitmentPrefix,
         proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
         connection_id: &ConnectionId,
         expected_connection_end: &ConnectionEnd,
-    ) -> Result<(), Box<dyn std::error::Error>>;
+    ) -> Result<Option<Self::ClientState>, Box<dyn std::error::Error>>;
 
     /// Verify a `proof` that a channel state matches that of the input `channel_end`.
     #[allow(clippy::too_many_arguments)]
     fn verify_channel_state(
         &self,
+        ctx: &dyn ChannelReader,
         client_state: &Self::ClientState,
         height: Height,
         prefix: &CommitmentPrefix,
         proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
         port_id: &PortId,
         channel_id: &ChannelId,
         expected_channel_end: &ChannelEnd,
-    ) -> Result<(), Box<dyn std::error::Error>>;
+    ) -> Result<Option<Self::ClientState>, Box<dyn std::error::Error>>;
 
     /// Verify the client state for this chain that it is stored on the counterparty chain.
     #[allow(clippy::too_many_arguments)]
     fn verify_client_full_state(
         &self,
+        ctx: &dyn ConnectionReader,
         _client_state: &Self::ClientState,
         height: Height,
         root: &CommitmentRoot,
@@ -101,13 +190,77 @@ pub trait ClientDef: Clone {
         client_id: &ClientId,
         proof: &CommitmentProofBytes,
         client_state: &AnyClientState,
-    ) -> Result<(), Box<dyn std::error::Error>>;
+    ) -> Result<Option<Self::ClientState>, Box<dyn std::error::Error>>;
+
+    /// Verify a `proof` that a packet has been commited.
+    #[allow(clippy::too_many_arguments)]
+    fn verify_packet_data(
+        &self,
+        ctx: &dyn ChannelReader,
+        client_state: &Self::ClientState,
+        height: Height,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        seq: &Sequence,
+        commitment: PacketCommitment,
+    ) -> Result<Option<Self::ClientState>, Box<dyn std::error::Error>>;
+
+    /// Verify a `proof` that a packet has been acknowledged.
+    #[allow(clippy::too_many_arguments)]
+    fn verify_packet_acknowledgement(
+        &self,
+        ctx: &dyn ChannelReader,
+        client_state: &Self::ClientState,
+        height: Height,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        seq: &Sequence,
+        ack: Vec<u8>,
+    ) -> Result<Option<Self::ClientState>, Box<dyn std::error::Error>>;
+
+    /// Verify a `proof` of the next sequence number to be received on the counterparty channel.
+    #[allow(clippy::too_many_arguments)]
+    fn verify_next_sequence_recv(
+        &self,
+        ctx: &dyn ChannelReader,
+        client_state: &Self::ClientState,
+        height: Height,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        seq: &Sequence,
+    ) -> Result<Option<Self::ClientState>, Box<dyn std::error::Error>>;
+
+    /// Verify a `proof` that a packet has not been received.
+    #[allow(clippy::too_many_arguments)]
+    fn verify_packet_receipt_absence(
+        &self,
+        ctx: &dyn ChannelReader,
+        client_state: &Self::ClientState,
+        height: Height,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        seq: &Sequence,
+    ) -> Result<Option<Self::ClientState>, Box<dyn std::error::Error>>;
 }
 
 #[derive(Clone, Debug, PartialEq)] // TODO: Add Eq bound once possible
 #[allow(clippy::large_enum_variant)]
 pub enum AnyHeader {
     Tendermint(TendermintHeader),
+    SoloMachine(SoloMachineHeader),
+    Localhost(LocalhostHeader),
 
     #[cfg(any(test, feature = "mocks"))]
     Mock(MockHeader),
@@ -117,6 +270,8 @@ impl Header for AnyHeader {
     fn client_type(&self) -> ClientType {
         match self {
             Self::Tendermint(header) => header.client_type(),
+            Self::SoloMachine(header) => header.client_type(),
+            Self::Localhost(header) => header.client_type(),
 
             #[cfg(any(test, feature = "mocks"))]
             Self::Mock(header) => header.client_type(),
@@ -126,6 +281,8 @@ impl Header for AnyHeader {
     fn height(&self) -> Height {
         match self {
             Self::Tendermint(header) => header.height(),
+            Self::SoloMachine(header) => header.height(),
+            Self::Localhost(header) => header.height(),
 
             #[cfg(any(test, feature = "mocks"))]
             Self::Mock(header) => header.height(),
@@ -148,6 +305,14 @@ impl TryFrom<Any> for AnyHeader {
                 TendermintHeader::decode_vec(&raw.value)
                     .map_err(|e| Kind::InvalidRawHeader.context(e))?,
             )),
+            SOLOMACHINE_HEADER_TYPE_URL => Ok(AnyHeader::SoloMachine(
+                SoloMachineHeader::decode_vec(&raw.value)
+                    .map_err(|e| Kind::InvalidRawHeader.context(e))?,
+            )),
+            LOCALHOST_HEADER_TYPE_URL => Ok(AnyHeader::Localhost(
+                LocalhostHeader::decode_vec(&raw.value)
+                    .map_err(|e| Kind::InvalidRawHeader.context(e))?,
+            )),
 
             #[cfg(any(test, feature = "mocks"))]
             MOCK_HEADER_TYPE_URL => Ok(AnyHeader::Mock(
@@ -167,6 +332,14 @@ impl From<AnyHeader> for Any {
                 type_url: TENDERMINT_HEADER_TYPE_URL.to_string(),
                 value: header.encode_vec().unwrap(),
             },
+            AnyHeader::SoloMachine(header) => Any {
+                type_url: SOLOMACHINE_HEADER_TYPE_URL.to_string(),
+                value: header.encode_vec().unwrap(),
+            },
+            AnyHeader::Localhost(header) => Any {
+                type_url: LOCALHOST_HEADER_TYPE_URL.to_string(),
+                value: header.encode_vec().unwrap(),
+            },
             #[cfg(any(test, feature = "mocks"))]
             AnyHeader::Mock(header) => Any {
                 type_url: MOCK_HEADER_TYPE_URL.to_string(),
@@ -176,10 +349,95 @@ impl From<AnyHeader> for Any {
     }
 }
 
+#[derive(Clone, Debug, PartialEq)] // TODO: Add Eq bound once possible
+#[allow(clippy::large_enum_variant)]
+pub enum AnyMisbehaviour {
+    Tendermint(TendermintMisbehaviour),
+    SoloMachine(SoloMachineMisbehaviour),
+
+    #[cfg(any(test, feature = "mocks"))]
+    Mock(MockMisbehaviour),
+}
+
+impl Misbehaviour for AnyMisbehaviour {
+    fn client_id(&self) -> &ClientId {
+        match self {
+            Self::Tendermint(misbehaviour) => misbehaviour.client_id(),
+            Self::SoloMachine(misbehaviour) => misbehaviour.client_id(),
+
+            #[cfg(any(test, feature = "mocks"))]
+            Self::Mock(misbehaviour) => misbehaviour.client_id(),
+        }
+    }
+
+    fn height(&self) -> Height {
+        match self {
+            Self::Tendermint(misbehaviour) => misbehaviour.height(),
+            Self::SoloMachine(misbehaviour) => misbehaviour.height(),
+
+            #[cfg(any(test, feature = "mocks"))]
+            Self::Mock(misbehaviour) => misbehaviour.height(),
+        }
+    }
+
+    fn wrap_any(self) -> AnyMisbehaviour {
+        self
+    }
+}
+
+impl Protobuf<Any> for AnyMisbehaviour {}
+
+impl TryFrom<Any> for AnyMisbehaviour {
+    type Error = Error;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        match raw.type_url.as_str() {
+            TENDERMINT_MISBEHAVIOUR_TYPE_URL => Ok(AnyMisbehaviour::Tendermint(
+                TendermintMisbehaviour::decode_vec(&raw.value)
+                    .map_err(|e| Kind::InvalidRawMisbehaviour.context(e))?,
+            )),
+            SOLOMACHINE_MISBEHAVIOUR_TYPE_URL => Ok(AnyMisbehaviour::SoloMachine(
+                SoloMachineMisbehaviour::decode_vec(&raw.value)
+                    .map_err(|e| Kind::InvalidRawMisbehaviour.context(e))?,
+            )),
+
+            #[cfg(any(test, feature = "mocks"))]
+            MOCK_MISBEHAVIOUR_TYPE_URL => Ok(AnyMisbehaviour::Mock(
+                MockMisbehaviour::decode_vec(&raw.value)
+                    .map_err(|e| Kind::InvalidRawMisbehaviour.context(e))?,
+            )),
+
+            _ => Err(Kind::UnknownMisbehaviourType(raw.type_url).into()),
+        }
+    }
+}
+
+impl From<AnyMisbehaviour> for Any {
+    fn from(value: AnyMisbehaviour) -> Self {
+        match value {
+            AnyMisbehaviour::Tendermint(misbehaviour) => Any {
+                type_url: TENDERMINT_MISBEHAVIOUR_TYPE_URL.to_string(),
+                value: misbehaviour.encode_vec().unwrap(),
+            },
+            AnyMisbehaviour::SoloMachine(misbehaviour) => Any {
+                type_url: SOLOMACHINE_MISBEHAVIOUR_TYPE_URL.to_string(),
+                value: misbehaviour.encode_vec().unwrap(),
+            },
+            #[cfg(any(test, feature = "mocks"))]
+            AnyMisbehaviour::Mock(misbehaviour) => Any {
+                type_url: MOCK_MISBEHAVIOUR_TYPE_URL.to_string(),
+                value: misbehaviour.encode_vec().unwrap(),
+            },
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Serialize)]
 #[serde(tag = "type")]
 pub enum AnyClientState {
     Tendermint(TendermintClientState),
+    SoloMachine(SoloMachineClientState),
+    Localhost(LocalhostClientState),
 
     #[cfg(any(test, feature = "mocks"))]
     Mock(MockClientState),
@@ -189,6 +447,8 @@ impl AnyClientState {
     pub fn latest_height(&self) -> Height {
         match self {
             Self::Tendermint(tm_state) => tm_state.latest_height(),
+            Self::SoloMachine(sm_state) => sm_state.latest_height(),
+            Self::Localhost(lh_state) => lh_state.latest_height(),
 
             #[cfg(any(test, feature = "mocks"))]
             Self::Mock(mock_state) => mock_state.latest_height(),
@@ -197,6 +457,8 @@ impl AnyClientState {
     pub fn client_type(&self) -> ClientType {
         match self {
             Self::Tendermint(state) => state.client_type(),
+            Self::SoloMachine(state) => state.client_type(),
+            Self::Localhost(state) => state.client_type(),
 
             #[cfg(any(test, feature = "mocks"))]
             Self::Mock(state) => state.client_type(),
@@ -217,6 +479,14 @@ impl TryFrom<Any> for AnyClientState {
                 TendermintClientState::decode_vec(&raw.value)
                     .map_err(|e| Kind::InvalidRawClientState.context(e))?,
             )),
+            SOLOMACHINE_CLIENT_STATE_TYPE_URL => Ok(AnyClientState::SoloMachine(
+                SoloMachineClientState::decode_vec(&raw.value)
+                    .map_err(|e| Kind::InvalidRawClientState.context(e))?,
+            )),
+            LOCALHOST_CLIENT_STATE_TYPE_URL => Ok(AnyClientState::Localhost(
+                LocalhostClientState::decode_vec(&raw.value)
+                    .map_err(|e| Kind::InvalidRawClientState.context(e))?,
+            )),
 
             #[cfg(any(test, feature = "mocks"))]
             MOCK_CLIENT_STATE_TYPE_URL => Ok(AnyClientState::Mock(
@@ -236,6 +506,14 @@ impl From<AnyClientState> for Any {
                 type_url: TENDERMINT_CLIENT_STATE_TYPE_URL.to_string(),
                 value: value.encode_vec().unwrap(),
             },
+            AnyClientState::SoloMachine(value) => Any {
+                type_url: SOLOMACHINE_CLIENT_STATE_TYPE_URL.to_string(),
+                value: value.encode_vec().unwrap(),
+            },
+            AnyClientState::Localhost(value) => Any {
+                type_url: LOCALHOST_CLIENT_STATE_TYPE_URL.to_string(),
+                value: value.encode_vec().unwrap(),
+            },
             #[cfg(any(test, feature = "mocks"))]
             AnyClientState::Mock(value) => Any {
                 type_url: MOCK_CLIENT_STATE_TYPE_URL.to_string(),
@@ -261,6 +539,8 @@ impl ClientState for AnyClientState {
     fn is_frozen(&self) -> bool {
         match self {
             AnyClientState::Tendermint(tm_state) => tm_state.is_frozen(),
+            AnyClientState::SoloMachine(sm_state) => sm_state.is_frozen(),
+            AnyClientState::Localhost(lh_state) => lh_state.is_frozen(),
 
             #[cfg(any(test, feature = "mocks"))]
             AnyClientState::Mock(mock_state) => mock_state.is_frozen(),
@@ -276,6 +556,8 @@ impl ClientState for AnyClientState {
 #[serde(tag = "type")]
 pub enum AnyConsensusState {
     Tendermint(TendermintConsensusState),
+    SoloMachine(SoloMachineConsensusState),
+    Localhost(LocalhostConsensusState),
 
     #[cfg(any(test, feature = "mocks"))]
     Mock(MockConsensusState),
@@ -285,6 +567,8 @@ impl AnyConsensusState {
     pub fn client_type(&self) -> ClientType {
         match self {
             AnyConsensusState::Tendermint(_cs) => ClientType::Tendermint,
+            AnyConsensusState::SoloMachine(_cs) => ClientType::SoloMachine,
+            AnyConsensusState::Localhost(_cs) => ClientType::Localhost,
 
             #[cfg(any(test, feature = "mocks"))]
             AnyConsensusState::Mock(_cs) => ClientType::Mock,
@@ -305,6 +589,14 @@ impl TryFrom<Any> for AnyConsensusState {
                 TendermintConsensusState::decode_vec(&value.value)
                     .map_err(|e| Kind::InvalidRawConsensusState.context(e))?,
             )),
+            SOLOMACHINE_CONSENSUS_STATE_TYPE_URL => Ok(AnyConsensusState::SoloMachine(
+                SoloMachineConsensusState::decode_vec(&value.value)
+                    .map_err(|e| Kind::InvalidRawConsensusState.context(e))?,
+            )),
+            LOCALHOST_CONSENSUS_STATE_TYPE_URL => Ok(AnyConsensusState::Localhost(
+                LocalhostConsensusState::decode_vec(&value.value)
+                    .map_err(|e| Kind::InvalidRawConsensusState.context(e))?,
+            )),
 
             #[cfg(any(test, feature = "mocks"))]
             MOCK_CONSENSUS_STATE_TYPE_URL => Ok(AnyConsensusState::Mock(
@@ -324,6 +616,14 @@ impl From<AnyConsensusState> for Any {
                 type_url: TENDERMINT_CONSENSUS_STATE_TYPE_URL.to_string(),
                 value: value.encode_vec().unwrap(),
             },
+            AnyConsensusState::SoloMachine(value) => Any {
+                type_url: SOLOMACHINE_CONSENSUS_STATE_TYPE_URL.to_string(),
+                value: value.encode_vec().unwrap(),
+            },
+            AnyConsensusState::Localhost(value) => Any {
+                type_url: LOCALHOST_CONSENSUS_STATE_TYPE_URL.to_string(),
+                value: value.encode_vec().unwrap(),
+            },
             #[cfg(any(test, feature = "mocks"))]
             AnyConsensusState::Mock(value) => Any {
                 type_url: MOCK_CONSENSUS_STATE_TYPE_URL.to_string(),
@@ -339,7 +639,25 @@ impl ConsensusState for AnyConsensusState {
     }
 
     fn root(&self) -> &CommitmentRoot {
-        todo!()
+        match self {
+            Self::Tendermint(cs_state) => cs_state.root(),
+            Self::SoloMachine(cs_state) => cs_state.root(),
+            Self::Localhost(cs_state) => cs_state.root(),
+
+            #[cfg(any(test, feature = "mocks"))]
+            Self::Mock(mock_state) => mock_state.root(),
+        }
+    }
+
+    fn timestamp(&self) -> Timestamp {
+        match self {
+            Self::Tendermint(cs_state) => cs_state.timestamp(),
+            Self::SoloMachine(cs_state) => cs_state.timestamp(),
+            Self::Localhost(cs_state) => cs_state.timestamp(),
+
+            #[cfg(any(test, feature = "mocks"))]
+            Self::Mock(mock_state) => mock_state.timestamp(),
+        }
     }
 
     fn validate_basic(&self) -> Result<(), Box<dyn std::error::Error>> {
@@ -354,6 +672,8 @@ impl ConsensusState for AnyConsensusState {
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum AnyClient {
     Tendermint(TendermintClient),
+    SoloMachine(SoloMachineClient),
+    Localhost(LocalhostClient),
 
     #[cfg(any(test, feature = "mocks"))]
     Mock(MockClient),
@@ -363,6 +683,8 @@ impl AnyClient {
     pub fn from_client_type(client_type: ClientType) -> AnyClient {
         match client_type {
             ClientType::Tendermint => Self::Tendermint(TendermintClient),
+            ClientType::SoloMachine => Self::SoloMachine(SoloMachineClient),
+            ClientType::Localhost => Self::Localhost(LocalhostClient),
 
             #[cfg(any(test, feature = "mocks"))]
             ClientType::Mock => Self::Mock(MockClient),
@@ -373,12 +695,15 @@ impl AnyClient {
 // ⚠️  Beware of the awful boilerplate below ⚠️
 impl ClientDef for AnyClient {
     type Header = AnyHeader;
+    type Misbehaviour = AnyMisbehaviour;
     type ClientState = AnyClientState;
     type ConsensusState = AnyConsensusState;
 
     /// Validates an incoming `header` against the latest consensus state of this client.
     fn check_header_and_update_state(
         &self,
+        ctx: &dyn ClientReader,
+        client_id: ClientId,
         client_state: AnyClientState,
         header: AnyHeader,
     ) -> Result<(AnyClientState, AnyConsensusState), Box<dyn std::error::Error>> {
@@ -391,7 +716,7 @@ impl ClientDef for AnyClient {
                 .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Tendermint))?;
 
                 let (new_state, new_consensus) =
-                    client.check_header_and_update_state(client_state, header)?;
+                    client.check_header_and_update_state(ctx, client_id, client_state, header)?;
 
                 Ok((
                     AnyClientState::Tendermint(new_state),
@@ -399,6 +724,38 @@ impl ClientDef for AnyClient {
                 ))
             }
 
+            Self::SoloMachine(client) => {
+                let (client_state, header) = downcast!(
+                    client_state => AnyClientState::SoloMachine,
+                    header => AnyHeader::SoloMachine,
+                )
+                .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::SoloMachine))?;
+
+                let (new_state, new_consensus) =
+                    client.check_header_and_update_state(ctx, client_id, client_state, header)?;
+
+                Ok((
+                    AnyClientState::SoloMachine(new_state),
+                    AnyConsensusState::SoloMachine(new_consensus),
+                ))
+            }
+
+            Self::Localhost(client) => {
+                let (client_state, header) = downcast!(
+                    client_state => AnyClientState::Localhost,
+                    header => AnyHeader::Localhost,
+                )
+                .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Localhost))?;
+
+                let (new_state, new_consensus) =
+                    client.check_header_and_update_state(ctx, client_id, client_state, header)?;
+
+                Ok((
+                    AnyClientState::Localhost(new_state),
+                    AnyConsensusState::Localhost(new_consensus),
+                ))
+            }
+
             #[cfg(any(test, feature = "mocks"))]
             Self::Mock(client) => {
                 let (client_state, header) = downcast!(
@@ -408,7 +765,185 @@ impl ClientDef for AnyClient {
                 .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Mock))?;
 
                 let (new_state, new_consensus) =
-                    client.check_header_and_update_state(client_state, header)?;
+                    client.check_header_and_update_state(ctx, client_id, client_state, header)?;
+
+                Ok((
+                    AnyClientState::Mock(new_state),
+                    AnyConsensusState::Mock(new_consensus),
+                ))
+            }
+        }
+    }
+
+    fn check_misbehaviour_and_update_state(
+        &self,
+        ctx: &dyn ClientReader,
+        client_id: ClientId,
+        client_state: AnyClientState,
+        misbehaviour: AnyMisbehaviour,
+    ) -> Result<AnyClientState, Box<dyn std::error::Error>> {
+        match self {
+            Self::Tendermint(client) => {
+                let (client_state, misbehaviour) = downcast!(
+                    client_state => AnyClientState::Tendermint,
+                    misbehaviour => AnyMisbehaviour::Tendermint,
+                )
+                .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Tendermint))?;
+
+                let new_state = client.check_misbehaviour_and_update_state(
+                    ctx,
+                    client_id,
+                    client_state,
+                    misbehaviour,
+                )?;
+
+                Ok(AnyClientState::Tendermint(new_state))
+            }
+
+            Self::SoloMachine(client) => {
+                let (client_state, misbehaviour) = downcast!(
+                    client_state => AnyClientState::SoloMachine,
+                    misbehaviour => AnyMisbehaviour::SoloMachine,
+                )
+                .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::SoloMachine))?;
+
+                let new_state = client.check_misbehaviour_and_update_state(
+                    ctx,
+                    client_id,
+                    client_state,
+                    misbehaviour,
+                )?;
+
+                Ok(AnyClientState::SoloMachine(new_state))
+            }
+
+            // There is no evidence of misbehaviour for localhost clients.
+            Self::Localhost(_) => Err(Kind::ClientArgsTypeMismatch(ClientType::Localhost).into()),
+
+            #[cfg(any(test, feature = "mocks"))]
+            Self::Mock(client) => {
+                let (client_state, misbehaviour) = downcast!(
+                    client_state => AnyClientState::Mock,
+                    misbehaviour => AnyMisbehaviour::Mock,
+                )
+                .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Mock))?;
+
+                let new_state = client.check_misbehaviour_and_update_state(
+                    ctx,
+                    client_id,
+                    client_state,
+                    misbehaviour,
+                )?;
+
+                Ok(AnyClientState::Mock(new_state))
+            }
+        }
+    }
+
+    fn verify_upgrade_and_update_state(
+        &self,
+        ctx: &dyn ClientReader,
+        client_id: ClientId,
+        client_state: AnyClientState,
+        upgraded_client_state: AnyClientState,
+        upgraded_consensus_state: AnyConsensusState,
+        proof_upgrade_client: &CommitmentProofBytes,
+        proof_upgrade_consensus_state: &CommitmentProofBytes,
+    ) -> Result<(AnyClientState, AnyConsensusState), Box<dyn std::error::Error>> {
+        charge_proof(ctx, proof_upgrade_client)?;
+        charge_proof(ctx, proof_upgrade_consensus_state)?;
+
+        match self {
+            Self::Tendermint(client) => {
+                let (client_state, (upgraded_client_state, upgraded_consensus_state)) = downcast!(
+                    client_state => AnyClientState::Tendermint,
+                    upgraded_client_state => AnyClientState::Tendermint,
+                    upgraded_consensus_state => AnyConsensusState::Tendermint,
+                )
+                .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Tendermint))?;
+
+                let (new_state, new_consensus) = client.verify_upgrade_and_update_state(
+                    ctx,
+                    client_id,
+                    client_state,
+                    upgraded_client_state,
+                    upgraded_consensus_state,
+                    proof_upgrade_client,
+                    proof_upgrade_consensus_state,
+                )?;
+
+                Ok((
+                    AnyClientState::Tendermint(new_state),
+                    AnyConsensusState::Tendermint(new_consensus),
+                ))
+            }
+
+            Self::SoloMachine(client) => {
+                let (client_state, (upgraded_client_state, upgraded_consensus_state)) = downcast!(
+                    client_state => AnyClientState::SoloMachine,
+                    upgraded_client_state => AnyClientState::SoloMachine,
+                    upgraded_consensus_state => AnyConsensusState::SoloMachine,
+                )
+                .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::SoloMachine))?;
+
+                let (new_state, new_consensus) = client.verify_upgrade_and_update_state(
+                    ctx,
+                    client_id,
+                    client_state,
+                    upgraded_client_state,
+                    upgraded_consensus_state,
+                    proof_upgrade_client,
+                    proof_upgrade_consensus_state,
+                )?;
+
+                Ok((
+                    AnyClientState::SoloMachine(new_state),
+                    AnyConsensusState::SoloMachine(new_consensus),
+                ))
+            }
+
+            Self::Localhost(client) => {
+                let (client_state, (upgraded_client_state, upgraded_consensus_state)) = downcast!(
+                    client_state => AnyClientState::Localhost,
+                    upgraded_client_state => AnyClientState::Localhost,
+                    upgraded_consensus_state => AnyConsensusState::Localhost,
+                )
+                .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Localhost))?;
+
+                let (new_state, new_consensus) = client.verify_upgrade_and_update_state(
+                    ctx,
+                    client_id,
+                    client_state,
+                    upgraded_client_state,
+                    upgraded_consensus_state,
+                    proof_upgrade_client,
+                    proof_upgrade_consensus_state,
+                )?;
+
+                Ok((
+                    AnyClientState::Localhost(new_state),
+                    AnyConsensusState::Localhost(new_consensus),
+                ))
+            }
+
+            #[cfg(any(test, feature = "mocks"))]
+            Self::Mock(client) => {
+                let (client_state, (upgraded_client_state, upgraded_consensus_state)) = downcast!(
+                    client_state => AnyClientState::Mock,
+                    upgraded_client_state => AnyClientState::Mock,
+                    upgraded_consensus_state => AnyConsensusState::Mock,
+                )
+                .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Mock))?;
+
+                let (new_state, new_consensus) = client.verify_upgrade_and_update_state(
+                    ctx,
+                    client_id,
+                    client_state,
+                    upgraded_client_state,
+                    upgraded_consensus_state,
+                    proof_upgrade_client,
+                    proof_upgrade_consensus_state,
+                )?;
 
                 Ok((
                     AnyClientState::Mock(new_state),
@@ -418,16 +953,34 @@ impl ClientDef for AnyClient {
         }
     }
 
+    fn embedded_consensus_state(&self, client_state: &AnyClientState) -> Option<AnyConsensusState> {
+        match self {
+            Self::SoloMachine(client) => {
+                let client_state = downcast!(client_state => AnyClientState::SoloMachine)?;
+
+                client
+                    .embedded_consensus_state(client_state)
+                    .map(AnyConsensusState::SoloMachine)
+            }
+
+            _ => None,
+        }
+    }
+
     fn verify_client_consensus_state(
         &self,
+        ctx: &dyn ConnectionReader,
         client_state: &Self::ClientState,
         height: Height,
         prefix: &CommitmentPrefix,
         proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
         client_id: &ClientId,
         consensus_height: Height,
         expected_consensus_state: &AnyConsensusState,
-    ) -> Result<(), Box<dyn std::error::Error>> {
+    ) -> Result<Option<AnyClientState>, Box<dyn std::error::Error>> {
+        charge_proof(ctx, proof)?;
+
         match self {
             Self::Tendermint(client) => {
                 let client_state = downcast!(
@@ -435,15 +988,61 @@ impl ClientDef for AnyClient {
                 )
                 .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Tendermint))?;
 
-                client.verify_client_consensus_state(
-                    client_state,
-                    height,
-                    prefix,
-                    proof,
-                    client_id,
-                    consensus_height,
-                    expected_consensus_state,
+                client
+                    .verify_client_consensus_state(
+                        ctx,
+                        client_state,
+                        height,
+                        prefix,
+                        proof,
+                        root,
+                        client_id,
+                        consensus_height,
+                        expected_consensus_state,
+                    )
+                    .map(|state| state.map(ClientState::wrap_any))
+            }
+
+            Self::SoloMachine(client) => {
+                let client_state = downcast!(
+                    client_state => AnyClientState::SoloMachine
                 )
+                .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::SoloMachine))?;
+
+                client
+                    .verify_client_consensus_state(
+                        ctx,
+                        client_state,
+                        height,
+                        prefix,
+                        proof,
+                        root,
+                        client_id,
+                        consensus_height,
+                        expected_consensus_state,
+                    )
+                    .map(|state| state.map(ClientState::wrap_any))
+            }
+
+            Self::Localhost(client) => {
+                let client_state = downcast!(
+                    client_state => AnyClientState::Localhost
+                )
+                .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Localhost))?;
+
+                client
+                    .verify_client_consensus_state(
+                        ctx,
+                        client_state,
+                        height,
+                        prefix,
+                        proof,
+                        root,
+                        client_id,
+                        consensus_height,
+                        expected_consensus_state,
+                    )
+                    .map(|state| state.map(ClientState::wrap_any))
             }
 
             #[cfg(any(test, feature = "mocks"))]
@@ -453,41 +1052,89 @@ impl ClientDef for AnyClient {
                 )
                 .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Mock))?;
 
-                client.verify_client_consensus_state(
-                    client_state,
-                    height,
-                    prefix,
-                    proof,
-                    client_id,
-                    consensus_height,
-                    expected_consensus_state,
-                )
+                client
+                    .verify_client_consensus_state(
+                        ctx,
+                        client_state,
+                        height,
+                        prefix,
+                        proof,
+                        root,
+                        client_id,
+                        consensus_height,
+                        expected_consensus_state,
+                    )
+                    .map(|state| state.map(ClientState::wrap_any))
             }
         }
     }
 
     fn verify_connection_state(
         &self,
+        ctx: &dyn ConnectionReader,
         client_state: &AnyClientState,
         height: Height,
         prefix: &CommitmentPrefix,
         proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
         connection_id: &ConnectionId,
         expected_connection_end: &ConnectionEnd,
-    ) -> Result<(), Box<dyn std::error::Error>> {
+    ) -> Result<Option<AnyClientState>, Box<dyn std::error::Error>> {
+        charge_proof(ctx, proof)?;
+
         match self {
             Self::Tendermint(client) => {
                 let client_state = downcast!(client_state => AnyClientState::Tendermint)
                     .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Tendermint))?;
 
-                client.verify_connection_state(
-                    client_state,
-                    height,
-                    prefix,
-                    proof,
-                    connection_id,
-                    expected_connection_end,
-                )
+                client
+                    .verify_connection_state(
+                        ctx,
+                        client_state,
+                        height,
+                        prefix,
+                        proof,
+                        root,
+                        connection_id,
+                        expected_connection_end,
+                    )
+                    .map(|state| state.map(ClientState::wrap_any))
+            }
+
+            Self::SoloMachine(client) => {
+                let client_state = downcast!(client_state => AnyClientState::SoloMachine)
+                    .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::SoloMachine))?;
+
+                client
+                    .verify_connection_state(
+                        ctx,
+                        client_state,
+                        height,
+                        prefix,
+                        proof,
+                        root,
+                        connection_id,
+                        expected_connection_end,
+                    )
+                    .map(|state| state.map(ClientState::wrap_any))
+            }
+
+            Self::Localhost(client) => {
+                let client_state = downcast!(client_state => AnyClientState::Localhost)
+                    .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Localhost))?;
+
+                client
+                    .verify_connection_state(
+                        ctx,
+                        client_state,
+                        height,
+                        prefix,
+                        proof,
+                        root,
+                        connection_id,
+                        expected_connection_end,
+                    )
+                    .map(|state| state.map(ClientState::wrap_any))
             }
 
             #[cfg(any(test, feature = "mocks"))]
@@ -495,42 +1142,92 @@ impl ClientDef for AnyClient {
                 let client_state = downcast!(client_state => AnyClientState::Mock)
                     .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Mock))?;
 
-                client.verify_connection_state(
-                    client_state,
-                    height,
-                    prefix,
-                    proof,
-                    connection_id,
-                    expected_connection_end,
-                )
+                client
+                    .verify_connection_state(
+                        ctx,
+                        client_state,
+                        height,
+                        prefix,
+                        proof,
+                        root,
+                        connection_id,
+                        expected_connection_end,
+                    )
+                    .map(|state| state.map(ClientState::wrap_any))
             }
         }
     }
 
     fn verify_channel_state(
         &self,
+        ctx: &dyn ChannelReader,
         client_state: &AnyClientState,
         height: Height,
         prefix: &CommitmentPrefix,
         proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
         port_id: &PortId,
         channel_id: &ChannelId,
         expected_channel_end: &ChannelEnd,
-    ) -> Result<(), Box<dyn std::error::Error>> {
+    ) -> Result<Option<AnyClientState>, Box<dyn std::error::Error>> {
+        charge_proof(ctx, proof)?;
+
         match self {
             Self::Tendermint(client) => {
                 let client_state = downcast!(client_state => AnyClientState::Tendermint)
                     .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Tendermint))?;
 
-                client.verify_channel_state(
-                    client_state,
-                    height,
-                    prefix,
-                    proof,
-                    port_id,
-                    channel_id,
-                    expected_channel_end,
-                )
+                client
+                    .verify_channel_state(
+                        ctx,
+                        client_state,
+                        height,
+                        prefix,
+                        proof,
+                        root,
+                        port_id,
+                        channel_id,
+                        expected_channel_end,
+                    )
+                    .map(|state| state.map(ClientState::wrap_any))
+            }
+
+            Self::SoloMachine(client) => {
+                let client_state = downcast!(client_state => AnyClientState::SoloMachine)
+                    .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::SoloMachine))?;
+
+                client
+                    .verify_channel_state(
+                        ctx,
+                        client_state,
+                        height,
+                        prefix,
+                        proof,
+                        root,
+                        port_id,
+                        channel_id,
+                        expected_channel_end,
+                    )
+                    .map(|state| state.map(ClientState::wrap_any))
+            }
+
+            Self::Localhost(client) => {
+                let client_state = downcast!(client_state => AnyClientState::Localhost)
+                    .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Localhost))?;
+
+                client
+                    .verify_channel_state(
+                        ctx,
+                        client_state,
+                        height,
+                        prefix,
+                        proof,
+                        root,
+                        port_id,
+                        channel_id,
+                        expected_channel_end,
+                    )
+                    .map(|state| state.map(ClientState::wrap_any))
             }
 
             #[cfg(any(test, feature = "mocks"))]
@@ -538,21 +1235,26 @@ impl ClientDef for AnyClient {
                 let client_state = downcast!(client_state => AnyClientState::Mock)
                     .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Mock))?;
 
-                client.verify_channel_state(
-                    client_state,
-                    height,
-                    prefix,
-                    proof,
-                    port_id,
-                    channel_id,
-                    expected_channel_end,
-                )
+                client
+                    .verify_channel_state(
+                        ctx,
+                        client_state,
+                        height,
+                        prefix,
+                        proof,
+                        root,
+                        port_id,
+                        channel_id,
+                        expected_channel_end,
+                    )
+                    .map(|state| state.map(ClientState::wrap_any))
             }
         }
     }
 
     fn verify_client_full_state(
         &self,
+        ctx: &dyn ConnectionReader,
         client_state: &Self::ClientState,
         height: Height,
         root: &CommitmentRoot,
@@ -560,7 +1262,9 @@ impl ClientDef for AnyClient {
         client_id: &ClientId,
         proof: &CommitmentProofBytes,
         client_state_on_counterparty: &AnyClientState,
-    ) -> Result<(), Box<dyn std::error::Error>> {
+    ) -> Result<Option<AnyClientState>, Box<dyn std::error::Error>> {
+        charge_proof(ctx, proof)?;
+
         match self {
             Self::Tendermint(client) => {
                 let client_state = downcast!(
@@ -568,15 +1272,58 @@ impl ClientDef for AnyClient {
                 )
                 .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Tendermint))?;
 
-                client.verify_client_full_state(
-                    client_state,
-                    height,
-                    root,
-                    prefix,
-                    client_id,
-                    proof,
-                    client_state_on_counterparty,
+                client
+                    .verify_client_full_state(
+                        ctx,
+                        client_state,
+                        height,
+                        root,
+                        prefix,
+                        client_id,
+                        proof,
+                        client_state_on_counterparty,
+                    )
+                    .map(|state| state.map(ClientState::wrap_any))
+            }
+
+            Self::SoloMachine(client) => {
+                let client_state = downcast!(
+                    client_state => AnyClientState::SoloMachine
                 )
+                .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::SoloMachine))?;
+
+                client
+                    .verify_client_full_state(
+                        ctx,
+                        client_state,
+                        height,
+                        root,
+                        prefix,
+                        client_id,
+                        proof,
+                        client_state_on_counterparty,
+                    )
+                    .map(|state| state.map(ClientState::wrap_any))
+            }
+
+            Self::Localhost(client) => {
+                let client_state = downcast!(
+                    client_state => AnyClientState::Localhost
+                )
+                .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Localhost))?;
+
+                client
+                    .verify_client_full_state(
+                        ctx,
+                        client_state,
+                        height,
+                        root,
+                        prefix,
+                        client_id,
+                        proof,
+                        client_state_on_counterparty,
+                    )
+                    .map(|state| state.map(ClientState::wrap_any))
             }
 
             #[cfg(any(test, feature = "mocks"))]
@@ -586,18 +1333,419 @@ impl ClientDef for AnyClient {
                 )
                 .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Mock))?;
 
-                client.verify_client_full_state(
-                    client_state,
-                    height,
-                    root,
-                    prefix,
-                    client_id,
-                    proof,
-                    client_state_on_counterparty,
-                )
+                client
+                    .verify_client_full_state(
+                        ctx,
+                        client_state,
+                        height,
+                        root,
+                        prefix,
+                        client_id,
+                        proof,
+                        client_state_on_counterparty,
+                    )
+                    .map(|state| state.map(ClientState::wrap_any))
+            }
+        }
+    }
+
+    fn verify_packet_data(
+        &self,
+        ctx: &dyn ChannelReader,
+        client_state: &AnyClientState,
+        height: Height,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        seq: &Sequence,
+        commitment: PacketCommitment,
+    ) -> Result<Option<AnyClientState>, Box<dyn std::error::Error>> {
+        charge_proof(ctx, proof)?;
+
+        match self {
+            Self::Tendermint(client) => {
+                let client_state = downcast!(client_state => AnyClientState::Tendermint)
+                    .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Tendermint))?;
+
+                client
+                    .verify_packet_data(
+                        ctx,
+                        client_state,
+                        height,
+                        prefix,
+                        proof,
+                        root,
+                        port_id,
+                        channel_id,
+                        seq,
+                        commitment,
+                    )
+                    .map(|state| state.map(ClientState::wrap_any))
+            }
+
+            Self::SoloMachine(client) => {
+                let client_state = downcast!(client_state => AnyClientState::SoloMachine)
+                    .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::SoloMachine))?;
+
+                client
+                    .verify_packet_data(
+                        ctx,
+                        client_state,
+                        height,
+                        prefix,
+                        proof,
+                        root,
+                        port_id,
+                        channel_id,
+                        seq,
+                        commitment,
+                    )
+                    .map(|state| state.map(ClientState::wrap_any))
+            }
+
+            Self::Localhost(client) => {
+                let client_state = downcast!(client_state => AnyClientState::Localhost)
+                    .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Localhost))?;
+
+                client
+                    .verify_packet_data(
+                        ctx,
+                        client_state,
+                        height,
+                        prefix,
+                        proof,
+                        root,
+                        port_id,
+                        channel_id,
+                        seq,
+                        commitment,
+                    )
+                    .map(|state| state.map(ClientState::wrap_any))
+            }
+
+            #[cfg(any(test, feature = "mocks"))]
+            Self::Mock(client) => {
+                let client_state = downcast!(client_state => AnyClientState::Mock)
+                    .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Mock))?;
+
+                client
+                    .verify_packet_data(
+                        ctx,
+                        client_state,
+                        height,
+                        prefix,
+                        proof,
+                        root,
+                        port_id,
+                        channel_id,
+                        seq,
+                        commitment,
+                    )
+                    .map(|state| state.map(ClientState::wrap_any))
             }
         }
     }
+
+    fn verify_packet_acknowledgement(
+        &self,
+        ctx: &dyn ChannelReader,
+        client_state: &AnyClientState,
+        height: Height,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        seq: &Sequence,
+        ack: Vec<u8>,
+    ) -> Result<Option<AnyClientState>, Box<dyn std::error::Error>> {
+        charge_proof(ctx, proof)?;
+
+        match self {
+            Self::Tendermint(client) => {
+                let client_state = downcast!(client_state => AnyClientState::Tendermint)
+                    .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Tendermint))?;
+
+                client
+                    .verify_packet_acknowledgement(
+                        ctx,
+                        client_state,
+                        height,
+                        prefix,
+                        proof,
+                        root,
+                        port_id,
+                        channel_id,
+                        seq,
+                        ack,
+                    )
+                    .map(|state| state.map(ClientState::wrap_any))
+            }
+
+            Self::SoloMachine(client) => {
+                let client_state = downcast!(client_state => AnyClientState::SoloMachine)
+                    .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::SoloMachine))?;
+
+                client
+                    .verify_packet_acknowledgement(
+                        ctx,
+                        client_state,
+                        height,
+                        prefix,
+                        proof,
+                        root,
+                        port_id,
+                        channel_id,
+                        seq,
+                        ack,
+                    )
+                    .map(|state| state.map(ClientState::wrap_any))
+            }
+
+            Self::Localhost(client) => {
+                let client_state = downcast!(client_state => AnyClientState::Localhost)
+                    .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Localhost))?;
+
+                client
+                    .verify_packet_acknowledgement(
+                        ctx,
+                        client_state,
+                        height,
+                        prefix,
+                        proof,
+                        root,
+                        port_id,
+                        channel_id,
+                        seq,
+                        ack,
+                    )
+                    .map(|state| state.map(ClientState::wrap_any))
+            }
+
+            #[cfg(any(test, feature = "mocks"))]
+            Self::Mock(client) => {
+                let client_state = downcast!(client_state => AnyClientState::Mock)
+                    .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Mock))?;
+
+                client
+                    .verify_packet_acknowledgement(
+                        ctx,
+                        client_state,
+                        height,
+                        prefix,
+                        proof,
+                        root,
+                        port_id,
+                        channel_id,
+                        seq,
+                        ack,
+                    )
+                    .map(|state| state.map(ClientState::wrap_any))
+            }
+        }
+    }
+
+    fn verify_next_sequence_recv(
+        &self,
+        ctx: &dyn ChannelReader,
+        client_state: &AnyClientState,
+        height: Height,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        seq: &Sequence,
+    ) -> Result<Option<AnyClientState>, Box<dyn std::error::Error>> {
+        charge_proof(ctx, proof)?;
+
+        match self {
+            Self::Tendermint(client) => {
+                let client_state = downcast!(client_state => AnyClientState::Tendermint)
+                    .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Tendermint))?;
+
+                client
+                    .verify_next_sequence_recv(
+                        ctx,
+                        client_state,
+                        height,
+                        prefix,
+                        proof,
+                        root,
+                        port_id,
+                        channel_id,
+                        seq,
+                    )
+                    .map(|state| state.map(ClientState::wrap_any))
+            }
+
+            Self::SoloMachine(client) => {
+                let client_state = downcast!(client_state => AnyClientState::SoloMachine)
+                    .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::SoloMachine))?;
+
+                client
+                    .verify_next_sequence_recv(
+                        ctx,
+                        client_state,
+                        height,
+                        prefix,
+                        proof,
+                        root,
+                        port_id,
+                        channel_id,
+                        seq,
+                    )
+                    .map(|state| state.map(ClientState::wrap_any))
+            }
+
+            Self::Localhost(client) => {
+                let client_state = downcast!(client_state => AnyClientState::Localhost)
+                    .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Localhost))?;
+
+                client
+                    .verify_next_sequence_recv(
+                        ctx,
+                        client_state,
+                        height,
+                        prefix,
+                        proof,
+                        root,
+                        port_id,
+                        channel_id,
+                        seq,
+                    )
+                    .map(|state| state.map(ClientState::wrap_any))
+            }
+
+            #[cfg(any(test, feature = "mocks"))]
+            Self::Mock(client) => {
+                let client_state = downcast!(client_state => AnyClientState::Mock)
+                    .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Mock))?;
+
+                client
+                    .verify_next_sequence_recv(
+                        ctx,
+                        client_state,
+                        height,
+                        prefix,
+                        proof,
+                        root,
+                        port_id,
+                        channel_id,
+                        seq,
+                    )
+                    .map(|state| state.map(ClientState::wrap_any))
+            }
+        }
+    }
+
+    fn verify_packet_receipt_absence(
+        &self,
+        ctx: &dyn ChannelReader,
+        client_state: &AnyClientState,
+        height: Height,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        seq: &Sequence,
+    ) -> Result<Option<AnyClientState>, Box<dyn std::error::Error>> {
+        charge_proof(ctx, proof)?;
+
+        match self {
+            Self::Tendermint(client) => {
+                let client_state = downcast!(client_state => AnyClientState::Tendermint)
+                    .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Tendermint))?;
+
+                client
+                    .verify_packet_receipt_absence(
+                        ctx,
+                        client_state,
+                        height,
+                        prefix,
+                        proof,
+                        root,
+                        port_id,
+                        channel_id,
+                        seq,
+                    )
+                    .map(|state| state.map(ClientState::wrap_any))
+            }
+
+            Self::SoloMachine(client) => {
+                let client_state = downcast!(client_state => AnyClientState::SoloMachine)
+                    .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::SoloMachine))?;
+
+                client
+                    .verify_packet_receipt_absence(
+                        ctx,
+                        client_state,
+                        height,
+                        prefix,
+                        proof,
+                        root,
+                        port_id,
+                        channel_id,
+                        seq,
+                    )
+                    .map(|state| state.map(ClientState::wrap_any))
+            }
+
+            Self::Localhost(client) => {
+                let client_state = downcast!(client_state => AnyClientState::Localhost)
+                    .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Localhost))?;
+
+                client
+                    .verify_packet_receipt_absence(
+                        ctx,
+                        client_state,
+                        height,
+                        prefix,
+                        proof,
+                        root,
+                        port_id,
+                        channel_id,
+                        seq,
+                    )
+                    .map(|state| state.map(ClientState::wrap_any))
+            }
+
+            #[cfg(any(test, feature = "mocks"))]
+            Self::Mock(client) => {
+                let client_state = downcast!(client_state => AnyClientState::Mock)
+                    .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Mock))?;
+
+                client
+                    .verify_packet_receipt_absence(
+                        ctx,
+                        client_state,
+                        height,
+                        prefix,
+                        proof,
+                        root,
+                        port_id,
+                        channel_id,
+                        seq,
+                    )
+                    .map(|state| state.map(ClientState::wrap_any))
+            }
+        }
+    }
+}
+
+/// Charges the verification of `proof` by its size, whatever the type of the client. The
+/// verification functions of `AnyClient` are the single place where proofs are charged.
+fn charge_proof<Ctx>(ctx: &Ctx, proof: &CommitmentProofBytes) -> Result<(), OutOfGas>
+where
+    Ctx: GasMeter + ?Sized,
+{
+    ctx.consume_gas(
+        ctx.gas_costs().verify_proof(proof.len()),
+        "proof verification",
+    )
 }
 
 #[cfg(test)]
@@ -2,19 +2,29 @@
 //! that any host chain must implement to be able to process any `ClientMsg`. See
 //! "ADR 003: IBC protocol implementation" for more details.
 
+use tendermint::Time;
+
+use crate::gas::GasMeter;
 use crate::ics02_client::client_def::{AnyClientState, AnyConsensusState};
 use crate::ics02_client::client_type::ClientType;
 use crate::ics02_client::error::Error;
-use crate::ics02_client::handler::ClientResult::{self, Create, Update};
+use crate::ics02_client::handler::ClientResult::{self, Create, Misbehaviour, Update, Upgrade};
 use crate::ics24_host::identifier::ClientId;
 use crate::Height;
 
-/// Defines the read-only part of ICS2 (client functions) context.
-pub trait ClientReader {
+/// Defines the read-only part of ICS2 (client functions) context. The handlers consume gas from the
+/// meter of the host (see `gas`).
+pub trait ClientReader: GasMeter {
     fn client_type(&self, client_id: &ClientId) -> Option<ClientType>;
     fn client_state(&self, client_id: &ClientId) -> Option<AnyClientState>;
     fn consensus_state(&self, client_id: &ClientId, height: Height) -> Option<AnyConsensusState>;
 
+    /// Returns the current height of the local chain.
+    fn host_height(&self) -> Height;
+
+    /// Returns the current timestamp of the local chain.
+    fn host_timestamp(&self) -> Time;
+
     /// Returns a natural number, counting how many clients have been created thus far.
     /// The value of this counter should increase only via method `ClientKeeper::increase_client_counter`.
     fn client_counter(&self) -> u64;
@@ -30,10 +40,20 @@ pub trait ClientKeeper {
                 self.store_client_type(client_id.clone(), res.client_type)?;
                 self.store_client_state(client_id.clone(), res.client_state.clone())?;
                 self.store_consensus_state(
-                    client_id,
+                    client_id.clone(),
                     res.client_state.latest_height(),
                     res.consensus_state,
                 )?;
+                self.store_update_time(
+                    client_id.clone(),
+                    res.client_state.latest_height(),
+                    res.processed_time,
+                )?;
+                self.store_update_height(
+                    client_id,
+                    res.client_state.latest_height(),
+                    res.processed_height,
+                )?;
                 self.increase_client_counter();
                 Ok(())
             }
@@ -44,6 +64,39 @@ pub trait ClientKeeper {
                     res.client_state.latest_height(),
                     res.consensus_state,
                 )?;
+                self.store_update_time(
+                    res.client_id.clone(),
+                    res.client_state.latest_height(),
+                    res.processed_time,
+                )?;
+                self.store_update_height(
+                    res.client_id,
+                    res.client_state.latest_height(),
+                    res.processed_height,
+                )?;
+                Ok(())
+            }
+            Misbehaviour(res) => {
+                self.store_client_state(res.client_id, res.client_state)?;
+                Ok(())
+            }
+            Upgrade(res) => {
+                self.store_client_state(res.client_id.clone(), res.client_state.clone())?;
+                self.store_consensus_state(
+                    res.client_id.clone(),
+                    res.client_state.latest_height(),
+                    res.consensus_state,
+                )?;
+                self.store_update_time(
+                    res.client_id.clone(),
+                    res.client_state.latest_height(),
+                    res.processed_time,
+                )?;
+                self.store_update_height(
+                    res.client_id,
+                    res.client_state.latest_height(),
+                    res.processed_height,
+                )?;
                 Ok(())
             }
         }
@@ -56,14 +109,14 @@ pub trait ClientKeeper {
         client_type: ClientType,
     ) -> Result<(), Error>;
 
-    /// Called upon successful client creation and update
+    /// Called upon successful client creation, update, upgrade and misbehaviour handling
     fn store_client_state(
         &mut self,
         client_id: ClientId,
         client_state: AnyClientState,
     ) -> Result<(), Error>;
 
-    /// Called upon successful client creation and update
+    /// Called upon successful client creation, update and upgrade
     fn store_consensus_state(
         &mut self,
         client_id: ClientId,
@@ -71,6 +124,24 @@ pub trait ClientKeeper {
         consensus_state: AnyConsensusState,
     ) -> Result<(), Error>;
 
+    /// Called upon successful client creation, update and upgrade, to record the time of the host
+    /// chain when the consensus state of the client at `height` is stored.
+    fn store_update_time(
+        &mut self,
+        client_id: ClientId,
+        height: Height,
+        timestamp: Time,
+    ) -> Result<(), Error>;
+
+    /// Called upon successful client creation, update and upgrade, to record the height of the
+    /// host chain when the consensus state of the client at `height` is stored.
+    fn store_update_height(
+        &mut self,
+        client_id: ClientId,
+        height: Height,
+        host_height: Height,
+    ) -> Result<(), Error>;
+
     /// Called upon client creation.
     /// Increases the counter which keeps track of how many clients have been created.
     /// Should never fail.
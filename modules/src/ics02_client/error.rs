@@ -1,6 +1,7 @@
 use anomaly::{BoxError, Context};
 use thiserror::Error;
 
+use crate::gas::OutOfGas;
 use crate::ics02_client::client_type::ClientType;
 use crate::ics24_host::identifier::ClientId;
 use crate::Height;
@@ -30,6 +31,27 @@ pub enum Kind {
     #[error("header verification failed")]
     HeaderVerificationFailure,
 
+    #[error("client {0} is frozen")]
+    ClientFrozen(ClientId),
+
+    #[error("misbehaviour verification failed")]
+    MisbehaviourVerificationFailure,
+
+    #[error("unknown misbehaviour type: {0}")]
+    UnknownMisbehaviourType(String),
+
+    #[error("invalid raw misbehaviour")]
+    InvalidRawMisbehaviour,
+
+    #[error("upgrade verification failed")]
+    UpgradeVerificationFailure,
+
+    #[error("invalid proof for the upgraded client state")]
+    InvalidUpgradeClientProof,
+
+    #[error("invalid proof for the upgraded consensus state")]
+    InvalidUpgradeConsensusStateProof,
+
     #[error("unknown client state type: {0}")]
     UnknownClientStateType(String),
 
@@ -71,6 +93,9 @@ pub enum Kind {
         state_type: ClientType,
         consensus_type: ClientType,
     },
+
+    #[error("{0}")]
+    OutOfGas(OutOfGas),
 }
 
 impl Kind {
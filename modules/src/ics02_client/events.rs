@@ -1,9 +1,12 @@
 //! Types for the IBC events emitted from Tendermint Websocket by the client module.
 use crate::attribute;
 use crate::events::{IBCEvent, RawObject};
+use crate::ics02_client::client_def::AnyHeader;
 use crate::ics02_client::client_type::ClientType;
 use crate::ics24_host::identifier::ClientId;
 use anomaly::BoxError;
+use subtle_encoding::hex;
+use tendermint_proto::Protobuf;
 
 use crate::ics02_client::height::Height;
 use serde_derive::{Deserialize, Serialize};
@@ -13,6 +16,8 @@ use tendermint::block;
 /// The content of the `type` field for the event that a chain produces upon executing the create client transaction.
 const CREATE_EVENT_TYPE: &str = "create_client";
 const UPDATE_EVENT_TYPE: &str = "update_client";
+const MISBEHAVIOUR_EVENT_TYPE: &str = "client_misbehaviour";
+const UPGRADE_EVENT_TYPE: &str = "upgrade_client";
 
 /// The content of the `key` field for the attribute containing the client identifier.
 const CLIENT_ID_ATTRIBUTE_KEY: &str = "client_id";
@@ -23,12 +28,23 @@ const CLIENT_TYPE_ATTRIBUTE_KEY: &str = "client_type";
 /// The content of the `key` field for the attribute containing the height.
 const CONSENSUS_HEIGHT_ATTRIBUTE_KEY: &str = "consensus_height";
 
+/// The content of the `key` field for the attribute containing the header, which the chain encodes
+/// as the hex string of an `Any` protobuf.
+const HEADER_ATTRIBUTE_KEY: &str = "header";
+
 pub fn try_from_tx(event: &tendermint::abci::Event) -> Option<IBCEvent> {
     match event.type_str.as_ref() {
         CREATE_EVENT_TYPE => Some(IBCEvent::CreateClient(CreateClient(
             extract_attributes_from_tx(event),
         ))),
-        UPDATE_EVENT_TYPE => Some(IBCEvent::UpdateClient(UpdateClient(
+        UPDATE_EVENT_TYPE => Some(IBCEvent::UpdateClient(UpdateClient {
+            common: extract_attributes_from_tx(event),
+            header: extract_header_from_tx(event),
+        })),
+        MISBEHAVIOUR_EVENT_TYPE => Some(IBCEvent::ClientMisbehavior(ClientMisbehavior(
+            extract_attributes_from_tx(event),
+        ))),
+        UPGRADE_EVENT_TYPE => Some(IBCEvent::UpgradeClient(UpgradeClient(
             extract_attributes_from_tx(event),
         ))),
         _ => None,
@@ -53,6 +69,21 @@ fn extract_attributes_from_tx(event: &tendermint::abci::Event) -> Attributes {
     attr
 }
 
+fn extract_header_from_tx(event: &tendermint::abci::Event) -> Option<Box<AnyHeader>> {
+    event
+        .attributes
+        .iter()
+        .find(|tag| tag.key.as_ref() == HEADER_ATTRIBUTE_KEY)
+        .and_then(|tag| decode_header(tag.value.as_ref()))
+}
+
+/// Decodes a header from the hex-encoded `Any` protobuf which chains emit in update events.
+/// Returns `None` if the encoding is invalid, or if the header has an unknown type.
+fn decode_header(hex_header: &str) -> Option<Box<AnyHeader>> {
+    let bytes = hex::decode(hex_header).ok()?;
+    AnyHeader::decode_vec(&bytes).ok().map(Box::new)
+}
+
 /// NewBlock event signals the committing & execution of a new block.
 // TODO - find a better place for NewBlock
 #[derive(Debug, Deserialize, Serialize, Clone)]
@@ -128,21 +159,34 @@ impl From<CreateClient> for IBCEvent {
 
 /// UpdateClient event signals a recent update of an on-chain client (IBC Client).
 #[derive(Debug, Deserialize, Serialize, Clone)]
-pub struct UpdateClient(Attributes);
+pub struct UpdateClient {
+    pub common: Attributes,
+    /// The header the client was updated with, if the chain included it in the event. Relayers
+    /// use it to check the update for misbehaviour of the chain the client tracks.
+    #[serde(skip)]
+    pub header: Option<Box<AnyHeader>>,
+}
 
 impl UpdateClient {
     pub fn client_id(&self) -> &ClientId {
-        &self.0.client_id
+        &self.common.client_id
     }
 
-    pub fn height(&self) -> &tendermint::block::Height {
-        &self.0.height
+    pub fn height(&self) -> &block::Height {
+        &self.common.height
+    }
+
+    pub fn consensus_height(&self) -> Height {
+        self.common.consensus_height
     }
 }
 
 impl From<Attributes> for UpdateClient {
     fn from(attrs: Attributes) -> Self {
-        UpdateClient(attrs)
+        UpdateClient {
+            common: attrs,
+            header: None,
+        }
     }
 }
 
@@ -150,12 +194,21 @@ impl TryFrom<RawObject> for UpdateClient {
     type Error = BoxError;
     fn try_from(obj: RawObject) -> Result<Self, Self::Error> {
         let consensus_height_str: String = attribute!(obj, "update_client.consensus_height");
-        Ok(UpdateClient(Attributes {
-            height: obj.height,
-            client_id: attribute!(obj, "update_client.client_id"),
-            client_type: attribute!(obj, "update_client.client_type"),
-            consensus_height: consensus_height_str.as_str().try_into()?,
-        }))
+        let header = obj
+            .events
+            .get("update_client.header")
+            .and_then(|headers| headers.get(obj.idx))
+            .and_then(|header| decode_header(header));
+
+        Ok(UpdateClient {
+            common: Attributes {
+                height: obj.height,
+                client_id: attribute!(obj, "update_client.client_id"),
+                client_type: attribute!(obj, "update_client.client_type"),
+                consensus_height: consensus_height_str.as_str().try_into()?,
+            },
+            header,
+        })
     }
 }
 
@@ -170,6 +223,22 @@ impl From<UpdateClient> for IBCEvent {
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct ClientMisbehavior(Attributes);
 
+impl ClientMisbehavior {
+    pub fn client_id(&self) -> &ClientId {
+        &self.0.client_id
+    }
+
+    pub fn height(&self) -> &block::Height {
+        &self.0.height
+    }
+}
+
+impl From<Attributes> for ClientMisbehavior {
+    fn from(attrs: Attributes) -> Self {
+        ClientMisbehavior(attrs)
+    }
+}
+
 impl TryFrom<RawObject> for ClientMisbehavior {
     type Error = BoxError;
     fn try_from(obj: RawObject) -> Result<Self, Self::Error> {
@@ -188,3 +257,43 @@ impl From<ClientMisbehavior> for IBCEvent {
         IBCEvent::ClientMisbehavior(v)
     }
 }
+
+/// UpgradeClient event signals the upgrade of an on-chain client (IBC Client) to the client and
+/// consensus states of the upgraded chain that it tracks.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct UpgradeClient(Attributes);
+
+impl UpgradeClient {
+    pub fn client_id(&self) -> &ClientId {
+        &self.0.client_id
+    }
+
+    pub fn height(&self) -> &block::Height {
+        &self.0.height
+    }
+}
+
+impl From<Attributes> for UpgradeClient {
+    fn from(attrs: Attributes) -> Self {
+        UpgradeClient(attrs)
+    }
+}
+
+impl TryFrom<RawObject> for UpgradeClient {
+    type Error = BoxError;
+    fn try_from(obj: RawObject) -> Result<Self, Self::Error> {
+        let consensus_height_str: String = attribute!(obj, "upgrade_client.consensus_height");
+        Ok(UpgradeClient(Attributes {
+            height: obj.height,
+            client_id: attribute!(obj, "upgrade_client.client_id"),
+            client_type: attribute!(obj, "upgrade_client.client_type"),
+            consensus_height: consensus_height_str.as_str().try_into()?,
+        }))
+    }
+}
+
+impl From<UpgradeClient> for IBCEvent {
+    fn from(v: UpgradeClient) -> Self {
+        IBCEvent::UpgradeClient(v)
+    }
+}
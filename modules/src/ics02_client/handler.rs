@@ -2,16 +2,20 @@
 
 use crate::handler::HandlerOutput;
 use crate::ics02_client::context::ClientReader;
-use crate::ics02_client::error::Error;
+use crate::ics02_client::error::{Error, Kind};
 use crate::ics02_client::msgs::ClientMsg;
 
 pub mod create_client;
+pub mod misbehaviour;
 pub mod update_client;
+pub mod upgrade_client;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum ClientResult {
     Create(create_client::Result),
     Update(update_client::Result),
+    Misbehaviour(misbehaviour::Result),
+    Upgrade(upgrade_client::Result),
 }
 
 /// General entry point for processing any message related to ICS2 (client functions) protocols.
@@ -19,8 +23,16 @@ pub fn dispatch<Ctx>(ctx: &Ctx, msg: ClientMsg) -> Result<HandlerOutput<ClientRe
 where
     Ctx: ClientReader,
 {
-    match msg {
+    let output = match msg {
         ClientMsg::CreateClient(msg) => create_client::process(ctx, msg),
         ClientMsg::UpdateClient(msg) => update_client::process(ctx, msg),
-    }
+        ClientMsg::Misbehaviour(msg) => misbehaviour::process(ctx, msg),
+        ClientMsg::UpgradeClient(msg) => upgrade_client::process(ctx, msg),
+    }?;
+
+    // The events emitted by the handler are charged once it succeeded.
+    ctx.consume_gas(ctx.gas_costs().events(output.events.len()), "events")
+        .map_err(Kind::OutOfGas)?;
+
+    Ok(output)
 }
@@ -1,5 +1,7 @@
 //! Protocol logic specific to processing ICS2 messages of type `MsgCreateAnyClient`.
 
+use tendermint::Time;
+
 use crate::events::IBCEvent;
 use crate::handler::{HandlerOutput, HandlerResult};
 use crate::ics02_client::client_def::{AnyClientState, AnyConsensusState};
@@ -10,6 +12,7 @@ use crate::ics02_client::events::Attributes;
 use crate::ics02_client::handler::ClientResult;
 use crate::ics02_client::msgs::create_client::MsgCreateAnyClient;
 use crate::ics24_host::identifier::ClientId;
+use crate::Height;
 
 /// The result following the successful processing of a `MsgCreateAnyClient` message. Preferably
 /// this data type should be used with a qualified name `create_client::Result` to avoid ambiguity.
@@ -19,6 +22,10 @@ pub struct Result {
     pub client_type: ClientType,
     pub client_state: AnyClientState,
     pub consensus_state: AnyConsensusState,
+    /// The time and height of the host chain when the consensus state is stored, which are the
+    /// reference for the delay period of the connections over this client.
+    pub processed_time: Time,
+    pub processed_height: Height,
 }
 
 pub fn process(
@@ -40,6 +47,8 @@ pub fn process(
         client_type: msg.client_state().client_type(),
         client_state: msg.client_state(),
         consensus_state: msg.consensus_state(),
+        processed_time: ctx.host_timestamp(),
+        processed_height: ctx.host_height(),
     });
 
     let event_attributes = Attributes {
@@ -81,8 +90,8 @@ mod tests {
         let height = Height::new(0, 42);
 
         let msg = MsgCreateAnyClient::new(
-            MockClientState(MockHeader(height)).into(),
-            MockConsensusState(MockHeader(height)).into(),
+            MockClientState::new(MockHeader::new(height)).into(),
+            MockConsensusState::new(MockHeader::new(height)).into(),
             signer,
         )
         .unwrap();
@@ -127,12 +136,12 @@ mod tests {
 
         let create_client_msgs: Vec<MsgCreateAnyClient> = vec![
             MsgCreateAnyClient::new(
-                MockClientState(MockHeader(Height {
+                MockClientState::new(MockHeader::new(Height {
                     revision_height: 42,
                     ..height
                 }))
                 .into(),
-                MockConsensusState(MockHeader(Height {
+                MockConsensusState::new(MockHeader::new(Height {
                     revision_height: 42,
                     ..height
                 }))
@@ -141,12 +150,12 @@ mod tests {
             )
             .unwrap(),
             MsgCreateAnyClient::new(
-                MockClientState(MockHeader(Height {
+                MockClientState::new(MockHeader::new(Height {
                     revision_height: 42,
                     ..height
                 }))
                 .into(),
-                MockConsensusState(MockHeader(Height {
+                MockConsensusState::new(MockHeader::new(Height {
                     revision_height: 42,
                     ..height
                 }))
@@ -155,12 +164,12 @@ mod tests {
             )
             .unwrap(),
             MsgCreateAnyClient::new(
-                MockClientState(MockHeader(Height {
+                MockClientState::new(MockHeader::new(Height {
                     revision_height: 50,
                     ..height
                 }))
                 .into(),
-                MockConsensusState(MockHeader(Height {
+                MockConsensusState::new(MockHeader::new(Height {
                     revision_height: 50,
                     ..height
                 }))
@@ -0,0 +1,233 @@
+//! Protocol logic specific to processing ICS2 messages of type `MsgSubmitAnyMisbehaviour`.
+
+use tendermint_proto::Protobuf;
+
+use crate::events::IBCEvent;
+use crate::handler::{HandlerOutput, HandlerResult};
+use crate::ics02_client::client_def::{AnyClient, AnyClientState, ClientDef};
+use crate::ics02_client::context::ClientReader;
+use crate::ics02_client::error::{Error, Kind};
+use crate::ics02_client::events::Attributes;
+use crate::ics02_client::handler::ClientResult;
+use crate::ics02_client::misbehaviour::Misbehaviour;
+use crate::ics02_client::msgs::misbehaviour::MsgSubmitAnyMisbehaviour;
+use crate::ics02_client::state::ClientState;
+use crate::ics24_host::identifier::ClientId;
+
+/// The result following the successful processing of a `MsgSubmitAnyMisbehaviour` message.
+/// Preferably this data type should be used with a qualified name `misbehaviour::Result` to avoid
+/// ambiguity.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Result {
+    pub client_id: ClientId,
+    pub client_state: AnyClientState,
+}
+
+pub fn process(
+    ctx: &dyn ClientReader,
+    msg: MsgSubmitAnyMisbehaviour,
+) -> HandlerResult<ClientResult, Error> {
+    let mut output = HandlerOutput::builder();
+
+    let MsgSubmitAnyMisbehaviour {
+        client_id,
+        misbehaviour,
+        signer: _,
+    } = msg;
+
+    // The evidence must concern the client it is submitted to.
+    if misbehaviour.client_id() != &client_id {
+        return Err(Kind::MisbehaviourVerificationFailure
+            .context(format!(
+                "misbehaviour is for client {}, not {}",
+                misbehaviour.client_id(),
+                client_id
+            ))
+            .into());
+    }
+
+    // Read client type from the host chain store. The client should already exist.
+    let client_type = ctx
+        .client_type(&client_id)
+        .ok_or_else(|| Kind::ClientNotFound(client_id.clone()))?;
+
+    let client_def = AnyClient::from_client_type(client_type);
+
+    // Read client state from the host chain store.
+    let client_state = ctx
+        .client_state(&client_id)
+        .ok_or_else(|| Kind::ClientNotFound(client_id.clone()))?;
+
+    // A client which is already frozen has nothing left to freeze.
+    if client_state.is_frozen() {
+        return Err(Kind::ClientFrozen(client_id).into());
+    }
+
+    let misbehaviour_height = misbehaviour.height();
+
+    // The verification of the evidence is charged by its size.
+    let misbehaviour_len = misbehaviour.encode_vec().map_or(0, |bytes| bytes.len());
+    ctx.consume_gas(
+        ctx.gas_costs().verify_header(misbehaviour_len),
+        "misbehaviour verification",
+    )
+    .map_err(Kind::OutOfGas)?;
+
+    // Verify the evidence against the trusted consensus states of the client, obtaining the
+    // frozen client state. This will be later persisted by the keeper.
+    let new_client_state = client_def
+        .check_misbehaviour_and_update_state(ctx, client_id.clone(), client_state, misbehaviour)
+        .map_err(|e| Kind::MisbehaviourVerificationFailure.context(e.to_string()))?;
+
+    let result = ClientResult::Misbehaviour(Result {
+        client_id: client_id.clone(),
+        client_state: new_client_state,
+    });
+
+    let event_attributes = Attributes {
+        client_id,
+        client_type,
+        consensus_height: misbehaviour_height,
+        ..Default::default()
+    };
+    output.emit(IBCEvent::ClientMisbehavior(event_attributes.into()));
+
+    Ok(output.with_result(result))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::events::IBCEvent;
+    use crate::ics02_client::client_def::AnyClientState;
+    use crate::ics02_client::context::ClientKeeper;
+    use crate::ics02_client::handler::{dispatch, ClientResult};
+    use crate::ics02_client::msgs::misbehaviour::MsgSubmitAnyMisbehaviour;
+    use crate::ics02_client::msgs::ClientMsg;
+    use crate::ics02_client::state::ClientState;
+    use crate::ics24_host::identifier::ClientId;
+    use crate::mock::client_state::MockClientState;
+    use crate::mock::context::MockContext;
+    use crate::mock::header::MockHeader;
+    use crate::mock::misbehaviour::MockMisbehaviour;
+    use crate::test_utils::get_dummy_account_id;
+    use crate::Height;
+
+    #[test]
+    fn submit_misbehaviour_processing() {
+        struct Test {
+            name: String,
+            ctx: MockContext,
+            msg: MsgSubmitAnyMisbehaviour,
+            want_pass: bool,
+        }
+
+        let client_id = ClientId::from_str("mockclient1").unwrap();
+        let other_client_id = ClientId::from_str("mockclient2").unwrap();
+        let client_height = Height::new(0, 42);
+        let misbehaviour_height = Height::new(0, 40);
+
+        let ctx = MockContext::default().with_client(&client_id, client_height);
+
+        let mut frozen_ctx = ctx.clone();
+        frozen_ctx
+            .store_client_state(
+                client_id.clone(),
+                MockClientState::new(MockHeader::new(client_height))
+                    .with_frozen_height(misbehaviour_height)
+                    .into(),
+            )
+            .unwrap();
+
+        let msg = |client_id: &ClientId, header1: Height, header2: Height| {
+            MsgSubmitAnyMisbehaviour::new(
+                client_id.clone(),
+                MockMisbehaviour {
+                    client_id: client_id.clone(),
+                    header1: MockHeader::new(header1),
+                    header2: MockHeader::new(header2),
+                }
+                .into(),
+                get_dummy_account_id(),
+            )
+        };
+
+        let tests: Vec<Test> = vec![
+            Test {
+                name: "Good parameters".to_string(),
+                ctx: ctx.clone(),
+                msg: msg(&client_id, misbehaviour_height, misbehaviour_height),
+                want_pass: true,
+            },
+            Test {
+                name: "Headers at different heights are no evidence".to_string(),
+                ctx: ctx.clone(),
+                msg: msg(
+                    &client_id,
+                    misbehaviour_height,
+                    misbehaviour_height.increment(),
+                ),
+                want_pass: false,
+            },
+            Test {
+                name: "Client does not exist".to_string(),
+                ctx: ctx.clone(),
+                msg: msg(&other_client_id, misbehaviour_height, misbehaviour_height),
+                want_pass: false,
+            },
+            Test {
+                name: "Misbehaviour is for another client".to_string(),
+                ctx: ctx.clone(),
+                msg: MsgSubmitAnyMisbehaviour {
+                    client_id: client_id.clone(),
+                    ..msg(&other_client_id, misbehaviour_height, misbehaviour_height)
+                },
+                want_pass: false,
+            },
+            Test {
+                name: "Client is already frozen".to_string(),
+                ctx: frozen_ctx,
+                msg: msg(&client_id, misbehaviour_height, misbehaviour_height),
+                want_pass: false,
+            },
+        ];
+
+        for test in tests {
+            let res = dispatch(&test.ctx, ClientMsg::Misbehaviour(test.msg.clone()));
+
+            // Additionally check the events, the result and the frozen client state.
+            if let Ok(ref output) = res {
+                assert_eq!(output.events.len(), 1);
+                assert!(matches!(
+                    &output.events[0],
+                    IBCEvent::ClientMisbehavior(e) if e.client_id() == &test.msg.client_id
+                ));
+
+                match &output.result {
+                    ClientResult::Misbehaviour(res) => {
+                        assert_eq!(res.client_id, test.msg.client_id);
+                        assert!(res.client_state.is_frozen());
+                        assert!(matches!(
+                            res.client_state,
+                            AnyClientState::Mock(state)
+                                if state.frozen_height == Some(misbehaviour_height)
+                        ));
+                    }
+                    _ => {
+                        panic!("misbehaviour handler result has type other than MisbehaviourResult")
+                    }
+                }
+            }
+
+            assert_eq!(
+                test.want_pass,
+                res.is_ok(),
+                "submit_misbehaviour: test {}, \nmsg {:?} error: {:?}",
+                test.name,
+                test.msg,
+                res.err()
+            );
+        }
+    }
+}
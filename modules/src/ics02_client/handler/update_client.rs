@@ -1,14 +1,21 @@
 //! Protocol logic specific to processing ICS2 messages of type `MsgUpdateAnyClient`.
 
+use tendermint::Time;
+
+use tendermint_proto::Protobuf;
+
 use crate::events::IBCEvent;
 use crate::handler::{HandlerOutput, HandlerResult};
 use crate::ics02_client::client_def::{AnyClient, AnyClientState, AnyConsensusState, ClientDef};
 use crate::ics02_client::context::ClientReader;
 use crate::ics02_client::error::{Error, Kind};
-use crate::ics02_client::events::Attributes;
+use crate::ics02_client::events::{Attributes, UpdateClient};
 use crate::ics02_client::handler::ClientResult;
+use crate::ics02_client::header::Header;
 use crate::ics02_client::msgs::update_client::MsgUpdateAnyClient;
+use crate::ics02_client::state::ClientState;
 use crate::ics24_host::identifier::ClientId;
+use crate::Height;
 
 /// The result following the successful processing of a `MsgUpdateAnyClient` message. Preferably
 /// this data type should be used with a qualified name `update_client::Result` to avoid ambiguity.
@@ -17,6 +24,10 @@ pub struct Result {
     pub client_id: ClientId,
     pub client_state: AnyClientState,
     pub consensus_state: AnyConsensusState,
+    /// The time and height of the host chain when the consensus state is stored (see
+    /// `create_client::Result`).
+    pub processed_time: Time,
+    pub processed_height: Height,
 }
 
 pub fn process(
@@ -43,28 +54,48 @@ pub fn process(
         .client_state(&client_id)
         .ok_or_else(|| Kind::ClientNotFound(client_id.clone()))?;
 
+    // A frozen client cannot be updated anymore.
+    if client_state.is_frozen() {
+        return Err(Kind::ClientFrozen(client_id).into());
+    }
+
     let latest_height = client_state.latest_height();
     ctx.consensus_state(&client_id, latest_height)
         .ok_or_else(|| Kind::ConsensusStateNotFound(client_id.clone(), latest_height))?;
 
+    // The verification of the header is charged by its size.
+    let header_len = header.encode_vec().map_or(0, |bytes| bytes.len());
+    ctx.consume_gas(
+        ctx.gas_costs().verify_header(header_len),
+        "header verification",
+    )
+    .map_err(Kind::OutOfGas)?;
+
     // Use client_state to validate the new header against the latest consensus_state.
     // This function will return the new client_state (its latest_height changed) and a
     // consensus_state obtained from header. These will be later persisted by the keeper.
     let (new_client_state, new_consensus_state) = client_def
-        .check_header_and_update_state(client_state, header)
+        .check_header_and_update_state(ctx, client_id.clone(), client_state, header.clone())
         .map_err(|e| Kind::HeaderVerificationFailure.context(e.to_string()))?;
 
     let result = ClientResult::Update(Result {
         client_id: client_id.clone(),
         client_state: new_client_state,
         consensus_state: new_consensus_state,
+        processed_time: ctx.host_timestamp(),
+        processed_height: ctx.host_height(),
     });
 
     let event_attributes = Attributes {
         client_id,
+        client_type,
+        consensus_height: header.height(),
         ..Default::default()
     };
-    output.emit(IBCEvent::UpdateClient(event_attributes.into()));
+    output.emit(IBCEvent::UpdateClient(UpdateClient {
+        common: event_attributes,
+        header: Some(Box::new(header)),
+    }));
 
     Ok(output.with_result(result))
 }
@@ -76,9 +107,10 @@ mod tests {
     use crate::events::IBCEvent;
     use crate::handler::HandlerOutput;
     use crate::ics02_client::client_def::AnyClientState;
+    use crate::ics02_client::context::ClientKeeper;
     use crate::ics02_client::error::Kind;
     use crate::ics02_client::handler::dispatch;
-    use crate::ics02_client::handler::ClientResult::{Create, Update};
+    use crate::ics02_client::handler::ClientResult::Update;
     use crate::ics02_client::header::Header;
     use crate::ics02_client::msgs::update_client::MsgUpdateAnyClient;
     use crate::ics02_client::msgs::ClientMsg;
@@ -98,7 +130,7 @@ mod tests {
 
         let msg = MsgUpdateAnyClient {
             client_id: client_id.clone(),
-            header: MockHeader(Height::new(0, 46)).into(),
+            header: MockHeader::new(Height::new(0, 46)).into(),
             signer,
         };
 
@@ -122,10 +154,12 @@ mod tests {
                         assert_eq!(upd_res.client_id, client_id);
                         assert_eq!(
                             upd_res.client_state,
-                            AnyClientState::Mock(MockClientState(MockHeader(msg.header.height())))
+                            AnyClientState::Mock(MockClientState::new(MockHeader::new(
+                                msg.header.height()
+                            )))
                         )
                     }
-                    Create(_) => panic!("update handler result has type CreateResult"),
+                    _ => panic!("update handler result has type other than UpdateResult"),
                 }
             }
             Err(err) => {
@@ -143,7 +177,7 @@ mod tests {
 
         let msg = MsgUpdateAnyClient {
             client_id: ClientId::from_str("nonexistingclient").unwrap(),
-            header: MockHeader(Height::new(0, 46)).into(),
+            header: MockHeader::new(Height::new(0, 46)).into(),
             signer,
         };
 
@@ -159,6 +193,39 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_update_frozen_client() {
+        let client_id = ClientId::from_str("mockclient1").unwrap();
+        let signer = get_dummy_account_id();
+        let height = Height::new(0, 42);
+
+        let mut ctx = MockContext::default().with_client(&client_id, height);
+        ctx.store_client_state(
+            client_id.clone(),
+            MockClientState::new(MockHeader::new(height))
+                .with_frozen_height(height)
+                .into(),
+        )
+        .unwrap();
+
+        let msg = MsgUpdateAnyClient {
+            client_id: client_id.clone(),
+            header: MockHeader::new(Height::new(0, 46)).into(),
+            signer,
+        };
+
+        let output = dispatch(&ctx, ClientMsg::UpdateClient(msg));
+
+        match output {
+            Ok(_) => {
+                panic!("unexpected success (expected error)");
+            }
+            Err(err) => {
+                assert_eq!(err.kind(), &Kind::ClientFrozen(client_id));
+            }
+        }
+    }
+
     #[test]
     fn test_update_client_ok_multiple() {
         let client_ids = vec![
@@ -179,7 +246,7 @@ mod tests {
         for cid in &client_ids {
             let msg = MsgUpdateAnyClient {
                 client_id: cid.clone(),
-                header: MockHeader(update_height).into(),
+                header: MockHeader::new(update_height).into(),
                 signer,
             };
 
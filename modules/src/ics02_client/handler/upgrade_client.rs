@@ -0,0 +1,233 @@
+//! Protocol logic specific to processing ICS2 messages of type `MsgUpgradeAnyClient`.
+
+use tendermint::Time;
+
+use crate::events::IBCEvent;
+use crate::handler::{HandlerOutput, HandlerResult};
+use crate::ics02_client::client_def::{AnyClient, AnyClientState, AnyConsensusState, ClientDef};
+use crate::ics02_client::context::ClientReader;
+use crate::ics02_client::error::{Error, Kind};
+use crate::ics02_client::events::Attributes;
+use crate::ics02_client::handler::ClientResult;
+use crate::ics02_client::msgs::upgrade_client::MsgUpgradeAnyClient;
+use crate::ics02_client::state::ClientState;
+use crate::ics24_host::identifier::ClientId;
+use crate::Height;
+
+/// The result following the successful processing of a `MsgUpgradeAnyClient` message. Preferably
+/// this data type should be used with a qualified name `upgrade_client::Result` to avoid ambiguity.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Result {
+    pub client_id: ClientId,
+    pub client_state: AnyClientState,
+    pub consensus_state: AnyConsensusState,
+    /// The time and height of the host chain when the consensus state is stored (see
+    /// `create_client::Result`).
+    pub processed_time: Time,
+    pub processed_height: Height,
+}
+
+pub fn process(
+    ctx: &dyn ClientReader,
+    msg: MsgUpgradeAnyClient,
+) -> HandlerResult<ClientResult, Error> {
+    let mut output = HandlerOutput::builder();
+
+    let MsgUpgradeAnyClient {
+        client_id,
+        client_state: upgraded_client_state,
+        consensus_state: upgraded_consensus_state,
+        proof_upgrade_client,
+        proof_upgrade_consensus_state,
+        signer: _,
+    } = msg;
+
+    // Read client type from the host chain store. The client should already exist.
+    let client_type = ctx
+        .client_type(&client_id)
+        .ok_or_else(|| Kind::ClientNotFound(client_id.clone()))?;
+
+    let client_def = AnyClient::from_client_type(client_type);
+
+    // Read client state from the host chain store.
+    let client_state = ctx
+        .client_state(&client_id)
+        .ok_or_else(|| Kind::ClientNotFound(client_id.clone()))?;
+
+    // A frozen client cannot be upgraded.
+    if client_state.is_frozen() {
+        return Err(Kind::ClientFrozen(client_id).into());
+    }
+
+    // Verify that the chain committed to the upgraded states before the upgrade, obtaining the
+    // client state and consensus state with which the client continues. These will be later
+    // persisted by the keeper.
+    let (new_client_state, new_consensus_state) = client_def
+        .verify_upgrade_and_update_state(
+            ctx,
+            client_id.clone(),
+            client_state,
+            upgraded_client_state,
+            upgraded_consensus_state,
+            &proof_upgrade_client,
+            &proof_upgrade_consensus_state,
+        )
+        .map_err(|e| Kind::UpgradeVerificationFailure.context(e.to_string()))?;
+
+    let upgrade_height = new_client_state.latest_height();
+
+    let result = ClientResult::Upgrade(Result {
+        client_id: client_id.clone(),
+        client_state: new_client_state,
+        consensus_state: new_consensus_state,
+        processed_time: ctx.host_timestamp(),
+        processed_height: ctx.host_height(),
+    });
+
+    let event_attributes = Attributes {
+        client_id,
+        client_type,
+        consensus_height: upgrade_height,
+        ..Default::default()
+    };
+    output.emit(IBCEvent::UpgradeClient(event_attributes.into()));
+
+    Ok(output.with_result(result))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::events::IBCEvent;
+    use crate::ics02_client::client_def::{AnyClientState, AnyConsensusState};
+    use crate::ics02_client::context::ClientKeeper;
+    use crate::ics02_client::handler::{dispatch, ClientResult};
+    use crate::ics02_client::msgs::upgrade_client::MsgUpgradeAnyClient;
+    use crate::ics02_client::msgs::ClientMsg;
+    use crate::ics07_tendermint::client_state::test_util::get_dummy_tendermint_client_state;
+    use crate::ics07_tendermint::header::test_util::get_dummy_tendermint_header;
+    use crate::ics24_host::identifier::ClientId;
+    use crate::mock::client_state::{MockClientState, MockConsensusState};
+    use crate::mock::context::MockContext;
+    use crate::mock::header::MockHeader;
+    use crate::test_utils::get_dummy_account_id;
+    use crate::Height;
+
+    #[test]
+    fn upgrade_client_processing() {
+        struct Test {
+            name: String,
+            ctx: MockContext,
+            msg: MsgUpgradeAnyClient,
+            want_pass: bool,
+        }
+
+        let client_id = ClientId::from_str("mockclient1").unwrap();
+        let other_client_id = ClientId::from_str("mockclient2").unwrap();
+        let client_height = Height::new(0, 42);
+        let upgrade_height = Height::new(1, 1);
+
+        let ctx = MockContext::default().with_client(&client_id, client_height);
+
+        let mut frozen_ctx = ctx.clone();
+        frozen_ctx
+            .store_client_state(
+                client_id.clone(),
+                MockClientState::new(MockHeader::new(client_height))
+                    .with_frozen_height(client_height)
+                    .into(),
+            )
+            .unwrap();
+
+        let msg = |client_id: &ClientId, height: Height| {
+            MsgUpgradeAnyClient::new(
+                client_id.clone(),
+                MockClientState::new(MockHeader::new(height)).into(),
+                MockConsensusState::new(MockHeader::new(height)).into(),
+                b"proof of client state".to_vec().into(),
+                b"proof of consensus state".to_vec().into(),
+                get_dummy_account_id(),
+            )
+            .unwrap()
+        };
+
+        let tm_header = get_dummy_tendermint_header();
+
+        let tests: Vec<Test> = vec![
+            Test {
+                name: "Good parameters".to_string(),
+                ctx: ctx.clone(),
+                msg: msg(&client_id, upgrade_height),
+                want_pass: true,
+            },
+            Test {
+                name: "Upgraded height is not greater than the client height".to_string(),
+                ctx: ctx.clone(),
+                msg: msg(&client_id, client_height),
+                want_pass: false,
+            },
+            Test {
+                name: "Client does not exist".to_string(),
+                ctx: ctx.clone(),
+                msg: msg(&other_client_id, upgrade_height),
+                want_pass: false,
+            },
+            Test {
+                name: "Client is frozen".to_string(),
+                ctx: frozen_ctx,
+                msg: msg(&client_id, upgrade_height),
+                want_pass: false,
+            },
+            Test {
+                name: "Upgraded states are of another client type".to_string(),
+                ctx,
+                msg: MsgUpgradeAnyClient::new(
+                    client_id.clone(),
+                    get_dummy_tendermint_client_state(tm_header.clone()),
+                    AnyConsensusState::Tendermint(tm_header.into()),
+                    b"proof of client state".to_vec().into(),
+                    b"proof of consensus state".to_vec().into(),
+                    get_dummy_account_id(),
+                )
+                .unwrap(),
+                want_pass: false,
+            },
+        ];
+
+        for test in tests {
+            let res = dispatch(&test.ctx, ClientMsg::UpgradeClient(test.msg.clone()));
+
+            // Additionally check the events and the upgraded client and consensus states.
+            if let Ok(ref output) = res {
+                assert_eq!(output.events.len(), 1);
+                assert!(matches!(
+                    &output.events[0],
+                    IBCEvent::UpgradeClient(e) if e.client_id() == &test.msg.client_id
+                ));
+
+                match &output.result {
+                    ClientResult::Upgrade(res) => {
+                        assert_eq!(res.client_id, test.msg.client_id);
+                        assert_eq!(res.client_state, test.msg.client_state);
+                        assert_eq!(res.consensus_state, test.msg.consensus_state);
+                        assert!(matches!(
+                            res.client_state,
+                            AnyClientState::Mock(state) if state.latest_height() == upgrade_height
+                        ));
+                    }
+                    _ => panic!("upgrade handler result has type other than UpgradeResult"),
+                }
+            }
+
+            assert_eq!(
+                test.want_pass,
+                res.is_ok(),
+                "upgrade_client: test {}, \nmsg {:?} error: {:?}",
+                test.name,
+                test.msg,
+                res.err()
+            );
+        }
+    }
+}
@@ -0,0 +1,18 @@
+use crate::ics24_host::identifier::ClientId;
+use crate::Height;
+
+use super::client_def::AnyMisbehaviour;
+
+/// Evidence that a client's counterparty chain has misbehaved, e.g., that it signed two
+/// conflicting headers
+#[dyn_clonable::clonable]
+pub trait Misbehaviour: Clone + std::fmt::Debug + Send + Sync {
+    /// The identifier of the client which the evidence is submitted to
+    fn client_id(&self) -> &ClientId;
+
+    /// The height of the consensus state at which the misbehaviour occurred
+    fn height(&self) -> Height;
+
+    /// Wrap into an `AnyMisbehaviour`
+    fn wrap_any(self) -> AnyMisbehaviour;
+}
@@ -8,5 +8,6 @@ pub mod events;
 pub mod handler;
 pub mod header;
 pub mod height;
+pub mod misbehaviour;
 pub mod msgs;
 pub mod state;
@@ -5,14 +5,20 @@
 //! https://github.com/cosmos/ics/tree/master/spec/ics-002-client-semantics#create.
 
 use crate::ics02_client::msgs::create_client::MsgCreateAnyClient;
+use crate::ics02_client::msgs::misbehaviour::MsgSubmitAnyMisbehaviour;
 use crate::ics02_client::msgs::update_client::MsgUpdateAnyClient;
+use crate::ics02_client::msgs::upgrade_client::MsgUpgradeAnyClient;
 
 pub mod create_client;
+pub mod misbehaviour;
 pub mod update_client;
+pub mod upgrade_client;
 
 #[allow(clippy::large_enum_variant)]
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum ClientMsg {
     CreateClient(MsgCreateAnyClient),
     UpdateClient(MsgUpdateAnyClient),
+    Misbehaviour(MsgSubmitAnyMisbehaviour),
+    UpgradeClient(MsgUpgradeAnyClient),
 }
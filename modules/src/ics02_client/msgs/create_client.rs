@@ -119,6 +119,11 @@ mod tests {
     use crate::ics07_tendermint::header::test_util::get_dummy_tendermint_header;
     use crate::test_utils::get_dummy_account_id;
 
+    use crate::ics02_client::msgs::create_client::TYPE_URL;
+    use crate::test::test_msg_roundtrip;
+    use ibc_proto::ibc::core::client::v1::MsgCreateClient as RawMsgCreateClient;
+    use proptest::prelude::*;
+
     #[test]
     fn msg_create_client_serialization() {
         let signer = get_dummy_account_id();
@@ -139,4 +144,11 @@ mod tests {
         assert_eq!(msg, msg_back);
         assert_eq!(raw, raw_back);
     }
+
+    proptest! {
+        #[test]
+        fn roundtrip(msg in any::<MsgCreateAnyClient>()) {
+            test_msg_roundtrip::<_, RawMsgCreateClient>(msg, TYPE_URL);
+        }
+    }
 }
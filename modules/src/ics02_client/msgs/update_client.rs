@@ -63,8 +63,11 @@ impl TryFrom<RawMsgUpdateClient> for MsgUpdateAnyClient {
         let signer = string_to_account(raw.signer).map_err(|e| Kind::InvalidAddress.context(e))?;
 
         Ok(MsgUpdateAnyClient {
-            client_id: raw.client_id.parse().unwrap(),
-            header: AnyHeader::try_from(raw_header).unwrap(),
+            client_id: raw
+                .client_id
+                .parse()
+                .map_err(|e| Kind::InvalidIdentifier.context(e))?,
+            header: AnyHeader::try_from(raw_header)?,
             signer,
         })
     }
@@ -86,13 +89,21 @@ mod tests {
 
     use ibc_proto::ibc::core::client::v1::MsgUpdateClient;
 
+    use prost_types::Any;
+
     use crate::ics02_client::client_def::AnyHeader;
+    use crate::ics02_client::error::Kind;
     use crate::ics02_client::msgs::MsgUpdateAnyClient;
     use crate::ics24_host::identifier::ClientId;
 
     use crate::ics07_tendermint::header::test_util::get_dummy_ics07_header;
     use crate::test_utils::get_dummy_account_id;
 
+    use crate::ics02_client::msgs::update_client::TYPE_URL;
+    use crate::test::test_msg_roundtrip;
+    use ibc_proto::ibc::core::client::v1::MsgUpdateClient as RawMsgUpdateClient;
+    use proptest::prelude::*;
+
     #[test]
     fn msg_update_client_serialization() {
         let client_id: ClientId = "tendermint".parse().unwrap();
@@ -107,4 +118,49 @@ mod tests {
         assert_eq!(msg, msg_back);
         assert_eq!(raw, raw_back);
     }
+
+    #[test]
+    fn malformed_msg_update_client() {
+        let client_id: ClientId = "tendermint".parse().unwrap();
+        let header = AnyHeader::Tendermint(get_dummy_ics07_header());
+        let raw = MsgUpdateClient::from(MsgUpdateAnyClient::new(
+            client_id,
+            header,
+            get_dummy_account_id(),
+        ));
+
+        // The conversion fails, instead of panicking, on a malformed client id or header.
+        let err = MsgUpdateAnyClient::try_from(MsgUpdateClient {
+            client_id: "c".to_string(),
+            ..raw.clone()
+        })
+        .unwrap_err();
+        assert!(matches!(err.kind(), Kind::InvalidIdentifier));
+
+        let err = MsgUpdateAnyClient::try_from(MsgUpdateClient {
+            header: Some(Any {
+                type_url: "/unknown.Header".to_string(),
+                value: vec![],
+            }),
+            ..raw.clone()
+        })
+        .unwrap_err();
+        assert!(matches!(err.kind(), Kind::UnknownHeaderType(_)));
+
+        let mut garbled_header = raw.header.clone().unwrap();
+        garbled_header.value = vec![0xff; 4];
+        let err = MsgUpdateAnyClient::try_from(MsgUpdateClient {
+            header: Some(garbled_header),
+            ..raw
+        })
+        .unwrap_err();
+        assert!(matches!(err.kind(), Kind::InvalidRawHeader));
+    }
+
+    proptest! {
+        #[test]
+        fn roundtrip(msg in any::<MsgUpdateAnyClient>()) {
+            test_msg_roundtrip::<_, RawMsgUpdateClient>(msg, TYPE_URL);
+        }
+    }
 }
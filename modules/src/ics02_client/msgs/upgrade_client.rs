@@ -0,0 +1,200 @@
+//! Definition of the message that a relayer submits to a chain to upgrade one of its clients,
+//! after the chain tracked by the client has gone through a planned upgrade. See:
+//! https://github.com/cosmos/ics/tree/master/spec/ics-002-client-semantics#upgrades.
+
+use std::convert::TryFrom;
+
+use tendermint::account::Id as AccountId;
+use tendermint_proto::Protobuf;
+
+use ibc_proto::ibc::core::client::v1::MsgUpgradeClient as RawMsgUpgradeClient;
+
+use crate::address::{account_to_string, string_to_account};
+use crate::ics02_client::client_def::{AnyClientState, AnyConsensusState};
+use crate::ics02_client::error::{Error, Kind};
+use crate::ics23_commitment::commitment::CommitmentProofBytes;
+use crate::ics24_host::identifier::ClientId;
+use crate::tx_msg::Msg;
+
+pub const TYPE_URL: &str = "/ibc.core.client.v1.MsgUpgradeClient";
+
+/// A type of message that upgrades an on-chain (IBC) client to the client and consensus states
+/// which the tracked chain committed to before its upgrade.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MsgUpgradeAnyClient {
+    /// client unique identifier
+    pub client_id: ClientId,
+    /// upgraded client state
+    pub client_state: AnyClientState,
+    /// upgraded consensus state
+    pub consensus_state: AnyConsensusState,
+    /// proof that the chain committed to the upgraded client state
+    pub proof_upgrade_client: CommitmentProofBytes,
+    /// proof that the chain committed to the upgraded consensus state
+    pub proof_upgrade_consensus_state: CommitmentProofBytes,
+    /// signer address
+    pub signer: AccountId,
+}
+
+impl MsgUpgradeAnyClient {
+    pub fn new(
+        client_id: ClientId,
+        client_state: AnyClientState,
+        consensus_state: AnyConsensusState,
+        proof_upgrade_client: CommitmentProofBytes,
+        proof_upgrade_consensus_state: CommitmentProofBytes,
+        signer: AccountId,
+    ) -> Result<Self, Error> {
+        if client_state.client_type() != consensus_state.client_type() {
+            return Err(Kind::RawClientAndConsensusStateTypesMismatch {
+                state_type: client_state.client_type(),
+                consensus_type: consensus_state.client_type(),
+            }
+            .into());
+        }
+        if proof_upgrade_client.is_empty() {
+            return Err(Kind::InvalidUpgradeClientProof
+                .context("empty proof")
+                .into());
+        }
+        if proof_upgrade_consensus_state.is_empty() {
+            return Err(Kind::InvalidUpgradeConsensusStateProof
+                .context("empty proof")
+                .into());
+        }
+
+        Ok(MsgUpgradeAnyClient {
+            client_id,
+            client_state,
+            consensus_state,
+            proof_upgrade_client,
+            proof_upgrade_consensus_state,
+            signer,
+        })
+    }
+}
+
+impl Msg for MsgUpgradeAnyClient {
+    type ValidationError = crate::ics24_host::error::ValidationError;
+
+    fn route(&self) -> String {
+        crate::keys::ROUTER_KEY.to_string()
+    }
+
+    fn type_url(&self) -> String {
+        TYPE_URL.to_string()
+    }
+
+    fn get_signers(&self) -> Vec<AccountId> {
+        vec![self.signer]
+    }
+}
+
+impl Protobuf<RawMsgUpgradeClient> for MsgUpgradeAnyClient {}
+
+impl TryFrom<RawMsgUpgradeClient> for MsgUpgradeAnyClient {
+    type Error = Error;
+
+    fn try_from(raw: RawMsgUpgradeClient) -> Result<Self, Self::Error> {
+        let raw_client_state = raw
+            .client_state
+            .ok_or_else(|| Kind::InvalidRawClientState.context("missing client state"))?;
+
+        let raw_consensus_state = raw
+            .consensus_state
+            .ok_or_else(|| Kind::InvalidRawConsensusState.context("missing consensus state"))?;
+
+        let signer = string_to_account(raw.signer).map_err(|e| Kind::InvalidAddress.context(e))?;
+
+        MsgUpgradeAnyClient::new(
+            raw.client_id
+                .parse()
+                .map_err(|e| Kind::InvalidIdentifier.context(e))?,
+            AnyClientState::try_from(raw_client_state)
+                .map_err(|e| Kind::InvalidRawClientState.context(e))?,
+            AnyConsensusState::try_from(raw_consensus_state)
+                .map_err(|e| Kind::InvalidRawConsensusState.context(e))?,
+            raw.proof_upgrade_client.into(),
+            raw.proof_upgrade_consensus_state.into(),
+            signer,
+        )
+    }
+}
+
+impl From<MsgUpgradeAnyClient> for RawMsgUpgradeClient {
+    fn from(ics_msg: MsgUpgradeAnyClient) -> Self {
+        RawMsgUpgradeClient {
+            client_id: ics_msg.client_id.to_string(),
+            client_state: Some(ics_msg.client_state.into()),
+            consensus_state: Some(ics_msg.consensus_state.into()),
+            proof_upgrade_client: ics_msg.proof_upgrade_client.into(),
+            proof_upgrade_consensus_state: ics_msg.proof_upgrade_consensus_state.into(),
+            signer: account_to_string(ics_msg.signer).unwrap(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use ibc_proto::ibc::core::client::v1::MsgUpgradeClient;
+
+    use crate::ics02_client::client_def::AnyConsensusState;
+    use crate::ics02_client::msgs::upgrade_client::MsgUpgradeAnyClient;
+    use crate::ics07_tendermint::client_state::test_util::get_dummy_tendermint_client_state;
+    use crate::ics07_tendermint::header::test_util::get_dummy_tendermint_header;
+    use crate::ics24_host::identifier::ClientId;
+    use crate::test_utils::get_dummy_account_id;
+
+    use crate::ics02_client::msgs::upgrade_client::TYPE_URL;
+    use crate::test::test_msg_roundtrip;
+    use ibc_proto::ibc::core::client::v1::MsgUpgradeClient as RawMsgUpgradeClient;
+    use proptest::prelude::*;
+
+    #[test]
+    fn msg_upgrade_client_serialization() {
+        let signer = get_dummy_account_id();
+
+        let tm_header = get_dummy_tendermint_header();
+        let tm_client_state = get_dummy_tendermint_client_state(tm_header.clone());
+
+        let msg = MsgUpgradeAnyClient::new(
+            ClientId::default(),
+            tm_client_state,
+            AnyConsensusState::Tendermint(tm_header.into()),
+            b"proof of client state".to_vec().into(),
+            b"proof of consensus state".to_vec().into(),
+            signer,
+        )
+        .unwrap();
+
+        let raw = MsgUpgradeClient::from(msg.clone());
+        let msg_back = MsgUpgradeAnyClient::try_from(raw.clone()).unwrap();
+        let raw_back = MsgUpgradeClient::from(msg_back.clone());
+        assert_eq!(msg, msg_back);
+        assert_eq!(raw, raw_back);
+    }
+
+    #[test]
+    fn msg_upgrade_client_without_proofs() {
+        let tm_header = get_dummy_tendermint_header();
+
+        let res = MsgUpgradeAnyClient::new(
+            ClientId::default(),
+            get_dummy_tendermint_client_state(tm_header.clone()),
+            AnyConsensusState::Tendermint(tm_header.into()),
+            vec![].into(),
+            b"proof of consensus state".to_vec().into(),
+            get_dummy_account_id(),
+        );
+        assert!(res.is_err());
+    }
+
+    proptest! {
+        #[test]
+        fn roundtrip(msg in any::<MsgUpgradeAnyClient>()) {
+            test_msg_roundtrip::<_, RawMsgUpgradeClient>(msg, TYPE_URL);
+        }
+    }
+}
@@ -3,6 +3,7 @@ use super::{
     client_type::ClientType,
 };
 use crate::ics23_commitment::commitment::CommitmentRoot;
+use crate::timestamp::Timestamp;
 use crate::Height;
 
 #[dyn_clonable::clonable]
@@ -13,6 +14,10 @@ pub trait ConsensusState: Clone + std::fmt::Debug + Send + Sync {
     /// Commitment root of the consensus state, which is used for key-value pair verification.
     fn root(&self) -> &CommitmentRoot;
 
+    /// Time of the tracked chain at the height of this consensus state, against which packet
+    /// timeout timestamps are checked.
+    fn timestamp(&self) -> Timestamp;
+
     /// Performs basic validation of the consensus state
     fn validate_basic(&self) -> Result<(), Box<dyn std::error::Error>>;
 
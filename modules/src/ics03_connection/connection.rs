@@ -20,6 +20,8 @@ pub struct ConnectionEnd {
     client_id: ClientId,
     counterparty: Counterparty,
     versions: Vec<Version>,
+    /// The delay period (in nanoseconds) that packet proofs must wait for, once the consensus
+    /// state they are verified against is stored, before they are accepted.
     pub(crate) delay_period: u64,
 }
 
@@ -76,7 +78,7 @@ impl From<ConnectionEnd> for RawConnectionEnd {
                 .collect(),
             state: value.state as i32,
             counterparty: Some(value.counterparty.into()),
-            delay_period: 0,
+            delay_period: value.delay_period,
         }
     }
 }
@@ -148,6 +150,11 @@ impl ConnectionEnd {
         self.counterparty.clone()
     }
 
+    /// Getter for the delay period (in nanoseconds) of this connection end.
+    pub fn delay_period(&self) -> u64 {
+        self.delay_period
+    }
+
     /// TODO: Clean this up, probably not necessary.
     pub fn validate_basic(&self) -> Result<(), ValidationError> {
         self.counterparty.validate_basic()
@@ -2,6 +2,7 @@
 //! the interface that any host chain must implement to be able to process any `ConnectionMsg`.
 //! See "ADR 003: IBC protocol implementation" for more details.
 
+use crate::gas::GasMeter;
 use crate::ics02_client::client_def::{AnyClientState, AnyConsensusState};
 use crate::ics03_connection::connection::{ConnectionEnd, State};
 use crate::ics03_connection::error::Error;
@@ -12,7 +13,7 @@ use crate::ics24_host::identifier::{ClientId, ConnectionId};
 use crate::Height;
 
 /// A context supplying all the necessary read-only dependencies for processing any `ConnectionMsg`.
-pub trait ConnectionReader {
+pub trait ConnectionReader: GasMeter {
     /// Returns the ConnectionEnd for the given identifier `conn_id`.
     fn connection_end(&self, conn_id: &ConnectionId) -> Option<ConnectionEnd>;
 
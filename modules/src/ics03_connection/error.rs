@@ -3,6 +3,7 @@ use anomaly::{BoxError, Context};
 use thiserror::Error;
 pub type Error = anomaly::Error<Kind>;
 
+use crate::gas::OutOfGas;
 use crate::ics24_host::identifier::{ClientId, ConnectionId};
 use crate::Height;
 
@@ -91,6 +92,9 @@ pub enum Kind {
 
     #[error("the client state proof verification failed for client id: {0}")]
     ClientStateVerificationFailure(ClientId),
+
+    #[error("{0}")]
+    OutOfGas(OutOfGas),
 }
 
 impl Kind {
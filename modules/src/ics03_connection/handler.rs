@@ -1,9 +1,10 @@
 //! This module implements the processing logic for ICS3 (connection open handshake) messages.
 
 use crate::handler::HandlerOutput;
+use crate::ics02_client::client_def::AnyClientState;
 use crate::ics03_connection::connection::ConnectionEnd;
 use crate::ics03_connection::context::ConnectionReader;
-use crate::ics03_connection::error::Error;
+use crate::ics03_connection::error::{Error, Kind};
 use crate::ics03_connection::msgs::ConnectionMsg;
 use crate::ics24_host::identifier::ConnectionId;
 
@@ -17,6 +18,9 @@ mod verify;
 pub struct ConnectionResult {
     pub connection_id: Option<ConnectionId>,
     pub connection_end: ConnectionEnd,
+    /// The new state of the client of the connection, if verifying the proofs of the message
+    /// updated it (see `ClientDef`).
+    pub client_state: Option<AnyClientState>,
 }
 
 /// General entry point for processing any type of message related to the ICS3 connection open
@@ -28,10 +32,16 @@ pub fn dispatch<Ctx>(
 where
     Ctx: ConnectionReader,
 {
-    match msg {
+    let output = match msg {
         ConnectionMsg::ConnectionOpenInit(msg) => conn_open_init::process(ctx, msg),
         ConnectionMsg::ConnectionOpenTry(msg) => conn_open_try::process(ctx, *msg),
         ConnectionMsg::ConnectionOpenAck(msg) => conn_open_ack::process(ctx, *msg),
         ConnectionMsg::ConnectionOpenConfirm(msg) => conn_open_confirm::process(ctx, msg),
-    }
+    }?;
+
+    // The events emitted by the handler are charged once it succeeded.
+    ctx.consume_gas(ctx.gas_costs().events(output.events.len()), "events")
+        .map_err(Kind::OutOfGas)?;
+
+    Ok(output)
 }
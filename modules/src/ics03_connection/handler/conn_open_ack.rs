@@ -67,7 +67,7 @@ pub(crate) fn process(
     );
 
     // 2. Pass the details to the verification function.
-    verify_proofs(
+    let client_state = verify_proofs(
         ctx,
         msg.client_state(),
         &new_conn_end,
@@ -83,6 +83,7 @@ pub(crate) fn process(
     let result = ConnectionResult {
         connection_end: new_conn_end,
         connection_id: Some(msg.connection_id().clone()),
+        client_state,
     };
 
     let event_attributes = Attributes {
@@ -52,7 +52,7 @@ pub(crate) fn process(
     );
 
     // 2. Pass the details to the verification function.
-    verify_proofs(ctx, None, &new_conn_end, &expected_conn, msg.proofs())?;
+    let client_state = verify_proofs(ctx, None, &new_conn_end, &expected_conn, msg.proofs())?;
 
     output.log("success: connection verification passed");
 
@@ -62,6 +62,7 @@ pub(crate) fn process(
     let result = ConnectionResult {
         connection_id: Some(msg.connection_id().clone()),
         connection_end: new_conn_end,
+        client_state,
     };
 
     let event_attributes = Attributes {
@@ -81,6 +82,7 @@ mod tests {
     use crate::events::IBCEvent;
     use crate::ics03_connection::connection::{ConnectionEnd, Counterparty, State};
     use crate::ics03_connection::context::ConnectionReader;
+    use crate::ics03_connection::error::Kind;
     use crate::ics03_connection::handler::{dispatch, ConnectionResult};
     use crate::ics03_connection::msgs::conn_open_confirm::test_util::get_dummy_msg_conn_open_confirm;
     use crate::ics03_connection::msgs::conn_open_confirm::MsgConnectionOpenConfirm;
@@ -189,4 +191,29 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn conn_open_confirm_without_counterparty_connection_id() {
+        let client_id = ClientId::from_str("mock_clientid").unwrap();
+        let msg_confirm =
+            MsgConnectionOpenConfirm::try_from(get_dummy_msg_conn_open_confirm()).unwrap();
+
+        // The connection end does not know the connection id on the counterparty chain, hence the
+        // proof of the counterparty connection end cannot be verified.
+        let context = MockContext::default();
+        let conn_end = ConnectionEnd::new(
+            State::TryOpen,
+            client_id.clone(),
+            Counterparty::new(client_id.clone(), None, CommitmentPrefix::from(vec![])),
+            context.get_compatible_versions(),
+            0,
+        );
+        let context = context
+            .with_client(&client_id, Height::new(0, 10))
+            .with_connection(msg_confirm.connection_id().clone(), conn_end);
+
+        let err =
+            dispatch(&context, ConnectionMsg::ConnectionOpenConfirm(msg_confirm)).unwrap_err();
+        assert_eq!(err.kind(), &Kind::InvalidCounterparty);
+    }
 }
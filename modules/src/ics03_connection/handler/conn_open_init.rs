@@ -33,6 +33,7 @@ pub(crate) fn process(
     let result = ConnectionResult {
         connection_id: None,
         connection_end: new_connection_end,
+        client_state: None,
     };
 
     let event_attributes = Attributes {
@@ -64,7 +64,7 @@ pub(crate) fn process(
     );
 
     // 2. Pass the details to the verification function.
-    verify_proofs(
+    let client_state = verify_proofs(
         ctx,
         msg.client_state(),
         &new_connection_end,
@@ -88,6 +88,7 @@ pub(crate) fn process(
     let result = ConnectionResult {
         connection_id: msg.previous_connection_id().clone(),
         connection_end: new_connection_end,
+        client_state,
     };
 
     // TODO: move connection id decision (`next_connection_id` method) in ClientReader
@@ -104,15 +105,28 @@ pub(crate) fn process(
 #[cfg(test)]
 mod tests {
     use std::convert::TryFrom;
+    use std::str::FromStr;
+
+    use ibc_proto::ibc::core::client::v1::Height as RawHeight;
+    use ibc_proto::ibc::core::commitment::v1::MerklePrefix;
+    use ibc_proto::ibc::core::connection::v1::Counterparty as RawCounterparty;
 
     use crate::events::IBCEvent;
-    use crate::ics03_connection::connection::State;
+    use crate::ics02_client::client_def::AnyHeader;
+    use crate::ics02_client::client_type::ClientType;
+    use crate::ics02_client::context::ClientReader;
+    use crate::ics03_connection::connection::{ConnectionEnd, Counterparty, State};
     use crate::ics03_connection::context::ConnectionReader;
     use crate::ics03_connection::handler::{dispatch, ConnectionResult};
     use crate::ics03_connection::msgs::conn_open_try::test_util::get_dummy_msg_conn_open_try;
     use crate::ics03_connection::msgs::conn_open_try::MsgConnectionOpenTry;
     use crate::ics03_connection::msgs::ConnectionMsg;
-    use crate::ics24_host::identifier::ChainId;
+    use crate::ics03_connection::version::get_compatible_versions;
+    use crate::ics18_relayer::context::ICS18Context;
+    use crate::ics18_relayer::utils::build_client_update_datagram;
+    use crate::ics24_host::identifier::{ChainId, ClientId, ConnectionId};
+    use crate::ics24_host::Path;
+    use crate::ics26_routing::msgs::ICS26Envelope;
     use crate::mock::context::MockContext;
     use crate::mock::host::HostType;
     use crate::Height;
@@ -244,4 +258,135 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn conn_open_try_with_tendermint_proofs() {
+        struct Test {
+            name: String,
+            msg: MsgConnectionOpenTry,
+            want_pass: bool,
+        }
+
+        let chain_id_a = ChainId::new("mockgaiaA".to_string(), 1);
+        let client_on_a_for_b = ClientId::new(ClientType::Mock, 0).unwrap();
+        let client_on_b_for_a = ClientId::new(ClientType::Tendermint, 0).unwrap();
+        let conn_id_on_a = ConnectionId::from_str("connection-0").unwrap();
+        let consensus_height = Height::new(1, 4);
+
+        // The chain handling the message (B) has a Tendermint client for chain A.
+        let mut ctx_b = MockContext::default().with_client_parametrized_with_chain_id(
+            chain_id_a.clone(),
+            &client_on_b_for_a,
+            Height::new(1, 5),
+            Some(ClientType::Tendermint),
+            None,
+        );
+
+        // Chain A initializes the connection, on top of its client for chain B.
+        let mut ctx_a = MockContext::new(
+            chain_id_a,
+            HostType::SyntheticTendermint,
+            5,
+            Height::new(1, 5),
+        )
+        .with_client(&client_on_a_for_b, consensus_height);
+        let conn_end_on_a = ConnectionEnd::new(
+            State::Init,
+            client_on_a_for_b.clone(),
+            Counterparty::new(client_on_b_for_a.clone(), None, ctx_b.commitment_prefix()),
+            get_compatible_versions(),
+            0,
+        );
+        ctx_a = ctx_a.with_connection(conn_id_on_a.clone(), conn_end_on_a);
+        ctx_a.advance_host_chain_height();
+        let proof_height = ctx_a.query_latest_height();
+
+        // Update the client on B to the height at which A committed to the connection.
+        let mut header = ctx_a.query_latest_header().unwrap();
+        if let AnyHeader::Tendermint(header) = &mut header {
+            header.trusted_height = Height::new(1, 5);
+        }
+        let update_msg = build_client_update_datagram(&ctx_b, &client_on_b_for_a, header).unwrap();
+        ctx_b.deliver(ICS26Envelope::ICS2Msg(update_msg)).unwrap();
+
+        let mut raw_msg = get_dummy_msg_conn_open_try(0, 0);
+        raw_msg.client_id = client_on_b_for_a.to_string();
+        raw_msg.previous_connection_id = "".to_string();
+        raw_msg.client_state =
+            ClientReader::client_state(&ctx_a, &client_on_a_for_b).map(Into::into);
+        raw_msg.counterparty = Some(RawCounterparty {
+            client_id: client_on_a_for_b.to_string(),
+            connection_id: conn_id_on_a.to_string(),
+            prefix: Some(MerklePrefix {
+                key_prefix: ctx_a.commitment_prefix().into_vec(),
+            }),
+        });
+        raw_msg.proof_height = Some(RawHeight {
+            revision_number: proof_height.revision_number,
+            revision_height: proof_height.revision_height,
+        });
+        raw_msg.consensus_height = Some(RawHeight {
+            revision_number: consensus_height.revision_number,
+            revision_height: consensus_height.revision_height,
+        });
+
+        let proof = |path: Path| ctx_a.query_proof(proof_height, &path).unwrap().into();
+        raw_msg.proof_init = proof(Path::Connections(conn_id_on_a.clone()));
+        raw_msg.proof_client = proof(Path::ClientState(client_on_a_for_b.clone()));
+        raw_msg.proof_consensus = proof(Path::ClientConsensusState {
+            client_id: client_on_a_for_b.clone(),
+            epoch: consensus_height.revision_number,
+            height: consensus_height.revision_height,
+        });
+
+        let mut other_client_state = raw_msg.clone();
+        other_client_state.client_state = ClientReader::client_state(
+            &MockContext::default().with_client(&client_on_a_for_b, Height::new(1, 3)),
+            &client_on_a_for_b,
+        )
+        .map(Into::into);
+
+        let mut other_consensus_height = raw_msg.clone();
+        other_consensus_height.consensus_height = Some(RawHeight {
+            revision_number: 1,
+            revision_height: 3,
+        });
+
+        let mut other_connection = raw_msg.clone();
+        other_connection.delay_period = 1;
+
+        let tests: Vec<Test> = vec![
+            Test {
+                name: "The state of chain A is proven against its root".to_string(),
+                msg: MsgConnectionOpenTry::try_from(raw_msg).unwrap(),
+                want_pass: true,
+            },
+            Test {
+                name: "Chain A does not store the given client state".to_string(),
+                msg: MsgConnectionOpenTry::try_from(other_client_state).unwrap(),
+                want_pass: false,
+            },
+            Test {
+                name: "Chain A has no consensus state at the given height".to_string(),
+                msg: MsgConnectionOpenTry::try_from(other_consensus_height).unwrap(),
+                want_pass: false,
+            },
+            Test {
+                name: "Chain A initialized a connection with another delay period".to_string(),
+                msg: MsgConnectionOpenTry::try_from(other_connection).unwrap(),
+                want_pass: false,
+            },
+        ];
+
+        for test in tests {
+            let res = dispatch(&ctx_b, ConnectionMsg::ConnectionOpenTry(Box::new(test.msg)));
+            assert_eq!(
+                test.want_pass,
+                res.is_ok(),
+                "conn_open_try: test {} failed with result {:?}",
+                test.name,
+                res.err()
+            );
+        }
+    }
 }
@@ -1,6 +1,6 @@
 //! ICS3 verification functions, common across all four handlers of ICS3.
 
-use crate::ics02_client::client_def::AnyClientState;
+use crate::ics02_client::client_def::{AnyClientState, AnyConsensusState};
 use crate::ics02_client::state::{ClientState, ConsensusState};
 use crate::ics02_client::{client_def::AnyClient, client_def::ClientDef};
 use crate::ics03_connection::connection::ConnectionEnd;
@@ -10,26 +10,67 @@ use crate::ics23_commitment::commitment::CommitmentProofBytes;
 use crate::proofs::{ConsensusProof, Proofs};
 use crate::Height;
 
-/// Entry point for verifying all proofs bundled in any ICS3 message.
+/// Entry point for verifying all proofs bundled in any ICS3 message. Returns the state of the client
+/// of the connection if verifying the proofs updated it (see `ClientDef`), for the host chain to
+/// store.
 pub fn verify_proofs(
     ctx: &dyn ConnectionReader,
     client_state: Option<AnyClientState>,
     connection_end: &ConnectionEnd,
     expected_conn: &ConnectionEnd,
     proofs: &Proofs,
-) -> Result<(), Error> {
-    verify_connection_proof(
+) -> Result<Option<AnyClientState>, Error> {
+    // Fetch the client state (IBC client on the local/host chain).
+    let mut local_client_state = ctx
+        .client_state(connection_end.client_id())
+        .ok_or_else(|| Kind::MissingClient(connection_end.client_id().clone()))?;
+
+    // The client must not be frozen.
+    if local_client_state.is_frozen() {
+        return Err(Kind::FrozenClient(connection_end.client_id().clone()).into());
+    }
+
+    // The client must have the consensus state for the height where the proofs were created,
+    // unless it keeps its consensus state within its client state.
+    let client_def = AnyClient::from_client_type(local_client_state.client_type());
+    let consensus_state = match client_def.embedded_consensus_state(&local_client_state) {
+        Some(consensus_state) => consensus_state,
+        None => ctx
+            .client_consensus_state(connection_end.client_id(), proofs.height())
+            .ok_or_else(|| {
+                Kind::MissingClientConsensusState(
+                    proofs.height(),
+                    connection_end.client_id().clone(),
+                )
+            })?,
+    };
+
+    // Every proof is verified with the client state left by the verification of the previous one.
+    let mut updated = false;
+    let mut update = |local_client_state: &mut AnyClientState, new_state| {
+        if let Some(new_state) = new_state {
+            *local_client_state = new_state;
+            updated = true;
+        }
+    };
+
+    let new_state = verify_connection_proof(
         ctx,
+        &local_client_state,
+        &consensus_state,
         connection_end,
         expected_conn,
         proofs.height(),
         proofs.object_proof(),
     )?;
+    update(&mut local_client_state, new_state);
 
     // If the message includes a client state, then verify the proof for that state.
     if let Some(expected_client_state) = client_state {
-        verify_client_proof(
+        let new_state = verify_client_proof(
             ctx,
+            &local_client_state,
+            &consensus_state,
             connection_end,
             expected_client_state,
             proofs.height(),
@@ -38,19 +79,27 @@ pub fn verify_proofs(
                 .as_ref()
                 .ok_or(Kind::NullClientProof)?,
         )?;
+        update(&mut local_client_state, new_state);
     }
 
     // If a consensus proof is attached to the message, then verify it.
     if let Some(proof) = proofs.consensus_proof() {
-        Ok(verify_consensus_proof(
+        let new_state = verify_consensus_proof(
             ctx,
+            &local_client_state,
+            &consensus_state,
             connection_end,
             proofs.height(),
             &proof,
-        )?)
-    } else {
-        Ok(())
+        )?;
+        update(&mut local_client_state, new_state);
     }
+
+    Ok(if updated {
+        Some(local_client_state)
+    } else {
+        None
+    })
 }
 
 /// Verifies the authenticity and semantic correctness of a commitment `proof`. The commitment
@@ -58,45 +107,33 @@ pub fn verify_proofs(
 /// which created this proof). This object must match the state of `expected_conn`.
 pub fn verify_connection_proof(
     ctx: &dyn ConnectionReader,
+    client_state: &AnyClientState,
+    consensus_state: &AnyConsensusState,
     connection_end: &ConnectionEnd,
     expected_conn: &ConnectionEnd,
     proof_height: Height,
     proof: &CommitmentProofBytes,
-) -> Result<(), Error> {
-    // Fetch the client state (IBC client on the local/host chain).
-    let client_state = ctx
-        .client_state(connection_end.client_id())
-        .ok_or_else(|| Kind::MissingClient(connection_end.client_id().clone()))?;
-
-    // The client must not be frozen.
-    if client_state.is_frozen() {
-        return Err(Kind::FrozenClient(connection_end.client_id().clone()).into());
-    }
-
-    // The client must have the consensus state for the height where this proof was created.
-    if ctx
-        .client_consensus_state(connection_end.client_id(), proof_height)
-        .is_none()
-    {
-        return Err(Kind::MissingClientConsensusState(
-            proof_height,
-            connection_end.client_id().clone(),
-        )
-        .into());
-    }
-
+) -> Result<Option<AnyClientState>, Error> {
     let client_def = AnyClient::from_client_type(client_state.client_type());
 
+    // The connection id on the counterparty chain must be known to look up its connection end,
+    // which is not the case of a connection end initialized without it (`ConnOpenInit`).
+    let counterparty_connection_id = connection_end
+        .counterparty()
+        .connection_id()
+        .cloned()
+        .ok_or(Kind::InvalidCounterparty)?;
+
     // Verify the proof for the connection state against the expected connection end.
-    // A counterparty connection id of None causes `unwrap()` below and indicates an internal
-    // error as this is the connection id on the counterparty chain that must always be present.
     Ok(client_def
         .verify_connection_state(
-            &client_state,
+            ctx,
+            client_state,
             proof_height,
             connection_end.counterparty().prefix(),
             proof,
-            &connection_end.counterparty().connection_id().unwrap(),
+            consensus_state.root(),
+            &counterparty_connection_id,
             expected_conn,
         )
         .map_err(|_| Kind::InvalidProof)?)
@@ -111,31 +148,19 @@ pub fn verify_connection_proof(
 /// `proof` is correct.
 pub fn verify_client_proof(
     ctx: &dyn ConnectionReader,
+    client_state: &AnyClientState,
+    consensus_state: &AnyConsensusState,
     connection_end: &ConnectionEnd,
     expected_client_state: AnyClientState,
     proof_height: Height,
     proof: &CommitmentProofBytes,
-) -> Result<(), Error> {
-    // Fetch the local client state (IBC client running on the host chain).
-    let client_state = ctx
-        .client_state(connection_end.client_id())
-        .ok_or_else(|| Kind::MissingClient(connection_end.client_id().clone()))?;
-
-    if client_state.is_frozen() {
-        return Err(Kind::FrozenClient(connection_end.client_id().clone()).into());
-    }
-
-    let consensus_state = ctx
-        .client_consensus_state(connection_end.client_id(), proof_height)
-        .ok_or_else(|| {
-            Kind::MissingClientConsensusState(proof_height, connection_end.client_id().clone())
-        })?;
-
+) -> Result<Option<AnyClientState>, Error> {
     let client_def = AnyClient::from_client_type(client_state.client_type());
 
     Ok(client_def
         .verify_client_full_state(
-            &client_state,
+            ctx,
+            client_state,
             proof_height,
             consensus_state.root(),
             connection_end.counterparty().prefix(),
@@ -151,19 +176,12 @@ pub fn verify_client_proof(
 
 pub fn verify_consensus_proof(
     ctx: &dyn ConnectionReader,
+    client_state: &AnyClientState,
+    consensus_state: &AnyConsensusState,
     connection_end: &ConnectionEnd,
     proof_height: Height,
     proof: &ConsensusProof,
-) -> Result<(), Error> {
-    // Fetch the client state (IBC client on the local chain).
-    let client_state = ctx
-        .client_state(connection_end.client_id())
-        .ok_or_else(|| Kind::MissingClient(connection_end.client_id().clone()))?;
-
-    if client_state.is_frozen() {
-        return Err(Kind::FrozenClient(connection_end.client_id().clone()).into());
-    }
-
+) -> Result<Option<AnyClientState>, Error> {
     // Fetch the expected consensus state from the historical (local) header data.
     let expected_consensus = ctx
         .host_consensus_state(proof.height())
@@ -173,10 +191,12 @@ pub fn verify_consensus_proof(
 
     Ok(client
         .verify_client_consensus_state(
-            &client_state,
+            ctx,
+            client_state,
             proof_height,
             connection_end.counterparty().prefix(),
             proof.proof(),
+            consensus_state.root(),
             connection_end.counterparty().client_id(),
             proof.height(),
             &expected_consensus,
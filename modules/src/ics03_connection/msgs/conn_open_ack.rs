@@ -236,6 +236,10 @@ mod tests {
     use crate::ics03_connection::msgs::conn_open_ack::test_util::get_dummy_msg_conn_open_ack;
     use crate::ics03_connection::msgs::conn_open_ack::MsgConnectionOpenAck;
 
+    use crate::ics03_connection::msgs::conn_open_ack::TYPE_URL;
+    use crate::test::test_msg_roundtrip;
+    use proptest::prelude::*;
+
     #[test]
     fn parse_connection_open_ack_msg() {
         #[derive(Clone, Debug, PartialEq)]
@@ -318,4 +322,11 @@ mod tests {
         assert_eq!(raw, raw_back);
         assert_eq!(msg, msg_back);
     }
+
+    proptest! {
+        #[test]
+        fn roundtrip(msg in any::<MsgConnectionOpenAck>()) {
+            test_msg_roundtrip::<_, RawMsgConnectionOpenAck>(msg, TYPE_URL);
+        }
+    }
 }
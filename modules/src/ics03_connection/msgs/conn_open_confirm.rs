@@ -116,6 +116,10 @@ mod tests {
     use crate::ics03_connection::msgs::conn_open_confirm::test_util::get_dummy_msg_conn_open_confirm;
     use crate::ics03_connection::msgs::conn_open_confirm::MsgConnectionOpenConfirm;
 
+    use crate::ics03_connection::msgs::conn_open_confirm::TYPE_URL;
+    use crate::test::test_msg_roundtrip;
+    use proptest::prelude::*;
+
     #[test]
     fn parse_connection_open_confirm_msg() {
         #[derive(Clone, Debug, PartialEq)]
@@ -178,4 +182,11 @@ mod tests {
         assert_eq!(raw, raw_back);
         assert_eq!(msg, msg_back);
     }
+
+    proptest! {
+        #[test]
+        fn roundtrip(msg in any::<MsgConnectionOpenConfirm>()) {
+            test_msg_roundtrip::<_, RawMsgConnectionOpenConfirm>(msg, TYPE_URL);
+        }
+    }
 }
@@ -141,6 +141,10 @@ mod tests {
     use crate::ics03_connection::msgs::conn_open_init::test_util::get_dummy_msg_conn_open_init;
     use crate::ics03_connection::msgs::test_util::get_dummy_counterparty;
 
+    use crate::ics03_connection::msgs::conn_open_init::TYPE_URL;
+    use crate::test::test_msg_roundtrip;
+    use proptest::prelude::*;
+
     #[test]
     fn parse_connection_open_init_msg() {
         #[derive(Clone, Debug, PartialEq)]
@@ -206,4 +210,11 @@ mod tests {
         assert_eq!(raw, raw_back);
         assert_eq!(msg, msg_back);
     }
+
+    proptest! {
+        #[test]
+        fn roundtrip(msg in any::<MsgConnectionOpenInit>()) {
+            test_msg_roundtrip::<_, RawMsgConnectionOpenInit>(msg, TYPE_URL);
+        }
+    }
 }
@@ -187,7 +187,7 @@ impl From<MsgConnectionOpenTry> for RawMsgConnectionOpenTry {
                 .client_state
                 .map_or_else(|| None, |v| Some(v.into())),
             counterparty: Some(ics_msg.counterparty.into()),
-            delay_period: 0,
+            delay_period: ics_msg.delay_period,
             counterparty_versions: ics_msg
                 .counterparty_versions
                 .iter()
@@ -296,6 +296,10 @@ mod tests {
     use crate::ics03_connection::msgs::conn_open_try::MsgConnectionOpenTry;
     use crate::ics03_connection::msgs::test_util::get_dummy_counterparty;
 
+    use crate::ics03_connection::msgs::conn_open_try::TYPE_URL;
+    use crate::test::test_msg_roundtrip;
+    use proptest::prelude::*;
+
     #[test]
     fn parse_connection_open_try_msg() {
         #[derive(Clone, Debug, PartialEq)]
@@ -413,5 +417,21 @@ mod tests {
         let msg_back = MsgConnectionOpenTry::try_from(raw_back.clone()).unwrap();
         assert_eq!(raw, raw_back);
         assert_eq!(msg, msg_back);
+
+        // The delay period survives the round trip.
+        let raw = RawMsgConnectionOpenTry {
+            delay_period: 1000,
+            ..get_dummy_msg_conn_open_try(10, 34)
+        };
+        let msg = MsgConnectionOpenTry::try_from(raw.clone()).unwrap();
+        assert_eq!(msg.delay_period, 1000);
+        assert_eq!(RawMsgConnectionOpenTry::from(msg), raw);
+    }
+
+    proptest! {
+        #[test]
+        fn roundtrip(msg in any::<MsgConnectionOpenTry>()) {
+            test_msg_roundtrip::<_, RawMsgConnectionOpenTry>(msg, TYPE_URL);
+        }
     }
 }
@@ -117,6 +117,12 @@ impl ChannelEnd {
         self.version = v;
     }
 
+    /// Updates the channel id on the counterparty chain, which is unknown when the channel is
+    /// initialized.
+    pub fn set_counterparty_channel_id(&mut self, c: ChannelId) {
+        self.remote.channel_id = Some(c);
+    }
+
     pub fn state(&self) -> &State {
         &self.state
     }
@@ -357,7 +363,7 @@ pub mod test_util {
     pub fn get_dummy_raw_counterparty_ics26() -> RawCounterparty {
         RawCounterparty {
             port_id: "port".into(),
-            channel_id: "defaultChannel-0".into(),
+            channel_id: "channel-0".into(),
         }
     }
 
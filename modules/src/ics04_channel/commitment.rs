@@ -0,0 +1,150 @@
+//! Commitments that chains store for packets and acknowledgements, computed as in ibc-go so that
+//! they can be proven to, and verified by, any counterparty chain.
+
+use sha2::{Digest, Sha256};
+
+use crate::timestamp::Timestamp;
+use crate::Height;
+
+/// The commitment stored by the sending chain for a packet until it is acknowledged or timed out.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PacketCommitment(Vec<u8>);
+
+impl PacketCommitment {
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    pub fn into_vec(self) -> Vec<u8> {
+        self.0
+    }
+}
+
+impl From<Vec<u8>> for PacketCommitment {
+    fn from(bytes: Vec<u8>) -> Self {
+        Self(bytes)
+    }
+}
+
+/// The commitment stored by the receiving chain for the acknowledgement it wrote for a packet.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AcknowledgementCommitment(Vec<u8>);
+
+impl AcknowledgementCommitment {
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    pub fn into_vec(self) -> Vec<u8> {
+        self.0
+    }
+}
+
+impl From<Vec<u8>> for AcknowledgementCommitment {
+    fn from(bytes: Vec<u8>) -> Self {
+        Self(bytes)
+    }
+}
+
+/// Computes the commitment of a packet: the SHA-256 hash of its timeout timestamp (in nanoseconds,
+/// zero if not set), the revision number and the revision height of its timeout height, each as a
+/// big-endian 64-bit integer, followed by the SHA-256 hash of its data.
+pub fn compute_packet_commitment(
+    data: &[u8],
+    timeout_height: Height,
+    timeout_timestamp: Timestamp,
+) -> PacketCommitment {
+    let mut hash_input = timeout_timestamp.nanoseconds().to_be_bytes().to_vec();
+    hash_input.extend_from_slice(&timeout_height.revision_number.to_be_bytes());
+    hash_input.extend_from_slice(&timeout_height.revision_height.to_be_bytes());
+    hash_input.extend_from_slice(&Sha256::digest(data));
+
+    PacketCommitment(Sha256::digest(&hash_input).to_vec())
+}
+
+/// Computes the commitment of an acknowledgement: the SHA-256 hash of its bytes.
+pub fn compute_ack_commitment(ack: &[u8]) -> AcknowledgementCommitment {
+    AcknowledgementCommitment(Sha256::digest(ack).to_vec())
+}
+
+#[cfg(test)]
+mod tests {
+    use subtle_encoding::hex;
+
+    use crate::ics04_channel::commitment::{compute_ack_commitment, compute_packet_commitment};
+    use crate::timestamp::Timestamp;
+    use crate::Height;
+
+    // The expected commitments are those computed by `CommitPacket` and `CommitAcknowledgement` of
+    // ibc-go v1.0.0 (`modules/core/04-channel/types/packet.go`), against which they can be checked.
+
+    #[test]
+    fn packet_commitment_vectors() {
+        struct Test {
+            name: String,
+            data: Vec<u8>,
+            timeout_height: Height,
+            timeout_timestamp: u64,
+            want: &'static str,
+        }
+
+        let tests: Vec<Test> = vec![
+            Test {
+                name: "Empty packet".to_string(),
+                data: vec![],
+                timeout_height: Height::zero(),
+                timeout_timestamp: 0,
+                want: "e6414172e184a44066320223590766e7ff9d758405e51f5cdddb546267a848f5",
+            },
+            Test {
+                name: "Transfer packet with timeout height".to_string(),
+                data: br#"{"amount":"100","denom":"uatom","receiver":"cosmos1receiver","sender":"cosmos1sender"}"#.to_vec(),
+                timeout_height: Height::new(1, 1000),
+                timeout_timestamp: 0,
+                want: "da164c4294f75d673ec2fbe99b2f60189ede0538d3e1dff7a7714ac8efb57fa7",
+            },
+            Test {
+                name: "Packet with timeout height and timestamp".to_string(),
+                data: b"hello".to_vec(),
+                timeout_height: Height::new(0, 10),
+                timeout_timestamp: 1_624_000_000_000_000_000,
+                want: "dd0d5343b8ed3c0bb17f0aaadb468d58a79a621122dc0f3ccbe10109979ff940",
+            },
+        ];
+
+        for test in tests {
+            let commitment = compute_packet_commitment(
+                &test.data,
+                test.timeout_height,
+                Timestamp::from_nanoseconds(test.timeout_timestamp).unwrap(),
+            );
+            assert_eq!(
+                hex::encode(commitment.as_bytes()),
+                test.want.as_bytes(),
+                "{}",
+                test.name
+            );
+        }
+    }
+
+    #[test]
+    fn ack_commitment_vectors() {
+        let tests = vec![
+            (
+                br#"{"result":"AQ=="}"#.to_vec(),
+                "08f7557ed51826fe18d84512bf24ec75001edbaf2123a477df72a0a9f3640a7c",
+            ),
+            (
+                br#"{"error":"insufficient funds"}"#.to_vec(),
+                "75ea0c48475a58a554f36402f4caf251844f4452174b9cdf14d24835d4b28070",
+            ),
+        ];
+
+        for (ack, want) in tests {
+            assert_eq!(
+                hex::encode(compute_ack_commitment(&ack).as_bytes()),
+                want.as_bytes()
+            );
+        }
+    }
+}
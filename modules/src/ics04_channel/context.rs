@@ -1,17 +1,28 @@
 //! ICS4 (channel) context. The two traits `ChannelReader ` and `ChannelKeeper` define
 //! the interface that any host chain must implement to be able to process any `ChannelMsg`.
 //!
+use std::time::Duration;
+
+use tendermint::Time;
+
+use crate::gas::GasMeter;
 use crate::ics02_client::client_def::{AnyClientState, AnyConsensusState};
 use crate::ics03_connection::connection::ConnectionEnd;
 use crate::ics04_channel::channel::ChannelEnd;
+use crate::ics04_channel::commitment::{
+    compute_ack_commitment, compute_packet_commitment, AcknowledgementCommitment, PacketCommitment,
+};
 use crate::ics04_channel::error::Error;
-use crate::ics04_channel::handler::ChannelResult;
+use crate::ics04_channel::handler::{ChannelIdState, ChannelResult, PacketResult};
+use crate::ics04_channel::packet::{Packet, Receipt, Sequence};
 use crate::ics05_port::capabilities::Capability;
-use crate::ics24_host::identifier::{ChannelId, ConnectionId, PortId};
+use crate::ics05_port::context::PortReader;
+use crate::ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId};
 use crate::Height;
 
 /// A context supplying all the necessary read-only dependencies for processing any `ChannelMsg`.
-pub trait ChannelReader {
+/// Capabilities are authenticated through the `PortReader` of the host chain.
+pub trait ChannelReader: GasMeter + PortReader {
     /// Returns the ChannelEnd for the given `port_id` and `chan_id`.
     fn channel_end(&self, port_channel_id: &(PortId, ChannelId)) -> Option<ChannelEnd>;
 
@@ -29,42 +40,186 @@ pub trait ChannelReader {
         height: Height,
     ) -> Option<AnyConsensusState>;
 
-    fn authenticated_capability(&self, port_id: &PortId) -> Result<Capability, Error>;
+    /// Returns the capability of the given port, owned by the IBC module and by the module bound
+    /// to the port.
+    fn port_capability(&self, port_id: &PortId) -> Result<Capability, Error>;
+
+    /// Returns the capability of the given channel, owned by the IBC module and by the module
+    /// which opened the channel.
+    fn channel_capability(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<Capability, Error>;
+
+    /// Returns the ClientState for the given identifier `client_id`. Necessary dependency towards
+    /// proof verification of packet messages.
+    fn client_state(&self, client_id: &ClientId) -> Option<AnyClientState>;
+
+    /// Returns the ConsensusState that the given client stores at a specific height.
+    fn client_consensus_state(
+        &self,
+        client_id: &ClientId,
+        height: Height,
+    ) -> Option<AnyConsensusState>;
+
+    fn get_next_sequence_send(&self, port_channel_id: &(PortId, ChannelId)) -> Option<Sequence>;
+
+    fn get_next_sequence_recv(&self, port_channel_id: &(PortId, ChannelId)) -> Option<Sequence>;
+
+    fn get_next_sequence_ack(&self, port_channel_id: &(PortId, ChannelId)) -> Option<Sequence>;
+
+    fn get_packet_commitment(
+        &self,
+        key: &(PortId, ChannelId, Sequence),
+    ) -> Option<PacketCommitment>;
+
+    fn get_packet_receipt(&self, key: &(PortId, ChannelId, Sequence)) -> Option<Receipt>;
+
+    fn get_packet_acknowledgement(
+        &self,
+        key: &(PortId, ChannelId, Sequence),
+    ) -> Option<AcknowledgementCommitment>;
+
+    /// Computes the commitment that the sending chain stores for the given `packet`, i.e., the
+    /// hash of its timeout timestamp, timeout height and data.
+    fn packet_commitment(&self, packet: &Packet) -> PacketCommitment {
+        compute_packet_commitment(
+            &packet.data,
+            packet.timeout_height,
+            packet.timeout_timestamp,
+        )
+    }
+
+    /// Computes the commitment that the receiving chain stores for the acknowledgement it wrote for
+    /// a packet.
+    fn ack_commitment(&self, ack: &[u8]) -> AcknowledgementCommitment {
+        compute_ack_commitment(ack)
+    }
+
+    /// Returns the current height of the local chain.
+    fn host_height(&self) -> Height;
+
+    /// Returns the current timestamp of the local chain.
+    fn host_timestamp(&self) -> Time;
+
+    /// Returns the time of the local chain when the consensus state of the given client at
+    /// `height` was stored.
+    fn client_update_time(&self, client_id: &ClientId, height: Height) -> Option<Time>;
+
+    /// Returns the height of the local chain when the consensus state of the given client at
+    /// `height` was stored.
+    fn client_update_height(&self, client_id: &ClientId, height: Height) -> Option<Height>;
+
+    /// Returns the maximum time that the local chain is expected to take to produce a block. The
+    /// delay period of a connection is converted into a number of blocks using this time.
+    fn max_expected_time_per_block(&self) -> Duration;
+
+    /// Computes the number of blocks that the local chain must produce for the given
+    /// `delay_period` to elapse, i.e., the delay period divided by the maximum expected time per
+    /// block, rounded up.
+    fn block_delay(&self, delay_period: Duration) -> u64 {
+        let max_time_per_block = self.max_expected_time_per_block().as_nanos();
+        if max_time_per_block == 0 {
+            return 0;
+        }
+
+        delay_period.as_nanos().div_ceil(max_time_per_block) as u64
+    }
+
+    /// Returns a natural number, counting how many channels have been opened on the host chain
+    /// thus far. The value of this counter should increase only via method
+    /// `ChannelKeeper::increase_channel_counter`.
+    fn channel_counter(&self) -> u64;
 }
 
 /// A context supplying all the necessary write-only dependencies (i.e., storage writing facility)
 /// for processing any `ChannelMsg`.
 pub trait ChannelKeeper {
-    fn next_channel_id(&mut self) -> ChannelId;
-
+    /// Stores the channel end resulting from a channel handshake step. For channels being opened
+    /// on the host chain, also initializes their sequences and increases the channel counter.
     fn store_channel_result(&mut self, result: ChannelResult) -> Result<(), Error> {
-        if result.channel_id.is_none() {
-            // If this is the first time the handler processed this channel
-            let channel_id = self.next_channel_id();
-
-            self.store_channel(
-                &(result.port_id.clone(), channel_id.clone()),
-                &result.channel_end,
-            )?;
+        let port_channel_id = (result.port_id, result.channel_id);
+        self.store_channel(&port_channel_id, &result.channel_end)?;
 
+        if result.channel_id_state == ChannelIdState::Generated {
             // associate also the channel end to its connection
             self.store_connection_channels(
                 &result.channel_end.connection_hops()[0].clone(),
-                &(result.port_id.clone(), channel_id.clone()),
+                &port_channel_id,
             )?;
 
             // initialize send sequence number
-            self.store_next_sequence_send(&(result.port_id.clone(), channel_id.clone()), 1)?;
+            self.store_next_sequence_send(&port_channel_id, 1.into())?;
             // initialize recv sequence number
-            self.store_next_sequence_recv(&(result.port_id.clone(), channel_id.clone()), 1)?;
+            self.store_next_sequence_recv(&port_channel_id, 1.into())?;
             // initialize ack sequence number
-            self.store_next_sequence_ack(&(result.port_id, channel_id), 1)?;
-        } else {
-            //the handler processed this channel for channel open init
-            self.store_channel(
-                &(result.port_id.clone(), result.channel_id.clone().unwrap()),
-                &result.channel_end,
-            )?;
+            self.store_next_sequence_ack(&port_channel_id, 1.into())?;
+
+            self.increase_channel_counter();
+        }
+
+        Ok(())
+    }
+
+    fn store_packet_result(&mut self, general_result: PacketResult) -> Result<(), Error> {
+        match general_result {
+            PacketResult::Send(res) => {
+                self.store_next_sequence_send(
+                    &(res.port_id.clone(), res.channel_id.clone()),
+                    res.seq_number,
+                )?;
+
+                self.store_packet_commitment(
+                    (res.port_id.clone(), res.channel_id.clone(), res.seq),
+                    res.commitment,
+                )?;
+            }
+            PacketResult::Recv(res) => {
+                if let Some(seq_number) = res.seq_number {
+                    // Ordered channel: advance the expected receive sequence.
+                    self.store_next_sequence_recv(
+                        &(res.port_id.clone(), res.channel_id.clone()),
+                        seq_number,
+                    )?;
+                }
+
+                if let Some(receipt) = res.receipt {
+                    // Unordered channel: keep track of every received packet through receipts.
+                    self.store_packet_receipt((res.port_id, res.channel_id, res.seq), receipt)?;
+                }
+            }
+            PacketResult::Ack(res) => {
+                if let Some(seq_number) = res.seq_number {
+                    // Ordered channel: advance the expected acknowledgement sequence.
+                    self.store_next_sequence_ack(
+                        &(res.port_id.clone(), res.channel_id.clone()),
+                        seq_number,
+                    )?;
+                }
+
+                // The packet is acknowledged, so its commitment is no longer necessary.
+                self.delete_packet_commitment((
+                    res.port_id.clone(),
+                    res.channel_id.clone(),
+                    res.seq,
+                ))?;
+            }
+            PacketResult::Timeout(res) => {
+                if let Some(channel_end) = res.channel {
+                    // Ordered channel: a timeout closes the channel.
+                    self.store_channel(
+                        &(res.port_id.clone(), res.channel_id.clone()),
+                        &channel_end,
+                    )?;
+                }
+
+                self.delete_packet_commitment((
+                    res.port_id.clone(),
+                    res.channel_id.clone(),
+                    res.seq,
+                ))?;
+            }
         }
         Ok(())
     }
@@ -85,18 +240,43 @@ pub trait ChannelKeeper {
     fn store_next_sequence_send(
         &mut self,
         port_channel_id: &(PortId, ChannelId),
-        seq: u64,
+        seq: Sequence,
     ) -> Result<(), Error>;
 
     fn store_next_sequence_recv(
         &mut self,
         port_channel_id: &(PortId, ChannelId),
-        seq: u64,
+        seq: Sequence,
     ) -> Result<(), Error>;
 
     fn store_next_sequence_ack(
         &mut self,
         port_channel_id: &(PortId, ChannelId),
-        seq: u64,
+        seq: Sequence,
+    ) -> Result<(), Error>;
+
+    fn store_packet_commitment(
+        &mut self,
+        key: (PortId, ChannelId, Sequence),
+        commitment: PacketCommitment,
+    ) -> Result<(), Error>;
+
+    fn delete_packet_commitment(&mut self, key: (PortId, ChannelId, Sequence))
+        -> Result<(), Error>;
+
+    fn store_packet_receipt(
+        &mut self,
+        key: (PortId, ChannelId, Sequence),
+        receipt: Receipt,
     ) -> Result<(), Error>;
+
+    fn store_packet_acknowledgement(
+        &mut self,
+        key: (PortId, ChannelId, Sequence),
+        ack_commitment: AcknowledgementCommitment,
+    ) -> Result<(), Error>;
+
+    /// Increases the counter which keeps track of how many channels have been opened.
+    /// Should never fail.
+    fn increase_channel_counter(&mut self);
 }
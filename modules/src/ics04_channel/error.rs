@@ -1,9 +1,14 @@
 use anomaly::{BoxError, Context};
+use tendermint::Time;
 use thiserror::Error;
 
 pub type Error = anomaly::Error<Kind>;
 
-use crate::ics24_host::identifier::{ChannelId, ConnectionId};
+use crate::gas::OutOfGas;
+use crate::ics04_channel::packet::Sequence;
+use crate::ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId};
+use crate::timestamp::Timestamp;
+use crate::Height;
 
 #[derive(Clone, Debug, Error)]
 pub enum Kind {
@@ -34,6 +39,9 @@ pub enum Kind {
     #[error("invalid timeout height for the packet")]
     InvalidTimeoutHeight,
 
+    #[error("invalid timeout timestamp for the packet")]
+    InvalidTimeoutTimestamp,
+
     #[error("invalid packet")]
     InvalidPacket,
 
@@ -43,6 +51,9 @@ pub enum Kind {
     #[error("acknowledgement too long")]
     AcknowledgementTooLong,
 
+    #[error("invalid acknowledgement")]
+    InvalidAcknowledgement,
+
     #[error("missing counterparty")]
     MissingCounterparty,
 
@@ -58,8 +69,14 @@ pub enum Kind {
     #[error("the port has no capability associated")]
     NoPortCapability,
 
-    #[error("the module associated with the port does not have the capability it needs")]
-    InvalidPortCapability,
+    #[error("channel {1} on port {0} has no capability associated")]
+    NoChannelCapability(PortId, ChannelId),
+
+    #[error("the given capability is not the capability of port {0}")]
+    InvalidPortCapability(PortId),
+
+    #[error("the given capability is not the capability of channel {1} on port {0}")]
+    InvalidChannelCapability(PortId, ChannelId),
 
     #[error("single version must be negociated on connection before opening channel")]
     InvalidVersionLengthConnection,
@@ -107,6 +124,61 @@ pub enum Kind {
 
     #[error("Channel chain verification fails on ChannelOpenAck for ChannelOpenTry")]
     FailedChanneOpenAckVerification,
+
+    #[error(
+        "Packet destination port {0} and channel {1} doesn't match the counterparty's port/channel"
+    )]
+    InvalidPacketCounterparty(PortId, ChannelId),
+
+    #[error("Receiving chain block height {0} >= packet timeout height {1}")]
+    LowPacketHeight(Height, Height),
+
+    #[error("Receiving chain block timestamp {0} >= packet timeout timestamp {1}")]
+    LowPacketTimestamp(Timestamp, Timestamp),
+
+    #[error("Packet timeout height {0} > chain height {1} and timeout timestamp {2} > chain timestamp {3}")]
+    PacketTimeoutNotReached(Height, Height, Timestamp, Timestamp),
+
+    #[error("Invalid packet sequence {0} ≠ next send sequence {1}")]
+    InvalidPacketSequence(Sequence, Sequence),
+
+    #[error("Missing sequence number for sending packets")]
+    MissingNextSendSeq,
+
+    #[error("Missing sequence number for receiving packets")]
+    MissingNextRecvSeq,
+
+    #[error("Missing sequence number for acknowledging packets")]
+    MissingNextAckSeq,
+
+    #[error("Packet with sequence {0} was already received")]
+    PacketAlreadyReceived(Sequence),
+
+    #[error("Packet commitment for sequence {0} not found")]
+    PacketCommitmentNotFound(Sequence),
+
+    #[error("The stored commitment of the packet {0} is incorrect")]
+    IncorrectPacketCommitment(Sequence),
+
+    #[error("Verification fails for the packet with the sequence number {0}")]
+    PacketVerificationFailed(Sequence),
+
+    #[error("Processed time of the consensus state of client {0} at height {1} not found")]
+    ProcessedTimeNotFound(ClientId, Height),
+
+    #[error("Processed height of the consensus state of client {0} at height {1} not found")]
+    ProcessedHeightNotFound(ClientId, Height),
+
+    #[error(
+        "Host chain time {0} is lower than the earliest time {1} allowed by the connection delay"
+    )]
+    DelayTimeNotElapsed(Time, Time),
+
+    #[error("Host chain height {0} is lower than the earliest height {1} allowed by the connection delay")]
+    DelayHeightNotElapsed(Height, Height),
+
+    #[error("{0}")]
+    OutOfGas(OutOfGas),
 }
 
 impl Kind {
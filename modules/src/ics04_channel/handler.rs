@@ -1,41 +1,132 @@
 //! This module implements the processing logic for ICS4 (channel) messages.
 
 use crate::handler::HandlerOutput;
+use crate::ics02_client::client_def::AnyClientState;
 use crate::ics04_channel::channel::ChannelEnd;
 use crate::ics04_channel::context::ChannelReader;
-use crate::ics04_channel::error::Error;
-use crate::ics04_channel::msgs::ChannelMsg;
+use crate::ics04_channel::error::{Error, Kind};
+use crate::ics04_channel::msgs::{ChannelMsg, PacketMsg};
 use crate::ics05_port::capabilities::Capability;
-use crate::ics24_host::identifier::{ChannelId, PortId};
+use crate::ics24_host::identifier::{ChannelId, ClientId, PortId};
 
+pub mod acknowledgement;
 pub mod chan_close_confirm;
 pub mod chan_close_init;
 pub mod chan_open_ack;
 pub mod chan_open_confirm;
 pub mod chan_open_init;
 pub mod chan_open_try;
+pub mod recv_packet;
+pub mod send_packet;
+pub mod timeout;
+pub mod timeout_on_close;
 
 mod verify;
+/// Whether a channel handshake step opens a new channel on the host chain, whose identifier is
+/// then generated from the channel counter (see `ChannelReader::channel_counter`), or continues
+/// the handshake of an existing one.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChannelIdState {
+    Generated,
+    Reused,
+}
+
 #[derive(Clone, Debug)]
 pub struct ChannelResult {
     pub port_id: PortId,
-    pub channel_id: Option<ChannelId>,
+    pub channel_id: ChannelId,
+    pub channel_id_state: ChannelIdState,
+    /// The capability authorising the handshake step: the capability of the port for the steps
+    /// opening a channel on the host chain, and the capability of the channel afterwards.
     pub channel_cap: Capability,
     pub channel_end: ChannelEnd,
+    /// The new state of the client of the channel, with its identifier, if verifying the proofs of
+    /// the message updated it (see `ClientDef`).
+    pub client_state: Option<(ClientId, AnyClientState)>,
+}
+
+/// The result of processing any packet-related message, to be applied to the host chain store
+/// through `ChannelKeeper::store_packet_result`.
+#[derive(Clone, Debug)]
+pub enum PacketResult {
+    Send(send_packet::SendPacketResult),
+    Recv(recv_packet::RecvPacketResult),
+    Ack(acknowledgement::AckPacketResult),
+    Timeout(timeout::TimeoutPacketResult),
 }
 
 /// General entry point for processing any type of message related to the ICS4 channel open
-/// handshake protocol.
-pub fn dispatch<Ctx>(ctx: &Ctx, msg: ChannelMsg) -> Result<HandlerOutput<ChannelResult>, Error>
+/// handshake protocol, on behalf of the module holding `channel_cap`. The handlers only accept
+/// the capability of the port for the steps opening a channel on the host chain, and the
+/// capability of the channel afterwards. `None` stands for a module holding no such capability.
+pub fn dispatch<Ctx>(
+    ctx: &Ctx,
+    channel_cap: Option<&Capability>,
+    msg: ChannelMsg,
+) -> Result<HandlerOutput<ChannelResult>, Error>
 where
     Ctx: ChannelReader,
 {
-    match msg {
-        ChannelMsg::ChannelOpenInit(msg) => chan_open_init::process(ctx, msg),
-        ChannelMsg::ChannelOpenTry(msg) => chan_open_try::process(ctx, msg),
-        ChannelMsg::ChannelOpenAck(msg) => chan_open_ack::process(ctx, msg),
-        ChannelMsg::ChannelOpenConfirm(msg) => chan_open_confirm::process(ctx, msg),
-        ChannelMsg::ChannelCloseInit(msg) => chan_close_init::process(ctx, msg),
-        ChannelMsg::ChannelCloseConfirm(msg) => chan_close_confirm::process(ctx, msg),
+    let output = match msg {
+        ChannelMsg::ChannelOpenInit(msg) => chan_open_init::process(ctx, channel_cap, msg),
+        ChannelMsg::ChannelOpenTry(msg) => chan_open_try::process(ctx, channel_cap, msg),
+        ChannelMsg::ChannelOpenAck(msg) => chan_open_ack::process(ctx, channel_cap, msg),
+        ChannelMsg::ChannelOpenConfirm(msg) => chan_open_confirm::process(ctx, channel_cap, msg),
+        ChannelMsg::ChannelCloseInit(msg) => chan_close_init::process(ctx, channel_cap, msg),
+        ChannelMsg::ChannelCloseConfirm(msg) => chan_close_confirm::process(ctx, channel_cap, msg),
+    }?;
+
+    // The events emitted by the handler are charged once it succeeded.
+    ctx.consume_gas(ctx.gas_costs().events(output.events.len()), "events")
+        .map_err(Kind::OutOfGas)?;
+
+    Ok(output)
+}
+
+/// Dispatcher for processing any type of message related to the ICS4 packet protocols, on behalf
+/// of the module holding `channel_cap`. The handlers only accept the capability of the local
+/// channel end of the packet.
+pub fn packet_dispatch<Ctx>(
+    ctx: &Ctx,
+    channel_cap: Option<&Capability>,
+    msg: PacketMsg,
+) -> Result<HandlerOutput<PacketResult>, Error>
+where
+    Ctx: ChannelReader,
+{
+    let output = match msg {
+        PacketMsg::RecvPacket(msg) => recv_packet::process(ctx, channel_cap, msg),
+        PacketMsg::AckPacket(msg) => acknowledgement::process(ctx, channel_cap, msg),
+        PacketMsg::ToPacket(msg) => timeout::process(ctx, channel_cap, msg),
+        PacketMsg::ToClosePacket(msg) => timeout_on_close::process(ctx, channel_cap, msg),
+    }?;
+
+    // The events emitted by the handler are charged once it succeeded.
+    ctx.consume_gas(ctx.gas_costs().events(output.events.len()), "events")
+        .map_err(Kind::OutOfGas)?;
+
+    Ok(output)
+}
+
+#[cfg(test)]
+pub mod test_util {
+    use crate::ics04_channel::msgs::{ChannelMsg, PacketMsg};
+    use crate::ics05_port::capabilities::{Capability, ModuleId};
+    use crate::ics05_port::context::PortReader;
+    use crate::ics26_routing::handler::{channel_msg_capability_name, packet_msg_capability_name};
+    use crate::mock::context::MockContext;
+
+    /// Returns the capability which the router hands over to `dispatch` along with `msg`, if
+    /// the port or channel of the message has one.
+    pub fn channel_msg_capability(ctx: &MockContext, msg: &ChannelMsg) -> Option<Capability> {
+        ctx.capability_keeper()
+            .get_capability(&ModuleId::ibc(), &channel_msg_capability_name(msg))
+    }
+
+    /// Returns the capability which the router hands over to `packet_dispatch` along with `msg`,
+    /// if the channel of the message has one.
+    pub fn packet_msg_capability(ctx: &MockContext, msg: &PacketMsg) -> Option<Capability> {
+        ctx.capability_keeper()
+            .get_capability(&ModuleId::ibc(), &packet_msg_capability_name(msg))
     }
 }
@@ -0,0 +1,282 @@
+//! Protocol logic specific to ICS4 messages of type `MsgAcknowledgement`.
+use crate::events::IBCEvent;
+use crate::handler::{HandlerOutput, HandlerResult};
+use crate::ics02_client::client_def::AnyClientState;
+use crate::ics03_connection::connection::State as ConnectionState;
+use crate::ics04_channel::channel::{Order, State};
+use crate::ics04_channel::context::ChannelReader;
+use crate::ics04_channel::error::{Error, Kind};
+use crate::ics04_channel::events::AcknowledgePacket;
+use crate::ics04_channel::handler::verify::{check_client, verify_packet_acknowledgement_proofs};
+use crate::ics04_channel::handler::PacketResult;
+use crate::ics04_channel::msgs::acknowledgement::MsgAcknowledgement;
+use crate::ics04_channel::packet::Sequence;
+use crate::ics05_port::capabilities::Capability;
+use crate::ics24_host::identifier::{ChannelId, ClientId, PortId};
+
+#[derive(Clone, Debug)]
+pub struct AckPacketResult {
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+    pub seq: Sequence,
+    /// The next sequence to be acknowledged, only for ordered channels.
+    pub seq_number: Option<Sequence>,
+    /// The new state of the client of the channel, with its identifier, if verifying the proofs of
+    /// the message updated it (see `ClientDef`).
+    pub client_state: Option<(ClientId, AnyClientState)>,
+}
+
+pub(crate) fn process(
+    ctx: &dyn ChannelReader,
+    channel_cap: Option<&Capability>,
+    msg: MsgAcknowledgement,
+) -> HandlerResult<PacketResult, Error> {
+    let mut output = HandlerOutput::builder();
+
+    let packet = msg.packet();
+
+    let source_channel_end = ctx
+        .channel_end(&(packet.source_port.clone(), packet.source_channel.clone()))
+        .ok_or_else(|| Kind::ChannelNotFound.context(packet.source_channel.to_string()))?;
+
+    if !source_channel_end.state_matches(&State::Open) {
+        return Err(Kind::InvalidChannelState(packet.source_channel.clone()).into());
+    }
+
+    // The module which sent the packet must hold the capability of the sending channel.
+    if !channel_cap.is_some_and(|cap| {
+        ctx.authenticate_channel(cap, &packet.source_port, &packet.source_channel)
+    }) {
+        return Err(Kind::InvalidChannelCapability(
+            packet.source_port.clone(),
+            packet.source_channel.clone(),
+        )
+        .into());
+    }
+
+    // The packet must be destined to the counterparty of the sending channel end.
+    let counterparty = source_channel_end.counterparty();
+    if counterparty.port_id() != &packet.destination_port
+        || counterparty.channel_id() != Some(&packet.destination_channel)
+    {
+        return Err(Kind::InvalidPacketCounterparty(
+            packet.destination_port.clone(),
+            packet.destination_channel.clone(),
+        )
+        .into());
+    }
+
+    let connection_end = ctx
+        .connection_end(&source_channel_end.connection_hops()[0])
+        .ok_or_else(|| Kind::MissingConnection(source_channel_end.connection_hops()[0].clone()))?;
+
+    if !connection_end.state_matches(&ConnectionState::Open) {
+        return Err(
+            Kind::ConnectionNotOpen(source_channel_end.connection_hops()[0].clone()).into(),
+        );
+    }
+
+    // The packet must have been sent, and not yet acknowledged or timed out.
+    let packet_commitment = ctx
+        .get_packet_commitment(&(
+            packet.source_port.clone(),
+            packet.source_channel.clone(),
+            packet.sequence,
+        ))
+        .ok_or(Kind::PacketCommitmentNotFound(packet.sequence))?;
+
+    if packet_commitment != ctx.packet_commitment(packet) {
+        return Err(Kind::IncorrectPacketCommitment(packet.sequence).into());
+    }
+
+    // Verify the acknowledgement proof
+    let client_state = check_client(ctx, &connection_end)?;
+    let client_state = verify_packet_acknowledgement_proofs(
+        ctx,
+        &client_state,
+        packet,
+        msg.acknowledgement().clone(),
+        &connection_end,
+        msg.proofs(),
+    )?
+    .map(|state| (connection_end.client_id().clone(), state));
+
+    let result = if source_channel_end.order_matches(&Order::Ordered) {
+        let next_seq_ack = ctx
+            .get_next_sequence_ack(&(packet.source_port.clone(), packet.source_channel.clone()))
+            .ok_or(Kind::MissingNextAckSeq)?;
+
+        if packet.sequence != next_seq_ack {
+            return Err(Kind::InvalidPacketSequence(packet.sequence, next_seq_ack).into());
+        }
+
+        PacketResult::Ack(AckPacketResult {
+            port_id: packet.source_port.clone(),
+            channel_id: packet.source_channel.clone(),
+            seq: packet.sequence,
+            seq_number: Some(next_seq_ack.increment()),
+            client_state,
+        })
+    } else {
+        PacketResult::Ack(AckPacketResult {
+            port_id: packet.source_port.clone(),
+            channel_id: packet.source_channel.clone(),
+            seq: packet.sequence,
+            seq_number: None,
+            client_state,
+        })
+    };
+
+    output.log("success: packet ack");
+
+    output.emit(IBCEvent::AcknowledgePacket(AcknowledgePacket {
+        height: Default::default(),
+        packet: msg.packet,
+    }));
+
+    Ok(output.with_result(result))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use crate::events::IBCEvent;
+    use crate::ics03_connection::connection::ConnectionEnd;
+    use crate::ics03_connection::connection::Counterparty as ConnectionCounterparty;
+    use crate::ics03_connection::connection::State as ConnectionState;
+    use crate::ics03_connection::version::get_compatible_versions;
+    use crate::ics04_channel::channel::{ChannelEnd, Counterparty, Order, State};
+    use crate::ics04_channel::context::ChannelReader;
+    use crate::ics04_channel::handler::test_util::packet_msg_capability;
+    use crate::ics04_channel::handler::{packet_dispatch, PacketResult};
+    use crate::ics04_channel::msgs::acknowledgement::test_util::get_dummy_raw_msg_acknowledgement;
+    use crate::ics04_channel::msgs::acknowledgement::MsgAcknowledgement;
+    use crate::ics04_channel::msgs::PacketMsg;
+    use crate::ics24_host::identifier::{ClientId, ConnectionId};
+    use crate::mock::context::MockContext;
+
+    #[test]
+    fn ack_packet_processing() {
+        struct Test {
+            name: String,
+            ctx: MockContext,
+            msg: MsgAcknowledgement,
+            want_pass: bool,
+        }
+
+        let context = MockContext::default();
+
+        let msg = MsgAcknowledgement::try_from(get_dummy_raw_msg_acknowledgement(10)).unwrap();
+        let packet = msg.packet.clone();
+
+        let source_channel_end = ChannelEnd::new(
+            State::Open,
+            Order::default(),
+            Counterparty::new(
+                packet.destination_port.clone(),
+                Some(packet.destination_channel.clone()),
+            ),
+            vec![ConnectionId::default()],
+            "ics20".to_string(),
+        );
+
+        let connection_end = ConnectionEnd::new(
+            ConnectionState::Open,
+            ClientId::default(),
+            ConnectionCounterparty::new(
+                ClientId::default(),
+                Some(ConnectionId::default()),
+                Default::default(),
+            ),
+            get_compatible_versions(),
+            0,
+        );
+
+        let good_ctx = context
+            .clone()
+            .with_client(&ClientId::default(), msg.proofs().height())
+            .with_connection(ConnectionId::default(), connection_end)
+            .with_port_capability(packet.source_port.clone())
+            .with_channel_init(
+                packet.source_port.clone(),
+                packet.source_channel.clone(),
+                source_channel_end,
+            );
+
+        let commitment = good_ctx.packet_commitment(&packet);
+
+        let tests: Vec<Test> = vec![
+            Test {
+                name: "Processing fails because no channel exists in the context".to_string(),
+                ctx: context,
+                msg: msg.clone(),
+                want_pass: false,
+            },
+            Test {
+                name: "Processing fails because the packet commitment is missing".to_string(),
+                ctx: good_ctx.clone(),
+                msg: msg.clone(),
+                want_pass: false,
+            },
+            Test {
+                name: "Processing fails because the packet commitment does not match".to_string(),
+                ctx: good_ctx.clone().with_packet_commitment(
+                    packet.source_port.clone(),
+                    packet.source_channel.clone(),
+                    packet.sequence,
+                    vec![0; 32].into(),
+                ),
+                msg: msg.clone(),
+                want_pass: false,
+            },
+            Test {
+                name: "Good parameters".to_string(),
+                ctx: good_ctx.with_packet_commitment(
+                    packet.source_port.clone(),
+                    packet.source_channel.clone(),
+                    packet.sequence,
+                    commitment,
+                ),
+                msg,
+                want_pass: true,
+            },
+        ]
+        .into_iter()
+        .collect();
+
+        for test in tests {
+            let msg = PacketMsg::AckPacket(test.msg.clone());
+            let channel_cap = packet_msg_capability(&test.ctx, &msg);
+            let res = packet_dispatch(&test.ctx, channel_cap.as_ref(), msg);
+            // Additionally check the events and the output objects in the result.
+            match res {
+                Ok(proto_output) => {
+                    assert!(
+                        test.want_pass,
+                        "ack_packet: test passed but was supposed to fail for test: {}, \nparams {:?} {:?}",
+                        test.name,
+                        test.msg.clone(),
+                        test.ctx.clone()
+                    );
+                    assert!(!proto_output.events.is_empty()); // Some events must exist.
+                    assert!(matches!(proto_output.result, PacketResult::Ack(_)));
+
+                    for e in proto_output.events.iter() {
+                        assert!(matches!(e, &IBCEvent::AcknowledgePacket(_)));
+                    }
+                }
+                Err(e) => {
+                    assert!(
+                        !test.want_pass,
+                        "ack_packet: did not pass test: {}, \nparams {:?} {:?} error: {:?}",
+                        test.name,
+                        test.msg.clone(),
+                        test.ctx.clone(),
+                        e,
+                    );
+                }
+            }
+        }
+    }
+}
@@ -5,9 +5,10 @@ use crate::ics04_channel::channel::State;
 use crate::ics04_channel::context::ChannelReader;
 use crate::ics04_channel::error::{Error, Kind};
 use crate::ics04_channel::events::Attributes;
-use crate::ics04_channel::handler::verify::verify_proofs;
-use crate::ics04_channel::handler::ChannelResult;
+use crate::ics04_channel::handler::verify::{check_client, verify_proofs};
+use crate::ics04_channel::handler::{ChannelIdState, ChannelResult};
 use crate::ics04_channel::msgs::chan_close_confirm::MsgChannelCloseConfirm;
+use crate::ics05_port::capabilities::Capability;
 use crate::{
     events::IBCEvent,
     ics04_channel::channel::{ChannelEnd, Counterparty},
@@ -16,6 +17,7 @@ use Kind::ConnectionNotOpen;
 
 pub(crate) fn process(
     ctx: &dyn ChannelReader,
+    channel_cap: Option<&Capability>,
     msg: MsgChannelCloseConfirm,
 ) -> HandlerResult<ChannelResult, Error> {
     let mut output = HandlerOutput::builder();
@@ -31,8 +33,12 @@ pub(crate) fn process(
         return Err(Kind::ChannelAlreadyClosed(msg.channel_id().clone()).into());
     }
 
-    // Channel capabilities
-    let channel_cap = ctx.authenticated_capability(&msg.port_id().clone())?;
+    // The module which opened the channel must hold the capability of the channel.
+    let channel_cap = channel_cap
+        .filter(|cap| ctx.authenticate_channel(cap, msg.port_id(), msg.channel_id()))
+        .ok_or_else(|| {
+            Kind::InvalidChannelCapability(msg.port_id().clone(), msg.channel_id().clone())
+        })?;
 
     // An OPEN IBC connection running on the local (host) chain should exist.
     if channel_end.connection_hops().len() != 1 {
@@ -66,14 +72,17 @@ pub(crate) fn process(
         channel_end.version(),
     );
 
-    verify_proofs(
+    let client_state = check_client(ctx, &conn)?;
+    let client_state = verify_proofs(
         ctx,
+        &client_state,
         &channel_end,
         &conn,
         &expected_channel_end,
         &msg.proofs(),
     )
-    .map_err(|e| Kind::FailedChanneOpenTryVerification.context(e))?;
+    .map_err(|e| Kind::FailedChanneOpenTryVerification.context(e))?
+    .map(|state| (conn.client_id().clone(), state));
 
     output.log("success: channel close confirm ");
 
@@ -82,9 +91,11 @@ pub(crate) fn process(
 
     let result = ChannelResult {
         port_id: msg.port_id().clone(),
-        channel_id: Some(msg.channel_id().clone()),
-        channel_cap,
+        channel_id: msg.channel_id().clone(),
+        channel_id_state: ChannelIdState::Reused,
+        channel_cap: channel_cap.clone(),
         channel_end,
+        client_state,
     };
 
     let event_attributes = Attributes {
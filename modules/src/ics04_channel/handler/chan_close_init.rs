@@ -6,12 +6,14 @@ use crate::ics04_channel::channel::State;
 use crate::ics04_channel::context::ChannelReader;
 use crate::ics04_channel::error::{Error, Kind};
 use crate::ics04_channel::events::Attributes;
-use crate::ics04_channel::handler::ChannelResult;
+use crate::ics04_channel::handler::{ChannelIdState, ChannelResult};
 use crate::ics04_channel::msgs::chan_close_init::MsgChannelCloseInit;
+use crate::ics05_port::capabilities::Capability;
 use Kind::ConnectionNotOpen;
 
 pub(crate) fn process(
     ctx: &dyn ChannelReader,
+    channel_cap: Option<&Capability>,
     msg: MsgChannelCloseInit,
 ) -> HandlerResult<ChannelResult, Error> {
     let mut output = HandlerOutput::builder();
@@ -30,8 +32,12 @@ pub(crate) fn process(
         )));
     }
 
-    // Channel capabilities
-    let channel_cap = ctx.authenticated_capability(&msg.port_id().clone())?;
+    // The module which opened the channel must hold the capability of the channel.
+    let channel_cap = channel_cap
+        .filter(|cap| ctx.authenticate_channel(cap, msg.port_id(), msg.channel_id()))
+        .ok_or_else(|| {
+            Kind::InvalidChannelCapability(msg.port_id().clone(), msg.channel_id().clone())
+        })?;
     // An OPEN IBC connection running on the local (host) chain should exist.
 
     if channel_end.connection_hops().len() != 1 {
@@ -53,9 +59,11 @@ pub(crate) fn process(
 
     let result = ChannelResult {
         port_id: msg.port_id().clone(),
-        channel_id: Some(msg.channel_id().clone()),
-        channel_cap,
+        channel_id: msg.channel_id().clone(),
+        channel_id_state: ChannelIdState::Reused,
+        channel_cap: channel_cap.clone(),
         channel_end,
+        client_state: None,
     };
 
     let event_attributes = Attributes {
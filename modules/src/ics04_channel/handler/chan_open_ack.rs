@@ -6,13 +6,15 @@ use crate::ics04_channel::channel::{ChannelEnd, Counterparty, State};
 use crate::ics04_channel::context::ChannelReader;
 use crate::ics04_channel::error::{Error, Kind};
 use crate::ics04_channel::events::Attributes;
-use crate::ics04_channel::handler::verify::verify_proofs;
-use crate::ics04_channel::handler::ChannelResult;
+use crate::ics04_channel::handler::verify::{check_client, verify_proofs};
+use crate::ics04_channel::handler::{ChannelIdState, ChannelResult};
 use crate::ics04_channel::msgs::chan_open_ack::MsgChannelOpenAck;
+use crate::ics05_port::capabilities::Capability;
 use Kind::ConnectionNotOpen;
 
 pub(crate) fn process(
     ctx: &dyn ChannelReader,
+    channel_cap: Option<&Capability>,
     msg: MsgChannelOpenAck,
 ) -> HandlerResult<ChannelResult, Error> {
     let mut output = HandlerOutput::builder();
@@ -29,8 +31,12 @@ pub(crate) fn process(
         return Err(Kind::InvalidChannelState(msg.channel_id().clone()).into());
     }
 
-    // Channel capabilities
-    let channel_cap = ctx.authenticated_capability(&msg.port_id().clone())?;
+    // The module which opened the channel must hold the capability of the channel.
+    let channel_cap = channel_cap
+        .filter(|cap| ctx.authenticate_channel(cap, msg.port_id(), msg.channel_id()))
+        .ok_or_else(|| {
+            Kind::InvalidChannelCapability(msg.port_id().clone(), msg.channel_id().clone())
+        })?;
 
     // An OPEN IBC connection running on the local (host) chain should exist.
 
@@ -46,6 +52,10 @@ pub(crate) fn process(
         return Err(ConnectionNotOpen(channel_end.connection_hops()[0].clone()).into());
     }
 
+    // The channel id on the counterparty chain is only known from now on if the channel was
+    // initialized on the host chain.
+    channel_end.set_counterparty_channel_id(msg.counterparty_channel_id().clone());
+
     // Proof verification in two steps:
     // 1. Setup: build the Channel as we expect to find it on the other party.
 
@@ -67,14 +77,17 @@ pub(crate) fn process(
         msg.counterparty_version().clone(),
     );
     //2. Verify proofs
-    verify_proofs(
+    let client_state = check_client(ctx, &conn)?;
+    let client_state = verify_proofs(
         ctx,
+        &client_state,
         &channel_end,
         &conn,
         &expected_channel_end,
         &msg.proofs(),
     )
-    .map_err(|e| Kind::FailedChanneOpenAckVerification.context(e))?;
+    .map_err(|e| Kind::FailedChanneOpenAckVerification.context(e))?
+    .map(|state| (conn.client_id().clone(), state));
 
     output.log("success: channel open try ");
 
@@ -84,9 +97,11 @@ pub(crate) fn process(
 
     let result = ChannelResult {
         port_id: msg.port_id().clone(),
-        channel_id: Some(msg.channel_id().clone()),
-        channel_cap,
+        channel_id: msg.channel_id().clone(),
+        channel_id_state: ChannelIdState::Reused,
+        channel_cap: channel_cap.clone(),
         channel_end,
+        client_state,
     };
 
     let event_attributes = Attributes {
@@ -115,6 +130,8 @@ mod tests {
     use crate::ics03_connection::version::get_compatible_versions;
 
     use crate::ics04_channel::channel::{ChannelEnd, Counterparty, State};
+    use crate::ics04_channel::context::ChannelReader;
+    use crate::ics04_channel::handler::test_util::channel_msg_capability;
     use crate::ics04_channel::handler::{dispatch, ChannelResult};
     use crate::ics04_channel::msgs::chan_open_ack::test_util::get_dummy_raw_msg_chan_open_ack;
     use crate::ics04_channel::msgs::chan_open_ack::MsgChannelOpenAck;
@@ -123,6 +140,7 @@ mod tests {
     use crate::ics04_channel::msgs::ChannelMsg;
 
     use crate::ics24_host::identifier::ConnectionId;
+    use crate::ics26_routing::msgs::ICS26Envelope;
     use crate::mock::context::MockContext;
     use crate::Height;
 
@@ -299,7 +317,8 @@ mod tests {
         .collect();
 
         for test in tests {
-            let res = dispatch(&test.ctx, test.msg.clone());
+            let channel_cap = channel_msg_capability(&test.ctx, &test.msg);
+            let res = dispatch(&test.ctx, channel_cap.as_ref(), test.msg.clone());
             // Additionally check the events and the output objects in the result.
             match res {
                 Ok(proto_output) => {
@@ -336,4 +355,66 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn chan_open_ack_stores_counterparty_channel_id() {
+        let proof_height = 10;
+        let msg_chan_ack =
+            MsgChannelOpenAck::try_from(get_dummy_raw_msg_chan_open_ack(proof_height)).unwrap();
+        let msg_chan_try =
+            MsgChannelOpenTry::try_from(get_dummy_raw_msg_chan_open_try(proof_height)).unwrap();
+
+        let msg_conn_init =
+            MsgConnectionOpenInit::try_from(get_dummy_msg_conn_open_init()).unwrap();
+        let conn_id = ConnectionId::from_str("defaultConnection-0").unwrap();
+        let conn_end = ConnectionEnd::new(
+            ConnectionState::Open,
+            msg_conn_init.client_id().clone(),
+            ConnectionCounterparty::new(
+                msg_conn_init.counterparty().client_id().clone(),
+                Some(ConnectionId::from_str("defaultConnection-1").unwrap()),
+                msg_conn_init.counterparty().prefix().clone(),
+            ),
+            get_compatible_versions(),
+            msg_conn_init.delay_period,
+        );
+
+        // The channel was initialized on the host chain, when the channel id on the counterparty
+        // chain was not known yet.
+        let chan_end = ChannelEnd::new(
+            State::Init,
+            *msg_chan_try.channel.ordering(),
+            Counterparty::new(msg_chan_ack.port_id().clone(), None),
+            vec![conn_id.clone()],
+            msg_chan_try.channel.version(),
+        );
+
+        let mut context = MockContext::default()
+            .with_client(msg_conn_init.client_id(), Height::new(1, proof_height))
+            .with_connection(conn_id, conn_end)
+            .with_port_capability(msg_chan_ack.port_id().clone())
+            .with_channel_init(
+                msg_chan_ack.port_id().clone(),
+                msg_chan_ack.channel_id().clone(),
+                chan_end,
+            );
+
+        context
+            .deliver(ICS26Envelope::ICS4ChannelMsg(ChannelMsg::ChannelOpenAck(
+                msg_chan_ack.clone(),
+            )))
+            .unwrap();
+
+        let chan_end = context
+            .channel_end(&(
+                msg_chan_ack.port_id().clone(),
+                msg_chan_ack.channel_id().clone(),
+            ))
+            .unwrap();
+        assert_eq!(chan_end.state(), &State::Open);
+        assert_eq!(
+            chan_end.counterparty().channel_id(),
+            Some(msg_chan_ack.counterparty_channel_id())
+        );
+    }
 }
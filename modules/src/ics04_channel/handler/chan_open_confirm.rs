@@ -6,13 +6,15 @@ use crate::ics04_channel::channel::{ChannelEnd, Counterparty, State};
 use crate::ics04_channel::context::ChannelReader;
 use crate::ics04_channel::error::{Error, Kind};
 use crate::ics04_channel::events::Attributes;
-use crate::ics04_channel::handler::verify::verify_proofs;
-use crate::ics04_channel::handler::ChannelResult;
+use crate::ics04_channel::handler::verify::{check_client, verify_proofs};
+use crate::ics04_channel::handler::{ChannelIdState, ChannelResult};
 use crate::ics04_channel::msgs::chan_open_confirm::MsgChannelOpenConfirm;
+use crate::ics05_port::capabilities::Capability;
 use Kind::ConnectionNotOpen;
 
 pub(crate) fn process(
     ctx: &dyn ChannelReader,
+    channel_cap: Option<&Capability>,
     msg: MsgChannelOpenConfirm,
 ) -> HandlerResult<ChannelResult, Error> {
     let mut output = HandlerOutput::builder();
@@ -29,8 +31,12 @@ pub(crate) fn process(
         return Err(Kind::InvalidChannelState(msg.channel_id().clone()).into());
     }
 
-    // Channel capabilities
-    let channel_cap = ctx.authenticated_capability(&msg.port_id().clone())?;
+    // The module which opened the channel must hold the capability of the channel.
+    let channel_cap = channel_cap
+        .filter(|cap| ctx.authenticate_channel(cap, msg.port_id(), msg.channel_id()))
+        .ok_or_else(|| {
+            Kind::InvalidChannelCapability(msg.port_id().clone(), msg.channel_id().clone())
+        })?;
 
     // An OPEN IBC connection running on the local (host) chain should exist.
 
@@ -67,14 +73,17 @@ pub(crate) fn process(
         channel_end.version(),
     );
     //2. Verify proofs
-    verify_proofs(
+    let client_state = check_client(ctx, &conn)?;
+    let client_state = verify_proofs(
         ctx,
+        &client_state,
         &channel_end,
         &conn,
         &expected_channel_end,
         &msg.proofs(),
     )
-    .map_err(|e| Kind::FailedChanneOpenAckVerification.context(e))?;
+    .map_err(|e| Kind::FailedChanneOpenAckVerification.context(e))?
+    .map(|state| (conn.client_id().clone(), state));
 
     output.log("success: channel open confirm ");
 
@@ -83,9 +92,11 @@ pub(crate) fn process(
 
     let result = ChannelResult {
         port_id: msg.port_id().clone(),
-        channel_id: Some(msg.channel_id().clone()),
-        channel_cap,
+        channel_id: msg.channel_id().clone(),
+        channel_id_state: ChannelIdState::Reused,
+        channel_cap: channel_cap.clone(),
         channel_end,
+        client_state,
     };
 
     let event_attributes = Attributes {
@@ -112,6 +123,7 @@ mod tests {
     use crate::ics03_connection::version::get_compatible_versions;
 
     use crate::ics04_channel::channel::{ChannelEnd, Counterparty, State};
+    use crate::ics04_channel::handler::test_util::channel_msg_capability;
     use crate::ics04_channel::handler::{dispatch, ChannelResult};
     use crate::ics04_channel::msgs::chan_open_confirm::test_util::get_dummy_raw_msg_chan_open_confirm;
     use crate::ics04_channel::msgs::chan_open_confirm::MsgChannelOpenConfirm;
@@ -209,7 +221,8 @@ mod tests {
         .collect();
 
         for test in tests {
-            let res = dispatch(&test.ctx, test.msg.clone());
+            let channel_cap = channel_msg_capability(&test.ctx, &test.msg);
+            let res = dispatch(&test.ctx, channel_cap.as_ref(), test.msg.clone());
             // Additionally check the events and the output objects in the result.
             match res {
                 Ok(proto_output) => {
@@ -6,17 +6,22 @@ use crate::ics04_channel::channel::{ChannelEnd, State};
 use crate::ics04_channel::context::ChannelReader;
 use crate::ics04_channel::error::{Error, Kind};
 use crate::ics04_channel::events::Attributes;
-use crate::ics04_channel::handler::ChannelResult;
+use crate::ics04_channel::handler::{ChannelIdState, ChannelResult};
 use crate::ics04_channel::msgs::chan_open_init::MsgChannelOpenInit;
+use crate::ics05_port::capabilities::Capability;
+use crate::ics24_host::identifier::ChannelId;
 
 pub(crate) fn process(
     ctx: &dyn ChannelReader,
+    channel_cap: Option<&Capability>,
     msg: MsgChannelOpenInit,
 ) -> HandlerResult<ChannelResult, Error> {
     let mut output = HandlerOutput::builder();
 
-    // Channel capabilities
-    let channel_cap = ctx.authenticated_capability(&msg.port_id().clone())?;
+    // The module bound to the port must hold the capability of the port.
+    let channel_cap = channel_cap
+        .filter(|cap| ctx.authenticate(cap, msg.port_id()))
+        .ok_or_else(|| Kind::InvalidPortCapability(msg.port_id().clone()))?;
 
     if msg.channel().connection_hops().len() != 1 {
         return Err(Kind::InvalidConnectionHopsLength.into());
@@ -57,9 +62,11 @@ pub(crate) fn process(
 
     let result = ChannelResult {
         port_id: msg.port_id().clone(),
-        channel_id: None,
+        channel_id: ChannelId::new(ctx.channel_counter()),
+        channel_id_state: ChannelIdState::Generated,
         channel_end: new_channel_end,
-        channel_cap,
+        channel_cap: channel_cap.clone(),
+        client_state: None,
     };
 
     let event_attributes = Attributes {
@@ -83,6 +90,8 @@ mod tests {
     use crate::ics03_connection::msgs::conn_open_init::MsgConnectionOpenInit;
     use crate::ics03_connection::version::get_compatible_versions;
     use crate::ics04_channel::channel::State;
+    use crate::ics04_channel::error::Kind;
+    use crate::ics04_channel::handler::test_util::channel_msg_capability;
     use crate::ics04_channel::handler::{dispatch, ChannelResult};
     use crate::ics04_channel::msgs::chan_open_init::test_util::get_dummy_raw_msg_chan_open_init;
     use crate::ics04_channel::msgs::chan_open_init::MsgChannelOpenInit;
@@ -155,7 +164,8 @@ mod tests {
         .collect();
 
         for test in tests {
-            let res = dispatch(&test.ctx, test.msg.clone());
+            let channel_cap = channel_msg_capability(&test.ctx, &test.msg);
+            let res = dispatch(&test.ctx, channel_cap.as_ref(), test.msg.clone());
             // Additionally check the events and the output objects in the result.
             match res {
                 Ok(proto_output) => {
@@ -196,4 +206,41 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn chan_open_init_requires_port_capability() {
+        let msg_chan_init =
+            MsgChannelOpenInit::try_from(get_dummy_raw_msg_chan_open_init()).unwrap();
+        let msg_conn_init =
+            MsgConnectionOpenInit::try_from(get_dummy_msg_conn_open_init()).unwrap();
+        let init_conn_end = ConnectionEnd::new(
+            ConnectionState::Init,
+            msg_conn_init.client_id().clone(),
+            msg_conn_init.counterparty().clone(),
+            get_compatible_versions(),
+            msg_conn_init.delay_period,
+        );
+
+        let mut context = MockContext::default()
+            .with_connection(
+                ConnectionId::from_str("defaultConnection-0").unwrap(),
+                init_conn_end,
+            )
+            .with_port_capability(msg_chan_init.port_id().clone());
+        let foreign_cap = context.foreign_capability();
+        let msg = ChannelMsg::ChannelOpenInit(msg_chan_init.clone());
+
+        // Neither a module holding no capability, nor one holding a capability other than the
+        // capability of the port, may open a channel on the port.
+        for channel_cap in [None, Some(&foreign_cap)] {
+            let err = dispatch(&context, channel_cap, msg.clone()).unwrap_err();
+            assert!(matches!(
+                err.kind(),
+                Kind::InvalidPortCapability(port_id) if port_id == msg_chan_init.port_id()
+            ));
+        }
+
+        let port_cap = channel_msg_capability(&context, &msg);
+        assert!(dispatch(&context, port_cap.as_ref(), msg).is_ok());
+    }
 }
@@ -9,12 +9,15 @@ use crate::ics04_channel::channel::{ChannelEnd, Counterparty, State};
 use crate::ics04_channel::context::ChannelReader;
 use crate::ics04_channel::error::{Error, Kind};
 use crate::ics04_channel::events::Attributes;
-use crate::ics04_channel::handler::verify::verify_proofs;
-use crate::ics04_channel::handler::ChannelResult;
+use crate::ics04_channel::handler::verify::{check_client, verify_proofs};
+use crate::ics04_channel::handler::{ChannelIdState, ChannelResult};
 use crate::ics04_channel::msgs::chan_open_try::MsgChannelOpenTry;
+use crate::ics05_port::capabilities::Capability;
+use crate::ics24_host::identifier::ChannelId;
 
 pub(crate) fn process(
     ctx: &dyn ChannelReader,
+    channel_cap: Option<&Capability>,
     msg: MsgChannelOpenTry,
 ) -> HandlerResult<ChannelResult, Error> {
     let mut output = HandlerOutput::builder();
@@ -55,8 +58,8 @@ pub(crate) fn process(
                 ))
             }
         }
-        // No channel id is supplied to  create a new channel end. Note: the id is assigned
-        // by the ChannelKeeper.
+        // No channel id is supplied to  create a new channel end. Note: the id is generated
+        // from the channel counter below.
         None => {
             channel_id = None;
 
@@ -96,8 +99,18 @@ pub(crate) fn process(
         return Err(Kind::ChannelFeatureNotSuportedByConnection.into());
     }
 
-    // Channel capabilities
-    let channel_cap = ctx.authenticated_capability(&msg.port_id().clone())?;
+    // The module bound to the port must hold the capability of the port, or the one of the
+    // channel if it already exists.
+    let channel_cap = match &channel_id {
+        Some(channel_id) => channel_cap
+            .filter(|cap| ctx.authenticate_channel(cap, msg.port_id(), channel_id))
+            .ok_or_else(|| {
+                Kind::InvalidChannelCapability(msg.port_id().clone(), channel_id.clone())
+            })?,
+        None => channel_cap
+            .filter(|cap| ctx.authenticate(cap, msg.port_id()))
+            .ok_or_else(|| Kind::InvalidPortCapability(msg.port_id().clone()))?,
+    };
 
     if msg.channel().version().is_empty() {
         return Err(Kind::InvalidVersion.into());
@@ -123,25 +136,38 @@ pub(crate) fn process(
         msg.counterparty_version().clone(),
     );
 
-    verify_proofs(
+    let client_state = check_client(ctx, &conn)?;
+    let client_state = verify_proofs(
         ctx,
+        &client_state,
         &new_channel_end,
         &conn,
         &expected_channel_end,
         &msg.proofs(),
     )
-    .map_err(|e| Kind::FailedChanneOpenTryVerification.context(e))?;
+    .map_err(|e| Kind::FailedChanneOpenTryVerification.context(e))?
+    .map(|state| (conn.client_id().clone(), state));
 
     output.log("success: channel open try ");
 
     // Transition the channel end to the new state & pick a version.
     new_channel_end.set_state(State::TryOpen);
 
+    let (channel_id, channel_id_state) = match channel_id {
+        Some(channel_id) => (channel_id, ChannelIdState::Reused),
+        None => (
+            ChannelId::new(ctx.channel_counter()),
+            ChannelIdState::Generated,
+        ),
+    };
+
     let result = ChannelResult {
         port_id: msg.port_id().clone(),
-        channel_cap,
+        channel_cap: channel_cap.clone(),
         channel_id,
+        channel_id_state,
         channel_end: new_channel_end,
+        client_state,
     };
 
     let event_attributes = Attributes {
@@ -169,6 +195,7 @@ mod tests {
     use crate::ics03_connection::version::get_compatible_versions;
 
     use crate::ics04_channel::channel::{ChannelEnd, State};
+    use crate::ics04_channel::handler::test_util::channel_msg_capability;
     use crate::ics04_channel::handler::{dispatch, ChannelResult};
     use crate::ics04_channel::msgs::chan_open_try::test_util::get_dummy_raw_msg_chan_open_try;
     use crate::ics04_channel::msgs::chan_open_try::test_util::get_dummy_raw_msg_chan_open_try_with_counterparty;
@@ -425,7 +452,8 @@ mod tests {
         .collect();
 
         for test in tests {
-            let res = dispatch(&test.ctx, test.msg.clone());
+            let channel_cap = channel_msg_capability(&test.ctx, &test.msg);
+            let res = dispatch(&test.ctx, channel_cap.as_ref(), test.msg.clone());
             // Additionally check the events and the output objects in the result.
             match res {
                 Ok(proto_output) => {
@@ -462,4 +490,40 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn chan_open_try_verifies_proofs_with_connection_client() {
+        let proof_height = 10;
+        let msg_chan_try = MsgChannelOpenTry::try_from(
+            get_dummy_raw_msg_chan_open_try_with_counterparty(proof_height),
+        )
+        .unwrap();
+
+        let msg_conn_init =
+            MsgConnectionOpenInit::try_from(get_dummy_msg_conn_open_init()).unwrap();
+        let conn_id = ConnectionId::from_str("defaultConnection-0").unwrap();
+        let conn_end = ConnectionEnd::new(
+            ConnectionState::Open,
+            msg_conn_init.client_id().clone(),
+            ConnectionCounterparty::new(
+                msg_conn_init.counterparty().client_id().clone(),
+                Some(conn_id.clone()),
+                msg_conn_init.counterparty().prefix().clone(),
+            ),
+            get_compatible_versions(),
+            msg_conn_init.delay_period,
+        );
+
+        // No channel is stored on the host chain: the proofs are verified with the client of the
+        // connection of the channel.
+        let context = MockContext::default()
+            .with_client(msg_conn_init.client_id(), Height::new(1, proof_height))
+            .with_connection(conn_id, conn_end)
+            .with_port_capability(msg_chan_try.port_id().clone());
+
+        let msg = ChannelMsg::ChannelOpenTry(msg_chan_try);
+        let channel_cap = channel_msg_capability(&context, &msg);
+        let output = dispatch(&context, channel_cap.as_ref(), msg).unwrap();
+        assert_eq!(output.result.channel_end.state(), &State::TryOpen);
+    }
 }
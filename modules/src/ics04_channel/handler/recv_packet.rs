@@ -0,0 +1,498 @@
+//! Protocol logic specific to ICS4 messages of type `MsgRecvPacket`.
+use crate::events::IBCEvent;
+use crate::handler::{HandlerOutput, HandlerResult};
+use crate::ics02_client::client_def::AnyClientState;
+use crate::ics03_connection::connection::State as ConnectionState;
+use crate::ics04_channel::channel::{Order, State};
+use crate::ics04_channel::context::ChannelReader;
+use crate::ics04_channel::error::{Error, Kind};
+use crate::ics04_channel::events::ReceivePacket;
+use crate::ics04_channel::handler::verify::{check_client, verify_packet_recv_proofs};
+use crate::ics04_channel::handler::PacketResult;
+use crate::ics04_channel::msgs::recv_packet::MsgRecvPacket;
+use crate::ics04_channel::packet::{Receipt, Sequence};
+use crate::ics05_port::capabilities::Capability;
+use crate::ics24_host::identifier::{ChannelId, ClientId, PortId};
+use crate::timestamp::Timestamp;
+
+#[derive(Clone, Debug)]
+pub struct RecvPacketResult {
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+    pub seq: Sequence,
+    /// The next sequence to be received, only for ordered channels.
+    pub seq_number: Option<Sequence>,
+    /// The receipt to store, only for unordered channels.
+    pub receipt: Option<Receipt>,
+    /// The new state of the client of the channel, with its identifier, if verifying the proofs of
+    /// the message updated it (see `ClientDef`).
+    pub client_state: Option<(ClientId, AnyClientState)>,
+}
+
+pub(crate) fn process(
+    ctx: &dyn ChannelReader,
+    channel_cap: Option<&Capability>,
+    msg: MsgRecvPacket,
+) -> HandlerResult<PacketResult, Error> {
+    let mut output = HandlerOutput::builder();
+
+    let packet = msg.packet();
+
+    let dest_channel_end = ctx
+        .channel_end(&(
+            packet.destination_port.clone(),
+            packet.destination_channel.clone(),
+        ))
+        .ok_or_else(|| Kind::ChannelNotFound.context(packet.destination_channel.to_string()))?;
+
+    if !dest_channel_end.state_matches(&State::Open) {
+        return Err(Kind::InvalidChannelState(packet.destination_channel.clone()).into());
+    }
+
+    // The module which opened the receiving channel must hold its capability.
+    if !channel_cap.is_some_and(|cap| {
+        ctx.authenticate_channel(cap, &packet.destination_port, &packet.destination_channel)
+    }) {
+        return Err(Kind::InvalidChannelCapability(
+            packet.destination_port.clone(),
+            packet.destination_channel.clone(),
+        )
+        .into());
+    }
+
+    // The packet must originate from the counterparty of the receiving channel end.
+    let counterparty = dest_channel_end.counterparty();
+    if counterparty.port_id() != &packet.source_port
+        || counterparty.channel_id() != Some(&packet.source_channel)
+    {
+        return Err(Kind::InvalidPacketCounterparty(
+            packet.source_port.clone(),
+            packet.source_channel.clone(),
+        )
+        .into());
+    }
+
+    let connection_end = ctx
+        .connection_end(&dest_channel_end.connection_hops()[0])
+        .ok_or_else(|| Kind::MissingConnection(dest_channel_end.connection_hops()[0].clone()))?;
+
+    if !connection_end.state_matches(&ConnectionState::Open) {
+        return Err(Kind::ConnectionNotOpen(dest_channel_end.connection_hops()[0].clone()).into());
+    }
+
+    // Check if the packet has timed out, according to the local (host) chain height and time.
+    let host_height = ctx.host_height();
+    if packet.timed_out(host_height) {
+        return Err(Kind::LowPacketHeight(host_height, packet.timeout_height).into());
+    }
+
+    let host_timestamp = Timestamp::from(ctx.host_timestamp());
+    if packet.timestamp_expired(&host_timestamp) {
+        return Err(Kind::LowPacketTimestamp(host_timestamp, packet.timeout_timestamp).into());
+    }
+
+    let client_state = check_client(ctx, &connection_end)?;
+    let client_state =
+        verify_packet_recv_proofs(ctx, &client_state, packet, &connection_end, msg.proofs())?
+            .map(|state| (connection_end.client_id().clone(), state));
+
+    let result = if dest_channel_end.order_matches(&Order::Ordered) {
+        let next_seq_recv = ctx
+            .get_next_sequence_recv(&(
+                packet.destination_port.clone(),
+                packet.destination_channel.clone(),
+            ))
+            .ok_or(Kind::MissingNextRecvSeq)?;
+
+        if packet.sequence != next_seq_recv {
+            return Err(Kind::InvalidPacketSequence(packet.sequence, next_seq_recv).into());
+        }
+
+        PacketResult::Recv(RecvPacketResult {
+            port_id: packet.destination_port.clone(),
+            channel_id: packet.destination_channel.clone(),
+            seq: packet.sequence,
+            seq_number: Some(next_seq_recv.increment()),
+            receipt: None,
+            client_state,
+        })
+    } else {
+        let packet_rec = ctx.get_packet_receipt(&(
+            packet.destination_port.clone(),
+            packet.destination_channel.clone(),
+            packet.sequence,
+        ));
+
+        if packet_rec.is_some() {
+            return Err(Kind::PacketAlreadyReceived(packet.sequence).into());
+        }
+
+        PacketResult::Recv(RecvPacketResult {
+            port_id: packet.destination_port.clone(),
+            channel_id: packet.destination_channel.clone(),
+            seq: packet.sequence,
+            seq_number: None,
+            receipt: Some(Receipt::Ok),
+            client_state,
+        })
+    };
+
+    output.log("success: packet receive ");
+
+    output.emit(IBCEvent::ReceivePacket(ReceivePacket {
+        height: Default::default(),
+        packet: msg.packet,
+    }));
+
+    Ok(output.with_result(result))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use ibc_proto::ibc::core::client::v1::Height as RawHeight;
+
+    use crate::events::IBCEvent;
+    use crate::ics02_client::client_def::AnyHeader;
+    use crate::ics02_client::client_type::ClientType;
+    use crate::ics03_connection::connection::ConnectionEnd;
+    use crate::ics03_connection::connection::Counterparty as ConnectionCounterparty;
+    use crate::ics03_connection::connection::State as ConnectionState;
+    use crate::ics03_connection::context::ConnectionReader;
+    use crate::ics03_connection::version::get_compatible_versions;
+    use crate::ics04_channel::channel::{ChannelEnd, Counterparty, Order, State};
+    use crate::ics04_channel::context::ChannelReader;
+    use crate::ics04_channel::handler::test_util::packet_msg_capability;
+    use crate::ics04_channel::handler::{packet_dispatch, PacketResult};
+    use crate::ics04_channel::msgs::recv_packet::test_util::get_dummy_raw_msg_recv_packet;
+    use crate::ics04_channel::msgs::recv_packet::MsgRecvPacket;
+    use crate::ics04_channel::msgs::PacketMsg;
+    use crate::ics04_channel::packet::Receipt;
+    use crate::ics18_relayer::context::ICS18Context;
+    use crate::ics18_relayer::utils::build_client_update_datagram;
+    use crate::ics24_host::identifier::{ChainId, ClientId, ConnectionId};
+    use crate::ics24_host::Path;
+    use crate::ics26_routing::msgs::ICS26Envelope;
+    use crate::mock::context::MockContext;
+    use crate::mock::host::HostType;
+    use crate::timestamp::Timestamp;
+    use crate::Height;
+
+    #[test]
+    fn recv_packet_processing() {
+        struct Test {
+            name: String,
+            ctx: MockContext,
+            msg: MsgRecvPacket,
+            want_pass: bool,
+        }
+
+        // The host chain is at height 5; the packet times out at height 10.
+        let context = MockContext::default();
+
+        let msg = MsgRecvPacket::try_from(get_dummy_raw_msg_recv_packet(10)).unwrap();
+
+        let packet = msg.packet.clone();
+
+        // The packet timed out at a time in the past of the host chain.
+        let mut msg_expired = msg.clone();
+        msg_expired.packet.timeout_timestamp =
+            Timestamp::from_nanoseconds(1_600_000_000_000_000_000).unwrap();
+
+        let dest_channel_end = ChannelEnd::new(
+            State::Open,
+            Order::default(),
+            Counterparty::new(
+                packet.source_port.clone(),
+                Some(packet.source_channel.clone()),
+            ),
+            vec![ConnectionId::default()],
+            "ics20".to_string(),
+        );
+
+        let connection_end = ConnectionEnd::new(
+            ConnectionState::Open,
+            ClientId::default(),
+            ConnectionCounterparty::new(
+                ClientId::default(),
+                Some(ConnectionId::default()),
+                Default::default(),
+            ),
+            get_compatible_versions(),
+            0,
+        );
+
+        let ordered_channel_end = ChannelEnd::new(
+            State::Open,
+            Order::Ordered,
+            Counterparty::new(
+                packet.source_port.clone(),
+                Some(packet.source_channel.clone()),
+            ),
+            vec![ConnectionId::default()],
+            "ics20".to_string(),
+        );
+
+        let good_ctx = context
+            .clone()
+            .with_client(&ClientId::default(), msg.proofs().height())
+            .with_connection(ConnectionId::default(), connection_end)
+            .with_port_capability(packet.destination_port.clone());
+
+        let tests: Vec<Test> = vec![
+            Test {
+                name: "Processing fails because no channel exists in the context".to_string(),
+                ctx: context,
+                msg: msg.clone(),
+                want_pass: false,
+            },
+            Test {
+                name: "Good parameters, unordered channel".to_string(),
+                ctx: good_ctx.clone().with_channel_init(
+                    packet.destination_port.clone(),
+                    packet.destination_channel.clone(),
+                    dest_channel_end.clone(),
+                ),
+                msg: msg.clone(),
+                want_pass: true,
+            },
+            Test {
+                name: "Packet timeout timestamp is not after the host timestamp".to_string(),
+                ctx: good_ctx.clone().with_channel_init(
+                    packet.destination_port.clone(),
+                    packet.destination_channel.clone(),
+                    dest_channel_end.clone(),
+                ),
+                msg: msg_expired,
+                want_pass: false,
+            },
+            Test {
+                name: "Packet was already received on the unordered channel".to_string(),
+                ctx: good_ctx
+                    .clone()
+                    .with_channel_init(
+                        packet.destination_port.clone(),
+                        packet.destination_channel.clone(),
+                        dest_channel_end,
+                    )
+                    .with_packet_receipt(
+                        packet.destination_port.clone(),
+                        packet.destination_channel.clone(),
+                        packet.sequence,
+                        Receipt::Ok,
+                    ),
+                msg: msg.clone(),
+                want_pass: false,
+            },
+            Test {
+                name: "Good parameters, ordered channel".to_string(),
+                ctx: good_ctx
+                    .clone()
+                    .with_channel_init(
+                        packet.destination_port.clone(),
+                        packet.destination_channel.clone(),
+                        ordered_channel_end.clone(),
+                    )
+                    .with_recv_sequence(
+                        packet.destination_port.clone(),
+                        packet.destination_channel.clone(),
+                        packet.sequence,
+                    ),
+                msg: msg.clone(),
+                want_pass: true,
+            },
+            Test {
+                name: "Packet sequence does not match the next receive sequence".to_string(),
+                ctx: good_ctx
+                    .with_channel_init(
+                        packet.destination_port.clone(),
+                        packet.destination_channel.clone(),
+                        ordered_channel_end,
+                    )
+                    .with_recv_sequence(
+                        packet.destination_port.clone(),
+                        packet.destination_channel.clone(),
+                        packet.sequence.increment(),
+                    ),
+                msg: msg.clone(),
+                want_pass: false,
+            },
+        ]
+        .into_iter()
+        .collect();
+
+        for test in tests {
+            let msg = PacketMsg::RecvPacket(test.msg.clone());
+            let channel_cap = packet_msg_capability(&test.ctx, &msg);
+            let res = packet_dispatch(&test.ctx, channel_cap.as_ref(), msg);
+            // Additionally check the events and the output objects in the result.
+            match res {
+                Ok(proto_output) => {
+                    assert!(
+                        test.want_pass,
+                        "recv_packet: test passed but was supposed to fail for test: {}, \nparams {:?} {:?}",
+                        test.name,
+                        test.msg.clone(),
+                        test.ctx.clone()
+                    );
+                    assert!(!proto_output.events.is_empty()); // Some events must exist.
+                    assert!(matches!(proto_output.result, PacketResult::Recv(_)));
+
+                    for e in proto_output.events.iter() {
+                        assert!(matches!(e, &IBCEvent::ReceivePacket(_)));
+                    }
+                }
+                Err(e) => {
+                    assert!(
+                        !test.want_pass,
+                        "recv_packet: did not pass test: {}, \nparams {:?} {:?} error: {:?}",
+                        test.name,
+                        test.msg.clone(),
+                        test.ctx.clone(),
+                        e,
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn recv_packet_with_tendermint_proofs() {
+        struct Test {
+            name: String,
+            msg: MsgRecvPacket,
+            want_pass: bool,
+        }
+
+        let chain_id_a = ChainId::new("mockgaiaA".to_string(), 1);
+        let client_on_b_for_a = ClientId::new(ClientType::Tendermint, 0).unwrap();
+
+        let mut raw_msg = get_dummy_raw_msg_recv_packet(20);
+        let packet = MsgRecvPacket::try_from(raw_msg.clone()).unwrap().packet;
+
+        // The source chain (A) commits to the packet in its store.
+        let ctx_a = MockContext::new(
+            chain_id_a.clone(),
+            HostType::SyntheticTendermint,
+            5,
+            Height::new(1, 5),
+        );
+        let commitment = ctx_a.packet_commitment(&packet);
+        let mut ctx_a = ctx_a.with_packet_commitment(
+            packet.source_port.clone(),
+            packet.source_channel.clone(),
+            packet.sequence,
+            commitment,
+        );
+        ctx_a.advance_host_chain_height();
+        let proof_height = ctx_a.query_latest_height();
+
+        // The destination chain (B) has a channel on top of a Tendermint client for chain A.
+        let connection_end = ConnectionEnd::new(
+            ConnectionState::Open,
+            client_on_b_for_a.clone(),
+            ConnectionCounterparty::new(
+                ClientId::default(),
+                Some(ConnectionId::default()),
+                ctx_a.commitment_prefix(),
+            ),
+            get_compatible_versions(),
+            0,
+        );
+        let channel_end = ChannelEnd::new(
+            State::Open,
+            Order::default(),
+            Counterparty::new(
+                packet.source_port.clone(),
+                Some(packet.source_channel.clone()),
+            ),
+            vec![ConnectionId::default()],
+            "ics20".to_string(),
+        );
+        let mut ctx_b = MockContext::default()
+            .with_client_parametrized_with_chain_id(
+                chain_id_a,
+                &client_on_b_for_a,
+                Height::new(1, 5),
+                Some(ClientType::Tendermint),
+                None,
+            )
+            .with_connection(ConnectionId::default(), connection_end)
+            .with_port_capability(packet.destination_port.clone())
+            .with_channel_init(
+                packet.destination_port.clone(),
+                packet.destination_channel.clone(),
+                channel_end,
+            );
+
+        // Update the client on B to the height at which A committed to the packet.
+        let mut header = ctx_a.query_latest_header().unwrap();
+        if let AnyHeader::Tendermint(header) = &mut header {
+            header.trusted_height = Height::new(1, 5);
+        }
+        let update_msg = build_client_update_datagram(&ctx_b, &client_on_b_for_a, header).unwrap();
+        ctx_b.deliver(ICS26Envelope::ICS2Msg(update_msg)).unwrap();
+
+        let commitment_path = Path::Commitments {
+            port_id: packet.source_port.clone(),
+            channel_id: packet.source_channel.clone(),
+            sequence: packet.sequence,
+        };
+        raw_msg.proof_commitment = ctx_a
+            .query_proof(proof_height, &commitment_path)
+            .unwrap()
+            .into();
+        raw_msg.proof_height = Some(RawHeight {
+            revision_number: proof_height.revision_number,
+            revision_height: proof_height.revision_height,
+        });
+
+        let mut other_data = raw_msg.clone();
+        other_data.packet.as_mut().unwrap().data = b"other".to_vec();
+
+        let mut absence_proof = raw_msg.clone();
+        absence_proof.proof_commitment = ctx_a
+            .query_proof(
+                proof_height,
+                &Path::Commitments {
+                    port_id: packet.source_port.clone(),
+                    channel_id: packet.source_channel.clone(),
+                    sequence: packet.sequence.increment(),
+                },
+            )
+            .unwrap()
+            .into();
+
+        let tests: Vec<Test> = vec![
+            Test {
+                name: "The packet commitment is proven against the root of chain A".to_string(),
+                msg: MsgRecvPacket::try_from(raw_msg).unwrap(),
+                want_pass: true,
+            },
+            Test {
+                name: "The proof does not match the packet data".to_string(),
+                msg: MsgRecvPacket::try_from(other_data).unwrap(),
+                want_pass: false,
+            },
+            Test {
+                name: "The proof is for another packet".to_string(),
+                msg: MsgRecvPacket::try_from(absence_proof).unwrap(),
+                want_pass: false,
+            },
+        ];
+
+        for test in tests {
+            let msg = PacketMsg::RecvPacket(test.msg);
+            let channel_cap = packet_msg_capability(&ctx_b, &msg);
+            let res = packet_dispatch(&ctx_b, channel_cap.as_ref(), msg);
+            assert_eq!(
+                test.want_pass,
+                res.is_ok(),
+                "recv_packet: test {} failed with result {:?}",
+                test.name,
+                res.err()
+            );
+        }
+    }
+}
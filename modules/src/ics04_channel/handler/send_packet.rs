@@ -0,0 +1,312 @@
+//! Protocol logic for sending a packet (ICS4 `sendPacket`). Unlike the other packet handlers, this
+//! is not triggered by a message, but by an application module running on the host chain.
+use crate::events::IBCEvent;
+use crate::handler::{HandlerOutput, HandlerResult};
+use crate::ics02_client::state::{ClientState, ConsensusState};
+use crate::ics04_channel::channel::State;
+use crate::ics04_channel::commitment::PacketCommitment;
+use crate::ics04_channel::events::SendPacket;
+use crate::ics04_channel::handler::PacketResult;
+use crate::ics04_channel::packet::{Packet, Sequence};
+use crate::ics04_channel::{context::ChannelReader, error::Error, error::Kind};
+use crate::ics05_port::capabilities::Capability;
+use crate::ics24_host::identifier::{ChannelId, PortId};
+
+#[derive(Clone, Debug)]
+pub struct SendPacketResult {
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+    pub seq: Sequence,
+    pub seq_number: Sequence,
+    pub commitment: PacketCommitment,
+}
+
+/// Sends `packet` on behalf of the module holding `channel_cap`, which must be the capability of
+/// the source channel of the packet.
+pub fn send_packet(
+    ctx: &dyn ChannelReader,
+    channel_cap: &Capability,
+    packet: Packet,
+) -> HandlerResult<PacketResult, Error> {
+    let mut output = HandlerOutput::builder();
+
+    let source_channel_end = ctx
+        .channel_end(&(packet.source_port.clone(), packet.source_channel.clone()))
+        .ok_or_else(|| Kind::ChannelNotFound.context(packet.source_channel.to_string()))?;
+
+    if source_channel_end.state_matches(&State::Closed) {
+        return Err(Kind::ChannelAlreadyClosed(packet.source_channel).into());
+    }
+
+    if !ctx.authenticate_channel(channel_cap, &packet.source_port, &packet.source_channel) {
+        return Err(Kind::InvalidChannelCapability(
+            packet.source_port.clone(),
+            packet.source_channel.clone(),
+        )
+        .into());
+    }
+
+    let counterparty = source_channel_end.counterparty();
+    if counterparty.port_id() != &packet.destination_port
+        || counterparty.channel_id() != Some(&packet.destination_channel)
+    {
+        return Err(Kind::InvalidPacketCounterparty(
+            packet.destination_port.clone(),
+            packet.destination_channel,
+        )
+        .into());
+    }
+
+    let connection_end = ctx
+        .connection_end(&source_channel_end.connection_hops()[0])
+        .ok_or_else(|| Kind::MissingConnection(source_channel_end.connection_hops()[0].clone()))?;
+
+    let client_id = connection_end.client_id().clone();
+
+    let client_state = ctx
+        .client_state(&client_id)
+        .ok_or_else(|| Kind::MissingClientState.context(client_id.to_string()))?;
+
+    // Prevent accidental sends with clients that cannot be updated.
+    if client_state.is_frozen() {
+        return Err(Kind::FrozenClient.context(client_id.to_string()).into());
+    }
+
+    // Check that the packet has not already timed out according to the latest height of the
+    // client tracking the receiving chain.
+    let latest_height = client_state.latest_height();
+    if packet.timed_out(latest_height) {
+        return Err(Kind::LowPacketHeight(latest_height, packet.timeout_height).into());
+    }
+
+    // Likewise for the timeout timestamp, according to the time of the latest consensus state.
+    let consensus_state = ctx
+        .client_consensus_state(&client_id, latest_height)
+        .ok_or_else(|| Kind::MissingClientConsensusState.context(client_id.to_string()))?;
+    let latest_timestamp = consensus_state.timestamp();
+    if packet.timestamp_expired(&latest_timestamp) {
+        return Err(Kind::LowPacketTimestamp(latest_timestamp, packet.timeout_timestamp).into());
+    }
+
+    let next_seq_send = ctx
+        .get_next_sequence_send(&(packet.source_port.clone(), packet.source_channel.clone()))
+        .ok_or(Kind::MissingNextSendSeq)?;
+
+    if packet.sequence != next_seq_send {
+        return Err(Kind::InvalidPacketSequence(packet.sequence, next_seq_send).into());
+    }
+
+    output.log("success: packet send ");
+
+    let result = PacketResult::Send(SendPacketResult {
+        port_id: packet.source_port.clone(),
+        channel_id: packet.source_channel.clone(),
+        seq: packet.sequence,
+        seq_number: next_seq_send.increment(),
+        commitment: ctx.packet_commitment(&packet),
+    });
+
+    output.emit(IBCEvent::SendPacket(SendPacket {
+        height: Default::default(),
+        packet,
+    }));
+
+    Ok(output.with_result(result))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use std::str::FromStr;
+
+    use crate::events::IBCEvent;
+    use crate::ics03_connection::connection::ConnectionEnd;
+    use crate::ics03_connection::connection::Counterparty as ConnectionCounterparty;
+    use crate::ics03_connection::connection::State as ConnectionState;
+    use crate::ics03_connection::version::get_compatible_versions;
+    use crate::ics04_channel::channel::{ChannelEnd, Counterparty, Order, State};
+    use crate::ics04_channel::handler::send_packet::send_packet;
+    use crate::ics04_channel::packet::test_utils::get_dummy_raw_packet;
+    use crate::ics04_channel::packet::Packet;
+    use crate::ics05_port::capabilities::Capability;
+    use crate::ics05_port::context::PortReader;
+    use crate::ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId};
+    use crate::mock::client_state::MockConsensusState;
+    use crate::mock::context::MockContext;
+    use crate::mock::header::MockHeader;
+    use crate::timestamp::Timestamp;
+    use crate::Height;
+
+    #[test]
+    fn send_packet_processing() {
+        struct Test {
+            name: String,
+            ctx: MockContext,
+            channel_cap: Capability,
+            packet: Packet,
+            want_pass: bool,
+        }
+
+        let context = MockContext::default();
+
+        let mut packet = Packet::try_from(get_dummy_raw_packet(10)).unwrap();
+        packet.sequence = 1.into();
+
+        let channel_end = ChannelEnd::new(
+            State::TryOpen,
+            Order::default(),
+            Counterparty::new(
+                packet.destination_port.clone(),
+                Some(packet.destination_channel.clone()),
+            ),
+            vec![ConnectionId::default()],
+            "ics20".to_string(),
+        );
+
+        let connection_end = ConnectionEnd::new(
+            ConnectionState::Open,
+            ClientId::default(),
+            ConnectionCounterparty::new(
+                ClientId::default(),
+                Some(ConnectionId::default()),
+                Default::default(),
+            ),
+            get_compatible_versions(),
+            0,
+        );
+
+        let mut packet_old = packet.clone();
+        packet_old.timeout_height = Height::new(1, 1);
+
+        // The client's latest consensus state is timestamped after the packet timeout timestamp,
+        // but before that of `packet_later`.
+        let latest_timestamp = Timestamp::from_nanoseconds(1_700_000_000_000_000_000).unwrap();
+        let mut packet_expired = packet.clone();
+        packet_expired.timeout_timestamp =
+            Timestamp::from_nanoseconds(1_600_000_000_000_000_000).unwrap();
+        let mut packet_later = packet.clone();
+        packet_later.timeout_timestamp =
+            Timestamp::from_nanoseconds(1_800_000_000_000_000_000).unwrap();
+
+        let good_ctx = context
+            .clone()
+            .with_client(&ClientId::default(), Height::new(1, 5))
+            .with_connection(ConnectionId::default(), connection_end)
+            .with_port_capability(packet.source_port.clone())
+            .with_channel_init(
+                packet.source_port.clone(),
+                packet.source_channel.clone(),
+                channel_end.clone(),
+            )
+            .with_send_sequence(
+                packet.source_port.clone(),
+                packet.source_channel.clone(),
+                1.into(),
+            );
+
+        let (_, channel_cap) = good_ctx
+            .lookup_module_by_channel(&packet.source_port, &packet.source_channel)
+            .unwrap();
+
+        let timestamp_ctx = good_ctx.clone().with_consensus_state(
+            &ClientId::default(),
+            Height::new(1, 5),
+            MockConsensusState::new(
+                MockHeader::new(Height::new(1, 5)).with_timestamp(latest_timestamp),
+            )
+            .into(),
+        );
+
+        // Another module, bound to another port, owns a channel of its own.
+        let intruder_port = PortId::from_str("intruder").unwrap();
+        let intruder_channel = ChannelId::from_str("intruderchannel").unwrap();
+        let intruder_ctx = good_ctx
+            .clone()
+            .with_port_capability(intruder_port.clone())
+            .with_channel_init(intruder_port.clone(), intruder_channel.clone(), channel_end);
+
+        let (_, intruder_cap) = intruder_ctx
+            .lookup_module_by_channel(&intruder_port, &intruder_channel)
+            .unwrap();
+
+        let tests: Vec<Test> = vec![
+            Test {
+                name: "Processing fails because no channel exists in the context".to_string(),
+                ctx: context,
+                channel_cap: channel_cap.clone(),
+                packet: packet.clone(),
+                want_pass: false,
+            },
+            Test {
+                name: "Good parameters".to_string(),
+                ctx: good_ctx.clone(),
+                channel_cap: channel_cap.clone(),
+                packet: packet.clone(),
+                want_pass: true,
+            },
+            Test {
+                name: "Module which does not own the source channel cannot send packets"
+                    .to_string(),
+                ctx: intruder_ctx,
+                channel_cap: intruder_cap,
+                packet: packet.clone(),
+                want_pass: false,
+            },
+            Test {
+                name: "Packet timeout timestamp is after the client's latest timestamp".to_string(),
+                ctx: timestamp_ctx.clone(),
+                channel_cap: channel_cap.clone(),
+                packet: packet_later,
+                want_pass: true,
+            },
+            Test {
+                name: "Packet timeout timestamp is not after the client's latest timestamp"
+                    .to_string(),
+                ctx: timestamp_ctx,
+                channel_cap: channel_cap.clone(),
+                packet: packet_expired,
+                want_pass: false,
+            },
+            Test {
+                name: "Packet timeout height is lower than the client's latest height".to_string(),
+                ctx: good_ctx,
+                channel_cap,
+                packet: packet_old,
+                want_pass: false,
+            },
+        ]
+        .into_iter()
+        .collect();
+
+        for test in tests {
+            let res = send_packet(&test.ctx, &test.channel_cap, test.packet.clone());
+            // Additionally check the events and the output objects in the result.
+            match res {
+                Ok(proto_output) => {
+                    assert!(
+                        test.want_pass,
+                        "send_packet: test passed but was supposed to fail for test: {}, \nparams {:?} {:?}",
+                        test.name,
+                        test.packet.clone(),
+                        test.ctx.clone()
+                    );
+                    assert!(!proto_output.events.is_empty()); // Some events must exist.
+
+                    for e in proto_output.events.iter() {
+                        assert!(matches!(e, &IBCEvent::SendPacket(_)));
+                    }
+                }
+                Err(e) => {
+                    assert!(
+                        !test.want_pass,
+                        "send_packet: did not pass test: {}, \nparams {:?} {:?} error: {:?}",
+                        test.name,
+                        test.packet.clone(),
+                        test.ctx.clone(),
+                        e,
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,387 @@
+//! Protocol logic specific to ICS4 messages of type `MsgTimeout`.
+use crate::events::IBCEvent;
+use crate::handler::{HandlerOutput, HandlerResult};
+use crate::ics02_client::client_def::AnyClientState;
+use crate::ics02_client::state::ConsensusState;
+use crate::ics04_channel::channel::{ChannelEnd, Order, State};
+use crate::ics04_channel::context::ChannelReader;
+use crate::ics04_channel::error::{Error, Kind};
+use crate::ics04_channel::events::TimeoutPacket;
+use crate::ics04_channel::handler::verify::{
+    check_client, verify_next_sequence_recv, verify_packet_receipt_absence,
+};
+use crate::ics04_channel::handler::PacketResult;
+use crate::ics04_channel::msgs::timeout::MsgTimeout;
+use crate::ics04_channel::packet::Sequence;
+use crate::ics05_port::capabilities::Capability;
+use crate::ics24_host::identifier::{ChannelId, ClientId, PortId};
+
+#[derive(Clone, Debug)]
+pub struct TimeoutPacketResult {
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+    pub seq: Sequence,
+    /// The closed channel end, only for ordered channels.
+    pub channel: Option<ChannelEnd>,
+    /// The new state of the client of the channel, with its identifier, if verifying the proofs of
+    /// the message updated it (see `ClientDef`).
+    pub client_state: Option<(ClientId, AnyClientState)>,
+}
+
+pub(crate) fn process(
+    ctx: &dyn ChannelReader,
+    channel_cap: Option<&Capability>,
+    msg: MsgTimeout,
+) -> HandlerResult<PacketResult, Error> {
+    let mut output = HandlerOutput::builder();
+
+    let packet = msg.packet();
+
+    let mut source_channel_end = ctx
+        .channel_end(&(packet.source_port.clone(), packet.source_channel.clone()))
+        .ok_or_else(|| Kind::ChannelNotFound.context(packet.source_channel.to_string()))?;
+
+    if !source_channel_end.state_matches(&State::Open) {
+        return Err(Kind::InvalidChannelState(packet.source_channel.clone()).into());
+    }
+
+    // The module which sent the packet must hold the capability of the sending channel.
+    if !channel_cap.is_some_and(|cap| {
+        ctx.authenticate_channel(cap, &packet.source_port, &packet.source_channel)
+    }) {
+        return Err(Kind::InvalidChannelCapability(
+            packet.source_port.clone(),
+            packet.source_channel.clone(),
+        )
+        .into());
+    }
+
+    // The packet must be destined to the counterparty of the sending channel end.
+    let counterparty = source_channel_end.counterparty();
+    if counterparty.port_id() != &packet.destination_port
+        || counterparty.channel_id() != Some(&packet.destination_channel)
+    {
+        return Err(Kind::InvalidPacketCounterparty(
+            packet.destination_port.clone(),
+            packet.destination_channel.clone(),
+        )
+        .into());
+    }
+
+    let connection_end = ctx
+        .connection_end(&source_channel_end.connection_hops()[0])
+        .ok_or_else(|| Kind::MissingConnection(source_channel_end.connection_hops()[0].clone()))?;
+
+    // At the proof height, the counterparty chain must have reached either the timeout height or,
+    // according to the consensus state stored for that height, the timeout timestamp.
+    let proof_height = msg.proofs().height();
+    let client_id = connection_end.client_id();
+    let consensus_state = ctx
+        .client_consensus_state(client_id, proof_height)
+        .ok_or_else(|| Kind::MissingClientConsensusState.context(client_id.to_string()))?;
+    let proof_timestamp = consensus_state.timestamp();
+    if !packet.timed_out(proof_height) && !packet.timestamp_expired(&proof_timestamp) {
+        return Err(Kind::PacketTimeoutNotReached(
+            packet.timeout_height,
+            proof_height,
+            packet.timeout_timestamp,
+            proof_timestamp,
+        )
+        .into());
+    }
+
+    // The packet must have been sent, and not yet acknowledged or timed out.
+    let packet_commitment = ctx
+        .get_packet_commitment(&(
+            packet.source_port.clone(),
+            packet.source_channel.clone(),
+            packet.sequence,
+        ))
+        .ok_or(Kind::PacketCommitmentNotFound(packet.sequence))?;
+
+    if packet_commitment != ctx.packet_commitment(packet) {
+        return Err(Kind::IncorrectPacketCommitment(packet.sequence).into());
+    }
+
+    let client_state = check_client(ctx, &connection_end)?;
+
+    let result = if source_channel_end.order_matches(&Order::Ordered) {
+        // The counterparty must not have received this packet yet.
+        if packet.sequence < msg.next_sequence_recv() {
+            return Err(
+                Kind::InvalidPacketSequence(packet.sequence, msg.next_sequence_recv()).into(),
+            );
+        }
+        let client_state = verify_next_sequence_recv(
+            ctx,
+            &client_state,
+            packet,
+            msg.next_sequence_recv(),
+            &connection_end,
+            msg.proofs(),
+        )?;
+
+        // A timeout on an ordered channel closes the channel.
+        source_channel_end.set_state(State::Closed);
+
+        PacketResult::Timeout(TimeoutPacketResult {
+            port_id: packet.source_port.clone(),
+            channel_id: packet.source_channel.clone(),
+            seq: packet.sequence,
+            channel: Some(source_channel_end),
+            client_state: client_state.map(|state| (client_id.clone(), state)),
+        })
+    } else {
+        let client_state = verify_packet_receipt_absence(
+            ctx,
+            &client_state,
+            packet,
+            &connection_end,
+            msg.proofs(),
+        )?;
+
+        PacketResult::Timeout(TimeoutPacketResult {
+            port_id: packet.source_port.clone(),
+            channel_id: packet.source_channel.clone(),
+            seq: packet.sequence,
+            channel: None,
+            client_state: client_state.map(|state| (client_id.clone(), state)),
+        })
+    };
+
+    output.log("success: packet timeout ");
+
+    output.emit(IBCEvent::TimeoutPacket(TimeoutPacket {
+        height: Default::default(),
+        packet: msg.packet,
+    }));
+
+    Ok(output.with_result(result))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use ibc_proto::ibc::core::channel::v1::MsgTimeout as RawMsgTimeout;
+
+    use crate::events::IBCEvent;
+    use crate::ics03_connection::connection::ConnectionEnd;
+    use crate::ics03_connection::connection::Counterparty as ConnectionCounterparty;
+    use crate::ics03_connection::connection::State as ConnectionState;
+    use crate::ics03_connection::version::get_compatible_versions;
+    use crate::ics04_channel::channel::{ChannelEnd, Counterparty, Order, State};
+    use crate::ics04_channel::context::ChannelReader;
+    use crate::ics04_channel::handler::test_util::packet_msg_capability;
+    use crate::ics04_channel::handler::{packet_dispatch, PacketResult};
+    use crate::ics04_channel::msgs::timeout::test_util::get_dummy_raw_msg_timeout;
+    use crate::ics04_channel::msgs::timeout::MsgTimeout;
+    use crate::ics04_channel::msgs::PacketMsg;
+    use crate::ics24_host::identifier::{ClientId, ConnectionId};
+    use crate::mock::client_state::MockConsensusState;
+    use crate::mock::context::MockContext;
+    use crate::mock::header::MockHeader;
+    use crate::timestamp::Timestamp;
+    use crate::Height;
+
+    #[test]
+    fn timeout_packet_processing() {
+        struct Test {
+            name: String,
+            ctx: MockContext,
+            msg: MsgTimeout,
+            want_pass: bool,
+        }
+
+        let context = MockContext::default();
+
+        let height = 10;
+
+        // Returns a timeout message with proof height `height` for a packet timing out at
+        // `timeout_height`, on the same revision as the proof, and at `timeout_timestamp`.
+        let timeout_msg = |timeout_height: u64, timeout_timestamp: u64| -> MsgTimeout {
+            let mut raw_msg: RawMsgTimeout = get_dummy_raw_msg_timeout(height);
+            if let Some(raw_packet) = raw_msg.packet.as_mut() {
+                raw_packet.timeout_height = Some(Height::new(0, timeout_height).into());
+                raw_packet.timeout_timestamp = timeout_timestamp;
+            }
+            MsgTimeout::try_from(raw_msg).unwrap()
+        };
+
+        let msg = timeout_msg(height, 0);
+        let packet = msg.packet.clone();
+
+        // The proof height is below the timeout height of the packet.
+        let msg_early = timeout_msg(height + 5, 0);
+
+        // The proof height is below the timeout height of the packet, but the time of the
+        // consensus state at the proof height is past its timeout timestamp.
+        let proof_timestamp = Timestamp::from_nanoseconds(1_700_000_000_000_000_000).unwrap();
+        let msg_timestamp = timeout_msg(height + 5, 1_600_000_000_000_000_000);
+        let msg_timestamp_early = timeout_msg(height + 5, 1_800_000_000_000_000_000);
+
+        let mut source_channel_end = ChannelEnd::new(
+            State::Open,
+            Order::default(),
+            Counterparty::new(
+                packet.destination_port.clone(),
+                Some(packet.destination_channel.clone()),
+            ),
+            vec![ConnectionId::default()],
+            "ics20".to_string(),
+        );
+
+        let connection_end = ConnectionEnd::new(
+            ConnectionState::Open,
+            ClientId::default(),
+            ConnectionCounterparty::new(
+                ClientId::default(),
+                Some(ConnectionId::default()),
+                Default::default(),
+            ),
+            get_compatible_versions(),
+            0,
+        );
+
+        let good_ctx = context
+            .clone()
+            .with_client(&ClientId::default(), msg.proofs().height())
+            .with_connection(ConnectionId::default(), connection_end)
+            .with_port_capability(packet.source_port.clone())
+            .with_channel_init(
+                packet.source_port.clone(),
+                packet.source_channel.clone(),
+                source_channel_end.clone(),
+            );
+
+        let commitment = good_ctx.packet_commitment(&packet);
+        let early_commitment = good_ctx.packet_commitment(&msg_early.packet);
+        let timestamp_commitment = good_ctx.packet_commitment(&msg_timestamp.packet);
+        let timestamp_early_commitment = good_ctx.packet_commitment(&msg_timestamp_early.packet);
+
+        let timestamp_ctx = good_ctx.clone().with_consensus_state(
+            &ClientId::default(),
+            msg.proofs().height(),
+            MockConsensusState::new(
+                MockHeader::new(msg.proofs().height()).with_timestamp(proof_timestamp),
+            )
+            .into(),
+        );
+
+        source_channel_end.set_state(State::Closed);
+
+        let tests: Vec<Test> = vec![
+            Test {
+                name: "Processing fails because no channel exists in the context".to_string(),
+                ctx: context,
+                msg: msg.clone(),
+                want_pass: false,
+            },
+            Test {
+                name: "Processing fails because the packet commitment is missing".to_string(),
+                ctx: good_ctx.clone(),
+                msg: msg.clone(),
+                want_pass: false,
+            },
+            Test {
+                name: "Processing fails because the packet has not timed out yet".to_string(),
+                ctx: good_ctx.clone().with_packet_commitment(
+                    packet.source_port.clone(),
+                    packet.source_channel.clone(),
+                    packet.sequence,
+                    early_commitment,
+                ),
+                msg: msg_early,
+                want_pass: false,
+            },
+            Test {
+                name: "Processing fails because the packet has not timed out yet by timestamp"
+                    .to_string(),
+                ctx: timestamp_ctx.clone().with_packet_commitment(
+                    packet.source_port.clone(),
+                    packet.source_channel.clone(),
+                    packet.sequence,
+                    timestamp_early_commitment,
+                ),
+                msg: msg_timestamp_early,
+                want_pass: false,
+            },
+            Test {
+                name: "Good parameters, packet timed out by timestamp".to_string(),
+                ctx: timestamp_ctx.with_packet_commitment(
+                    packet.source_port.clone(),
+                    packet.source_channel.clone(),
+                    packet.sequence,
+                    timestamp_commitment,
+                ),
+                msg: msg_timestamp,
+                want_pass: true,
+            },
+            Test {
+                name: "Processing fails because the channel is closed".to_string(),
+                ctx: good_ctx
+                    .clone()
+                    .with_channel_init(
+                        packet.source_port.clone(),
+                        packet.source_channel.clone(),
+                        source_channel_end,
+                    )
+                    .with_packet_commitment(
+                        packet.source_port.clone(),
+                        packet.source_channel.clone(),
+                        packet.sequence,
+                        commitment.clone(),
+                    ),
+                msg: msg.clone(),
+                want_pass: false,
+            },
+            Test {
+                name: "Good parameters".to_string(),
+                ctx: good_ctx.with_packet_commitment(
+                    packet.source_port.clone(),
+                    packet.source_channel.clone(),
+                    packet.sequence,
+                    commitment,
+                ),
+                msg,
+                want_pass: true,
+            },
+        ]
+        .into_iter()
+        .collect();
+
+        for test in tests {
+            let msg = PacketMsg::ToPacket(test.msg.clone());
+            let channel_cap = packet_msg_capability(&test.ctx, &msg);
+            let res = packet_dispatch(&test.ctx, channel_cap.as_ref(), msg);
+            // Additionally check the events and the output objects in the result.
+            match res {
+                Ok(proto_output) => {
+                    assert!(
+                        test.want_pass,
+                        "timeout_packet: test passed but was supposed to fail for test: {}, \nparams {:?} {:?}",
+                        test.name,
+                        test.msg.clone(),
+                        test.ctx.clone()
+                    );
+                    assert!(!proto_output.events.is_empty()); // Some events must exist.
+                    assert!(matches!(proto_output.result, PacketResult::Timeout(_)));
+
+                    for e in proto_output.events.iter() {
+                        assert!(matches!(e, &IBCEvent::TimeoutPacket(_)));
+                    }
+                }
+                Err(e) => {
+                    assert!(
+                        !test.want_pass,
+                        "timeout_packet: did not pass test: {}, \nparams {:?} {:?} error: {:?}",
+                        test.name,
+                        test.msg.clone(),
+                        test.ctx.clone(),
+                        e,
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,305 @@
+//! Protocol logic specific to ICS4 messages of type `MsgTimeoutOnClose`.
+use crate::events::IBCEvent;
+use crate::handler::{HandlerOutput, HandlerResult};
+use crate::ics04_channel::channel::{ChannelEnd, Counterparty, Order, State};
+use crate::ics04_channel::context::ChannelReader;
+use crate::ics04_channel::error::{Error, Kind};
+use crate::ics04_channel::events::TimeoutOnClosePacket;
+use crate::ics04_channel::handler::timeout::TimeoutPacketResult;
+use crate::ics04_channel::handler::verify::{
+    check_client, verify_channel_proof, verify_next_sequence_recv, verify_packet_receipt_absence,
+};
+use crate::ics04_channel::handler::PacketResult;
+use crate::ics04_channel::msgs::timeout_on_close::MsgTimeoutOnClose;
+use crate::ics05_port::capabilities::Capability;
+
+pub(crate) fn process(
+    ctx: &dyn ChannelReader,
+    channel_cap: Option<&Capability>,
+    msg: MsgTimeoutOnClose,
+) -> HandlerResult<PacketResult, Error> {
+    let mut output = HandlerOutput::builder();
+
+    let packet = msg.packet();
+
+    let mut source_channel_end = ctx
+        .channel_end(&(packet.source_port.clone(), packet.source_channel.clone()))
+        .ok_or_else(|| Kind::ChannelNotFound.context(packet.source_channel.to_string()))?;
+
+    // The module which sent the packet must hold the capability of the sending channel.
+    if !channel_cap.is_some_and(|cap| {
+        ctx.authenticate_channel(cap, &packet.source_port, &packet.source_channel)
+    }) {
+        return Err(Kind::InvalidChannelCapability(
+            packet.source_port.clone(),
+            packet.source_channel.clone(),
+        )
+        .into());
+    }
+
+    // The packet must be destined to the counterparty of the sending channel end.
+    let counterparty = source_channel_end.counterparty();
+    if counterparty.port_id() != &packet.destination_port
+        || counterparty.channel_id() != Some(&packet.destination_channel)
+    {
+        return Err(Kind::InvalidPacketCounterparty(
+            packet.destination_port.clone(),
+            packet.destination_channel.clone(),
+        )
+        .into());
+    }
+
+    let connection_end = ctx
+        .connection_end(&source_channel_end.connection_hops()[0])
+        .ok_or_else(|| Kind::MissingConnection(source_channel_end.connection_hops()[0].clone()))?;
+
+    // The packet must have been sent, and not yet acknowledged or timed out.
+    let packet_commitment = ctx
+        .get_packet_commitment(&(
+            packet.source_port.clone(),
+            packet.source_channel.clone(),
+            packet.sequence,
+        ))
+        .ok_or(Kind::PacketCommitmentNotFound(packet.sequence))?;
+
+    if packet_commitment != ctx.packet_commitment(packet) {
+        return Err(Kind::IncorrectPacketCommitment(packet.sequence).into());
+    }
+
+    // Verify that the counterparty channel end is closed.
+    let expected_counterparty = Counterparty::new(
+        packet.source_port.clone(),
+        Some(packet.source_channel.clone()),
+    );
+
+    let counterparty_connection_id = connection_end
+        .counterparty()
+        .connection_id()
+        .cloned()
+        .ok_or_else(|| {
+            Kind::UndefinedConnectionCounterparty(source_channel_end.connection_hops()[0].clone())
+        })?;
+
+    let expected_channel_end = ChannelEnd::new(
+        State::Closed,
+        *source_channel_end.ordering(),
+        expected_counterparty,
+        vec![counterparty_connection_id],
+        source_channel_end.version(),
+    );
+
+    let proof_close = msg
+        .proofs()
+        .other_proof()
+        .as_ref()
+        .ok_or(Kind::InvalidProof)?;
+
+    let client_state = check_client(ctx, &connection_end)?;
+    let closed_client_state = verify_channel_proof(
+        ctx,
+        &client_state,
+        &source_channel_end,
+        &connection_end,
+        &expected_channel_end,
+        proof_close,
+        msg.proofs().height(),
+    )?;
+
+    // The second proof is verified with the client state left by the verification of the first.
+    let client_id = connection_end.client_id();
+    let client_state = closed_client_state.as_ref().unwrap_or(&client_state);
+
+    let result = if source_channel_end.order_matches(&Order::Ordered) {
+        // The counterparty must not have received this packet yet.
+        if packet.sequence < msg.next_sequence_recv() {
+            return Err(
+                Kind::InvalidPacketSequence(packet.sequence, msg.next_sequence_recv()).into(),
+            );
+        }
+        let client_state = verify_next_sequence_recv(
+            ctx,
+            client_state,
+            packet,
+            msg.next_sequence_recv(),
+            &connection_end,
+            msg.proofs(),
+        )?
+        .or(closed_client_state);
+
+        source_channel_end.set_state(State::Closed);
+
+        PacketResult::Timeout(TimeoutPacketResult {
+            port_id: packet.source_port.clone(),
+            channel_id: packet.source_channel.clone(),
+            seq: packet.sequence,
+            channel: Some(source_channel_end),
+            client_state: client_state.map(|state| (client_id.clone(), state)),
+        })
+    } else {
+        let client_state = verify_packet_receipt_absence(
+            ctx,
+            client_state,
+            packet,
+            &connection_end,
+            msg.proofs(),
+        )?
+        .or(closed_client_state);
+
+        PacketResult::Timeout(TimeoutPacketResult {
+            port_id: packet.source_port.clone(),
+            channel_id: packet.source_channel.clone(),
+            seq: packet.sequence,
+            channel: None,
+            client_state: client_state.map(|state| (client_id.clone(), state)),
+        })
+    };
+
+    output.log("success: packet timeout on close ");
+
+    output.emit(IBCEvent::TimeoutOnClosePacket(TimeoutOnClosePacket {
+        height: Default::default(),
+        packet: msg.packet,
+    }));
+
+    Ok(output.with_result(result))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use crate::events::IBCEvent;
+    use crate::ics03_connection::connection::ConnectionEnd;
+    use crate::ics03_connection::connection::Counterparty as ConnectionCounterparty;
+    use crate::ics03_connection::connection::State as ConnectionState;
+    use crate::ics03_connection::version::get_compatible_versions;
+    use crate::ics04_channel::channel::{ChannelEnd, Counterparty, Order, State};
+    use crate::ics04_channel::context::ChannelReader;
+    use crate::ics04_channel::handler::test_util::packet_msg_capability;
+    use crate::ics04_channel::handler::{packet_dispatch, PacketResult};
+    use crate::ics04_channel::msgs::timeout_on_close::test_util::get_dummy_raw_msg_timeout_on_close;
+    use crate::ics04_channel::msgs::timeout_on_close::MsgTimeoutOnClose;
+    use crate::ics04_channel::msgs::PacketMsg;
+    use crate::ics24_host::identifier::{ClientId, ConnectionId};
+    use crate::mock::context::MockContext;
+
+    #[test]
+    fn timeout_on_close_packet_processing() {
+        struct Test {
+            name: String,
+            ctx: MockContext,
+            msg: MsgTimeoutOnClose,
+            want_pass: bool,
+        }
+
+        let context = MockContext::default();
+
+        let msg = MsgTimeoutOnClose::try_from(get_dummy_raw_msg_timeout_on_close(10)).unwrap();
+        let packet = msg.packet.clone();
+
+        let source_channel_end = ChannelEnd::new(
+            State::Open,
+            Order::Ordered,
+            Counterparty::new(
+                packet.destination_port.clone(),
+                Some(packet.destination_channel.clone()),
+            ),
+            vec![ConnectionId::default()],
+            "ics20".to_string(),
+        );
+
+        let connection_end = ConnectionEnd::new(
+            ConnectionState::Open,
+            ClientId::default(),
+            ConnectionCounterparty::new(
+                ClientId::default(),
+                Some(ConnectionId::default()),
+                Default::default(),
+            ),
+            get_compatible_versions(),
+            0,
+        );
+
+        let good_ctx = context
+            .clone()
+            .with_client(&ClientId::default(), msg.proofs().height())
+            .with_connection(ConnectionId::default(), connection_end)
+            .with_port_capability(packet.source_port.clone())
+            .with_channel_init(
+                packet.source_port.clone(),
+                packet.source_channel.clone(),
+                source_channel_end,
+            );
+
+        let commitment = good_ctx.packet_commitment(&packet);
+
+        let tests: Vec<Test> = vec![
+            Test {
+                name: "Processing fails because no channel exists in the context".to_string(),
+                ctx: context,
+                msg: msg.clone(),
+                want_pass: false,
+            },
+            Test {
+                name: "Processing fails because the packet commitment is missing".to_string(),
+                ctx: good_ctx.clone(),
+                msg: msg.clone(),
+                want_pass: false,
+            },
+            Test {
+                name: "Good parameters".to_string(),
+                ctx: good_ctx.with_packet_commitment(
+                    packet.source_port.clone(),
+                    packet.source_channel.clone(),
+                    packet.sequence,
+                    commitment,
+                ),
+                msg,
+                want_pass: true,
+            },
+        ]
+        .into_iter()
+        .collect();
+
+        for test in tests {
+            let msg = PacketMsg::ToClosePacket(test.msg.clone());
+            let channel_cap = packet_msg_capability(&test.ctx, &msg);
+            let res = packet_dispatch(&test.ctx, channel_cap.as_ref(), msg);
+            // Additionally check the events and the output objects in the result.
+            match res {
+                Ok(proto_output) => {
+                    assert!(
+                        test.want_pass,
+                        "timeout_on_close: test passed but was supposed to fail for test: {}, \nparams {:?} {:?}",
+                        test.name,
+                        test.msg.clone(),
+                        test.ctx.clone()
+                    );
+                    assert!(!proto_output.events.is_empty()); // Some events must exist.
+
+                    // A timeout on an ordered channel closes the channel.
+                    match proto_output.result {
+                        PacketResult::Timeout(res) => {
+                            assert_eq!(res.channel.map(|c| c.state().clone()), Some(State::Closed))
+                        }
+                        res => panic!("unexpected packet result: {:?}", res),
+                    }
+
+                    for e in proto_output.events.iter() {
+                        assert!(matches!(e, &IBCEvent::TimeoutOnClosePacket(_)));
+                    }
+                }
+                Err(e) => {
+                    assert!(
+                        !test.want_pass,
+                        "timeout_on_close: did not pass test: {}, \nparams {:?} {:?} error: {:?}",
+                        test.name,
+                        test.msg.clone(),
+                        test.ctx.clone(),
+                        e,
+                    );
+                }
+            }
+        }
+    }
+}
@@ -1,55 +1,469 @@
-use crate::ics02_client::state::ClientState;
+use std::time::Duration;
+
+use crate::ics02_client::client_def::{AnyClientState, AnyConsensusState};
+use crate::ics02_client::state::{ClientState, ConsensusState};
 use crate::ics02_client::{client_def::AnyClient, client_def::ClientDef};
 use crate::ics03_connection::connection::ConnectionEnd;
 use crate::ics04_channel::channel::ChannelEnd;
 use crate::ics04_channel::context::ChannelReader;
 use crate::ics04_channel::error::{Error, Kind};
+use crate::ics04_channel::packet::{Packet, Sequence};
+use crate::ics23_commitment::commitment::CommitmentProofBytes;
 use crate::proofs::Proofs;
+use crate::Height;
 
-/// Entry point for verifying all proofs bundled in any ICS4 message.
+/// Entry point for verifying all proofs bundled in any ICS4 message, with the given state of the
+/// client of the connection (see `check_client`). Returns the new state of the client if verifying
+/// the proofs updated it, for the host chain to store.
 pub fn verify_proofs(
     ctx: &dyn ChannelReader,
+    client_state: &AnyClientState,
     channel_end: &ChannelEnd,
     connection_end: &ConnectionEnd,
     expected_chan: &ChannelEnd,
     proofs: &Proofs,
+) -> Result<Option<AnyClientState>, Error> {
+    // The client is the one of the connection, since the channel end may not be stored yet
+    // (`ChannelOpenTry`).
+    let consensus_state = consensus_state(ctx, client_state, connection_end, proofs.height())?;
+
+    let client_def = AnyClient::from_client_type(client_state.client_type());
+
+    // The channel id on the counterparty chain must be known to look up its channel end, which is
+    // not the case of a channel end initialized without it (`ChannelOpenInit`).
+    let counterparty_channel_id = channel_end
+        .counterparty()
+        .channel_id()
+        .ok_or(Kind::InvalidCounterpartyChannelId)?;
+
+    // Verify the proof for the channel state against the expected channel end.
+    Ok(client_def
+        .verify_channel_state(
+            ctx,
+            client_state,
+            proofs.height(),
+            connection_end.counterparty().prefix(),
+            proofs.object_proof(),
+            consensus_state.root(),
+            channel_end.counterparty().port_id(),
+            counterparty_channel_id,
+            expected_chan,
+        )
+        .map_err(|e| Kind::InvalidProof.context(e.to_string()))?)
+}
+
+/// Verifies the proof that the counterparty channel end of `channel_end` is in the state described
+/// by `expected_chan`, e.g., that it is closed. Unlike `verify_proofs`, the proof is passed
+/// explicitly, since some packet messages bundle it as a secondary proof.
+pub fn verify_channel_proof(
+    ctx: &dyn ChannelReader,
+    client_state: &AnyClientState,
+    channel_end: &ChannelEnd,
+    connection_end: &ConnectionEnd,
+    expected_chan: &ChannelEnd,
+    proof: &CommitmentProofBytes,
+    proof_height: Height,
+) -> Result<Option<AnyClientState>, Error> {
+    let consensus_state = consensus_state(ctx, client_state, connection_end, proof_height)?;
+
+    let client_def = AnyClient::from_client_type(client_state.client_type());
+
+    let counterparty_channel_id = channel_end
+        .counterparty()
+        .channel_id()
+        .ok_or(Kind::InvalidCounterpartyChannelId)?;
+
+    Ok(client_def
+        .verify_channel_state(
+            ctx,
+            client_state,
+            proof_height,
+            connection_end.counterparty().prefix(),
+            proof,
+            consensus_state.root(),
+            channel_end.counterparty().port_id(),
+            counterparty_channel_id,
+            expected_chan,
+        )
+        .map_err(|e| Kind::InvalidProof.context(e.to_string()))?)
+}
+
+/// Entry point for verifying the proof bundled with a "receive packet" message.
+pub fn verify_packet_recv_proofs(
+    ctx: &dyn ChannelReader,
+    client_state: &AnyClientState,
+    packet: &Packet,
+    connection_end: &ConnectionEnd,
+    proofs: &Proofs,
+) -> Result<Option<AnyClientState>, Error> {
+    let consensus_state =
+        packet_consensus_state(ctx, client_state, connection_end, proofs.height())?;
+
+    let client_def = AnyClient::from_client_type(client_state.client_type());
+
+    // Verify the proof for the packet commitment against the counterparty chain store.
+    Ok(client_def
+        .verify_packet_data(
+            ctx,
+            client_state,
+            proofs.height(),
+            connection_end.counterparty().prefix(),
+            proofs.object_proof(),
+            consensus_state.root(),
+            &packet.source_port,
+            &packet.source_channel,
+            &packet.sequence,
+            ctx.packet_commitment(packet),
+        )
+        .map_err(|e| Kind::PacketVerificationFailed(packet.sequence).context(e.to_string()))?)
+}
+
+/// Entry point for verifying the proof bundled with an acknowledgement packet message.
+pub fn verify_packet_acknowledgement_proofs(
+    ctx: &dyn ChannelReader,
+    client_state: &AnyClientState,
+    packet: &Packet,
+    acknowledgement: Vec<u8>,
+    connection_end: &ConnectionEnd,
+    proofs: &Proofs,
+) -> Result<Option<AnyClientState>, Error> {
+    let consensus_state =
+        packet_consensus_state(ctx, client_state, connection_end, proofs.height())?;
+
+    let client_def = AnyClient::from_client_type(client_state.client_type());
+
+    // Verify the proof for the acknowledgement against the counterparty chain store.
+    Ok(client_def
+        .verify_packet_acknowledgement(
+            ctx,
+            client_state,
+            proofs.height(),
+            connection_end.counterparty().prefix(),
+            proofs.object_proof(),
+            consensus_state.root(),
+            &packet.destination_port,
+            &packet.destination_channel,
+            &packet.sequence,
+            acknowledgement,
+        )
+        .map_err(|e| Kind::PacketVerificationFailed(packet.sequence).context(e.to_string()))?)
+}
+
+/// Entry point for verifying the proof of the next sequence that the counterparty (ordered)
+/// channel expects to receive, as bundled in timeout messages.
+pub fn verify_next_sequence_recv(
+    ctx: &dyn ChannelReader,
+    client_state: &AnyClientState,
+    packet: &Packet,
+    seq: Sequence,
+    connection_end: &ConnectionEnd,
+    proofs: &Proofs,
+) -> Result<Option<AnyClientState>, Error> {
+    let consensus_state =
+        packet_consensus_state(ctx, client_state, connection_end, proofs.height())?;
+
+    let client_def = AnyClient::from_client_type(client_state.client_type());
+
+    Ok(client_def
+        .verify_next_sequence_recv(
+            ctx,
+            client_state,
+            proofs.height(),
+            connection_end.counterparty().prefix(),
+            proofs.object_proof(),
+            consensus_state.root(),
+            &packet.destination_port,
+            &packet.destination_channel,
+            &seq,
+        )
+        .map_err(|e| Kind::PacketVerificationFailed(seq).context(e.to_string()))?)
+}
+
+/// Entry point for verifying the proof that a packet was not received on the counterparty
+/// (unordered) channel, as bundled in timeout messages.
+pub fn verify_packet_receipt_absence(
+    ctx: &dyn ChannelReader,
+    client_state: &AnyClientState,
+    packet: &Packet,
+    connection_end: &ConnectionEnd,
+    proofs: &Proofs,
+) -> Result<Option<AnyClientState>, Error> {
+    let consensus_state =
+        packet_consensus_state(ctx, client_state, connection_end, proofs.height())?;
+
+    let client_def = AnyClient::from_client_type(client_state.client_type());
+
+    Ok(client_def
+        .verify_packet_receipt_absence(
+            ctx,
+            client_state,
+            proofs.height(),
+            connection_end.counterparty().prefix(),
+            proofs.object_proof(),
+            consensus_state.root(),
+            &packet.destination_port,
+            &packet.destination_channel,
+            &packet.sequence,
+        )
+        .map_err(|e| Kind::PacketVerificationFailed(packet.sequence).context(e.to_string()))?)
+}
+
+/// Checks that the delay period of the connection has elapsed on the host chain since it stored the
+/// consensus state of the client at `height`, both in time and in number of blocks. Until then,
+/// proofs verified against this consensus state are rejected, to leave time for the submission of
+/// evidence of misbehaviour.
+pub fn verify_delay_passed(
+    ctx: &dyn ChannelReader,
+    connection_end: &ConnectionEnd,
+    height: Height,
 ) -> Result<(), Error> {
-    let client = connection_end.client_id().clone();
+    let client_id = connection_end.client_id();
+
+    let processed_time = ctx
+        .client_update_time(client_id, height)
+        .ok_or_else(|| Kind::ProcessedTimeNotFound(client_id.clone(), height))?;
+    let processed_height = ctx
+        .client_update_height(client_id, height)
+        .ok_or_else(|| Kind::ProcessedHeightNotFound(client_id.clone(), height))?;
+
+    let delay_period = Duration::from_nanos(connection_end.delay_period());
+
+    let current_time = ctx.host_timestamp();
+    let earliest_time = processed_time + delay_period;
+    if current_time < earliest_time {
+        return Err(Kind::DelayTimeNotElapsed(current_time, earliest_time).into());
+    }
+
+    let current_height = ctx.host_height();
+    let earliest_height = processed_height.add(ctx.block_delay(delay_period));
+    if current_height < earliest_height {
+        return Err(Kind::DelayHeightNotElapsed(current_height, earliest_height).into());
+    }
 
-    let port_id = channel_end.counterparty().port_id().clone();
-    let chan_id = channel_end.counterparty().channel_id().unwrap().clone();
+    Ok(())
+}
 
+/// Fetches the state of the client of `connection_end` and checks that it is not frozen. The proofs
+/// of a message are verified with this state, each with the state left by the previous one, if
+/// verifying it updated the client (see `ClientDef`).
+pub fn check_client(
+    ctx: &dyn ChannelReader,
+    connection_end: &ConnectionEnd,
+) -> Result<AnyClientState, Error> {
+    let client_id = connection_end.client_id();
     let client_state = ctx
-        .channel_client_state(&(port_id.clone(), chan_id.clone()))
-        .ok_or(Kind::MissingClientState)?;
+        .client_state(client_id)
+        .ok_or_else(|| Kind::MissingClientState.context(client_id.to_string()))?;
 
     // The client must not be frozen.
     if client_state.is_frozen() {
-        return Err(Kind::FrozenClient.context(client.to_string()).into());
+        return Err(Kind::FrozenClient.context(client_id.to_string()).into());
     }
 
-    if ctx
-        .channel_client_consensus_state(&(port_id, chan_id), proofs.height())
-        .is_none()
-    {
-        return Err(Kind::MissingClientConsensusState
-            .context(client.to_string())
-            .into());
+    Ok(client_state)
+}
+
+/// Fetches the consensus state against which the proofs at `height` are verified: the one which
+/// the client keeps within its client state, if any (see `ClientDef::embedded_consensus_state`),
+/// or else the one which the host chain stores for the client of `connection_end` at `height`.
+fn consensus_state(
+    ctx: &dyn ChannelReader,
+    client_state: &AnyClientState,
+    connection_end: &ConnectionEnd,
+    height: Height,
+) -> Result<AnyConsensusState, Error> {
+    let client_def = AnyClient::from_client_type(client_state.client_type());
+    if let Some(consensus_state) = client_def.embedded_consensus_state(client_state) {
+        return Ok(consensus_state);
     }
 
+    let client_id = connection_end.client_id();
+    ctx.client_consensus_state(client_id, height)
+        .ok_or_else(|| {
+            Kind::MissingClientConsensusState
+                .context(client_id.to_string())
+                .into()
+        })
+}
+
+/// Fetches the consensus state against which the packet proofs at `height` are verified, and
+/// checks that the delay period of the connection has elapsed since the host chain stored it. As
+/// in ibc-go, the solo machine, whose consensus state is kept within its client state instead, is
+/// not subject to delay periods.
+fn packet_consensus_state(
+    ctx: &dyn ChannelReader,
+    client_state: &AnyClientState,
+    connection_end: &ConnectionEnd,
+    height: Height,
+) -> Result<AnyConsensusState, Error> {
     let client_def = AnyClient::from_client_type(client_state.client_type());
+    if let Some(consensus_state) = client_def.embedded_consensus_state(client_state) {
+        return Ok(consensus_state);
+    }
 
-    // Verify the proof for the channel state against the expected channel end.
-    // A counterparty channel id of None in not possible, and is checked by validate_basic in msg.
-    Ok(client_def
-        .verify_channel_state(
-            &client_state,
-            proofs.height(),
-            connection_end.counterparty().prefix(),
-            proofs.object_proof(),
-            &channel_end.counterparty().port_id(),
-            &channel_end.counterparty().channel_id().unwrap(),
-            expected_chan,
+    let consensus_state = consensus_state(ctx, client_state, connection_end, height)?;
+    verify_delay_passed(ctx, connection_end, height)?;
+
+    Ok(consensus_state)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::ics02_client::client_def::{AnyClientState, AnyConsensusState};
+    use crate::ics02_client::client_type::ClientType;
+    use crate::ics02_client::context::ClientKeeper;
+    use crate::ics03_connection::connection::ConnectionEnd;
+    use crate::ics03_connection::connection::Counterparty as ConnectionCounterparty;
+    use crate::ics03_connection::connection::State as ConnectionState;
+    use crate::ics03_connection::version::get_compatible_versions;
+    use crate::ics04_channel::context::ChannelReader;
+    use crate::ics04_channel::handler::verify::{packet_consensus_state, verify_delay_passed};
+    use crate::ics06_solomachine::client_state::test_util::get_dummy_solomachine_client_state;
+    use crate::ics24_host::identifier::{ClientId, ConnectionId};
+    use crate::mock::context::MockContext;
+    use crate::Height;
+
+    #[test]
+    fn delay_period() {
+        struct Test {
+            name: String,
+            ctx: MockContext,
+            delay_period: Duration,
+            proof_height: Height,
+            want_pass: bool,
+        }
+
+        let client_id = ClientId::default();
+        let client_height = Height::new(0, 10);
+
+        // The consensus state of the client is stored at the current height of the host, 1-5.
+        let context = MockContext::default().with_client(&client_id, client_height);
+        let mut advanced_context = context.clone();
+        advanced_context.advance_host_chain_height();
+
+        let tests: Vec<Test> = vec![
+            Test {
+                name: "No delay".to_string(),
+                ctx: context.clone(),
+                delay_period: Duration::from_secs(0),
+                proof_height: client_height,
+                want_pass: true,
+            },
+            Test {
+                name: "Time delay not elapsed".to_string(),
+                ctx: advanced_context.clone(),
+                delay_period: Duration::from_secs(3600),
+                proof_height: client_height,
+                want_pass: false,
+            },
+            Test {
+                name: "Block delay not elapsed".to_string(),
+                ctx: context.clone(),
+                delay_period: Duration::from_nanos(1),
+                proof_height: client_height,
+                want_pass: false,
+            },
+            Test {
+                name: "Time and block delays elapsed".to_string(),
+                ctx: advanced_context,
+                delay_period: Duration::from_nanos(1),
+                proof_height: client_height,
+                want_pass: true,
+            },
+            Test {
+                name: "No consensus state processed at the proof height".to_string(),
+                ctx: context,
+                delay_period: Duration::from_secs(0),
+                proof_height: client_height.increment(),
+                want_pass: false,
+            },
+        ];
+
+        for test in tests {
+            let connection_end = ConnectionEnd::new(
+                ConnectionState::Open,
+                client_id.clone(),
+                ConnectionCounterparty::new(
+                    ClientId::default(),
+                    Some(ConnectionId::default()),
+                    Default::default(),
+                ),
+                get_compatible_versions(),
+                test.delay_period.as_nanos() as u64,
+            );
+
+            let res = verify_delay_passed(&test.ctx, &connection_end, test.proof_height);
+
+            assert_eq!(
+                test.want_pass,
+                res.is_ok(),
+                "delay_period: test {} failed with result {:?}",
+                test.name,
+                res.err()
+            );
+        }
+    }
+
+    #[test]
+    fn solo_machine_consensus_state() {
+        let client_id = ClientId::new(ClientType::SoloMachine, 0).unwrap();
+
+        // The host chain stores the consensus state of the solo machine at sequence 1, when the
+        // client was created. Verifying proofs then advanced the sequence to 3.
+        let mut ctx = MockContext::default().with_client_parametrized(
+            &client_id,
+            Height::new(0, 1),
+            Some(ClientType::SoloMachine),
+            None,
+        );
+        let client_state = get_dummy_solomachine_client_state(3);
+        ClientKeeper::store_client_state(
+            &mut ctx,
+            client_id.clone(),
+            AnyClientState::SoloMachine(client_state.clone()),
+        )
+        .unwrap();
+
+        let connection_end = ConnectionEnd::new(
+            ConnectionState::Open,
+            client_id.clone(),
+            ConnectionCounterparty::new(
+                ClientId::default(),
+                Some(ConnectionId::default()),
+                Default::default(),
+            ),
+            get_compatible_versions(),
+            Duration::from_secs(3600).as_nanos() as u64,
+        );
+
+        // The proofs at the current sequence are verified against the consensus state kept in the
+        // client state, which the host chain does not store at this sequence, regardless of the
+        // delay period of the connection.
+        let proof_height = Height::new(0, 3);
+        assert!(ChannelReader::client_consensus_state(&ctx, &client_id, proof_height).is_none());
+        let consensus_state = packet_consensus_state(
+            &ctx,
+            &AnyClientState::SoloMachine(client_state.clone()),
+            &connection_end,
+            proof_height,
         )
-        .map_err(|_| Kind::InvalidProof)?)
+        .unwrap();
+        assert_eq!(
+            consensus_state,
+            AnyConsensusState::SoloMachine(client_state.consensus_state)
+        );
+    }
+
+    #[test]
+    fn block_delay() {
+        // The mock host chain is expected to produce a block every 30 seconds.
+        let ctx = MockContext::default();
+
+        assert_eq!(ctx.block_delay(Duration::from_secs(0)), 0);
+        assert_eq!(ctx.block_delay(Duration::from_secs(1)), 1);
+        assert_eq!(ctx.block_delay(Duration::from_secs(30)), 1);
+        assert_eq!(ctx.block_delay(Duration::from_secs(31)), 2);
+    }
 }
@@ -1,6 +1,7 @@
 //! ICS 04: IBC Channel implementation
 
 pub mod channel;
+pub mod commitment;
 pub mod context;
 pub mod error;
 pub mod events;
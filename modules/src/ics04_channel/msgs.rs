@@ -8,6 +8,11 @@ use crate::ics04_channel::msgs::chan_open_confirm::MsgChannelOpenConfirm;
 use crate::ics04_channel::msgs::chan_open_init::MsgChannelOpenInit;
 use crate::ics04_channel::msgs::chan_open_try::MsgChannelOpenTry;
 
+use crate::ics04_channel::msgs::acknowledgement::MsgAcknowledgement;
+use crate::ics04_channel::msgs::recv_packet::MsgRecvPacket;
+use crate::ics04_channel::msgs::timeout::MsgTimeout;
+use crate::ics04_channel::msgs::timeout_on_close::MsgTimeoutOnClose;
+
 // Opening handshake messages.
 pub mod chan_open_ack;
 pub mod chan_open_confirm;
@@ -34,3 +39,12 @@ pub enum ChannelMsg {
     ChannelCloseInit(MsgChannelCloseInit),
     ChannelCloseConfirm(MsgChannelCloseConfirm),
 }
+
+/// Enumeration of all the packet-related messages that the ICS4 protocol processes.
+#[derive(Clone, Debug, PartialEq)]
+pub enum PacketMsg {
+    RecvPacket(MsgRecvPacket),
+    AckPacket(MsgAcknowledgement),
+    ToPacket(MsgTimeout),
+    ToClosePacket(MsgTimeoutOnClose),
+}
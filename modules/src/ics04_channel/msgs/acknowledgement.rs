@@ -37,6 +37,18 @@ impl MsgAcknowledgement {
             signer,
         })
     }
+
+    pub fn packet(&self) -> &Packet {
+        &self.packet
+    }
+
+    pub fn acknowledgement(&self) -> &Vec<u8> {
+        &self.acknowledgement
+    }
+
+    pub fn proofs(&self) -> &Proofs {
+        &self.proofs
+    }
 }
 
 impl Msg for MsgAcknowledgement {
@@ -103,7 +115,7 @@ impl From<MsgAcknowledgement> for RawMsgAcknowledgement {
 }
 
 #[cfg(test)]
-mod test_util {
+pub mod test_util {
     use ibc_proto::ibc::core::channel::v1::MsgAcknowledgement as RawMsgAcknowledgement;
     use ibc_proto::ibc::core::client::v1::Height as RawHeight;
 
@@ -136,6 +148,10 @@ mod test {
     use crate::ics04_channel::msgs::acknowledgement::test_util::get_dummy_raw_msg_acknowledgement;
     use crate::ics04_channel::msgs::acknowledgement::MsgAcknowledgement;
 
+    use crate::ics04_channel::msgs::acknowledgement::TYPE_URL;
+    use crate::test::test_msg_roundtrip;
+    use proptest::prelude::*;
+
     #[test]
     fn msg_acknowledgment_try_from_raw() {
         struct Test {
@@ -200,4 +216,11 @@ mod test {
             );
         }
     }
+
+    proptest! {
+        #[test]
+        fn roundtrip(msg in any::<MsgAcknowledgement>()) {
+            test_msg_roundtrip::<_, RawMsgAcknowledgement>(msg, TYPE_URL);
+        }
+    }
 }
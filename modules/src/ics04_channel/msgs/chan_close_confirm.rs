@@ -127,7 +127,7 @@ pub mod test_util {
     ) -> RawMsgChannelCloseConfirm {
         RawMsgChannelCloseConfirm {
             port_id: "port".to_string(),
-            channel_id: "defaultChannel-0".to_string(),
+            channel_id: "channel-0".to_string(),
             proof_init: get_dummy_proof(),
             proof_height: Some(Height {
                 revision_number: 0,
@@ -148,6 +148,10 @@ mod tests {
     use crate::ics04_channel::msgs::chan_close_confirm::test_util::get_dummy_raw_msg_chan_close_confirm;
     use crate::ics04_channel::msgs::chan_close_confirm::MsgChannelCloseConfirm;
 
+    use crate::ics04_channel::msgs::chan_close_confirm::TYPE_URL;
+    use crate::test::test_msg_roundtrip;
+    use proptest::prelude::*;
+
     #[test]
     fn parse_channel_close_confirm_msg() {
         struct Test {
@@ -255,4 +259,11 @@ mod tests {
         assert_eq!(raw, raw_back);
         assert_eq!(msg, msg_back);
     }
+
+    proptest! {
+        #[test]
+        fn roundtrip(msg in any::<MsgChannelCloseConfirm>()) {
+            test_msg_roundtrip::<_, RawMsgChannelCloseConfirm>(msg, TYPE_URL);
+        }
+    }
 }
@@ -106,6 +106,10 @@ mod tests {
     use crate::ics04_channel::msgs::chan_close_init::test_util::get_dummy_raw_msg_chan_close_init;
     use crate::ics04_channel::msgs::chan_close_init::MsgChannelCloseInit;
 
+    use crate::ics04_channel::msgs::chan_close_init::TYPE_URL;
+    use crate::test::test_msg_roundtrip;
+    use proptest::prelude::*;
+
     #[test]
     fn parse_channel_close_init_msg() {
         struct Test {
@@ -197,4 +201,11 @@ mod tests {
         assert_eq!(raw, raw_back);
         assert_eq!(msg, msg_back);
     }
+
+    proptest! {
+        #[test]
+        fn roundtrip(msg in any::<MsgChannelCloseInit>()) {
+            test_msg_roundtrip::<_, RawMsgChannelCloseInit>(msg, TYPE_URL);
+        }
+    }
 }
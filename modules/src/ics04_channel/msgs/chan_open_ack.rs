@@ -145,8 +145,8 @@ pub mod test_util {
     pub fn get_dummy_raw_msg_chan_open_ack_ics26(proof_height: u64) -> RawMsgChannelOpenAck {
         RawMsgChannelOpenAck {
             port_id: "port".to_string(),
-            channel_id: "defaultChannel-0".to_string(),
-            counterparty_channel_id: "defaultChannel-0".to_string(),
+            channel_id: "channel-0".to_string(),
+            counterparty_channel_id: "channel-0".to_string(),
             counterparty_version: "v1".to_string(),
             proof_try: get_dummy_proof(),
             proof_height: Some(Height {
@@ -167,6 +167,10 @@ mod tests {
     use ibc_proto::ibc::core::client::v1::Height;
     use std::convert::TryFrom;
 
+    use crate::ics04_channel::msgs::chan_open_ack::TYPE_URL;
+    use crate::test::test_msg_roundtrip;
+    use proptest::prelude::*;
+
     #[test]
     fn parse_channel_open_ack_msg() {
         struct Test {
@@ -326,4 +330,11 @@ mod tests {
         assert_eq!(raw, raw_back);
         assert_eq!(msg, msg_back);
     }
+
+    proptest! {
+        #[test]
+        fn roundtrip(msg in any::<MsgChannelOpenAck>()) {
+            test_msg_roundtrip::<_, RawMsgChannelOpenAck>(msg, TYPE_URL);
+        }
+    }
 }
@@ -156,6 +156,10 @@ mod tests {
     use ibc_proto::ibc::core::client::v1::Height;
     use std::convert::TryFrom;
 
+    use crate::ics04_channel::msgs::chan_open_confirm::TYPE_URL;
+    use crate::test::test_msg_roundtrip;
+    use proptest::prelude::*;
+
     #[test]
     fn parse_channel_open_confirm_msg() {
         struct Test {
@@ -267,4 +271,11 @@ mod tests {
         assert_eq!(raw, raw_back);
         assert_eq!(msg, msg_back);
     }
+
+    proptest! {
+        #[test]
+        fn roundtrip(msg in any::<MsgChannelOpenConfirm>()) {
+            test_msg_roundtrip::<_, RawMsgChannelOpenConfirm>(msg, TYPE_URL);
+        }
+    }
 }
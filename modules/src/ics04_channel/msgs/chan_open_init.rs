@@ -125,6 +125,10 @@ mod tests {
     use ibc_proto::ibc::core::channel::v1::MsgChannelOpenInit as RawMsgChannelOpenInit;
     use std::convert::TryFrom;
 
+    use crate::ics04_channel::msgs::chan_open_init::TYPE_URL;
+    use crate::test::test_msg_roundtrip;
+    use proptest::prelude::*;
+
     #[test]
     fn channel_open_init_from_raw() {
         struct Test {
@@ -184,4 +188,11 @@ mod tests {
         assert_eq!(raw, raw_back);
         assert_eq!(msg, msg_back);
     }
+
+    proptest! {
+        #[test]
+        fn roundtrip(msg in any::<MsgChannelOpenInit>()) {
+            test_msg_roundtrip::<_, RawMsgChannelOpenInit>(msg, TYPE_URL);
+        }
+    }
 }
@@ -203,6 +203,10 @@ mod tests {
     use ibc_proto::ibc::core::client::v1::Height;
     use std::convert::TryFrom;
 
+    use crate::ics04_channel::msgs::chan_open_try::TYPE_URL;
+    use crate::test::test_msg_roundtrip;
+    use proptest::prelude::*;
+
     #[test]
     fn channel_open_try_from_raw() {
         struct Test {
@@ -338,4 +342,11 @@ mod tests {
         assert_eq!(raw, raw_back);
         assert_eq!(msg, msg_back);
     }
+
+    proptest! {
+        #[test]
+        fn roundtrip(msg in any::<MsgChannelOpenTry>()) {
+            test_msg_roundtrip::<_, RawMsgChannelOpenTry>(msg, TYPE_URL);
+        }
+    }
 }
@@ -30,6 +30,14 @@ impl MsgRecvPacket {
             signer,
         })
     }
+
+    pub fn packet(&self) -> &Packet {
+        &self.packet
+    }
+
+    pub fn proofs(&self) -> &Proofs {
+        &self.proofs
+    }
 }
 
 impl Msg for MsgRecvPacket {
@@ -94,7 +102,7 @@ impl From<MsgRecvPacket> for RawMsgRecvPacket {
 }
 
 #[cfg(test)]
-mod test_util {
+pub mod test_util {
     use ibc_proto::ibc::core::channel::v1::MsgRecvPacket as RawMsgRecvPacket;
     use ibc_proto::ibc::core::client::v1::Height as RawHeight;
 
@@ -126,6 +134,10 @@ mod test {
     use crate::ics04_channel::msgs::recv_packet::test_util::get_dummy_raw_msg_recv_packet;
     use crate::ics04_channel::msgs::recv_packet::MsgRecvPacket;
 
+    use crate::ics04_channel::msgs::recv_packet::TYPE_URL;
+    use crate::test::test_msg_roundtrip;
+    use proptest::prelude::*;
+
     #[test]
     fn msg_recv_packet_try_from_raw() {
         struct Test {
@@ -191,4 +203,11 @@ mod test {
         assert_eq!(raw, raw_back);
         assert_eq!(msg, msg_back);
     }
+
+    proptest! {
+        #[test]
+        fn roundtrip(msg in any::<MsgRecvPacket>()) {
+            test_msg_roundtrip::<_, RawMsgRecvPacket>(msg, TYPE_URL);
+        }
+    }
 }
@@ -37,6 +37,18 @@ impl MsgTimeout {
             signer,
         })
     }
+
+    pub fn packet(&self) -> &Packet {
+        &self.packet
+    }
+
+    pub fn next_sequence_recv(&self) -> Sequence {
+        self.next_sequence_recv
+    }
+
+    pub fn proofs(&self) -> &Proofs {
+        &self.proofs
+    }
 }
 
 impl Msg for MsgTimeout {
@@ -105,7 +117,7 @@ impl From<MsgTimeout> for RawMsgTimeout {
 }
 
 #[cfg(test)]
-mod test_util {
+pub mod test_util {
     use ibc_proto::ibc::core::channel::v1::MsgTimeout as RawMsgTimeout;
     use ibc_proto::ibc::core::client::v1::Height as RawHeight;
 
@@ -138,6 +150,10 @@ mod test {
     use crate::ics04_channel::msgs::timeout::test_util::get_dummy_raw_msg_timeout;
     use crate::ics04_channel::msgs::timeout::MsgTimeout;
 
+    use crate::ics04_channel::msgs::timeout::TYPE_URL;
+    use crate::test::test_msg_roundtrip;
+    use proptest::prelude::*;
+
     #[test]
     fn msg_timeout_try_from_raw() {
         struct Test {
@@ -212,4 +228,11 @@ mod test {
         assert_eq!(raw, raw_back);
         assert_eq!(msg, msg_back);
     }
+
+    proptest! {
+        #[test]
+        fn roundtrip(msg in any::<MsgTimeout>()) {
+            test_msg_roundtrip::<_, RawMsgTimeout>(msg, TYPE_URL);
+        }
+    }
 }
@@ -37,6 +37,18 @@ impl MsgTimeoutOnClose {
             signer,
         })
     }
+
+    pub fn packet(&self) -> &Packet {
+        &self.packet
+    }
+
+    pub fn next_sequence_recv(&self) -> Sequence {
+        self.next_sequence_recv
+    }
+
+    pub fn proofs(&self) -> &Proofs {
+        &self.proofs
+    }
 }
 
 impl Msg for MsgTimeoutOnClose {
@@ -64,11 +76,17 @@ impl TryFrom<RawMsgTimeoutOnClose> for MsgTimeoutOnClose {
         let signer =
             string_to_account(raw_msg.signer).map_err(|e| Kind::InvalidSigner.context(e))?;
 
+        if raw_msg.proof_close.is_empty() {
+            return Err(Kind::InvalidProof
+                .context("missing proof of channel closure")
+                .into());
+        }
+
         let proofs = Proofs::new(
             raw_msg.proof_unreceived.into(),
             None,
             None,
-            None,
+            Some(raw_msg.proof_close.into()),
             raw_msg
                 .proof_height
                 .ok_or(Kind::MissingHeight)?
@@ -108,3 +126,133 @@ impl From<MsgTimeoutOnClose> for RawMsgTimeoutOnClose {
         }
     }
 }
+
+#[cfg(test)]
+pub mod test_util {
+    use ibc_proto::ibc::core::channel::v1::MsgTimeoutOnClose as RawMsgTimeoutOnClose;
+    use ibc_proto::ibc::core::client::v1::Height as RawHeight;
+
+    use crate::ics04_channel::packet::test_utils::get_dummy_raw_packet;
+    use crate::test_utils::{get_dummy_bech32_account, get_dummy_proof};
+
+    /// Returns a dummy `RawMsgTimeoutOnClose`, for testing only!
+    /// The `height` parametrizes both the proof height as well as the timeout height.
+    pub fn get_dummy_raw_msg_timeout_on_close(height: u64) -> RawMsgTimeoutOnClose {
+        RawMsgTimeoutOnClose {
+            packet: Some(get_dummy_raw_packet(height)),
+            proof_unreceived: get_dummy_proof(),
+            proof_close: get_dummy_proof(),
+            proof_height: Some(RawHeight {
+                revision_number: 0,
+                revision_height: height,
+            }),
+            next_sequence_recv: 0,
+            signer: get_dummy_bech32_account(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::convert::{TryFrom, TryInto};
+
+    use ibc_proto::ibc::core::channel::v1::MsgTimeoutOnClose as RawMsgTimeoutOnClose;
+
+    use crate::ics04_channel::error::Error;
+    use crate::ics04_channel::msgs::timeout_on_close::test_util::get_dummy_raw_msg_timeout_on_close;
+    use crate::ics04_channel::msgs::timeout_on_close::MsgTimeoutOnClose;
+
+    use crate::ics04_channel::msgs::timeout_on_close::TYPE_URL;
+    use crate::test::test_msg_roundtrip;
+    use proptest::prelude::*;
+
+    #[test]
+    fn msg_timeout_on_close_try_from_raw() {
+        struct Test {
+            name: String,
+            raw: RawMsgTimeoutOnClose,
+            want_pass: bool,
+        }
+
+        let height = 50;
+        let default_raw_msg = get_dummy_raw_msg_timeout_on_close(height);
+
+        let tests: Vec<Test> = vec![
+            Test {
+                name: "Good parameters".to_string(),
+                raw: default_raw_msg.clone(),
+                want_pass: true,
+            },
+            Test {
+                name: "Missing packet".to_string(),
+                raw: RawMsgTimeoutOnClose {
+                    packet: None,
+                    ..default_raw_msg.clone()
+                },
+                want_pass: false,
+            },
+            Test {
+                name: "Missing proof of non-receipt".to_string(),
+                raw: RawMsgTimeoutOnClose {
+                    proof_unreceived: vec![],
+                    ..default_raw_msg.clone()
+                },
+                want_pass: false,
+            },
+            Test {
+                name: "Missing proof of channel closure".to_string(),
+                raw: RawMsgTimeoutOnClose {
+                    proof_close: vec![],
+                    ..default_raw_msg.clone()
+                },
+                want_pass: false,
+            },
+            Test {
+                name: "Missing proof height".to_string(),
+                raw: RawMsgTimeoutOnClose {
+                    proof_height: None,
+                    ..default_raw_msg.clone()
+                },
+                want_pass: false,
+            },
+            Test {
+                name: "Missing signer".to_string(),
+                raw: RawMsgTimeoutOnClose {
+                    signer: "".to_string(),
+                    ..default_raw_msg
+                },
+                want_pass: false,
+            },
+        ];
+
+        for test in tests {
+            let res_msg: Result<MsgTimeoutOnClose, Error> = test.raw.clone().try_into();
+
+            assert_eq!(
+                res_msg.is_ok(),
+                test.want_pass,
+                "MsgTimeoutOnClose::try_from failed for test {} \nraw message: {:?} with error: {:?}",
+                test.name,
+                test.raw,
+                res_msg.err()
+            );
+        }
+    }
+
+    #[test]
+    fn to_and_from() {
+        let raw = get_dummy_raw_msg_timeout_on_close(15);
+        let msg = MsgTimeoutOnClose::try_from(raw.clone()).unwrap();
+        let raw_back = RawMsgTimeoutOnClose::from(msg.clone());
+        let msg_back = MsgTimeoutOnClose::try_from(raw_back.clone()).unwrap();
+        assert_eq!(raw, raw_back);
+        assert_eq!(msg, msg_back);
+    }
+
+    proptest! {
+        #[test]
+        fn roundtrip(msg in any::<MsgTimeoutOnClose>()) {
+            test_msg_roundtrip::<_, RawMsgTimeoutOnClose>(msg, TYPE_URL);
+        }
+    }
+}
@@ -1,11 +1,13 @@
 use std::convert::{TryFrom, TryInto};
 
 use serde_derive::{Deserialize, Serialize};
+use subtle_encoding::base64;
 
 use ibc_proto::ibc::core::channel::v1::Packet as RawPacket;
 
 use crate::ics04_channel::error::Kind;
 use crate::ics24_host::identifier::{ChannelId, PortId};
+use crate::timestamp::{Expiry, Timestamp};
 use crate::Height;
 
 /// Enumeration of proof carrying ICS3 message, helper for relayer.
@@ -29,9 +31,21 @@ impl std::fmt::Display for PacketMsgType {
 }
 
 /// The sequence number of a packet enforces ordering among packets from the same source.
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[derive(
+    Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize,
+)]
 pub struct Sequence(u64);
 
+impl Sequence {
+    pub fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn increment(&self) -> Sequence {
+        Sequence(self.0 + 1)
+    }
+}
+
 impl From<u64> for Sequence {
     fn from(seq: u64) -> Self {
         Sequence(seq)
@@ -60,7 +74,86 @@ pub struct Packet {
     #[serde(serialize_with = "crate::serializers::ser_hex_upper")]
     pub data: Vec<u8>,
     pub timeout_height: Height,
-    pub timeout_timestamp: u64,
+    pub timeout_timestamp: Timestamp,
+}
+
+/// Records that a packet was received on an unordered channel. Its presence in the host store
+/// prevents the same packet from being received twice.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Receipt {
+    Ok,
+}
+
+/// The acknowledgement that the receiving chain writes for a packet, in the standard envelope of
+/// the Cosmos SDK: the JSON encoding of either a `result`, base64-encoded, or an `error`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Acknowledgement {
+    /// The application processed the packet, with the given non-empty result.
+    Result(Vec<u8>),
+    /// The application failed to process the packet.
+    Error(String),
+}
+
+#[derive(Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+enum JsonAcknowledgement {
+    Result(String),
+    Error(String),
+}
+
+impl Acknowledgement {
+    pub fn is_success(&self) -> bool {
+        matches!(self, Acknowledgement::Result(_))
+    }
+
+    /// Encodes the acknowledgement into the bytes which the receiving chain writes.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let json = match self {
+            Acknowledgement::Result(result) => {
+                JsonAcknowledgement::Result(String::from_utf8(base64::encode(result)).unwrap())
+            }
+            Acknowledgement::Error(e) => JsonAcknowledgement::Error(e.clone()),
+        };
+
+        serde_json::to_vec(&json).unwrap()
+    }
+
+    /// Decodes an acknowledgement written by the receiving chain, rejecting an empty result or
+    /// error.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, anomaly::Error<Kind>> {
+        let json: JsonAcknowledgement =
+            serde_json::from_slice(bytes).map_err(|e| Kind::InvalidAcknowledgement.context(e))?;
+
+        match json {
+            JsonAcknowledgement::Result(result) => {
+                let result =
+                    base64::decode(result).map_err(|e| Kind::InvalidAcknowledgement.context(e))?;
+                if result.is_empty() {
+                    return Err(Kind::InvalidAcknowledgement.context("empty result").into());
+                }
+                Ok(Acknowledgement::Result(result))
+            }
+            JsonAcknowledgement::Error(e) if e.trim().is_empty() => {
+                Err(Kind::InvalidAcknowledgement.context("empty error").into())
+            }
+            JsonAcknowledgement::Error(e) => Ok(Acknowledgement::Error(e)),
+        }
+    }
+}
+
+impl Packet {
+    /// Checks whether the packet has timed out with respect to the given (counterparty or host)
+    /// `height`. A zero timeout height means that the packet never times out by height.
+    pub fn timed_out(&self, height: Height) -> bool {
+        !self.timeout_height.is_zero() && self.timeout_height <= height
+    }
+
+    /// Checks whether the packet has timed out with respect to the given (counterparty or host)
+    /// `timestamp`. A packet without timeout timestamp never times out by time, and neither does
+    /// any packet with respect to a missing timestamp.
+    pub fn timestamp_expired(&self, timestamp: &Timestamp) -> bool {
+        self.timeout_timestamp.check_expiry(timestamp) == Expiry::Expired
+    }
 }
 
 impl std::fmt::Display for Packet {
@@ -83,7 +176,7 @@ impl Default for Packet {
             destination_channel: Default::default(),
             data: vec![],
             timeout_height: Default::default(),
-            timeout_timestamp: 0,
+            timeout_timestamp: Timestamp::none(),
         }
     }
 }
@@ -116,7 +209,8 @@ impl TryFrom<RawPacket> for Packet {
                 .ok_or(Kind::MissingHeight)?
                 .try_into()
                 .map_err(|e| Kind::InvalidTimeoutHeight.context(e))?,
-            timeout_timestamp: raw_pkt.timeout_timestamp,
+            timeout_timestamp: Timestamp::from_nanoseconds(raw_pkt.timeout_timestamp)
+                .map_err(|e| Kind::InvalidTimeoutTimestamp.context(e))?,
         })
     }
 }
@@ -131,7 +225,7 @@ impl From<Packet> for RawPacket {
             destination_channel: packet.destination_channel.to_string(),
             data: packet.data,
             timeout_height: Some(packet.timeout_height.into()),
-            timeout_timestamp: packet.timeout_timestamp,
+            timeout_timestamp: packet.timeout_timestamp.nanoseconds(),
         }
     }
 }
@@ -166,7 +260,7 @@ mod tests {
     use ibc_proto::ibc::core::channel::v1::Packet as RawPacket;
 
     use crate::ics04_channel::packet::test_utils::get_dummy_raw_packet;
-    use crate::ics04_channel::packet::Packet;
+    use crate::ics04_channel::packet::{Acknowledgement, Packet};
 
     #[test]
     fn packet_try_from_raw() {
@@ -314,4 +408,28 @@ mod tests {
         assert_eq!(raw, raw_back);
         assert_eq!(msg, msg_back);
     }
+
+    #[test]
+    fn acknowledgement_encoding() {
+        let success = Acknowledgement::Result(vec![1]);
+        assert_eq!(success.to_bytes(), br#"{"result":"AQ=="}"#.to_vec());
+        assert_eq!(
+            Acknowledgement::from_bytes(&success.to_bytes()).unwrap(),
+            success
+        );
+
+        let error = Acknowledgement::Error("insufficient funds".to_string());
+        assert_eq!(
+            error.to_bytes(),
+            br#"{"error":"insufficient funds"}"#.to_vec()
+        );
+        assert_eq!(
+            Acknowledgement::from_bytes(&error.to_bytes()).unwrap(),
+            error
+        );
+
+        assert!(Acknowledgement::from_bytes(br#"{"result":""}"#).is_err());
+        assert!(Acknowledgement::from_bytes(br#"{"error":" "}"#).is_err());
+        assert!(Acknowledgement::from_bytes(b"\x01").is_err());
+    }
 }
@@ -1,18 +1,230 @@
-//! Capabilities: this is a placeholder.
+//! Capabilities, i.e., the object-capability keys which modules hold to be allowed to act on
+//! ports and channels (see ICS5). Capabilities are allocated and tracked by a `CapabilityKeeper`,
+//! in the scope of the modules owning them.
 
-#[derive(Clone, Debug, PartialEq)]
+use std::collections::{BTreeSet, HashMap};
+use std::fmt;
+
+use crate::ics05_port::error::{Error, Kind};
+use crate::ics24_host::identifier::{ChannelId, PortId};
+
+/// A key granting its owners the right to act on the object it was allocated for. Capabilities
+/// can only be allocated by a `CapabilityKeeper`, and are identified by their index.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct Capability {
     index: u64,
 }
 
 impl Capability {
-    pub fn new() -> Capability {
-        Self { index: 0x0 }
+    pub fn index(&self) -> u64 {
+        self.index
+    }
+}
+
+/// Identifies a module owning capabilities, e.g., the IBC module or an application module.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ModuleId(String);
+
+impl ModuleId {
+    pub fn new(id: &str) -> Self {
+        Self(id.to_string())
+    }
+
+    /// The identifier of the IBC module, which owns the capabilities of all ports and channels.
+    pub fn ibc() -> Self {
+        Self::new(IBC_MODULE_ID)
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for ModuleId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+pub const IBC_MODULE_ID: &str = "ibc";
+
+/// The name under which the capability of a port is owned.
+pub fn port_capability_name(port_id: &PortId) -> String {
+    format!("ports/{}", port_id)
+}
+
+/// The name under which the capability of a channel is owned.
+pub fn channel_capability_name(port_id: &PortId, channel_id: &ChannelId) -> String {
+    format!("capabilities/ports/{}/channels/{}", port_id, channel_id)
+}
+
+/// Allocates capabilities and keeps track of their owners. Each module owns capabilities under
+/// names which are unique within the scope of the module, and can only look up, authenticate or
+/// release the capabilities it owns.
+#[derive(Clone, Debug, Default)]
+pub struct CapabilityKeeper {
+    /// The index of the next capability to be allocated.
+    next_index: u64,
+
+    /// Maps the index of every allocated capability to its owners, i.e., to the modules owning
+    /// the capability, each with the name under which it owns the capability.
+    owners: HashMap<u64, BTreeSet<(ModuleId, String)>>,
+
+    /// Maps the scope of every module, i.e., the names of the capabilities owned by the module,
+    /// to the capabilities.
+    scopes: HashMap<(ModuleId, String), Capability>,
+}
+
+impl CapabilityKeeper {
+    /// Allocates a new capability, owned by `module_id` under the given name.
+    pub fn new_capability(
+        &mut self,
+        module_id: &ModuleId,
+        name: &str,
+    ) -> Result<Capability, Error> {
+        let scoped_name = (module_id.clone(), name.to_string());
+        if self.scopes.contains_key(&scoped_name) {
+            return Err(Kind::CapabilityTaken(module_id.clone(), name.to_string()).into());
+        }
+
+        let capability = Capability {
+            index: self.next_index,
+        };
+        self.next_index += 1;
+
+        self.owners
+            .entry(capability.index)
+            .or_default()
+            .insert(scoped_name.clone());
+        self.scopes.insert(scoped_name, capability.clone());
+
+        Ok(capability)
+    }
+
+    /// Makes `module_id` an owner of the given capability, under the given name. Modules claim the
+    /// capabilities passed on to them by other modules.
+    pub fn claim_capability(
+        &mut self,
+        module_id: &ModuleId,
+        capability: &Capability,
+        name: &str,
+    ) -> Result<(), Error> {
+        let owners = self
+            .owners
+            .get_mut(&capability.index)
+            .ok_or(Kind::UnknownCapability(capability.index))?;
+
+        let scoped_name = (module_id.clone(), name.to_string());
+        if self.scopes.contains_key(&scoped_name) {
+            return Err(Kind::CapabilityTaken(module_id.clone(), name.to_string()).into());
+        }
+
+        owners.insert(scoped_name.clone());
+        self.scopes.insert(scoped_name, capability.clone());
+
+        Ok(())
+    }
+
+    /// Returns the capability which `module_id` owns under the given name, if any.
+    pub fn get_capability(&self, module_id: &ModuleId, name: &str) -> Option<Capability> {
+        self.scopes
+            .get(&(module_id.clone(), name.to_string()))
+            .cloned()
+    }
+
+    /// Checks that `module_id` owns the given capability under the given name.
+    pub fn authenticate_capability(
+        &self,
+        module_id: &ModuleId,
+        capability: &Capability,
+        name: &str,
+    ) -> bool {
+        self.get_capability(module_id, name).as_ref() == Some(capability)
+    }
+
+    /// Returns the modules owning the capability with the given name in the scope of
+    /// `module_id`, along with the capability. The result includes `module_id` itself.
+    pub fn lookup_modules(
+        &self,
+        module_id: &ModuleId,
+        name: &str,
+    ) -> Option<(Vec<ModuleId>, Capability)> {
+        let capability = self.get_capability(module_id, name)?;
+        let modules = self.owners[&capability.index]
+            .iter()
+            .map(|(owner, _)| owner.clone())
+            .collect();
+
+        Some((modules, capability))
+    }
+
+    /// Revokes the ownership of the given capability by `module_id`. The capability is freed once
+    /// it has no owners left.
+    pub fn release_capability(
+        &mut self,
+        module_id: &ModuleId,
+        capability: &Capability,
+    ) -> Result<(), Error> {
+        let owners = self
+            .owners
+            .get_mut(&capability.index)
+            .ok_or(Kind::UnknownCapability(capability.index))?;
+
+        let scoped_name = owners
+            .iter()
+            .find(|(owner, _)| owner == module_id)
+            .cloned()
+            .ok_or_else(|| Kind::CapabilityNotOwned(module_id.clone(), capability.index))?;
+
+        owners.remove(&scoped_name);
+        if owners.is_empty() {
+            self.owners.remove(&capability.index);
+        }
+        self.scopes.remove(&scoped_name);
+
+        Ok(())
     }
 }
 
-impl Default for Capability {
-    fn default() -> Self {
-        Self::new()
+#[cfg(test)]
+mod tests {
+    use crate::ics05_port::capabilities::{CapabilityKeeper, ModuleId};
+
+    #[test]
+    fn capability_ownership() {
+        let ibc = ModuleId::ibc();
+        let transfer = ModuleId::new("transfer");
+        let intruder = ModuleId::new("intruder");
+        let name = "ports/transfer";
+
+        let mut keeper = CapabilityKeeper::default();
+
+        let cap = keeper.new_capability(&ibc, name).unwrap();
+        assert!(keeper.new_capability(&ibc, name).is_err());
+        assert!(keeper.claim_capability(&transfer, &cap, name).is_ok());
+        assert!(keeper.claim_capability(&transfer, &cap, name).is_err());
+
+        // Both owners can look up and authenticate the capability, whereas other modules cannot.
+        assert_eq!(keeper.get_capability(&transfer, name), Some(cap.clone()));
+        assert!(keeper.authenticate_capability(&ibc, &cap, name));
+        assert!(keeper.authenticate_capability(&transfer, &cap, name));
+        assert_eq!(keeper.get_capability(&intruder, name), None);
+        assert!(!keeper.authenticate_capability(&intruder, &cap, name));
+        assert!(keeper.lookup_modules(&intruder, name).is_none());
+
+        let (modules, _) = keeper.lookup_modules(&ibc, name).unwrap();
+        assert_eq!(modules, vec![ibc.clone(), transfer.clone()]);
+
+        // A capability of another object does not authenticate.
+        let other_cap = keeper.new_capability(&intruder, "ports/other").unwrap();
+        assert_ne!(cap, other_cap);
+        assert!(!keeper.authenticate_capability(&intruder, &other_cap, name));
+
+        // Only owners can release a capability, which is freed once all owners released it.
+        assert!(keeper.release_capability(&intruder, &cap).is_err());
+        assert!(keeper.release_capability(&transfer, &cap).is_ok());
+        assert!(!keeper.authenticate_capability(&transfer, &cap, name));
+        assert!(keeper.release_capability(&ibc, &cap).is_ok());
+        assert!(keeper.claim_capability(&transfer, &cap, name).is_err());
     }
 }
@@ -1,10 +1,101 @@
-use crate::ics05_port::capabilities::Capability;
-use crate::ics24_host::identifier::PortId;
+use crate::ics05_port::capabilities::{
+    channel_capability_name, port_capability_name, Capability, CapabilityKeeper, ModuleId,
+};
+use crate::ics05_port::error::{Error, Kind};
+use crate::ics24_host::identifier::{ChannelId, PortId};
 
-// A context supplying all the necessary read-only dependencies for processing any information regarding a port.
+/// A context supplying all the necessary read-only dependencies for processing any information
+/// regarding a port. Port and channel capabilities are owned by the IBC module, as well as by the
+/// module bound to the port.
 pub trait PortReader {
-    fn lookup_module_by_port(&self, port_id: &PortId) -> Option<Capability>;
-    fn autenthenticate(&self, key: &Capability, port_id: &PortId) -> bool;
+    /// Returns the keeper of the capabilities allocated on the host chain.
+    fn capability_keeper(&self) -> &CapabilityKeeper;
+
+    /// Returns the module bound to the given port, along with the capability of the port.
+    fn lookup_module_by_port(&self, port_id: &PortId) -> Option<(ModuleId, Capability)> {
+        lookup_module(self.capability_keeper(), &port_capability_name(port_id))
+    }
+
+    /// Returns the module owning the given channel, along with the capability of the channel.
+    fn lookup_module_by_channel(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Option<(ModuleId, Capability)> {
+        lookup_module(
+            self.capability_keeper(),
+            &channel_capability_name(port_id, channel_id),
+        )
+    }
+
+    /// Checks that `key` is the capability of the given port.
+    fn authenticate(&self, key: &Capability, port_id: &PortId) -> bool {
+        self.capability_keeper().authenticate_capability(
+            &ModuleId::ibc(),
+            key,
+            &port_capability_name(port_id),
+        )
+    }
+
+    /// Checks that `key` is the capability of the given channel.
+    fn authenticate_channel(
+        &self,
+        key: &Capability,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> bool {
+        self.capability_keeper().authenticate_capability(
+            &ModuleId::ibc(),
+            key,
+            &channel_capability_name(port_id, channel_id),
+        )
+    }
+}
+
+/// A context supplying all the necessary write-only dependencies (i.e., storage writing facility)
+/// for binding ports and allocating channel capabilities.
+pub trait PortKeeper: PortReader {
+    fn capability_keeper_mut(&mut self) -> &mut CapabilityKeeper;
+
+    /// Binds the given port to module `module_id`, returning the capability of the port.
+    fn bind_port(&mut self, module_id: &ModuleId, port_id: &PortId) -> Result<Capability, Error> {
+        if self.lookup_module_by_port(port_id).is_some() {
+            return Err(Kind::PortAlreadyBound(port_id.clone()).into());
+        }
+
+        let name = port_capability_name(port_id);
+        let keeper = self.capability_keeper_mut();
+        let capability = keeper.new_capability(&ModuleId::ibc(), &name)?;
+        keeper.claim_capability(module_id, &capability, &name)?;
+
+        Ok(capability)
+    }
+
+    /// Allocates the capability of a channel opened on the given port, owned by the module bound
+    /// to the port.
+    fn new_channel_capability(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<Capability, Error> {
+        let (module_id, _) = self
+            .lookup_module_by_port(port_id)
+            .ok_or(Kind::UnknownPort)?;
+
+        let name = channel_capability_name(port_id, channel_id);
+        let keeper = self.capability_keeper_mut();
+        let capability = keeper.new_capability(&ModuleId::ibc(), &name)?;
+        keeper.claim_capability(&module_id, &capability, &name)?;
+
+        Ok(capability)
+    }
 }
 
-//  Result<Capability, Error>//return Ok(Capability::new());
+/// Returns the module other than the IBC module which owns the capability with the given name in
+/// the scope of the IBC module, along with the capability.
+fn lookup_module(keeper: &CapabilityKeeper, name: &str) -> Option<(ModuleId, Capability)> {
+    let (modules, capability) = keeper.lookup_modules(&ModuleId::ibc(), name)?;
+    let module_id = modules.into_iter().find(|m| m != &ModuleId::ibc())?;
+
+    Some((module_id, capability))
+}
@@ -1,12 +1,27 @@
 use anomaly::{BoxError, Context};
 use thiserror::Error;
 
+use crate::ics05_port::capabilities::ModuleId;
+use crate::ics24_host::identifier::PortId;
+
 pub type Error = anomaly::Error<Kind>;
 
 #[derive(Clone, Debug, Error)]
 pub enum Kind {
     #[error("port unknown")]
     UnknownPort,
+
+    #[error("port {0} is already bound")]
+    PortAlreadyBound(PortId),
+
+    #[error("module {0} already owns a capability named {1}")]
+    CapabilityTaken(ModuleId, String),
+
+    #[error("capability {0} does not exist")]
+    UnknownCapability(u64),
+
+    #[error("module {0} does not own capability {1}")]
+    CapabilityNotOwned(ModuleId, u64),
 }
 
 impl Kind {
@@ -0,0 +1,885 @@
+use prost::Message;
+use prost_types::Any;
+
+use ibc_proto::cosmos::tx::signing::v1beta1::signature_descriptor::{data::Sum, Data};
+use ibc_proto::ibc::core::channel::v1::Channel as RawChannel;
+use ibc_proto::ibc::core::connection::v1::ConnectionEnd as RawConnectionEnd;
+use ibc_proto::ibc::lightclients::solomachine::v1::{
+    ChannelStateData, ClientStateData, ConnectionStateData, ConsensusStateData, DataType,
+    HeaderData, NextSequenceRecvData, PacketAcknowledgementData, PacketCommitmentData,
+    PacketReceiptAbsenceData, SignBytes, TimestampedSignatureData,
+};
+
+use crate::ics02_client::client_def::{AnyClientState, AnyConsensusState, ClientDef};
+use crate::ics02_client::context::ClientReader;
+use crate::ics02_client::state::ClientState as ICS2ClientState;
+use crate::ics03_connection::connection::ConnectionEnd;
+use crate::ics03_connection::context::ConnectionReader;
+use crate::ics04_channel::channel::ChannelEnd;
+use crate::ics04_channel::commitment::PacketCommitment;
+use crate::ics04_channel::context::ChannelReader;
+use crate::ics04_channel::packet::Sequence;
+use crate::ics06_solomachine::client_state::ClientState;
+use crate::ics06_solomachine::consensus_state::ConsensusState;
+use crate::ics06_solomachine::error::{Error, Kind};
+use crate::ics06_solomachine::header::Header;
+use crate::ics06_solomachine::misbehaviour::{Misbehaviour, SignatureAndData};
+use crate::ics06_solomachine::public_key::PublicKey;
+use crate::ics23_commitment::commitment::{CommitmentPrefix, CommitmentProofBytes, CommitmentRoot};
+use crate::ics23_commitment::merkle::apply_prefix;
+use crate::ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId};
+use crate::ics24_host::Path;
+use crate::Height;
+
+/// The solo machine client, which verifies the signatures of a solo machine, e.g., an off-chain
+/// signer, instead of proofs of the state of a chain (see ICS6). The proofs passed to the
+/// verification functions are `TimestampedSignatureData` protobuf messages, whose signatures sign
+/// the `SignBytes` of the verified data at the current sequence of the solo machine.
+///
+/// As in the Cosmos SDK, the sequence advances with every verified proof, as well as with headers,
+/// so that each signature verifies only once: the proofs of a message bundling several of them
+/// are signed at consecutive sequences.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SoloMachineClient;
+
+impl ClientDef for SoloMachineClient {
+    type Header = Header;
+    type Misbehaviour = Misbehaviour;
+    type ClientState = ClientState;
+    type ConsensusState = ConsensusState;
+
+    fn check_header_and_update_state(
+        &self,
+        _ctx: &dyn ClientReader,
+        _client_id: ClientId,
+        client_state: Self::ClientState,
+        header: Self::Header,
+    ) -> Result<(Self::ClientState, Self::ConsensusState), Box<dyn std::error::Error>> {
+        if client_state.is_frozen() {
+            return Err(Kind::FrozenClient.into());
+        }
+        if header.sequence != client_state.sequence {
+            return Err(Kind::MismatchedSequence(header.sequence, client_state.sequence).into());
+        }
+
+        let consensus_state = &client_state.consensus_state;
+        if header.timestamp < consensus_state.timestamp {
+            return Err(Kind::LowTimestamp(header.timestamp, consensus_state.timestamp).into());
+        }
+
+        // The header is signed with the current key, and rotates it.
+        let data = encode(&HeaderData {
+            new_pub_key: Some(header.new_public_key.clone().into()),
+            new_diversifier: header.new_diversifier.clone(),
+        });
+        let sign_bytes = sign_bytes(
+            header.sequence,
+            header.timestamp,
+            &consensus_state.diversifier,
+            DataType::Header,
+            data,
+        );
+        verify_signature(&consensus_state.public_key, &sign_bytes, &header.signature)?;
+
+        let new_consensus_state = ConsensusState::new(
+            header.new_public_key,
+            header.new_diversifier,
+            header.timestamp,
+        );
+        let new_client_state = ClientState {
+            sequence: client_state.sequence + 1,
+            consensus_state: new_consensus_state.clone(),
+            ..client_state
+        };
+
+        Ok((new_client_state, new_consensus_state))
+    }
+
+    fn check_misbehaviour_and_update_state(
+        &self,
+        _ctx: &dyn ClientReader,
+        _client_id: ClientId,
+        client_state: Self::ClientState,
+        misbehaviour: Self::Misbehaviour,
+    ) -> Result<Self::ClientState, Box<dyn std::error::Error>> {
+        if client_state.is_frozen() {
+            return Err(Kind::FrozenClient.into());
+        }
+
+        // Both signatures must be valid signatures of the solo machine at the same sequence. An
+        // honest solo machine signs a single message per sequence, since the sequence advances
+        // with every verified signature. That they sign different data is checked when decoding
+        // the misbehaviour.
+        for signature in [&misbehaviour.signature_one, &misbehaviour.signature_two].iter() {
+            verify_signature_and_data(&client_state, misbehaviour.sequence, signature)
+                .map_err(|e| Kind::InvalidMisbehaviour.context(e))?;
+        }
+
+        Ok(client_state.with_frozen_sequence(misbehaviour.sequence))
+    }
+
+    fn verify_upgrade_and_update_state(
+        &self,
+        _ctx: &dyn ClientReader,
+        _client_id: ClientId,
+        _client_state: Self::ClientState,
+        _upgraded_client_state: Self::ClientState,
+        _upgraded_consensus_state: Self::ConsensusState,
+        _proof_upgrade_client: &CommitmentProofBytes,
+        _proof_upgrade_consensus_state: &CommitmentProofBytes,
+    ) -> Result<(Self::ClientState, Self::ConsensusState), Box<dyn std::error::Error>> {
+        Err(Kind::UpgradeNotSupported.into())
+    }
+
+    /// The solo machine signs its proofs with its current consensus state, whatever the sequence
+    /// the proofs are for.
+    fn embedded_consensus_state(
+        &self,
+        client_state: &Self::ClientState,
+    ) -> Option<Self::ConsensusState> {
+        Some(client_state.consensus_state.clone())
+    }
+
+    fn verify_client_consensus_state(
+        &self,
+        _ctx: &dyn ConnectionReader,
+        client_state: &Self::ClientState,
+        height: Height,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        _root: &CommitmentRoot,
+        client_id: &ClientId,
+        consensus_height: Height,
+        expected_consensus_state: &AnyConsensusState,
+    ) -> Result<Option<Self::ClientState>, Box<dyn std::error::Error>> {
+        let path = Path::ClientConsensusState {
+            client_id: client_id.clone(),
+            epoch: consensus_height.revision_number,
+            height: consensus_height.revision_height,
+        };
+        let data = encode(&ConsensusStateData {
+            path: path_bytes(prefix, path)?,
+            consensus_state: Some(Any::from(expected_consensus_state.clone())),
+        });
+
+        verify_signed_data(client_state, height, proof, DataType::ConsensusState, data).map(Some)
+    }
+
+    fn verify_connection_state(
+        &self,
+        _ctx: &dyn ConnectionReader,
+        client_state: &Self::ClientState,
+        height: Height,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        _root: &CommitmentRoot,
+        connection_id: &ConnectionId,
+        expected_connection_end: &ConnectionEnd,
+    ) -> Result<Option<Self::ClientState>, Box<dyn std::error::Error>> {
+        let path = Path::Connections(connection_id.clone());
+        let data = encode(&ConnectionStateData {
+            path: path_bytes(prefix, path)?,
+            connection: Some(RawConnectionEnd::from(expected_connection_end.clone())),
+        });
+
+        verify_signed_data(client_state, height, proof, DataType::ConnectionState, data).map(Some)
+    }
+
+    fn verify_channel_state(
+        &self,
+        _ctx: &dyn ChannelReader,
+        client_state: &Self::ClientState,
+        height: Height,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        _root: &CommitmentRoot,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        expected_channel_end: &ChannelEnd,
+    ) -> Result<Option<Self::ClientState>, Box<dyn std::error::Error>> {
+        let path = Path::ChannelEnds(port_id.clone(), channel_id.clone());
+        let data = encode(&ChannelStateData {
+            path: path_bytes(prefix, path)?,
+            channel: Some(RawChannel::from(expected_channel_end.clone())),
+        });
+
+        verify_signed_data(client_state, height, proof, DataType::ChannelState, data).map(Some)
+    }
+
+    fn verify_client_full_state(
+        &self,
+        _ctx: &dyn ConnectionReader,
+        client_state: &Self::ClientState,
+        height: Height,
+        _root: &CommitmentRoot,
+        prefix: &CommitmentPrefix,
+        client_id: &ClientId,
+        proof: &CommitmentProofBytes,
+        expected_client_state: &AnyClientState,
+    ) -> Result<Option<Self::ClientState>, Box<dyn std::error::Error>> {
+        let path = Path::ClientState(client_id.clone());
+        let data = encode(&ClientStateData {
+            path: path_bytes(prefix, path)?,
+            client_state: Some(Any::from(expected_client_state.clone())),
+        });
+
+        verify_signed_data(client_state, height, proof, DataType::ClientState, data).map(Some)
+    }
+
+    fn verify_packet_data(
+        &self,
+        _ctx: &dyn ChannelReader,
+        client_state: &Self::ClientState,
+        height: Height,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        _root: &CommitmentRoot,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        seq: &Sequence,
+        commitment: PacketCommitment,
+    ) -> Result<Option<Self::ClientState>, Box<dyn std::error::Error>> {
+        let path = Path::Commitments {
+            port_id: port_id.clone(),
+            channel_id: channel_id.clone(),
+            sequence: *seq,
+        };
+        let data = encode(&PacketCommitmentData {
+            path: path_bytes(prefix, path)?,
+            commitment: commitment.into_vec(),
+        });
+
+        verify_signed_data(
+            client_state,
+            height,
+            proof,
+            DataType::PacketCommitment,
+            data,
+        )
+        .map(Some)
+    }
+
+    fn verify_packet_acknowledgement(
+        &self,
+        _ctx: &dyn ChannelReader,
+        client_state: &Self::ClientState,
+        height: Height,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        _root: &CommitmentRoot,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        seq: &Sequence,
+        ack: Vec<u8>,
+    ) -> Result<Option<Self::ClientState>, Box<dyn std::error::Error>> {
+        let path = Path::Acks {
+            port_id: port_id.clone(),
+            channel_id: channel_id.clone(),
+            sequence: *seq,
+        };
+        // Solo machines sign the acknowledgement itself, not its hash.
+        let data = encode(&PacketAcknowledgementData {
+            path: path_bytes(prefix, path)?,
+            acknowledgement: ack,
+        });
+
+        verify_signed_data(
+            client_state,
+            height,
+            proof,
+            DataType::PacketAcknowledgement,
+            data,
+        )
+        .map(Some)
+    }
+
+    fn verify_next_sequence_recv(
+        &self,
+        _ctx: &dyn ChannelReader,
+        client_state: &Self::ClientState,
+        height: Height,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        _root: &CommitmentRoot,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        seq: &Sequence,
+    ) -> Result<Option<Self::ClientState>, Box<dyn std::error::Error>> {
+        let path = Path::SeqRecvs(port_id.clone(), channel_id.clone());
+        let data = encode(&NextSequenceRecvData {
+            path: path_bytes(prefix, path)?,
+            next_seq_recv: u64::from(*seq),
+        });
+
+        verify_signed_data(
+            client_state,
+            height,
+            proof,
+            DataType::NextSequenceRecv,
+            data,
+        )
+        .map(Some)
+    }
+
+    fn verify_packet_receipt_absence(
+        &self,
+        _ctx: &dyn ChannelReader,
+        client_state: &Self::ClientState,
+        height: Height,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        _root: &CommitmentRoot,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        seq: &Sequence,
+    ) -> Result<Option<Self::ClientState>, Box<dyn std::error::Error>> {
+        let path = Path::Receipts {
+            port_id: port_id.clone(),
+            channel_id: channel_id.clone(),
+            sequence: *seq,
+        };
+        let data = encode(&PacketReceiptAbsenceData {
+            path: path_bytes(prefix, path)?,
+        });
+
+        verify_signed_data(
+            client_state,
+            height,
+            proof,
+            DataType::PacketReceiptAbsence,
+            data,
+        )
+        .map(Some)
+    }
+}
+
+/// Returns the bytes which a solo machine signs at `sequence` to attest `data` of the given type.
+/// The diversifier is that of the current consensus state of the solo machine.
+pub fn sign_bytes(
+    sequence: u64,
+    timestamp: u64,
+    diversifier: &str,
+    data_type: DataType,
+    data: Vec<u8>,
+) -> Vec<u8> {
+    encode(&SignBytes {
+        sequence,
+        timestamp,
+        diversifier: diversifier.to_string(),
+        data_type: data_type as i32,
+        data,
+    })
+}
+
+/// Verifies that the solo machine signed `data` of the given type at its current sequence, as
+/// attested by the timestamped signature `proof`. The proof `height` must not be past the current
+/// sequence. Returns the client state with the sequence advanced past the signature.
+fn verify_signed_data(
+    client_state: &ClientState,
+    height: Height,
+    proof: &CommitmentProofBytes,
+    data_type: DataType,
+    data: Vec<u8>,
+) -> Result<ClientState, Box<dyn std::error::Error>> {
+    if client_state.is_frozen() {
+        return Err(Kind::FrozenClient.into());
+    }
+    if height > client_state.latest_height() {
+        return Err(Kind::InvalidProofHeight(height, client_state.latest_height()).into());
+    }
+    if proof.is_empty() {
+        return Err(Kind::EmptyProof.into());
+    }
+
+    let proof = TimestampedSignatureData::decode(Vec::<u8>::from(proof.clone()).as_slice())
+        .map_err(|e| Kind::InvalidProof.context(e))?;
+
+    let consensus_state = &client_state.consensus_state;
+    if proof.timestamp < consensus_state.timestamp {
+        return Err(Kind::LowTimestamp(proof.timestamp, consensus_state.timestamp).into());
+    }
+
+    let sign_bytes = sign_bytes(
+        client_state.sequence,
+        proof.timestamp,
+        &consensus_state.diversifier,
+        data_type,
+        data,
+    );
+    verify_signature(
+        &consensus_state.public_key,
+        &sign_bytes,
+        &proof.signature_data,
+    )?;
+
+    let mut new_client_state = client_state.clone();
+    new_client_state.sequence += 1;
+    new_client_state.consensus_state.timestamp = proof.timestamp;
+
+    Ok(new_client_state)
+}
+
+/// Verifies one of the signatures of a misbehaviour evidence at the given sequence.
+fn verify_signature_and_data(
+    client_state: &ClientState,
+    sequence: u64,
+    signature: &SignatureAndData,
+) -> Result<(), Error> {
+    let consensus_state = &client_state.consensus_state;
+    let sign_bytes = sign_bytes(
+        sequence,
+        signature.timestamp,
+        &consensus_state.diversifier,
+        signature.data_type,
+        signature.data.clone(),
+    );
+
+    verify_signature(
+        &consensus_state.public_key,
+        &sign_bytes,
+        &signature.signature,
+    )
+}
+
+/// Verifies a signature of `sign_bytes` by `public_key`, encoded as a `SignatureDescriptor.Data`
+/// protobuf message. Only single signatures are supported.
+fn verify_signature(
+    public_key: &PublicKey,
+    sign_bytes: &[u8],
+    signature_data: &[u8],
+) -> Result<(), Error> {
+    let signature_data =
+        Data::decode(signature_data).map_err(|e| Kind::InvalidSignature.context(e))?;
+
+    match signature_data.sum {
+        Some(Sum::Single(single)) => public_key.verify(sign_bytes, &single.signature),
+        Some(Sum::Multi(_)) => Err(Kind::MultiSignatureNotSupported.into()),
+        None => Err(Kind::InvalidSignature
+            .context("missing signature data")
+            .into()),
+    }
+}
+
+/// Returns the encoding of `path` prefixed with the store `prefix`, as signed by solo machines.
+fn path_bytes(
+    prefix: &CommitmentPrefix,
+    path: Path,
+) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+    let merkle_path = apply_prefix(prefix, vec![path.to_string()])?;
+    Ok(encode(&merkle_path))
+}
+
+fn encode<M: Message>(message: &M) -> Vec<u8> {
+    let mut buf = Vec::with_capacity(message.encoded_len());
+    // Encoding into a vector cannot fail.
+    message.encode(&mut buf).unwrap();
+    buf
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use k256::ecdsa::signature::Signer;
+    use k256::ecdsa::SigningKey;
+    use prost::Message;
+
+    use ibc_proto::cosmos::tx::signing::v1beta1::signature_descriptor::data::{Single, Sum};
+    use ibc_proto::cosmos::tx::signing::v1beta1::signature_descriptor::Data;
+    use ibc_proto::ibc::core::connection::v1::ConnectionEnd as RawConnectionEnd;
+    use ibc_proto::ibc::lightclients::solomachine::v1::{
+        ClientStateData, ConnectionStateData, ConsensusStateData, DataType, HeaderData,
+        Misbehaviour as RawMisbehaviour, TimestampedSignatureData,
+    };
+    use prost_types::Any;
+
+    use crate::ics02_client::client_def::{AnyClientState, AnyConsensusState, ClientDef};
+    use crate::ics02_client::state::ClientState as ICS2ClientState;
+    use crate::ics03_connection::connection::{
+        ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
+    };
+    use crate::ics03_connection::version::get_compatible_versions;
+    use crate::ics06_solomachine::client_def::{encode, path_bytes, sign_bytes, SoloMachineClient};
+    use crate::ics06_solomachine::client_state::test_util::{
+        get_dummy_solomachine_client_state, get_dummy_solomachine_signing_key, DUMMY_DIVERSIFIER,
+    };
+    use crate::ics06_solomachine::client_state::ClientState;
+    use crate::ics06_solomachine::header::Header;
+    use crate::ics06_solomachine::misbehaviour::{Misbehaviour, SignatureAndData};
+    use crate::ics06_solomachine::public_key::PublicKey;
+    use crate::ics23_commitment::commitment::{
+        CommitmentPrefix, CommitmentProofBytes, CommitmentRoot,
+    };
+    use crate::ics24_host::identifier::{ClientId, ConnectionId};
+    use crate::ics24_host::Path;
+    use crate::mock::context::MockContext;
+    use crate::Height;
+
+    /// Signs `sign_bytes` with `key`, encoding the signature as solo machines do.
+    fn sign(key: &SigningKey, sign_bytes: &[u8]) -> Vec<u8> {
+        let signature: k256::ecdsa::Signature = key.sign(sign_bytes);
+        let data = Data {
+            sum: Some(Sum::Single(Single {
+                mode: 1,
+                signature: signature.as_ref().to_vec(),
+            })),
+        };
+
+        let mut buf = Vec::new();
+        data.encode(&mut buf).unwrap();
+        buf
+    }
+
+    fn public_key(key: &SigningKey) -> PublicKey {
+        PublicKey::Secp256k1(key.verify_key().to_bytes().to_vec())
+    }
+
+    fn prefix() -> CommitmentPrefix {
+        CommitmentPrefix::from(b"ibc".to_vec())
+    }
+
+    /// Returns a header signed by `key`, rotating the key of the solo machine to `new_key`.
+    fn signed_header(
+        sequence: u64,
+        timestamp: u64,
+        key: &SigningKey,
+        new_key: &SigningKey,
+    ) -> Header {
+        let new_public_key = public_key(new_key);
+        let new_diversifier = "new diversifier".to_string();
+
+        let mut data = Vec::new();
+        HeaderData {
+            new_pub_key: Some(new_public_key.clone().into()),
+            new_diversifier: new_diversifier.clone(),
+        }
+        .encode(&mut data)
+        .unwrap();
+        let sign_bytes = sign_bytes(
+            sequence,
+            timestamp,
+            DUMMY_DIVERSIFIER,
+            DataType::Header,
+            data,
+        );
+
+        Header {
+            sequence,
+            timestamp,
+            signature: sign(key, &sign_bytes),
+            new_public_key,
+            new_diversifier,
+        }
+    }
+
+    #[test]
+    fn update_with_header() {
+        struct Test {
+            name: String,
+            header: Header,
+            want_pass: bool,
+        }
+
+        let key = get_dummy_solomachine_signing_key();
+        let new_key = SigningKey::from_bytes(&[2; 32]).unwrap();
+        let client_state = get_dummy_solomachine_client_state(5);
+
+        let tests: Vec<Test> = vec![
+            Test {
+                name: "Header signed with the current key".to_string(),
+                header: signed_header(5, 10, &key, &new_key),
+                want_pass: true,
+            },
+            Test {
+                name: "Header at another sequence".to_string(),
+                header: signed_header(6, 10, &key, &new_key),
+                want_pass: false,
+            },
+            Test {
+                name: "Header older than the consensus state".to_string(),
+                header: signed_header(5, 0, &key, &new_key),
+                want_pass: false,
+            },
+            Test {
+                name: "Header signed with another key".to_string(),
+                header: signed_header(5, 10, &new_key, &new_key),
+                want_pass: false,
+            },
+        ];
+
+        for test in tests {
+            let res = SoloMachineClient.check_header_and_update_state(
+                &MockContext::default(),
+                ClientId::default(),
+                client_state.clone(),
+                test.header.clone(),
+            );
+
+            assert_eq!(
+                test.want_pass,
+                res.is_ok(),
+                "update_with_header: test \"{}\" failed: {:?}",
+                test.name,
+                res.err()
+            );
+
+            if let Ok((new_client_state, new_consensus_state)) = res {
+                assert_eq!(new_client_state.latest_height(), Height::new(0, 6));
+                assert_eq!(new_client_state.consensus_state, new_consensus_state);
+                assert_eq!(new_consensus_state.public_key, public_key(&new_key));
+                assert_eq!(new_consensus_state.timestamp, test.header.timestamp);
+            }
+        }
+    }
+
+    #[test]
+    fn verify_connection_state() {
+        let key = get_dummy_solomachine_signing_key();
+        let client_state = get_dummy_solomachine_client_state(5);
+        let height = client_state.latest_height();
+
+        let connection_id = ConnectionId::default();
+        let connection_end = ConnectionEnd::new(
+            ConnectionState::Init,
+            ClientId::default(),
+            ConnectionCounterparty::new(ClientId::default(), None, prefix()),
+            get_compatible_versions(),
+            0,
+        );
+
+        // The solo machine signs the connection end at its current sequence.
+        let mut data = Vec::new();
+        ConnectionStateData {
+            path: path_bytes(&prefix(), Path::Connections(connection_id.clone())).unwrap(),
+            connection: Some(RawConnectionEnd::from(connection_end.clone())),
+        }
+        .encode(&mut data)
+        .unwrap();
+        let sign_bytes = sign_bytes(5, 10, DUMMY_DIVERSIFIER, DataType::ConnectionState, data);
+
+        let mut proof = Vec::new();
+        TimestampedSignatureData {
+            signature_data: sign(&key, &sign_bytes),
+            timestamp: 10,
+        }
+        .encode(&mut proof)
+        .unwrap();
+        let proof = CommitmentProofBytes::from(proof);
+
+        let verify = |client_state: &ClientState, height, connection_end: &ConnectionEnd| {
+            SoloMachineClient.verify_connection_state(
+                &MockContext::default(),
+                client_state,
+                height,
+                &prefix(),
+                &proof,
+                &CommitmentRoot::from_bytes(&[]),
+                &connection_id,
+                connection_end,
+            )
+        };
+
+        // Verifying the signature advances the sequence past it.
+        let new_client_state = verify(&client_state, height, &connection_end)
+            .unwrap()
+            .unwrap();
+        assert_eq!(new_client_state.sequence, 6);
+        assert_eq!(new_client_state.latest_height(), height.increment());
+        assert!(verify(&new_client_state, height, &connection_end).is_err());
+
+        // The solo machine signed a connection end in a different state.
+        let mut other_connection_end = connection_end.clone();
+        other_connection_end.set_state(ConnectionState::Open);
+        assert!(verify(&client_state, height, &other_connection_end).is_err());
+
+        // The proof height is past the current sequence of the solo machine.
+        assert!(verify(&client_state, height.increment(), &connection_end).is_err());
+
+        // A frozen client verifies nothing.
+        let frozen_client_state = client_state.clone().with_frozen_sequence(5);
+        assert!(verify(&frozen_client_state, height, &connection_end).is_err());
+    }
+
+    #[test]
+    fn verify_multiple_proofs() {
+        let key = get_dummy_solomachine_signing_key();
+        let client_state = get_dummy_solomachine_client_state(5);
+        let height = client_state.latest_height();
+        let client_id = ClientId::default();
+
+        // The proofs of a `MsgConnectionOpenTry` from an honest solo machine, signed at consecutive
+        // sequences, with the data they sign.
+        let connection_id = ConnectionId::default();
+        let connection_end = ConnectionEnd::new(
+            ConnectionState::Init,
+            client_id.clone(),
+            ConnectionCounterparty::new(client_id.clone(), None, prefix()),
+            get_compatible_versions(),
+            0,
+        );
+        let expected_client_state = AnyClientState::SoloMachine(client_state.clone());
+        let expected_consensus_state =
+            AnyConsensusState::SoloMachine(client_state.consensus_state.clone());
+
+        let connection_data = encode(&ConnectionStateData {
+            path: path_bytes(&prefix(), Path::Connections(connection_id.clone())).unwrap(),
+            connection: Some(RawConnectionEnd::from(connection_end.clone())),
+        });
+        let client_data = encode(&ClientStateData {
+            path: path_bytes(&prefix(), Path::ClientState(client_id.clone())).unwrap(),
+            client_state: Some(Any::from(expected_client_state.clone())),
+        });
+        let consensus_path = Path::ClientConsensusState {
+            client_id: client_id.clone(),
+            epoch: height.revision_number,
+            height: height.revision_height,
+        };
+        let consensus_data = encode(&ConsensusStateData {
+            path: path_bytes(&prefix(), consensus_path).unwrap(),
+            consensus_state: Some(Any::from(expected_consensus_state.clone())),
+        });
+
+        let signature_and_data = |sequence, data_type, data: &[u8]| {
+            let sign_bytes = sign_bytes(sequence, 10, DUMMY_DIVERSIFIER, data_type, data.to_vec());
+            SignatureAndData {
+                signature: sign(&key, &sign_bytes),
+                data_type,
+                data: data.to_vec(),
+                timestamp: 10,
+            }
+        };
+        let proof = |signature: &SignatureAndData| {
+            CommitmentProofBytes::from(encode(&TimestampedSignatureData {
+                signature_data: signature.signature.clone(),
+                timestamp: signature.timestamp,
+            }))
+        };
+
+        let connection_signature =
+            signature_and_data(5, DataType::ConnectionState, &connection_data);
+        let client_signature = signature_and_data(6, DataType::ClientState, &client_data);
+        let consensus_signature = signature_and_data(7, DataType::ConsensusState, &consensus_data);
+
+        // Each proof verifies with the client state left by the verification of the previous one.
+        let ctx = MockContext::default();
+        let root = CommitmentRoot::from_bytes(&[]);
+        let client_state_1 = SoloMachineClient
+            .verify_connection_state(
+                &ctx,
+                &client_state,
+                height,
+                &prefix(),
+                &proof(&connection_signature),
+                &root,
+                &connection_id,
+                &connection_end,
+            )
+            .unwrap()
+            .unwrap();
+        let client_state_2 = SoloMachineClient
+            .verify_client_full_state(
+                &ctx,
+                &client_state_1,
+                height,
+                &root,
+                &prefix(),
+                &client_id,
+                &proof(&client_signature),
+                &expected_client_state,
+            )
+            .unwrap()
+            .unwrap();
+        let client_state_3 = SoloMachineClient
+            .verify_client_consensus_state(
+                &ctx,
+                &client_state_2,
+                height,
+                &prefix(),
+                &proof(&consensus_signature),
+                &root,
+                &client_id,
+                height,
+                &expected_consensus_state,
+            )
+            .unwrap()
+            .unwrap();
+        assert_eq!(client_state_3.sequence, 8);
+
+        // No two signatures of the message are at the same sequence, so they are no evidence of
+        // misbehaviour.
+        let misbehaviour = Misbehaviour {
+            client_id,
+            sequence: 5,
+            signature_one: connection_signature,
+            signature_two: client_signature,
+        };
+        assert!(SoloMachineClient
+            .check_misbehaviour_and_update_state(
+                &ctx,
+                ClientId::default(),
+                client_state,
+                misbehaviour
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn freeze_on_misbehaviour() {
+        let key = get_dummy_solomachine_signing_key();
+        let client_state = get_dummy_solomachine_client_state(5);
+
+        let signature_and_data = |data: &[u8]| {
+            let sign_bytes = sign_bytes(
+                5,
+                10,
+                DUMMY_DIVERSIFIER,
+                DataType::ConnectionState,
+                data.to_vec(),
+            );
+            SignatureAndData {
+                signature: sign(&key, &sign_bytes),
+                data_type: DataType::ConnectionState,
+                data: data.to_vec(),
+                timestamp: 10,
+            }
+        };
+
+        let misbehaviour = Misbehaviour {
+            client_id: ClientId::default(),
+            sequence: 5,
+            signature_one: signature_and_data(b"one"),
+            signature_two: signature_and_data(b"two"),
+        };
+
+        // Conflicting data cannot be decoded as misbehaviour unless it differs.
+        let mut raw: RawMisbehaviour = misbehaviour.clone().into();
+        assert!(Misbehaviour::try_from(raw.clone()).is_ok());
+        raw.signature_two = raw.signature_one.clone();
+        assert!(Misbehaviour::try_from(raw).is_err());
+
+        let frozen_client_state = SoloMachineClient
+            .check_misbehaviour_and_update_state(
+                &MockContext::default(),
+                ClientId::default(),
+                client_state.clone(),
+                misbehaviour.clone(),
+            )
+            .unwrap();
+        assert!(frozen_client_state.is_frozen());
+        assert_eq!(frozen_client_state.frozen_sequence, 5);
+
+        // Signatures at another sequence are no evidence of misbehaviour.
+        let misbehaviour = Misbehaviour {
+            sequence: 6,
+            ..misbehaviour
+        };
+        assert!(SoloMachineClient
+            .check_misbehaviour_and_update_state(
+                &MockContext::default(),
+                ClientId::default(),
+                client_state,
+                misbehaviour,
+            )
+            .is_err());
+    }
+}
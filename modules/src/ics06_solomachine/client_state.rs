@@ -0,0 +1,127 @@
+use std::convert::{TryFrom, TryInto};
+
+use serde::Serialize;
+use tendermint_proto::Protobuf;
+
+use ibc_proto::ibc::lightclients::solomachine::v1::ClientState as RawClientState;
+
+use crate::ics02_client::client_def::AnyClientState;
+use crate::ics02_client::client_type::ClientType;
+use crate::ics06_solomachine::consensus_state::ConsensusState;
+use crate::ics06_solomachine::error::{Error, Kind};
+use crate::Height;
+
+/// The client state of a solo machine. The sequence of the solo machine, i.e., the number of
+/// headers and proofs it signed so far, stands for its height. A frozen client records the sequence
+/// at which the solo machine misbehaved.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct ClientState {
+    pub sequence: u64,
+    pub frozen_sequence: u64,
+    pub consensus_state: ConsensusState,
+    pub allow_update_after_proposal: bool,
+}
+
+impl ClientState {
+    pub fn new(
+        sequence: u64,
+        consensus_state: ConsensusState,
+        allow_update_after_proposal: bool,
+    ) -> Self {
+        Self {
+            sequence,
+            frozen_sequence: 0,
+            consensus_state,
+            allow_update_after_proposal,
+        }
+    }
+
+    /// Returns the client state frozen at the given sequence.
+    pub fn with_frozen_sequence(self, frozen_sequence: u64) -> Self {
+        Self {
+            frozen_sequence,
+            ..self
+        }
+    }
+}
+
+impl crate::ics02_client::state::ClientState for ClientState {
+    fn chain_id(&self) -> String {
+        // Solo machines are not chains.
+        String::new()
+    }
+
+    fn client_type(&self) -> ClientType {
+        ClientType::SoloMachine
+    }
+
+    fn latest_height(&self) -> Height {
+        Height::new(0, self.sequence)
+    }
+
+    fn is_frozen(&self) -> bool {
+        self.frozen_sequence != 0
+    }
+
+    fn wrap_any(self) -> AnyClientState {
+        AnyClientState::SoloMachine(self)
+    }
+}
+
+impl Protobuf<RawClientState> for ClientState {}
+
+impl TryFrom<RawClientState> for ClientState {
+    type Error = Error;
+
+    fn try_from(raw: RawClientState) -> Result<Self, Self::Error> {
+        Ok(Self {
+            sequence: raw.sequence,
+            frozen_sequence: raw.frozen_sequence,
+            consensus_state: raw
+                .consensus_state
+                .ok_or_else(|| Kind::InvalidRawClientState.context("missing consensus state"))?
+                .try_into()?,
+            allow_update_after_proposal: raw.allow_update_after_proposal,
+        })
+    }
+}
+
+impl From<ClientState> for RawClientState {
+    fn from(value: ClientState) -> Self {
+        RawClientState {
+            sequence: value.sequence,
+            frozen_sequence: value.frozen_sequence,
+            consensus_state: Some(value.consensus_state.into()),
+            allow_update_after_proposal: value.allow_update_after_proposal,
+        }
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+pub mod test_util {
+    use k256::ecdsa::SigningKey;
+
+    use crate::ics06_solomachine::client_state::ClientState;
+    use crate::ics06_solomachine::consensus_state::ConsensusState;
+    use crate::ics06_solomachine::public_key::PublicKey;
+
+    pub const DUMMY_DIVERSIFIER: &str = "diversifier";
+
+    /// Returns the Secp256k1 key with which the dummy solo machine signs.
+    pub fn get_dummy_solomachine_signing_key() -> SigningKey {
+        SigningKey::from_bytes(&[1; 32]).unwrap()
+    }
+
+    /// Returns the state of a client tracking the dummy solo machine at the given sequence.
+    pub fn get_dummy_solomachine_client_state(sequence: u64) -> ClientState {
+        let public_key = PublicKey::Secp256k1(
+            get_dummy_solomachine_signing_key()
+                .verify_key()
+                .to_bytes()
+                .to_vec(),
+        );
+        let consensus_state = ConsensusState::new(public_key, DUMMY_DIVERSIFIER.to_string(), 1);
+
+        ClientState::new(sequence, consensus_state, false)
+    }
+}
@@ -0,0 +1,123 @@
+use std::convert::{TryFrom, TryInto};
+
+use serde::Serialize;
+use tendermint_proto::Protobuf;
+
+use ibc_proto::ibc::lightclients::solomachine::v1::ConsensusState as RawConsensusState;
+
+use crate::ics02_client::{client_def::AnyConsensusState, client_type::ClientType};
+use crate::ics06_solomachine::error::{Error, Kind};
+use crate::ics06_solomachine::public_key::PublicKey;
+use crate::ics23_commitment::commitment::CommitmentRoot;
+use crate::timestamp::Timestamp;
+
+/// The consensus state of a solo machine: the public key with which the solo machine currently
+/// signs, the diversifier mixed into its signatures, and the timestamp of its latest signature.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct ConsensusState {
+    pub public_key: PublicKey,
+    pub diversifier: String,
+    pub timestamp: u64,
+
+    /// Solo machines do not commit to their state, their proofs are signatures. The root is always
+    /// empty.
+    #[serde(skip)]
+    root: CommitmentRoot,
+}
+
+impl ConsensusState {
+    pub fn new(public_key: PublicKey, diversifier: String, timestamp: u64) -> Self {
+        Self {
+            public_key,
+            diversifier,
+            timestamp,
+            root: CommitmentRoot::from_bytes(&[]),
+        }
+    }
+}
+
+impl crate::ics02_client::state::ConsensusState for ConsensusState {
+    fn client_type(&self) -> ClientType {
+        ClientType::SoloMachine
+    }
+
+    fn root(&self) -> &CommitmentRoot {
+        &self.root
+    }
+
+    /// The timestamp of a solo machine is a number of nanoseconds since the Unix epoch.
+    fn timestamp(&self) -> Timestamp {
+        Timestamp::from_nanoseconds(self.timestamp).unwrap_or_else(|_| Timestamp::none())
+    }
+
+    fn validate_basic(&self) -> Result<(), Box<dyn std::error::Error>> {
+        if self.public_key.as_bytes().is_empty() {
+            return Err(Kind::InvalidConsensusState
+                .context("empty public key")
+                .into());
+        }
+        if self.diversifier.trim().is_empty() {
+            return Err(Kind::InvalidConsensusState
+                .context("empty diversifier")
+                .into());
+        }
+        if self.timestamp == 0 {
+            return Err(Kind::InvalidConsensusState.context("zero timestamp").into());
+        }
+        Ok(())
+    }
+
+    fn wrap_any(self) -> AnyConsensusState {
+        AnyConsensusState::SoloMachine(self)
+    }
+}
+
+impl Protobuf<RawConsensusState> for ConsensusState {}
+
+impl TryFrom<RawConsensusState> for ConsensusState {
+    type Error = Error;
+
+    fn try_from(raw: RawConsensusState) -> Result<Self, Self::Error> {
+        let public_key = raw
+            .public_key
+            .ok_or_else(|| Kind::InvalidRawConsensusState.context("missing public key"))?
+            .try_into()?;
+
+        Ok(Self::new(public_key, raw.diversifier, raw.timestamp))
+    }
+}
+
+impl From<ConsensusState> for RawConsensusState {
+    fn from(value: ConsensusState) -> Self {
+        RawConsensusState {
+            public_key: Some(value.public_key.into()),
+            diversifier: value.diversifier,
+            timestamp: value.timestamp,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ics02_client::state::ConsensusState as ICS2ConsensusState;
+    use crate::ics06_solomachine::client_state::test_util::get_dummy_solomachine_client_state;
+    use crate::ics06_solomachine::public_key::PublicKey;
+
+    #[test]
+    fn validate_basic() {
+        let consensus_state = get_dummy_solomachine_client_state(1).consensus_state;
+        assert!(consensus_state.validate_basic().is_ok());
+
+        let mut empty_public_key = consensus_state.clone();
+        empty_public_key.public_key = PublicKey::Secp256k1(vec![]);
+        assert!(empty_public_key.validate_basic().is_err());
+
+        let mut empty_diversifier = consensus_state.clone();
+        empty_diversifier.diversifier = " ".to_string();
+        assert!(empty_diversifier.validate_basic().is_err());
+
+        let mut zero_timestamp = consensus_state;
+        zero_timestamp.timestamp = 0;
+        assert!(zero_timestamp.validate_basic().is_err());
+    }
+}
@@ -0,0 +1,69 @@
+use anomaly::{BoxError, Context};
+use thiserror::Error;
+
+use crate::Height;
+
+pub type Error = anomaly::Error<Kind>;
+
+#[derive(Clone, Debug, Error)]
+pub enum Kind {
+    #[error("invalid raw client state")]
+    InvalidRawClientState,
+
+    #[error("invalid raw client consensus state")]
+    InvalidRawConsensusState,
+
+    #[error("invalid consensus state")]
+    InvalidConsensusState,
+
+    #[error("invalid raw header")]
+    InvalidRawHeader,
+
+    #[error("invalid raw misbehaviour")]
+    InvalidRawMisbehaviour,
+
+    #[error("invalid public key")]
+    InvalidPublicKey,
+
+    #[error("unknown public key type: {0}")]
+    UnknownPublicKeyType(String),
+
+    #[error("invalid signature")]
+    InvalidSignature,
+
+    #[error("multi-signatures are not supported")]
+    MultiSignatureNotSupported,
+
+    #[error("signature verification failed")]
+    SignatureVerificationFailure,
+
+    #[error("the client is frozen")]
+    FrozenClient,
+
+    #[error("sequence ({0}) does not match the client sequence ({1})")]
+    MismatchedSequence(u64, u64),
+
+    #[error("timestamp ({0}) is lower than the timestamp of the client consensus state ({1})")]
+    LowTimestamp(u64, u64),
+
+    #[error("proof height ({0}) is past the client latest height ({1})")]
+    InvalidProofHeight(Height, Height),
+
+    #[error("empty proof")]
+    EmptyProof,
+
+    #[error("invalid proof")]
+    InvalidProof,
+
+    #[error("invalid misbehaviour evidence")]
+    InvalidMisbehaviour,
+
+    #[error("solo machine clients cannot be upgraded")]
+    UpgradeNotSupported,
+}
+
+impl Kind {
+    pub fn context(self, source: impl Into<BoxError>) -> Context<Self> {
+        Context::new(self, Some(source.into()))
+    }
+}
@@ -0,0 +1,71 @@
+use std::convert::{TryFrom, TryInto};
+
+use tendermint_proto::Protobuf;
+
+use ibc_proto::ibc::lightclients::solomachine::v1::Header as RawHeader;
+
+use crate::ics02_client::{client_def::AnyHeader, client_type::ClientType};
+use crate::ics06_solomachine::error::{Error, Kind};
+use crate::ics06_solomachine::public_key::PublicKey;
+use crate::Height;
+
+/// A header of a solo machine, signed with its current key at its current sequence, which rotates
+/// the public key and diversifier of the solo machine.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Header {
+    pub sequence: u64,
+    pub timestamp: u64,
+    /// The signature, encoded as a `SignatureDescriptor.Data` protobuf message.
+    pub signature: Vec<u8>,
+    pub new_public_key: PublicKey,
+    pub new_diversifier: String,
+}
+
+impl crate::ics02_client::header::Header for Header {
+    fn client_type(&self) -> ClientType {
+        ClientType::SoloMachine
+    }
+
+    fn height(&self) -> Height {
+        Height::new(0, self.sequence)
+    }
+
+    fn wrap_any(self) -> AnyHeader {
+        AnyHeader::SoloMachine(self)
+    }
+}
+
+impl Protobuf<RawHeader> for Header {}
+
+impl TryFrom<RawHeader> for Header {
+    type Error = Error;
+
+    fn try_from(raw: RawHeader) -> Result<Self, Self::Error> {
+        if raw.signature.is_empty() {
+            return Err(Kind::InvalidRawHeader.context("empty signature").into());
+        }
+
+        Ok(Self {
+            sequence: raw.sequence,
+            timestamp: raw.timestamp,
+            signature: raw.signature,
+            new_public_key: raw
+                .new_public_key
+                .ok_or_else(|| Kind::InvalidRawHeader.context("missing new public key"))?
+                .try_into()?,
+            new_diversifier: raw.new_diversifier,
+        })
+    }
+}
+
+impl From<Header> for RawHeader {
+    fn from(value: Header) -> Self {
+        RawHeader {
+            sequence: value.sequence,
+            timestamp: value.timestamp,
+            signature: value.signature,
+            new_public_key: Some(value.new_public_key.into()),
+            new_diversifier: value.new_diversifier,
+        }
+    }
+}
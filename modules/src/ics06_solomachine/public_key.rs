@@ -0,0 +1,96 @@
+use std::convert::TryFrom;
+
+use k256::ecdsa::signature::Verifier;
+use prost::Message;
+use prost_types::Any;
+use serde::Serialize;
+use tendermint_proto::Protobuf;
+
+use crate::ics06_solomachine::error::{Error, Kind};
+
+pub const ED25519_PUBLIC_KEY_TYPE_URL: &str = "/cosmos.crypto.ed25519.PubKey";
+pub const SECP256K1_PUBLIC_KEY_TYPE_URL: &str = "/cosmos.crypto.secp256k1.PubKey";
+
+/// The public key with which a solo machine signs, encoded as in the Cosmos SDK: Ed25519 keys are
+/// raw 32-byte keys, Secp256k1 keys are 33-byte compressed SEC1 points.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub enum PublicKey {
+    Ed25519(Vec<u8>),
+    Secp256k1(Vec<u8>),
+}
+
+impl PublicKey {
+    pub fn as_bytes(&self) -> &[u8] {
+        match self {
+            Self::Ed25519(key) | Self::Secp256k1(key) => key,
+        }
+    }
+
+    /// Verifies that `signature` is a signature of `msg` by this key. Secp256k1 signatures are
+    /// 64-byte `r || s` ECDSA signatures over the SHA-256 digest of `msg`.
+    pub fn verify(&self, msg: &[u8], signature: &[u8]) -> Result<(), Error> {
+        match self {
+            Self::Ed25519(key) => {
+                let key =
+                    tendermint::PublicKey::from_raw_ed25519(key).ok_or(Kind::InvalidPublicKey)?;
+                let signature = tendermint::signature::Ed25519Signature::try_from(signature)
+                    .map_err(|e| Kind::InvalidSignature.context(e))?;
+
+                key.verify(msg, &tendermint::Signature::Ed25519(signature))
+                    .map_err(|e| Kind::SignatureVerificationFailure.context(e).into())
+            }
+            Self::Secp256k1(key) => {
+                let key = k256::ecdsa::VerifyingKey::from_sec1_bytes(key)
+                    .map_err(|e| Kind::InvalidPublicKey.context(e))?;
+                let signature = k256::ecdsa::Signature::try_from(signature)
+                    .map_err(|e| Kind::InvalidSignature.context(e))?;
+
+                key.verify(msg, &signature)
+                    .map_err(|e| Kind::SignatureVerificationFailure.context(e).into())
+            }
+        }
+    }
+}
+
+impl Protobuf<Any> for PublicKey {}
+
+impl TryFrom<Any> for PublicKey {
+    type Error = Error;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        // Both `PubKey` messages of the Cosmos SDK consist of a single `bytes key = 1` field.
+        let key = Vec::<u8>::decode(raw.value.as_slice())
+            .map_err(|e| Kind::InvalidPublicKey.context(e))?;
+
+        match raw.type_url.as_str() {
+            ED25519_PUBLIC_KEY_TYPE_URL => {
+                tendermint::PublicKey::from_raw_ed25519(&key).ok_or(Kind::InvalidPublicKey)?;
+                Ok(Self::Ed25519(key))
+            }
+            SECP256K1_PUBLIC_KEY_TYPE_URL => {
+                k256::ecdsa::VerifyingKey::from_sec1_bytes(&key)
+                    .map_err(|e| Kind::InvalidPublicKey.context(e))?;
+                Ok(Self::Secp256k1(key))
+            }
+            _ => Err(Kind::UnknownPublicKeyType(raw.type_url).into()),
+        }
+    }
+}
+
+impl From<PublicKey> for Any {
+    fn from(value: PublicKey) -> Self {
+        let type_url = match value {
+            PublicKey::Ed25519(_) => ED25519_PUBLIC_KEY_TYPE_URL,
+            PublicKey::Secp256k1(_) => SECP256K1_PUBLIC_KEY_TYPE_URL,
+        };
+
+        let mut buf = Vec::new();
+        // Encoding into a vector cannot fail.
+        value.as_bytes().to_vec().encode(&mut buf).unwrap();
+
+        Any {
+            type_url: type_url.to_string(),
+            value: buf,
+        }
+    }
+}
@@ -1,25 +1,50 @@
+use std::convert::TryFrom;
+
+use tendermint::Time;
+use tendermint_light_client::operations::{
+    Hasher, ProdCommitValidator, ProdHasher, ProdVotingPowerCalculator,
+};
+use tendermint_light_client::predicates::{ProdPredicates, VerificationPredicates};
+use tendermint_proto::Protobuf;
+
 use crate::ics02_client::client_def::{AnyClientState, AnyConsensusState, ClientDef};
+use crate::ics02_client::context::ClientReader;
 use crate::ics02_client::header::Header as ICS2Header;
 use crate::ics03_connection::connection::ConnectionEnd;
+use crate::ics03_connection::context::ConnectionReader;
 use crate::ics04_channel::channel::ChannelEnd;
+use crate::ics04_channel::commitment::{compute_ack_commitment, PacketCommitment};
+use crate::ics04_channel::context::ChannelReader;
+use crate::ics04_channel::packet::Sequence;
 use crate::ics07_tendermint::client_state::ClientState;
 use crate::ics07_tendermint::consensus_state::ConsensusState;
+use crate::ics07_tendermint::error::{Error, Kind};
 use crate::ics07_tendermint::header::Header;
+use crate::ics07_tendermint::misbehaviour::Misbehaviour;
 use crate::ics23_commitment::commitment::{CommitmentPrefix, CommitmentProofBytes, CommitmentRoot};
+use crate::ics23_commitment::merkle::{apply_prefix, cosmos_specs, MerkleProof};
 use crate::ics24_host::identifier::ConnectionId;
 use crate::ics24_host::identifier::{ChannelId, ClientId, PortId};
+use crate::ics24_host::{Path, UPGRADED_CLIENT_CONSENSUS_STATE, UPGRADED_CLIENT_STATE};
 use crate::Height;
 
+/// The root of the consensus state with which a client resumes after an upgrade of its chain, as
+/// set by the Cosmos SDK.
+pub const SENTINEL_ROOT: &str = "sentinel_root";
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct TendermintClient;
 
 impl ClientDef for TendermintClient {
     type Header = Header;
+    type Misbehaviour = Misbehaviour;
     type ClientState = ClientState;
     type ConsensusState = ConsensusState;
 
     fn check_header_and_update_state(
         &self,
+        ctx: &dyn ClientReader,
+        client_id: ClientId,
         client_state: Self::ClientState,
         header: Self::Header,
     ) -> Result<(Self::ClientState, Self::ConsensusState), Box<dyn std::error::Error>> {
@@ -30,7 +55,42 @@ impl ClientDef for TendermintClient {
             );
         }
 
-        // TODO: Additional verifications should be implemented here.
+        // The header must come from the chain (and revision) that this client is tracking.
+        let header_chain_id = header.signed_header.header.chain_id.as_str();
+        if header_chain_id != client_state.chain_id {
+            return Err(Kind::MismatchedChainId(
+                header_chain_id.to_string(),
+                client_state.chain_id.clone(),
+            )
+            .into());
+        }
+
+        if header.height().revision_number != client_state.latest_height().revision_number {
+            return Err(Kind::MismatchedRevisions(
+                header.height().revision_number,
+                client_state.latest_height().revision_number,
+            )
+            .into());
+        }
+
+        if header.trusted_height.revision_number != header.height().revision_number
+            || header.trusted_height >= header.height()
+        {
+            return Err(Kind::InvalidTrustedHeight(header.trusted_height, header.height()).into());
+        }
+
+        // The header is verified against the consensus state at the trusted height.
+        let trusted_consensus_state = match ctx.consensus_state(&client_id, header.trusted_height) {
+            Some(AnyConsensusState::Tendermint(consensus_state)) => consensus_state,
+            _ => return Err(Kind::TrustedConsensusStateNotFound(header.trusted_height).into()),
+        };
+
+        verify_header(
+            &client_state,
+            &trusted_consensus_state,
+            &header,
+            ctx.host_timestamp(),
+        )?;
 
         Ok((
             client_state.with_header(header.clone()),
@@ -38,54 +98,1392 @@ impl ClientDef for TendermintClient {
         ))
     }
 
+    fn check_misbehaviour_and_update_state(
+        &self,
+        ctx: &dyn ClientReader,
+        client_id: ClientId,
+        client_state: Self::ClientState,
+        misbehaviour: Self::Misbehaviour,
+    ) -> Result<Self::ClientState, Box<dyn std::error::Error>> {
+        let (header1, header2) = (&misbehaviour.header1, &misbehaviour.header2);
+
+        // Both headers must come from the chain that this client is tracking.
+        for header in [header1, header2].iter() {
+            let header_chain_id = header.signed_header.header.chain_id.as_str();
+            if header_chain_id != client_state.chain_id {
+                return Err(Kind::MismatchedChainId(
+                    header_chain_id.to_string(),
+                    client_state.chain_id.clone(),
+                )
+                .into());
+            }
+        }
+
+        // By convention, the first header is the one at the greater (or equal) height.
+        if header1.height() < header2.height() {
+            return Err(Kind::InvalidMisbehaviour
+                .context("header1 height is lower than header2 height")
+                .into());
+        }
+
+        // The headers must conflict: either two different blocks at the same height, or a later
+        // block with a timestamp that is not after the timestamp of an earlier one.
+        if header1.height() == header2.height() {
+            if header1.signed_header.commit.block_id.hash
+                == header2.signed_header.commit.block_id.hash
+            {
+                return Err(Kind::InvalidMisbehaviour
+                    .context("headers at the same height commit to the same block")
+                    .into());
+            }
+        } else if header1.signed_header.header.time > header2.signed_header.header.time {
+            return Err(Kind::InvalidMisbehaviour
+                .context("headers do not violate BFT time monotonicity")
+                .into());
+        }
+
+        // Each header must be trusted by the client, i.e., signed by enough of the validators of
+        // the consensus state at its trusted height.
+        for header in [header1, header2].iter() {
+            let trusted_consensus_state = match ctx
+                .consensus_state(&client_id, header.trusted_height)
+            {
+                Some(AnyConsensusState::Tendermint(consensus_state)) => consensus_state,
+                _ => return Err(Kind::TrustedConsensusStateNotFound(header.trusted_height).into()),
+            };
+
+            verify_misbehaviour_header(
+                &client_state,
+                &trusted_consensus_state,
+                header,
+                ctx.host_timestamp(),
+            )?;
+        }
+
+        Ok(client_state.with_frozen_height(header1.height()))
+    }
+
+    fn verify_upgrade_and_update_state(
+        &self,
+        ctx: &dyn ClientReader,
+        client_id: ClientId,
+        client_state: Self::ClientState,
+        upgraded_client_state: Self::ClientState,
+        upgraded_consensus_state: Self::ConsensusState,
+        proof_upgrade_client: &CommitmentProofBytes,
+        proof_upgrade_consensus_state: &CommitmentProofBytes,
+    ) -> Result<(Self::ClientState, Self::ConsensusState), Box<dyn std::error::Error>> {
+        let latest_height = client_state.latest_height();
+        if upgraded_client_state.latest_height() <= latest_height {
+            return Err(Kind::LowUpgradeHeight(
+                upgraded_client_state.latest_height(),
+                latest_height,
+            )
+            .into());
+        }
+
+        // The proofs are verified against the consensus state at the latest height of the client,
+        // which must still be trusted.
+        let consensus_state = match ctx.consensus_state(&client_id, latest_height) {
+            Some(AnyConsensusState::Tendermint(consensus_state)) => consensus_state,
+            _ => return Err(Kind::TrustedConsensusStateNotFound(latest_height).into()),
+        };
+        if consensus_state.timestamp + client_state.trusting_period <= ctx.host_timestamp() {
+            return Err(Kind::TrustedConsensusStateExpired.into());
+        }
+
+        // The chain commits to the upgraded client state without the relayer-chosen fields.
+        let client_path = client_state
+            .upgrade_merkle_path(latest_height.revision_height, UPGRADED_CLIENT_STATE)?;
+        let committed_client_state =
+            AnyClientState::Tendermint(upgraded_client_state.clone().zero_custom_fields());
+        MerkleProof::try_from(proof_upgrade_client)?
+            .verify_membership(
+                &cosmos_specs(),
+                &consensus_state.root,
+                client_path,
+                committed_client_state.encode_vec()?,
+            )
+            .map_err(|e| Kind::UpgradeVerificationFailure.context(e))?;
+
+        let consensus_path = client_state.upgrade_merkle_path(
+            latest_height.revision_height,
+            UPGRADED_CLIENT_CONSENSUS_STATE,
+        )?;
+        let committed_consensus_state =
+            AnyConsensusState::Tendermint(upgraded_consensus_state.clone());
+        MerkleProof::try_from(proof_upgrade_consensus_state)?
+            .verify_membership(
+                &cosmos_specs(),
+                &consensus_state.root,
+                consensus_path,
+                committed_consensus_state.encode_vec()?,
+            )
+            .map_err(|e| Kind::UpgradeVerificationFailure.context(e))?;
+
+        // The parameters chosen by the chain are taken from the upgraded client state, while
+        // those chosen by the relayer are kept from the current client state.
+        let new_client_state = ClientState::new(
+            upgraded_client_state.chain_id,
+            client_state.trust_level,
+            client_state.trusting_period,
+            upgraded_client_state.unbonding_period,
+            client_state.max_clock_drift,
+            upgraded_client_state.latest_height,
+            Height::zero(),
+            upgraded_client_state.upgrade_path,
+            client_state.allow_update_after_expiry,
+            client_state.allow_update_after_misbehaviour,
+        )?;
+
+        // The upgraded chain has not produced any block yet, so there is no root to commit to. The
+        // sentinel root is replaced by the first update of the client after the upgrade.
+        let new_consensus_state = ConsensusState::new(
+            CommitmentRoot::from(SENTINEL_ROOT.as_bytes().to_vec()),
+            upgraded_consensus_state.timestamp,
+            upgraded_consensus_state.next_validators_hash,
+        );
+
+        Ok((new_client_state, new_consensus_state))
+    }
+
     fn verify_client_consensus_state(
         &self,
-        _client_state: &Self::ClientState,
-        _height: Height,
-        _prefix: &CommitmentPrefix,
-        _proof: &CommitmentProofBytes,
-        _client_id: &ClientId,
-        _consensus_height: Height,
-        _expected_consensus_state: &AnyConsensusState,
-    ) -> Result<(), Box<dyn std::error::Error>> {
-        todo!()
+        _ctx: &dyn ConnectionReader,
+        client_state: &Self::ClientState,
+        height: Height,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        client_id: &ClientId,
+        consensus_height: Height,
+        expected_consensus_state: &AnyConsensusState,
+    ) -> Result<Option<Self::ClientState>, Box<dyn std::error::Error>> {
+        let path = Path::ClientConsensusState {
+            client_id: client_id.clone(),
+            epoch: consensus_height.revision_number,
+            height: consensus_height.revision_height,
+        };
+        let value = expected_consensus_state.encode_vec()?;
+
+        verify_membership(client_state, height, prefix, proof, root, path, value)?;
+
+        Ok(None)
     }
 
     fn verify_connection_state(
         &self,
-        _client_state: &Self::ClientState,
-        _height: Height,
-        _prefix: &CommitmentPrefix,
-        _proof: &CommitmentProofBytes,
-        _connection_id: &ConnectionId,
-        _expected_connection_end: &ConnectionEnd,
-    ) -> Result<(), Box<dyn std::error::Error>> {
-        todo!()
+        _ctx: &dyn ConnectionReader,
+        client_state: &Self::ClientState,
+        height: Height,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        connection_id: &ConnectionId,
+        expected_connection_end: &ConnectionEnd,
+    ) -> Result<Option<Self::ClientState>, Box<dyn std::error::Error>> {
+        let path = Path::Connections(connection_id.clone());
+        let value = expected_connection_end.encode_vec()?;
+
+        verify_membership(client_state, height, prefix, proof, root, path, value)?;
+
+        Ok(None)
     }
 
     fn verify_channel_state(
         &self,
-        _client_state: &Self::ClientState,
-        _height: Height,
-        _prefix: &CommitmentPrefix,
-        _proof: &CommitmentProofBytes,
-        _port_id: &PortId,
-        _channel_id: &ChannelId,
-        _expected_channel_end: &ChannelEnd,
-    ) -> Result<(), Box<dyn std::error::Error>> {
-        todo!()
+        _ctx: &dyn ChannelReader,
+        client_state: &Self::ClientState,
+        height: Height,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        expected_channel_end: &ChannelEnd,
+    ) -> Result<Option<Self::ClientState>, Box<dyn std::error::Error>> {
+        let path = Path::ChannelEnds(port_id.clone(), channel_id.clone());
+        let value = expected_channel_end.encode_vec()?;
+
+        verify_membership(client_state, height, prefix, proof, root, path, value)?;
+
+        Ok(None)
     }
 
     fn verify_client_full_state(
         &self,
-        _client_state: &Self::ClientState,
-        _height: Height,
-        _root: &CommitmentRoot,
-        _prefix: &CommitmentPrefix,
-        _client_id: &ClientId,
-        _proof: &CommitmentProofBytes,
-        _expected_client_state: &AnyClientState,
-    ) -> Result<(), Box<dyn std::error::Error>> {
-        unimplemented!()
+        _ctx: &dyn ConnectionReader,
+        client_state: &Self::ClientState,
+        height: Height,
+        root: &CommitmentRoot,
+        prefix: &CommitmentPrefix,
+        client_id: &ClientId,
+        proof: &CommitmentProofBytes,
+        expected_client_state: &AnyClientState,
+    ) -> Result<Option<Self::ClientState>, Box<dyn std::error::Error>> {
+        let path = Path::ClientState(client_id.clone());
+        let value = expected_client_state.encode_vec()?;
+
+        verify_membership(client_state, height, prefix, proof, root, path, value)?;
+
+        Ok(None)
+    }
+
+    fn verify_packet_data(
+        &self,
+        _ctx: &dyn ChannelReader,
+        client_state: &Self::ClientState,
+        height: Height,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        seq: &Sequence,
+        commitment: PacketCommitment,
+    ) -> Result<Option<Self::ClientState>, Box<dyn std::error::Error>> {
+        let path = Path::Commitments {
+            port_id: port_id.clone(),
+            channel_id: channel_id.clone(),
+            sequence: *seq,
+        };
+
+        verify_membership(
+            client_state,
+            height,
+            prefix,
+            proof,
+            root,
+            path,
+            commitment.into_vec(),
+        )?;
+
+        Ok(None)
+    }
+
+    fn verify_packet_acknowledgement(
+        &self,
+        _ctx: &dyn ChannelReader,
+        client_state: &Self::ClientState,
+        height: Height,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        seq: &Sequence,
+        ack: Vec<u8>,
+    ) -> Result<Option<Self::ClientState>, Box<dyn std::error::Error>> {
+        let path = Path::Acks {
+            port_id: port_id.clone(),
+            channel_id: channel_id.clone(),
+            sequence: *seq,
+        };
+        // The counterparty chain stores the commitment of the acknowledgement, not the raw bytes.
+        let value = compute_ack_commitment(&ack).into_vec();
+
+        verify_membership(client_state, height, prefix, proof, root, path, value)?;
+
+        Ok(None)
+    }
+
+    fn verify_next_sequence_recv(
+        &self,
+        _ctx: &dyn ChannelReader,
+        client_state: &Self::ClientState,
+        height: Height,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        seq: &Sequence,
+    ) -> Result<Option<Self::ClientState>, Box<dyn std::error::Error>> {
+        let path = Path::SeqRecvs(port_id.clone(), channel_id.clone());
+        let value = u64::from(*seq).to_be_bytes().to_vec();
+
+        verify_membership(client_state, height, prefix, proof, root, path, value)?;
+
+        Ok(None)
+    }
+
+    fn verify_packet_receipt_absence(
+        &self,
+        _ctx: &dyn ChannelReader,
+        client_state: &Self::ClientState,
+        height: Height,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        seq: &Sequence,
+    ) -> Result<Option<Self::ClientState>, Box<dyn std::error::Error>> {
+        let path = Path::Receipts {
+            port_id: port_id.clone(),
+            channel_id: channel_id.clone(),
+            sequence: *seq,
+        };
+
+        verify_non_membership(client_state, height, prefix, proof, root, path)?;
+
+        Ok(None)
+    }
+}
+
+/// Verifies the `header` against the `trusted` consensus state, following the Tendermint light
+/// client verification rules: the trusted state must be within the trusting period, the header
+/// must not be from the future (up to the client's clock drift), the validator sets must match
+/// their hashes, and the header must be signed by enough voting power of both the trusted and the
+/// new validator sets.
+fn verify_header(
+    client_state: &ClientState,
+    trusted: &ConsensusState,
+    header: &Header,
+    now: Time,
+) -> Result<(), Error> {
+    let predicates = ProdPredicates;
+    let signed_header = &header.signed_header;
+
+    verify_signed_header(client_state, trusted, header, now)?;
+
+    predicates
+        .is_header_from_past(&signed_header.header, client_state.max_clock_drift, now)
+        .map_err(|e| Kind::HeaderVerificationFailure.context(e))?;
+
+    if signed_header.header.time <= trusted.timestamp {
+        return Err(Kind::HeaderVerificationFailure
+            .context("header time is not after the trusted consensus state time")
+            .into());
+    }
+
+    if header.height() == header.trusted_height.increment() {
+        // For adjacent headers, the validator set must be the one announced by the trusted state.
+        if signed_header.header.validators_hash != trusted.next_validators_hash {
+            return Err(Kind::InvalidValidatorSet.into());
+        }
+    } else {
+        // Otherwise, enough of the trusted validators must have signed the header.
+        verify_trusted_overlap(client_state, header)?;
+    }
+
+    Ok(())
+}
+
+/// Verifies one of the headers of a misbehaviour against the `trusted` consensus state. Unlike
+/// `verify_header`, this neither requires the header to be newer than the trusted state nor
+/// assumes that adjacent headers are honest: the header must always be signed by enough of the
+/// trusted validators.
+fn verify_misbehaviour_header(
+    client_state: &ClientState,
+    trusted: &ConsensusState,
+    header: &Header,
+    now: Time,
+) -> Result<(), Error> {
+    verify_signed_header(client_state, trusted, header, now)?;
+    verify_trusted_overlap(client_state, header)
+}
+
+/// The verification steps common to header updates and misbehaviour: the trusted state must be
+/// within the trusting period, the validator sets must match their hashes, and the commit must be
+/// valid and signed by enough voting power of the header's own validator set.
+fn verify_signed_header(
+    client_state: &ClientState,
+    trusted: &ConsensusState,
+    header: &Header,
+    now: Time,
+) -> Result<(), Error> {
+    let predicates = ProdPredicates;
+    let hasher = ProdHasher;
+    let signed_header = &header.signed_header;
+
+    if trusted.timestamp + client_state.trusting_period <= now {
+        return Err(Kind::TrustedConsensusStateExpired.into());
+    }
+
+    if hasher.hash_validator_set(&header.validator_set) != signed_header.header.validators_hash {
+        return Err(Kind::InvalidValidatorSet.into());
+    }
+
+    if hasher.hash_validator_set(&header.trusted_validator_set) != trusted.next_validators_hash {
+        return Err(Kind::InvalidTrustedValidatorSet.into());
+    }
+
+    predicates
+        .header_matches_commit(signed_header, &hasher)
+        .map_err(|e| Kind::HeaderVerificationFailure.context(e))?;
+
+    predicates
+        .valid_commit(
+            signed_header,
+            &header.validator_set,
+            &ProdCommitValidator::default(),
+        )
+        .map_err(|e| Kind::HeaderVerificationFailure.context(e))?;
+
+    predicates
+        .has_sufficient_signers_overlap(
+            signed_header,
+            &header.validator_set,
+            &ProdVotingPowerCalculator,
+        )
+        .map_err(|e| Kind::HeaderVerificationFailure.context(e))?;
+
+    Ok(())
+}
+
+/// Checks that the validators trusted by the client hold at least the client's trust level of
+/// the voting power that signed the `header`.
+fn verify_trusted_overlap(client_state: &ClientState, header: &Header) -> Result<(), Error> {
+    ProdPredicates
+        .has_sufficient_validators_overlap(
+            &header.signed_header,
+            &header.trusted_validator_set,
+            &client_state.trust_level,
+            &ProdVotingPowerCalculator,
+        )
+        .map_err(|e| Kind::HeaderVerificationFailure.context(e))?;
+
+    Ok(())
+}
+
+/// Checks that the client can be used to verify a proof at the given `height`: the client must
+/// have a consensus state at this height, and must not be frozen.
+fn verify_height(
+    client_state: &ClientState,
+    height: Height,
+) -> Result<(), Box<dyn std::error::Error>> {
+    if client_state.latest_height() < height {
+        return Err(format!(
+            "client latest height ({}) is lower than the proof height ({})",
+            client_state.latest_height(),
+            height
+        )
+        .into());
+    }
+
+    if !client_state.frozen_height.is_zero() {
+        return Err(format!("client was frozen at height {}", client_state.frozen_height).into());
+    }
+
+    Ok(())
+}
+
+/// Verifies that `value` is stored under `path` (in the store denoted by `prefix`) in the state
+/// of the counterparty chain committed to by `root`.
+fn verify_membership(
+    client_state: &ClientState,
+    height: Height,
+    prefix: &CommitmentPrefix,
+    proof: &CommitmentProofBytes,
+    root: &CommitmentRoot,
+    path: Path,
+    value: Vec<u8>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    verify_height(client_state, height)?;
+
+    let merkle_path = apply_prefix(prefix, vec![path.to_string()])?;
+    let merkle_proof = MerkleProof::try_from(proof)?;
+
+    merkle_proof.verify_membership(&cosmos_specs(), root, merkle_path, value)?;
+    Ok(())
+}
+
+/// Verifies that nothing is stored under `path` (in the store denoted by `prefix`) in the state of
+/// the counterparty chain committed to by `root`.
+fn verify_non_membership(
+    client_state: &ClientState,
+    height: Height,
+    prefix: &CommitmentPrefix,
+    proof: &CommitmentProofBytes,
+    root: &CommitmentRoot,
+    path: Path,
+) -> Result<(), Box<dyn std::error::Error>> {
+    verify_height(client_state, height)?;
+
+    let merkle_path = apply_prefix(prefix, vec![path.to_string()])?;
+    let merkle_proof = MerkleProof::try_from(proof)?;
+
+    merkle_proof.verify_non_membership(&cosmos_specs(), root, merkle_path)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use tendermint_proto::Protobuf;
+
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    use tendermint_testgen::{
+        Commit as TestgenCommit, Generator, Header as TestgenHeader,
+        LightBlock as TestgenLightBlock, Validator as TestgenValidator,
+    };
+
+    use crate::ics02_client::client_def::{AnyClientState, AnyConsensusState, ClientDef};
+    use crate::ics02_client::client_type::ClientType;
+    use crate::ics02_client::context::{ClientKeeper, ClientReader};
+    use crate::ics02_client::header::Header as ICS2Header;
+    use crate::ics03_connection::connection::{
+        ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
+    };
+    use crate::ics03_connection::version::get_compatible_versions;
+    use crate::ics04_channel::channel::{ChannelEnd, Counterparty, Order, State};
+    use crate::ics04_channel::commitment::compute_packet_commitment;
+    use crate::ics04_channel::packet::Sequence;
+    use crate::ics07_tendermint::client_def::{TendermintClient, SENTINEL_ROOT};
+    use crate::ics07_tendermint::client_state::test_util::get_dummy_tendermint_client_state;
+    use crate::ics07_tendermint::client_state::ClientState;
+    use crate::ics07_tendermint::consensus_state::ConsensusState;
+    use crate::ics07_tendermint::header::test_util::get_dummy_tendermint_header;
+    use crate::ics07_tendermint::header::Header;
+    use crate::ics07_tendermint::misbehaviour::Misbehaviour;
+    use crate::ics23_commitment::commitment::{
+        CommitmentPrefix, CommitmentProofBytes, CommitmentRoot,
+    };
+    use crate::ics23_commitment::merkle::test_util::DummyStore;
+    use crate::ics24_host::identifier::{ChainId, ChannelId, ClientId, ConnectionId, PortId};
+    use crate::ics24_host::{
+        Path, UPGRADED_CLIENT_CONSENSUS_STATE, UPGRADED_CLIENT_STATE, UPGRADED_IBC_STATE,
+    };
+    use crate::mock::context::MockContext;
+    use crate::mock::host::HostBlock;
+    use crate::timestamp::Timestamp;
+    use crate::Height;
+
+    fn client_state() -> ClientState {
+        match get_dummy_tendermint_client_state(get_dummy_tendermint_header()) {
+            AnyClientState::Tendermint(client_state) => client_state,
+            _ => unreachable!(),
+        }
+    }
+
+    /// Generates a header at `height` for the chain `chain_id`, signed by `validators` and
+    /// timestamped at `time` (in seconds since the Unix epoch).
+    fn signed_header(
+        chain_id: &str,
+        height: u64,
+        validators: &[TestgenValidator],
+        time: u64,
+    ) -> Header {
+        let header = TestgenHeader::new(validators)
+            .height(height)
+            .chain_id(chain_id)
+            .next_validators(validators)
+            .time(time);
+        let commit = TestgenCommit::new(header.clone(), 1);
+
+        TestgenLightBlock::new(header, commit)
+            .generate()
+            .unwrap()
+            .into()
+    }
+
+    fn prefix() -> CommitmentPrefix {
+        CommitmentPrefix::from(b"ibc".to_vec())
+    }
+
+    /// A counterparty store holding `value` at `path`, next to another, unrelated entry.
+    /// The captured proofs in `tests/support/query` predate ICS23 (see the README there), hence
+    /// the proofs of this synthetic store.
+    fn store_with(path: Path, value: Vec<u8>) -> DummyStore {
+        DummyStore::new(
+            (path.to_string(), value),
+            ("ports/transfer".to_string(), b"unrelated".to_vec()),
+        )
+    }
+
+    #[test]
+    fn verify_connection_and_channel_state() {
+        let client = TendermintClient;
+        let client_state = client_state();
+        let height = client_state.latest_height();
+
+        let connection_id = ConnectionId::default();
+        let connection_end = ConnectionEnd::new(
+            ConnectionState::Init,
+            ClientId::default(),
+            ConnectionCounterparty::new(ClientId::default(), None, prefix()),
+            get_compatible_versions(),
+            0,
+        );
+        let store = store_with(
+            Path::Connections(connection_id.clone()),
+            connection_end.encode_vec().unwrap(),
+        );
+        let path = Path::Connections(connection_id.clone()).to_string();
+        let proof = store.existence_proof(&path);
+
+        assert!(client
+            .verify_connection_state(
+                &MockContext::default(),
+                &client_state,
+                height,
+                &prefix(),
+                &proof,
+                &store.root(),
+                &connection_id,
+                &connection_end,
+            )
+            .is_ok());
+
+        // The counterparty stores a connection end in a different state.
+        let mut other_connection_end = connection_end.clone();
+        other_connection_end.set_state(ConnectionState::Open);
+        assert!(client
+            .verify_connection_state(
+                &MockContext::default(),
+                &client_state,
+                height,
+                &prefix(),
+                &proof,
+                &store.root(),
+                &connection_id,
+                &other_connection_end,
+            )
+            .is_err());
+
+        // The proof was produced at a height the client has not seen yet.
+        assert!(client
+            .verify_connection_state(
+                &MockContext::default(),
+                &client_state,
+                height.increment(),
+                &prefix(),
+                &proof,
+                &store.root(),
+                &connection_id,
+                &connection_end,
+            )
+            .is_err());
+
+        let port_id = PortId::default();
+        let channel_id = ChannelId::default();
+        let channel_end = ChannelEnd::new(
+            State::Init,
+            Order::Unordered,
+            Counterparty::new(PortId::default(), None),
+            vec![connection_id],
+            "ics20".to_string(),
+        );
+        let path = Path::ChannelEnds(port_id.clone(), channel_id.clone());
+        let store = store_with(path.clone(), channel_end.encode_vec().unwrap());
+
+        assert!(client
+            .verify_channel_state(
+                &MockContext::default(),
+                &client_state,
+                height,
+                &prefix(),
+                &store.existence_proof(&path.to_string()),
+                &store.root(),
+                &port_id,
+                &channel_id,
+                &channel_end,
+            )
+            .is_ok());
+    }
+
+    #[test]
+    fn verify_client_states() {
+        let client = TendermintClient;
+        let client_state = client_state();
+        let height = client_state.latest_height();
+        let client_id = ClientId::default();
+
+        let consensus_height = Height::new(0, 10);
+        let consensus_state =
+            AnyConsensusState::Tendermint(ConsensusState::from(get_dummy_tendermint_header()));
+        let path = Path::ClientConsensusState {
+            client_id: client_id.clone(),
+            epoch: consensus_height.revision_number,
+            height: consensus_height.revision_height,
+        };
+        let store = store_with(path.clone(), consensus_state.encode_vec().unwrap());
+        let proof = store.existence_proof(&path.to_string());
+
+        assert!(client
+            .verify_client_consensus_state(
+                &MockContext::default(),
+                &client_state,
+                height,
+                &prefix(),
+                &proof,
+                &store.root(),
+                &client_id,
+                consensus_height,
+                &consensus_state,
+            )
+            .is_ok());
+
+        // The proof is for the consensus state at another height.
+        assert!(client
+            .verify_client_consensus_state(
+                &MockContext::default(),
+                &client_state,
+                height,
+                &prefix(),
+                &proof,
+                &store.root(),
+                &client_id,
+                consensus_height.increment(),
+                &consensus_state,
+            )
+            .is_err());
+
+        let any_client_state = AnyClientState::Tendermint(client_state.clone());
+        let path = Path::ClientState(client_id.clone());
+        let store = store_with(path.clone(), any_client_state.encode_vec().unwrap());
+
+        assert!(client
+            .verify_client_full_state(
+                &MockContext::default(),
+                &client_state,
+                height,
+                &store.root(),
+                &prefix(),
+                &client_id,
+                &store.existence_proof(&path.to_string()),
+                &any_client_state,
+            )
+            .is_ok());
+    }
+
+    #[test]
+    fn verify_packet_proofs() {
+        let client = TendermintClient;
+        let client_state = client_state();
+        let height = client_state.latest_height();
+        let port_id = PortId::default();
+        let channel_id = ChannelId::default();
+        let seq = Sequence::from(1);
+
+        let commitment =
+            compute_packet_commitment(b"packet data", Height::new(0, 10), Timestamp::none());
+        let path = Path::Commitments {
+            port_id: port_id.clone(),
+            channel_id: channel_id.clone(),
+            sequence: seq,
+        };
+        let store = store_with(path.clone(), commitment.as_bytes().to_vec());
+
+        assert!(client
+            .verify_packet_data(
+                &MockContext::default(),
+                &client_state,
+                height,
+                &prefix(),
+                &store.existence_proof(&path.to_string()),
+                &store.root(),
+                &port_id,
+                &channel_id,
+                &seq,
+                commitment,
+            )
+            .is_ok());
+
+        let ack = b"ack".to_vec();
+        let path = Path::Acks {
+            port_id: port_id.clone(),
+            channel_id: channel_id.clone(),
+            sequence: seq,
+        };
+        let store = store_with(
+            path.clone(),
+            <sha2::Sha256 as sha2::Digest>::digest(&ack).to_vec(),
+        );
+
+        assert!(client
+            .verify_packet_acknowledgement(
+                &MockContext::default(),
+                &client_state,
+                height,
+                &prefix(),
+                &store.existence_proof(&path.to_string()),
+                &store.root(),
+                &port_id,
+                &channel_id,
+                &seq,
+                ack,
+            )
+            .is_ok());
+
+        let path = Path::SeqRecvs(port_id.clone(), channel_id.clone());
+        let store = store_with(path.clone(), 1u64.to_be_bytes().to_vec());
+        let proof = store.existence_proof(&path.to_string());
+
+        assert!(client
+            .verify_next_sequence_recv(
+                &MockContext::default(),
+                &client_state,
+                height,
+                &prefix(),
+                &proof,
+                &store.root(),
+                &port_id,
+                &channel_id,
+                &seq,
+            )
+            .is_ok());
+        assert!(client
+            .verify_next_sequence_recv(
+                &MockContext::default(),
+                &client_state,
+                height,
+                &prefix(),
+                &proof,
+                &store.root(),
+                &port_id,
+                &channel_id,
+                &seq.increment(),
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn verify_packet_receipt_absence() {
+        let client = TendermintClient;
+        let mut client_state = client_state();
+        let height = client_state.latest_height();
+        let port_id = PortId::default();
+        let channel_id = ChannelId::default();
+        let seq = Sequence::from(1);
+
+        let path = Path::Receipts {
+            port_id: port_id.clone(),
+            channel_id: channel_id.clone(),
+            sequence: seq,
+        }
+        .to_string();
+        let store = DummyStore::new(
+            (
+                "clients/07-tendermint-0/clientState".to_string(),
+                b"client".to_vec(),
+            ),
+            ("seqAcks/ports/transfer".to_string(), b"sequence".to_vec()),
+        );
+        let proof = store.absence_proof(&path);
+
+        assert!(client
+            .verify_packet_receipt_absence(
+                &MockContext::default(),
+                &client_state,
+                height,
+                &prefix(),
+                &proof,
+                &store.root(),
+                &port_id,
+                &channel_id,
+                &seq,
+            )
+            .is_ok());
+
+        // A frozen client cannot verify any proof.
+        client_state.frozen_height = height;
+        assert!(client
+            .verify_packet_receipt_absence(
+                &MockContext::default(),
+                &client_state,
+                height,
+                &prefix(),
+                &proof,
+                &store.root(),
+                &port_id,
+                &channel_id,
+                &seq,
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn check_header_and_update_state() {
+        struct Test {
+            name: String,
+            client_state: ClientState,
+            header: Header,
+            want_pass: bool,
+        }
+
+        let chain_id = ChainId::new("mockgaiaB".to_string(), 1);
+        let client_id = ClientId::new(ClientType::Tendermint, 0).unwrap();
+        let trusted_height = Height::new(1, 10);
+
+        let ctx = MockContext::default().with_client_parametrized_with_chain_id(
+            chain_id.clone(),
+            &client_id,
+            trusted_height,
+            Some(ClientType::Tendermint),
+            Some(trusted_height),
+        );
+        let client_state = match ctx.client_state(&client_id) {
+            Some(AnyClientState::Tendermint(client_state)) => client_state,
+            _ => unreachable!(),
+        };
+
+        let header_at = |height: u64, trusted_height: Height| {
+            let mut header: Header = HostBlock::generate_tm_block(chain_id.clone(), height).into();
+            header.trusted_height = trusted_height;
+            header
+        };
+
+        let trusted_validators = [
+            TestgenValidator::new("1").voting_power(50),
+            TestgenValidator::new("2").voting_power(50),
+        ];
+        let other_validators = [
+            TestgenValidator::new("3").voting_power(50),
+            TestgenValidator::new("4").voting_power(50),
+        ];
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+
+        let tests: Vec<Test> = vec![
+            Test {
+                name: "Adjacent header".to_string(),
+                client_state: client_state.clone(),
+                header: header_at(11, trusted_height),
+                want_pass: true,
+            },
+            Test {
+                name: "Non-adjacent header".to_string(),
+                client_state: client_state.clone(),
+                header: header_at(15, trusted_height),
+                want_pass: true,
+            },
+            Test {
+                name: "Header from another chain".to_string(),
+                client_state: client_state.clone(),
+                header: {
+                    let mut header: Header =
+                        HostBlock::generate_tm_block(ChainId::new("mockgaiaC".to_string(), 1), 11)
+                            .into();
+                    header.trusted_height = trusted_height;
+                    header
+                },
+                want_pass: false,
+            },
+            Test {
+                name: "No consensus state at the trusted height".to_string(),
+                client_state: client_state.clone(),
+                header: header_at(11, Height::new(1, 9)),
+                want_pass: false,
+            },
+            Test {
+                name: "Trusted height is not lower than the header height".to_string(),
+                client_state: ClientState {
+                    latest_height: Height::new(1, 5),
+                    ..client_state.clone()
+                },
+                header: header_at(10, trusted_height),
+                want_pass: false,
+            },
+            Test {
+                name: "Validator set does not match the header".to_string(),
+                client_state: client_state.clone(),
+                header: {
+                    let mut header = header_at(11, trusted_height);
+                    header.validator_set =
+                        signed_header(chain_id.as_str(), 11, &other_validators, now).validator_set;
+                    header
+                },
+                want_pass: false,
+            },
+            Test {
+                name: "Trusted validator set does not match the trusted consensus state"
+                    .to_string(),
+                client_state: client_state.clone(),
+                header: {
+                    let mut header = header_at(15, trusted_height);
+                    header.trusted_validator_set =
+                        signed_header(chain_id.as_str(), 15, &other_validators, now).validator_set;
+                    header
+                },
+                want_pass: false,
+            },
+            Test {
+                name: "Not enough trusted voting power signed the header".to_string(),
+                client_state: client_state.clone(),
+                header: {
+                    let mut header =
+                        signed_header(chain_id.as_str(), 15, &other_validators, now - 1);
+                    header.trusted_height = trusted_height;
+                    header.trusted_validator_set =
+                        signed_header(chain_id.as_str(), 15, &trusted_validators, now - 1)
+                            .validator_set;
+                    header
+                },
+                want_pass: false,
+            },
+            Test {
+                name: "Header from the future".to_string(),
+                client_state: client_state.clone(),
+                header: {
+                    let mut header =
+                        signed_header(chain_id.as_str(), 11, &trusted_validators, now + 1000);
+                    header.trusted_height = trusted_height;
+                    header
+                },
+                want_pass: false,
+            },
+            Test {
+                name: "Trusted consensus state has expired".to_string(),
+                client_state: ClientState {
+                    trusting_period: Duration::from_secs(1),
+                    ..client_state
+                },
+                header: header_at(11, trusted_height),
+                want_pass: false,
+            },
+        ];
+
+        for test in tests {
+            let res = TendermintClient.check_header_and_update_state(
+                &ctx,
+                client_id.clone(),
+                test.client_state,
+                test.header.clone(),
+            );
+
+            assert_eq!(
+                test.want_pass,
+                res.is_ok(),
+                "check_header_and_update_state failed for test: {}, \nheader {:?} error: {:?}",
+                test.name,
+                test.header,
+                res.err()
+            );
+        }
+    }
+
+    #[test]
+    fn check_misbehaviour_and_update_state() {
+        struct Test {
+            name: String,
+            misbehaviour: Misbehaviour,
+            want_pass: bool,
+        }
+
+        let chain_id = ChainId::new("mockgaiaB".to_string(), 1);
+        let client_id = ClientId::new(ClientType::Tendermint, 0).unwrap();
+        let trusted_height = Height::new(1, 10);
+
+        let ctx = MockContext::default().with_client_parametrized_with_chain_id(
+            chain_id.clone(),
+            &client_id,
+            trusted_height,
+            Some(ClientType::Tendermint),
+            Some(trusted_height),
+        );
+        let client_state = match ctx.client_state(&client_id) {
+            Some(AnyClientState::Tendermint(client_state)) => client_state,
+            _ => unreachable!(),
+        };
+
+        let trusted_validators = [
+            TestgenValidator::new("1").voting_power(50),
+            TestgenValidator::new("2").voting_power(50),
+        ];
+        let other_validators = [
+            TestgenValidator::new("3").voting_power(50),
+            TestgenValidator::new("4").voting_power(50),
+        ];
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+
+        let header_at = |height: u64, time: u64| {
+            let mut header =
+                signed_header(chain_id.as_str(), height, &trusted_validators, now - time);
+            header.trusted_height = trusted_height;
+            header
+        };
+        let misbehaviour = |header1: Header, header2: Header| Misbehaviour {
+            client_id: client_id.clone(),
+            header1,
+            header2,
+        };
+
+        let tests: Vec<Test> = vec![
+            Test {
+                name: "Conflicting headers at the same height".to_string(),
+                misbehaviour: misbehaviour(header_at(15, 100), header_at(15, 200)),
+                want_pass: true,
+            },
+            Test {
+                name: "Headers violating BFT time monotonicity".to_string(),
+                misbehaviour: misbehaviour(header_at(16, 200), header_at(15, 100)),
+                want_pass: true,
+            },
+            Test {
+                name: "Identical headers".to_string(),
+                misbehaviour: misbehaviour(header_at(15, 100), header_at(15, 100)),
+                want_pass: false,
+            },
+            Test {
+                name: "Headers respecting BFT time monotonicity".to_string(),
+                misbehaviour: misbehaviour(header_at(16, 100), header_at(15, 200)),
+                want_pass: false,
+            },
+            Test {
+                name: "First header is lower than the second".to_string(),
+                misbehaviour: misbehaviour(header_at(15, 200), header_at(16, 100)),
+                want_pass: false,
+            },
+            Test {
+                name: "Header from another chain".to_string(),
+                misbehaviour: misbehaviour(header_at(15, 100), {
+                    let mut header =
+                        signed_header("mockgaiaC-1", 15, &trusted_validators, now - 200);
+                    header.trusted_height = trusted_height;
+                    header
+                }),
+                want_pass: false,
+            },
+            Test {
+                name: "No consensus state at the trusted height".to_string(),
+                misbehaviour: misbehaviour(header_at(15, 100), {
+                    let mut header = header_at(15, 200);
+                    header.trusted_height = Height::new(1, 9);
+                    header
+                }),
+                want_pass: false,
+            },
+            Test {
+                name: "Not enough trusted voting power signed a header".to_string(),
+                misbehaviour: misbehaviour(header_at(15, 100), {
+                    let mut header =
+                        signed_header(chain_id.as_str(), 15, &other_validators, now - 200);
+                    header.trusted_height = trusted_height;
+                    header.trusted_validator_set = header_at(15, 200).validator_set;
+                    header
+                }),
+                want_pass: false,
+            },
+        ];
+
+        for test in tests {
+            let res = TendermintClient.check_misbehaviour_and_update_state(
+                &ctx,
+                client_id.clone(),
+                client_state.clone(),
+                test.misbehaviour.clone(),
+            );
+
+            assert_eq!(
+                test.want_pass,
+                res.is_ok(),
+                "check_misbehaviour_and_update_state failed for test: {}, \nmisbehaviour {:?} error: {:?}",
+                test.name,
+                test.misbehaviour,
+                res.err()
+            );
+
+            if let Ok(frozen_state) = res {
+                assert_eq!(
+                    frozen_state.frozen_height,
+                    test.misbehaviour.header1.height()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn verify_upgrade_and_update_state() {
+        struct Test {
+            name: String,
+            client_state: ClientState,
+            upgraded_client_state: ClientState,
+            proof_upgrade_client: CommitmentProofBytes,
+            want_pass: bool,
+        }
+
+        let chain_id = ChainId::new("mockgaiaB".to_string(), 1);
+        let client_id = ClientId::new(ClientType::Tendermint, 0).unwrap();
+        let latest_height = Height::new(1, 10);
+
+        let mut ctx = MockContext::default().with_client_parametrized_with_chain_id(
+            chain_id.clone(),
+            &client_id,
+            latest_height,
+            Some(ClientType::Tendermint),
+            Some(latest_height),
+        );
+
+        // The dummy store only commits to an `ibc` store, which thus plays the role of the store
+        // of the upgrade module.
+        let client_state = match ctx.client_state(&client_id) {
+            Some(AnyClientState::Tendermint(client_state)) => ClientState {
+                upgrade_path: vec!["ibc".to_string(), UPGRADED_IBC_STATE.to_string()],
+                ..client_state
+            },
+            _ => unreachable!(),
+        };
+
+        let upgraded_client_state = ClientState {
+            chain_id: ChainId::new("mockgaiaB".to_string(), 2).to_string(),
+            unbonding_period: Duration::from_secs(100000),
+            latest_height: Height::new(2, 1),
+            ..client_state.clone()
+        };
+        let upgraded_consensus_state = ConsensusState::from(
+            signed_header(
+                upgraded_client_state.chain_id.as_str(),
+                1,
+                &[TestgenValidator::new("1").voting_power(50)],
+                SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .unwrap()
+                    .as_secs(),
+            )
+            .signed_header
+            .header,
+        );
+
+        // The chain commits to the upgraded states under the upgrade path of the client.
+        let client_key = format!("{}/{}/{}", UPGRADED_IBC_STATE, 10, UPGRADED_CLIENT_STATE);
+        let consensus_key = format!(
+            "{}/{}/{}",
+            UPGRADED_IBC_STATE, 10, UPGRADED_CLIENT_CONSENSUS_STATE
+        );
+        let store = DummyStore::new(
+            (
+                client_key.clone(),
+                AnyClientState::Tendermint(upgraded_client_state.clone().zero_custom_fields())
+                    .encode_vec()
+                    .unwrap(),
+            ),
+            (
+                consensus_key.clone(),
+                AnyConsensusState::Tendermint(upgraded_consensus_state.clone())
+                    .encode_vec()
+                    .unwrap(),
+            ),
+        );
+
+        let consensus_state = match ctx.consensus_state(&client_id, latest_height) {
+            Some(AnyConsensusState::Tendermint(consensus_state)) => ConsensusState {
+                root: store.root(),
+                ..consensus_state
+            },
+            _ => unreachable!(),
+        };
+        ctx.store_consensus_state(
+            client_id.clone(),
+            latest_height,
+            AnyConsensusState::Tendermint(consensus_state),
+        )
+        .unwrap();
+
+        let proof_upgrade_client = store.existence_proof(&client_key);
+        let proof_upgrade_consensus_state = store.existence_proof(&consensus_key);
+
+        let tests: Vec<Test> = vec![
+            Test {
+                name: "Valid upgrade".to_string(),
+                client_state: client_state.clone(),
+                upgraded_client_state: upgraded_client_state.clone(),
+                proof_upgrade_client: proof_upgrade_client.clone(),
+                want_pass: true,
+            },
+            Test {
+                name: "Relayer-chosen fields of the upgraded client state are ignored".to_string(),
+                client_state: client_state.clone(),
+                upgraded_client_state: ClientState {
+                    trusting_period: Duration::from_secs(1),
+                    max_clock_drift: Duration::from_secs(1),
+                    ..upgraded_client_state.clone()
+                },
+                proof_upgrade_client: proof_upgrade_client.clone(),
+                want_pass: true,
+            },
+            Test {
+                name: "Upgraded client state is not the committed one".to_string(),
+                client_state: client_state.clone(),
+                upgraded_client_state: ClientState {
+                    unbonding_period: Duration::from_secs(200000),
+                    ..upgraded_client_state.clone()
+                },
+                proof_upgrade_client: proof_upgrade_client.clone(),
+                want_pass: false,
+            },
+            Test {
+                name: "Proof of the upgraded client state is for another key".to_string(),
+                client_state: client_state.clone(),
+                upgraded_client_state: upgraded_client_state.clone(),
+                proof_upgrade_client: proof_upgrade_consensus_state.clone(),
+                want_pass: false,
+            },
+            Test {
+                name: "Upgraded height is not greater than the client height".to_string(),
+                client_state: client_state.clone(),
+                upgraded_client_state: ClientState {
+                    latest_height,
+                    ..upgraded_client_state.clone()
+                },
+                proof_upgrade_client: proof_upgrade_client.clone(),
+                want_pass: false,
+            },
+            Test {
+                name: "Client has no upgrade path".to_string(),
+                client_state: ClientState {
+                    upgrade_path: vec![],
+                    ..client_state
+                },
+                upgraded_client_state: upgraded_client_state.clone(),
+                proof_upgrade_client,
+                want_pass: false,
+            },
+        ];
+
+        for test in tests {
+            let res = TendermintClient.verify_upgrade_and_update_state(
+                &ctx,
+                client_id.clone(),
+                test.client_state.clone(),
+                test.upgraded_client_state.clone(),
+                upgraded_consensus_state.clone(),
+                &test.proof_upgrade_client,
+                &proof_upgrade_consensus_state,
+            );
+
+            assert_eq!(
+                test.want_pass,
+                res.is_ok(),
+                "verify_upgrade_and_update_state failed for test: {}, error: {:?}",
+                test.name,
+                res.err()
+            );
+
+            // The chain-chosen fields come from the upgraded client state, the others are kept.
+            if let Ok((new_client_state, new_consensus_state)) = res {
+                assert_eq!(new_client_state.chain_id, upgraded_client_state.chain_id);
+                assert_eq!(
+                    new_client_state.latest_height,
+                    upgraded_client_state.latest_height
+                );
+                assert_eq!(
+                    new_client_state.unbonding_period,
+                    upgraded_client_state.unbonding_period
+                );
+                assert_eq!(
+                    new_client_state.trusting_period,
+                    test.client_state.trusting_period
+                );
+                assert_eq!(
+                    new_client_state.max_clock_drift,
+                    test.client_state.max_clock_drift
+                );
+                assert_eq!(
+                    new_consensus_state.timestamp,
+                    upgraded_consensus_state.timestamp
+                );
+                assert_eq!(
+                    new_consensus_state.root,
+                    CommitmentRoot::from(SENTINEL_ROOT.as_bytes().to_vec())
+                );
+            }
+        }
     }
 }
@@ -6,6 +6,7 @@ use serde::Serialize;
 use tendermint::trust_threshold::TrustThresholdFraction as TrustThreshold;
 use tendermint_proto::Protobuf;
 
+use ibc_proto::ibc::core::commitment::v1::MerklePath;
 use ibc_proto::ibc::lightclients::tendermint::v1::{ClientState as RawClientState, Fraction};
 
 use crate::ics02_client::client_def::AnyClientState;
@@ -103,6 +104,46 @@ impl ClientState {
             ..self
         }
     }
+
+    /// Returns this client state, frozen at height `h`.
+    pub fn with_frozen_height(self, h: Height) -> Self {
+        ClientState {
+            frozen_height: h,
+            ..self
+        }
+    }
+
+    /// Returns this client state with the fields chosen by the relayer which created the client
+    /// (rather than by the chain the client tracks) zeroed out. This is the form in which a chain
+    /// commits to its upgraded client state.
+    pub fn zero_custom_fields(self) -> Self {
+        ClientState {
+            trust_level: TrustThreshold {
+                numerator: 0,
+                denominator: 0,
+            },
+            trusting_period: Duration::default(),
+            max_clock_drift: Duration::default(),
+            frozen_height: Height::zero(),
+            allow_update_after_expiry: false,
+            allow_update_after_misbehaviour: false,
+            ..self
+        }
+    }
+
+    /// The Merkle path under which the tracked chain commits to the upgrade value with the given
+    /// `key`, for an upgrade planned at `height`. The last key of the client's upgrade path is
+    /// suffixed with the height and `key`, as done by the Cosmos SDK upgrade module.
+    pub fn upgrade_merkle_path(&self, height: u64, key: &str) -> Result<MerklePath, Error> {
+        let (last_key, store_keys) = self
+            .upgrade_path
+            .split_last()
+            .ok_or(Kind::EmptyUpgradePath)?;
+
+        let mut key_path = store_keys.to_vec();
+        key_path.push(format!("{}/{}/{}", last_key, height, key));
+        Ok(MerklePath { key_path })
+    }
 }
 
 impl crate::ics02_client::state::ClientState for ClientState {
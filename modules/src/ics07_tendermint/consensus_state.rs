@@ -11,6 +11,7 @@ use crate::ics02_client::{client_def::AnyConsensusState, client_type::ClientType
 use crate::ics07_tendermint::error::{Error, Kind};
 use crate::ics07_tendermint::header::Header;
 use crate::ics23_commitment::commitment::CommitmentRoot;
+use crate::timestamp::Timestamp;
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize)]
 pub struct ConsensusState {
@@ -38,6 +39,10 @@ impl crate::ics02_client::state::ConsensusState for ConsensusState {
         &self.root
     }
 
+    fn timestamp(&self) -> Timestamp {
+        self.timestamp.into()
+    }
+
     fn validate_basic(&self) -> Result<(), Box<dyn std::error::Error>> {
         unimplemented!()
     }
@@ -1,6 +1,8 @@
 use anomaly::{BoxError, Context};
 use thiserror::Error;
 
+use crate::Height;
+
 pub type Error = anomaly::Error<Kind>;
 
 #[derive(Clone, Debug, Error)]
@@ -31,6 +33,45 @@ pub enum Kind {
 
     #[error("invalid raw header")]
     InvalidRawHeader,
+
+    #[error("header chain id ({0}) does not match the client chain id ({1})")]
+    MismatchedChainId(String, String),
+
+    #[error("header revision ({0}) does not match the client latest revision ({1})")]
+    MismatchedRevisions(u64, u64),
+
+    #[error("trusted height ({0}) must be at the header revision and lower than the header height ({1})")]
+    InvalidTrustedHeight(Height, Height),
+
+    #[error("no consensus state found for the trusted height {0}")]
+    TrustedConsensusStateNotFound(Height),
+
+    #[error("the trusted consensus state has expired")]
+    TrustedConsensusStateExpired,
+
+    #[error("the validator set does not match the header validators hash")]
+    InvalidValidatorSet,
+
+    #[error("the trusted validator set does not match the next validators hash of the trusted consensus state")]
+    InvalidTrustedValidatorSet,
+
+    #[error("header verification failed")]
+    HeaderVerificationFailure,
+
+    #[error("invalid raw misbehaviour")]
+    InvalidRawMisbehaviour,
+
+    #[error("invalid misbehaviour evidence")]
+    InvalidMisbehaviour,
+
+    #[error("the client has no upgrade path")]
+    EmptyUpgradePath,
+
+    #[error("upgraded client height ({0}) must be greater than the client latest height ({1})")]
+    LowUpgradeHeight(Height, Height),
+
+    #[error("upgrade verification failed")]
+    UpgradeVerificationFailure,
 }
 
 impl Kind {
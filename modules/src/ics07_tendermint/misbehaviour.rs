@@ -0,0 +1,99 @@
+use std::convert::{TryFrom, TryInto};
+
+use tendermint_proto::Protobuf;
+
+use ibc_proto::ibc::lightclients::tendermint::v1::Misbehaviour as RawMisbehaviour;
+
+use crate::ics02_client::client_def::AnyMisbehaviour;
+use crate::ics02_client::header::Header as ICS2Header;
+use crate::ics07_tendermint::error::{Error, Kind};
+use crate::ics07_tendermint::header::Header;
+use crate::ics24_host::identifier::ClientId;
+use crate::Height;
+
+/// Evidence of misbehaviour of a Tendermint chain: two headers, both signed by enough of the
+/// validators trusted by the client, that conflict with each other. They are either two different
+/// headers at the same height, or headers whose timestamps violate BFT time monotonicity.
+#[derive(Clone, Debug, PartialEq)] // TODO: Add Eq bound once present in tendermint-rs
+pub struct Misbehaviour {
+    pub client_id: ClientId,
+    pub header1: Header,
+    pub header2: Header,
+}
+
+impl crate::ics02_client::misbehaviour::Misbehaviour for Misbehaviour {
+    fn client_id(&self) -> &ClientId {
+        &self.client_id
+    }
+
+    fn height(&self) -> Height {
+        self.header1.height()
+    }
+
+    fn wrap_any(self) -> AnyMisbehaviour {
+        AnyMisbehaviour::Tendermint(self)
+    }
+}
+
+impl Protobuf<RawMisbehaviour> for Misbehaviour {}
+
+impl TryFrom<RawMisbehaviour> for Misbehaviour {
+    type Error = Error;
+
+    fn try_from(raw: RawMisbehaviour) -> Result<Self, Self::Error> {
+        Ok(Self {
+            client_id: raw
+                .client_id
+                .parse()
+                .map_err(|e| Kind::InvalidRawMisbehaviour.context(e))?,
+            header1: raw
+                .header_1
+                .ok_or_else(|| Kind::InvalidRawMisbehaviour.context("missing header1"))?
+                .try_into()?,
+            header2: raw
+                .header_2
+                .ok_or_else(|| Kind::InvalidRawMisbehaviour.context("missing header2"))?
+                .try_into()?,
+        })
+    }
+}
+
+impl From<Misbehaviour> for RawMisbehaviour {
+    fn from(value: Misbehaviour) -> Self {
+        RawMisbehaviour {
+            client_id: value.client_id.to_string(),
+            header_1: Some(value.header1.into()),
+            header_2: Some(value.header2.into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use ibc_proto::ibc::lightclients::tendermint::v1::Misbehaviour as RawMisbehaviour;
+
+    use crate::ics07_tendermint::header::test_util::get_dummy_ics07_header;
+    use crate::ics07_tendermint::misbehaviour::Misbehaviour;
+
+    #[test]
+    fn to_and_from() {
+        let misbehaviour = Misbehaviour {
+            client_id: Default::default(),
+            header1: get_dummy_ics07_header(),
+            header2: get_dummy_ics07_header(),
+        };
+
+        let raw = RawMisbehaviour::from(misbehaviour.clone());
+        let misbehaviour_back = Misbehaviour::try_from(raw.clone()).unwrap();
+        assert_eq!(misbehaviour, misbehaviour_back);
+        assert_eq!(raw, RawMisbehaviour::from(misbehaviour_back));
+
+        let raw_without_header = RawMisbehaviour {
+            header_2: None,
+            ..raw
+        };
+        assert!(Misbehaviour::try_from(raw_without_header).is_err());
+    }
+}
@@ -0,0 +1,433 @@
+use crate::ics02_client::client_def::{AnyClientState, AnyConsensusState, ClientDef};
+use crate::ics02_client::context::ClientReader;
+use crate::ics02_client::header::Header as ICS2Header;
+use crate::ics02_client::state::ClientState as ICS2ClientState;
+use crate::ics03_connection::connection::ConnectionEnd;
+use crate::ics03_connection::context::ConnectionReader;
+use crate::ics04_channel::channel::ChannelEnd;
+use crate::ics04_channel::commitment::PacketCommitment;
+use crate::ics04_channel::context::ChannelReader;
+use crate::ics04_channel::packet::Sequence;
+use crate::ics09_localhost::client_state::ClientState;
+use crate::ics09_localhost::consensus_state::ConsensusState;
+use crate::ics09_localhost::error::Kind;
+use crate::ics09_localhost::header::Header;
+use crate::ics09_localhost::misbehaviour::Misbehaviour;
+use crate::ics23_commitment::commitment::{CommitmentPrefix, CommitmentProofBytes, CommitmentRoot};
+use crate::ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId};
+use crate::ics24_host::Path;
+use crate::Height;
+
+/// The localhost client, i.e., the client of the host chain itself (see ICS9), which lets two
+/// modules of the same chain communicate over IBC. Instead of verifying proofs, it reads the
+/// expected state directly from the store of the host chain, through the context passed to the
+/// verification functions. Proofs, proof heights and commitment prefixes are ignored.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LocalhostClient;
+
+impl ClientDef for LocalhostClient {
+    type Header = Header;
+    type Misbehaviour = Misbehaviour;
+    type ClientState = ClientState;
+    type ConsensusState = ConsensusState;
+
+    fn check_header_and_update_state(
+        &self,
+        ctx: &dyn ClientReader,
+        _client_id: ClientId,
+        client_state: Self::ClientState,
+        header: Self::Header,
+    ) -> Result<(Self::ClientState, Self::ConsensusState), Box<dyn std::error::Error>> {
+        if header.height() <= client_state.latest_height() {
+            return Err(
+                Kind::LowHeaderHeight(header.height(), client_state.latest_height()).into(),
+            );
+        }
+        if header.height() > ctx.host_height() {
+            return Err(Kind::HeaderInTheFuture(header.height(), ctx.host_height()).into());
+        }
+
+        Ok((
+            client_state.with_height(header.height()),
+            ConsensusState::new(),
+        ))
+    }
+
+    fn check_misbehaviour_and_update_state(
+        &self,
+        _ctx: &dyn ClientReader,
+        _client_id: ClientId,
+        _client_state: Self::ClientState,
+        misbehaviour: Self::Misbehaviour,
+    ) -> Result<Self::ClientState, Box<dyn std::error::Error>> {
+        match misbehaviour {}
+    }
+
+    fn verify_upgrade_and_update_state(
+        &self,
+        _ctx: &dyn ClientReader,
+        _client_id: ClientId,
+        _client_state: Self::ClientState,
+        _upgraded_client_state: Self::ClientState,
+        _upgraded_consensus_state: Self::ConsensusState,
+        _proof_upgrade_client: &CommitmentProofBytes,
+        _proof_upgrade_consensus_state: &CommitmentProofBytes,
+    ) -> Result<(Self::ClientState, Self::ConsensusState), Box<dyn std::error::Error>> {
+        Err(Kind::UpgradeNotSupported.into())
+    }
+
+    fn verify_client_consensus_state(
+        &self,
+        ctx: &dyn ConnectionReader,
+        _client_state: &Self::ClientState,
+        _height: Height,
+        _prefix: &CommitmentPrefix,
+        _proof: &CommitmentProofBytes,
+        _root: &CommitmentRoot,
+        client_id: &ClientId,
+        consensus_height: Height,
+        expected_consensus_state: &AnyConsensusState,
+    ) -> Result<Option<Self::ClientState>, Box<dyn std::error::Error>> {
+        let path = Path::ClientConsensusState {
+            client_id: client_id.clone(),
+            epoch: consensus_height.revision_number,
+            height: consensus_height.revision_height,
+        };
+        let stored = ctx.client_consensus_state(client_id, consensus_height);
+
+        verify_stored(path, stored, expected_consensus_state)?;
+
+        Ok(None)
+    }
+
+    fn verify_connection_state(
+        &self,
+        ctx: &dyn ConnectionReader,
+        _client_state: &Self::ClientState,
+        _height: Height,
+        _prefix: &CommitmentPrefix,
+        _proof: &CommitmentProofBytes,
+        _root: &CommitmentRoot,
+        connection_id: &ConnectionId,
+        expected_connection_end: &ConnectionEnd,
+    ) -> Result<Option<Self::ClientState>, Box<dyn std::error::Error>> {
+        let path = Path::Connections(connection_id.clone());
+        let stored = ctx.connection_end(connection_id);
+
+        verify_stored(path, stored, expected_connection_end)?;
+
+        Ok(None)
+    }
+
+    fn verify_channel_state(
+        &self,
+        ctx: &dyn ChannelReader,
+        _client_state: &Self::ClientState,
+        _height: Height,
+        _prefix: &CommitmentPrefix,
+        _proof: &CommitmentProofBytes,
+        _root: &CommitmentRoot,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        expected_channel_end: &ChannelEnd,
+    ) -> Result<Option<Self::ClientState>, Box<dyn std::error::Error>> {
+        let path = Path::ChannelEnds(port_id.clone(), channel_id.clone());
+        let stored = ctx.channel_end(&(port_id.clone(), channel_id.clone()));
+
+        verify_stored(path, stored, expected_channel_end)?;
+
+        Ok(None)
+    }
+
+    fn verify_client_full_state(
+        &self,
+        ctx: &dyn ConnectionReader,
+        _client_state: &Self::ClientState,
+        _height: Height,
+        _root: &CommitmentRoot,
+        _prefix: &CommitmentPrefix,
+        client_id: &ClientId,
+        _proof: &CommitmentProofBytes,
+        expected_client_state: &AnyClientState,
+    ) -> Result<Option<Self::ClientState>, Box<dyn std::error::Error>> {
+        let path = Path::ClientState(client_id.clone());
+        let stored = ctx.client_state(client_id);
+
+        verify_stored(path, stored, expected_client_state)?;
+
+        Ok(None)
+    }
+
+    fn verify_packet_data(
+        &self,
+        ctx: &dyn ChannelReader,
+        _client_state: &Self::ClientState,
+        _height: Height,
+        _prefix: &CommitmentPrefix,
+        _proof: &CommitmentProofBytes,
+        _root: &CommitmentRoot,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        seq: &Sequence,
+        commitment: PacketCommitment,
+    ) -> Result<Option<Self::ClientState>, Box<dyn std::error::Error>> {
+        let path = Path::Commitments {
+            port_id: port_id.clone(),
+            channel_id: channel_id.clone(),
+            sequence: *seq,
+        };
+        let stored = ctx.get_packet_commitment(&(port_id.clone(), channel_id.clone(), *seq));
+
+        verify_stored(path, stored, &commitment)?;
+
+        Ok(None)
+    }
+
+    fn verify_packet_acknowledgement(
+        &self,
+        ctx: &dyn ChannelReader,
+        _client_state: &Self::ClientState,
+        _height: Height,
+        _prefix: &CommitmentPrefix,
+        _proof: &CommitmentProofBytes,
+        _root: &CommitmentRoot,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        seq: &Sequence,
+        ack: Vec<u8>,
+    ) -> Result<Option<Self::ClientState>, Box<dyn std::error::Error>> {
+        let path = Path::Acks {
+            port_id: port_id.clone(),
+            channel_id: channel_id.clone(),
+            sequence: *seq,
+        };
+        // The host stores the commitment of the acknowledgement, not the raw bytes.
+        let stored = ctx.get_packet_acknowledgement(&(port_id.clone(), channel_id.clone(), *seq));
+
+        verify_stored(path, stored, &ctx.ack_commitment(&ack))?;
+
+        Ok(None)
+    }
+
+    fn verify_next_sequence_recv(
+        &self,
+        ctx: &dyn ChannelReader,
+        _client_state: &Self::ClientState,
+        _height: Height,
+        _prefix: &CommitmentPrefix,
+        _proof: &CommitmentProofBytes,
+        _root: &CommitmentRoot,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        seq: &Sequence,
+    ) -> Result<Option<Self::ClientState>, Box<dyn std::error::Error>> {
+        let path = Path::SeqRecvs(port_id.clone(), channel_id.clone());
+        let stored = ctx.get_next_sequence_recv(&(port_id.clone(), channel_id.clone()));
+
+        verify_stored(path, stored, seq)?;
+
+        Ok(None)
+    }
+
+    fn verify_packet_receipt_absence(
+        &self,
+        ctx: &dyn ChannelReader,
+        _client_state: &Self::ClientState,
+        _height: Height,
+        _prefix: &CommitmentPrefix,
+        _proof: &CommitmentProofBytes,
+        _root: &CommitmentRoot,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        seq: &Sequence,
+    ) -> Result<Option<Self::ClientState>, Box<dyn std::error::Error>> {
+        let path = Path::Receipts {
+            port_id: port_id.clone(),
+            channel_id: channel_id.clone(),
+            sequence: *seq,
+        };
+
+        match ctx.get_packet_receipt(&(port_id.clone(), channel_id.clone(), *seq)) {
+            Some(_) => Err(Kind::UnexpectedState(path.to_string()).into()),
+            None => Ok(None),
+        }
+    }
+}
+
+/// Checks that the host stores the `expected` value at `path`.
+fn verify_stored<T: PartialEq>(
+    path: Path,
+    stored: Option<T>,
+    expected: &T,
+) -> Result<(), Box<dyn std::error::Error>> {
+    match stored {
+        Some(stored) if &stored == expected => Ok(()),
+        Some(_) => Err(Kind::MismatchedState(path.to_string()).into()),
+        None => Err(Kind::MissingState(path.to_string()).into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ics02_client::client_def::ClientDef;
+    use crate::ics02_client::state::ClientState as ICS2ClientState;
+    use crate::ics03_connection::connection::{ConnectionEnd, State as ConnectionState};
+    use crate::ics04_channel::packet::{Receipt, Sequence};
+    use crate::ics09_localhost::client_def::LocalhostClient;
+    use crate::ics09_localhost::client_state::ClientState;
+    use crate::ics09_localhost::header::Header;
+    use crate::ics23_commitment::commitment::{
+        CommitmentPrefix, CommitmentProofBytes, CommitmentRoot,
+    };
+    use crate::ics24_host::identifier::{ChainId, ChannelId, ClientId, ConnectionId, PortId};
+    use crate::mock::context::MockContext;
+    use crate::Height;
+
+    #[test]
+    fn update_with_header() {
+        struct Test {
+            name: String,
+            header_height: Height,
+            want_pass: bool,
+        }
+
+        // The host of the default mock context is at height 1-5.
+        let ctx = MockContext::default();
+        let client_state =
+            ClientState::new(ChainId::new("mockgaia".to_string(), 1), Height::new(1, 2));
+
+        let tests: Vec<Test> = vec![
+            Test {
+                name: "Good header".to_string(),
+                header_height: Height::new(1, 4),
+                want_pass: true,
+            },
+            Test {
+                name: "Header at the host height".to_string(),
+                header_height: Height::new(1, 5),
+                want_pass: true,
+            },
+            Test {
+                name: "Header not higher than the latest client height".to_string(),
+                header_height: Height::new(1, 2),
+                want_pass: false,
+            },
+            Test {
+                name: "Header beyond the host height".to_string(),
+                header_height: Height::new(1, 6),
+                want_pass: false,
+            },
+        ];
+
+        for test in tests {
+            let res = LocalhostClient.check_header_and_update_state(
+                &ctx,
+                ClientId::default(),
+                client_state.clone(),
+                Header::new(test.header_height),
+            );
+
+            assert_eq!(
+                test.want_pass,
+                res.is_ok(),
+                "update_with_header: test {} failed with result {:?}",
+                test.name,
+                res.as_ref().err()
+            );
+
+            if let Ok((new_client_state, _)) = res {
+                assert_eq!(new_client_state.latest_height(), test.header_height);
+            }
+        }
+    }
+
+    #[test]
+    fn verify_host_state() {
+        let client_state =
+            ClientState::new(ChainId::new("mockgaia".to_string(), 1), Height::new(1, 5));
+        let height = client_state.latest_height();
+        let prefix = CommitmentPrefix::from(vec![]);
+        let proof = CommitmentProofBytes::from(vec![]);
+        let root = CommitmentRoot::from_bytes(&[]);
+
+        let conn_id = ConnectionId::default();
+        let mut conn_end = ConnectionEnd::default();
+        conn_end.set_state(ConnectionState::Init);
+        let mut other_conn_end = conn_end.clone();
+        other_conn_end.set_state(ConnectionState::Open);
+
+        let port_id = PortId::default();
+        let chan_id = ChannelId::default();
+        let seq = Sequence::from(1);
+
+        let ctx = MockContext::default()
+            .with_connection(conn_id.clone(), conn_end.clone())
+            .with_packet_receipt(port_id.clone(), chan_id.clone(), seq, Receipt::Ok);
+
+        // The stored connection end is verified, while a different one or a missing one is not.
+        assert!(LocalhostClient
+            .verify_connection_state(
+                &ctx,
+                &client_state,
+                height,
+                &prefix,
+                &proof,
+                &root,
+                &conn_id,
+                &conn_end,
+            )
+            .is_ok());
+        assert!(LocalhostClient
+            .verify_connection_state(
+                &ctx,
+                &client_state,
+                height,
+                &prefix,
+                &proof,
+                &root,
+                &conn_id,
+                &other_conn_end,
+            )
+            .is_err());
+        assert!(LocalhostClient
+            .verify_connection_state(
+                &MockContext::default(),
+                &client_state,
+                height,
+                &prefix,
+                &proof,
+                &root,
+                &conn_id,
+                &conn_end,
+            )
+            .is_err());
+
+        // The absence of a packet receipt holds only if the host stores no receipt.
+        assert!(LocalhostClient
+            .verify_packet_receipt_absence(
+                &ctx,
+                &client_state,
+                height,
+                &prefix,
+                &proof,
+                &root,
+                &port_id,
+                &chan_id,
+                &seq,
+            )
+            .is_err());
+        assert!(LocalhostClient
+            .verify_packet_receipt_absence(
+                &ctx,
+                &client_state,
+                height,
+                &prefix,
+                &proof,
+                &root,
+                &port_id,
+                &chan_id,
+                &Sequence::from(2),
+            )
+            .is_ok());
+    }
+}
@@ -0,0 +1,82 @@
+use std::convert::{TryFrom, TryInto};
+
+use serde::Serialize;
+use tendermint_proto::Protobuf;
+
+use ibc_proto::ibc::lightclients::localhost::v1::ClientState as RawClientState;
+
+use crate::ics02_client::client_def::AnyClientState;
+use crate::ics02_client::client_type::ClientType;
+use crate::ics09_localhost::error::{Error, Kind};
+use crate::ics24_host::identifier::ChainId;
+use crate::Height;
+
+/// The client state of a localhost client, i.e., of a client of the host chain itself. It tracks
+/// the identifier and the height of the host chain.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct ClientState {
+    pub chain_id: ChainId,
+    pub height: Height,
+}
+
+impl ClientState {
+    pub fn new(chain_id: ChainId, height: Height) -> Self {
+        Self { chain_id, height }
+    }
+
+    pub fn with_height(self, height: Height) -> Self {
+        Self { height, ..self }
+    }
+}
+
+impl crate::ics02_client::state::ClientState for ClientState {
+    fn chain_id(&self) -> String {
+        self.chain_id.to_string()
+    }
+
+    fn client_type(&self) -> ClientType {
+        ClientType::Localhost
+    }
+
+    fn latest_height(&self) -> Height {
+        self.height
+    }
+
+    fn is_frozen(&self) -> bool {
+        // The host chain cannot misbehave towards itself.
+        false
+    }
+
+    fn wrap_any(self) -> AnyClientState {
+        AnyClientState::Localhost(self)
+    }
+}
+
+impl Protobuf<RawClientState> for ClientState {}
+
+impl TryFrom<RawClientState> for ClientState {
+    type Error = Error;
+
+    fn try_from(raw: RawClientState) -> Result<Self, Self::Error> {
+        Ok(Self {
+            chain_id: raw
+                .chain_id
+                .parse()
+                .map_err(|e| Kind::InvalidRawClientState.context(e))?,
+            height: raw
+                .height
+                .ok_or_else(|| Kind::InvalidRawClientState.context("missing height"))?
+                .try_into()
+                .map_err(|e| Kind::InvalidRawClientState.context(e))?,
+        })
+    }
+}
+
+impl From<ClientState> for RawClientState {
+    fn from(value: ClientState) -> Self {
+        RawClientState {
+            chain_id: value.chain_id.to_string(),
+            height: Some(value.height.into()),
+        }
+    }
+}
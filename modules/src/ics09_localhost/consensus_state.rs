@@ -0,0 +1,70 @@
+use std::convert::TryFrom;
+
+use serde::Serialize;
+use tendermint_proto::Protobuf;
+
+use crate::ics02_client::{client_def::AnyConsensusState, client_type::ClientType};
+use crate::ics09_localhost::error::Error;
+use crate::ics23_commitment::commitment::CommitmentRoot;
+use crate::timestamp::Timestamp;
+
+/// The consensus state of a localhost client. A localhost client reads the store of the host chain
+/// directly, so its consensus states carry no information, and are only stored for the handlers to
+/// find a consensus state at the height of every update of the client. They are encoded as empty
+/// protobuf messages.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct ConsensusState {
+    #[serde(skip)]
+    root: CommitmentRoot,
+}
+
+impl ConsensusState {
+    pub fn new() -> Self {
+        Self {
+            root: CommitmentRoot::from_bytes(&[]),
+        }
+    }
+}
+
+impl Default for ConsensusState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl crate::ics02_client::state::ConsensusState for ConsensusState {
+    fn client_type(&self) -> ClientType {
+        ClientType::Localhost
+    }
+
+    fn root(&self) -> &CommitmentRoot {
+        &self.root
+    }
+
+    /// No timestamp, hence packets sent over a localhost client only time out by height.
+    fn timestamp(&self) -> Timestamp {
+        Timestamp::none()
+    }
+
+    fn validate_basic(&self) -> Result<(), Box<dyn std::error::Error>> {
+        Ok(())
+    }
+
+    fn wrap_any(self) -> AnyConsensusState {
+        AnyConsensusState::Localhost(self)
+    }
+}
+
+impl Protobuf<()> for ConsensusState {}
+
+impl TryFrom<()> for ConsensusState {
+    type Error = Error;
+
+    fn try_from(_raw: ()) -> Result<Self, Self::Error> {
+        Ok(Self::new())
+    }
+}
+
+impl From<ConsensusState> for () {
+    fn from(_value: ConsensusState) -> Self {}
+}
@@ -0,0 +1,39 @@
+use anomaly::{BoxError, Context};
+use thiserror::Error;
+
+use crate::Height;
+
+pub type Error = anomaly::Error<Kind>;
+
+#[derive(Clone, Debug, Error)]
+pub enum Kind {
+    #[error("invalid raw client state")]
+    InvalidRawClientState,
+
+    #[error("invalid raw header")]
+    InvalidRawHeader,
+
+    #[error("header height ({0}) must be greater than the client latest height ({1})")]
+    LowHeaderHeight(Height, Height),
+
+    #[error("header height ({0}) must not be greater than the host height ({1})")]
+    HeaderInTheFuture(Height, Height),
+
+    #[error("nothing is stored at {0} on the host")]
+    MissingState(String),
+
+    #[error("the value stored at {0} on the host does not match the expected value")]
+    MismatchedState(String),
+
+    #[error("a value is stored at {0} on the host")]
+    UnexpectedState(String),
+
+    #[error("localhost clients cannot be upgraded")]
+    UpgradeNotSupported,
+}
+
+impl Kind {
+    pub fn context(self, source: impl Into<BoxError>) -> Context<Self> {
+        Context::new(self, Some(source.into()))
+    }
+}
@@ -0,0 +1,54 @@
+use std::convert::TryFrom;
+
+use tendermint_proto::Protobuf;
+
+use ibc_proto::ibc::core::client::v1::Height as RawHeight;
+
+use crate::ics02_client::{client_def::AnyHeader, client_type::ClientType};
+use crate::ics09_localhost::error::{Error, Kind};
+use crate::Height;
+
+/// A header of the host chain for a localhost client, which advances the client to the given
+/// height of the host chain. It is encoded as a protobuf `Height`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Header {
+    pub height: Height,
+}
+
+impl Header {
+    pub fn new(height: Height) -> Self {
+        Self { height }
+    }
+}
+
+impl crate::ics02_client::header::Header for Header {
+    fn client_type(&self) -> ClientType {
+        ClientType::Localhost
+    }
+
+    fn height(&self) -> Height {
+        self.height
+    }
+
+    fn wrap_any(self) -> AnyHeader {
+        AnyHeader::Localhost(self)
+    }
+}
+
+impl Protobuf<RawHeight> for Header {}
+
+impl TryFrom<RawHeight> for Header {
+    type Error = Error;
+
+    fn try_from(raw: RawHeight) -> Result<Self, Self::Error> {
+        Ok(Self::new(
+            Height::try_from(raw).map_err(|e| Kind::InvalidRawHeader.context(e))?,
+        ))
+    }
+}
+
+impl From<Header> for RawHeight {
+    fn from(value: Header) -> Self {
+        value.height.into()
+    }
+}
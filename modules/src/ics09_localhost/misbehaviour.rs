@@ -0,0 +1,22 @@
+use crate::ics02_client::client_def::AnyMisbehaviour;
+use crate::ics24_host::identifier::ClientId;
+use crate::Height;
+
+/// The host chain cannot misbehave towards itself, hence there is no evidence of misbehaviour for
+/// localhost clients.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Misbehaviour {}
+
+impl crate::ics02_client::misbehaviour::Misbehaviour for Misbehaviour {
+    fn client_id(&self) -> &ClientId {
+        match *self {}
+    }
+
+    fn height(&self) -> Height {
+        match *self {}
+    }
+
+    fn wrap_any(self) -> AnyMisbehaviour {
+        match self {}
+    }
+}
@@ -52,6 +52,7 @@ where
 
 #[cfg(test)]
 mod tests {
+    use crate::ics02_client::client_def::AnyHeader;
     use crate::ics02_client::client_type::ClientType;
     use crate::ics02_client::header::Header;
     use crate::ics18_relayer::context::ICS18Context;
@@ -76,6 +77,8 @@ mod tests {
         let client_on_a_for_b = ClientId::new(ClientType::Tendermint, 0).unwrap();
         let client_on_b_for_a = ClientId::new(ClientType::Mock, 0).unwrap();
 
+        let chain_id_b = ChainId::new("mockgaiaB".to_string(), 1);
+
         // Create two mock contexts, one for each chain.
         let mut ctx_a = MockContext::new(
             ChainId::new("mockgaiaA".to_string(), 1),
@@ -83,14 +86,15 @@ mod tests {
             5,
             chain_a_start_height,
         )
-        .with_client_parametrized(
+        .with_client_parametrized_with_chain_id(
+            chain_id_b.clone(),
             &client_on_a_for_b,
             client_on_a_for_b_height,
             Some(ClientType::Tendermint), // The target host chain (B) is synthetic TM.
             Some(client_on_a_for_b_height),
         );
         let mut ctx_b = MockContext::new(
-            ChainId::new("mockgaiaB".to_string(), 1),
+            chain_id_b,
             HostType::SyntheticTendermint,
             5,
             chain_b_start_height,
@@ -150,7 +154,7 @@ mod tests {
 
             // Update client on chain B to latest height of B.
             // - create the client update message with the latest header from B
-            let b_latest_header = ctx_b.query_latest_header().unwrap();
+            let mut b_latest_header = ctx_b.query_latest_header().unwrap();
             assert_eq!(
                 b_latest_header.client_type(),
                 ClientType::Tendermint,
@@ -159,6 +163,14 @@ mod tests {
                 ClientType::Tendermint
             );
 
+            // - the header is verified against the latest consensus state of the client on A
+            if let AnyHeader::Tendermint(header) = &mut b_latest_header {
+                header.trusted_height = ctx_a
+                    .query_client_full_state(&client_on_a_for_b)
+                    .unwrap()
+                    .latest_height();
+            }
+
             let client_msg_a_res =
                 build_client_update_datagram(&ctx_a, &client_on_a_for_b, b_latest_header);
             assert_eq!(
@@ -50,6 +50,11 @@ impl CommitmentProofBytes {
     pub fn is_empty(&self) -> bool {
         self.bytes.len() == 0
     }
+
+    /// The size of the proof, by which its verification is charged (see `gas`).
+    pub fn len(&self) -> usize {
+        self.bytes.len()
+    }
 }
 
 impl From<Vec<u8>> for CommitmentProofBytes {
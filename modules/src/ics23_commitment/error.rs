@@ -7,6 +7,27 @@ pub type Error = anomaly::Error<Kind>;
 pub enum Kind {
     #[error("invalid raw merkle proof")]
     InvalidRawMerkleProof,
+
+    #[error("invalid merkle proof")]
+    InvalidMerkleProof,
+
+    #[error("invalid proof spec")]
+    InvalidProofSpec,
+
+    #[error("empty merkle proof")]
+    EmptyMerkleProof,
+
+    #[error("empty merkle root")]
+    EmptyMerkleRoot,
+
+    #[error("mismatch between the number of proof specs ({0}) and proofs ({1})")]
+    NumberOfSpecsMismatch(usize, usize),
+
+    #[error("mismatch between the number of keys ({0}) and proofs ({1})")]
+    NumberOfKeysMismatch(usize, usize),
+
+    #[error("failed to verify the merkle proof")]
+    VerificationFailure,
 }
 
 impl Kind {
@@ -1,8 +1,13 @@
+use std::convert::TryFrom;
+
 use ibc_proto::ibc::core::commitment::v1::MerklePath;
 use ibc_proto::ibc::core::commitment::v1::MerkleProof as RawMerkleProof;
+use ics23::commitment_proof::Proof as Ics23Proof;
+use ics23::{calculate_existence_root, verify_membership, verify_non_membership};
+use prost::Message;
 
-use crate::ics23_commitment::commitment::{CommitmentPrefix, CommitmentProofBytes};
-use crate::ics23_commitment::error::Error;
+use crate::ics23_commitment::commitment::{CommitmentPrefix, CommitmentProofBytes, CommitmentRoot};
+use crate::ics23_commitment::error::{Error, Kind};
 use tendermint::merkle::proof::Proof;
 
 pub fn apply_prefix(
@@ -64,9 +69,187 @@ pub fn cosmos_specs() -> Vec<ibc_proto::ics23::ProofSpec> {
     ]
 }
 
+/// Domain type for a Merkle proof: a chain of ICS23 commitment proofs, ordered from the proof
+/// for the key in the lowest (IAVL) store up to the proof for that store in the multistore root.
 #[derive(Clone, Debug, PartialEq)]
 pub struct MerkleProof {
-    pub proof: Vec<CommitmentProofBytes>,
+    pub proofs: Vec<ics23::CommitmentProof>,
+}
+
+impl MerkleProof {
+    /// Verifies that `value` is stored at the path `keys` in the tree committed to by `root`.
+    pub fn verify_membership(
+        &self,
+        specs: &[ibc_proto::ics23::ProofSpec],
+        root: &CommitmentRoot,
+        keys: MerklePath,
+        value: Vec<u8>,
+    ) -> Result<(), Error> {
+        self.check_inputs(specs, root, &keys)?;
+
+        let specs = convert_specs(specs)?;
+        let keys: Vec<&String> = keys.key_path.iter().rev().collect();
+
+        verify_chained_membership(&self.proofs, &specs, root, &keys, value)
+    }
+
+    /// Verifies that no value is stored at the path `keys` in the tree committed to by `root`.
+    pub fn verify_non_membership(
+        &self,
+        specs: &[ibc_proto::ics23::ProofSpec],
+        root: &CommitmentRoot,
+        keys: MerklePath,
+    ) -> Result<(), Error> {
+        self.check_inputs(specs, root, &keys)?;
+
+        let specs = convert_specs(specs)?;
+        let keys: Vec<&String> = keys.key_path.iter().rev().collect();
+
+        // The first proof is an absence proof for the key in the lowest store. Its subroot is
+        // then proven to be part of the upper stores, as any other value.
+        let proof = &self.proofs[0];
+        let subroot = match &proof.proof {
+            Some(Ics23Proof::Nonexist(non_existence_proof)) => {
+                let neighbour = non_existence_proof
+                    .left
+                    .as_ref()
+                    .or(non_existence_proof.right.as_ref())
+                    .ok_or_else(|| Kind::InvalidMerkleProof.context("empty absence proof"))?;
+                calculate_existence_root(neighbour)
+                    .map_err(|e| Kind::InvalidMerkleProof.context(e.to_string()))?
+            }
+            _ => {
+                return Err(Kind::InvalidMerkleProof
+                    .context("expected an absence proof")
+                    .into())
+            }
+        };
+
+        if !verify_non_membership(proof, &specs[0], &subroot, keys[0].as_bytes()) {
+            return Err(Kind::VerificationFailure.context(keys[0].clone()).into());
+        }
+
+        verify_chained_membership(&self.proofs[1..], &specs[1..], root, &keys[1..], subroot)
+    }
+
+    fn check_inputs(
+        &self,
+        specs: &[ibc_proto::ics23::ProofSpec],
+        root: &CommitmentRoot,
+        keys: &MerklePath,
+    ) -> Result<(), Error> {
+        if self.proofs.is_empty() {
+            return Err(Kind::EmptyMerkleProof.into());
+        }
+        if root.as_bytes().is_empty() {
+            return Err(Kind::EmptyMerkleRoot.into());
+        }
+        if specs.len() != self.proofs.len() {
+            return Err(Kind::NumberOfSpecsMismatch(specs.len(), self.proofs.len()).into());
+        }
+        if keys.key_path.len() != self.proofs.len() {
+            return Err(Kind::NumberOfKeysMismatch(keys.key_path.len(), self.proofs.len()).into());
+        }
+        Ok(())
+    }
+}
+
+/// Verifies each existence proof in `proofs` against the subroot computed by the next one, starting
+/// with `value` and ending with `root`. The `keys` are ordered from the lowest store upwards.
+fn verify_chained_membership(
+    proofs: &[ics23::CommitmentProof],
+    specs: &[ics23::ProofSpec],
+    root: &CommitmentRoot,
+    keys: &[&String],
+    mut value: Vec<u8>,
+) -> Result<(), Error> {
+    for ((proof, spec), key) in proofs.iter().zip(specs).zip(keys) {
+        let subroot = match &proof.proof {
+            Some(Ics23Proof::Exist(existence_proof)) => {
+                calculate_existence_root(existence_proof)
+                    .map_err(|e| Kind::InvalidMerkleProof.context(e.to_string()))?
+            }
+            _ => {
+                return Err(Kind::InvalidMerkleProof
+                    .context("expected an existence proof")
+                    .into())
+            }
+        };
+
+        if !verify_membership(proof, spec, &subroot, key.as_bytes(), &value) {
+            return Err(Kind::VerificationFailure.context(key.to_string()).into());
+        }
+
+        value = subroot;
+    }
+
+    if root.as_bytes() != value.as_slice() {
+        return Err(Kind::VerificationFailure
+            .context("computed root does not match the commitment root")
+            .into());
+    }
+
+    Ok(())
+}
+
+/// The proof specs are given in their protobuf representation; re-encode them as the types that
+/// the `ics23` crate works with.
+fn convert_specs(specs: &[ibc_proto::ics23::ProofSpec]) -> Result<Vec<ics23::ProofSpec>, Error> {
+    specs
+        .iter()
+        .map(|spec| {
+            ics23::ProofSpec::decode(reencode(spec).as_slice())
+                .map_err(|e| Kind::InvalidProofSpec.context(e).into())
+        })
+        .collect()
+}
+
+fn reencode(message: &impl Message) -> Vec<u8> {
+    let mut buf = Vec::new();
+    // Encoding into a `Vec` cannot run out of buffer space.
+    message.encode(&mut buf).unwrap();
+    buf
+}
+
+impl TryFrom<RawMerkleProof> for MerkleProof {
+    type Error = Error;
+
+    fn try_from(value: RawMerkleProof) -> Result<Self, Self::Error> {
+        let proofs = value
+            .proofs
+            .iter()
+            .map(|proof| {
+                ics23::CommitmentProof::decode(reencode(proof).as_slice())
+                    .map_err(|e| Kind::InvalidRawMerkleProof.context(e).into())
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        Ok(MerkleProof { proofs })
+    }
+}
+
+impl From<MerkleProof> for RawMerkleProof {
+    fn from(value: MerkleProof) -> Self {
+        RawMerkleProof {
+            proofs: value
+                .proofs
+                .iter()
+                .map(|proof| {
+                    // Both types are generated from the same protobuf definition.
+                    ibc_proto::ics23::CommitmentProof::decode(reencode(proof).as_slice()).unwrap()
+                })
+                .collect(),
+        }
+    }
+}
+
+impl TryFrom<&CommitmentProofBytes> for MerkleProof {
+    type Error = Error;
+
+    fn try_from(value: &CommitmentProofBytes) -> Result<Self, Self::Error> {
+        let raw_proof = RawMerkleProof::try_from(value.clone())?;
+        MerkleProof::try_from(raw_proof)
+    }
 }
 
 // Merkle Proof serialization notes:
@@ -83,8 +266,8 @@ pub struct MerkleProof {
 //  - Vec<u8>: RawMerkleProof is not explicitly used but, serialized as Vec<u8>, it is
 //       included in all handshake messages that require proofs (i.e. all except the two `OpenInit`),
 //       and also in all queries that require proofs
-//  - MerkleProof: Domain type for RawMerkleProof, currently not used and identical to RawMerkleProof.
-//       This will change with verification implementation.
+//  - MerkleProof: Domain type for RawMerkleProof, holding the `ics23` crate representation of the
+//       proofs, which is used for verification.
 //  - CommitmentProof: Defined in ibc-rs as Vec<u8> and currently used in all its messages
 //
 // Here are a couple of flows that illustrate the different conversions:
@@ -94,7 +277,7 @@ pub struct MerkleProof {
 // Relayer: from the proof in the  query response to the proof being included in a message
 //    TmProof -> RawProofOps => RawMerkleProof -> MerkleProof -> verify()
 //      -> MerkleProof -> RawMerkleProof -> CommitmentProof -> Vec<u8>
-// Note: current implementation for ^ is simplified since verification is not yet implemented:
+// Note: the relayer does not verify the proofs it queries, so this is simplified to:
 //    TmProof -> RawProofOps => RawMerkleProof -> CommitmentProof -> Vec<u8>
 //
 // Implementations of (de)serializers and conversions:
@@ -107,21 +290,6 @@ pub struct MerkleProof {
 //      TmProof <-> RawProofOps
 //  - cosmos.rs:abci_query() converts from query proof to Merkle proof:
 //      RawProofOps => RawMerkleProof
-//
-// impl TryFrom<RawMerkleProof> for MerkleProof {
-//     type Error = Error;
-//     fn try_from(value: RawMerkleProof) -> Result<Self, Self::Error> {
-//         Ok(MerkleProof { proof: value.proofs.into_iter().map(|v| v.into()).collect() })
-//     }
-// }
-//
-// impl From<MerkleProof> for RawMerkleProof {
-//     fn from(value: MerkleProof) -> Self {
-//         RawMerkleProof { proof: value.proof }
-//     }
-// }
-
-use prost::Message;
 
 pub fn convert_tm_to_ics_merkle_proof(
     tm_proof: Option<Proof>,
@@ -139,3 +307,309 @@ pub fn convert_tm_to_ics_merkle_proof(
         Ok(None)
     }
 }
+
+#[cfg(test)]
+pub mod test_util {
+    use ibc_proto::ibc::core::commitment::v1::MerkleProof as RawMerkleProof;
+    use ics23::commitment_proof::Proof as Ics23Proof;
+    use ics23::{
+        CommitmentProof, ExistenceProof, HashOp, InnerOp, LeafOp, LengthOp, NonExistenceProof,
+    };
+    use sha2::{Digest, Sha256};
+
+    use crate::ics23_commitment::commitment::{CommitmentProofBytes, CommitmentRoot};
+    use crate::ics23_commitment::merkle::MerkleProof;
+
+    /// IAVL metadata (height, size and version of a node) which prefixes the inner nodes.
+    const IAVL_NODE_METADATA: [u8; 4] = [2, 4, 0x90, 0x03];
+
+    /// A minimal model of the state of a Cosmos SDK chain, from which ICS23 proofs can be built:
+    /// a multistore with two stores, `bank` and `ibc`, where the `ibc` store is an IAVL tree that
+    /// contains two entries.
+    pub struct DummyStore {
+        entries: [(Vec<u8>, Vec<u8>); 2],
+    }
+
+    impl DummyStore {
+        pub fn new(first: (String, Vec<u8>), second: (String, Vec<u8>)) -> Self {
+            let mut entries = [
+                (first.0.into_bytes(), first.1),
+                (second.0.into_bytes(), second.1),
+            ];
+            entries.sort();
+            Self { entries }
+        }
+
+        /// The root of the multistore, as found in the app hash of a block header.
+        pub fn root(&self) -> CommitmentRoot {
+            let ibc_root = self.ibc_root();
+            let proof = multistore_proof(ibc_root);
+            CommitmentRoot::from(ics23::calculate_existence_root(&proof).unwrap())
+        }
+
+        /// Proves that the entry with the given `key` is in the `ibc` store.
+        pub fn existence_proof(&self, key: &str) -> CommitmentProofBytes {
+            let idx = self
+                .entries
+                .iter()
+                .position(|(k, _)| k.as_slice() == key.as_bytes())
+                .expect("no entry for this key");
+
+            self.proof(Ics23Proof::Exist(self.iavl_proof(idx)))
+        }
+
+        /// Proves that no entry exists in the `ibc` store for any key that sorts strictly between
+        /// the keys of the two entries of the store.
+        pub fn absence_proof(&self, key: &str) -> CommitmentProofBytes {
+            assert!(self.entries[0].0.as_slice() < key.as_bytes());
+            assert!(key.as_bytes() < self.entries[1].0.as_slice());
+
+            self.proof(Ics23Proof::Nonexist(NonExistenceProof {
+                key: key.as_bytes().to_vec(),
+                left: Some(self.iavl_proof(0)),
+                right: Some(self.iavl_proof(1)),
+            }))
+        }
+
+        fn proof(&self, ibc_proof: Ics23Proof) -> CommitmentProofBytes {
+            let proof = MerkleProof {
+                proofs: vec![
+                    CommitmentProof {
+                        proof: Some(ibc_proof),
+                    },
+                    CommitmentProof {
+                        proof: Some(Ics23Proof::Exist(multistore_proof(self.ibc_root()))),
+                    },
+                ],
+            };
+            RawMerkleProof::from(proof).into()
+        }
+
+        fn ibc_root(&self) -> Vec<u8> {
+            ics23::calculate_existence_root(&self.iavl_proof(0)).unwrap()
+        }
+
+        /// Existence proof for one of the two leaves of the IAVL tree.
+        fn iavl_proof(&self, idx: usize) -> ExistenceProof {
+            let (key, value) = &self.entries[idx];
+            let sibling = &self.entries[1 - idx];
+            let sibling_hash = leaf_hash(&iavl_leaf(), &sibling.0, &sibling.1);
+
+            let mut prefix = IAVL_NODE_METADATA.to_vec();
+            let mut suffix = vec![];
+            if idx == 0 {
+                prefix.push(32);
+                suffix.push(32);
+                suffix.extend(sibling_hash);
+            } else {
+                prefix.push(32);
+                prefix.extend(sibling_hash);
+                prefix.push(32);
+            }
+
+            ExistenceProof {
+                key: key.clone(),
+                value: value.clone(),
+                leaf: Some(iavl_leaf()),
+                path: vec![InnerOp {
+                    hash: HashOp::Sha256.into(),
+                    prefix,
+                    suffix,
+                }],
+            }
+        }
+    }
+
+    /// Existence proof for the `ibc` store, with the given root, in the multistore. The `ibc` store
+    /// is the right sibling of the `bank` store.
+    fn multistore_proof(ibc_root: Vec<u8>) -> ExistenceProof {
+        let bank_hash = leaf_hash(&simple_leaf(), b"bank", &Sha256::digest(b"bank store"));
+
+        let mut prefix = vec![1];
+        prefix.extend(bank_hash);
+
+        ExistenceProof {
+            key: b"ibc".to_vec(),
+            value: ibc_root,
+            leaf: Some(simple_leaf()),
+            path: vec![InnerOp {
+                hash: HashOp::Sha256.into(),
+                prefix,
+                suffix: vec![],
+            }],
+        }
+    }
+
+    fn iavl_leaf() -> LeafOp {
+        LeafOp {
+            hash: HashOp::Sha256.into(),
+            prehash_key: HashOp::NoHash.into(),
+            prehash_value: HashOp::Sha256.into(),
+            length: LengthOp::VarProto.into(),
+            prefix: vec![0, 2, 0x90, 0x03],
+        }
+    }
+
+    fn simple_leaf() -> LeafOp {
+        LeafOp {
+            hash: HashOp::Sha256.into(),
+            prehash_key: HashOp::NoHash.into(),
+            prehash_value: HashOp::Sha256.into(),
+            length: LengthOp::VarProto.into(),
+            prefix: vec![0],
+        }
+    }
+
+    fn leaf_hash(leaf: &LeafOp, key: &[u8], value: &[u8]) -> Vec<u8> {
+        let proof = ExistenceProof {
+            key: key.to_vec(),
+            value: value.to_vec(),
+            leaf: Some(leaf.clone()),
+            path: vec![],
+        };
+        ics23::calculate_existence_root(&proof).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use ibc_proto::ibc::core::commitment::v1::MerklePath;
+
+    use crate::ics23_commitment::commitment::{CommitmentPrefix, CommitmentRoot};
+    use crate::ics23_commitment::merkle::test_util::DummyStore;
+    use crate::ics23_commitment::merkle::{apply_prefix, cosmos_specs, MerkleProof};
+
+    fn path(key: &str) -> MerklePath {
+        apply_prefix(
+            &CommitmentPrefix::from(b"ibc".to_vec()),
+            vec![key.to_string()],
+        )
+        .unwrap()
+    }
+
+    fn store() -> DummyStore {
+        DummyStore::new(
+            (
+                "clients/07-tendermint-0/clientState".to_string(),
+                b"client".to_vec(),
+            ),
+            (
+                "connections/connection-0".to_string(),
+                b"connection".to_vec(),
+            ),
+        )
+    }
+
+    #[test]
+    fn verify_membership() {
+        let store = store();
+        let root = store.root();
+        let key = "connections/connection-0";
+        let proof = MerkleProof::try_from(&store.existence_proof(key)).unwrap();
+
+        // The proof is valid for the stored value.
+        assert!(proof
+            .verify_membership(&cosmos_specs(), &root, path(key), b"connection".to_vec())
+            .is_ok());
+
+        // The proof is not valid for any other value.
+        assert!(proof
+            .verify_membership(&cosmos_specs(), &root, path(key), b"other".to_vec())
+            .is_err());
+
+        // The proof is not valid for another key.
+        assert!(proof
+            .verify_membership(
+                &cosmos_specs(),
+                &root,
+                path("connections/connection-1"),
+                b"connection".to_vec()
+            )
+            .is_err());
+
+        // The proof is not valid for another store.
+        let mut other_store = path(key);
+        other_store.key_path[0] = "bank".to_string();
+        assert!(proof
+            .verify_membership(&cosmos_specs(), &root, other_store, b"connection".to_vec())
+            .is_err());
+
+        // The proof is not valid against another root.
+        let mut other_root = root.clone().into_vec();
+        other_root[0] ^= 1;
+        assert!(proof
+            .verify_membership(
+                &cosmos_specs(),
+                &CommitmentRoot::from(other_root),
+                path(key),
+                b"connection".to_vec()
+            )
+            .is_err());
+
+        // An existence proof does not prove absence.
+        assert!(proof
+            .verify_non_membership(&cosmos_specs(), &root, path(key))
+            .is_err());
+    }
+
+    #[test]
+    fn verify_non_membership() {
+        let store = store();
+        let root = store.root();
+        let key = "commitments/ports/transfer/channels/channel-0/sequences/1";
+        let proof = MerkleProof::try_from(&store.absence_proof(key)).unwrap();
+
+        assert!(proof
+            .verify_non_membership(&cosmos_specs(), &root, path(key))
+            .is_ok());
+
+        // The proof does not cover keys outside of the range between its two neighbours.
+        assert!(proof
+            .verify_non_membership(&cosmos_specs(), &root, path("receipts/ports/transfer"))
+            .is_err());
+
+        // An absence proof does not prove membership.
+        assert!(proof
+            .verify_membership(&cosmos_specs(), &root, path(key), b"value".to_vec())
+            .is_err());
+    }
+
+    #[test]
+    fn verify_membership_with_malformed_inputs() {
+        let store = store();
+        let root = store.root();
+        let key = "connections/connection-0";
+        let value = b"connection".to_vec();
+        let proof = MerkleProof::try_from(&store.existence_proof(key)).unwrap();
+
+        // Missing spec for the multistore proof.
+        assert!(proof
+            .verify_membership(&cosmos_specs()[..1], &root, path(key), value.clone())
+            .is_err());
+
+        // Missing prefix.
+        let unprefixed = MerklePath {
+            key_path: vec![key.to_string()],
+        };
+        assert!(proof
+            .verify_membership(&cosmos_specs(), &root, unprefixed, value.clone())
+            .is_err());
+
+        // Empty root.
+        assert!(proof
+            .verify_membership(
+                &cosmos_specs(),
+                &CommitmentRoot::from(vec![]),
+                path(key),
+                value.clone()
+            )
+            .is_err());
+
+        // Empty proof.
+        assert!(MerkleProof { proofs: vec![] }
+            .verify_membership(&cosmos_specs(), &root, path(key), value)
+            .is_err());
+    }
+}
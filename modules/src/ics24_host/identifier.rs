@@ -146,6 +146,8 @@ impl ClientId {
     pub fn prefix(client_type: ClientType) -> &'static str {
         match client_type {
             ClientType::Tendermint => ClientType::Tendermint.as_string(),
+            ClientType::SoloMachine => ClientType::SoloMachine.as_string(),
+            ClientType::Localhost => ClientType::Localhost.as_string(),
 
             #[cfg(any(test, feature = "mocks"))]
             ClientType::Mock => ClientType::Mock.as_string(),
@@ -283,6 +285,19 @@ impl Default for PortId {
 pub struct ChannelId(String);
 
 impl ChannelId {
+    /// Builds a new channel identifier. Like client identifiers, channel identifiers are
+    /// deterministically formed from a prefix, "channel", and a monotonically increasing
+    /// `counter`, separated by a dash "-".
+    ///
+    /// ```
+    /// # use ibc::ics24_host::identifier::ChannelId;
+    /// let channel_id = ChannelId::new(11);
+    /// assert_eq!(channel_id.as_str(), "channel-11");
+    /// ```
+    pub fn new(counter: u64) -> Self {
+        Self(format!("channel-{}", counter))
+    }
+
     /// Get this identifier as a borrowed `&str`
     pub fn as_str(&self) -> &str {
         &self.0
@@ -3,5 +3,10 @@
 pub mod error;
 pub mod identifier;
 mod path;
-pub use path::{Path, IBC_QUERY_PATH};
+pub use path::{
+    ClientUpgradePath, Path, IBC_QUERY_PATH, NEXT_CHANNEL_SEQUENCE, NEXT_CLIENT_SEQUENCE,
+    NEXT_CONNECTION_SEQUENCE, SDK_UPGRADE_QUERY_PATH, UPGRADED_CLIENT_CONSENSUS_STATE,
+    UPGRADED_CLIENT_STATE, UPGRADED_IBC_STATE,
+};
+pub mod store;
 pub mod validate;
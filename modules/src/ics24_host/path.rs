@@ -3,15 +3,34 @@ use crate::ics04_channel::packet::Sequence;
 /// https://github.com/cosmos/ics/tree/master/spec/ics-024-host-requirements#path-space
 /// Some of these are implemented in other ICSs, but ICS-024 has a nice summary table.
 ///
+use crate::ics24_host::error::{ValidationError, ValidationKind};
 use crate::ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId};
+use std::convert::TryFrom;
 use std::fmt::{Display, Formatter, Result};
+use std::str::FromStr;
 
 /// IBC Query Path is hard-coded
 pub const IBC_QUERY_PATH: &str = "store/ibc/key";
 
+/// Query path of the store of the Cosmos SDK upgrade module
+pub const SDK_UPGRADE_QUERY_PATH: &str = "store/upgrade/key";
+
+/// Keys used by the Cosmos SDK upgrade module for the states of the upgraded chain
+pub const UPGRADED_IBC_STATE: &str = "upgradedIBCState";
+pub const UPGRADED_CLIENT_STATE: &str = "upgradedClient";
+pub const UPGRADED_CLIENT_CONSENSUS_STATE: &str = "upgradedConsState";
+
+/// Keys of the counters used to allocate client, connection and channel identifiers
+pub const NEXT_CLIENT_SEQUENCE: &str = "nextClientSequence";
+pub const NEXT_CONNECTION_SEQUENCE: &str = "nextConnectionSequence";
+pub const NEXT_CHANNEL_SEQUENCE: &str = "nextChannelSequence";
+
 /// The Path enum abstracts out the different sub-paths
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum Path {
+    NextClientSequence,
+    NextConnectionSequence,
+    NextChannelSequence,
     ClientType(ClientId),
     ClientState(ClientId),
     ClientConsensusState {
@@ -19,6 +38,16 @@ pub enum Path {
         epoch: u64,
         height: u64,
     },
+    ClientConsensusProcessedTime {
+        client_id: ClientId,
+        epoch: u64,
+        height: u64,
+    },
+    ClientConsensusProcessedHeight {
+        client_id: ClientId,
+        epoch: u64,
+        height: u64,
+    },
     ClientConnections(ClientId),
     Connections(ConnectionId),
     Ports(PortId),
@@ -41,6 +70,7 @@ pub enum Path {
         channel_id: ChannelId,
         sequence: Sequence,
     },
+    Upgrade(ClientUpgradePath),
 }
 
 impl Path {
@@ -60,6 +90,9 @@ impl Path {
 impl Display for Path {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         match &self {
+            Path::NextClientSequence => write!(f, "{}", NEXT_CLIENT_SEQUENCE),
+            Path::NextConnectionSequence => write!(f, "{}", NEXT_CONNECTION_SEQUENCE),
+            Path::NextChannelSequence => write!(f, "{}", NEXT_CHANNEL_SEQUENCE),
             Path::ClientType(client_id) => write!(f, "clients/{}/clientType", client_id),
             Path::ClientState(client_id) => write!(f, "clients/{}/clientState", client_id),
             Path::ClientConsensusState {
@@ -71,6 +104,24 @@ impl Display for Path {
                 "clients/{}/consensusStates/{}-{}",
                 client_id, epoch, height
             ),
+            Path::ClientConsensusProcessedTime {
+                client_id,
+                epoch,
+                height,
+            } => write!(
+                f,
+                "clients/{}/consensusStates/{}-{}/processedTime",
+                client_id, epoch, height
+            ),
+            Path::ClientConsensusProcessedHeight {
+                client_id,
+                epoch,
+                height,
+            } => write!(
+                f,
+                "clients/{}/consensusStates/{}-{}/processedHeight",
+                client_id, epoch, height
+            ),
             Path::ClientConnections(client_id) => write!(f, "clients/{}/connections", client_id),
             Path::Connections(connection_id) => write!(f, "connections/{}", connection_id),
             Path::Ports(port_id) => write!(f, "ports/{}", port_id),
@@ -119,6 +170,286 @@ impl Display for Path {
                 "receipts/ports/{}/channels/{}/sequences/{}",
                 port_id, channel_id, sequence
             ),
+            Path::Upgrade(upgrade_path) => write!(f, "{}", upgrade_path),
         }
     }
 }
+
+/// Parses a path from its string representation, as found in the keys of the store,
+/// such that `Path::from_str(&path.to_string())` gives back `path`.
+impl FromStr for Path {
+    type Err = ValidationError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let invalid = || ValidationKind::invalid_path(s.to_string());
+        let segments: Vec<&str> = s.split('/').collect();
+
+        let path = match segments.as_slice() {
+            [NEXT_CLIENT_SEQUENCE] => Path::NextClientSequence,
+            [NEXT_CONNECTION_SEQUENCE] => Path::NextConnectionSequence,
+            [NEXT_CHANNEL_SEQUENCE] => Path::NextChannelSequence,
+            ["clients", client_id, "clientType"] => Path::ClientType(client_id.parse()?),
+            ["clients", client_id, "clientState"] => Path::ClientState(client_id.parse()?),
+            ["clients", client_id, "connections"] => Path::ClientConnections(client_id.parse()?),
+            ["clients", client_id, "consensusStates", height] => {
+                let (epoch, height) = parse_height(height).ok_or_else(invalid)?;
+                Path::ClientConsensusState {
+                    client_id: client_id.parse()?,
+                    epoch,
+                    height,
+                }
+            }
+            ["clients", client_id, "consensusStates", height, "processedTime"] => {
+                let (epoch, height) = parse_height(height).ok_or_else(invalid)?;
+                Path::ClientConsensusProcessedTime {
+                    client_id: client_id.parse()?,
+                    epoch,
+                    height,
+                }
+            }
+            ["clients", client_id, "consensusStates", height, "processedHeight"] => {
+                let (epoch, height) = parse_height(height).ok_or_else(invalid)?;
+                Path::ClientConsensusProcessedHeight {
+                    client_id: client_id.parse()?,
+                    epoch,
+                    height,
+                }
+            }
+            ["connections", connection_id] => Path::Connections(connection_id.parse()?),
+            ["ports", port_id] => Path::Ports(port_id.parse()?),
+            ["channelEnds", "ports", port_id, "channels", channel_id] => {
+                Path::ChannelEnds(port_id.parse()?, channel_id.parse()?)
+            }
+            ["seqSends", "ports", port_id, "channels", channel_id, "nextSequenceSend"] => {
+                Path::SeqSends(port_id.parse()?, channel_id.parse()?)
+            }
+            ["seqRecvs", "ports", port_id, "channels", channel_id, "nextSequenceRecv"] => {
+                Path::SeqRecvs(port_id.parse()?, channel_id.parse()?)
+            }
+            ["seqAcks", "ports", port_id, "channels", channel_id, "nextSequenceAck"] => {
+                Path::SeqAcks(port_id.parse()?, channel_id.parse()?)
+            }
+            ["commitments", "ports", port_id, "channels", channel_id, "sequences", sequence] => {
+                Path::Commitments {
+                    port_id: port_id.parse()?,
+                    channel_id: channel_id.parse()?,
+                    sequence: parse_sequence(sequence).ok_or_else(invalid)?,
+                }
+            }
+            ["acks", "ports", port_id, "channels", channel_id, "sequences", sequence] => {
+                Path::Acks {
+                    port_id: port_id.parse()?,
+                    channel_id: channel_id.parse()?,
+                    sequence: parse_sequence(sequence).ok_or_else(invalid)?,
+                }
+            }
+            ["receipts", "ports", port_id, "channels", channel_id, "sequences", sequence] => {
+                Path::Receipts {
+                    port_id: port_id.parse()?,
+                    channel_id: channel_id.parse()?,
+                    sequence: parse_sequence(sequence).ok_or_else(invalid)?,
+                }
+            }
+            [UPGRADED_IBC_STATE, height, UPGRADED_CLIENT_STATE] => Path::Upgrade(
+                ClientUpgradePath::UpgradedClientState(height.parse().map_err(|_| invalid())?),
+            ),
+            [UPGRADED_IBC_STATE, height, UPGRADED_CLIENT_CONSENSUS_STATE] => {
+                Path::Upgrade(ClientUpgradePath::UpgradedClientConsensusState(
+                    height.parse().map_err(|_| invalid())?,
+                ))
+            }
+            _ => return Err(invalid().into()),
+        };
+
+        Ok(path)
+    }
+}
+
+impl TryFrom<&[u8]> for Path {
+    type Error = ValidationError;
+
+    fn try_from(bytes: &[u8]) -> std::result::Result<Self, Self::Error> {
+        let s = std::str::from_utf8(bytes).map_err(|e| {
+            ValidationKind::invalid_path(String::from_utf8_lossy(bytes).into_owned()).context(e)
+        })?;
+        Path::from_str(s)
+    }
+}
+
+/// Parses a height of the form `{epoch}-{height}`.
+fn parse_height(s: &str) -> Option<(u64, u64)> {
+    let (epoch, height) = s.split_once('-')?;
+    Some((epoch.parse().ok()?, height.parse().ok()?))
+}
+
+fn parse_sequence(s: &str) -> Option<Sequence> {
+    s.parse::<u64>().ok().map(Sequence::from)
+}
+
+/// Paths in the store of the Cosmos SDK upgrade module, where a chain which plans an upgrade at
+/// the given height commits to the client and consensus states of the upgraded chain.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ClientUpgradePath {
+    UpgradedClientState(u64),
+    UpgradedClientConsensusState(u64),
+}
+
+impl Display for ClientUpgradePath {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            ClientUpgradePath::UpgradedClientState(height) => write!(
+                f,
+                "{}/{}/{}",
+                UPGRADED_IBC_STATE, height, UPGRADED_CLIENT_STATE
+            ),
+            ClientUpgradePath::UpgradedClientConsensusState(height) => write!(
+                f,
+                "{}/{}/{}",
+                UPGRADED_IBC_STATE, height, UPGRADED_CLIENT_CONSENSUS_STATE
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use std::str::FromStr;
+
+    use crate::ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId};
+    use crate::ics24_host::{ClientUpgradePath, Path};
+
+    #[test]
+    fn path_spec_table() {
+        let client_id = || ClientId::from_str("07-tendermint-0").unwrap();
+        let port_id = || PortId::from_str("transfer").unwrap();
+        let channel_id = || ChannelId::from_str("channel-7").unwrap();
+
+        let spec = vec![
+            (Path::NextClientSequence, "nextClientSequence"),
+            (Path::NextConnectionSequence, "nextConnectionSequence"),
+            (Path::NextChannelSequence, "nextChannelSequence"),
+            (
+                Path::ClientType(client_id()),
+                "clients/07-tendermint-0/clientType",
+            ),
+            (
+                Path::ClientState(client_id()),
+                "clients/07-tendermint-0/clientState",
+            ),
+            (
+                Path::ClientConsensusState {
+                    client_id: client_id(),
+                    epoch: 1,
+                    height: 15,
+                },
+                "clients/07-tendermint-0/consensusStates/1-15",
+            ),
+            (
+                Path::ClientConsensusProcessedTime {
+                    client_id: client_id(),
+                    epoch: 1,
+                    height: 15,
+                },
+                "clients/07-tendermint-0/consensusStates/1-15/processedTime",
+            ),
+            (
+                Path::ClientConsensusProcessedHeight {
+                    client_id: client_id(),
+                    epoch: 1,
+                    height: 15,
+                },
+                "clients/07-tendermint-0/consensusStates/1-15/processedHeight",
+            ),
+            (
+                Path::ClientConnections(client_id()),
+                "clients/07-tendermint-0/connections",
+            ),
+            (
+                Path::Connections(ConnectionId::from_str("connection-3").unwrap()),
+                "connections/connection-3",
+            ),
+            (Path::Ports(port_id()), "ports/transfer"),
+            (
+                Path::ChannelEnds(port_id(), channel_id()),
+                "channelEnds/ports/transfer/channels/channel-7",
+            ),
+            (
+                Path::SeqSends(port_id(), channel_id()),
+                "seqSends/ports/transfer/channels/channel-7/nextSequenceSend",
+            ),
+            (
+                Path::SeqRecvs(port_id(), channel_id()),
+                "seqRecvs/ports/transfer/channels/channel-7/nextSequenceRecv",
+            ),
+            (
+                Path::SeqAcks(port_id(), channel_id()),
+                "seqAcks/ports/transfer/channels/channel-7/nextSequenceAck",
+            ),
+            (
+                Path::Commitments {
+                    port_id: port_id(),
+                    channel_id: channel_id(),
+                    sequence: 42.into(),
+                },
+                "commitments/ports/transfer/channels/channel-7/sequences/42",
+            ),
+            (
+                Path::Acks {
+                    port_id: port_id(),
+                    channel_id: channel_id(),
+                    sequence: 42.into(),
+                },
+                "acks/ports/transfer/channels/channel-7/sequences/42",
+            ),
+            (
+                Path::Receipts {
+                    port_id: port_id(),
+                    channel_id: channel_id(),
+                    sequence: 42.into(),
+                },
+                "receipts/ports/transfer/channels/channel-7/sequences/42",
+            ),
+            (
+                Path::Upgrade(ClientUpgradePath::UpgradedClientState(100)),
+                "upgradedIBCState/100/upgradedClient",
+            ),
+            (
+                Path::Upgrade(ClientUpgradePath::UpgradedClientConsensusState(100)),
+                "upgradedIBCState/100/upgradedConsState",
+            ),
+        ];
+
+        for (path, key) in spec {
+            assert_eq!(path.to_string(), key);
+            assert_eq!(Path::from_str(key).unwrap(), path, "parsing {}", key);
+            assert_eq!(Path::try_from(key.as_bytes()).unwrap(), path);
+        }
+    }
+
+    #[test]
+    fn parse_invalid_paths() {
+        let keys = vec![
+            "",
+            "clients",
+            "nextClientSequence/1",
+            "clients/07-tendermint-0/unknown",
+            "clients/07-tendermint-0/consensusStates/15",
+            "clients/07-tendermint-0/consensusStates/1-x",
+            "clients/07-tendermint-0/consensusStates/1-15/processed",
+            "clients/a/clientState",
+            "connections/connection-3/extra",
+            "channelEnds/ports/transfer/channels",
+            "commitments/ports/transfer/channels/channel-7/sequences/-1",
+            "seqSends/ports/transfer/channels/channel-7/nextSequenceRecv",
+            "upgradedIBCState/latest/upgradedClient",
+            "/connections/connection-3",
+        ];
+
+        for key in keys {
+            assert!(Path::from_str(key).is_err(), "parsing {}", key);
+        }
+
+        assert!(Path::try_from(&[0xff, 0xfe][..]).is_err());
+    }
+}
@@ -0,0 +1,999 @@
+//! The provable store of a host chain, and the implementation of the ICS2, ICS3 and ICS4 readers
+//! and keepers for any host that keeps its IBC state in such a store, laid out as in ICS24. The
+//! domain types are stored in their protobuf encoding; the readers report values that fail to
+//! decode as missing.
+
+use std::collections::BTreeSet;
+use std::convert::TryFrom;
+use std::str::FromStr;
+use std::time::Duration;
+
+use ibc_proto::ibc::core::connection::v1::ClientPaths;
+use prost::Message;
+use tendermint::Time;
+use tendermint_proto::Protobuf;
+
+use crate::gas::{GasMeter, OutOfGas};
+use crate::ics02_client::client_def::{AnyClientState, AnyConsensusState};
+use crate::ics02_client::client_type::ClientType;
+use crate::ics02_client::context::{ClientKeeper, ClientReader};
+use crate::ics02_client::error::{Error as ICS2Error, Kind as ICS2Kind};
+use crate::ics03_connection::connection::ConnectionEnd;
+use crate::ics03_connection::context::{ConnectionKeeper, ConnectionReader};
+use crate::ics03_connection::error::{Error as ICS3Error, Kind as ICS3Kind};
+use crate::ics04_channel::channel::ChannelEnd;
+use crate::ics04_channel::commitment::{AcknowledgementCommitment, PacketCommitment};
+use crate::ics04_channel::context::{ChannelKeeper, ChannelReader};
+use crate::ics04_channel::error::{Error as ICS4Error, Kind as ICS4Kind};
+use crate::ics04_channel::packet::{Receipt, Sequence};
+use crate::ics05_port::capabilities::Capability;
+use crate::ics05_port::context::PortReader;
+use crate::ics23_commitment::commitment::{CommitmentPrefix, CommitmentProofBytes, CommitmentRoot};
+use crate::ics24_host::error::ValidationError;
+use crate::ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId};
+use crate::ics24_host::Path;
+use crate::Height;
+
+/// A key-value store, keyed by ICS24 paths, that commits to its content at every height of the
+/// host chain and proves what it contains at these heights.
+pub trait Store {
+    /// Returns the value stored at `path` in the current (not yet committed) state.
+    fn get(&self, path: &Path) -> Option<Vec<u8>>;
+
+    /// Sets the value stored at `path`.
+    fn set(&mut self, path: Path, value: Vec<u8>);
+
+    /// Removes the value stored at `path`, if any.
+    fn delete(&mut self, path: &Path);
+
+    /// Returns, in ascending order, the keys in the current state which start with `key_prefix`.
+    /// The keys are the string representations of the paths.
+    fn get_keys(&self, key_prefix: &str) -> Vec<String>;
+
+    /// Commits to the current state at the given height and returns the resulting root.
+    fn commit(&mut self, height: Height) -> CommitmentRoot;
+
+    /// Returns the root of the state committed at the given height, if any.
+    fn root(&self, height: Height) -> Option<CommitmentRoot>;
+
+    /// Proves that a value is stored at `path` in the state committed at `height`, or that there
+    /// is no such value.
+    fn get_proof(&self, height: Height, path: &Path) -> Option<CommitmentProofBytes>;
+}
+
+/// A host chain that keeps its IBC state in a `Store`. Such a host only provides the store and
+/// the information about the chain itself; it implements `ClientReader`, `ClientKeeper`,
+/// `ConnectionReader`, `ConnectionKeeper`, as well as `ChannelReader` and `ChannelKeeper` if it
+/// also implements `PortReader`, through the implementations in this module. These charge the
+/// accesses to the store to the gas meter of the host.
+pub trait StoreHost: GasMeter {
+    type Store: Store;
+
+    /// Returns the store holding the IBC state of the host chain.
+    fn store(&self) -> &Self::Store;
+
+    fn store_mut(&mut self) -> &mut Self::Store;
+
+    /// Returns the current height of the host chain.
+    fn host_height(&self) -> Height;
+
+    /// Returns the current timestamp of the host chain.
+    fn host_timestamp(&self) -> Time;
+
+    /// Returns the consensus state of the host chain at the given height.
+    fn host_consensus_state(&self, height: Height) -> Option<AnyConsensusState>;
+
+    /// Returns the number of consensus state historical entries for the host chain.
+    fn host_chain_history_size(&self) -> usize;
+
+    /// Returns the prefix under which the IBC state of the host chain is committed.
+    fn commitment_prefix(&self) -> CommitmentPrefix;
+
+    /// Returns the maximum time that the host chain is expected to take to produce a block.
+    fn max_expected_time_per_block(&self) -> Duration;
+}
+
+/// A change to the current state of a store.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum StoreChange {
+    /// The value is stored at the path, in place of the previous one if any.
+    Set(Path, Vec<u8>),
+
+    /// The value stored at the path is removed.
+    Delete(Path),
+}
+
+/// Returns the changes which turn the current state of `before` into the current state of
+/// `after`, in ascending order of the paths as strings. Fails if either store holds a key which is
+/// not the string representation of a path.
+pub fn diff<S: Store>(before: &S, after: &S) -> Result<Vec<StoreChange>, ValidationError> {
+    let keys: BTreeSet<String> = before
+        .get_keys("")
+        .into_iter()
+        .chain(after.get_keys(""))
+        .collect();
+
+    let mut changes = Vec::new();
+    for key in keys {
+        let path = Path::from_str(&key)?;
+        match (before.get(&path), after.get(&path)) {
+            (old, Some(new)) if old.as_ref() != Some(&new) => {
+                changes.push(StoreChange::Set(path, new))
+            }
+            (Some(_), None) => changes.push(StoreChange::Delete(path)),
+            _ => {}
+        }
+    }
+    Ok(changes)
+}
+
+/// Reads the value at `path` in the store of the host, and charges the read to its gas meter.
+/// Reads cannot fail: running out of gas is found out by the next consumption of gas (see `gas`).
+fn get<H: StoreHost>(host: &H, path: &Path) -> Option<Vec<u8>> {
+    let value = host.store().get(path);
+    let gas = host.gas_costs().read(value.as_ref().map_or(0, Vec::len));
+    let _ = host.consume_gas(gas, "store read");
+    value
+}
+
+/// Lists the keys starting with `key_prefix` in the store of the host, and charges the iteration
+/// over them to its gas meter, as reads are.
+fn keys<H: StoreHost>(host: &H, key_prefix: &str) -> Vec<String> {
+    let keys = host.store().get_keys(key_prefix);
+    let _ = host.consume_gas(host.gas_costs().iter(keys.len()), "store iteration");
+    keys
+}
+
+/// Writes `value` at `path` in the store of the host, and charges the write to its gas meter.
+fn set<H: StoreHost>(host: &mut H, path: Path, value: Vec<u8>) -> Result<(), OutOfGas> {
+    let gas = host.gas_costs().write(value.len());
+    host.store_mut().set(path, value);
+    host.consume_gas(gas, "store write")
+}
+
+/// Removes the value at `path` from the store of the host, and charges the removal to its gas
+/// meter as the write of an empty value.
+fn delete<H: StoreHost>(host: &mut H, path: &Path) -> Result<(), OutOfGas> {
+    host.store_mut().delete(path);
+    host.consume_gas(host.gas_costs().write(0), "store delete")
+}
+
+/// Sequences are stored as big-endian 64-bit integers.
+fn get_sequence<H: StoreHost>(host: &H, path: Path) -> Option<Sequence> {
+    let bytes = <[u8; 8]>::try_from(get(host, &path)?.as_slice()).ok()?;
+    Some(u64::from_be_bytes(bytes).into())
+}
+
+fn consensus_state_path(client_id: &ClientId, height: Height) -> Path {
+    Path::ClientConsensusState {
+        client_id: client_id.clone(),
+        epoch: height.revision_number,
+        height: height.revision_height,
+    }
+}
+
+fn commitments_path((port_id, channel_id, sequence): &(PortId, ChannelId, Sequence)) -> Path {
+    Path::Commitments {
+        port_id: port_id.clone(),
+        channel_id: channel_id.clone(),
+        sequence: *sequence,
+    }
+}
+
+fn receipts_path((port_id, channel_id, sequence): &(PortId, ChannelId, Sequence)) -> Path {
+    Path::Receipts {
+        port_id: port_id.clone(),
+        channel_id: channel_id.clone(),
+        sequence: *sequence,
+    }
+}
+
+fn acks_path((port_id, channel_id, sequence): &(PortId, ChannelId, Sequence)) -> Path {
+    Path::Acks {
+        port_id: port_id.clone(),
+        channel_id: channel_id.clone(),
+        sequence: *sequence,
+    }
+}
+
+/// Returns the value of the counter at `path` and increments it.
+fn next_counter_value<H: StoreHost>(host: &mut H, path: Path) -> u64 {
+    let counter = get_counter(host, &path);
+    // Running out of gas is found out by the next consumption of gas, as for reads.
+    let _ = set(host, path, (counter + 1).to_be_bytes().to_vec());
+    counter
+}
+
+/// Counters are stored as big-endian 64-bit integers, and start at zero.
+fn get_counter<H: StoreHost>(host: &H, path: &Path) -> u64 {
+    get(host, path)
+        .and_then(|bytes| <[u8; 8]>::try_from(bytes.as_slice()).ok())
+        .map_or(0, u64::from_be_bytes)
+}
+
+impl<H: StoreHost> ClientReader for H {
+    fn client_type(&self, client_id: &ClientId) -> Option<ClientType> {
+        let bytes = get(self, &Path::ClientType(client_id.clone()))?;
+        ClientType::from_str(std::str::from_utf8(&bytes).ok()?).ok()
+    }
+
+    fn client_state(&self, client_id: &ClientId) -> Option<AnyClientState> {
+        AnyClientState::decode_vec(&get(self, &Path::ClientState(client_id.clone()))?).ok()
+    }
+
+    fn consensus_state(&self, client_id: &ClientId, height: Height) -> Option<AnyConsensusState> {
+        AnyConsensusState::decode_vec(&get(self, &consensus_state_path(client_id, height))?).ok()
+    }
+
+    fn host_height(&self) -> Height {
+        StoreHost::host_height(self)
+    }
+
+    fn host_timestamp(&self) -> Time {
+        StoreHost::host_timestamp(self)
+    }
+
+    fn client_counter(&self) -> u64 {
+        get_counter(self, &Path::NextClientSequence)
+    }
+}
+
+impl<H: StoreHost> ClientKeeper for H {
+    fn store_client_type(
+        &mut self,
+        client_id: ClientId,
+        client_type: ClientType,
+    ) -> Result<(), ICS2Error> {
+        set(
+            self,
+            Path::ClientType(client_id),
+            client_type.as_string().as_bytes().to_vec(),
+        )
+        .map_err(ICS2Kind::OutOfGas)?;
+        Ok(())
+    }
+
+    fn store_client_state(
+        &mut self,
+        client_id: ClientId,
+        client_state: AnyClientState,
+    ) -> Result<(), ICS2Error> {
+        set(
+            self,
+            Path::ClientState(client_id),
+            client_state.encode_vec().unwrap(),
+        )
+        .map_err(ICS2Kind::OutOfGas)?;
+        Ok(())
+    }
+
+    fn store_consensus_state(
+        &mut self,
+        client_id: ClientId,
+        height: Height,
+        consensus_state: AnyConsensusState,
+    ) -> Result<(), ICS2Error> {
+        set(
+            self,
+            consensus_state_path(&client_id, height),
+            consensus_state.encode_vec().unwrap(),
+        )
+        .map_err(ICS2Kind::OutOfGas)?;
+        Ok(())
+    }
+
+    fn store_update_time(
+        &mut self,
+        client_id: ClientId,
+        height: Height,
+        timestamp: Time,
+    ) -> Result<(), ICS2Error> {
+        let path = Path::ClientConsensusProcessedTime {
+            client_id,
+            epoch: height.revision_number,
+            height: height.revision_height,
+        };
+        set(self, path, timestamp.encode_vec().unwrap()).map_err(ICS2Kind::OutOfGas)?;
+        Ok(())
+    }
+
+    fn store_update_height(
+        &mut self,
+        client_id: ClientId,
+        height: Height,
+        host_height: Height,
+    ) -> Result<(), ICS2Error> {
+        let path = Path::ClientConsensusProcessedHeight {
+            client_id,
+            epoch: height.revision_number,
+            height: height.revision_height,
+        };
+        set(self, path, host_height.encode_vec().unwrap()).map_err(ICS2Kind::OutOfGas)?;
+        Ok(())
+    }
+
+    fn increase_client_counter(&mut self) {
+        next_counter_value(self, Path::NextClientSequence);
+    }
+}
+
+impl<H: StoreHost> ConnectionReader for H {
+    fn connection_end(&self, conn_id: &ConnectionId) -> Option<ConnectionEnd> {
+        ConnectionEnd::decode_vec(&get(self, &Path::Connections(conn_id.clone()))?).ok()
+    }
+
+    fn client_state(&self, client_id: &ClientId) -> Option<AnyClientState> {
+        ClientReader::client_state(self, client_id)
+    }
+
+    fn host_current_height(&self) -> Height {
+        StoreHost::host_height(self)
+    }
+
+    fn host_chain_history_size(&self) -> usize {
+        StoreHost::host_chain_history_size(self)
+    }
+
+    fn commitment_prefix(&self) -> CommitmentPrefix {
+        StoreHost::commitment_prefix(self)
+    }
+
+    fn client_consensus_state(
+        &self,
+        client_id: &ClientId,
+        height: Height,
+    ) -> Option<AnyConsensusState> {
+        ClientReader::consensus_state(self, client_id, height)
+    }
+
+    fn host_consensus_state(&self, height: Height) -> Option<AnyConsensusState> {
+        StoreHost::host_consensus_state(self, height)
+    }
+}
+
+impl<H: StoreHost> ConnectionKeeper for H {
+    fn next_connection_id(&mut self) -> ConnectionId {
+        let counter = next_counter_value(self, Path::NextConnectionSequence);
+        ConnectionId::from_str(&format!("connection-{}", counter)).unwrap()
+    }
+
+    fn store_connection(
+        &mut self,
+        connection_id: &ConnectionId,
+        connection_end: &ConnectionEnd,
+    ) -> Result<(), ICS3Error> {
+        set(
+            self,
+            Path::Connections(connection_id.clone()),
+            connection_end.encode_vec().unwrap(),
+        )
+        .map_err(ICS3Kind::OutOfGas)?;
+        Ok(())
+    }
+
+    fn store_connection_to_client(
+        &mut self,
+        connection_id: &ConnectionId,
+        client_id: &ClientId,
+    ) -> Result<(), ICS3Error> {
+        // The connections of a client are stored as a list, as in the Cosmos SDK.
+        let path = Path::ClientConnections(client_id.clone());
+        let mut connections = get(self, &path)
+            .and_then(|bytes| ClientPaths::decode(bytes.as_slice()).ok())
+            .unwrap_or_default();
+        connections.paths.push(connection_id.to_string());
+
+        let mut bytes = Vec::new();
+        connections.encode(&mut bytes).unwrap();
+        set(self, path, bytes).map_err(ICS3Kind::OutOfGas)?;
+        Ok(())
+    }
+}
+
+impl<H: StoreHost + PortReader> ChannelReader for H {
+    fn channel_end(&self, port_channel_id: &(PortId, ChannelId)) -> Option<ChannelEnd> {
+        let (port_id, channel_id) = port_channel_id.clone();
+        ChannelEnd::decode_vec(&get(self, &Path::ChannelEnds(port_id, channel_id))?).ok()
+    }
+
+    fn connection_end(&self, connection_id: &ConnectionId) -> Option<ConnectionEnd> {
+        ConnectionReader::connection_end(self, connection_id)
+    }
+
+    /// The channels of a connection are found by going through all the channel ends, since
+    /// ICS24 does not lay out an index of the channels by connection.
+    fn connection_channels(&self, cid: &ConnectionId) -> Option<Vec<(PortId, ChannelId)>> {
+        let channels = keys(self, "channelEnds/")
+            .iter()
+            .filter_map(|key| match Path::from_str(key) {
+                Ok(Path::ChannelEnds(port_id, channel_id)) => Some((port_id, channel_id)),
+                _ => None,
+            })
+            .filter(|port_channel_id| {
+                matches!(
+                    self.channel_end(port_channel_id),
+                    Some(channel) if channel.connection_hops().first() == Some(cid)
+                )
+            })
+            .collect();
+
+        Some(channels)
+    }
+
+    fn channel_client_state(
+        &self,
+        port_channel_id: &(PortId, ChannelId),
+    ) -> Option<AnyClientState> {
+        let channel = self.channel_end(port_channel_id)?;
+        let connection = ChannelReader::connection_end(self, channel.connection_hops().first()?)?;
+        ClientReader::client_state(self, connection.client_id())
+    }
+
+    fn channel_client_consensus_state(
+        &self,
+        port_channel_id: &(PortId, ChannelId),
+        height: Height,
+    ) -> Option<AnyConsensusState> {
+        let channel = self.channel_end(port_channel_id)?;
+        let connection = ChannelReader::connection_end(self, channel.connection_hops().first()?)?;
+        ClientReader::consensus_state(self, connection.client_id(), height)
+    }
+
+    fn port_capability(&self, port_id: &PortId) -> Result<Capability, ICS4Error> {
+        match self.lookup_module_by_port(port_id) {
+            Some((_, key)) => Ok(key),
+            None => Err(ICS4Kind::NoPortCapability.into()),
+        }
+    }
+
+    fn channel_capability(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<Capability, ICS4Error> {
+        match self.lookup_module_by_channel(port_id, channel_id) {
+            Some((_, key)) => Ok(key),
+            None => Err(ICS4Kind::NoChannelCapability(port_id.clone(), channel_id.clone()).into()),
+        }
+    }
+
+    fn client_state(&self, client_id: &ClientId) -> Option<AnyClientState> {
+        ClientReader::client_state(self, client_id)
+    }
+
+    fn client_consensus_state(
+        &self,
+        client_id: &ClientId,
+        height: Height,
+    ) -> Option<AnyConsensusState> {
+        ClientReader::consensus_state(self, client_id, height)
+    }
+
+    fn get_next_sequence_send(&self, port_channel_id: &(PortId, ChannelId)) -> Option<Sequence> {
+        let (port_id, channel_id) = port_channel_id.clone();
+        get_sequence(self, Path::SeqSends(port_id, channel_id))
+    }
+
+    fn get_next_sequence_recv(&self, port_channel_id: &(PortId, ChannelId)) -> Option<Sequence> {
+        let (port_id, channel_id) = port_channel_id.clone();
+        get_sequence(self, Path::SeqRecvs(port_id, channel_id))
+    }
+
+    fn get_next_sequence_ack(&self, port_channel_id: &(PortId, ChannelId)) -> Option<Sequence> {
+        let (port_id, channel_id) = port_channel_id.clone();
+        get_sequence(self, Path::SeqAcks(port_id, channel_id))
+    }
+
+    fn get_packet_commitment(
+        &self,
+        key: &(PortId, ChannelId, Sequence),
+    ) -> Option<PacketCommitment> {
+        get(self, &commitments_path(key)).map(Into::into)
+    }
+
+    fn get_packet_receipt(&self, key: &(PortId, ChannelId, Sequence)) -> Option<Receipt> {
+        get(self, &receipts_path(key)).map(|_| Receipt::Ok)
+    }
+
+    fn get_packet_acknowledgement(
+        &self,
+        key: &(PortId, ChannelId, Sequence),
+    ) -> Option<AcknowledgementCommitment> {
+        get(self, &acks_path(key)).map(Into::into)
+    }
+
+    fn host_height(&self) -> Height {
+        StoreHost::host_height(self)
+    }
+
+    fn host_timestamp(&self) -> Time {
+        StoreHost::host_timestamp(self)
+    }
+
+    fn client_update_time(&self, client_id: &ClientId, height: Height) -> Option<Time> {
+        let path = Path::ClientConsensusProcessedTime {
+            client_id: client_id.clone(),
+            epoch: height.revision_number,
+            height: height.revision_height,
+        };
+        Time::decode_vec(&get(self, &path)?).ok()
+    }
+
+    fn client_update_height(&self, client_id: &ClientId, height: Height) -> Option<Height> {
+        let path = Path::ClientConsensusProcessedHeight {
+            client_id: client_id.clone(),
+            epoch: height.revision_number,
+            height: height.revision_height,
+        };
+        Height::decode_vec(&get(self, &path)?).ok()
+    }
+
+    fn max_expected_time_per_block(&self) -> Duration {
+        StoreHost::max_expected_time_per_block(self)
+    }
+
+    fn channel_counter(&self) -> u64 {
+        get_counter(self, &Path::NextChannelSequence)
+    }
+}
+
+impl<H: StoreHost + PortReader> ChannelKeeper for H {
+    /// Nothing to do, as the channels of a connection are found through their channel ends.
+    fn store_connection_channels(
+        &mut self,
+        _conn_id: &ConnectionId,
+        _port_channel_id: &(PortId, ChannelId),
+    ) -> Result<(), ICS4Error> {
+        Ok(())
+    }
+
+    fn store_channel(
+        &mut self,
+        port_channel_id: &(PortId, ChannelId),
+        channel_end: &ChannelEnd,
+    ) -> Result<(), ICS4Error> {
+        let (port_id, channel_id) = port_channel_id.clone();
+        set(
+            self,
+            Path::ChannelEnds(port_id, channel_id),
+            channel_end.encode_vec().unwrap(),
+        )
+        .map_err(ICS4Kind::OutOfGas)?;
+        Ok(())
+    }
+
+    fn store_next_sequence_send(
+        &mut self,
+        port_channel_id: &(PortId, ChannelId),
+        seq: Sequence,
+    ) -> Result<(), ICS4Error> {
+        let (port_id, channel_id) = port_channel_id.clone();
+        set(
+            self,
+            Path::SeqSends(port_id, channel_id),
+            u64::from(seq).to_be_bytes().to_vec(),
+        )
+        .map_err(ICS4Kind::OutOfGas)?;
+        Ok(())
+    }
+
+    fn store_next_sequence_recv(
+        &mut self,
+        port_channel_id: &(PortId, ChannelId),
+        seq: Sequence,
+    ) -> Result<(), ICS4Error> {
+        let (port_id, channel_id) = port_channel_id.clone();
+        set(
+            self,
+            Path::SeqRecvs(port_id, channel_id),
+            u64::from(seq).to_be_bytes().to_vec(),
+        )
+        .map_err(ICS4Kind::OutOfGas)?;
+        Ok(())
+    }
+
+    fn store_next_sequence_ack(
+        &mut self,
+        port_channel_id: &(PortId, ChannelId),
+        seq: Sequence,
+    ) -> Result<(), ICS4Error> {
+        let (port_id, channel_id) = port_channel_id.clone();
+        set(
+            self,
+            Path::SeqAcks(port_id, channel_id),
+            u64::from(seq).to_be_bytes().to_vec(),
+        )
+        .map_err(ICS4Kind::OutOfGas)?;
+        Ok(())
+    }
+
+    fn store_packet_commitment(
+        &mut self,
+        key: (PortId, ChannelId, Sequence),
+        commitment: PacketCommitment,
+    ) -> Result<(), ICS4Error> {
+        set(self, commitments_path(&key), commitment.into_vec()).map_err(ICS4Kind::OutOfGas)?;
+        Ok(())
+    }
+
+    fn delete_packet_commitment(
+        &mut self,
+        key: (PortId, ChannelId, Sequence),
+    ) -> Result<(), ICS4Error> {
+        delete(self, &commitments_path(&key)).map_err(ICS4Kind::OutOfGas)?;
+        Ok(())
+    }
+
+    /// As in the Cosmos SDK, the value of a receipt only marks its presence.
+    fn store_packet_receipt(
+        &mut self,
+        key: (PortId, ChannelId, Sequence),
+        _receipt: Receipt,
+    ) -> Result<(), ICS4Error> {
+        set(self, receipts_path(&key), vec![1]).map_err(ICS4Kind::OutOfGas)?;
+        Ok(())
+    }
+
+    fn store_packet_acknowledgement(
+        &mut self,
+        key: (PortId, ChannelId, Sequence),
+        ack_commitment: AcknowledgementCommitment,
+    ) -> Result<(), ICS4Error> {
+        set(self, acks_path(&key), ack_commitment.into_vec()).map_err(ICS4Kind::OutOfGas)?;
+        Ok(())
+    }
+
+    fn increase_channel_counter(&mut self) {
+        next_counter_value(self, Path::NextChannelSequence);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use std::str::FromStr;
+    use std::time::Duration;
+
+    use tendermint::Time;
+    use tendermint_proto::Protobuf;
+
+    use crate::gas::{BasicGasMeter, Gas, GasCosts, GasMeter, OutOfGas};
+    use crate::ics02_client::client_def::{AnyClientState, AnyConsensusState};
+    use crate::ics02_client::client_type::ClientType;
+    use crate::ics02_client::context::{ClientKeeper, ClientReader};
+    use crate::ics02_client::msgs::create_client::MsgCreateAnyClient;
+    use crate::ics02_client::msgs::ClientMsg;
+    use crate::ics03_connection::connection::State as ConnectionState;
+    use crate::ics03_connection::context::{ConnectionKeeper, ConnectionReader};
+    use crate::ics03_connection::msgs::conn_open_init::test_util::get_dummy_msg_conn_open_init_ics26;
+    use crate::ics03_connection::msgs::conn_open_init::MsgConnectionOpenInit;
+    use crate::ics03_connection::msgs::ConnectionMsg;
+    use crate::ics04_channel::context::ChannelReader;
+    use crate::ics04_channel::msgs::chan_open_init::test_util::get_dummy_raw_msg_chan_open_init_ics26;
+    use crate::ics04_channel::msgs::chan_open_init::MsgChannelOpenInit;
+    use crate::ics04_channel::msgs::ChannelMsg;
+    use crate::ics05_port::capabilities::{CapabilityKeeper, ModuleId};
+    use crate::ics05_port::context::{PortKeeper, PortReader};
+    use crate::ics23_commitment::commitment::{
+        CommitmentPrefix, CommitmentProofBytes, CommitmentRoot,
+    };
+    use crate::ics23_commitment::merkle::{apply_prefix, cosmos_specs, MerkleProof};
+    use crate::ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId};
+    use crate::ics24_host::store::{diff, Store, StoreChange, StoreHost};
+    use crate::ics24_host::Path;
+    use crate::ics26_routing::context::ICS26Context;
+    use crate::ics26_routing::handler::dispatch;
+    use crate::ics26_routing::msgs::ICS26Envelope;
+    use crate::ics26_routing::router::Router;
+    use crate::mock::client_state::{MockClientState, MockConsensusState};
+    use crate::mock::header::MockHeader;
+    use crate::mock::module::MockModule;
+    use crate::mock::store::InMemoryStore;
+    use crate::test_utils::get_dummy_account_id;
+    use crate::Height;
+
+    /// A host chain which implements nothing but the storage backend and the information about
+    /// itself, and relies on the readers and keepers implemented over its store.
+    #[derive(Clone, Debug, Default)]
+    struct StoreOnlyHost {
+        store: InMemoryStore,
+        capabilities: CapabilityKeeper,
+        router: Router<StoreOnlyHost>,
+        gas_meter: BasicGasMeter,
+    }
+
+    impl GasMeter for StoreOnlyHost {
+        fn gas_costs(&self) -> &GasCosts {
+            self.gas_meter.gas_costs()
+        }
+
+        fn gas_consumed(&self) -> Gas {
+            self.gas_meter.gas_consumed()
+        }
+
+        fn consume_gas(&self, amount: Gas, descriptor: &str) -> Result<(), OutOfGas> {
+            self.gas_meter.consume_gas(amount, descriptor)
+        }
+    }
+
+    impl StoreHost for StoreOnlyHost {
+        type Store = InMemoryStore;
+
+        fn store(&self) -> &InMemoryStore {
+            &self.store
+        }
+
+        fn store_mut(&mut self) -> &mut InMemoryStore {
+            &mut self.store
+        }
+
+        fn host_height(&self) -> Height {
+            Height::new(0, 5)
+        }
+
+        fn host_timestamp(&self) -> Time {
+            Time::now()
+        }
+
+        fn host_consensus_state(&self, _height: Height) -> Option<AnyConsensusState> {
+            None
+        }
+
+        fn host_chain_history_size(&self) -> usize {
+            5
+        }
+
+        fn commitment_prefix(&self) -> CommitmentPrefix {
+            CommitmentPrefix::from(b"ibc".to_vec())
+        }
+
+        fn max_expected_time_per_block(&self) -> Duration {
+            Duration::from_secs(30)
+        }
+    }
+
+    impl PortReader for StoreOnlyHost {
+        fn capability_keeper(&self) -> &CapabilityKeeper {
+            &self.capabilities
+        }
+    }
+
+    impl PortKeeper for StoreOnlyHost {
+        fn capability_keeper_mut(&mut self) -> &mut CapabilityKeeper {
+            &mut self.capabilities
+        }
+    }
+
+    impl ICS26Context for StoreOnlyHost {
+        fn router(&self) -> &Router<Self> {
+            &self.router
+        }
+
+        fn router_mut(&mut self) -> &mut Router<Self> {
+            &mut self.router
+        }
+    }
+
+    #[test]
+    fn store_accesses_consume_gas() {
+        let costs = GasCosts::default();
+        let client_id = ClientId::new(ClientType::Mock, 0).unwrap();
+        let client_state =
+            AnyClientState::from(MockClientState::new(MockHeader::new(Height::new(0, 4))));
+        let len = client_state.encode_vec().unwrap().len();
+
+        // Writes and reads are charged by the size of the value, including missing values.
+        let mut host = StoreOnlyHost::default();
+        host.store_client_state(client_id.clone(), client_state.clone())
+            .unwrap();
+        assert_eq!(host.gas_meter.gas_consumed(), costs.write(len));
+
+        assert_eq!(
+            ClientReader::client_state(&host, &client_id),
+            Some(client_state.clone())
+        );
+        assert!(ClientReader::client_state(&host, &ClientId::default()).is_none());
+        assert_eq!(
+            host.gas_meter.gas_consumed(),
+            costs.write(len) + costs.read(len) + costs.read(0)
+        );
+
+        // Once out of gas, writes fail, while reads go on and leave the meter out of gas.
+        let mut host = StoreOnlyHost {
+            gas_meter: BasicGasMeter::new(costs.write(len), costs),
+            ..Default::default()
+        };
+        host.store_client_state(client_id.clone(), client_state.clone())
+            .unwrap();
+        assert_eq!(
+            ClientReader::client_state(&host, &client_id),
+            Some(client_state.clone())
+        );
+        assert!(host.consume_gas(0, "check").is_err());
+        assert!(host.store_client_state(client_id, client_state).is_err());
+    }
+
+    #[test]
+    fn handlers_over_store() {
+        let mut host = StoreOnlyHost::default();
+        let client_height = Height::new(0, 4);
+        let client_state =
+            AnyClientState::from(MockClientState::new(MockHeader::new(client_height)));
+
+        // Create a client.
+        let msg = MsgCreateAnyClient::new(
+            client_state.clone(),
+            AnyConsensusState::from(MockConsensusState::new(MockHeader::new(client_height))),
+            get_dummy_account_id(),
+        )
+        .unwrap();
+        dispatch(
+            &mut host,
+            ICS26Envelope::ICS2Msg(ClientMsg::CreateClient(msg)),
+        )
+        .unwrap();
+
+        let client_id = ClientId::new(ClientType::Mock, 0).unwrap();
+        assert_eq!(host.client_counter(), 1);
+        assert_eq!(host.client_type(&client_id), Some(ClientType::Mock));
+        assert_eq!(
+            ClientReader::client_state(&host, &client_id),
+            Some(client_state)
+        );
+        assert!(host.consensus_state(&client_id, client_height).is_some());
+        assert!(host.client_update_time(&client_id, client_height).is_some());
+        assert_eq!(
+            host.client_update_height(&client_id, client_height),
+            Some(Height::new(0, 5))
+        );
+
+        // Initialize a connection on top of the client.
+        let msg = MsgConnectionOpenInit::try_from(get_dummy_msg_conn_open_init_ics26()).unwrap();
+        dispatch(
+            &mut host,
+            ICS26Envelope::ICS3Msg(ConnectionMsg::ConnectionOpenInit(msg)),
+        )
+        .unwrap();
+
+        let connection_id = ConnectionId::from_str("connection-0").unwrap();
+        let connection_end = ConnectionReader::connection_end(&host, &connection_id).unwrap();
+        assert_eq!(connection_end.state(), &ConnectionState::Init);
+        assert_eq!(
+            host.store().get(&Path::ClientConnections(client_id)),
+            // The protobuf encoding of the list of the connections of the client.
+            Some(b"\n\x0cconnection-0".to_vec())
+        );
+        assert_eq!(host.next_connection_id().as_str(), "connection-1");
+
+        // Initialize a channel on top of the connection.
+        let mut raw_msg = get_dummy_raw_msg_chan_open_init_ics26();
+        raw_msg.channel.as_mut().unwrap().connection_hops = vec![connection_id.to_string()];
+        let msg = MsgChannelOpenInit::try_from(raw_msg).unwrap();
+        let port_channel_id = (
+            msg.port_id.clone(),
+            ChannelId::from_str("channel-0").unwrap(),
+        );
+
+        host.router
+            .add_route(
+                msg.port_id.clone(),
+                ModuleId::new(msg.port_id.as_str()),
+                MockModule,
+            )
+            .unwrap();
+        host.bind_port(&ModuleId::new(msg.port_id.as_str()), &msg.port_id)
+            .unwrap();
+        dispatch(
+            &mut host,
+            ICS26Envelope::ICS4ChannelMsg(ChannelMsg::ChannelOpenInit(msg)),
+        )
+        .unwrap();
+
+        assert!(host.channel_end(&port_channel_id).is_some());
+        assert_eq!(
+            host.connection_channels(&connection_id),
+            Some(vec![port_channel_id.clone()])
+        );
+        assert_eq!(
+            host.connection_channels(&ConnectionId::from_str("connection-1").unwrap()),
+            Some(vec![])
+        );
+        assert_eq!(
+            host.get_next_sequence_send(&port_channel_id),
+            Some(1.into())
+        );
+
+        // The connection end is proven in the committed state.
+        let height = Height::new(0, 5);
+        let root = host.store.commit(height);
+        let path = Path::Connections(connection_id);
+        let proof = MerkleProof::try_from(&host.store().get_proof(height, &path).unwrap()).unwrap();
+        let merkle_path =
+            apply_prefix(&StoreHost::commitment_prefix(&host), vec![path.to_string()]).unwrap();
+        assert!(proof
+            .verify_membership(
+                &cosmos_specs(),
+                &root,
+                merkle_path,
+                connection_end.encode_vec().unwrap()
+            )
+            .is_ok());
+
+        // Values which do not decode are missing.
+        let port_id = PortId::from_str("port").unwrap();
+        host.store.set(
+            Path::ChannelEnds(port_id.clone(), ChannelId::from_str("channel-1").unwrap()),
+            b"garbage".to_vec(),
+        );
+        assert!(host
+            .channel_end(&(port_id, ChannelId::from_str("channel-1").unwrap()))
+            .is_none());
+    }
+
+    #[test]
+    fn diff_of_stores() {
+        let path = |id: &str| Path::ClientState(ClientId::from_str(id).unwrap());
+
+        let mut before = InMemoryStore::default();
+        before.set(path("07-tendermint-0"), vec![0]);
+        before.set(path("07-tendermint-1"), vec![1]);
+        before.set(path("07-tendermint-2"), vec![2]);
+
+        let mut after = before.clone();
+        after.delete(&path("07-tendermint-0"));
+        after.set(path("07-tendermint-1"), vec![1]);
+        after.set(path("07-tendermint-2"), vec![3]);
+        after.set(path("07-tendermint-3"), vec![4]);
+
+        assert_eq!(
+            diff(&before, &after).unwrap(),
+            vec![
+                StoreChange::Delete(path("07-tendermint-0")),
+                StoreChange::Set(path("07-tendermint-2"), vec![3]),
+                StoreChange::Set(path("07-tendermint-3"), vec![4]),
+            ]
+        );
+        assert!(diff(&before, &before).unwrap().is_empty());
+    }
+
+    #[test]
+    fn diff_of_stores_with_invalid_key() {
+        /// A store which also lists a key that is not the string representation of a path.
+        struct StoreWithInvalidKey(InMemoryStore);
+
+        impl Store for StoreWithInvalidKey {
+            fn get(&self, path: &Path) -> Option<Vec<u8>> {
+                self.0.get(path)
+            }
+
+            fn set(&mut self, path: Path, value: Vec<u8>) {
+                self.0.set(path, value)
+            }
+
+            fn delete(&mut self, path: &Path) {
+                self.0.delete(path)
+            }
+
+            fn get_keys(&self, key_prefix: &str) -> Vec<String> {
+                let mut keys = self.0.get_keys(key_prefix);
+                keys.push("clients/07-tendermint-0/unknown".to_string());
+                keys
+            }
+
+            fn commit(&mut self, height: Height) -> CommitmentRoot {
+                self.0.commit(height)
+            }
+
+            fn root(&self, height: Height) -> Option<CommitmentRoot> {
+                self.0.root(height)
+            }
+
+            fn get_proof(&self, height: Height, path: &Path) -> Option<CommitmentProofBytes> {
+                self.0.get_proof(height, path)
+            }
+        }
+
+        let store = StoreWithInvalidKey(InMemoryStore::default());
+        assert!(diff(&store, &store).is_err());
+    }
+}
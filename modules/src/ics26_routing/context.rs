@@ -1,10 +1,16 @@
 use crate::ics02_client::context::{ClientKeeper, ClientReader};
 use crate::ics03_connection::context::{ConnectionKeeper, ConnectionReader};
 use crate::ics04_channel::context::{ChannelKeeper, ChannelReader};
-use crate::ics05_port::context::PortReader;
+use crate::ics05_port::context::PortKeeper;
+use crate::ics26_routing::router::Router;
+
 /// This trait captures all the functional dependencies (i.e., context) which the ICS26 module
 /// requires to be able to dispatch and process IBC messages. In other words, this is the
 /// representation of a chain from the perspective of the IBC module of that chain.
+///
+/// A clone of the context must be a snapshot of the state of the chain, which evolves on its
+/// own: `deliver` applies the messages of a transaction to such a snapshot, and discards it if
+/// any of them fails.
 pub trait ICS26Context:
     ClientReader
     + ClientKeeper
@@ -12,7 +18,12 @@ pub trait ICS26Context:
     + ConnectionKeeper
     + ChannelKeeper
     + ChannelReader
-    + PortReader
+    + PortKeeper
     + Clone
 {
+    /// Returns the router through which the application modules bound to the ports of the host
+    /// chain are looked up.
+    fn router(&self) -> &Router<Self>;
+
+    fn router_mut(&mut self) -> &mut Router<Self>;
 }
@@ -1,6 +1,9 @@
 use anomaly::{BoxError, Context};
 use thiserror::Error;
 
+use crate::gas::OutOfGas;
+use crate::ics24_host::identifier::PortId;
+
 pub type Error = anomaly::Error<Kind>;
 
 #[derive(Clone, Debug, Error, PartialEq, Eq)]
@@ -16,6 +19,18 @@ pub enum Kind {
 
     #[error("the message is malformed and cannot be decoded")]
     MalformedMessageBytes,
+
+    #[error("no module is bound to port {0}")]
+    ModuleNotFound(PortId),
+
+    #[error("a module is already bound to port {0}")]
+    RouteAlreadyExists(PortId),
+
+    #[error("the callback of the module bound to port {0} failed")]
+    ModuleCallbackFailed(PortId),
+
+    #[error("{0}")]
+    OutOfGas(OutOfGas),
 }
 
 impl Kind {
@@ -1,63 +1,247 @@
+use std::sync::Arc;
+
 use prost_types::Any;
 use tendermint_proto::Protobuf;
 
 // use crate::handler;
 use crate::events::IBCEvent;
 use crate::handler::HandlerOutput;
+use crate::ics02_client::client_def::AnyClientState;
 use crate::ics02_client::handler::dispatch as ics2_msg_dispatcher;
 use crate::ics02_client::msgs::create_client;
+use crate::ics02_client::msgs::misbehaviour;
 use crate::ics02_client::msgs::update_client;
+use crate::ics02_client::msgs::upgrade_client;
 use crate::ics02_client::msgs::ClientMsg;
 use crate::ics03_connection::handler::dispatch as ics3_msg_dispatcher;
+use crate::ics03_connection::msgs::{
+    conn_open_ack, conn_open_confirm, conn_open_init, conn_open_try, ConnectionMsg,
+};
+use crate::ics04_channel::channel::ChannelEnd;
+use crate::ics04_channel::events::WriteAcknowledgement;
 use crate::ics04_channel::handler::dispatch as ics4_msg_dispatcher;
+use crate::ics04_channel::handler::packet_dispatch as ics04_packet_msg_dispatcher;
+use crate::ics04_channel::handler::{ChannelIdState, PacketResult};
+use crate::ics04_channel::msgs::{
+    acknowledgement, chan_close_confirm, chan_close_init, chan_open_ack, chan_open_confirm,
+    chan_open_init, chan_open_try, recv_packet, timeout, timeout_on_close, ChannelMsg, PacketMsg,
+};
+use crate::ics04_channel::packet::Packet;
+use crate::ics05_port::capabilities::{
+    channel_capability_name, port_capability_name, Capability, ModuleId,
+};
+use crate::ics24_host::identifier::{ChannelId, ClientId, PortId};
 
 use crate::ics26_routing::context::ICS26Context;
 use crate::ics26_routing::error::{Error, Kind};
+use crate::ics26_routing::module::Module;
 use crate::ics26_routing::msgs::ICS26Envelope;
-use crate::ics26_routing::msgs::ICS26Envelope::{ICS2Msg, ICS3Msg, ICS4Msg};
+use crate::ics26_routing::msgs::ICS26Envelope::{ICS2Msg, ICS3Msg, ICS4ChannelMsg, ICS4PacketMsg};
 
 /// Mimics the DeliverTx ABCI interface, but a slightly lower level. No need for authentication
 /// info or signature checks here.
 /// https://github.com/cosmos/cosmos-sdk/tree/master/docs/basics
-/// Returns a vector of all events that got generated as a byproduct of processing `messages`.
-pub fn deliver<Ctx>(ctx: &mut Ctx, messages: Vec<Any>) -> Result<Vec<IBCEvent>, Error>
+///
+/// The messages are processed in order and atomically, as the messages of a transaction: they
+/// are applied to a snapshot of the context (see `simulate`), which replaces the context only once
+/// all of them succeeded. If any message fails, the context is left untouched, except for the gas
+/// consumed until then, which is still charged, and the error of that message is returned.
+///
+/// The handlers consume gas from the gas meter of the context (see `gas`), and a message fails
+/// with `Kind::OutOfGas` once the meter is out of gas.
+///
+/// Returns the logs and the events of all the messages, in order.
+pub fn deliver<Ctx>(ctx: &mut Ctx, messages: Vec<Any>) -> Result<HandlerOutput<()>, Error>
 where
     Ctx: ICS26Context,
 {
     // Create a clone, which will store each intermediary stage of applying txs.
     let mut ctx_interim = ctx.clone();
 
-    // A buffer for all the events, to be used as return value.
-    let mut res: Vec<IBCEvent> = vec![];
+    match apply(&mut ctx_interim, messages) {
+        Ok(output) => {
+            // No error has surfaced, so we now apply the changes permanently to the original
+            // context.
+            *ctx = ctx_interim;
+            Ok(output)
+        }
+        Err(e) => {
+            // The changes are discarded with the snapshot, but not the gas consumed to find out
+            // that the transaction fails. The meter of the context may run out of gas here, which
+            // the next consumption of gas finds out.
+            let gas_used = ctx_interim
+                .gas_consumed()
+                .saturating_sub(ctx.gas_consumed());
+            let _ = ctx.consume_gas(gas_used, "failed transaction");
+            Err(e)
+        }
+    }
+}
+
+/// The outcome of the simulation of a transaction.
+#[derive(Clone, Debug)]
+pub struct Simulation<Ctx> {
+    /// The logs and the events of all the messages, in order.
+    pub output: HandlerOutput<()>,
+
+    /// The context as the messages left it.
+    pub ctx: Ctx,
+}
+
+/// Runs the messages of a transaction as `deliver` does, but without mutating the context: the
+/// messages are applied to a snapshot of the context (a clone of it), which is returned along with
+/// their logs and events. The changes made to the state of hosts which keep it in a `Store` are
+/// then found by comparing the stores of the context and the snapshot (see `store::diff`).
+pub fn simulate<Ctx>(ctx: &Ctx, messages: Vec<Any>) -> Result<Simulation<Ctx>, Error>
+where
+    Ctx: ICS26Context,
+{
+    let mut ctx_interim = ctx.clone();
+    let output = apply(&mut ctx_interim, messages)?;
+
+    Ok(Simulation {
+        output,
+        ctx: ctx_interim,
+    })
+}
+
+/// Applies the messages of a transaction to `ctx` in order, and stops at the first one failing.
+fn apply<Ctx>(ctx: &mut Ctx, messages: Vec<Any>) -> Result<HandlerOutput<()>, Error>
+where
+    Ctx: ICS26Context,
+{
+    // The logs and events of all the messages, to be used as return value.
+    let mut output = HandlerOutput::builder();
 
     for any_msg in messages {
         // Decode the proto message into a domain message, creating an ICS26 envelope.
-        let envelope = match any_msg.type_url.as_str() {
-            // ICS2 messages
-            create_client::TYPE_URL => {
-                // Pop out the message and then wrap it in the corresponding type.
-                let domain_msg = create_client::MsgCreateAnyClient::decode_vec(&any_msg.value)
-                    .map_err(|e| Kind::MalformedMessageBytes.context(e))?;
-                Ok(ICS2Msg(ClientMsg::CreateClient(domain_msg)))
-            }
-            update_client::TYPE_URL => {
-                let domain_msg = update_client::MsgUpdateAnyClient::decode_vec(&any_msg.value)
-                    .map_err(|e| Kind::MalformedMessageBytes.context(e))?;
-                Ok(ICS2Msg(ClientMsg::UpdateClient(domain_msg)))
-            }
-            // TODO: ICS3 messages
-            _ => Err(Kind::UnknownMessageTypeURL(any_msg.type_url)),
-        }?;
-
-        // Process the envelope, and accumulate any events that were generated.
-        let mut output = dispatch(&mut ctx_interim, envelope)?;
-        // TODO: output.log and output.result are discarded
-        res.append(&mut output.events);
+        let envelope = decode(any_msg)?;
+
+        // Process the envelope, and accumulate the logs and events that were generated.
+        let msg_output = dispatch(ctx, envelope);
+
+        // Reading the state of the host does not fail once the gas meter is out of gas, so the
+        // message fails here in that case, whatever its outcome.
+        ctx.consume_gas(0, "message")
+            .map_err(|e| Kind::OutOfGas(e.clone()).context(e))?;
+
+        let msg_output = msg_output?;
+        output = output
+            .with_log(msg_output.log)
+            .with_events(msg_output.events);
     }
 
-    // No error has surfaced, so we now apply the changes permanently to the original context.
-    *ctx = ctx_interim;
-    Ok(res)
+    Ok(output.with_result(()))
+}
+
+/// Decodes a proto message into the domain message it holds, wrapped in an ICS26 envelope.
+fn decode(any_msg: Any) -> Result<ICS26Envelope, Error> {
+    match any_msg.type_url.as_str() {
+        // ICS2 messages
+        create_client::TYPE_URL => {
+            // Pop out the message and then wrap it in the corresponding type.
+            let domain_msg = create_client::MsgCreateAnyClient::decode_vec(&any_msg.value)
+                .map_err(|e| Kind::MalformedMessageBytes.context(e))?;
+            Ok(ICS2Msg(ClientMsg::CreateClient(domain_msg)))
+        }
+        update_client::TYPE_URL => {
+            let domain_msg = update_client::MsgUpdateAnyClient::decode_vec(&any_msg.value)
+                .map_err(|e| Kind::MalformedMessageBytes.context(e))?;
+            Ok(ICS2Msg(ClientMsg::UpdateClient(domain_msg)))
+        }
+        misbehaviour::TYPE_URL => {
+            let domain_msg = misbehaviour::MsgSubmitAnyMisbehaviour::decode_vec(&any_msg.value)
+                .map_err(|e| Kind::MalformedMessageBytes.context(e))?;
+            Ok(ICS2Msg(ClientMsg::Misbehaviour(domain_msg)))
+        }
+        upgrade_client::TYPE_URL => {
+            let domain_msg = upgrade_client::MsgUpgradeAnyClient::decode_vec(&any_msg.value)
+                .map_err(|e| Kind::MalformedMessageBytes.context(e))?;
+            Ok(ICS2Msg(ClientMsg::UpgradeClient(domain_msg)))
+        }
+
+        // ICS3 messages
+        conn_open_init::TYPE_URL => {
+            let domain_msg = conn_open_init::MsgConnectionOpenInit::decode_vec(&any_msg.value)
+                .map_err(|e| Kind::MalformedMessageBytes.context(e))?;
+            Ok(ICS3Msg(ConnectionMsg::ConnectionOpenInit(domain_msg)))
+        }
+        conn_open_try::TYPE_URL => {
+            let domain_msg = conn_open_try::MsgConnectionOpenTry::decode_vec(&any_msg.value)
+                .map_err(|e| Kind::MalformedMessageBytes.context(e))?;
+            Ok(ICS3Msg(ConnectionMsg::ConnectionOpenTry(Box::new(
+                domain_msg,
+            ))))
+        }
+        conn_open_ack::TYPE_URL => {
+            let domain_msg = conn_open_ack::MsgConnectionOpenAck::decode_vec(&any_msg.value)
+                .map_err(|e| Kind::MalformedMessageBytes.context(e))?;
+            Ok(ICS3Msg(ConnectionMsg::ConnectionOpenAck(Box::new(
+                domain_msg,
+            ))))
+        }
+        conn_open_confirm::TYPE_URL => {
+            let domain_msg =
+                conn_open_confirm::MsgConnectionOpenConfirm::decode_vec(&any_msg.value)
+                    .map_err(|e| Kind::MalformedMessageBytes.context(e))?;
+            Ok(ICS3Msg(ConnectionMsg::ConnectionOpenConfirm(domain_msg)))
+        }
+
+        // ICS4 channel messages
+        chan_open_init::TYPE_URL => {
+            let domain_msg = chan_open_init::MsgChannelOpenInit::decode_vec(&any_msg.value)
+                .map_err(|e| Kind::MalformedMessageBytes.context(e))?;
+            Ok(ICS4ChannelMsg(ChannelMsg::ChannelOpenInit(domain_msg)))
+        }
+        chan_open_try::TYPE_URL => {
+            let domain_msg = chan_open_try::MsgChannelOpenTry::decode_vec(&any_msg.value)
+                .map_err(|e| Kind::MalformedMessageBytes.context(e))?;
+            Ok(ICS4ChannelMsg(ChannelMsg::ChannelOpenTry(domain_msg)))
+        }
+        chan_open_ack::TYPE_URL => {
+            let domain_msg = chan_open_ack::MsgChannelOpenAck::decode_vec(&any_msg.value)
+                .map_err(|e| Kind::MalformedMessageBytes.context(e))?;
+            Ok(ICS4ChannelMsg(ChannelMsg::ChannelOpenAck(domain_msg)))
+        }
+        chan_open_confirm::TYPE_URL => {
+            let domain_msg = chan_open_confirm::MsgChannelOpenConfirm::decode_vec(&any_msg.value)
+                .map_err(|e| Kind::MalformedMessageBytes.context(e))?;
+            Ok(ICS4ChannelMsg(ChannelMsg::ChannelOpenConfirm(domain_msg)))
+        }
+        chan_close_init::TYPE_URL => {
+            let domain_msg = chan_close_init::MsgChannelCloseInit::decode_vec(&any_msg.value)
+                .map_err(|e| Kind::MalformedMessageBytes.context(e))?;
+            Ok(ICS4ChannelMsg(ChannelMsg::ChannelCloseInit(domain_msg)))
+        }
+        chan_close_confirm::TYPE_URL => {
+            let domain_msg = chan_close_confirm::MsgChannelCloseConfirm::decode_vec(&any_msg.value)
+                .map_err(|e| Kind::MalformedMessageBytes.context(e))?;
+            Ok(ICS4ChannelMsg(ChannelMsg::ChannelCloseConfirm(domain_msg)))
+        }
+
+        // ICS4 packet messages
+        recv_packet::TYPE_URL => {
+            let domain_msg = recv_packet::MsgRecvPacket::decode_vec(&any_msg.value)
+                .map_err(|e| Kind::MalformedMessageBytes.context(e))?;
+            Ok(ICS4PacketMsg(PacketMsg::RecvPacket(domain_msg)))
+        }
+        acknowledgement::TYPE_URL => {
+            let domain_msg = acknowledgement::MsgAcknowledgement::decode_vec(&any_msg.value)
+                .map_err(|e| Kind::MalformedMessageBytes.context(e))?;
+            Ok(ICS4PacketMsg(PacketMsg::AckPacket(domain_msg)))
+        }
+        timeout::TYPE_URL => {
+            let domain_msg = timeout::MsgTimeout::decode_vec(&any_msg.value)
+                .map_err(|e| Kind::MalformedMessageBytes.context(e))?;
+            Ok(ICS4PacketMsg(PacketMsg::ToPacket(domain_msg)))
+        }
+        timeout_on_close::TYPE_URL => {
+            let domain_msg = timeout_on_close::MsgTimeoutOnClose::decode_vec(&any_msg.value)
+                .map_err(|e| Kind::MalformedMessageBytes.context(e))?;
+            Ok(ICS4PacketMsg(PacketMsg::ToClosePacket(domain_msg)))
+        }
+        _ => Err(Kind::UnknownMessageTypeURL(any_msg.type_url).into()),
+    }
 }
 
 /// Top-level ICS dispatch function. Routes incoming IBC messages to their corresponding module.
@@ -86,6 +270,12 @@ where
             let handler_output =
                 ics3_msg_dispatcher(ctx, msg).map_err(|e| Kind::HandlerRaisedError.context(e))?;
 
+            // Verifying the proofs of the message may have updated the client of the connection.
+            if let Some(client_state) = handler_output.result.client_state.clone() {
+                let client_id = handler_output.result.connection_end.client_id().clone();
+                store_client_state(ctx, client_id, client_state)?;
+            }
+
             // Apply any results to the host chain store.
             ctx.store_connection_result(handler_output.result)
                 .map_err(|e| Kind::KeeperRaisedError.context(e))?;
@@ -96,28 +286,315 @@ where
                 .with_result(())
         }
 
-        ICS4Msg(msg) => {
-            let handler_output =
-                ics4_msg_dispatcher(ctx, msg).map_err(|e| Kind::HandlerRaisedError.context(e))?;
+        ICS4ChannelMsg(msg) => {
+            let (module_id, module) = lookup_module(ctx, channel_msg_port(&msg))?;
+            let channel_cap =
+                module_capability(ctx, &module_id, &channel_msg_capability_name(&msg));
+
+            let mut handler_output = ics4_msg_dispatcher(ctx, channel_cap.as_ref(), msg.clone())
+                .map_err(|e| Kind::HandlerRaisedError.context(e))?;
+
+            // Hand the handshake step over to the module bound to the port of the channel, before
+            // applying anything to the host chain store.
+            let result = &mut handler_output.result;
+            channel_callback(
+                ctx,
+                module.as_ref(),
+                &msg,
+                &result.port_id,
+                &result.channel_id,
+                &mut result.channel_end,
+            )?;
+
+            let port_id = handler_output.result.port_id.clone();
+            let channel_id = handler_output.result.channel_id.clone();
+            let new_channel = handler_output.result.channel_id_state == ChannelIdState::Generated;
+
+            // Verifying the proofs of the message may have updated the client of the channel.
+            if let Some((client_id, client_state)) = handler_output.result.client_state.clone() {
+                store_client_state(ctx, client_id, client_state)?;
+            }
 
             // Apply any results to the host chain store.
             ctx.store_channel_result(handler_output.result)
                 .map_err(|e| Kind::KeeperRaisedError.context(e))?;
 
+            // A channel opened on the host chain is given its own capability, which the module
+            // bound to the port of the channel owns.
+            if new_channel {
+                ctx.new_channel_capability(&port_id, &channel_id)
+                    .map_err(|e| Kind::KeeperRaisedError.context(e))?;
+            }
+
             HandlerOutput::builder()
                 .with_log(handler_output.log)
                 .with_events(handler_output.events)
                 .with_result(())
+        }
+
+        ICS4PacketMsg(msg) => {
+            let (module_id, module) = lookup_module(ctx, packet_msg_port(&msg))?;
+            let channel_cap = module_capability(ctx, &module_id, &packet_msg_capability_name(&msg));
+
+            let handler_output =
+                ics04_packet_msg_dispatcher(ctx, channel_cap.as_ref(), msg.clone())
+                    .map_err(|e| Kind::HandlerRaisedError.context(e))?;
+
+            // Hand the packet over to the module bound to the port of the local channel end,
+            // before applying anything to the host chain store.
+            let ack = packet_callback(ctx, module.as_ref(), &msg)?;
+
+            let client_state = match &handler_output.result {
+                PacketResult::Send(_) => None,
+                PacketResult::Recv(res) => res.client_state.clone(),
+                PacketResult::Ack(res) => res.client_state.clone(),
+                PacketResult::Timeout(res) => res.client_state.clone(),
+            };
+            if let Some((client_id, client_state)) = client_state {
+                store_client_state(ctx, client_id, client_state)?;
+            }
+
+            // Apply any results to the host chain store.
+            ctx.store_packet_result(handler_output.result)
+                .map_err(|e| Kind::KeeperRaisedError.context(e))?;
+
+            let mut events = handler_output.events;
+            if let (PacketMsg::RecvPacket(msg), Some(ack)) = (&msg, ack) {
+                events.push(write_acknowledgement(ctx, msg.packet(), ack)?);
+            }
+
+            HandlerOutput::builder()
+                .with_log(handler_output.log)
+                .with_events(events)
+                .with_result(())
         } // TODO: add dispatchers for others.
     };
 
     Ok(output)
 }
 
+/// Stores the new state of a client, which verifying the proofs of a message updated (see
+/// `ClientDef`).
+fn store_client_state<Ctx>(
+    ctx: &mut Ctx,
+    client_id: ClientId,
+    client_state: AnyClientState,
+) -> Result<(), Error>
+where
+    Ctx: ICS26Context,
+{
+    ctx.store_client_state(client_id, client_state)
+        .map_err(|e| Kind::KeeperRaisedError.context(e).into())
+}
+
+/// Returns the module bound to the given port, along with its identifier.
+fn lookup_module<Ctx>(
+    ctx: &Ctx,
+    port_id: &PortId,
+) -> Result<(ModuleId, Arc<dyn Module<Ctx>>), Error>
+where
+    Ctx: ICS26Context,
+{
+    ctx.router()
+        .route(port_id)
+        .ok_or_else(|| Kind::ModuleNotFound(port_id.clone()).into())
+}
+
+/// Returns the capability which the given module owns under the given name, if any. The ICS4
+/// handlers process the messages handed over to the module on behalf of this capability, which
+/// they authenticate.
+fn module_capability<Ctx>(ctx: &Ctx, module_id: &ModuleId, name: &str) -> Option<Capability>
+where
+    Ctx: ICS26Context,
+{
+    ctx.capability_keeper().get_capability(module_id, name)
+}
+
+/// Returns the port of the local channel end which the given channel message refers to.
+fn channel_msg_port(msg: &ChannelMsg) -> &PortId {
+    match msg {
+        ChannelMsg::ChannelOpenInit(msg) => msg.port_id(),
+        ChannelMsg::ChannelOpenTry(msg) => msg.port_id(),
+        ChannelMsg::ChannelOpenAck(msg) => msg.port_id(),
+        ChannelMsg::ChannelOpenConfirm(msg) => msg.port_id(),
+        ChannelMsg::ChannelCloseInit(msg) => msg.port_id(),
+        ChannelMsg::ChannelCloseConfirm(msg) => msg.port_id(),
+    }
+}
+
+/// Returns the name of the capability authorising the channel handshake step in `msg`: the
+/// capability of the port for the steps opening a channel on the host chain, and the capability
+/// of the channel afterwards.
+pub(crate) fn channel_msg_capability_name(msg: &ChannelMsg) -> String {
+    match msg {
+        ChannelMsg::ChannelOpenInit(msg) => port_capability_name(msg.port_id()),
+        ChannelMsg::ChannelOpenTry(msg) => match msg.previous_channel_id() {
+            Some(channel_id) => channel_capability_name(msg.port_id(), channel_id),
+            None => port_capability_name(msg.port_id()),
+        },
+        ChannelMsg::ChannelOpenAck(msg) => channel_capability_name(msg.port_id(), msg.channel_id()),
+        ChannelMsg::ChannelOpenConfirm(msg) => {
+            channel_capability_name(msg.port_id(), msg.channel_id())
+        }
+        ChannelMsg::ChannelCloseInit(msg) => {
+            channel_capability_name(msg.port_id(), msg.channel_id())
+        }
+        ChannelMsg::ChannelCloseConfirm(msg) => {
+            channel_capability_name(msg.port_id(), msg.channel_id())
+        }
+    }
+}
+
+/// Returns the name of the capability of the local channel end which the given packet message
+/// refers to: the destination channel for received packets, and the source channel otherwise.
+pub(crate) fn packet_msg_capability_name(msg: &PacketMsg) -> String {
+    match msg {
+        PacketMsg::RecvPacket(msg) => channel_capability_name(
+            &msg.packet().destination_port,
+            &msg.packet().destination_channel,
+        ),
+        PacketMsg::AckPacket(msg) => {
+            channel_capability_name(&msg.packet().source_port, &msg.packet().source_channel)
+        }
+        PacketMsg::ToPacket(msg) => {
+            channel_capability_name(&msg.packet().source_port, &msg.packet().source_channel)
+        }
+        PacketMsg::ToClosePacket(msg) => {
+            channel_capability_name(&msg.packet().source_port, &msg.packet().source_channel)
+        }
+    }
+}
+
+/// Returns the port of the local channel end which the given packet message refers to: the
+/// destination port for received packets, and the source port otherwise.
+fn packet_msg_port(msg: &PacketMsg) -> &PortId {
+    match msg {
+        PacketMsg::RecvPacket(msg) => &msg.packet().destination_port,
+        PacketMsg::AckPacket(msg) => &msg.packet().source_port,
+        PacketMsg::ToPacket(msg) => &msg.packet().source_port,
+        PacketMsg::ToClosePacket(msg) => &msg.packet().source_port,
+    }
+}
+
+/// Invokes the callback of `module` corresponding to the channel handshake step in `msg`, which
+/// passed the checks of the ICS4 handlers but is not yet applied to the host chain store. Upon
+/// `ChannelOpenTry`, the version of `channel_end` is set to the one the module settles on.
+fn channel_callback<Ctx>(
+    ctx: &mut Ctx,
+    module: &dyn Module<Ctx>,
+    msg: &ChannelMsg,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    channel_end: &mut ChannelEnd,
+) -> Result<(), Error>
+where
+    Ctx: ICS26Context,
+{
+    let callback_failed = |e: Box<dyn std::error::Error>| {
+        Kind::ModuleCallbackFailed(port_id.clone()).context(e.to_string())
+    };
+
+    match msg {
+        ChannelMsg::ChannelOpenInit(_) => module
+            .on_chan_open_init(ctx, port_id, channel_id, channel_end)
+            .map_err(callback_failed)?,
+        ChannelMsg::ChannelOpenTry(msg) => {
+            let version = module
+                .on_chan_open_try(
+                    ctx,
+                    port_id,
+                    channel_id,
+                    channel_end,
+                    msg.counterparty_version(),
+                )
+                .map_err(callback_failed)?;
+            channel_end.set_version(version);
+        }
+        ChannelMsg::ChannelOpenAck(msg) => module
+            .on_chan_open_ack(ctx, port_id, channel_id, msg.counterparty_version())
+            .map_err(callback_failed)?,
+        ChannelMsg::ChannelOpenConfirm(_) => module
+            .on_chan_open_confirm(ctx, port_id, channel_id)
+            .map_err(callback_failed)?,
+        ChannelMsg::ChannelCloseInit(_) => module
+            .on_chan_close_init(ctx, port_id, channel_id)
+            .map_err(callback_failed)?,
+        ChannelMsg::ChannelCloseConfirm(_) => module
+            .on_chan_close_confirm(ctx, port_id, channel_id)
+            .map_err(callback_failed)?,
+    }
+
+    Ok(())
+}
+
+/// Invokes the callback of `module` corresponding to the packet message `msg`, which passed the
+/// checks of the ICS4 handlers but is not yet applied to the host chain store. Returns the
+/// acknowledgement returned by the module for received packets.
+fn packet_callback<Ctx>(
+    ctx: &mut Ctx,
+    module: &dyn Module<Ctx>,
+    msg: &PacketMsg,
+) -> Result<Option<Vec<u8>>, Error>
+where
+    Ctx: ICS26Context,
+{
+    let port_id = packet_msg_port(msg);
+    let callback_failed = |e: Box<dyn std::error::Error>| {
+        Kind::ModuleCallbackFailed(port_id.clone()).context(e.to_string())
+    };
+
+    match msg {
+        PacketMsg::RecvPacket(msg) => {
+            let ack = module
+                .on_recv_packet(ctx, msg.packet())
+                .map_err(callback_failed)?;
+            return Ok(Some(ack));
+        }
+        PacketMsg::AckPacket(msg) => module
+            .on_acknowledgement_packet(ctx, msg.packet(), msg.acknowledgement())
+            .map_err(callback_failed)?,
+        PacketMsg::ToPacket(msg) => module
+            .on_timeout_packet(ctx, msg.packet())
+            .map_err(callback_failed)?,
+        PacketMsg::ToClosePacket(msg) => module
+            .on_timeout_packet(ctx, msg.packet())
+            .map_err(callback_failed)?,
+    }
+
+    Ok(None)
+}
+
+/// Writes the acknowledgement of a received packet, as returned by the module bound to the
+/// destination port, and returns the corresponding event.
+fn write_acknowledgement<Ctx>(
+    ctx: &mut Ctx,
+    packet: &Packet,
+    ack: Vec<u8>,
+) -> Result<IBCEvent, Error>
+where
+    Ctx: ICS26Context,
+{
+    let ack_commitment = ctx.ack_commitment(&ack);
+    ctx.store_packet_acknowledgement(
+        (
+            packet.destination_port.clone(),
+            packet.destination_channel.clone(),
+            packet.sequence,
+        ),
+        ack_commitment,
+    )
+    .map_err(|e| Kind::KeeperRaisedError.context(e))?;
+
+    Ok(IBCEvent::WriteAcknowledgement(WriteAcknowledgement {
+        height: Default::default(),
+        packet: packet.clone(),
+        ack,
+    }))
+}
+
 #[cfg(test)]
 mod tests {
     use std::convert::TryFrom;
-    use std::str::FromStr;
 
     use crate::ics02_client::msgs::create_client::MsgCreateAnyClient;
     use crate::ics02_client::msgs::update_client::MsgUpdateAnyClient;
@@ -145,21 +622,76 @@ mod tests {
         ics04_channel::msgs::chan_close_confirm::MsgChannelCloseConfirm,
     };
 
+    use crate::ics04_channel::channel::ChannelEnd;
+    use crate::ics04_channel::context::ChannelReader;
+    use crate::ics04_channel::error::{Error as ICS4Error, Kind as ICS4Kind};
     use crate::ics04_channel::msgs::chan_open_init::test_util::get_dummy_raw_msg_chan_open_init_with_missing_connection;
     use crate::ics04_channel::msgs::chan_open_init::MsgChannelOpenInit;
     use crate::ics04_channel::msgs::chan_open_try::MsgChannelOpenTry;
     use crate::ics04_channel::msgs::ChannelMsg;
-    use crate::ics24_host::identifier::ChannelId;
+    use crate::ics04_channel::packet::Packet;
+    use crate::ics05_port::capabilities::{port_capability_name, ModuleId};
+    use crate::ics05_port::context::PortKeeper;
+    use crate::ics24_host::identifier::{ChannelId, PortId};
+    use crate::ics26_routing::module::Module;
+    use crate::mock::module::MockModule;
+    use std::error::Error as _;
+
+    use prost_types::Any;
+    use tendermint_proto::Protobuf;
+
+    use ibc_proto::ibc::core::client::v1::{
+        MsgCreateClient as RawMsgCreateClient, MsgUpdateClient as RawMsgUpdateClient,
+    };
 
     use crate::events::IBCEvent;
-    use crate::ics26_routing::handler::dispatch;
+    use crate::gas::{BasicGasMeter, GasCosts, GasMeter};
+    use crate::ics02_client::client_def::AnyHeader;
+    use crate::ics02_client::client_type::ClientType;
+    use crate::ics02_client::context::ClientReader;
+    use crate::ics24_host::identifier::ClientId;
+    use crate::ics24_host::store::{diff, StoreChange};
+    use crate::ics24_host::Path;
+    use crate::ics26_routing::error::Kind;
+    use crate::ics26_routing::handler::{decode, deliver, dispatch, simulate};
     use crate::ics26_routing::msgs::ICS26Envelope;
     use crate::mock::client_state::{MockClientState, MockConsensusState};
     use crate::mock::context::MockContext;
     use crate::mock::header::MockHeader;
     use crate::test_utils::get_dummy_account_id;
+    use crate::tx_msg::Msg;
     use crate::Height;
 
+    use ibc_proto::ibc::core::channel::v1::{
+        MsgAcknowledgement as RawMsgAcknowledgement,
+        MsgChannelCloseConfirm as RawMsgChannelCloseConfirm,
+        MsgChannelCloseInit as RawMsgChannelCloseInit, MsgChannelOpenAck as RawMsgChannelOpenAck,
+        MsgChannelOpenConfirm as RawMsgChannelOpenConfirm,
+        MsgChannelOpenInit as RawMsgChannelOpenInit, MsgChannelOpenTry as RawMsgChannelOpenTry,
+        MsgRecvPacket as RawMsgRecvPacket, MsgTimeout as RawMsgTimeout,
+        MsgTimeoutOnClose as RawMsgTimeoutOnClose,
+    };
+    use ibc_proto::ibc::core::client::v1::{
+        MsgSubmitMisbehaviour as RawMsgSubmitMisbehaviour, MsgUpgradeClient as RawMsgUpgradeClient,
+    };
+    use ibc_proto::ibc::core::connection::v1::{
+        MsgConnectionOpenAck as RawMsgConnectionOpenAck,
+        MsgConnectionOpenConfirm as RawMsgConnectionOpenConfirm,
+        MsgConnectionOpenInit as RawMsgConnectionOpenInit,
+        MsgConnectionOpenTry as RawMsgConnectionOpenTry,
+    };
+    use proptest::prelude::*;
+
+    use crate::ics02_client::msgs::misbehaviour::MsgSubmitAnyMisbehaviour;
+    use crate::ics02_client::msgs::upgrade_client::MsgUpgradeAnyClient;
+    use crate::ics03_connection::msgs::conn_open_confirm::MsgConnectionOpenConfirm;
+    use crate::ics04_channel::msgs::acknowledgement::MsgAcknowledgement;
+    use crate::ics04_channel::msgs::chan_open_confirm::MsgChannelOpenConfirm;
+    use crate::ics04_channel::msgs::recv_packet::MsgRecvPacket;
+    use crate::ics04_channel::msgs::timeout::MsgTimeout;
+    use crate::ics04_channel::msgs::timeout_on_close::MsgTimeoutOnClose;
+    use crate::ics04_channel::msgs::PacketMsg;
+
     #[test]
     // These tests exercise two main paths: (1) the ability of the ICS26 routing module to dispatch
     // messages to the correct module handler, and more importantly: (2) the ability of ICS handlers
@@ -177,8 +709,10 @@ mod tests {
         let update_client_height = Height::new(0, 34);
 
         let create_client_msg = MsgCreateAnyClient::new(
-            AnyClientState::from(MockClientState(MockHeader(start_client_height))),
-            AnyConsensusState::from(MockConsensusState(MockHeader(start_client_height))),
+            AnyClientState::from(MockClientState::new(MockHeader::new(start_client_height))),
+            AnyConsensusState::from(MockConsensusState::new(MockHeader::new(
+                start_client_height,
+            ))),
             get_dummy_account_id(),
         )
         .unwrap();
@@ -212,11 +746,7 @@ mod tests {
         // We reuse this same context across all tests. Nothing in particular needs parametrizing.
         let mut ctx = MockContext::default();
 
-        let prefix = ChannelId::default().to_string();
-        let suffix = 0;
-        msg_chan_try2.previous_channel_id = Some(
-            <ChannelId as FromStr>::from_str(format!("{}-{}", prefix, suffix).as_str()).unwrap(),
-        );
+        msg_chan_try2.previous_channel_id = Some(ChannelId::new(0));
 
         let msg_chan_ack =
             MsgChannelOpenAck::try_from(get_dummy_raw_msg_chan_open_ack_ics26(proof_height))
@@ -230,7 +760,7 @@ mod tests {
         )
         .unwrap();
 
-        msg_chan_close_init.channel_id = ChannelId::from_str("defaultChannel-0").unwrap();
+        msg_chan_close_init.channel_id = ChannelId::new(0);
 
         // First, create a client..
         let res = dispatch(
@@ -269,7 +799,7 @@ mod tests {
                 name: "Client update successful".to_string(),
                 msg: ICS26Envelope::ICS2Msg(ClientMsg::UpdateClient(MsgUpdateAnyClient {
                     client_id: client_id.clone(),
-                    header: MockHeader(update_client_height).into(),
+                    header: MockHeader::new(update_client_height).into(),
                     signer: default_signer,
                 })),
                 want_pass: true,
@@ -278,7 +808,7 @@ mod tests {
                 name: "Client update fails due to stale header".to_string(),
                 msg: ICS26Envelope::ICS2Msg(ClientMsg::UpdateClient(MsgUpdateAnyClient {
                     client_id: client_id.clone(),
-                    header: MockHeader(update_client_height).into(),
+                    header: MockHeader::new(update_client_height).into(),
                     signer: default_signer,
                 })),
                 want_pass: false,
@@ -322,32 +852,34 @@ mod tests {
             // ICS04
             Test {
                 name: "Channel open init succeeds".to_string(),
-                msg: ICS26Envelope::ICS4Msg(ChannelMsg::ChannelOpenInit(msg_chan_init)),
+                msg: ICS26Envelope::ICS4ChannelMsg(ChannelMsg::ChannelOpenInit(msg_chan_init)),
                 want_pass: true,
             },
             Test {
                 name: "Channel open init fail due to missing connection".to_string(),
-                msg: ICS26Envelope::ICS4Msg(ChannelMsg::ChannelOpenInit(msg_chan_init2)),
+                msg: ICS26Envelope::ICS4ChannelMsg(ChannelMsg::ChannelOpenInit(msg_chan_init2)),
                 want_pass: false,
             },
             Test {
                 name: "Channel open try succedes".to_string(),
-                msg: ICS26Envelope::ICS4Msg(ChannelMsg::ChannelOpenTry(msg_chan_try2)),
+                msg: ICS26Envelope::ICS4ChannelMsg(ChannelMsg::ChannelOpenTry(msg_chan_try2)),
                 want_pass: true,
             },
             Test {
                 name: "Channel open ack succedes".to_string(),
-                msg: ICS26Envelope::ICS4Msg(ChannelMsg::ChannelOpenAck(msg_chan_ack)),
+                msg: ICS26Envelope::ICS4ChannelMsg(ChannelMsg::ChannelOpenAck(msg_chan_ack)),
                 want_pass: true,
             },
             Test {
                 name: "Channel close init succedes".to_string(),
-                msg: ICS26Envelope::ICS4Msg(ChannelMsg::ChannelCloseInit(msg_chan_close_init)),
+                msg: ICS26Envelope::ICS4ChannelMsg(ChannelMsg::ChannelCloseInit(
+                    msg_chan_close_init,
+                )),
                 want_pass: true,
             },
             Test {
                 name: "Channel close confirm fails cause channel is already closed".to_string(),
-                msg: ICS26Envelope::ICS4Msg(ChannelMsg::ChannelCloseConfirm(
+                msg: ICS26Envelope::ICS4ChannelMsg(ChannelMsg::ChannelCloseConfirm(
                     msg_chan_close_confirm,
                 )),
                 want_pass: false,
@@ -369,4 +901,458 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn deliver_is_atomic() {
+        let signer = get_dummy_account_id();
+        let client_id = ClientId::new(ClientType::Mock, 0).unwrap();
+        let header = |height| AnyHeader::from(MockHeader::new(Height::new(0, height)));
+
+        let create_client = MsgCreateAnyClient::new(
+            AnyClientState::from(MockClientState::new(MockHeader::new(Height::new(0, 5)))),
+            AnyConsensusState::from(MockConsensusState::new(MockHeader::new(Height::new(0, 5)))),
+            signer,
+        )
+        .unwrap()
+        .to_any::<RawMsgCreateClient>();
+        let update_client = |client_id: &ClientId, height| {
+            MsgUpdateAnyClient::new(client_id.clone(), header(height), signer)
+                .to_any::<RawMsgUpdateClient>()
+        };
+
+        let mut ctx = MockContext::default();
+
+        // The third message updates a client which does not exist: none of them is applied.
+        let res = deliver(
+            &mut ctx,
+            vec![
+                create_client.clone(),
+                update_client(&client_id, 10),
+                update_client(&ClientId::new(ClientType::Mock, 7).unwrap(), 10),
+            ],
+        );
+        assert!(res.is_err());
+        assert_eq!(ClientReader::client_counter(&ctx), 0);
+        assert!(ClientReader::client_state(&ctx, &client_id).is_none());
+
+        // Nor are messages which fail to decode.
+        let malformed = Any {
+            type_url: create_client.type_url.clone(),
+            value: vec![0xff],
+        };
+        assert!(deliver(&mut ctx, vec![create_client.clone(), malformed]).is_err());
+        assert_eq!(ClientReader::client_counter(&ctx), 0);
+
+        // All the messages are applied when they all succeed, and their logs and events are
+        // returned in order.
+        let output = deliver(&mut ctx, vec![create_client, update_client(&client_id, 10)]).unwrap();
+        assert!(matches!(
+            output.events.as_slice(),
+            [IBCEvent::CreateClient(_), IBCEvent::UpdateClient(_)]
+        ));
+        assert_eq!(
+            output.log,
+            vec!["success: generated new client identifier".to_string()]
+        );
+        assert_eq!(
+            ClientReader::client_state(&ctx, &client_id)
+                .unwrap()
+                .latest_height(),
+            Height::new(0, 10)
+        );
+    }
+
+    #[test]
+    fn deliver_handshake_messages() {
+        let create_client = MsgCreateAnyClient::new(
+            AnyClientState::from(MockClientState::new(MockHeader::new(Height::new(0, 5)))),
+            AnyConsensusState::from(MockConsensusState::new(MockHeader::new(Height::new(0, 5)))),
+            get_dummy_account_id(),
+        )
+        .unwrap()
+        .to_any::<RawMsgCreateClient>();
+
+        let mut ctx = MockContext::default();
+        let output = deliver(&mut ctx, vec![create_client]).unwrap();
+        let client_id = match output.events.as_slice() {
+            [IBCEvent::CreateClient(create_client)] => create_client.client_id().clone(),
+            events => panic!("unexpected IBC events: {:?}", events),
+        };
+
+        // The connection handshake, as relayed to the host chain in a single transaction.
+        let conn_open_init = MsgConnectionOpenInit::try_from(get_dummy_msg_conn_open_init_ics26())
+            .unwrap()
+            .with_client_id(client_id);
+        let conn_open_try =
+            MsgConnectionOpenTry::try_from(get_dummy_msg_conn_open_try_ics26(5, 5)).unwrap();
+        let conn_open_ack =
+            MsgConnectionOpenAck::try_from(get_dummy_msg_conn_open_ack_ics26(5, 5)).unwrap();
+
+        let output = deliver(
+            &mut ctx,
+            vec![
+                conn_open_init.to_any::<RawMsgConnectionOpenInit>(),
+                conn_open_try.to_any::<RawMsgConnectionOpenTry>(),
+                conn_open_ack.to_any::<RawMsgConnectionOpenAck>(),
+            ],
+        )
+        .unwrap();
+        assert!(matches!(
+            output.events.as_slice(),
+            [
+                IBCEvent::OpenInitConnection(_),
+                IBCEvent::OpenTryConnection(_),
+                IBCEvent::OpenAckConnection(_)
+            ]
+        ));
+
+        // The channel handshake, over the connection opened above.
+        let chan_open_init =
+            MsgChannelOpenInit::try_from(get_dummy_raw_msg_chan_open_init_ics26()).unwrap();
+        let mut chan_open_try =
+            MsgChannelOpenTry::try_from(get_dummy_raw_msg_chan_open_try_ics26(5)).unwrap();
+        chan_open_try.previous_channel_id = Some(ChannelId::new(0));
+        let chan_open_ack =
+            MsgChannelOpenAck::try_from(get_dummy_raw_msg_chan_open_ack_ics26(5)).unwrap();
+        ctx.add_port(chan_open_init.port_id().clone());
+
+        let output = deliver(
+            &mut ctx,
+            vec![
+                chan_open_init.to_any::<RawMsgChannelOpenInit>(),
+                chan_open_try.to_any::<RawMsgChannelOpenTry>(),
+                chan_open_ack.to_any::<RawMsgChannelOpenAck>(),
+            ],
+        )
+        .unwrap();
+        assert!(matches!(
+            output.events.as_slice(),
+            [
+                IBCEvent::OpenInitChannel(_),
+                IBCEvent::OpenTryChannel(_),
+                IBCEvent::OpenAckChannel(_)
+            ]
+        ));
+    }
+
+    #[test]
+    fn module_callbacks_precede_store_writes() {
+        /// A module which vetoes the channels opened on the host chain, and settles on its own
+        /// version for the channels opened by the counterparty.
+        #[derive(Debug)]
+        struct PickyModule;
+
+        impl Module<MockContext> for PickyModule {
+            fn on_chan_open_init(
+                &self,
+                _ctx: &mut MockContext,
+                _port_id: &PortId,
+                _channel_id: &ChannelId,
+                _channel_end: &ChannelEnd,
+            ) -> Result<(), Box<dyn std::error::Error>> {
+                Err("channels are opened by the counterparty".into())
+            }
+
+            fn on_chan_open_try(
+                &self,
+                _ctx: &mut MockContext,
+                _port_id: &PortId,
+                _channel_id: &ChannelId,
+                _channel_end: &ChannelEnd,
+                _counterparty_version: &str,
+            ) -> Result<String, Box<dyn std::error::Error>> {
+                Ok("picky".to_string())
+            }
+
+            fn on_recv_packet(
+                &self,
+                _ctx: &mut MockContext,
+                _packet: &Packet,
+            ) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+                Ok(vec![])
+            }
+        }
+
+        let chan_open_init =
+            MsgChannelOpenInit::try_from(get_dummy_raw_msg_chan_open_init_ics26()).unwrap();
+        let chan_open_try =
+            MsgChannelOpenTry::try_from(get_dummy_raw_msg_chan_open_try_ics26(5)).unwrap();
+        let port_id = chan_open_init.port_id().clone();
+
+        let mut ctx = MockContext::default().with_module(port_id.clone(), PickyModule);
+        let create_client = MsgCreateAnyClient::new(
+            AnyClientState::from(MockClientState::new(MockHeader::new(Height::new(0, 5)))),
+            AnyConsensusState::from(MockConsensusState::new(MockHeader::new(Height::new(0, 5)))),
+            get_dummy_account_id(),
+        )
+        .unwrap()
+        .to_any::<RawMsgCreateClient>();
+        let conn_open_init =
+            MsgConnectionOpenInit::try_from(get_dummy_msg_conn_open_init_ics26()).unwrap();
+        let conn_open_try =
+            MsgConnectionOpenTry::try_from(get_dummy_msg_conn_open_try_ics26(5, 5)).unwrap();
+        let conn_open_ack =
+            MsgConnectionOpenAck::try_from(get_dummy_msg_conn_open_ack_ics26(5, 5)).unwrap();
+        deliver(
+            &mut ctx,
+            vec![
+                create_client,
+                conn_open_init.to_any::<RawMsgConnectionOpenInit>(),
+                conn_open_try.to_any::<RawMsgConnectionOpenTry>(),
+                conn_open_ack.to_any::<RawMsgConnectionOpenAck>(),
+            ],
+        )
+        .unwrap();
+
+        // The vetoed channel is neither stored nor given an identifier or a capability.
+        let before = ctx.clone();
+        let err = dispatch(
+            &mut ctx,
+            ICS26Envelope::ICS4ChannelMsg(ChannelMsg::ChannelOpenInit(chan_open_init)),
+        )
+        .unwrap_err();
+        assert!(matches!(err.kind(), Kind::ModuleCallbackFailed(_)));
+        assert!(diff(before.store(), ctx.store()).unwrap().is_empty());
+        assert_eq!(ChannelReader::channel_counter(&ctx), 0);
+        assert!(ctx
+            .channel_capability(&port_id, &ChannelId::new(0))
+            .is_err());
+
+        // The channel opened by the counterparty is stored with the version of the module.
+        dispatch(
+            &mut ctx,
+            ICS26Envelope::ICS4ChannelMsg(ChannelMsg::ChannelOpenTry(chan_open_try)),
+        )
+        .unwrap();
+        let channel_end = ChannelReader::channel_end(&ctx, &(port_id, ChannelId::new(0))).unwrap();
+        assert_eq!(channel_end.version(), "picky");
+    }
+
+    #[test]
+    fn modules_are_authenticated_by_their_capabilities() {
+        let chan_open_init =
+            MsgChannelOpenInit::try_from(get_dummy_raw_msg_chan_open_init_ics26()).unwrap();
+        let port_id = chan_open_init.port_id().clone();
+        let module_id = ModuleId::new(port_id.as_str());
+
+        let mut ctx = MockContext::default().with_module(port_id.clone(), MockModule);
+        let create_client = MsgCreateAnyClient::new(
+            AnyClientState::from(MockClientState::new(MockHeader::new(Height::new(0, 5)))),
+            AnyConsensusState::from(MockConsensusState::new(MockHeader::new(Height::new(0, 5)))),
+            get_dummy_account_id(),
+        )
+        .unwrap()
+        .to_any::<RawMsgCreateClient>();
+        let conn_open_init =
+            MsgConnectionOpenInit::try_from(get_dummy_msg_conn_open_init_ics26()).unwrap();
+        deliver(
+            &mut ctx,
+            vec![
+                create_client,
+                conn_open_init.to_any::<RawMsgConnectionOpenInit>(),
+            ],
+        )
+        .unwrap();
+
+        // The module bound to the port gives up the capability of the port, and forges one under
+        // the same name instead.
+        let mut intruder_ctx = ctx.clone();
+        let port_cap = intruder_ctx.port_capability(&port_id).unwrap();
+        let keeper = intruder_ctx.capability_keeper_mut();
+        keeper.release_capability(&module_id, &port_cap).unwrap();
+        keeper
+            .new_capability(&module_id, &port_capability_name(&port_id))
+            .unwrap();
+
+        let msg = ICS26Envelope::ICS4ChannelMsg(ChannelMsg::ChannelOpenInit(chan_open_init));
+        let err = dispatch(&mut intruder_ctx, msg.clone()).unwrap_err();
+        assert!(matches!(err.kind(), Kind::HandlerRaisedError));
+        let handler_err = err
+            .source()
+            .and_then(|source| source.downcast_ref::<ICS4Error>())
+            .unwrap();
+        assert!(matches!(
+            handler_err.kind(),
+            ICS4Kind::InvalidPortCapability(id) if id == &port_id
+        ));
+        assert_eq!(ChannelReader::channel_counter(&intruder_ctx), 0);
+
+        // The module holding the capability of the port opens the channel.
+        dispatch(&mut ctx, msg).unwrap();
+        assert_eq!(ChannelReader::channel_counter(&ctx), 1);
+    }
+
+    #[test]
+    fn simulate_leaves_context_untouched() {
+        let client_id = ClientId::new(ClientType::Mock, 0).unwrap();
+        let client_state =
+            AnyClientState::from(MockClientState::new(MockHeader::new(Height::new(0, 5))));
+        let consensus_state =
+            AnyConsensusState::from(MockConsensusState::new(MockHeader::new(Height::new(0, 5))));
+        let create_client = MsgCreateAnyClient::new(
+            client_state.clone(),
+            consensus_state.clone(),
+            get_dummy_account_id(),
+        )
+        .unwrap()
+        .to_any::<RawMsgCreateClient>();
+
+        let ctx = MockContext::default();
+        let simulation = simulate(&ctx, vec![create_client]).unwrap();
+
+        assert!(matches!(
+            simulation.output.events.as_slice(),
+            [IBCEvent::CreateClient(_)]
+        ));
+        assert_eq!(ClientReader::client_counter(&ctx), 0);
+        assert!(ClientReader::client_state(&ctx, &client_id).is_none());
+        assert!(ClientReader::client_state(&simulation.ctx, &client_id).is_some());
+
+        // The changes to the store are those of the client creation, in the order of the paths.
+        assert_eq!(
+            diff(ctx.store(), simulation.ctx.store()).unwrap(),
+            vec![
+                StoreChange::Set(
+                    Path::ClientState(client_id.clone()),
+                    client_state.encode_vec().unwrap()
+                ),
+                StoreChange::Set(
+                    Path::ClientType(client_id.clone()),
+                    ClientType::Mock.as_string().as_bytes().to_vec()
+                ),
+                StoreChange::Set(
+                    Path::ClientConsensusState {
+                        client_id,
+                        epoch: 0,
+                        height: 5
+                    },
+                    consensus_state.encode_vec().unwrap()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn deliver_consumes_gas() {
+        let client_id = ClientId::new(ClientType::Mock, 0).unwrap();
+        let header = AnyHeader::from(MockHeader::new(Height::new(0, 10)));
+        let update_client =
+            MsgUpdateAnyClient::new(client_id.clone(), header.clone(), get_dummy_account_id())
+                .to_any::<RawMsgUpdateClient>();
+
+        // Besides the verification of the header and the event of the update, the reads and
+        // writes of the store are charged.
+        let costs = GasCosts::default();
+        let ctx = MockContext::default().with_client(&client_id, Height::new(0, 5));
+
+        let mut ctx_measure = ctx.clone().with_gas_meter(BasicGasMeter::infinite(costs));
+        assert!(deliver(&mut ctx_measure, vec![update_client.clone()]).is_ok());
+        let gas = ctx_measure.gas_meter().gas_consumed();
+        assert!(gas > costs.verify_header(header.encode_vec().unwrap().len()) + costs.events(1));
+
+        let mut ctx_with_gas = ctx.clone().with_gas_meter(BasicGasMeter::new(gas, costs));
+        assert!(deliver(&mut ctx_with_gas, vec![update_client.clone()]).is_ok());
+        assert_eq!(ctx_with_gas.gas_meter().gas_consumed(), gas);
+
+        // With a unit of gas less, the message fails, and the context is left untouched, except
+        // for the gas consumed until the failure, which is still charged.
+        let mut ctx_out_of_gas = ctx.with_gas_meter(BasicGasMeter::new(gas - 1, costs));
+        let err = deliver(&mut ctx_out_of_gas, vec![update_client]).unwrap_err();
+        assert!(matches!(err.kind(), Kind::OutOfGas(_)));
+        assert!(ctx_out_of_gas.gas_meter().gas_consumed() >= gas);
+        assert_eq!(
+            ClientReader::client_state(&ctx_out_of_gas, &client_id)
+                .unwrap()
+                .latest_height(),
+            Height::new(0, 5)
+        );
+    }
+
+    /// The messages which the routing module processes, in their `Any` encoding, along with the
+    /// envelopes which hold them.
+    fn encoded_envelope() -> impl Strategy<Value = (Any, ICS26Envelope)> {
+        use ICS26Envelope::{ICS2Msg, ICS3Msg, ICS4ChannelMsg, ICS4PacketMsg};
+
+        prop_oneof![
+            any::<MsgCreateAnyClient>().prop_map(|msg| (
+                msg.to_any::<RawMsgCreateClient>(),
+                ICS2Msg(ClientMsg::CreateClient(msg))
+            )),
+            any::<MsgUpdateAnyClient>().prop_map(|msg| (
+                msg.to_any::<RawMsgUpdateClient>(),
+                ICS2Msg(ClientMsg::UpdateClient(msg))
+            )),
+            any::<MsgSubmitAnyMisbehaviour>().prop_map(|msg| (
+                msg.to_any::<RawMsgSubmitMisbehaviour>(),
+                ICS2Msg(ClientMsg::Misbehaviour(msg))
+            )),
+            any::<MsgUpgradeAnyClient>().prop_map(|msg| (
+                msg.to_any::<RawMsgUpgradeClient>(),
+                ICS2Msg(ClientMsg::UpgradeClient(msg))
+            )),
+            any::<MsgConnectionOpenInit>().prop_map(|msg| (
+                msg.to_any::<RawMsgConnectionOpenInit>(),
+                ICS3Msg(ConnectionMsg::ConnectionOpenInit(msg))
+            )),
+            any::<MsgConnectionOpenTry>().prop_map(|msg| (
+                msg.to_any::<RawMsgConnectionOpenTry>(),
+                ICS3Msg(ConnectionMsg::ConnectionOpenTry(Box::new(msg)))
+            )),
+            any::<MsgConnectionOpenAck>().prop_map(|msg| (
+                msg.to_any::<RawMsgConnectionOpenAck>(),
+                ICS3Msg(ConnectionMsg::ConnectionOpenAck(Box::new(msg)))
+            )),
+            any::<MsgConnectionOpenConfirm>().prop_map(|msg| (
+                msg.to_any::<RawMsgConnectionOpenConfirm>(),
+                ICS3Msg(ConnectionMsg::ConnectionOpenConfirm(msg))
+            )),
+            any::<MsgChannelOpenInit>().prop_map(|msg| (
+                msg.to_any::<RawMsgChannelOpenInit>(),
+                ICS4ChannelMsg(ChannelMsg::ChannelOpenInit(msg))
+            )),
+            any::<MsgChannelOpenTry>().prop_map(|msg| (
+                msg.to_any::<RawMsgChannelOpenTry>(),
+                ICS4ChannelMsg(ChannelMsg::ChannelOpenTry(msg))
+            )),
+            any::<MsgChannelOpenAck>().prop_map(|msg| (
+                msg.to_any::<RawMsgChannelOpenAck>(),
+                ICS4ChannelMsg(ChannelMsg::ChannelOpenAck(msg))
+            )),
+            any::<MsgChannelOpenConfirm>().prop_map(|msg| (
+                msg.to_any::<RawMsgChannelOpenConfirm>(),
+                ICS4ChannelMsg(ChannelMsg::ChannelOpenConfirm(msg))
+            )),
+            any::<MsgChannelCloseInit>().prop_map(|msg| (
+                msg.to_any::<RawMsgChannelCloseInit>(),
+                ICS4ChannelMsg(ChannelMsg::ChannelCloseInit(msg))
+            )),
+            any::<MsgChannelCloseConfirm>().prop_map(|msg| (
+                msg.to_any::<RawMsgChannelCloseConfirm>(),
+                ICS4ChannelMsg(ChannelMsg::ChannelCloseConfirm(msg))
+            )),
+            any::<MsgRecvPacket>().prop_map(|msg| (
+                msg.to_any::<RawMsgRecvPacket>(),
+                ICS4PacketMsg(PacketMsg::RecvPacket(msg))
+            )),
+            any::<MsgAcknowledgement>().prop_map(|msg| (
+                msg.to_any::<RawMsgAcknowledgement>(),
+                ICS4PacketMsg(PacketMsg::AckPacket(msg))
+            )),
+            any::<MsgTimeout>().prop_map(|msg| (
+                msg.to_any::<RawMsgTimeout>(),
+                ICS4PacketMsg(PacketMsg::ToPacket(msg))
+            )),
+            any::<MsgTimeoutOnClose>().prop_map(|msg| (
+                msg.to_any::<RawMsgTimeoutOnClose>(),
+                ICS4PacketMsg(PacketMsg::ToClosePacket(msg))
+            )),
+        ]
+    }
+
+    proptest! {
+        #[test]
+        fn decode_any_message((any_msg, envelope) in encoded_envelope()) {
+            prop_assert_eq!(decode(any_msg).unwrap(), envelope);
+        }
+    }
 }
@@ -3,4 +3,6 @@
 pub mod context;
 pub mod error;
 pub mod handler;
+pub mod module;
 pub mod msgs;
+pub mod router;
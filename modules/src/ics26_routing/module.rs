@@ -0,0 +1,106 @@
+//! The callbacks through which the routing module hands channel handshake steps and packets over
+//! to the application module bound to the port of the channel (see ICS26 `ModuleCallbacks`).
+
+use std::error::Error;
+use std::fmt::Debug;
+
+use crate::ics04_channel::channel::ChannelEnd;
+use crate::ics04_channel::packet::Packet;
+use crate::ics24_host::identifier::{ChannelId, PortId};
+
+/// An application module running on the host chain, e.g., fungible token transfer. The module
+/// keeps its state in the host chain context `Ctx`, which every callback receives.
+///
+/// The callbacks are invoked once the corresponding channel or packet message passed the checks of
+/// the ICS4 handlers, before the message is applied to the host chain store. A callback returning
+/// an error fails the processing of the message, which allows the module to veto handshakes and
+/// packets. Unless noted otherwise, the default
+/// implementations accept everything.
+pub trait Module<Ctx>: Debug + Send + Sync {
+    /// Called upon opening a channel on the local chain, with the channel end in state `Init`.
+    fn on_chan_open_init(
+        &self,
+        _ctx: &mut Ctx,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _channel_end: &ChannelEnd,
+    ) -> Result<(), Box<dyn Error>> {
+        Ok(())
+    }
+
+    /// Called upon opening a channel on the local chain in response to the counterparty opening
+    /// it, with the channel end in state `TryOpen`. Returns the channel version the module agrees
+    /// to, which is the version the relayer proposed by default.
+    fn on_chan_open_try(
+        &self,
+        _ctx: &mut Ctx,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        channel_end: &ChannelEnd,
+        _counterparty_version: &str,
+    ) -> Result<String, Box<dyn Error>> {
+        Ok(channel_end.version())
+    }
+
+    /// Called once the counterparty accepted the channel, which is now open on the local chain.
+    fn on_chan_open_ack(
+        &self,
+        _ctx: &mut Ctx,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _counterparty_version: &str,
+    ) -> Result<(), Box<dyn Error>> {
+        Ok(())
+    }
+
+    /// Called once the counterparty opened the channel, which is now open on the local chain.
+    fn on_chan_open_confirm(
+        &self,
+        _ctx: &mut Ctx,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+    ) -> Result<(), Box<dyn Error>> {
+        Ok(())
+    }
+
+    /// Called upon closing a channel on the local chain.
+    fn on_chan_close_init(
+        &self,
+        _ctx: &mut Ctx,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+    ) -> Result<(), Box<dyn Error>> {
+        Ok(())
+    }
+
+    /// Called upon closing a channel on the local chain in response to the counterparty closing
+    /// it.
+    fn on_chan_close_confirm(
+        &self,
+        _ctx: &mut Ctx,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+    ) -> Result<(), Box<dyn Error>> {
+        Ok(())
+    }
+
+    /// Processes a packet received from the counterparty, returning the acknowledgement to be
+    /// written on the local chain.
+    fn on_recv_packet(&self, ctx: &mut Ctx, packet: &Packet) -> Result<Vec<u8>, Box<dyn Error>>;
+
+    /// Processes the acknowledgement that the counterparty wrote for a packet sent by the module.
+    fn on_acknowledgement_packet(
+        &self,
+        _ctx: &mut Ctx,
+        _packet: &Packet,
+        _acknowledgement: &[u8],
+    ) -> Result<(), Box<dyn Error>> {
+        Ok(())
+    }
+
+    /// Processes the timeout of a packet sent by the module, which the counterparty never
+    /// received.
+    fn on_timeout_packet(&self, _ctx: &mut Ctx, _packet: &Packet) -> Result<(), Box<dyn Error>> {
+        Ok(())
+    }
+}
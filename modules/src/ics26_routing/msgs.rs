@@ -1,11 +1,13 @@
 use crate::ics02_client::msgs::ClientMsg;
 use crate::ics03_connection::msgs::ConnectionMsg;
-use crate::ics04_channel::msgs::ChannelMsg;
+use crate::ics04_channel::msgs::{ChannelMsg, PacketMsg};
 
 /// Enumeration of all messages that the local ICS26 module is capable of routing.
-#[derive(Clone, Debug)]
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum ICS26Envelope {
     ICS2Msg(ClientMsg),
     ICS3Msg(ConnectionMsg),
-    ICS4Msg(ChannelMsg),
+    ICS4ChannelMsg(ChannelMsg),
+    ICS4PacketMsg(PacketMsg),
 }
@@ -0,0 +1,101 @@
+//! The router which the routing module uses to look up the application module bound to a port.
+
+use std::collections::HashMap;
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+
+use crate::ics05_port::capabilities::ModuleId;
+use crate::ics24_host::identifier::PortId;
+use crate::ics26_routing::error::{Error, Kind};
+use crate::ics26_routing::module::Module;
+
+/// Maps ports to the application modules bound to them, within a host chain context `Ctx`. Each
+/// module is identified by the `ModuleId` under which it owns the capabilities of the port and of
+/// its channels.
+pub struct Router<Ctx> {
+    routes: HashMap<PortId, (ModuleId, Arc<dyn Module<Ctx>>)>,
+}
+
+impl<Ctx> Router<Ctx> {
+    /// Binds `module`, identified by `module_id`, to the given port. Fails if another module is
+    /// already bound to the port.
+    pub fn add_route(
+        &mut self,
+        port_id: PortId,
+        module_id: ModuleId,
+        module: impl Module<Ctx> + 'static,
+    ) -> Result<(), Error> {
+        if self.routes.contains_key(&port_id) {
+            return Err(Kind::RouteAlreadyExists(port_id).into());
+        }
+
+        self.routes.insert(port_id, (module_id, Arc::new(module)));
+        Ok(())
+    }
+
+    /// Returns the module bound to the given port, along with its identifier, if any.
+    pub fn route(&self, port_id: &PortId) -> Option<(ModuleId, Arc<dyn Module<Ctx>>)> {
+        self.routes.get(port_id).cloned()
+    }
+
+    pub fn has_route(&self, port_id: &PortId) -> bool {
+        self.routes.contains_key(port_id)
+    }
+}
+
+impl<Ctx> Default for Router<Ctx> {
+    fn default() -> Self {
+        Router {
+            routes: HashMap::new(),
+        }
+    }
+}
+
+impl<Ctx> Clone for Router<Ctx> {
+    fn clone(&self) -> Self {
+        Router {
+            routes: self.routes.clone(),
+        }
+    }
+}
+
+impl<Ctx> Debug for Router<Ctx> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_map().entries(self.routes.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::ics05_port::capabilities::ModuleId;
+    use crate::ics24_host::identifier::PortId;
+    use crate::ics26_routing::router::Router;
+    use crate::mock::context::MockContext;
+    use crate::mock::module::MockModule;
+
+    #[test]
+    fn router_routes_by_port() {
+        let port_id = PortId::from_str("transfer").unwrap();
+        let unbound_port_id = PortId::from_str("unbound").unwrap();
+
+        let module_id = ModuleId::new("transfer");
+
+        let mut router: Router<MockContext> = Router::default();
+        assert!(router
+            .add_route(port_id.clone(), module_id.clone(), MockModule)
+            .is_ok());
+
+        assert!(router.has_route(&port_id));
+        assert_eq!(
+            router.route(&port_id).map(|(id, _)| id),
+            Some(module_id.clone())
+        );
+        assert!(!router.has_route(&unbound_port_id));
+        assert!(router.route(&unbound_port_id).is_none());
+
+        // Only one module may be bound to a port.
+        assert!(router.add_route(port_id, module_id, MockModule).is_err());
+    }
+}
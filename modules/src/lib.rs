@@ -16,7 +16,9 @@
 //! - ICS 03: Connection
 //! - ICS 04: Channel
 //! - ICS 05: Port
+//! - ICS 06: Solo Machine Client
 //! - ICS 07: Tendermint Client
+//! - ICS 09: Loopback (Localhost) Client
 //! - ICS 18: Basic relayer functions
 //! - ICS 23: Vector Commitment Scheme
 //! - ICS 24: Host Requirements
@@ -26,12 +28,15 @@
 pub mod address;
 pub mod application;
 pub mod events;
+pub mod gas;
 pub mod handler;
 pub mod ics02_client;
 pub mod ics03_connection;
 pub mod ics04_channel;
 pub mod ics05_port;
+pub mod ics06_solomachine;
 pub mod ics07_tendermint;
+pub mod ics09_localhost;
 pub mod ics18_relayer;
 pub mod ics23_commitment;
 pub mod ics24_host;
@@ -39,6 +44,7 @@ pub mod ics26_routing;
 pub mod keys;
 pub mod macros;
 pub mod proofs;
+pub mod timestamp;
 pub mod tx_msg;
 
 mod serializers;
@@ -49,8 +55,14 @@ pub type Height = crate::ics02_client::height::Height;
 #[cfg(test)]
 mod test;
 
+#[cfg(test)]
+mod arbitrary;
+
 #[cfg(any(test, feature = "mocks"))]
 pub mod test_utils;
 
+#[cfg(feature = "grpc")]
+pub mod grpc;
+
 #[cfg(any(test, feature = "mocks"))]
 pub mod mock; // Context mock, the underlying host chain, and client types: for testing all handlers.
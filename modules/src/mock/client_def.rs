@@ -1,12 +1,18 @@
 use crate::ics02_client::client_def::{AnyClientState, AnyConsensusState, ClientDef};
+use crate::ics02_client::context::ClientReader;
 use crate::ics03_connection::connection::ConnectionEnd;
+use crate::ics03_connection::context::ConnectionReader;
 use crate::ics04_channel::channel::ChannelEnd;
+use crate::ics04_channel::commitment::PacketCommitment;
+use crate::ics04_channel::context::ChannelReader;
+use crate::ics04_channel::packet::Sequence;
 use crate::ics23_commitment::commitment::{CommitmentPrefix, CommitmentProofBytes, CommitmentRoot};
 use crate::ics23_commitment::merkle::apply_prefix;
 use crate::ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId};
 use crate::ics24_host::Path;
 use crate::mock::client_state::{MockClientState, MockConsensusState};
 use crate::mock::header::MockHeader;
+use crate::mock::misbehaviour::MockMisbehaviour;
 use crate::Height;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -14,11 +20,14 @@ pub struct MockClient;
 
 impl ClientDef for MockClient {
     type Header = MockHeader;
+    type Misbehaviour = MockMisbehaviour;
     type ClientState = MockClientState;
     type ConsensusState = MockConsensusState;
 
     fn check_header_and_update_state(
         &self,
+        _ctx: &dyn ClientReader,
+        _client_id: ClientId,
         client_state: Self::ClientState,
         header: Self::Header,
     ) -> Result<(Self::ClientState, Self::ConsensusState), Box<dyn std::error::Error>> {
@@ -28,19 +37,57 @@ impl ClientDef for MockClient {
             );
         }
 
-        Ok((MockClientState(header), MockConsensusState(header)))
+        Ok((
+            MockClientState::new(header),
+            MockConsensusState::new(header),
+        ))
+    }
+
+    fn check_misbehaviour_and_update_state(
+        &self,
+        _ctx: &dyn ClientReader,
+        _client_id: ClientId,
+        client_state: Self::ClientState,
+        misbehaviour: Self::Misbehaviour,
+    ) -> Result<Self::ClientState, Box<dyn std::error::Error>> {
+        if misbehaviour.header1.height() != misbehaviour.header2.height() {
+            return Err("misbehaviour headers are not at the same height".into());
+        }
+
+        Ok(client_state.with_frozen_height(misbehaviour.header1.height()))
+    }
+
+    fn verify_upgrade_and_update_state(
+        &self,
+        _ctx: &dyn ClientReader,
+        _client_id: ClientId,
+        client_state: Self::ClientState,
+        upgraded_client_state: Self::ClientState,
+        upgraded_consensus_state: Self::ConsensusState,
+        _proof_upgrade_client: &CommitmentProofBytes,
+        _proof_upgrade_consensus_state: &CommitmentProofBytes,
+    ) -> Result<(Self::ClientState, Self::ConsensusState), Box<dyn std::error::Error>> {
+        if client_state.latest_height() >= upgraded_client_state.latest_height() {
+            return Err(
+                "upgraded client height is lower than (or equal to) client latest height".into(),
+            );
+        }
+
+        Ok((upgraded_client_state, upgraded_consensus_state))
     }
 
     fn verify_client_consensus_state(
         &self,
+        _ctx: &dyn ConnectionReader,
         _client_state: &Self::ClientState,
         height: Height,
         prefix: &CommitmentPrefix,
         _proof: &CommitmentProofBytes,
+        _root: &CommitmentRoot,
         client_id: &ClientId,
         _consensus_height: Height,
         _expected_consensus_state: &AnyConsensusState,
-    ) -> Result<(), Box<dyn std::error::Error>> {
+    ) -> Result<Option<Self::ClientState>, Box<dyn std::error::Error>> {
         let client_prefixed_path = Path::ClientConsensusState {
             client_id: client_id.clone(),
             epoch: height.revision_number,
@@ -55,36 +102,41 @@ impl ClientDef for MockClient {
         // TODO - implement this
         // proof.verify_membership(cs.root(), path, expected_consensus_state)
 
-        Ok(())
+        Ok(None)
     }
 
     fn verify_connection_state(
         &self,
+        _ctx: &dyn ConnectionReader,
         _client_state: &Self::ClientState,
         _height: Height,
         _prefix: &CommitmentPrefix,
         _proof: &CommitmentProofBytes,
+        _root: &CommitmentRoot,
         _connection_id: &ConnectionId,
         _expected_connection_end: &ConnectionEnd,
-    ) -> Result<(), Box<dyn std::error::Error>> {
-        Ok(())
+    ) -> Result<Option<Self::ClientState>, Box<dyn std::error::Error>> {
+        Ok(None)
     }
 
     fn verify_channel_state(
         &self,
+        _ctx: &dyn ChannelReader,
         _client_state: &Self::ClientState,
         _height: Height,
         _prefix: &CommitmentPrefix,
         _proof: &CommitmentProofBytes,
+        _root: &CommitmentRoot,
         _port_id: &PortId,
         _channel_id: &ChannelId,
         _expected_channel_end: &ChannelEnd,
-    ) -> Result<(), Box<dyn std::error::Error>> {
-        Ok(())
+    ) -> Result<Option<Self::ClientState>, Box<dyn std::error::Error>> {
+        Ok(None)
     }
 
     fn verify_client_full_state(
         &self,
+        _ctx: &dyn ConnectionReader,
         _client_state: &Self::ClientState,
         _height: Height,
         _root: &CommitmentRoot,
@@ -92,7 +144,69 @@ impl ClientDef for MockClient {
         _client_id: &ClientId,
         _proof: &CommitmentProofBytes,
         _expected_client_state: &AnyClientState,
-    ) -> Result<(), Box<dyn std::error::Error>> {
-        Ok(())
+    ) -> Result<Option<Self::ClientState>, Box<dyn std::error::Error>> {
+        Ok(None)
+    }
+
+    fn verify_packet_data(
+        &self,
+        _ctx: &dyn ChannelReader,
+        _client_state: &Self::ClientState,
+        _height: Height,
+        _prefix: &CommitmentPrefix,
+        _proof: &CommitmentProofBytes,
+        _root: &CommitmentRoot,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _seq: &Sequence,
+        _commitment: PacketCommitment,
+    ) -> Result<Option<Self::ClientState>, Box<dyn std::error::Error>> {
+        Ok(None)
+    }
+
+    fn verify_packet_acknowledgement(
+        &self,
+        _ctx: &dyn ChannelReader,
+        _client_state: &Self::ClientState,
+        _height: Height,
+        _prefix: &CommitmentPrefix,
+        _proof: &CommitmentProofBytes,
+        _root: &CommitmentRoot,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _seq: &Sequence,
+        _ack: Vec<u8>,
+    ) -> Result<Option<Self::ClientState>, Box<dyn std::error::Error>> {
+        Ok(None)
+    }
+
+    fn verify_next_sequence_recv(
+        &self,
+        _ctx: &dyn ChannelReader,
+        _client_state: &Self::ClientState,
+        _height: Height,
+        _prefix: &CommitmentPrefix,
+        _proof: &CommitmentProofBytes,
+        _root: &CommitmentRoot,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _seq: &Sequence,
+    ) -> Result<Option<Self::ClientState>, Box<dyn std::error::Error>> {
+        Ok(None)
+    }
+
+    fn verify_packet_receipt_absence(
+        &self,
+        _ctx: &dyn ChannelReader,
+        _client_state: &Self::ClientState,
+        _height: Height,
+        _prefix: &CommitmentPrefix,
+        _proof: &CommitmentProofBytes,
+        _root: &CommitmentRoot,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _seq: &Sequence,
+    ) -> Result<Option<Self::ClientState>, Box<dyn std::error::Error>> {
+        Ok(None)
     }
 }
@@ -2,6 +2,7 @@ use std::collections::HashMap;
 use std::convert::{TryFrom, TryInto};
 
 use serde::Serialize;
+use tendermint::Time;
 use tendermint_proto::Protobuf;
 
 use ibc_proto::ibc::mock::ClientState as RawMockClientState;
@@ -14,6 +15,7 @@ use crate::ics02_client::error::Kind;
 use crate::ics02_client::state::{ClientState, ConsensusState};
 use crate::ics23_commitment::commitment::CommitmentRoot;
 use crate::mock::header::MockHeader;
+use crate::timestamp::Timestamp;
 use crate::Height;
 
 /// A mock of an IBC client record as it is stored in a mock context.
@@ -28,19 +30,43 @@ pub struct MockClientRecord {
 
     /// Mapping of heights to consensus states for this client.
     pub consensus_states: HashMap<Height, AnyConsensusState>,
+
+    /// Mapping of heights to the times of the host chain when the consensus states at these
+    /// heights were stored.
+    pub processed_times: HashMap<Height, Time>,
+
+    /// Mapping of heights to the heights of the host chain when the consensus states at these
+    /// heights were stored.
+    pub processed_heights: HashMap<Height, Height>,
 }
 
 /// A mock of a client state. For an example of a real structure that this mocks, you can see
 /// `ClientState` of ics07_tendermint/client_state.rs.
-// TODO: `MockClientState` should evolve, at the very least needs a `is_frozen` boolean field.
 #[derive(Copy, Clone, Default, Debug, PartialEq, Eq, Serialize)]
-pub struct MockClientState(pub MockHeader);
+pub struct MockClientState {
+    pub header: MockHeader,
+    pub frozen_height: Option<Height>,
+}
 
 impl Protobuf<RawMockClientState> for MockClientState {}
 
 impl MockClientState {
+    pub fn new(header: MockHeader) -> Self {
+        Self {
+            header,
+            frozen_height: None,
+        }
+    }
+
     pub fn latest_height(&self) -> Height {
-        (self.0).0
+        self.header.height()
+    }
+
+    pub fn with_frozen_height(self, frozen_height: Height) -> Self {
+        Self {
+            frozen_height: Some(frozen_height),
+            ..self
+        }
     }
 }
 
@@ -54,16 +80,25 @@ impl TryFrom<RawMockClientState> for MockClientState {
     type Error = Error;
 
     fn try_from(raw: RawMockClientState) -> Result<Self, Self::Error> {
-        Ok(MockClientState(raw.header.unwrap().try_into()?))
+        Ok(MockClientState {
+            header: raw
+                .header
+                .ok_or_else(|| Kind::InvalidRawClientState.context("missing header"))?
+                .try_into()?,
+            frozen_height: raw
+                .frozen_height
+                .map(Height::try_from)
+                .transpose()
+                .map_err(|e| Kind::InvalidRawClientState.context(e))?,
+        })
     }
 }
 
 impl From<MockClientState> for RawMockClientState {
     fn from(value: MockClientState) -> Self {
         RawMockClientState {
-            header: Some(ibc_proto::ibc::mock::Header {
-                height: Some(value.0.height().into()),
-            }),
+            header: Some(value.header.into()),
+            frozen_height: value.frozen_height.map(Into::into),
         }
     }
 }
@@ -78,12 +113,11 @@ impl ClientState for MockClientState {
     }
 
     fn latest_height(&self) -> Height {
-        self.0.height()
+        self.header.height()
     }
 
     fn is_frozen(&self) -> bool {
-        // TODO
-        false
+        self.frozen_height.is_some()
     }
 
     fn wrap_any(self) -> AnyClientState {
@@ -93,12 +127,24 @@ impl ClientState for MockClientState {
 
 impl From<MockConsensusState> for MockClientState {
     fn from(cs: MockConsensusState) -> Self {
-        Self(cs.0)
+        Self::new(cs.header)
     }
 }
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize)]
-pub struct MockConsensusState(pub MockHeader);
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct MockConsensusState {
+    pub header: MockHeader,
+    pub root: CommitmentRoot,
+}
+
+impl MockConsensusState {
+    pub fn new(header: MockHeader) -> Self {
+        MockConsensusState {
+            header,
+            root: CommitmentRoot::from(vec![0]),
+        }
+    }
+}
 
 impl Protobuf<RawMockConsensusState> for MockConsensusState {}
 
@@ -110,16 +156,14 @@ impl TryFrom<RawMockConsensusState> for MockConsensusState {
             .header
             .ok_or_else(|| Kind::InvalidRawConsensusState.context("missing header"))?;
 
-        Ok(Self(MockHeader::try_from(raw_header)?))
+        Ok(Self::new(MockHeader::try_from(raw_header)?))
     }
 }
 
 impl From<MockConsensusState> for RawMockConsensusState {
     fn from(value: MockConsensusState) -> Self {
         RawMockConsensusState {
-            header: Some(ibc_proto::ibc::mock::Header {
-                height: Some(value.0.height().into()),
-            }),
+            header: Some(value.header.into()),
         }
     }
 }
@@ -136,7 +180,11 @@ impl ConsensusState for MockConsensusState {
     }
 
     fn root(&self) -> &CommitmentRoot {
-        todo!()
+        &self.root
+    }
+
+    fn timestamp(&self) -> Timestamp {
+        self.header.timestamp
     }
 
     fn validate_basic(&self) -> Result<(), Box<dyn std::error::Error>> {
@@ -4,39 +4,62 @@ use std::cmp::min;
 use std::collections::HashMap;
 use std::error::Error;
 use std::str::FromStr;
+use std::time::Duration;
 
 use prost_types::Any;
 use tendermint::account::Id;
-
+use tendermint::Time;
+use tendermint_proto::Protobuf;
+
+use crate::application::ics20_fungible_token_transfer::coin::Coin;
+use crate::application::ics20_fungible_token_transfer::context::{
+    BankKeeper, ICS20Context, ICS20Keeper, ICS20Reader,
+};
+use crate::application::ics20_fungible_token_transfer::denom::DenomTrace;
+use crate::application::ics20_fungible_token_transfer::error::{
+    Error as ICS20Error, Kind as ICS20Kind,
+};
+use crate::gas::{BasicGasMeter, Gas, GasCosts, GasMeter, OutOfGas};
 use crate::ics02_client::client_def::{AnyClientState, AnyConsensusState, AnyHeader};
 use crate::ics02_client::client_type::ClientType;
 use crate::ics02_client::context::{ClientKeeper, ClientReader};
-use crate::ics02_client::error::Error as ICS2Error;
+use crate::ics02_client::error::{Error as ICS2Error, Kind as ICS2ErrorKind};
 
-use crate::ics05_port::capabilities::Capability;
-use crate::ics05_port::context::PortReader;
+use crate::ics05_port::capabilities::{Capability, CapabilityKeeper, ModuleId};
+use crate::ics05_port::context::{PortKeeper, PortReader};
 
 use crate::ics03_connection::connection::ConnectionEnd;
 use crate::ics03_connection::context::{ConnectionKeeper, ConnectionReader};
-use crate::ics03_connection::error::Error as ICS3Error;
+use crate::ics03_connection::error::{Error as ICS3Error, Kind as ICS3Kind};
 
 use crate::events::IBCEvent;
 use crate::ics04_channel::channel::ChannelEnd;
+use crate::ics04_channel::commitment::{AcknowledgementCommitment, PacketCommitment};
 use crate::ics04_channel::context::{ChannelKeeper, ChannelReader};
 use crate::ics04_channel::error::Error as ICS4Error;
 use crate::ics04_channel::error::Kind as ICS4Kind;
+use crate::ics04_channel::packet::{Receipt, Sequence};
 
+use crate::ics06_solomachine::client_state::test_util::get_dummy_solomachine_client_state;
 use crate::ics07_tendermint::client_state::test_util::get_dummy_tendermint_client_state;
+use crate::ics09_localhost::client_state::ClientState as LocalhostClientState;
+use crate::ics09_localhost::consensus_state::ConsensusState as LocalhostConsensusState;
 use crate::ics18_relayer::context::ICS18Context;
 use crate::ics18_relayer::error::{Error as ICS18Error, Kind as ICS18ErrorKind};
-use crate::ics23_commitment::commitment::CommitmentPrefix;
+use crate::ics23_commitment::commitment::{CommitmentPrefix, CommitmentProofBytes};
 use crate::ics24_host::identifier::{ChainId, ChannelId, ClientId, ConnectionId, PortId};
+use crate::ics24_host::store::Store;
+use crate::ics24_host::Path;
 use crate::ics26_routing::context::ICS26Context;
 use crate::ics26_routing::handler::{deliver, dispatch};
+use crate::ics26_routing::module::Module;
 use crate::ics26_routing::msgs::ICS26Envelope;
+use crate::ics26_routing::router::Router;
 use crate::mock::client_state::{MockClientRecord, MockClientState, MockConsensusState};
 use crate::mock::header::MockHeader;
 use crate::mock::host::{HostBlock, HostType};
+use crate::mock::module::MockModule;
+use crate::mock::store::{InMemoryStore, IBC_STORE_KEY};
 use crate::Height;
 
 /// A context implementing the dependencies necessary for testing any IBC module.
@@ -78,24 +101,56 @@ pub struct MockContext {
     connection_channels: HashMap<ConnectionId, Vec<(PortId, ChannelId)>>,
 
     /// Tracks the sequence number for the next packet to be sent.
-    next_sequence_send: HashMap<(PortId, ChannelId), u64>,
+    next_sequence_send: HashMap<(PortId, ChannelId), Sequence>,
 
     /// Tracks the sequence number for the next packet to be received.
-    next_sequence_recv: HashMap<(PortId, ChannelId), u64>,
+    next_sequence_recv: HashMap<(PortId, ChannelId), Sequence>,
 
     /// Tracks the sequence number for the next packet to be acknowledged.
-    next_sequence_ack: HashMap<(PortId, ChannelId), u64>,
+    next_sequence_ack: HashMap<(PortId, ChannelId), Sequence>,
+
+    /// Commitments of the packets sent and not yet acknowledged or timed out.
+    packet_commitment: HashMap<(PortId, ChannelId, Sequence), PacketCommitment>,
+
+    /// Receipts of the packets received on unordered channels.
+    packet_receipt: HashMap<(PortId, ChannelId, Sequence), Receipt>,
+
+    /// Acknowledgements written for the received packets.
+    packet_acknowledgement: HashMap<(PortId, ChannelId, Sequence), AcknowledgementCommitment>,
+
+    /// The capabilities of the ports and channels, and their owners.
+    capabilities: CapabilityKeeper,
 
-    /// Maps ports to their capabilities
-    port_capabilities: HashMap<PortId, Capability>,
+    /// Maps ports to the application modules bound to them.
+    router: Router<MockContext>,
 
     /// Counter for connection identifiers (see `next_connection_id`).
     connection_ids_counter: u32,
 
-    /// Counter for channel identifiers (see `next_channel_id`).
-    channel_ids_counter: u32,
+    /// Counter for the channel identifiers, necessary for `increase_channel_counter` and the
+    /// `channel_counter` methods.
+    channel_ids_counter: u64,
+
+    /// The token balances of the accounts, indexed by account and denomination.
+    balances: HashMap<(Id, String), u64>,
+
+    /// The traces of the voucher denominations received by the transfer module, indexed by hash.
+    denom_traces: HashMap<String, DenomTrace>,
+
+    /// The maximum time that the host chain is expected to take to produce a block.
+    max_expected_time_per_block: Duration,
+
+    /// The provable store, which mirrors the IBC state above and commits to it at every height.
+    store: InMemoryStore,
+
+    /// The gas meter from which the handlers consume gas. The reads and writes of the IBC state of
+    /// the mock are charged as those of the store which mirrors it.
+    gas_meter: BasicGasMeter,
 }
 
+/// The default maximum time (in seconds) that a mock host chain takes to produce a block.
+const DEFAULT_MAX_EXPECTED_TIME_PER_BLOCK: u64 = 30;
+
 /// Returns a MockContext with bare minimum initialization: no clients, no connections and no channels are
 /// present, and the chain has Height(5). This should be used sparingly, mostly for testing the
 /// creation of new domain objects.
@@ -137,6 +192,9 @@ impl MockContext {
             "The version in the chain identifier must match the version in the latest height"
         );
 
+        // The store is empty at all the heights in the history.
+        let mut store = InMemoryStore::default();
+
         MockContext {
             host_chain_type: host_type,
             host_chain_id: host_id.clone(),
@@ -145,10 +203,13 @@ impl MockContext {
             history: (0..n)
                 .rev()
                 .map(|i| {
+                    let height = latest_height.sub(i).unwrap();
+                    let root = store.commit(height);
                     HostBlock::generate_block(
                         host_id.clone(),
                         host_type,
-                        latest_height.sub(i).unwrap().revision_height,
+                        height.revision_height,
+                        &root,
                     )
                 })
                 .collect(),
@@ -161,9 +222,18 @@ impl MockContext {
             next_sequence_send: Default::default(),
             next_sequence_recv: Default::default(),
             next_sequence_ack: Default::default(),
-            port_capabilities: Default::default(),
+            packet_commitment: Default::default(),
+            packet_receipt: Default::default(),
+            packet_acknowledgement: Default::default(),
+            capabilities: Default::default(),
+            router: Default::default(),
             connection_ids_counter: 0,
             channel_ids_counter: 0,
+            balances: Default::default(),
+            denom_traces: Default::default(),
+            max_expected_time_per_block: Duration::from_secs(DEFAULT_MAX_EXPECTED_TIME_PER_BLOCK),
+            store,
+            gas_meter: Default::default(),
         }
     }
 
@@ -181,7 +251,28 @@ impl MockContext {
     /// `consensus_state_height` is None, then the client will be initialized with a consensus
     /// state matching the same height as the client state (`client_state_height`).
     pub fn with_client_parametrized(
+        self,
+        client_id: &ClientId,
+        client_state_height: Height,
+        client_type: Option<ClientType>,
+        consensus_state_height: Option<Height>,
+    ) -> Self {
+        let chain_id = self.host_chain_id.clone();
+        self.with_client_parametrized_with_chain_id(
+            chain_id,
+            client_id,
+            client_state_height,
+            client_type,
+            consensus_state_height,
+        )
+    }
+
+    /// Similar to `with_client_parametrized`, but the client tracks the chain identified by
+    /// `chain_id`. This only makes a difference for Tendermint clients, which check that the
+    /// headers they verify come from the chain they track.
+    pub fn with_client_parametrized_with_chain_id(
         mut self,
+        chain_id: ChainId,
         client_id: &ClientId,
         client_state_height: Height,
         client_type: Option<ClientType>,
@@ -193,15 +284,12 @@ impl MockContext {
         let (client_state, consensus_state) = match client_type {
             // If it's a mock client, create the corresponding mock states.
             ClientType::Mock => (
-                Some(MockClientState(MockHeader(client_state_height)).into()),
-                MockConsensusState(MockHeader(cs_height)).into(),
+                Some(MockClientState::new(MockHeader::new(client_state_height)).into()),
+                MockConsensusState::new(MockHeader::new(cs_height)).into(),
             ),
             // If it's a Tendermint client, we need TM states.
             ClientType::Tendermint => {
-                let light_block = HostBlock::generate_tm_block(
-                    self.host_chain_id.clone(),
-                    cs_height.revision_height,
-                );
+                let light_block = HostBlock::generate_tm_block(chain_id, cs_height.revision_height);
                 let consensus_state = AnyConsensusState::from(light_block.clone());
                 let client_state =
                     get_dummy_tendermint_client_state(light_block.signed_header.header);
@@ -209,15 +297,54 @@ impl MockContext {
                 // Return the tuple.
                 (Some(client_state), consensus_state)
             }
+            // If it's a localhost client, it tracks the host chain itself.
+            ClientType::Localhost => (
+                Some(AnyClientState::Localhost(LocalhostClientState::new(
+                    chain_id,
+                    client_state_height,
+                ))),
+                AnyConsensusState::Localhost(LocalhostConsensusState::new()),
+            ),
+            // If it's a solo machine client, its sequence stands for the height.
+            ClientType::SoloMachine => {
+                let client_state = get_dummy_solomachine_client_state(cs_height.revision_height);
+                let consensus_state =
+                    AnyConsensusState::SoloMachine(client_state.consensus_state.clone());
+
+                (
+                    Some(AnyClientState::SoloMachine(client_state)),
+                    consensus_state,
+                )
+            }
         };
-        let consensus_states = vec![(cs_height, consensus_state)].into_iter().collect();
+        self.store_client_type(client_id.clone(), client_type)
+            .unwrap();
+        if let Some(client_state) = client_state {
+            self.store_client_state(client_id.clone(), client_state)
+                .unwrap();
+        }
+        self.store_consensus_state(client_id.clone(), cs_height, consensus_state)
+            .unwrap();
+
+        // The consensus state is processed by the host chain at its current time and height.
+        let host_height = self.latest_height;
+        self.store_update_time(client_id.clone(), cs_height, Time::now())
+            .unwrap();
+        self.store_update_height(client_id.clone(), cs_height, host_height)
+            .unwrap();
+        self
+    }
 
-        let client_record = MockClientRecord {
-            client_type,
-            client_state,
-            consensus_states,
-        };
-        self.clients.insert(client_id.clone(), client_record);
+    /// Stores `consensus_state` as the consensus state of client `client_id` at `height`, e.g. to
+    /// give it a timestamp.
+    pub fn with_consensus_state(
+        mut self,
+        client_id: &ClientId,
+        height: Height,
+        consensus_state: AnyConsensusState,
+    ) -> Self {
+        self.store_consensus_state(client_id.clone(), height, consensus_state)
+            .unwrap();
         self
     }
 
@@ -227,24 +354,137 @@ impl MockContext {
         connection_id: ConnectionId,
         connection_end: ConnectionEnd,
     ) -> Self {
-        self.connections.insert(connection_id, connection_end);
+        self.store_connection(&connection_id, &connection_end)
+            .unwrap();
         self
     }
 
+    /// Associates a capability to the given port. Unless a module is already bound to the port,
+    /// the port is bound to a `MockModule`.
     pub fn with_port_capability(mut self, port_id: PortId) -> Self {
-        self.port_capabilities.insert(port_id, Capability::new());
+        self.add_port(port_id);
+        self
+    }
+
+    /// Binds the given application module to a port, along with a capability for the port. The
+    /// module owns capabilities under the identifier of the port (see `add_port`).
+    pub fn with_module(
+        mut self,
+        port_id: PortId,
+        module: impl Module<MockContext> + 'static,
+    ) -> Self {
+        self.router
+            .add_route(port_id.clone(), ModuleId::new(port_id.as_str()), module)
+            .unwrap();
+        self.add_port(port_id);
         self
     }
 
+    /// Stores the given channel end. If a module is bound to the port of the channel, the module
+    /// is given the capability of the channel.
     pub fn with_channel_init(
-        self,
+        mut self,
         port_id: PortId,
         chan_id: ChannelId,
         channel_end: ChannelEnd,
     ) -> Self {
-        let mut channels = self.channels.clone();
-        channels.insert((port_id, chan_id), channel_end);
-        Self { channels, ..self }
+        if self.lookup_module_by_port(&port_id).is_some()
+            && self.lookup_module_by_channel(&port_id, &chan_id).is_none()
+        {
+            self.new_channel_capability(&port_id, &chan_id).unwrap();
+        }
+
+        self.store_channel(&(port_id, chan_id), &channel_end)
+            .unwrap();
+        self
+    }
+
+    pub fn with_send_sequence(
+        mut self,
+        port_id: PortId,
+        chan_id: ChannelId,
+        seq_number: Sequence,
+    ) -> Self {
+        self.store_next_sequence_send(&(port_id, chan_id), seq_number)
+            .unwrap();
+        self
+    }
+
+    pub fn with_recv_sequence(
+        mut self,
+        port_id: PortId,
+        chan_id: ChannelId,
+        seq_number: Sequence,
+    ) -> Self {
+        self.store_next_sequence_recv(&(port_id, chan_id), seq_number)
+            .unwrap();
+        self
+    }
+
+    pub fn with_ack_sequence(
+        mut self,
+        port_id: PortId,
+        chan_id: ChannelId,
+        seq_number: Sequence,
+    ) -> Self {
+        self.store_next_sequence_ack(&(port_id, chan_id), seq_number)
+            .unwrap();
+        self
+    }
+
+    pub fn with_packet_commitment(
+        mut self,
+        port_id: PortId,
+        chan_id: ChannelId,
+        seq: Sequence,
+        commitment: PacketCommitment,
+    ) -> Self {
+        self.store_packet_commitment((port_id, chan_id, seq), commitment)
+            .unwrap();
+        self
+    }
+
+    pub fn with_packet_receipt(
+        mut self,
+        port_id: PortId,
+        chan_id: ChannelId,
+        seq: Sequence,
+        receipt: Receipt,
+    ) -> Self {
+        self.store_packet_receipt((port_id, chan_id, seq), receipt)
+            .unwrap();
+        self
+    }
+
+    /// Credits the given amount of tokens to `account`.
+    pub fn with_balance(mut self, account: Id, amount: Coin) -> Self {
+        *self.balances.entry((account, amount.denom)).or_insert(0) += amount.amount;
+        self
+    }
+
+    /// Associates the trace of a voucher denomination to this context.
+    pub fn with_denom_trace(mut self, denom_trace: DenomTrace) -> Self {
+        self.denom_traces.insert(denom_trace.hash(), denom_trace);
+        self
+    }
+
+    /// Replaces the gas meter of this context.
+    pub fn with_gas_meter(mut self, gas_meter: BasicGasMeter) -> Self {
+        self.gas_meter = gas_meter;
+        self
+    }
+
+    /// Accessor for the gas meter of this context.
+    pub fn gas_meter(&self) -> &BasicGasMeter {
+        &self.gas_meter
+    }
+
+    /// Returns the balance of `account` in the given denomination.
+    pub fn balance(&self, account: &Id, denom: &str) -> u64 {
+        self.balances
+            .get(&(*account, denom.to_string()))
+            .copied()
+            .unwrap_or(0)
     }
 
     /// Accessor for a block of the local (host) chain from this context.
@@ -261,12 +501,51 @@ impl MockContext {
         }
     }
 
+    /// Returns the provable store which mirrors the IBC state of the host chain.
+    pub fn store(&self) -> &InMemoryStore {
+        &self.store
+    }
+
+    /// Proves the presence or the absence of a value at `path` in the state of the host chain at
+    /// the given height. The proof verifies against the root in the app hash of the synthetic
+    /// Tendermint block at that height. Returns `None` if the height is outside of the history,
+    /// or if the store was empty at that height.
+    pub fn query_proof(&self, height: Height, path: &Path) -> Option<CommitmentProofBytes> {
+        self.store.get_proof(height, path)
+    }
+
+    /// Charges the read of the value at `path` to the gas meter, by the size of the value in the
+    /// store, as hosts which keep their state in a `Store` do. Reads cannot fail: running out of
+    /// gas is found out by the next consumption of gas (see `gas`).
+    fn charge_read(&self, path: &Path) {
+        let len = self.store.get(path).map_or(0, |value| value.len());
+        let _ = self.consume_gas(self.gas_costs().read(len), "store read");
+    }
+
+    /// Writes `value` at `path` in the store, and charges the write to the gas meter.
+    fn store_set(&mut self, path: Path, value: Vec<u8>) -> Result<(), OutOfGas> {
+        let gas = self.gas_costs().write(value.len());
+        self.store.set(path, value);
+        self.consume_gas(gas, "store write")
+    }
+
+    /// Removes the value at `path` from the store, and charges the removal to the gas meter as
+    /// the write of an empty value.
+    fn store_delete(&mut self, path: &Path) -> Result<(), OutOfGas> {
+        self.store.delete(path);
+        self.consume_gas(self.gas_costs().write(0), "store delete")
+    }
+
     /// Triggers the advancing of the host chain, by extending the history of blocks (or headers).
+    /// The new block commits to the current state of the store.
     pub fn advance_host_chain_height(&mut self) {
+        let new_height = self.latest_height.increment();
+        let root = self.store.commit(new_height);
         let new_block = HostBlock::generate_block(
             self.host_chain_id.clone(),
             self.host_chain_type,
-            self.latest_height.increment().revision_height,
+            new_height.revision_height,
+            &root,
         );
 
         // Append the new header at the tip of the history.
@@ -278,7 +557,10 @@ impl MockContext {
             // History is not full yet.
             self.history.push(new_block);
         }
-        self.latest_height = self.latest_height.increment();
+        self.latest_height = new_height;
+
+        // Only the states at the heights in the history can be proven.
+        self.store.prune(self.history[0].height());
     }
 
     /// A datagram passes from the relayer to the IBC module (on host chain).
@@ -319,30 +601,101 @@ impl MockContext {
         Ok(())
     }
 
+    /// Binds the given port, unless it is already bound. The module bound to the port, which is a
+    /// `MockModule` unless another module was routed to the port, owns capabilities under the
+    /// identifier of the port.
     pub fn add_port(&mut self, port_id: PortId) {
-        self.port_capabilities.insert(port_id, Capability::new());
+        if !self.router.has_route(&port_id) {
+            self.router
+                .add_route(port_id.clone(), ModuleId::new(port_id.as_str()), MockModule)
+                .unwrap();
+        }
+        if self.lookup_module_by_port(&port_id).is_none() {
+            self.bind_port(&ModuleId::new(port_id.as_str()), &port_id)
+                .unwrap();
+        }
+    }
+
+    /// Returns a capability owned by a module which is bound to no port. The capability
+    /// authenticates no port or channel, as the ones a module would forge to act on the ports and
+    /// channels of other modules.
+    pub fn foreign_capability(&mut self) -> Capability {
+        let module_id = ModuleId::new("foreign");
+        let name = "foreign";
+        match self.capabilities.get_capability(&module_id, name) {
+            Some(capability) => capability,
+            None => self.capabilities.new_capability(&module_id, name).unwrap(),
+        }
+    }
+}
+
+impl GasMeter for MockContext {
+    fn gas_costs(&self) -> &GasCosts {
+        self.gas_meter.gas_costs()
+    }
+
+    fn gas_consumed(&self) -> Gas {
+        self.gas_meter.gas_consumed()
+    }
+
+    fn consume_gas(&self, amount: Gas, descriptor: &str) -> Result<(), OutOfGas> {
+        self.gas_meter.consume_gas(amount, descriptor)
+    }
+}
+
+impl ICS26Context for MockContext {
+    fn router(&self) -> &Router<Self> {
+        &self.router
+    }
+
+    fn router_mut(&mut self) -> &mut Router<Self> {
+        &mut self.router
     }
 }
 
-impl ICS26Context for MockContext {}
+#[cfg(feature = "grpc")]
+impl crate::grpc::QueryContext for MockContext {
+    fn paths(&self, prefix: &str) -> Vec<Path> {
+        self.store
+            .get_keys(prefix)
+            .iter()
+            .filter_map(|key| Path::from_str(key).ok())
+            .collect()
+    }
+
+    fn client_connections(&self, client_id: &ClientId) -> Vec<ConnectionId> {
+        self.client_connections
+            .get(client_id)
+            .cloned()
+            .into_iter()
+            .collect()
+    }
+
+    fn proof(&self, height: Height, path: &Path) -> Option<CommitmentProofBytes> {
+        self.query_proof(height, path)
+    }
+}
 
 impl PortReader for MockContext {
-    fn lookup_module_by_port(&self, port_id: &PortId) -> Option<Capability> {
-        self.port_capabilities.get(port_id).cloned()
+    fn capability_keeper(&self) -> &CapabilityKeeper {
+        &self.capabilities
     }
+}
 
-    fn autenthenticate(&self, _cap: &Capability, _port_id: &PortId) -> bool {
-        true
+impl PortKeeper for MockContext {
+    fn capability_keeper_mut(&mut self) -> &mut CapabilityKeeper {
+        &mut self.capabilities
     }
 }
 
 impl ChannelReader for MockContext {
     fn channel_end(&self, pcid: &(PortId, ChannelId)) -> Option<ChannelEnd> {
+        self.charge_read(&Path::ChannelEnds(pcid.0.clone(), pcid.1.clone()));
         self.channels.get(pcid).cloned()
     }
 
     fn connection_end(&self, cid: &ConnectionId) -> Option<ConnectionEnd> {
-        self.connections.get(cid).cloned()
+        ConnectionReader::connection_end(self, cid)
     }
 
     fn connection_channels(&self, cid: &ConnectionId) -> Option<Vec<(PortId, ChannelId)>> {
@@ -383,35 +736,125 @@ impl ChannelReader for MockContext {
         }
     }
 
-    fn authenticated_capability(&self, port_id: &PortId) -> Result<Capability, ICS4Error> {
-        let cap = PortReader::lookup_module_by_port(self, port_id);
-        match cap {
-            Some(key) => {
-                if !PortReader::autenthenticate(self, &key, port_id) {
-                    Err(ICS4Error::from(ICS4Kind::InvalidPortCapability))
-                } else {
-                    Ok(key)
-                }
-            }
-            None => Err(ICS4Error::from(ICS4Kind::NoPortCapability)),
-        }
+    fn port_capability(&self, port_id: &PortId) -> Result<Capability, ICS4Error> {
+        PortReader::lookup_module_by_port(self, port_id)
+            .map(|(_, key)| key)
+            .ok_or_else(|| ICS4Kind::NoPortCapability.into())
     }
-}
 
-impl ChannelKeeper for MockContext {
-    fn next_channel_id(&mut self) -> ChannelId {
-        let prefix = ChannelId::default().to_string();
-        let suffix = self.channel_ids_counter;
-        self.channel_ids_counter += 1;
+    fn channel_capability(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<Capability, ICS4Error> {
+        PortReader::lookup_module_by_channel(self, port_id, channel_id)
+            .map(|(_, key)| key)
+            .ok_or_else(|| {
+                ICS4Kind::NoChannelCapability(port_id.clone(), channel_id.clone()).into()
+            })
+    }
+
+    fn client_state(&self, client_id: &ClientId) -> Option<AnyClientState> {
+        ClientReader::client_state(self, client_id)
+    }
 
-        ChannelId::from_str(format!("{}-{}", prefix, suffix).as_str()).unwrap()
+    fn client_consensus_state(
+        &self,
+        client_id: &ClientId,
+        height: Height,
+    ) -> Option<AnyConsensusState> {
+        ClientReader::consensus_state(self, client_id, height)
+    }
+
+    fn get_next_sequence_send(&self, port_channel_id: &(PortId, ChannelId)) -> Option<Sequence> {
+        self.charge_read(&Path::SeqSends(
+            port_channel_id.0.clone(),
+            port_channel_id.1.clone(),
+        ));
+        self.next_sequence_send.get(port_channel_id).cloned()
+    }
+
+    fn get_next_sequence_recv(&self, port_channel_id: &(PortId, ChannelId)) -> Option<Sequence> {
+        self.charge_read(&Path::SeqRecvs(
+            port_channel_id.0.clone(),
+            port_channel_id.1.clone(),
+        ));
+        self.next_sequence_recv.get(port_channel_id).cloned()
+    }
+
+    fn get_next_sequence_ack(&self, port_channel_id: &(PortId, ChannelId)) -> Option<Sequence> {
+        self.charge_read(&Path::SeqAcks(
+            port_channel_id.0.clone(),
+            port_channel_id.1.clone(),
+        ));
+        self.next_sequence_ack.get(port_channel_id).cloned()
+    }
+
+    fn get_packet_commitment(
+        &self,
+        key: &(PortId, ChannelId, Sequence),
+    ) -> Option<PacketCommitment> {
+        self.charge_read(&commitments_path(key));
+        self.packet_commitment.get(key).cloned()
+    }
+
+    fn get_packet_receipt(&self, key: &(PortId, ChannelId, Sequence)) -> Option<Receipt> {
+        self.charge_read(&receipts_path(key));
+        self.packet_receipt.get(key).cloned()
+    }
+
+    fn get_packet_acknowledgement(
+        &self,
+        key: &(PortId, ChannelId, Sequence),
+    ) -> Option<AcknowledgementCommitment> {
+        self.charge_read(&acks_path(key));
+        self.packet_acknowledgement.get(key).cloned()
     }
 
+    fn host_height(&self) -> Height {
+        self.latest_height
+    }
+
+    fn host_timestamp(&self) -> Time {
+        ClientReader::host_timestamp(self)
+    }
+
+    fn client_update_time(&self, client_id: &ClientId, height: Height) -> Option<Time> {
+        self.clients
+            .get(client_id)?
+            .processed_times
+            .get(&height)
+            .cloned()
+    }
+
+    fn client_update_height(&self, client_id: &ClientId, height: Height) -> Option<Height> {
+        self.clients
+            .get(client_id)?
+            .processed_heights
+            .get(&height)
+            .cloned()
+    }
+
+    fn max_expected_time_per_block(&self) -> Duration {
+        self.max_expected_time_per_block
+    }
+
+    fn channel_counter(&self) -> u64 {
+        self.channel_ids_counter
+    }
+}
+
+impl ChannelKeeper for MockContext {
     fn store_channel(
         &mut self,
         port_channel_id: &(PortId, ChannelId),
         channel_end: &ChannelEnd,
     ) -> Result<(), ICS4Error> {
+        self.store_set(
+            Path::ChannelEnds(port_channel_id.0.clone(), port_channel_id.1.clone()),
+            channel_end.encode_vec().unwrap(),
+        )
+        .map_err(ICS4Kind::OutOfGas)?;
         self.channels
             .insert(port_channel_id.clone(), channel_end.clone());
         Ok(())
@@ -419,8 +862,13 @@ impl ChannelKeeper for MockContext {
     fn store_next_sequence_send(
         &mut self,
         port_channel_id: &(PortId, ChannelId),
-        seq: u64,
+        seq: Sequence,
     ) -> Result<(), ICS4Error> {
+        self.store_set(
+            Path::SeqSends(port_channel_id.0.clone(), port_channel_id.1.clone()),
+            u64::from(seq).to_be_bytes().to_vec(),
+        )
+        .map_err(ICS4Kind::OutOfGas)?;
         self.next_sequence_send.insert(port_channel_id.clone(), seq);
         Ok(())
     }
@@ -428,8 +876,13 @@ impl ChannelKeeper for MockContext {
     fn store_next_sequence_recv(
         &mut self,
         port_channel_id: &(PortId, ChannelId),
-        seq: u64,
+        seq: Sequence,
     ) -> Result<(), ICS4Error> {
+        self.store_set(
+            Path::SeqRecvs(port_channel_id.0.clone(), port_channel_id.1.clone()),
+            u64::from(seq).to_be_bytes().to_vec(),
+        )
+        .map_err(ICS4Kind::OutOfGas)?;
         self.next_sequence_recv.insert(port_channel_id.clone(), seq);
         Ok(())
     }
@@ -437,8 +890,13 @@ impl ChannelKeeper for MockContext {
     fn store_next_sequence_ack(
         &mut self,
         port_channel_id: &(PortId, ChannelId),
-        seq: u64,
+        seq: Sequence,
     ) -> Result<(), ICS4Error> {
+        self.store_set(
+            Path::SeqAcks(port_channel_id.0.clone(), port_channel_id.1.clone()),
+            u64::from(seq).to_be_bytes().to_vec(),
+        )
+        .map_err(ICS4Kind::OutOfGas)?;
         self.next_sequence_ack.insert(port_channel_id.clone(), seq);
         Ok(())
     }
@@ -454,10 +912,108 @@ impl ChannelKeeper for MockContext {
             .push(port_channel_id.clone());
         Ok(())
     }
+
+    fn store_packet_commitment(
+        &mut self,
+        key: (PortId, ChannelId, Sequence),
+        commitment: PacketCommitment,
+    ) -> Result<(), ICS4Error> {
+        self.store_set(commitments_path(&key), commitment.as_bytes().to_vec())
+            .map_err(ICS4Kind::OutOfGas)?;
+        self.packet_commitment.insert(key, commitment);
+        Ok(())
+    }
+
+    fn delete_packet_commitment(
+        &mut self,
+        key: (PortId, ChannelId, Sequence),
+    ) -> Result<(), ICS4Error> {
+        self.store_delete(&commitments_path(&key))
+            .map_err(ICS4Kind::OutOfGas)?;
+        self.packet_commitment.remove(&key);
+        Ok(())
+    }
+
+    fn store_packet_receipt(
+        &mut self,
+        key: (PortId, ChannelId, Sequence),
+        receipt: Receipt,
+    ) -> Result<(), ICS4Error> {
+        // As in the Cosmos SDK, the value of a receipt only marks its presence.
+        self.store_set(receipts_path(&key), vec![1])
+            .map_err(ICS4Kind::OutOfGas)?;
+        self.packet_receipt.insert(key, receipt);
+        Ok(())
+    }
+
+    fn store_packet_acknowledgement(
+        &mut self,
+        key: (PortId, ChannelId, Sequence),
+        ack_commitment: AcknowledgementCommitment,
+    ) -> Result<(), ICS4Error> {
+        self.store_set(acks_path(&key), ack_commitment.as_bytes().to_vec())
+            .map_err(ICS4Kind::OutOfGas)?;
+        self.packet_acknowledgement.insert(key, ack_commitment);
+        Ok(())
+    }
+
+    fn increase_channel_counter(&mut self) {
+        self.channel_ids_counter += 1;
+    }
+}
+
+impl BankKeeper for MockContext {
+    fn send_coins(&mut self, from: &Id, to: &Id, amount: &Coin) -> Result<(), ICS20Error> {
+        self.burn_coins(from, amount)?;
+        self.mint_coins(to, amount)
+    }
+
+    fn mint_coins(&mut self, account: &Id, amount: &Coin) -> Result<(), ICS20Error> {
+        *self
+            .balances
+            .entry((*account, amount.denom.clone()))
+            .or_insert(0) += amount.amount;
+        Ok(())
+    }
+
+    fn burn_coins(&mut self, account: &Id, amount: &Coin) -> Result<(), ICS20Error> {
+        let balance = self
+            .balances
+            .entry((*account, amount.denom.clone()))
+            .or_insert(0);
+
+        if *balance < amount.amount {
+            return Err(ICS20Kind::InsufficientFunds(
+                amount.amount,
+                amount.denom.clone(),
+                *balance,
+            )
+            .into());
+        }
+
+        *balance -= amount.amount;
+        Ok(())
+    }
+}
+
+impl ICS20Reader for MockContext {
+    fn get_denom_trace(&self, denom_hash: &str) -> Option<DenomTrace> {
+        self.denom_traces.get(denom_hash).cloned()
+    }
 }
 
+impl ICS20Keeper for MockContext {
+    fn store_denom_trace(&mut self, denom_trace: DenomTrace) -> Result<(), ICS20Error> {
+        self.denom_traces.insert(denom_trace.hash(), denom_trace);
+        Ok(())
+    }
+}
+
+impl ICS20Context for MockContext {}
+
 impl ConnectionReader for MockContext {
     fn connection_end(&self, cid: &ConnectionId) -> Option<ConnectionEnd> {
+        self.charge_read(&Path::Connections(cid.clone()));
         self.connections.get(cid).cloned()
     }
 
@@ -476,7 +1032,7 @@ impl ConnectionReader for MockContext {
     }
 
     fn commitment_prefix(&self) -> CommitmentPrefix {
-        CommitmentPrefix::from(vec![])
+        CommitmentPrefix::from(IBC_STORE_KEY.as_bytes().to_vec())
     }
 
     fn client_consensus_state(
@@ -508,6 +1064,11 @@ impl ConnectionKeeper for MockContext {
         connection_id: &ConnectionId,
         connection_end: &ConnectionEnd,
     ) -> Result<(), ICS3Error> {
+        self.store_set(
+            Path::Connections(connection_id.clone()),
+            connection_end.encode_vec().unwrap(),
+        )
+        .map_err(ICS3Kind::OutOfGas)?;
         self.connections
             .insert(connection_id.clone(), connection_end.clone());
         Ok(())
@@ -526,6 +1087,7 @@ impl ConnectionKeeper for MockContext {
 
 impl ClientReader for MockContext {
     fn client_type(&self, client_id: &ClientId) -> Option<ClientType> {
+        self.charge_read(&Path::ClientType(client_id.clone()));
         match self.clients.get(client_id) {
             Some(client_record) => client_record.client_type.into(),
             None => None,
@@ -533,6 +1095,7 @@ impl ClientReader for MockContext {
     }
 
     fn client_state(&self, client_id: &ClientId) -> Option<AnyClientState> {
+        self.charge_read(&Path::ClientState(client_id.clone()));
         match self.clients.get(client_id) {
             Some(client_record) => client_record.client_state.clone(),
             None => None,
@@ -540,6 +1103,11 @@ impl ClientReader for MockContext {
     }
 
     fn consensus_state(&self, client_id: &ClientId, height: Height) -> Option<AnyConsensusState> {
+        self.charge_read(&Path::ClientConsensusState {
+            client_id: client_id.clone(),
+            epoch: height.revision_number,
+            height: height.revision_height,
+        });
         match self.clients.get(client_id) {
             Some(client_record) => match client_record.consensus_states.get(&height) {
                 Some(consensus_state) => Option::from(consensus_state.clone()),
@@ -549,6 +1117,14 @@ impl ClientReader for MockContext {
         }
     }
 
+    fn host_height(&self) -> Height {
+        self.latest_height
+    }
+
+    fn host_timestamp(&self) -> Time {
+        Time::now()
+    }
+
     fn client_counter(&self) -> u64 {
         self.client_ids_counter
     }
@@ -560,10 +1136,17 @@ impl ClientKeeper for MockContext {
         client_id: ClientId,
         client_type: ClientType,
     ) -> Result<(), ICS2Error> {
+        self.store_set(
+            Path::ClientType(client_id.clone()),
+            client_type.as_string().as_bytes().to_vec(),
+        )
+        .map_err(ICS2ErrorKind::OutOfGas)?;
         let mut client_record = self.clients.entry(client_id).or_insert(MockClientRecord {
             client_type,
             consensus_states: Default::default(),
             client_state: Default::default(),
+            processed_times: Default::default(),
+            processed_heights: Default::default(),
         });
 
         client_record.client_type = client_type;
@@ -575,10 +1158,17 @@ impl ClientKeeper for MockContext {
         client_id: ClientId,
         client_state: AnyClientState,
     ) -> Result<(), ICS2Error> {
+        self.store_set(
+            Path::ClientState(client_id.clone()),
+            client_state.encode_vec().unwrap(),
+        )
+        .map_err(ICS2ErrorKind::OutOfGas)?;
         let mut client_record = self.clients.entry(client_id).or_insert(MockClientRecord {
             client_type: client_state.client_type(),
             consensus_states: Default::default(),
             client_state: Default::default(),
+            processed_times: Default::default(),
+            processed_heights: Default::default(),
         });
 
         client_record.client_state = Some(client_state);
@@ -591,10 +1181,19 @@ impl ClientKeeper for MockContext {
         height: Height,
         consensus_state: AnyConsensusState,
     ) -> Result<(), ICS2Error> {
+        let path = Path::ClientConsensusState {
+            client_id: client_id.clone(),
+            epoch: height.revision_number,
+            height: height.revision_height,
+        };
+        self.store_set(path, consensus_state.encode_vec().unwrap())
+            .map_err(ICS2ErrorKind::OutOfGas)?;
         let client_record = self.clients.entry(client_id).or_insert(MockClientRecord {
             client_type: ClientType::Mock,
             consensus_states: Default::default(),
             client_state: Default::default(),
+            processed_times: Default::default(),
+            processed_heights: Default::default(),
         });
 
         client_record
@@ -603,11 +1202,65 @@ impl ClientKeeper for MockContext {
         Ok(())
     }
 
+    fn store_update_time(
+        &mut self,
+        client_id: ClientId,
+        height: Height,
+        timestamp: Time,
+    ) -> Result<(), ICS2Error> {
+        let client_record = self
+            .clients
+            .get_mut(&client_id)
+            .ok_or(ICS2ErrorKind::ClientNotFound(client_id))?;
+
+        client_record.processed_times.insert(height, timestamp);
+        Ok(())
+    }
+
+    fn store_update_height(
+        &mut self,
+        client_id: ClientId,
+        height: Height,
+        host_height: Height,
+    ) -> Result<(), ICS2Error> {
+        let client_record = self
+            .clients
+            .get_mut(&client_id)
+            .ok_or(ICS2ErrorKind::ClientNotFound(client_id))?;
+
+        client_record.processed_heights.insert(height, host_height);
+        Ok(())
+    }
+
     fn increase_client_counter(&mut self) {
         self.client_ids_counter += 1
     }
 }
 
+fn commitments_path((port_id, channel_id, sequence): &(PortId, ChannelId, Sequence)) -> Path {
+    Path::Commitments {
+        port_id: port_id.clone(),
+        channel_id: channel_id.clone(),
+        sequence: *sequence,
+    }
+}
+
+fn receipts_path((port_id, channel_id, sequence): &(PortId, ChannelId, Sequence)) -> Path {
+    Path::Receipts {
+        port_id: port_id.clone(),
+        channel_id: channel_id.clone(),
+        sequence: *sequence,
+    }
+}
+
+fn acks_path((port_id, channel_id, sequence): &(PortId, ChannelId, Sequence)) -> Path {
+    Path::Acks {
+        port_id: port_id.clone(),
+        channel_id: channel_id.clone(),
+        sequence: *sequence,
+    }
+}
+
 impl ICS18Context for MockContext {
     fn query_latest_height(&self) -> Height {
         self.host_current_height()
@@ -625,11 +1278,11 @@ impl ICS18Context for MockContext {
 
     fn send(&mut self, msgs: Vec<Any>) -> Result<Vec<IBCEvent>, ICS18Error> {
         // Forward call to ICS26 delivery method.
-        let events =
+        let output =
             deliver(self, msgs).map_err(|e| ICS18ErrorKind::TransactionFailed.context(e))?;
 
         self.advance_host_chain_height(); // Advance chain height
-        Ok(events)
+        Ok(output.events)
     }
 
     fn signer(&self) -> Id {
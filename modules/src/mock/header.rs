@@ -10,10 +10,14 @@ use crate::ics02_client::client_type::ClientType;
 use crate::ics02_client::error::{self, Error};
 use crate::ics02_client::header::Header;
 use crate::mock::client_state::MockConsensusState;
+use crate::timestamp::Timestamp;
 use crate::Height;
 
 #[derive(Copy, Clone, Default, Debug, PartialEq, Eq, Serialize)]
-pub struct MockHeader(pub Height);
+pub struct MockHeader {
+    pub height: Height,
+    pub timestamp: Timestamp,
+}
 
 impl Protobuf<RawMockHeader> for MockHeader {}
 
@@ -21,24 +25,42 @@ impl TryFrom<RawMockHeader> for MockHeader {
     type Error = Error;
 
     fn try_from(raw: RawMockHeader) -> Result<Self, Self::Error> {
-        Ok(MockHeader(
-            raw.height
+        Ok(MockHeader {
+            height: raw
+                .height
                 .ok_or_else(|| error::Kind::InvalidRawHeader.context("missing height in header"))?
                 .try_into()
                 .map_err(|e| error::Kind::InvalidRawHeader.context(e))?,
-        ))
+            timestamp: Timestamp::from_nanoseconds(raw.timestamp)
+                .map_err(|e| error::Kind::InvalidRawHeader.context(e))?,
+        })
     }
 }
 
 impl From<MockHeader> for RawMockHeader {
     fn from(value: MockHeader) -> Self {
-        value.into()
+        RawMockHeader {
+            height: Some(value.height().into()),
+            timestamp: value.timestamp.nanoseconds(),
+        }
     }
 }
 
 impl MockHeader {
+    /// A header at the given height, without timestamp.
+    pub fn new(height: Height) -> Self {
+        Self {
+            height,
+            timestamp: Timestamp::none(),
+        }
+    }
+
+    pub fn with_timestamp(self, timestamp: Timestamp) -> Self {
+        Self { timestamp, ..self }
+    }
+
     pub fn height(&self) -> Height {
-        self.0
+        self.height
     }
 }
 
@@ -64,6 +86,6 @@ impl Header for MockHeader {
 
 impl From<MockHeader> for AnyConsensusState {
     fn from(h: MockHeader) -> Self {
-        AnyConsensusState::Mock(MockConsensusState(h))
+        AnyConsensusState::Mock(MockConsensusState::new(h))
     }
 }
@@ -3,15 +3,34 @@
 use crate::ics02_client::client_def::{AnyConsensusState, AnyHeader};
 use crate::ics07_tendermint::consensus_state::ConsensusState as TMConsensusState;
 use crate::ics07_tendermint::header::Header as TMHeader;
+use crate::ics23_commitment::commitment::CommitmentRoot;
 use crate::ics24_host::identifier::ChainId;
 use crate::mock::header::MockHeader;
 use crate::Height;
 
-use tendermint::chain::Id as TMChainId;
+use tendermint::block::parts::Header as PartSetHeader;
+use tendermint::block::{self, CommitSig};
+use tendermint::hash::AppHash;
+use tendermint::signature::Signer;
+use tendermint::vote::{self, ValidatorIndex};
+use tendermint_testgen::helpers::get_vote_sign_bytes;
 use tendermint_testgen::light_block::TMLightBlock;
-use tendermint_testgen::{Generator, LightBlock as TestgenLightBlock};
+use tendermint_testgen::{
+    Commit as TestgenCommit, Generator, Header as TestgenHeader, LightBlock as TestgenLightBlock,
+    Validator as TestgenValidator,
+};
 
 use std::convert::TryFrom;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// How far behind the current time (in seconds) the timestamps of synthetic Tendermint blocks are.
+const SYNTHETIC_BLOCK_TIME_LAG: u64 = 10_000;
+
+/// The time (in seconds since the Unix epoch) at which the first synthetic Tendermint block was
+/// generated, zero until then. All blocks are timed from it, so that the timestamps of two blocks
+/// only depend on their heights, and not on when each of them was generated.
+static SYNTHETIC_BLOCK_TIME_ORIGIN: AtomicU64 = AtomicU64::new(0);
 
 /// Defines the different types of host chains that a mock context can emulate.
 /// The variants are as follows:
@@ -44,21 +63,119 @@ impl HostBlock {
         }
     }
 
-    /// Generates a new block at `height` for the given chain identifier and chain type.
-    pub fn generate_block(chain_id: ChainId, chain_type: HostType, height: u64) -> HostBlock {
+    /// Generates a new block at `height` for the given chain identifier and chain type. The block
+    /// commits to the state of the chain through the given `root`, unless it is a `MockHeader`,
+    /// which does not carry any commitment root.
+    pub fn generate_block(
+        chain_id: ChainId,
+        chain_type: HostType,
+        height: u64,
+        root: &CommitmentRoot,
+    ) -> HostBlock {
         match chain_type {
-            HostType::Mock => HostBlock::Mock(MockHeader(Height::new(chain_id.version(), height))),
-            HostType::SyntheticTendermint => {
-                HostBlock::SyntheticTendermint(Box::new(Self::generate_tm_block(chain_id, height)))
+            HostType::Mock => {
+                HostBlock::Mock(MockHeader::new(Height::new(chain_id.version(), height)))
             }
+            HostType::SyntheticTendermint => HostBlock::SyntheticTendermint(Box::new(
+                Self::generate_tm_block_with_root(chain_id, height, root),
+            )),
         }
     }
 
     pub fn generate_tm_block(chain_id: ChainId, height: u64) -> TMLightBlock {
-        let mut block = TestgenLightBlock::new_default(height).generate().unwrap();
-        block.signed_header.header.chain_id = TMChainId::try_from(chain_id.to_string()).unwrap();
+        // The chain identifier is part of the signed header, hence it must be known upfront.
+        let header = TestgenHeader::new(&Self::tm_validators())
+            .height(height)
+            .chain_id(chain_id.as_str())
+            .next_validators(&Self::tm_validators())
+            .time(Self::tm_block_time(height));
+        let commit = TestgenCommit::new(header.clone(), 1);
+
+        TestgenLightBlock::new(header, commit).generate().unwrap()
+    }
+
+    /// Similar to `generate_tm_block`, but the app hash of the block is the given `root`.
+    pub fn generate_tm_block_with_root(
+        chain_id: ChainId,
+        height: u64,
+        root: &CommitmentRoot,
+    ) -> TMLightBlock {
+        let mut light_block = Self::generate_tm_block(chain_id, height);
+        let signed_header = &mut light_block.signed_header;
+
+        // The test generator does not let us pick the app hash, so we set it after the fact. This
+        // changes the hash of the header, thus the validators have to sign the commit again.
+        signed_header.header.app_hash = AppHash::try_from(root.as_bytes().to_vec()).unwrap();
+        let hash = signed_header.header.hash();
+        let block_id = block::Id {
+            hash,
+            part_set_header: PartSetHeader::new(1, hash).unwrap(),
+        };
+
+        let chain_id = signed_header.header.chain_id.clone();
+        let commit = &mut signed_header.commit;
+        commit.block_id = block_id;
+        for (index, commit_sig) in commit.signatures.iter_mut().enumerate() {
+            if let CommitSig::BlockIDFlagCommit {
+                validator_address,
+                timestamp,
+                signature,
+            } = commit_sig
+            {
+                let signer = Self::tm_validators()
+                    .iter()
+                    .find(|v| v.generate().unwrap().address == *validator_address)
+                    .unwrap()
+                    .get_private_key()
+                    .unwrap();
+
+                let vote = vote::Vote {
+                    vote_type: vote::Type::Precommit,
+                    height: commit.height,
+                    round: commit.round,
+                    block_id: Some(block_id),
+                    timestamp: Some(*timestamp),
+                    validator_address: *validator_address,
+                    validator_index: ValidatorIndex::try_from(index as u32).unwrap(),
+                    signature: *signature,
+                };
+                let sign_bytes = get_vote_sign_bytes(chain_id.clone(), &vote);
+                *signature = signer.sign(&sign_bytes).into();
+            }
+        }
+
+        light_block
+    }
+
+    /// The validators of synthetic Tendermint blocks.
+    fn tm_validators() -> [TestgenValidator; 2] {
+        [
+            TestgenValidator::new("1").voting_power(50),
+            TestgenValidator::new("2").voting_power(50),
+        ]
+    }
+
+    /// Returns the timestamp (in seconds since the Unix epoch) of a synthetic Tendermint block.
+    /// Blocks are one second apart, and the block at height zero lags `SYNTHETIC_BLOCK_TIME_LAG`
+    /// seconds behind the time at which the first block was generated, so that clients see them
+    /// neither as coming from the future, nor as being outside of their trusting period.
+    fn tm_block_time(height: u64) -> u64 {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("the system time is before the Unix epoch")
+            .as_secs();
+
+        let origin = match SYNTHETIC_BLOCK_TIME_ORIGIN.compare_exchange(
+            0,
+            now,
+            Ordering::SeqCst,
+            Ordering::SeqCst,
+        ) {
+            Ok(_) => now,
+            Err(origin) => origin,
+        };
 
-        block
+        origin - SYNTHETIC_BLOCK_TIME_LAG + height
     }
 }
 
@@ -0,0 +1,75 @@
+use std::convert::{TryFrom, TryInto};
+
+use serde::Serialize;
+use tendermint_proto::Protobuf;
+
+use ibc_proto::ibc::mock::Misbehaviour as RawMisbehaviour;
+
+use crate::ics02_client::client_def::AnyMisbehaviour;
+use crate::ics02_client::error::{Error, Kind};
+use crate::ics02_client::misbehaviour::Misbehaviour;
+use crate::ics24_host::identifier::ClientId;
+use crate::mock::header::MockHeader;
+use crate::Height;
+
+/// A mock of the evidence of misbehaviour. The mock client accepts any two headers at the same
+/// height as evidence, since mock headers carry nothing else that could conflict.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct MockMisbehaviour {
+    pub client_id: ClientId,
+    pub header1: MockHeader,
+    pub header2: MockHeader,
+}
+
+impl Misbehaviour for MockMisbehaviour {
+    fn client_id(&self) -> &ClientId {
+        &self.client_id
+    }
+
+    fn height(&self) -> Height {
+        self.header1.height()
+    }
+
+    fn wrap_any(self) -> AnyMisbehaviour {
+        AnyMisbehaviour::Mock(self)
+    }
+}
+
+impl From<MockMisbehaviour> for AnyMisbehaviour {
+    fn from(misbehaviour: MockMisbehaviour) -> Self {
+        Self::Mock(misbehaviour)
+    }
+}
+
+impl Protobuf<RawMisbehaviour> for MockMisbehaviour {}
+
+impl TryFrom<RawMisbehaviour> for MockMisbehaviour {
+    type Error = Error;
+
+    fn try_from(raw: RawMisbehaviour) -> Result<Self, Self::Error> {
+        Ok(Self {
+            client_id: raw
+                .client_id
+                .parse()
+                .map_err(|e| Kind::InvalidRawMisbehaviour.context(e))?,
+            header1: raw
+                .header1
+                .ok_or_else(|| Kind::InvalidRawMisbehaviour.context("missing header1"))?
+                .try_into()?,
+            header2: raw
+                .header2
+                .ok_or_else(|| Kind::InvalidRawMisbehaviour.context("missing header2"))?
+                .try_into()?,
+        })
+    }
+}
+
+impl From<MockMisbehaviour> for RawMisbehaviour {
+    fn from(value: MockMisbehaviour) -> Self {
+        RawMisbehaviour {
+            client_id: value.client_id.to_string(),
+            header1: Some(value.header1.into()),
+            header2: Some(value.header2.into()),
+        }
+    }
+}
@@ -5,3 +5,6 @@ pub mod client_state;
 pub mod context;
 pub mod header;
 pub mod host;
+pub mod misbehaviour;
+pub mod module;
+pub mod store;
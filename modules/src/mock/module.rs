@@ -0,0 +1,19 @@
+//! Implementation of a mock application module, which accepts every channel handshake step and
+//! every packet.
+
+use std::error::Error;
+
+use crate::ics04_channel::packet::{Acknowledgement, Packet};
+use crate::ics26_routing::module::Module;
+
+/// The result of the acknowledgement which the mock module writes for every packet it receives.
+pub const MOCK_ACK_RESULT: &[u8] = &[1];
+
+#[derive(Clone, Debug, Default)]
+pub struct MockModule;
+
+impl<Ctx> Module<Ctx> for MockModule {
+    fn on_recv_packet(&self, _ctx: &mut Ctx, _packet: &Packet) -> Result<Vec<u8>, Box<dyn Error>> {
+        Ok(Acknowledgement::Result(MOCK_ACK_RESULT.to_vec()).to_bytes())
+    }
+}
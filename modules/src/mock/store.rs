@@ -0,0 +1,437 @@
+//! A provable in-memory store, in which the context mock keeps its IBC state.
+//!
+//! The store is modelled after the state of a Cosmos SDK chain: the IBC state lives in an IAVL-like
+//! binary Merkle tree keyed by the `ics24_host::Path` strings, and the root of this tree is in turn
+//! committed to by a multistore whose root is the app hash of the blocks of the host chain. The
+//! proofs produced by the store are real ICS23 proofs that verify against the `cosmos_specs`.
+
+use std::collections::BTreeMap;
+
+use ibc_proto::ibc::core::commitment::v1::MerkleProof as RawMerkleProof;
+use ics23::commitment_proof::Proof as Ics23Proof;
+use ics23::{
+    CommitmentProof, ExistenceProof, HashOp, InnerOp, LeafOp, LengthOp, NonExistenceProof,
+};
+use sha2::{Digest, Sha256};
+
+use crate::ics23_commitment::commitment::{CommitmentProofBytes, CommitmentRoot};
+use crate::ics23_commitment::merkle::MerkleProof;
+use crate::ics24_host::store::Store;
+use crate::ics24_host::Path;
+use crate::Height;
+
+/// Prefix of the leaves of the IAVL tree. In IAVL, the prefix holds the height, size and version
+/// of the node; the store does not keep track of versions, so this is the same for all leaves.
+const IAVL_LEAF_PREFIX: [u8; 4] = [0, 2, 0x90, 0x03];
+
+/// Prefix of the inner nodes of the IAVL tree (see `IAVL_LEAF_PREFIX`).
+const IAVL_INNER_PREFIX: [u8; 4] = [2, 4, 0x90, 0x03];
+
+/// The length of the hashes of the nodes of the IAVL tree, as prepended to these hashes in the
+/// preimages of the inner nodes.
+const HASH_LENGTH: u8 = 32;
+
+/// The key of the IBC store in the multistore, i.e. the commitment prefix of the host chain.
+pub const IBC_STORE_KEY: &str = "ibc";
+
+/// An in-memory key-value store, keyed by `Path`, that commits to its state at every height of the
+/// host chain and proves the presence or the absence of values in the committed states.
+#[derive(Clone, Debug, Default)]
+pub struct InMemoryStore {
+    /// The current (yet to be committed) state.
+    working: BTreeMap<String, Vec<u8>>,
+
+    /// The committed states, indexed by the height at which they were committed.
+    committed: BTreeMap<Height, CommittedState>,
+}
+
+impl Store for InMemoryStore {
+    fn get(&self, path: &Path) -> Option<Vec<u8>> {
+        self.working.get(&path.to_string()).cloned()
+    }
+
+    fn set(&mut self, path: Path, value: Vec<u8>) {
+        self.working.insert(path.to_string(), value);
+    }
+
+    fn delete(&mut self, path: &Path) {
+        self.working.remove(&path.to_string());
+    }
+
+    fn get_keys(&self, key_prefix: &str) -> Vec<String> {
+        self.working
+            .range(key_prefix.to_string()..)
+            .map(|(key, _)| key)
+            .take_while(|key| key.starts_with(key_prefix))
+            .cloned()
+            .collect()
+    }
+
+    /// Commits to the current state at the given height and returns the root of the multistore.
+    fn commit(&mut self, height: Height) -> CommitmentRoot {
+        let state = CommittedState::new(&self.working);
+        let root = state.root();
+        self.committed.insert(height, state);
+        root
+    }
+
+    fn root(&self, height: Height) -> Option<CommitmentRoot> {
+        self.committed.get(&height).map(CommittedState::root)
+    }
+
+    /// Returns `None` if there is no state committed at `height`, or if this state is empty, as
+    /// nothing can be proven about an empty tree.
+    fn get_proof(&self, height: Height, path: &Path) -> Option<CommitmentProofBytes> {
+        self.committed
+            .get(&height)?
+            .proof(path.to_string().as_bytes())
+    }
+}
+
+impl InMemoryStore {
+    /// Drops all the states committed below the given height.
+    pub fn prune(&mut self, height: Height) {
+        self.committed = self.committed.split_off(&height);
+    }
+}
+
+/// The state of the store at some height, i.e. the leaves of the IAVL tree, sorted by key.
+#[derive(Clone, Debug)]
+struct CommittedState {
+    leaves: Vec<(Vec<u8>, Vec<u8>)>,
+}
+
+impl CommittedState {
+    fn new(entries: &BTreeMap<String, Vec<u8>>) -> Self {
+        let leaves = entries
+            .iter()
+            .map(|(key, value)| (key.clone().into_bytes(), value.clone()))
+            .collect();
+
+        CommittedState { leaves }
+    }
+
+    /// The root of the multistore, which consists of the IBC store alone.
+    fn root(&self) -> CommitmentRoot {
+        let proof = multistore_proof(self.iavl_root());
+        CommitmentRoot::from(ics23::calculate_existence_root(&proof).unwrap())
+    }
+
+    /// The root of the IAVL tree. The root of an empty tree is the hash of no data.
+    fn iavl_root(&self) -> Vec<u8> {
+        if self.leaves.is_empty() {
+            return Sha256::digest(&[]).to_vec();
+        }
+        subtree_hash(&self.leaf_hashes())
+    }
+
+    fn proof(&self, key: &[u8]) -> Option<CommitmentProofBytes> {
+        if self.leaves.is_empty() {
+            return None;
+        }
+
+        let leaf_hashes = self.leaf_hashes();
+        let position = self.leaves.binary_search_by(|(k, _)| k.as_slice().cmp(key));
+
+        let iavl_proof = match position {
+            Ok(index) => Ics23Proof::Exist(self.existence_proof(&leaf_hashes, index)),
+            // The key would be inserted at `index`, between the leaves at `index - 1` and `index`.
+            Err(index) => Ics23Proof::Nonexist(NonExistenceProof {
+                key: key.to_vec(),
+                left: index
+                    .checked_sub(1)
+                    .map(|left| self.existence_proof(&leaf_hashes, left)),
+                right: (index < self.leaves.len())
+                    .then(|| self.existence_proof(&leaf_hashes, index)),
+            }),
+        };
+
+        let proof = MerkleProof {
+            proofs: vec![
+                CommitmentProof {
+                    proof: Some(iavl_proof),
+                },
+                CommitmentProof {
+                    proof: Some(Ics23Proof::Exist(multistore_proof(self.iavl_root()))),
+                },
+            ],
+        };
+
+        Some(RawMerkleProof::from(proof).into())
+    }
+
+    fn existence_proof(&self, leaf_hashes: &[Vec<u8>], index: usize) -> ExistenceProof {
+        let (key, value) = &self.leaves[index];
+
+        ExistenceProof {
+            key: key.clone(),
+            value: value.clone(),
+            leaf: Some(iavl_leaf()),
+            path: inner_path(leaf_hashes, index),
+        }
+    }
+
+    fn leaf_hashes(&self) -> Vec<Vec<u8>> {
+        self.leaves
+            .iter()
+            .map(|(key, value)| leaf_hash(&iavl_leaf(), key, value))
+            .collect()
+    }
+}
+
+/// The number of leaves in the left subtree of a tree with `n > 1` leaves: the largest power of
+/// two smaller than `n`, as in the Tendermint simple Merkle tree.
+fn split_point(n: usize) -> usize {
+    let mut k = 1;
+    while k * 2 < n {
+        k *= 2;
+    }
+    k
+}
+
+/// The hash of the root of the (non-empty) tree with the given leaves.
+fn subtree_hash(leaf_hashes: &[Vec<u8>]) -> Vec<u8> {
+    if leaf_hashes.len() == 1 {
+        return leaf_hashes[0].clone();
+    }
+
+    let k = split_point(leaf_hashes.len());
+    inner_hash(
+        &subtree_hash(&leaf_hashes[..k]),
+        &subtree_hash(&leaf_hashes[k..]),
+    )
+}
+
+fn inner_hash(left: &[u8], right: &[u8]) -> Vec<u8> {
+    let mut hasher = Sha256::new();
+    hasher.update(IAVL_INNER_PREFIX);
+    hasher.update([HASH_LENGTH]);
+    hasher.update(left);
+    hasher.update([HASH_LENGTH]);
+    hasher.update(right);
+    hasher.finalize().to_vec()
+}
+
+/// The inner nodes on the path from the leaf at `index` up to the root of the tree, each of them
+/// given as the hash of the sibling of the node below, surrounded by the rest of the preimage.
+fn inner_path(leaf_hashes: &[Vec<u8>], index: usize) -> Vec<InnerOp> {
+    if leaf_hashes.len() == 1 {
+        return vec![];
+    }
+
+    let k = split_point(leaf_hashes.len());
+    let mut prefix = IAVL_INNER_PREFIX.to_vec();
+    prefix.push(HASH_LENGTH);
+    let mut suffix = vec![];
+
+    let mut path = if index < k {
+        suffix.push(HASH_LENGTH);
+        suffix.extend(subtree_hash(&leaf_hashes[k..]));
+        inner_path(&leaf_hashes[..k], index)
+    } else {
+        prefix.extend(subtree_hash(&leaf_hashes[..k]));
+        prefix.push(HASH_LENGTH);
+        inner_path(&leaf_hashes[k..], index - k)
+    };
+
+    path.push(InnerOp {
+        hash: HashOp::Sha256.into(),
+        prefix,
+        suffix,
+    });
+    path
+}
+
+/// Existence proof for the IBC store, with the given root, in the multistore.
+fn multistore_proof(ibc_root: Vec<u8>) -> ExistenceProof {
+    ExistenceProof {
+        key: IBC_STORE_KEY.as_bytes().to_vec(),
+        value: ibc_root,
+        leaf: Some(simple_leaf()),
+        path: vec![],
+    }
+}
+
+fn iavl_leaf() -> LeafOp {
+    LeafOp {
+        hash: HashOp::Sha256.into(),
+        prehash_key: HashOp::NoHash.into(),
+        prehash_value: HashOp::Sha256.into(),
+        length: LengthOp::VarProto.into(),
+        prefix: IAVL_LEAF_PREFIX.to_vec(),
+    }
+}
+
+fn simple_leaf() -> LeafOp {
+    LeafOp {
+        hash: HashOp::Sha256.into(),
+        prehash_key: HashOp::NoHash.into(),
+        prehash_value: HashOp::Sha256.into(),
+        length: LengthOp::VarProto.into(),
+        prefix: vec![0],
+    }
+}
+
+fn leaf_hash(leaf: &LeafOp, key: &[u8], value: &[u8]) -> Vec<u8> {
+    let proof = ExistenceProof {
+        key: key.to_vec(),
+        value: value.to_vec(),
+        leaf: Some(leaf.clone()),
+        path: vec![],
+    };
+    ics23::calculate_existence_root(&proof).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use std::str::FromStr;
+
+    use ibc_proto::ibc::core::commitment::v1::MerklePath;
+
+    use crate::ics23_commitment::commitment::CommitmentPrefix;
+    use crate::ics23_commitment::merkle::{apply_prefix, cosmos_specs, MerkleProof};
+    use crate::ics24_host::identifier::{ChannelId, ConnectionId, PortId};
+    use crate::ics24_host::store::Store;
+    use crate::ics24_host::Path;
+    use crate::mock::store::{InMemoryStore, IBC_STORE_KEY};
+    use crate::Height;
+
+    fn merkle_path(path: &Path) -> MerklePath {
+        apply_prefix(
+            &CommitmentPrefix::from(IBC_STORE_KEY.as_bytes().to_vec()),
+            vec![path.to_string()],
+        )
+        .unwrap()
+    }
+
+    fn connection_path(index: u64) -> Path {
+        Path::Connections(ConnectionId::from_str(&format!("connection-{}", index)).unwrap())
+    }
+
+    fn channel_path(index: u64) -> Path {
+        Path::ChannelEnds(
+            PortId::from_str("transfer").unwrap(),
+            ChannelId::from_str(&format!("channel-{}", index)).unwrap(),
+        )
+    }
+
+    #[test]
+    fn commit_and_prune() {
+        let mut store = InMemoryStore::default();
+        let (h1, h2, h3) = (Height::new(0, 1), Height::new(0, 2), Height::new(0, 3));
+
+        let empty_root = store.commit(h1);
+        assert!(store.get_proof(h1, &connection_path(0)).is_none());
+
+        store.set(connection_path(0), b"connection".to_vec());
+        let root = store.commit(h2);
+        assert_ne!(root, empty_root);
+        assert_eq!(store.get(&connection_path(0)), Some(b"connection".to_vec()));
+
+        // Deleting the only value brings the store back to its empty state.
+        store.delete(&connection_path(0));
+        assert_eq!(store.commit(h3), empty_root);
+
+        store.prune(h2);
+        assert!(store.root(h1).is_none());
+        assert_eq!(store.root(h2), Some(root));
+        assert!(store.get_proof(h2, &connection_path(0)).is_some());
+    }
+
+    #[test]
+    fn verify_proofs() {
+        let height = Height::new(0, 1);
+
+        // Trees of various shapes, including unbalanced ones.
+        for size in 1..10 {
+            let mut store = InMemoryStore::default();
+            // Leave gaps between the keys, so that there is room for absent keys in between.
+            for i in 0..size {
+                store.set(
+                    connection_path(2 * i + 1),
+                    format!("value {}", i).into_bytes(),
+                );
+            }
+            let root = store.commit(height);
+
+            for i in 0..size {
+                let path = connection_path(2 * i + 1);
+                let proof =
+                    MerkleProof::try_from(&store.get_proof(height, &path).unwrap()).unwrap();
+
+                assert!(
+                    proof
+                        .verify_membership(
+                            &cosmos_specs(),
+                            &root,
+                            merkle_path(&path),
+                            format!("value {}", i).into_bytes()
+                        )
+                        .is_ok(),
+                    "existence proof for key {} in a tree of size {}",
+                    path,
+                    size
+                );
+                assert!(proof
+                    .verify_membership(
+                        &cosmos_specs(),
+                        &root,
+                        merkle_path(&path),
+                        b"other".to_vec()
+                    )
+                    .is_err());
+            }
+
+            // Absent keys, below the first key, in between keys, and above the last key.
+            let absent_paths = (0..=size)
+                .map(|i| connection_path(2 * i))
+                .chain(std::iter::once(channel_path(0)));
+            for path in absent_paths {
+                let proof =
+                    MerkleProof::try_from(&store.get_proof(height, &path).unwrap()).unwrap();
+
+                assert!(
+                    proof
+                        .verify_non_membership(&cosmos_specs(), &root, merkle_path(&path))
+                        .is_ok(),
+                    "absence proof for key {} in a tree of size {}",
+                    path,
+                    size
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn proofs_are_bound_to_their_height() {
+        let mut store = InMemoryStore::default();
+        let (h1, h2) = (Height::new(0, 1), Height::new(0, 2));
+        let path = connection_path(0);
+
+        store.set(path.clone(), b"first".to_vec());
+        store.commit(h1);
+        store.set(path.clone(), b"second".to_vec());
+        let root = store.commit(h2);
+
+        let old_proof = MerkleProof::try_from(&store.get_proof(h1, &path).unwrap()).unwrap();
+        assert!(old_proof
+            .verify_membership(
+                &cosmos_specs(),
+                &root,
+                merkle_path(&path),
+                b"first".to_vec()
+            )
+            .is_err());
+
+        let proof = MerkleProof::try_from(&store.get_proof(h2, &path).unwrap()).unwrap();
+        assert!(proof
+            .verify_membership(
+                &cosmos_specs(),
+                &root,
+                merkle_path(&path),
+                b"second".to_vec()
+            )
+            .is_ok());
+    }
+}
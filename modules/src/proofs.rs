@@ -64,6 +64,11 @@ impl Proofs {
     pub fn client_proof(&self) -> &Option<CommitmentProofBytes> {
         &self.client_proof
     }
+
+    /// Getter for the other_proof.
+    pub fn other_proof(&self) -> &Option<CommitmentProofBytes> {
+        &self.other_proof
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize)]
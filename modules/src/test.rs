@@ -1,5 +1,11 @@
+use anomaly::BoxError;
+use prost::Message;
 use serde::{de::DeserializeOwned, Serialize};
+use std::convert::TryFrom;
 use std::fmt::Debug;
+use tendermint_proto::Protobuf;
+
+use crate::tx_msg::Msg;
 
 /// Test that a struct `T` can be:
 ///
@@ -28,3 +34,34 @@ where
     // TODO - fix PartialEq bound issue in AbciQuery
     //assert_eq!(parsed0, parsed1);
 }
+
+/// Test that a domain message `T`:
+///
+/// - converts into its raw (proto) type `R`, and back into an equal message
+/// - converts back into the same raw message
+/// - is encoded by `to_any` under the given type URL, and decoded back into an equal message
+///
+/// Any field which either conversion drops or overwrites fails the test, as long as its value in
+/// `msg` differs from its default.
+pub fn test_msg_roundtrip<T, R>(msg: T, type_url: &str)
+where
+    T: Msg + Protobuf<R> + Debug + PartialEq,
+    <T as TryFrom<R>>::Error: Into<BoxError>,
+    R: Message + From<T> + Default + Clone + Debug + PartialEq,
+{
+    let raw = R::from(msg.clone());
+    let msg_back = T::try_from(raw.clone()).map_err(Into::<BoxError>::into);
+    assert!(
+        msg_back.is_ok(),
+        "conversion from raw message {:?} failed: {:?}",
+        raw,
+        msg_back.err()
+    );
+    let msg_back = msg_back.unwrap();
+    assert_eq!(msg_back, msg);
+    assert_eq!(R::from(msg_back), raw);
+
+    let any = msg.to_any::<R>();
+    assert_eq!(any.type_url, type_url);
+    assert_eq!(T::decode_vec(&any.value).unwrap(), msg);
+}
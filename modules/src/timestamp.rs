@@ -0,0 +1,180 @@
+//! Timestamps as found in IBC packets and consensus states: a number of nanoseconds since the
+//! Unix epoch, where zero means that no timestamp is set.
+
+use std::convert::TryFrom;
+use std::fmt::{Display, Error as FmtError, Formatter};
+use std::num::ParseIntError;
+use std::str::FromStr;
+
+use chrono::{DateTime, TimeZone, Utc};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use tendermint::Time;
+use thiserror::Error;
+
+/// A point in time, or no time at all. Packets whose timeout timestamp is not set do not time
+/// out by time.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Timestamp {
+    time: Option<DateTime<Utc>>,
+}
+
+/// The outcome of comparing a timeout timestamp with the current time.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Expiry {
+    Expired,
+    NotExpired,
+    /// One of the two timestamps is not set.
+    InvalidTimestamp,
+}
+
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+pub enum ParseTimestampError {
+    #[error("{0} nanoseconds since the Unix epoch is out of the range of timestamps")]
+    OutOfRange(u64),
+
+    #[error("invalid number of nanoseconds: {0}")]
+    ParseInt(#[from] ParseIntError),
+}
+
+impl Timestamp {
+    /// Builds a timestamp from a number of nanoseconds since the Unix epoch, zero meaning that no
+    /// timestamp is set.
+    pub fn from_nanoseconds(nanoseconds: u64) -> Result<Timestamp, ParseTimestampError> {
+        if nanoseconds == 0 {
+            return Ok(Timestamp::none());
+        }
+
+        let nanoseconds =
+            i64::try_from(nanoseconds).map_err(|_| ParseTimestampError::OutOfRange(nanoseconds))?;
+        Ok(Timestamp {
+            time: Some(Utc.timestamp_nanos(nanoseconds)),
+        })
+    }
+
+    /// The current time.
+    pub fn now() -> Timestamp {
+        Timestamp {
+            time: Some(Utc::now()),
+        }
+    }
+
+    /// No timestamp.
+    pub fn none() -> Timestamp {
+        Timestamp { time: None }
+    }
+
+    pub fn is_set(&self) -> bool {
+        self.time.is_some()
+    }
+
+    /// Returns the number of nanoseconds since the Unix epoch, or zero if no timestamp is set.
+    pub fn nanoseconds(&self) -> u64 {
+        self.time
+            .and_then(|time| time.timestamp_nanos_opt())
+            .map_or(0, |nanoseconds| nanoseconds.max(0) as u64)
+    }
+
+    pub fn as_datetime(&self) -> Option<DateTime<Utc>> {
+        self.time
+    }
+
+    /// Checks whether this timestamp, taken as a timeout, has expired at time `now`, that is,
+    /// whether `now` is at or past it.
+    pub fn check_expiry(&self, now: &Timestamp) -> Expiry {
+        match (self.time, now.time) {
+            (Some(timeout), Some(now)) if now >= timeout => Expiry::Expired,
+            (Some(_), Some(_)) => Expiry::NotExpired,
+            _ => Expiry::InvalidTimestamp,
+        }
+    }
+}
+
+impl Display for Timestamp {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        match self.time {
+            Some(time) => write!(f, "{}", time.to_rfc3339()),
+            None => write!(f, "no timestamp"),
+        }
+    }
+}
+
+/// Parses a number of nanoseconds since the Unix epoch, as found in packet event attributes.
+impl FromStr for Timestamp {
+    type Err = ParseTimestampError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Timestamp::from_nanoseconds(s.parse()?)
+    }
+}
+
+impl From<Time> for Timestamp {
+    fn from(time: Time) -> Self {
+        Timestamp {
+            time: Some(time.into()),
+        }
+    }
+}
+
+/// Timestamps are serialized as their number of nanoseconds since the Unix epoch, as in packets.
+impl Serialize for Timestamp {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(self.nanoseconds())
+    }
+}
+
+impl<'de> Deserialize<'de> for Timestamp {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let nanoseconds = u64::deserialize(deserializer)?;
+        Timestamp::from_nanoseconds(nanoseconds).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::timestamp::{Expiry, Timestamp};
+
+    #[test]
+    fn timestamp_nanoseconds() {
+        for nanoseconds in [1, 1_624_000_000_000_000_000, i64::MAX as u64].iter() {
+            let timestamp = Timestamp::from_nanoseconds(*nanoseconds).unwrap();
+            assert!(timestamp.is_set());
+            assert_eq!(timestamp.nanoseconds(), *nanoseconds);
+        }
+
+        let none = Timestamp::from_nanoseconds(0).unwrap();
+        assert_eq!(none, Timestamp::none());
+        assert_eq!(none.nanoseconds(), 0);
+
+        assert!(Timestamp::from_nanoseconds(u64::MAX).is_err());
+
+        assert_eq!(
+            "1624000000000000000".parse::<Timestamp>().unwrap(),
+            Timestamp::from_nanoseconds(1_624_000_000_000_000_000).unwrap()
+        );
+        assert_eq!("0".parse::<Timestamp>().unwrap(), Timestamp::none());
+        assert!("now".parse::<Timestamp>().is_err());
+    }
+
+    #[test]
+    fn timestamp_expiry() {
+        let now = Timestamp::now();
+        let later = Timestamp::from_nanoseconds(
+            now.nanoseconds() + Duration::from_secs(10).as_nanos() as u64,
+        )
+        .unwrap();
+
+        assert_eq!(later.check_expiry(&now), Expiry::NotExpired);
+        assert_eq!(now.check_expiry(&later), Expiry::Expired);
+        assert_eq!(now.check_expiry(&now), Expiry::Expired);
+        assert_eq!(
+            Timestamp::none().check_expiry(&now),
+            Expiry::InvalidTimestamp
+        );
+        assert_eq!(
+            now.check_expiry(&Timestamp::none()),
+            Expiry::InvalidTimestamp
+        );
+    }
+}
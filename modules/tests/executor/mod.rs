@@ -7,32 +7,80 @@ use ibc::ics02_client::error::Kind as ICS02ErrorKind;
 use ibc::ics02_client::msgs::create_client::MsgCreateAnyClient;
 use ibc::ics02_client::msgs::update_client::MsgUpdateAnyClient;
 use ibc::ics02_client::msgs::ClientMsg;
+use ibc::ics03_connection::connection::{ConnectionEnd, Counterparty, State as ConnectionEndState};
+use ibc::ics03_connection::context::ConnectionReader;
+use ibc::ics03_connection::error::Kind as ICS03ErrorKind;
+use ibc::ics03_connection::msgs::conn_open_ack::MsgConnectionOpenAck;
+use ibc::ics03_connection::msgs::conn_open_confirm::MsgConnectionOpenConfirm;
+use ibc::ics03_connection::msgs::conn_open_init::MsgConnectionOpenInit;
+use ibc::ics03_connection::msgs::conn_open_try::MsgConnectionOpenTry;
+use ibc::ics03_connection::msgs::ConnectionMsg;
+use ibc::ics03_connection::version::Version;
+use ibc::ics04_channel::channel::{
+    ChannelEnd, Counterparty as ChannelCounterparty, Order, State as ChannelEndState,
+};
+use ibc::ics04_channel::context::{ChannelKeeper, ChannelReader};
+use ibc::ics04_channel::error::{Error as ICS04Error, Kind as ICS04ErrorKind};
+use ibc::ics04_channel::handler::send_packet::send_packet;
+use ibc::ics04_channel::msgs::acknowledgement::MsgAcknowledgement;
+use ibc::ics04_channel::msgs::chan_close_confirm::MsgChannelCloseConfirm;
+use ibc::ics04_channel::msgs::chan_close_init::MsgChannelCloseInit;
+use ibc::ics04_channel::msgs::chan_open_ack::MsgChannelOpenAck;
+use ibc::ics04_channel::msgs::chan_open_confirm::MsgChannelOpenConfirm;
+use ibc::ics04_channel::msgs::chan_open_init::MsgChannelOpenInit;
+use ibc::ics04_channel::msgs::chan_open_try::MsgChannelOpenTry;
+use ibc::ics04_channel::msgs::recv_packet::MsgRecvPacket;
+use ibc::ics04_channel::msgs::timeout::MsgTimeout;
+use ibc::ics04_channel::msgs::{ChannelMsg, PacketMsg};
+use ibc::ics04_channel::packet::{Acknowledgement, Packet};
 use ibc::ics18_relayer::context::ICS18Context;
 use ibc::ics18_relayer::error::{Error as ICS18Error, Kind as ICS18ErrorKind};
-use ibc::ics24_host::identifier::{ChainId, ClientId};
+use ibc::ics23_commitment::commitment::{CommitmentPrefix, CommitmentProofBytes};
+use ibc::ics24_host::identifier::{ChainId, ChannelId, ClientId, ConnectionId, PortId};
 use ibc::ics26_routing::error::{Error as ICS26Error, Kind as ICS26ErrorKind};
 use ibc::ics26_routing::msgs::ICS26Envelope;
 use ibc::mock::client_state::{MockClientState, MockConsensusState};
 use ibc::mock::context::MockContext;
 use ibc::mock::header::MockHeader;
 use ibc::mock::host::HostType;
+use ibc::mock::module::MOCK_ACK_RESULT;
+use ibc::proofs::{ConsensusProof, Proofs};
+use ibc::test_utils::get_dummy_proof;
+use ibc::timestamp::Timestamp;
 use ibc::Height;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::error::Error;
 use std::fmt::{Debug, Display};
-use step::{ActionOutcome, ActionType, Chain, Step};
+use std::str::FromStr;
+use step::{
+    ActionOutcome, ActionType, Chain, Channel, ChannelOrder, ChannelState, Connection,
+    ConnectionState, Step,
+};
 use tendermint::account::Id as AccountId;
 
+/// A packet on a chain, identified by the model identifiers of the chain and of the channel
+/// end of the packet on that chain, and by its sequence.
+type PacketKey = (String, u64, u64);
+
 #[derive(Debug)]
 pub struct IBCTestExecutor {
     // mapping from chain identifier to its context
     contexts: HashMap<ChainId, MockContext>,
+    // packets received by the chains, on their destination channel
+    received_packets: HashSet<PacketKey>,
+    // packets acknowledged on the chains, on their source channel
+    acknowledged_packets: HashSet<PacketKey>,
+    // packets timed out on the chains, on their source channel
+    timed_out_packets: HashSet<PacketKey>,
 }
 
 impl IBCTestExecutor {
     pub fn new() -> Self {
         Self {
             contexts: Default::default(),
+            received_packets: Default::default(),
+            acknowledged_packets: Default::default(),
+            timed_out_packets: Default::default(),
         }
     }
 
@@ -41,12 +89,14 @@ impl IBCTestExecutor {
     fn init_chain_context(&mut self, chain_id: String, initial_height: u64) {
         let chain_id = Self::chain_id(chain_id);
         let max_history_size = 1;
-        let ctx = MockContext::new(
+        let mut ctx = MockContext::new(
             chain_id.clone(),
             HostType::Mock,
             max_history_size,
             Height::new(Self::revision(), initial_height),
         );
+        // all channels are opened on the same port, bound to the mock module
+        ctx.add_port(Self::port_id());
         assert!(self.contexts.insert(chain_id, ctx).is_none());
     }
 
@@ -93,6 +143,13 @@ impl IBCTestExecutor {
             .clone()
     }
 
+    fn extract_error_kind<K>(result: Result<(), anomaly::Error<K>>) -> K
+    where
+        K: Clone + Debug + Display + Into<anomaly::BoxError> + 'static,
+    {
+        result.expect_err("error expected").kind().clone()
+    }
+
     fn chain_id(chain_id: String) -> ChainId {
         ChainId::new(chain_id, Self::revision())
     }
@@ -106,12 +163,25 @@ impl IBCTestExecutor {
             .expect("it should be possible to create the client identifier")
     }
 
+    fn connection_id(connection_id: u64) -> ConnectionId {
+        ConnectionId::from_str(&format!("{}-{}", ConnectionId::default(), connection_id))
+            .expect("it should be possible to create the connection identifier")
+    }
+
+    fn port_id() -> PortId {
+        PortId::default()
+    }
+
+    fn channel_id(channel_id: u64) -> ChannelId {
+        ChannelId::new(channel_id)
+    }
+
     fn height(height: u64) -> Height {
         Height::new(Self::revision(), height)
     }
 
     fn mock_header(height: u64) -> MockHeader {
-        MockHeader(Self::height(height))
+        MockHeader::new(Self::height(height))
     }
 
     fn header(height: u64) -> AnyHeader {
@@ -119,24 +189,322 @@ impl IBCTestExecutor {
     }
 
     fn client_state(height: u64) -> AnyClientState {
-        AnyClientState::Mock(MockClientState(Self::mock_header(height)))
+        AnyClientState::Mock(MockClientState::new(Self::mock_header(height)))
     }
 
     fn consensus_state(height: u64) -> AnyConsensusState {
-        AnyConsensusState::Mock(MockConsensusState(Self::mock_header(height)))
+        AnyConsensusState::Mock(MockConsensusState::new(Self::mock_header(height)))
     }
 
     fn signer() -> AccountId {
         AccountId::new([0; 20])
     }
 
+    fn counterparty(client_id: u64, connection_id: Option<u64>) -> Counterparty {
+        let connection_id = connection_id.map(Self::connection_id);
+        Counterparty::new(
+            Self::client_id(client_id),
+            connection_id,
+            CommitmentPrefix::from(b"ibc".to_vec()),
+        )
+    }
+
+    fn version() -> Version {
+        Version::default()
+    }
+
+    fn versions() -> Vec<Version> {
+        vec![Self::version()]
+    }
+
+    fn order(order: ChannelOrder) -> Order {
+        match order {
+            ChannelOrder::None => Order::None,
+            ChannelOrder::Ordered => Order::Ordered,
+            ChannelOrder::Unordered => Order::Unordered,
+        }
+    }
+
+    fn channel_version() -> String {
+        "ics20".to_string()
+    }
+
+    fn channel_end(
+        state: ChannelEndState,
+        order: ChannelOrder,
+        connection_id: u64,
+        counterparty_channel_id: Option<u64>,
+    ) -> ChannelEnd {
+        ChannelEnd::new(
+            state,
+            Self::order(order),
+            ChannelCounterparty::new(
+                Self::port_id(),
+                counterparty_channel_id.map(Self::channel_id),
+            ),
+            vec![Self::connection_id(connection_id)],
+            Self::channel_version(),
+        )
+    }
+
+    /// Returns the packet of the model, which has no timeout timestamp.
+    fn packet(packet: &step::Packet) -> Packet {
+        Packet {
+            sequence: packet.sequence.into(),
+            source_port: Self::port_id(),
+            source_channel: Self::channel_id(packet.source_channel_id),
+            destination_port: Self::port_id(),
+            destination_channel: Self::channel_id(packet.dest_channel_id),
+            data: Self::packet_data(),
+            timeout_height: Self::height(packet.timeout_height),
+            timeout_timestamp: Timestamp::none(),
+        }
+    }
+
+    fn packet_data() -> Vec<u8> {
+        b"packet".to_vec()
+    }
+
+    /// Returns the acknowledgement written by the mock module for every packet it receives.
+    fn acknowledgement() -> Vec<u8> {
+        Acknowledgement::Result(MOCK_ACK_RESULT.to_vec()).to_bytes()
+    }
+
+    fn proof() -> CommitmentProofBytes {
+        get_dummy_proof().into()
+    }
+
+    /// Returns the proofs of a connection handshake message, which the mock client does not
+    /// verify. They are at the latest height of the client `client_id` of the chain, if any, for
+    /// which the client has a consensus state. The height of the consensus state of the chain
+    /// which they claim, if any, is the height of `client_state`.
+    fn proofs(ctx: &MockContext, client_id: &ClientId, client_state: Option<u64>) -> Proofs {
+        let proof_height = ConnectionReader::client_state(ctx, client_id)
+            .map(|client_state| client_state.latest_height())
+            .unwrap_or_else(|| Self::height(1));
+        let (client_proof, consensus_proof) = match client_state {
+            Some(client_state) => (
+                Some(Self::proof()),
+                Some(
+                    ConsensusProof::new(Self::proof(), Self::height(client_state))
+                        .expect("it should be possible to create the consensus proof"),
+                ),
+            ),
+            None => (None, None),
+        };
+        Proofs::new(
+            Self::proof(),
+            client_proof,
+            consensus_proof,
+            None,
+            proof_height,
+        )
+        .expect("it should be possible to create the proofs")
+    }
+
+    /// Returns the proofs of a connection handshake message on an existing connection, at the
+    /// latest height of the client of the connection (see `proofs`).
+    fn connection_proofs(
+        ctx: &MockContext,
+        connection_id: &ConnectionId,
+        client_state: Option<u64>,
+    ) -> Proofs {
+        let client_id = ConnectionReader::connection_end(ctx, connection_id)
+            .map(|connection_end| connection_end.client_id().clone())
+            .unwrap_or_else(|| Self::client_id(0));
+        Self::proofs(ctx, &client_id, client_state)
+    }
+
+    /// Returns the proofs of a channel handshake or packet message on an existing channel, at the
+    /// latest height of the client of the connection of the channel (see `proofs`).
+    fn channel_proofs(ctx: &MockContext, channel_id: &ChannelId) -> Proofs {
+        let connection_id = ChannelReader::channel_end(ctx, &(Self::port_id(), channel_id.clone()))
+            .map(|channel_end| channel_end.connection_hops()[0].clone())
+            .unwrap_or_else(|| Self::connection_id(0));
+        Self::connection_proofs(ctx, &connection_id, None)
+    }
+
     /// Check that chain heights match the ones in the model.
-    fn check_chain_heights(&self, chains: HashMap<String, Chain>) -> bool {
-        chains.into_iter().all(|(chain_id, chain)| {
-            let ctx = self.chain_context(chain_id);
+    fn check_chain_heights(&self, chains: &HashMap<String, Chain>) -> bool {
+        chains.iter().all(|(chain_id, chain)| {
+            let ctx = self.chain_context(chain_id.clone());
             ctx.query_latest_height() == Self::height(chain.height)
         })
     }
+
+    /// Check that the connection ends of the chains match the connections in the model.
+    fn check_chain_connections(&self, chains: &HashMap<String, Chain>) -> bool {
+        chains.iter().all(|(chain_id, chain)| {
+            let ctx = self.chain_context(chain_id.clone());
+            chain.connections.iter().all(|(connection_id, connection)| {
+                let connection_end =
+                    ConnectionReader::connection_end(ctx, &Self::connection_id(*connection_id));
+                match connection.state {
+                    ConnectionState::Uninit => connection_end.is_none(),
+                    _ => connection_end.is_some_and(|connection_end| {
+                        Self::connection_matches(connection, &connection_end)
+                    }),
+                }
+            })
+        })
+    }
+
+    fn connection_matches(connection: &Connection, connection_end: &ConnectionEnd) -> bool {
+        let state = match connection.state {
+            ConnectionState::Uninit => ConnectionEndState::Uninitialized,
+            ConnectionState::Init => ConnectionEndState::Init,
+            ConnectionState::TryOpen => ConnectionEndState::TryOpen,
+            ConnectionState::Open => ConnectionEndState::Open,
+        };
+        connection_end.state_matches(&state)
+            && Some(connection_end.client_id())
+                == connection.client_id.map(Self::client_id).as_ref()
+            && Some(connection_end.counterparty().client_id())
+                == connection
+                    .counterparty_client_id
+                    .map(Self::client_id)
+                    .as_ref()
+            && connection_end.counterparty().connection_id()
+                == connection
+                    .counterparty_connection_id
+                    .map(Self::connection_id)
+                    .as_ref()
+    }
+
+    /// Check that the channel ends of the chains, and their sequences, match the channels in the
+    /// model.
+    fn check_chain_channels(&self, chains: &HashMap<String, Chain>) -> bool {
+        chains.iter().all(|(chain_id, chain)| {
+            let ctx = self.chain_context(chain_id.clone());
+            chain.channels.iter().all(|(channel_id, channel)| {
+                let port_channel_id = (Self::port_id(), Self::channel_id(*channel_id));
+                let channel_end = ChannelReader::channel_end(ctx, &port_channel_id);
+                match channel.state {
+                    ChannelState::Uninit => channel_end.is_none(),
+                    _ => {
+                        channel_end
+                            .is_some_and(|channel_end| Self::channel_matches(channel, &channel_end))
+                            && ctx.get_next_sequence_send(&port_channel_id)
+                                == Some(channel.next_send_seq.into())
+                            && ctx.get_next_sequence_recv(&port_channel_id)
+                                == Some(channel.next_recv_seq.into())
+                            && ctx.get_next_sequence_ack(&port_channel_id)
+                                == Some(channel.next_ack_seq.into())
+                    }
+                }
+            })
+        })
+    }
+
+    fn channel_matches(channel: &Channel, channel_end: &ChannelEnd) -> bool {
+        let state = match channel.state {
+            ChannelState::Uninit => ChannelEndState::Uninitialized,
+            ChannelState::Init => ChannelEndState::Init,
+            ChannelState::TryOpen => ChannelEndState::TryOpen,
+            ChannelState::Open => ChannelEndState::Open,
+            ChannelState::Closed => ChannelEndState::Closed,
+        };
+        let connection_hops: Vec<_> = channel
+            .connection_id
+            .map(Self::connection_id)
+            .into_iter()
+            .collect();
+        channel_end.state_matches(&state)
+            && channel_end.order_matches(&Self::order(channel.order))
+            && channel_end.connection_hops_matches(&connection_hops)
+            && channel_end.counterparty().channel_id()
+                == channel
+                    .counterparty_channel_id
+                    .map(Self::channel_id)
+                    .as_ref()
+    }
+
+    /// Check that the packet commitments, receipts and acknowledgements stored by the chains
+    /// match the packets in the model: the packets sent on a channel have a commitment until
+    /// they are acknowledged or timed out, and the packets received have an acknowledgement (and
+    /// a receipt on unordered channels).
+    fn check_chain_packets(&self, chains: &HashMap<String, Chain>) -> bool {
+        chains.iter().all(|(chain_id, chain)| {
+            let ctx = self.chain_context(chain_id.clone());
+            let commitments_match = chain.channels.iter().all(|(channel_id, channel)| {
+                (1..channel.next_send_seq).all(|sequence| {
+                    let key = (
+                        Self::port_id(),
+                        Self::channel_id(*channel_id),
+                        sequence.into(),
+                    );
+                    let committed = chain.packet_commitments.iter().any(|packet| {
+                        packet.source_channel_id == *channel_id && packet.sequence == sequence
+                    });
+                    ctx.get_packet_commitment(&key).is_some() == committed
+                })
+            });
+            let receipts_match = chain.packet_receipts.iter().all(|packet| {
+                let key = (
+                    Self::port_id(),
+                    Self::channel_id(packet.dest_channel_id),
+                    packet.sequence.into(),
+                );
+                let unordered = chain
+                    .channels
+                    .get(&packet.dest_channel_id)
+                    .is_some_and(|channel| channel.order == ChannelOrder::Unordered);
+                ctx.get_packet_acknowledgement(&key).is_some()
+                    && (!unordered || ctx.get_packet_receipt(&key).is_some())
+            });
+            commitments_match && receipts_match
+        })
+    }
+
+    /// Check that no packet was both acknowledged and timed out, and that the chains no longer
+    /// store a commitment for these packets.
+    fn check_no_packet_acknowledged_and_timed_out(&self) -> bool {
+        self.acknowledged_packets
+            .is_disjoint(&self.timed_out_packets)
+            && self
+                .acknowledged_packets
+                .union(&self.timed_out_packets)
+                .all(|(chain_id, channel_id, sequence)| {
+                    let ctx = self.chain_context(chain_id.clone());
+                    let key = (
+                        Self::port_id(),
+                        Self::channel_id(*channel_id),
+                        (*sequence).into(),
+                    );
+                    ctx.get_packet_commitment(&key).is_none()
+                })
+    }
+
+    /// Check that, on the ordered channel ends of the chains, the sequences of the packets
+    /// received and acknowledged never skip: they are exactly the ones below the next sequence
+    /// to be received and acknowledged, respectively.
+    fn check_ordered_sequences_never_skip(&self, chains: &HashMap<String, Chain>) -> bool {
+        chains.iter().all(|(chain_id, chain)| {
+            let ctx = self.chain_context(chain_id.clone());
+            chain.channels.keys().all(|channel_id| {
+                let port_channel_id = (Self::port_id(), Self::channel_id(*channel_id));
+                let ordered = ChannelReader::channel_end(ctx, &port_channel_id)
+                    .is_some_and(|channel_end| channel_end.order_matches(&Order::Ordered));
+                if !ordered {
+                    return true;
+                }
+                let sequences = |packets: &HashSet<PacketKey>| -> HashSet<u64> {
+                    packets
+                        .iter()
+                        .filter(|(id, channel, _)| id == chain_id && channel == channel_id)
+                        .map(|(_, _, sequence)| *sequence)
+                        .collect()
+                };
+                let below = |next_sequence: Option<u64>| -> HashSet<u64> {
+                    (1..next_sequence.unwrap_or(1)).collect()
+                };
+                sequences(&self.received_packets)
+                    == below(ctx.get_next_sequence_recv(&port_channel_id).map(u64::from))
+                    && sequences(&self.acknowledged_packets)
+                        == below(ctx.get_next_sequence_ack(&port_channel_id).map(u64::from))
+            })
+        })
+    }
 }
 
 impl modelator::TestExecutor<Step> for IBCTestExecutor {
@@ -245,8 +613,1122 @@ impl modelator::TestExecutor<Step> for IBCTestExecutor {
                     action => panic!("unexpected action outcome {:?}", action),
                 }
             }
+            ActionType::ICS03ConnectionOpenInit => {
+                // get action parameters
+                let chain_id = step
+                    .action
+                    .chain_id
+                    .expect("connection open init action should have a chain identifier");
+                let client_id = step
+                    .action
+                    .client_id
+                    .expect("connection open init action should have a client identifier");
+                let counterparty_client_id = step.action.counterparty_client_id.expect(
+                    "connection open init action should have a counterparty client identifier",
+                );
+
+                // get chain's context
+                let ctx = self.chain_context_mut(chain_id);
+
+                // create ICS26 message and deliver it
+                let msg = ICS26Envelope::ICS3Msg(ConnectionMsg::ConnectionOpenInit(
+                    MsgConnectionOpenInit {
+                        client_id: Self::client_id(client_id),
+                        counterparty: Self::counterparty(counterparty_client_id, None),
+                        version: Self::version(),
+                        delay_period: 0,
+                        signer: Self::signer(),
+                    },
+                ));
+                let result = ctx.deliver(msg);
+
+                // check the expected outcome
+                match step.action_outcome {
+                    ActionOutcome::ICS03ConnectionOpenInitOK => {
+                        // the implementaion matches the model if no error occurs
+                        result.is_ok()
+                    }
+                    ActionOutcome::ICS03MissingClient => {
+                        let handler_error_kind =
+                            Self::extract_handler_error_kind::<ICS03ErrorKind>(result);
+                        // the implementaion matches the model if there's an
+                        // error matching the expected outcome
+                        matches!(
+                            handler_error_kind,
+                            ICS03ErrorKind::MissingClient(error_client_id)
+                            if error_client_id == Self::client_id(client_id)
+                        )
+                    }
+                    action => panic!("unexpected action outcome {:?}", action),
+                }
+            }
+            ActionType::ICS03ConnectionOpenTry => {
+                // get action parameters
+                let chain_id = step
+                    .action
+                    .chain_id
+                    .expect("connection open try action should have a chain identifier");
+                let previous_connection_id = step.action.previous_connection_id;
+                let client_id = step
+                    .action
+                    .client_id
+                    .expect("connection open try action should have a client identifier");
+                let client_state = step
+                    .action
+                    .client_state
+                    .expect("connection open try action should have a client state");
+                let counterparty_client_id = step.action.counterparty_client_id.expect(
+                    "connection open try action should have a counterparty client identifier",
+                );
+                let counterparty_connection_id = step.action.counterparty_connection_id.expect(
+                    "connection open try action should have a counterparty connection identifier",
+                );
+
+                // get chain's context
+                let ctx = self.chain_context_mut(chain_id);
+
+                // create ICS26 message and deliver it
+                let msg = ICS26Envelope::ICS3Msg(ConnectionMsg::ConnectionOpenTry(Box::new(
+                    MsgConnectionOpenTry {
+                        previous_connection_id: previous_connection_id.map(Self::connection_id),
+                        client_id: Self::client_id(client_id),
+                        client_state: Some(Self::client_state(client_state)),
+                        counterparty: Self::counterparty(
+                            counterparty_client_id,
+                            Some(counterparty_connection_id),
+                        ),
+                        counterparty_versions: Self::versions(),
+                        proofs: Self::proofs(ctx, &Self::client_id(client_id), Some(client_state)),
+                        delay_period: 0,
+                        signer: Self::signer(),
+                    },
+                )));
+                let result = ctx.deliver(msg);
+
+                // check the expected outcome
+                match step.action_outcome {
+                    ActionOutcome::ICS03ConnectionOpenTryOK => {
+                        // the implementaion matches the model if no error occurs
+                        result.is_ok()
+                    }
+                    ActionOutcome::ICS03InvalidConsensusHeight => {
+                        let handler_error_kind =
+                            Self::extract_handler_error_kind::<ICS03ErrorKind>(result);
+                        // the implementaion matches the model if there's an
+                        // error matching the expected outcome
+                        matches!(
+                            handler_error_kind,
+                            ICS03ErrorKind::InvalidConsensusHeight(error_consensus_height, _)
+                            if error_consensus_height == Self::height(client_state)
+                        )
+                    }
+                    ActionOutcome::ICS03StaleConsensusHeight => {
+                        let handler_error_kind =
+                            Self::extract_handler_error_kind::<ICS03ErrorKind>(result);
+                        // the implementaion matches the model if there's an
+                        // error matching the expected outcome
+                        matches!(
+                            handler_error_kind,
+                            ICS03ErrorKind::StaleConsensusHeight(error_consensus_height, _)
+                            if error_consensus_height == Self::height(client_state)
+                        )
+                    }
+                    ActionOutcome::ICS03ConnectionNotFound => {
+                        let handler_error_kind =
+                            Self::extract_handler_error_kind::<ICS03ErrorKind>(result);
+                        // the implementaion matches the model if there's an
+                        // error matching the expected outcome
+                        matches!(
+                            handler_error_kind,
+                            ICS03ErrorKind::ConnectionNotFound(error_connection_id)
+                            if Some(&error_connection_id) == previous_connection_id.map(Self::connection_id).as_ref()
+                        )
+                    }
+                    ActionOutcome::ICS03ConnectionMismatch => {
+                        let handler_error_kind =
+                            Self::extract_handler_error_kind::<ICS03ErrorKind>(result);
+                        // the implementaion matches the model if there's an
+                        // error matching the expected outcome
+                        matches!(
+                            handler_error_kind,
+                            ICS03ErrorKind::ConnectionMismatch(error_connection_id)
+                            if Some(&error_connection_id) == previous_connection_id.map(Self::connection_id).as_ref()
+                        )
+                    }
+                    ActionOutcome::ICS03MissingClient => {
+                        let handler_error_kind =
+                            Self::extract_handler_error_kind::<ICS03ErrorKind>(result);
+                        // the implementaion matches the model if there's an
+                        // error matching the expected outcome
+                        matches!(
+                            handler_error_kind,
+                            ICS03ErrorKind::MissingClient(error_client_id)
+                            if error_client_id == Self::client_id(client_id)
+                        )
+                    }
+                    ActionOutcome::ICS03MissingLocalConsensusState => {
+                        let handler_error_kind =
+                            Self::extract_handler_error_kind::<ICS03ErrorKind>(result);
+                        // the implementaion matches the model if there's an
+                        // error matching the expected outcome
+                        handler_error_kind == ICS03ErrorKind::MissingLocalConsensusState
+                    }
+                    action => panic!("unexpected action outcome {:?}", action),
+                }
+            }
+            ActionType::ICS03ConnectionOpenAck => {
+                // get action parameters
+                let chain_id = step
+                    .action
+                    .chain_id
+                    .expect("connection open ack action should have a chain identifier");
+                let connection_id = step
+                    .action
+                    .connection_id
+                    .expect("connection open ack action should have a connection identifier");
+                let client_state = step
+                    .action
+                    .client_state
+                    .expect("connection open ack action should have a client state");
+                let counterparty_connection_id = step.action.counterparty_connection_id;
+
+                // get chain's context
+                let ctx = self.chain_context_mut(chain_id);
+
+                // create ICS26 message and deliver it
+                let connection_id = Self::connection_id(connection_id);
+                let msg = ICS26Envelope::ICS3Msg(ConnectionMsg::ConnectionOpenAck(Box::new(
+                    MsgConnectionOpenAck {
+                        connection_id: connection_id.clone(),
+                        counterparty_connection_id: counterparty_connection_id
+                            .map(Self::connection_id),
+                        client_state: Some(Self::client_state(client_state)),
+                        proofs: Self::connection_proofs(ctx, &connection_id, Some(client_state)),
+                        version: Self::version(),
+                        signer: Self::signer(),
+                    },
+                )));
+                let result = ctx.deliver(msg);
+
+                // check the expected outcome
+                match step.action_outcome {
+                    ActionOutcome::ICS03ConnectionOpenAckOK => {
+                        // the implementaion matches the model if no error occurs
+                        result.is_ok()
+                    }
+                    ActionOutcome::ICS03InvalidConsensusHeight => {
+                        let handler_error_kind =
+                            Self::extract_handler_error_kind::<ICS03ErrorKind>(result);
+                        // the implementaion matches the model if there's an
+                        // error matching the expected outcome
+                        matches!(
+                            handler_error_kind,
+                            ICS03ErrorKind::InvalidConsensusHeight(error_consensus_height, _)
+                            if error_consensus_height == Self::height(client_state)
+                        )
+                    }
+                    ActionOutcome::ICS03StaleConsensusHeight => {
+                        let handler_error_kind =
+                            Self::extract_handler_error_kind::<ICS03ErrorKind>(result);
+                        // the implementaion matches the model if there's an
+                        // error matching the expected outcome
+                        matches!(
+                            handler_error_kind,
+                            ICS03ErrorKind::StaleConsensusHeight(error_consensus_height, _)
+                            if error_consensus_height == Self::height(client_state)
+                        )
+                    }
+                    ActionOutcome::ICS03UninitializedConnection => {
+                        let handler_error_kind =
+                            Self::extract_handler_error_kind::<ICS03ErrorKind>(result);
+                        // the implementaion matches the model if there's an
+                        // error matching the expected outcome
+                        matches!(
+                            handler_error_kind,
+                            ICS03ErrorKind::UninitializedConnection(error_connection_id)
+                            if error_connection_id == connection_id
+                        )
+                    }
+                    ActionOutcome::ICS03ConnectionMismatch => {
+                        let handler_error_kind =
+                            Self::extract_handler_error_kind::<ICS03ErrorKind>(result);
+                        // the implementaion matches the model if there's an
+                        // error matching the expected outcome
+                        matches!(
+                            handler_error_kind,
+                            ICS03ErrorKind::ConnectionMismatch(error_connection_id)
+                            if error_connection_id == connection_id
+                        )
+                    }
+                    ActionOutcome::ICS03InvalidCounterparty => {
+                        let handler_error_kind =
+                            Self::extract_handler_error_kind::<ICS03ErrorKind>(result);
+                        // the implementaion matches the model if there's an
+                        // error matching the expected outcome
+                        handler_error_kind == ICS03ErrorKind::InvalidCounterparty
+                    }
+                    ActionOutcome::ICS03MissingLocalConsensusState => {
+                        let handler_error_kind =
+                            Self::extract_handler_error_kind::<ICS03ErrorKind>(result);
+                        // the implementaion matches the model if there's an
+                        // error matching the expected outcome
+                        handler_error_kind == ICS03ErrorKind::MissingLocalConsensusState
+                    }
+                    action => panic!("unexpected action outcome {:?}", action),
+                }
+            }
+            ActionType::ICS03ConnectionOpenConfirm => {
+                // get action parameters
+                let chain_id = step
+                    .action
+                    .chain_id
+                    .expect("connection open confirm action should have a chain identifier");
+                let connection_id = step
+                    .action
+                    .connection_id
+                    .expect("connection open confirm action should have a connection identifier");
+
+                // get chain's context
+                let ctx = self.chain_context_mut(chain_id);
+
+                // create ICS26 message and deliver it
+                let connection_id = Self::connection_id(connection_id);
+                let msg = ICS26Envelope::ICS3Msg(ConnectionMsg::ConnectionOpenConfirm(
+                    MsgConnectionOpenConfirm {
+                        connection_id: connection_id.clone(),
+                        proofs: Self::connection_proofs(ctx, &connection_id, None),
+                        signer: Self::signer(),
+                    },
+                ));
+                let result = ctx.deliver(msg);
+
+                // check the expected outcome
+                match step.action_outcome {
+                    ActionOutcome::ICS03ConnectionOpenConfirmOK => {
+                        // the implementaion matches the model if no error occurs
+                        result.is_ok()
+                    }
+                    ActionOutcome::ICS03UninitializedConnection => {
+                        let handler_error_kind =
+                            Self::extract_handler_error_kind::<ICS03ErrorKind>(result);
+                        // the implementaion matches the model if there's an
+                        // error matching the expected outcome
+                        matches!(
+                            handler_error_kind,
+                            ICS03ErrorKind::UninitializedConnection(error_connection_id)
+                            if error_connection_id == connection_id
+                        )
+                    }
+                    ActionOutcome::ICS03ConnectionMismatch => {
+                        let handler_error_kind =
+                            Self::extract_handler_error_kind::<ICS03ErrorKind>(result);
+                        // the implementaion matches the model if there's an
+                        // error matching the expected outcome
+                        matches!(
+                            handler_error_kind,
+                            ICS03ErrorKind::ConnectionMismatch(error_connection_id)
+                            if error_connection_id == connection_id
+                        )
+                    }
+                    action => panic!("unexpected action outcome {:?}", action),
+                }
+            }
+            ActionType::ICS04ChannelOpenInit => {
+                // get action parameters
+                let chain_id = step
+                    .action
+                    .chain_id
+                    .expect("channel open init action should have a chain identifier");
+                let connection_id = step
+                    .action
+                    .connection_id
+                    .expect("channel open init action should have a connection identifier");
+                let order = step
+                    .action
+                    .order
+                    .expect("channel open init action should have an order");
+
+                // get chain's context
+                let ctx = self.chain_context_mut(chain_id);
+
+                // create ICS26 message and deliver it
+                let msg = ICS26Envelope::ICS4ChannelMsg(ChannelMsg::ChannelOpenInit(
+                    MsgChannelOpenInit {
+                        port_id: Self::port_id(),
+                        channel: Self::channel_end(
+                            ChannelEndState::Init,
+                            order,
+                            connection_id,
+                            None,
+                        ),
+                        signer: Self::signer(),
+                    },
+                ));
+                let result = ctx.deliver(msg);
+
+                // check the expected outcome
+                match step.action_outcome {
+                    ActionOutcome::ICS04ChannelOpenInitOK => {
+                        // the implementaion matches the model if no error occurs
+                        result.is_ok()
+                    }
+                    ActionOutcome::ICS04MissingConnection => {
+                        let handler_error_kind =
+                            Self::extract_handler_error_kind::<ICS04ErrorKind>(result);
+                        // the implementaion matches the model if there's an
+                        // error matching the expected outcome
+                        matches!(
+                            handler_error_kind,
+                            ICS04ErrorKind::MissingConnection(error_connection_id)
+                            if error_connection_id == Self::connection_id(connection_id)
+                        )
+                    }
+                    action => panic!("unexpected action outcome {:?}", action),
+                }
+            }
+            ActionType::ICS04ChannelOpenTry => {
+                // get action parameters
+                let chain_id = step
+                    .action
+                    .chain_id
+                    .expect("channel open try action should have a chain identifier");
+                let connection_id = step
+                    .action
+                    .connection_id
+                    .expect("channel open try action should have a connection identifier");
+                let order = step
+                    .action
+                    .order
+                    .expect("channel open try action should have an order");
+                let counterparty_channel_id = step.action.counterparty_channel_id.expect(
+                    "channel open try action should have a counterparty channel identifier",
+                );
+
+                // get chain's context
+                let ctx = self.chain_context_mut(chain_id);
+
+                // create ICS26 message and deliver it
+                let msg =
+                    ICS26Envelope::ICS4ChannelMsg(ChannelMsg::ChannelOpenTry(MsgChannelOpenTry {
+                        port_id: Self::port_id(),
+                        previous_channel_id: None,
+                        channel: Self::channel_end(
+                            ChannelEndState::TryOpen,
+                            order,
+                            connection_id,
+                            Some(counterparty_channel_id),
+                        ),
+                        counterparty_version: Self::channel_version(),
+                        proofs: Self::connection_proofs(
+                            ctx,
+                            &Self::connection_id(connection_id),
+                            None,
+                        ),
+                        signer: Self::signer(),
+                    }));
+                let result = ctx.deliver(msg);
+
+                // check the expected outcome
+                match step.action_outcome {
+                    ActionOutcome::ICS04ChannelOpenTryOK => {
+                        // the implementaion matches the model if no error occurs
+                        result.is_ok()
+                    }
+                    ActionOutcome::ICS04MissingConnection => {
+                        let handler_error_kind =
+                            Self::extract_handler_error_kind::<ICS04ErrorKind>(result);
+                        // the implementaion matches the model if there's an
+                        // error matching the expected outcome
+                        matches!(
+                            handler_error_kind,
+                            ICS04ErrorKind::MissingConnection(error_connection_id)
+                            if error_connection_id == Self::connection_id(connection_id)
+                        )
+                    }
+                    ActionOutcome::ICS04ConnectionNotOpen => {
+                        let handler_error_kind =
+                            Self::extract_handler_error_kind::<ICS04ErrorKind>(result);
+                        // the implementaion matches the model if there's an
+                        // error matching the expected outcome
+                        matches!(
+                            handler_error_kind,
+                            ICS04ErrorKind::ConnectionNotOpen(error_connection_id)
+                            if error_connection_id == Self::connection_id(connection_id)
+                        )
+                    }
+                    action => panic!("unexpected action outcome {:?}", action),
+                }
+            }
+            ActionType::ICS04ChannelOpenAck => {
+                // get action parameters
+                let chain_id = step
+                    .action
+                    .chain_id
+                    .expect("channel open ack action should have a chain identifier");
+                let channel_id = step
+                    .action
+                    .channel_id
+                    .expect("channel open ack action should have a channel identifier");
+                let counterparty_channel_id = step.action.counterparty_channel_id.expect(
+                    "channel open ack action should have a counterparty channel identifier",
+                );
+
+                // get chain's context
+                let ctx = self.chain_context_mut(chain_id);
+
+                // create ICS26 message and deliver it
+                let channel_id = Self::channel_id(channel_id);
+                let msg =
+                    ICS26Envelope::ICS4ChannelMsg(ChannelMsg::ChannelOpenAck(MsgChannelOpenAck {
+                        port_id: Self::port_id(),
+                        channel_id: channel_id.clone(),
+                        counterparty_channel_id: Self::channel_id(counterparty_channel_id),
+                        counterparty_version: Self::channel_version(),
+                        proofs: Self::channel_proofs(ctx, &channel_id),
+                        signer: Self::signer(),
+                    }));
+                let result = ctx.deliver(msg);
+
+                // check the expected outcome
+                match step.action_outcome {
+                    ActionOutcome::ICS04ChannelOpenAckOK => {
+                        // the implementaion matches the model if no error occurs
+                        result.is_ok()
+                    }
+                    ActionOutcome::ICS04ChannelNotFound => {
+                        let handler_error_kind =
+                            Self::extract_handler_error_kind::<ICS04ErrorKind>(result);
+                        // the implementaion matches the model if there's an
+                        // error matching the expected outcome
+                        matches!(handler_error_kind, ICS04ErrorKind::ChannelNotFound)
+                    }
+                    ActionOutcome::ICS04InvalidChannelState => {
+                        let handler_error_kind =
+                            Self::extract_handler_error_kind::<ICS04ErrorKind>(result);
+                        // the implementaion matches the model if there's an
+                        // error matching the expected outcome
+                        matches!(
+                            handler_error_kind,
+                            ICS04ErrorKind::InvalidChannelState(error_channel_id)
+                            if error_channel_id == channel_id
+                        )
+                    }
+                    ActionOutcome::ICS04ConnectionNotOpen => {
+                        let handler_error_kind =
+                            Self::extract_handler_error_kind::<ICS04ErrorKind>(result);
+                        // the implementaion matches the model if there's an
+                        // error matching the expected outcome
+                        matches!(handler_error_kind, ICS04ErrorKind::ConnectionNotOpen(_))
+                    }
+                    action => panic!("unexpected action outcome {:?}", action),
+                }
+            }
+            ActionType::ICS04ChannelOpenConfirm => {
+                // get action parameters
+                let chain_id = step
+                    .action
+                    .chain_id
+                    .expect("channel open confirm action should have a chain identifier");
+                let channel_id = step
+                    .action
+                    .channel_id
+                    .expect("channel open confirm action should have a channel identifier");
+
+                // get chain's context
+                let ctx = self.chain_context_mut(chain_id);
+
+                // create ICS26 message and deliver it
+                let channel_id = Self::channel_id(channel_id);
+                let msg = ICS26Envelope::ICS4ChannelMsg(ChannelMsg::ChannelOpenConfirm(
+                    MsgChannelOpenConfirm {
+                        port_id: Self::port_id(),
+                        channel_id: channel_id.clone(),
+                        proofs: Self::channel_proofs(ctx, &channel_id),
+                        signer: Self::signer(),
+                    },
+                ));
+                let result = ctx.deliver(msg);
+
+                // check the expected outcome
+                match step.action_outcome {
+                    ActionOutcome::ICS04ChannelOpenConfirmOK => {
+                        // the implementaion matches the model if no error occurs
+                        result.is_ok()
+                    }
+                    ActionOutcome::ICS04ChannelNotFound => {
+                        let handler_error_kind =
+                            Self::extract_handler_error_kind::<ICS04ErrorKind>(result);
+                        // the implementaion matches the model if there's an
+                        // error matching the expected outcome
+                        matches!(handler_error_kind, ICS04ErrorKind::ChannelNotFound)
+                    }
+                    ActionOutcome::ICS04InvalidChannelState => {
+                        let handler_error_kind =
+                            Self::extract_handler_error_kind::<ICS04ErrorKind>(result);
+                        // the implementaion matches the model if there's an
+                        // error matching the expected outcome
+                        matches!(
+                            handler_error_kind,
+                            ICS04ErrorKind::InvalidChannelState(error_channel_id)
+                            if error_channel_id == channel_id
+                        )
+                    }
+                    ActionOutcome::ICS04ConnectionNotOpen => {
+                        let handler_error_kind =
+                            Self::extract_handler_error_kind::<ICS04ErrorKind>(result);
+                        // the implementaion matches the model if there's an
+                        // error matching the expected outcome
+                        matches!(handler_error_kind, ICS04ErrorKind::ConnectionNotOpen(_))
+                    }
+                    action => panic!("unexpected action outcome {:?}", action),
+                }
+            }
+            ActionType::ICS04ChannelCloseInit => {
+                // get action parameters
+                let chain_id = step
+                    .action
+                    .chain_id
+                    .expect("channel close init action should have a chain identifier");
+                let channel_id = step
+                    .action
+                    .channel_id
+                    .expect("channel close init action should have a channel identifier");
+
+                // get chain's context
+                let ctx = self.chain_context_mut(chain_id);
+
+                // create ICS26 message and deliver it
+                let channel_id = Self::channel_id(channel_id);
+                let msg = ICS26Envelope::ICS4ChannelMsg(ChannelMsg::ChannelCloseInit(
+                    MsgChannelCloseInit {
+                        port_id: Self::port_id(),
+                        channel_id: channel_id.clone(),
+                        signer: Self::signer(),
+                    },
+                ));
+                let result = ctx.deliver(msg);
+
+                // check the expected outcome
+                match step.action_outcome {
+                    ActionOutcome::ICS04ChannelCloseInitOK => {
+                        // the implementaion matches the model if no error occurs
+                        result.is_ok()
+                    }
+                    ActionOutcome::ICS04ChannelNotFound => {
+                        let handler_error_kind =
+                            Self::extract_handler_error_kind::<ICS04ErrorKind>(result);
+                        // the implementaion matches the model if there's an
+                        // error matching the expected outcome
+                        matches!(handler_error_kind, ICS04ErrorKind::ChannelNotFound)
+                    }
+                    ActionOutcome::ICS04InvalidChannelState => {
+                        let handler_error_kind =
+                            Self::extract_handler_error_kind::<ICS04ErrorKind>(result);
+                        // the implementaion matches the model if there's an
+                        // error matching the expected outcome
+                        matches!(
+                            handler_error_kind,
+                            ICS04ErrorKind::InvalidChannelState(error_channel_id)
+                            if error_channel_id == channel_id
+                        )
+                    }
+                    ActionOutcome::ICS04ConnectionNotOpen => {
+                        let handler_error_kind =
+                            Self::extract_handler_error_kind::<ICS04ErrorKind>(result);
+                        // the implementaion matches the model if there's an
+                        // error matching the expected outcome
+                        matches!(handler_error_kind, ICS04ErrorKind::ConnectionNotOpen(_))
+                    }
+                    action => panic!("unexpected action outcome {:?}", action),
+                }
+            }
+            ActionType::ICS04ChannelCloseConfirm => {
+                // get action parameters
+                let chain_id = step
+                    .action
+                    .chain_id
+                    .expect("channel close confirm action should have a chain identifier");
+                let channel_id = step
+                    .action
+                    .channel_id
+                    .expect("channel close confirm action should have a channel identifier");
+
+                // get chain's context
+                let ctx = self.chain_context_mut(chain_id);
+
+                // create ICS26 message and deliver it
+                let channel_id = Self::channel_id(channel_id);
+                let msg = ICS26Envelope::ICS4ChannelMsg(ChannelMsg::ChannelCloseConfirm(
+                    MsgChannelCloseConfirm {
+                        port_id: Self::port_id(),
+                        channel_id: channel_id.clone(),
+                        proofs: Self::channel_proofs(ctx, &channel_id),
+                        signer: Self::signer(),
+                    },
+                ));
+                let result = ctx.deliver(msg);
+
+                // check the expected outcome
+                match step.action_outcome {
+                    ActionOutcome::ICS04ChannelCloseConfirmOK => {
+                        // the implementaion matches the model if no error occurs
+                        result.is_ok()
+                    }
+                    ActionOutcome::ICS04ChannelNotFound => {
+                        let handler_error_kind =
+                            Self::extract_handler_error_kind::<ICS04ErrorKind>(result);
+                        // the implementaion matches the model if there's an
+                        // error matching the expected outcome
+                        matches!(handler_error_kind, ICS04ErrorKind::ChannelNotFound)
+                    }
+                    ActionOutcome::ICS04ChannelAlreadyClosed => {
+                        let handler_error_kind =
+                            Self::extract_handler_error_kind::<ICS04ErrorKind>(result);
+                        // the implementaion matches the model if there's an
+                        // error matching the expected outcome
+                        matches!(
+                            handler_error_kind,
+                            ICS04ErrorKind::ChannelAlreadyClosed(error_channel_id)
+                            if error_channel_id == channel_id
+                        )
+                    }
+                    ActionOutcome::ICS04ConnectionNotOpen => {
+                        let handler_error_kind =
+                            Self::extract_handler_error_kind::<ICS04ErrorKind>(result);
+                        // the implementaion matches the model if there's an
+                        // error matching the expected outcome
+                        matches!(handler_error_kind, ICS04ErrorKind::ConnectionNotOpen(_))
+                    }
+                    ActionOutcome::ICS04InvalidCounterpartyChannel => {
+                        let handler_error_kind =
+                            Self::extract_handler_error_kind::<ICS04ErrorKind>(result);
+                        // the implementaion matches the model if there's an
+                        // error matching the expected outcome: the channel end
+                        // of the counterparty cannot be verified
+                        matches!(
+                            handler_error_kind,
+                            ICS04ErrorKind::FailedChanneOpenTryVerification
+                        )
+                    }
+                    action => panic!("unexpected action outcome {:?}", action),
+                }
+            }
+            ActionType::ICS04SendPacket => {
+                // get action parameters
+                let chain_id = step
+                    .action
+                    .chain_id
+                    .expect("send packet action should have a chain identifier");
+                let channel_id = step
+                    .action
+                    .channel_id
+                    .expect("send packet action should have a channel identifier");
+                let timeout_height = step
+                    .action
+                    .timeout_height
+                    .expect("send packet action should have a timeout height");
+
+                // get chain's context
+                let ctx = self.chain_context_mut(chain_id);
+
+                // create the packet and send it: the packet is the next one on
+                // the channel, to the counterparty of the channel (if any)
+                let port_channel_id = (Self::port_id(), Self::channel_id(channel_id));
+                let destination_channel = ChannelReader::channel_end(ctx, &port_channel_id)
+                    .and_then(|channel_end| channel_end.counterparty().channel_id().cloned())
+                    .unwrap_or_default();
+                let packet = Packet {
+                    sequence: ctx
+                        .get_next_sequence_send(&port_channel_id)
+                        .unwrap_or_else(|| 1.into()),
+                    source_port: Self::port_id(),
+                    source_channel: port_channel_id.1.clone(),
+                    destination_port: Self::port_id(),
+                    destination_channel,
+                    data: Self::packet_data(),
+                    timeout_height: Self::height(timeout_height),
+                    timeout_timestamp: Timestamp::none(),
+                };
+                let channel_cap = ctx
+                    .channel_capability(&port_channel_id.0, &port_channel_id.1)
+                    .or_else(|_| ctx.port_capability(&port_channel_id.0))
+                    .expect("the port should have a capability");
+                // sending a packet is not a message: apply its result to
+                // the chain's context and create a new block
+                let result: Result<(), ICS04Error> =
+                    send_packet(ctx, &channel_cap, packet).map(|output| {
+                        ctx.store_packet_result(output.result)
+                            .expect("it should be possible to store the packet");
+                        ctx.advance_host_chain_height();
+                    });
+
+                // check the expected outcome
+                match step.action_outcome {
+                    ActionOutcome::ICS04PacketSendOK => {
+                        // the implementaion matches the model if no error occurs
+                        result.is_ok()
+                    }
+                    ActionOutcome::ICS04ChannelNotFound => {
+                        let error_kind = Self::extract_error_kind(result);
+                        // the implementaion matches the model if there's an
+                        // error matching the expected outcome
+                        matches!(error_kind, ICS04ErrorKind::ChannelNotFound)
+                    }
+                    ActionOutcome::ICS04ChannelAlreadyClosed => {
+                        let error_kind = Self::extract_error_kind(result);
+                        // the implementaion matches the model if there's an
+                        // error matching the expected outcome
+                        matches!(
+                            error_kind,
+                            ICS04ErrorKind::ChannelAlreadyClosed(error_channel_id)
+                            if error_channel_id == port_channel_id.1
+                        )
+                    }
+                    ActionOutcome::ICS04InvalidPacketCounterparty => {
+                        let error_kind = Self::extract_error_kind(result);
+                        // the implementaion matches the model if there's an
+                        // error matching the expected outcome
+                        matches!(error_kind, ICS04ErrorKind::InvalidPacketCounterparty(..))
+                    }
+                    ActionOutcome::ICS04LowPacketHeight => {
+                        let error_kind = Self::extract_error_kind(result);
+                        // the implementaion matches the model if there's an
+                        // error matching the expected outcome
+                        matches!(
+                            error_kind,
+                            ICS04ErrorKind::LowPacketHeight(_, error_timeout_height)
+                            if error_timeout_height == Self::height(timeout_height)
+                        )
+                    }
+                    action => panic!("unexpected action outcome {:?}", action),
+                }
+            }
+            ActionType::ICS04RecvPacket => {
+                // get action parameters
+                let chain_id = step
+                    .action
+                    .chain_id
+                    .expect("recv packet action should have a chain identifier");
+                let packet = step
+                    .action
+                    .packet
+                    .expect("recv packet action should have a packet");
+
+                // get chain's context
+                let ctx = self.chain_context_mut(chain_id.clone());
+
+                // create ICS26 message and deliver it
+                let proofs = Self::channel_proofs(ctx, &Self::channel_id(packet.dest_channel_id));
+                let msg = ICS26Envelope::ICS4PacketMsg(PacketMsg::RecvPacket(
+                    MsgRecvPacket::new(Self::packet(&packet), proofs, Self::signer())
+                        .expect("it should be possible to create the message"),
+                ));
+                let result = ctx.deliver(msg);
+
+                // check the expected outcome
+                match step.action_outcome {
+                    ActionOutcome::ICS04PacketRecvOK => {
+                        // the implementaion matches the model if no error occurs
+                        let outcome_matches = result.is_ok();
+                        if outcome_matches {
+                            self.received_packets.insert((
+                                chain_id,
+                                packet.dest_channel_id,
+                                packet.sequence,
+                            ));
+                        }
+                        outcome_matches
+                    }
+                    ActionOutcome::ICS04ChannelNotFound => {
+                        let handler_error_kind =
+                            Self::extract_handler_error_kind::<ICS04ErrorKind>(result);
+                        // the implementaion matches the model if there's an
+                        // error matching the expected outcome
+                        matches!(handler_error_kind, ICS04ErrorKind::ChannelNotFound)
+                    }
+                    ActionOutcome::ICS04InvalidChannelState => {
+                        let handler_error_kind =
+                            Self::extract_handler_error_kind::<ICS04ErrorKind>(result);
+                        // the implementaion matches the model if there's an
+                        // error matching the expected outcome
+                        matches!(
+                            handler_error_kind,
+                            ICS04ErrorKind::InvalidChannelState(error_channel_id)
+                            if error_channel_id == Self::channel_id(packet.dest_channel_id)
+                        )
+                    }
+                    ActionOutcome::ICS04InvalidPacketCounterparty => {
+                        let handler_error_kind =
+                            Self::extract_handler_error_kind::<ICS04ErrorKind>(result);
+                        // the implementaion matches the model if there's an
+                        // error matching the expected outcome
+                        matches!(
+                            handler_error_kind,
+                            ICS04ErrorKind::InvalidPacketCounterparty(..)
+                        )
+                    }
+                    ActionOutcome::ICS04ConnectionNotOpen => {
+                        let handler_error_kind =
+                            Self::extract_handler_error_kind::<ICS04ErrorKind>(result);
+                        // the implementaion matches the model if there's an
+                        // error matching the expected outcome
+                        matches!(handler_error_kind, ICS04ErrorKind::ConnectionNotOpen(_))
+                    }
+                    ActionOutcome::ICS04LowPacketHeight => {
+                        let handler_error_kind =
+                            Self::extract_handler_error_kind::<ICS04ErrorKind>(result);
+                        // the implementaion matches the model if there's an
+                        // error matching the expected outcome
+                        matches!(
+                            handler_error_kind,
+                            ICS04ErrorKind::LowPacketHeight(_, error_timeout_height)
+                            if error_timeout_height == Self::height(packet.timeout_height)
+                        )
+                    }
+                    ActionOutcome::ICS04InvalidPacketSequence => {
+                        let handler_error_kind =
+                            Self::extract_handler_error_kind::<ICS04ErrorKind>(result);
+                        // the implementaion matches the model if there's an
+                        // error matching the expected outcome
+                        matches!(
+                            handler_error_kind,
+                            ICS04ErrorKind::InvalidPacketSequence(error_sequence, _)
+                            if error_sequence == packet.sequence.into()
+                        )
+                    }
+                    ActionOutcome::ICS04PacketAlreadyReceived => {
+                        let handler_error_kind =
+                            Self::extract_handler_error_kind::<ICS04ErrorKind>(result);
+                        // the implementaion matches the model if there's an
+                        // error matching the expected outcome
+                        matches!(
+                            handler_error_kind,
+                            ICS04ErrorKind::PacketAlreadyReceived(error_sequence)
+                            if error_sequence == packet.sequence.into()
+                        )
+                    }
+                    action => panic!("unexpected action outcome {:?}", action),
+                }
+            }
+            ActionType::ICS04AcknowledgePacket => {
+                // get action parameters
+                let chain_id = step
+                    .action
+                    .chain_id
+                    .expect("acknowledge packet action should have a chain identifier");
+                let packet = step
+                    .action
+                    .packet
+                    .expect("acknowledge packet action should have a packet");
+
+                // get chain's context
+                let ctx = self.chain_context_mut(chain_id.clone());
+
+                // create ICS26 message and deliver it
+                let proofs = Self::channel_proofs(ctx, &Self::channel_id(packet.source_channel_id));
+                let msg = ICS26Envelope::ICS4PacketMsg(PacketMsg::AckPacket(
+                    MsgAcknowledgement::new(
+                        Self::packet(&packet),
+                        Self::acknowledgement(),
+                        proofs,
+                        Self::signer(),
+                    )
+                    .expect("it should be possible to create the message"),
+                ));
+                let result = ctx.deliver(msg);
+
+                // check the expected outcome
+                match step.action_outcome {
+                    ActionOutcome::ICS04PacketAckOK => {
+                        // the implementaion matches the model if no error occurs
+                        let outcome_matches = result.is_ok();
+                        if outcome_matches {
+                            self.acknowledged_packets.insert((
+                                chain_id,
+                                packet.source_channel_id,
+                                packet.sequence,
+                            ));
+                        }
+                        outcome_matches
+                    }
+                    ActionOutcome::ICS04ChannelNotFound => {
+                        let handler_error_kind =
+                            Self::extract_handler_error_kind::<ICS04ErrorKind>(result);
+                        // the implementaion matches the model if there's an
+                        // error matching the expected outcome
+                        matches!(handler_error_kind, ICS04ErrorKind::ChannelNotFound)
+                    }
+                    ActionOutcome::ICS04InvalidChannelState => {
+                        let handler_error_kind =
+                            Self::extract_handler_error_kind::<ICS04ErrorKind>(result);
+                        // the implementaion matches the model if there's an
+                        // error matching the expected outcome
+                        matches!(
+                            handler_error_kind,
+                            ICS04ErrorKind::InvalidChannelState(error_channel_id)
+                            if error_channel_id == Self::channel_id(packet.source_channel_id)
+                        )
+                    }
+                    ActionOutcome::ICS04InvalidPacketCounterparty => {
+                        let handler_error_kind =
+                            Self::extract_handler_error_kind::<ICS04ErrorKind>(result);
+                        // the implementaion matches the model if there's an
+                        // error matching the expected outcome
+                        matches!(
+                            handler_error_kind,
+                            ICS04ErrorKind::InvalidPacketCounterparty(..)
+                        )
+                    }
+                    ActionOutcome::ICS04ConnectionNotOpen => {
+                        let handler_error_kind =
+                            Self::extract_handler_error_kind::<ICS04ErrorKind>(result);
+                        // the implementaion matches the model if there's an
+                        // error matching the expected outcome
+                        matches!(handler_error_kind, ICS04ErrorKind::ConnectionNotOpen(_))
+                    }
+                    ActionOutcome::ICS04PacketCommitmentNotFound => {
+                        let handler_error_kind =
+                            Self::extract_handler_error_kind::<ICS04ErrorKind>(result);
+                        // the implementaion matches the model if there's an
+                        // error matching the expected outcome
+                        matches!(
+                            handler_error_kind,
+                            ICS04ErrorKind::PacketCommitmentNotFound(error_sequence)
+                            if error_sequence == packet.sequence.into()
+                        )
+                    }
+                    ActionOutcome::ICS04InvalidPacketSequence => {
+                        let handler_error_kind =
+                            Self::extract_handler_error_kind::<ICS04ErrorKind>(result);
+                        // the implementaion matches the model if there's an
+                        // error matching the expected outcome
+                        matches!(
+                            handler_error_kind,
+                            ICS04ErrorKind::InvalidPacketSequence(error_sequence, _)
+                            if error_sequence == packet.sequence.into()
+                        )
+                    }
+                    action => panic!("unexpected action outcome {:?}", action),
+                }
+            }
+            ActionType::ICS04TimeoutPacket => {
+                // get action parameters
+                let chain_id = step
+                    .action
+                    .chain_id
+                    .expect("timeout packet action should have a chain identifier");
+                let packet = step
+                    .action
+                    .packet
+                    .expect("timeout packet action should have a packet");
+                let next_sequence_recv = step
+                    .action
+                    .next_sequence_recv
+                    .expect("timeout packet action should have a next sequence to receive");
+
+                // get chain's context
+                let ctx = self.chain_context_mut(chain_id.clone());
+
+                // create ICS26 message and deliver it
+                let proofs = Self::channel_proofs(ctx, &Self::channel_id(packet.source_channel_id));
+                let msg = ICS26Envelope::ICS4PacketMsg(PacketMsg::ToPacket(
+                    MsgTimeout::new(
+                        Self::packet(&packet),
+                        next_sequence_recv.into(),
+                        proofs,
+                        Self::signer(),
+                    )
+                    .expect("it should be possible to create the message"),
+                ));
+                let result = ctx.deliver(msg);
+
+                // check the expected outcome
+                match step.action_outcome {
+                    ActionOutcome::ICS04PacketTimeoutOK => {
+                        // the implementaion matches the model if no error occurs
+                        let outcome_matches = result.is_ok();
+                        if outcome_matches {
+                            self.timed_out_packets.insert((
+                                chain_id,
+                                packet.source_channel_id,
+                                packet.sequence,
+                            ));
+                        }
+                        outcome_matches
+                    }
+                    ActionOutcome::ICS04ChannelNotFound => {
+                        let handler_error_kind =
+                            Self::extract_handler_error_kind::<ICS04ErrorKind>(result);
+                        // the implementaion matches the model if there's an
+                        // error matching the expected outcome
+                        matches!(handler_error_kind, ICS04ErrorKind::ChannelNotFound)
+                    }
+                    ActionOutcome::ICS04InvalidChannelState => {
+                        let handler_error_kind =
+                            Self::extract_handler_error_kind::<ICS04ErrorKind>(result);
+                        // the implementaion matches the model if there's an
+                        // error matching the expected outcome
+                        matches!(
+                            handler_error_kind,
+                            ICS04ErrorKind::InvalidChannelState(error_channel_id)
+                            if error_channel_id == Self::channel_id(packet.source_channel_id)
+                        )
+                    }
+                    ActionOutcome::ICS04InvalidPacketCounterparty => {
+                        let handler_error_kind =
+                            Self::extract_handler_error_kind::<ICS04ErrorKind>(result);
+                        // the implementaion matches the model if there's an
+                        // error matching the expected outcome
+                        matches!(
+                            handler_error_kind,
+                            ICS04ErrorKind::InvalidPacketCounterparty(..)
+                        )
+                    }
+                    ActionOutcome::ICS04PacketTimeoutNotReached => {
+                        let handler_error_kind =
+                            Self::extract_handler_error_kind::<ICS04ErrorKind>(result);
+                        // the implementaion matches the model if there's an
+                        // error matching the expected outcome
+                        matches!(
+                            handler_error_kind,
+                            ICS04ErrorKind::PacketTimeoutNotReached(error_timeout_height, ..)
+                            if error_timeout_height == Self::height(packet.timeout_height)
+                        )
+                    }
+                    ActionOutcome::ICS04PacketCommitmentNotFound => {
+                        let handler_error_kind =
+                            Self::extract_handler_error_kind::<ICS04ErrorKind>(result);
+                        // the implementaion matches the model if there's an
+                        // error matching the expected outcome
+                        matches!(
+                            handler_error_kind,
+                            ICS04ErrorKind::PacketCommitmentNotFound(error_sequence)
+                            if error_sequence == packet.sequence.into()
+                        )
+                    }
+                    ActionOutcome::ICS04InvalidPacketSequence => {
+                        let handler_error_kind =
+                            Self::extract_handler_error_kind::<ICS04ErrorKind>(result);
+                        // the implementaion matches the model if there's an
+                        // error matching the expected outcome
+                        matches!(
+                            handler_error_kind,
+                            ICS04ErrorKind::InvalidPacketSequence(error_sequence, _)
+                            if error_sequence == packet.sequence.into()
+                        )
+                    }
+                    action => panic!("unexpected action outcome {:?}", action),
+                }
+            }
         };
-        // also check that chain heights match
-        outcome_matches && self.check_chain_heights(step.chains)
+        // also check that chain heights, connections, channels and packets
+        // match, and that the invariants on packets hold
+        outcome_matches
+            && self.check_chain_heights(&step.chains)
+            && self.check_chain_connections(&step.chains)
+            && self.check_chain_channels(&step.chains)
+            && self.check_chain_packets(&step.chains)
+            && self.check_no_packet_acknowledged_and_timed_out()
+            && self.check_ordered_sequences_never_skip(&step.chains)
     }
 }
@@ -1,5 +1,5 @@
-use serde::Deserialize;
-use std::collections::HashMap;
+use serde::{Deserialize, Deserializer};
+use std::collections::{HashMap, HashSet};
 use std::fmt::Debug;
 
 #[derive(Debug, Clone, Deserialize)]
@@ -25,6 +25,49 @@ pub struct Action {
 
     #[serde(alias = "clientHeight")]
     pub client_height: Option<u64>,
+
+    #[serde(alias = "clientState")]
+    pub client_state: Option<u64>,
+
+    #[serde(alias = "counterpartyClientId")]
+    pub counterparty_client_id: Option<u64>,
+
+    #[serde(alias = "connectionId")]
+    pub connection_id: Option<u64>,
+
+    #[serde(
+        alias = "previousConnectionId",
+        default,
+        deserialize_with = "deserialize_id"
+    )]
+    pub previous_connection_id: Option<u64>,
+
+    #[serde(
+        alias = "counterpartyConnectionId",
+        default,
+        deserialize_with = "deserialize_id"
+    )]
+    pub counterparty_connection_id: Option<u64>,
+
+    #[serde(alias = "channelId")]
+    pub channel_id: Option<u64>,
+
+    #[serde(
+        alias = "counterpartyChannelId",
+        default,
+        deserialize_with = "deserialize_id"
+    )]
+    pub counterparty_channel_id: Option<u64>,
+
+    pub order: Option<ChannelOrder>,
+
+    #[serde(alias = "timeoutHeight")]
+    pub timeout_height: Option<u64>,
+
+    pub packet: Option<Packet>,
+
+    #[serde(alias = "nextSequenceRecv")]
+    pub next_sequence_recv: Option<u64>,
 }
 
 #[derive(Debug, Clone, PartialEq, Deserialize)]
@@ -32,6 +75,20 @@ pub enum ActionType {
     None,
     ICS02CreateClient,
     ICS02UpdateClient,
+    ICS03ConnectionOpenInit,
+    ICS03ConnectionOpenTry,
+    ICS03ConnectionOpenAck,
+    ICS03ConnectionOpenConfirm,
+    ICS04ChannelOpenInit,
+    ICS04ChannelOpenTry,
+    ICS04ChannelOpenAck,
+    ICS04ChannelOpenConfirm,
+    ICS04ChannelCloseInit,
+    ICS04ChannelCloseConfirm,
+    ICS04SendPacket,
+    ICS04RecvPacket,
+    ICS04AcknowledgePacket,
+    ICS04TimeoutPacket,
 }
 
 #[derive(Debug, Clone, PartialEq, Deserialize)]
@@ -41,9 +98,148 @@ pub enum ActionOutcome {
     ICS02UpdateOK,
     ICS02ClientNotFound,
     ICS02HeaderVerificationFailure,
+    ICS03ConnectionOpenInitOK,
+    ICS03MissingClient,
+    ICS03ConnectionOpenTryOK,
+    ICS03InvalidConsensusHeight,
+    ICS03StaleConsensusHeight,
+    ICS03ConnectionNotFound,
+    ICS03ConnectionMismatch,
+    ICS03MissingLocalConsensusState,
+    ICS03ConnectionOpenAckOK,
+    ICS03UninitializedConnection,
+    ICS03InvalidCounterparty,
+    ICS03ConnectionOpenConfirmOK,
+    ICS04ChannelOpenInitOK,
+    ICS04MissingConnection,
+    ICS04ChannelOpenTryOK,
+    ICS04ConnectionNotOpen,
+    ICS04ChannelOpenAckOK,
+    ICS04ChannelNotFound,
+    ICS04InvalidChannelState,
+    ICS04ChannelOpenConfirmOK,
+    ICS04ChannelCloseInitOK,
+    ICS04ChannelCloseConfirmOK,
+    ICS04ChannelAlreadyClosed,
+    ICS04InvalidCounterpartyChannel,
+    ICS04PacketSendOK,
+    ICS04InvalidPacketCounterparty,
+    ICS04LowPacketHeight,
+    ICS04PacketRecvOK,
+    ICS04InvalidPacketSequence,
+    ICS04PacketAlreadyReceived,
+    ICS04PacketAckOK,
+    ICS04PacketCommitmentNotFound,
+    ICS04PacketTimeoutOK,
+    ICS04PacketTimeoutNotReached,
 }
 
 #[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct Chain {
     pub height: u64,
+
+    #[serde(default)]
+    pub connections: HashMap<u64, Connection>,
+
+    #[serde(default)]
+    pub channels: HashMap<u64, Channel>,
+
+    #[serde(alias = "packetCommitments", default)]
+    pub packet_commitments: HashSet<Packet>,
+
+    #[serde(alias = "packetReceipts", default)]
+    pub packet_receipts: HashSet<Packet>,
+
+    #[serde(alias = "acknowledgedPackets", default)]
+    pub acknowledged_packets: HashSet<Packet>,
+
+    #[serde(alias = "timedOutPackets", default)]
+    pub timed_out_packets: HashSet<Packet>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Connection {
+    pub state: ConnectionState,
+
+    #[serde(alias = "clientId", deserialize_with = "deserialize_id")]
+    pub client_id: Option<u64>,
+
+    #[serde(alias = "counterpartyClientId", deserialize_with = "deserialize_id")]
+    pub counterparty_client_id: Option<u64>,
+
+    #[serde(
+        alias = "counterpartyConnectionId",
+        deserialize_with = "deserialize_id"
+    )]
+    pub counterparty_connection_id: Option<u64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+pub enum ConnectionState {
+    Uninit,
+    Init,
+    TryOpen,
+    Open,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Channel {
+    pub state: ChannelState,
+
+    pub order: ChannelOrder,
+
+    #[serde(alias = "connectionId", deserialize_with = "deserialize_id")]
+    pub connection_id: Option<u64>,
+
+    #[serde(alias = "counterpartyChannelId", deserialize_with = "deserialize_id")]
+    pub counterparty_channel_id: Option<u64>,
+
+    #[serde(alias = "nextSendSeq")]
+    pub next_send_seq: u64,
+
+    #[serde(alias = "nextRecvSeq")]
+    pub next_recv_seq: u64,
+
+    #[serde(alias = "nextAckSeq")]
+    pub next_ack_seq: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+pub enum ChannelState {
+    Uninit,
+    Init,
+    TryOpen,
+    Open,
+    Closed,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+pub enum ChannelOrder {
+    None,
+    Ordered,
+    Unordered,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+pub struct Packet {
+    #[serde(alias = "sourceChannelId")]
+    pub source_channel_id: u64,
+
+    #[serde(alias = "destChannelId")]
+    pub dest_channel_id: u64,
+
+    pub sequence: u64,
+
+    /// The timeout height is 0 (`TimeoutHeightNone`) if the packet has no timeout height.
+    #[serde(alias = "timeoutHeight")]
+    pub timeout_height: u64,
+}
+
+/// Identifiers which are not set are -1 in the model (e.g. `ConnectionIdNone`).
+fn deserialize_id<'de, D>(deserializer: D) -> Result<Option<u64>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let id = Option::<i64>::deserialize(deserializer)?;
+    Ok(id.filter(|id| *id >= 0).map(|id| id as u64))
 }
@@ -4,7 +4,46 @@ const TESTS_DIR: &str = "tests/support/model_based/tests";
 
 #[test]
 fn mbt() {
-    let tests = vec!["ICS02UpdateOKTest", "ICS02HeaderVerificationFailureTest"];
+    let tests = vec![
+        "ICS02UpdateOKTest",
+        "ICS02HeaderVerificationFailureTest",
+        "ICS03ConnectionOpenInitOKTest",
+        "ICS03MissingClientTest",
+        "ICS03ConnectionOpenTryOKTest",
+        "ICS03InvalidConsensusHeightTest",
+        "ICS03StaleConsensusHeightTest",
+        "ICS03ConnectionNotFoundTest",
+        "ICS03ConnectionMismatchTest",
+        "ICS03MissingLocalConsensusStateTest",
+        "ICS03ConnectionOpenAckOKTest",
+        "ICS03UninitializedConnectionTest",
+        "ICS03InvalidCounterpartyTest",
+        "ICS03ConnectionOpenConfirmOKTest",
+        "ICS04ChannelOpenInitOKTest",
+        "ICS04MissingConnectionTest",
+        "ICS04ChannelOpenTryOKTest",
+        "ICS04ConnectionNotOpenTest",
+        "ICS04ChannelOpenAckOKTest",
+        "ICS04ChannelNotFoundTest",
+        "ICS04InvalidChannelStateTest",
+        "ICS04ChannelOpenConfirmOKTest",
+        "ICS04ChannelCloseInitOKTest",
+        "ICS04ChannelCloseConfirmOKTest",
+        "ICS04ChannelAlreadyClosedTest",
+        "ICS04InvalidCounterpartyChannelTest",
+        "ICS04PacketSendOKTest",
+        "ICS04InvalidPacketCounterpartyTest",
+        "ICS04LowPacketHeightTest",
+        "ICS04PacketRecvOKTest",
+        "ICS04InvalidPacketSequenceTest",
+        "ICS04PacketAlreadyReceivedTest",
+        "ICS04PacketAckOKTest",
+        "ICS04UnorderedPacketAckOKTest",
+        "ICS04PacketCommitmentNotFoundTest",
+        "ICS04PacketTimeoutOKTest",
+        "ICS04UnorderedPacketTimeoutOKTest",
+        "ICS04PacketTimeoutNotReachedTest",
+    ];
 
     for test in tests {
         let test = format!("{}/{}.json", TESTS_DIR, test);
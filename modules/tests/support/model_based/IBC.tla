@@ -1,6 +1,6 @@
 --------------------------------- MODULE IBC ----------------------------------
 
-EXTENDS Integers, FiniteSets, ICS02
+EXTENDS Integers, FiniteSets, ICS02, ICS03, ICS04
 
 \* ids of existing chains
 CONSTANT ChainIds
@@ -10,6 +10,15 @@ ASSUME MaxClientsPerChain >= 0
 \* max height which clients can reach
 CONSTANT MaxClientHeight
 ASSUME MaxClientHeight >= 0
+\* max number of connections to be created per chain
+CONSTANT MaxConnectionsPerChain
+ASSUME MaxConnectionsPerChain >= 0
+\* max number of channels to be created per chain
+CONSTANT MaxChannelsPerChain
+ASSUME MaxChannelsPerChain >= 0
+\* max number of packets to be sent per channel
+CONSTANT MaxPacketSequence
+ASSUME MaxPacketSequence >= 0
 
 \* mapping from chain id to its data
 VARIABLE chains
@@ -23,11 +32,29 @@ vars == <<chains, action, actionOutcome>>
 \* operator for type annotations
 a <: b == a
 
+PacketType == [
+    sourceChannelId |-> Int,
+    destChannelId |-> Int,
+    sequence |-> Int,
+    timeoutHeight |-> Int
+]
+AsPacketSet(ps) == ps <: {PacketType}
 ActionType == [
     type |-> STRING,
     chainId |-> STRING,
     clientHeight |-> Int,
-    clientId |-> Int
+    clientId |-> Int,
+    clientState |-> Int,
+    counterpartyClientId |-> Int,
+    connectionId |-> Int,
+    previousConnectionId |-> Int,
+    counterpartyConnectionId |-> Int,
+    channelId |-> Int,
+    counterpartyChannelId |-> Int,
+    order |-> STRING,
+    timeoutHeight |-> Int,
+    packet |-> PacketType,
+    nextSequenceRecv |-> Int
 ]
 AsAction(a) == a <: ActionType
 (******************* END OF TYPE ANNOTATIONS FOR APALACHE ********************)
@@ -39,6 +66,36 @@ ClientIds == 0..(MaxClientsPerChain - 1)
 \* set of possible client heights
 ClientHeights == 1..MaxClientHeight
 
+\* set of possible connection identifiers
+ConnectionIds == 0..(MaxConnectionsPerChain - 1)
+\* set of possible connection states
+ConnectionStates == {
+    "Uninit",
+    "Init",
+    "TryOpen",
+    "Open"
+}
+
+\* set of possible channel identifiers
+ChannelIds == 0..(MaxChannelsPerChain - 1)
+\* set of possible channel states
+ChannelStates == {
+    "Uninit",
+    "Init",
+    "TryOpen",
+    "Open",
+    "Closed"
+}
+\* set of possible channel orders
+ChannelOrders == {
+    "Ordered",
+    "Unordered"
+}
+\* set of possible packet sequences
+PacketSequences == 1..MaxPacketSequence
+\* set of possible packet timeout heights (of the receiving chain)
+TimeoutHeights == {TimeoutHeightNone} \union ClientHeights
+
 \* data kept per cliennt
 Client == [
     height: ClientHeights \union {HeightNone}
@@ -47,11 +104,56 @@ Client == [
 Clients == [
     ClientIds -> Client
 ]
+\* data kept per connection
+Connection == [
+    state: ConnectionStates,
+    clientId: ClientIds \union {ClientIdNone},
+    counterpartyClientId: ClientIds \union {ClientIdNone},
+    counterpartyConnectionId: ConnectionIds \union {ConnectionIdNone}
+]
+\* mapping from connection identifier to its data
+Connections == [
+    ConnectionIds -> Connection
+]
+\* data kept per channel
+Channel == [
+    state: ChannelStates,
+    order: ChannelOrders \union {ChannelOrderNone},
+    connectionId: ConnectionIds \union {ConnectionIdNone},
+    counterpartyChannelId: ChannelIds \union {ChannelIdNone},
+    nextSendSeq: 0..(MaxPacketSequence + 1),
+    nextRecvSeq: 0..(MaxPacketSequence + 1),
+    nextAckSeq: 0..(MaxPacketSequence + 1)
+]
+\* mapping from channel identifier to its data
+Channels == [
+    ChannelIds -> Channel
+]
+\* data kept per packet, which is sent from the source channel to the
+\* destination channel (on the counterparty chain)
+Packet == [
+    sourceChannelId: ChannelIds,
+    destChannelId: ChannelIds,
+    sequence: PacketSequences,
+    timeoutHeight: TimeoutHeights
+]
 \* data kept per chain
 Chain == [
     height: ChainHeights,
     clients: Clients,
-    clientIdCounter: 0..MaxClientsPerChain
+    clientIdCounter: 0..MaxClientsPerChain,
+    connections: Connections,
+    connectionIdCounter: 0..MaxConnectionsPerChain,
+    channels: Channels,
+    channelIdCounter: 0..MaxChannelsPerChain,
+    \* packets sent and neither acknowledged nor timed out yet
+    packetCommitments: SUBSET Packet,
+    \* packets received
+    packetReceipts: SUBSET Packet,
+    \* packets sent and acknowledged (history)
+    acknowledgedPackets: SUBSET Packet,
+    \* packets sent and timed out (history)
+    timedOutPackets: SUBSET Packet
 ]
 \* mapping from chain identifier to its data
 Chains == [
@@ -73,10 +175,107 @@ UpdateClientActions == [
     clientHeight: ClientHeights,
     clientId: ClientIds
 ] <: {ActionType}
+ConnectionOpenInitActions == [
+    type: {"ICS03ConnectionOpenInit"},
+    chainId: ChainIds,
+    clientId: ClientIds,
+    counterpartyClientId: ClientIds
+] <: {ActionType}
+ConnectionOpenTryActions == [
+    type: {"ICS03ConnectionOpenTry"},
+    chainId: ChainIds,
+    previousConnectionId: ConnectionIds \union {ConnectionIdNone},
+    clientId: ClientIds,
+    clientState: ClientHeights,
+    counterpartyClientId: ClientIds,
+    counterpartyConnectionId: ConnectionIds
+] <: {ActionType}
+ConnectionOpenAckActions == [
+    type: {"ICS03ConnectionOpenAck"},
+    chainId: ChainIds,
+    connectionId: ConnectionIds,
+    clientState: ClientHeights,
+    counterpartyConnectionId: ConnectionIds \union {ConnectionIdNone}
+] <: {ActionType}
+ConnectionOpenConfirmActions == [
+    type: {"ICS03ConnectionOpenConfirm"},
+    chainId: ChainIds,
+    connectionId: ConnectionIds
+] <: {ActionType}
+ChannelOpenInitActions == [
+    type: {"ICS04ChannelOpenInit"},
+    chainId: ChainIds,
+    connectionId: ConnectionIds,
+    order: ChannelOrders
+] <: {ActionType}
+ChannelOpenTryActions == [
+    type: {"ICS04ChannelOpenTry"},
+    chainId: ChainIds,
+    connectionId: ConnectionIds,
+    order: ChannelOrders,
+    counterpartyChannelId: ChannelIds
+] <: {ActionType}
+ChannelOpenAckActions == [
+    type: {"ICS04ChannelOpenAck"},
+    chainId: ChainIds,
+    channelId: ChannelIds,
+    counterpartyChannelId: ChannelIds
+] <: {ActionType}
+ChannelOpenConfirmActions == [
+    type: {"ICS04ChannelOpenConfirm"},
+    chainId: ChainIds,
+    channelId: ChannelIds
+] <: {ActionType}
+ChannelCloseInitActions == [
+    type: {"ICS04ChannelCloseInit"},
+    chainId: ChainIds,
+    channelId: ChannelIds
+] <: {ActionType}
+ChannelCloseConfirmActions == [
+    type: {"ICS04ChannelCloseConfirm"},
+    chainId: ChainIds,
+    channelId: ChannelIds
+] <: {ActionType}
+SendPacketActions == [
+    type: {"ICS04SendPacket"},
+    chainId: ChainIds,
+    channelId: ChannelIds,
+    timeoutHeight: TimeoutHeights
+] <: {ActionType}
+RecvPacketActions == [
+    type: {"ICS04RecvPacket"},
+    chainId: ChainIds,
+    packet: Packet
+] <: {ActionType}
+AcknowledgePacketActions == [
+    type: {"ICS04AcknowledgePacket"},
+    chainId: ChainIds,
+    packet: Packet
+] <: {ActionType}
+TimeoutPacketActions == [
+    type: {"ICS04TimeoutPacket"},
+    chainId: ChainIds,
+    packet: Packet,
+    nextSequenceRecv: 0..(MaxPacketSequence + 1)
+] <: {ActionType}
 Actions ==
     NoneActions \union
     CreateClientActions \union
-    UpdateClientActions
+    UpdateClientActions \union
+    ConnectionOpenInitActions \union
+    ConnectionOpenTryActions \union
+    ConnectionOpenAckActions \union
+    ConnectionOpenConfirmActions \union
+    ChannelOpenInitActions \union
+    ChannelOpenTryActions \union
+    ChannelOpenAckActions \union
+    ChannelOpenConfirmActions \union
+    ChannelCloseInitActions \union
+    ChannelCloseConfirmActions \union
+    SendPacketActions \union
+    RecvPacketActions \union
+    AcknowledgePacketActions \union
+    TimeoutPacketActions
 
 \* set of possible action outcomes
 ActionOutcomes == {
@@ -87,7 +286,55 @@ ActionOutcomes == {
     \* ICS02_UpdateClient outcomes:
     "ICS02UpdateOK",
     "ICS02ClientNotFound",
-    "ICS02HeaderVerificationFailure"
+    "ICS02HeaderVerificationFailure",
+    \* ICS03_ConnectionOpenInit outcomes:
+    "ICS03ConnectionOpenInitOK",
+    "ICS03MissingClient",
+    \* ICS03_ConnectionOpenTry outcomes:
+    "ICS03ConnectionOpenTryOK",
+    "ICS03InvalidConsensusHeight",
+    "ICS03StaleConsensusHeight",
+    "ICS03ConnectionNotFound",
+    "ICS03ConnectionMismatch",
+    "ICS03MissingLocalConsensusState",
+    \* ICS03_ConnectionOpenAck outcomes:
+    "ICS03ConnectionOpenAckOK",
+    "ICS03UninitializedConnection",
+    "ICS03InvalidCounterparty",
+    \* ICS03_ConnectionOpenConfirm outcomes:
+    "ICS03ConnectionOpenConfirmOK",
+    \* ICS04_ChannelOpenInit outcomes:
+    "ICS04ChannelOpenInitOK",
+    "ICS04MissingConnection",
+    \* ICS04_ChannelOpenTry outcomes:
+    "ICS04ChannelOpenTryOK",
+    "ICS04ConnectionNotOpen",
+    \* ICS04_ChannelOpenAck outcomes:
+    "ICS04ChannelOpenAckOK",
+    "ICS04ChannelNotFound",
+    "ICS04InvalidChannelState",
+    \* ICS04_ChannelOpenConfirm outcomes:
+    "ICS04ChannelOpenConfirmOK",
+    \* ICS04_ChannelCloseInit outcomes:
+    "ICS04ChannelCloseInitOK",
+    \* ICS04_ChannelCloseConfirm outcomes:
+    "ICS04ChannelCloseConfirmOK",
+    "ICS04ChannelAlreadyClosed",
+    "ICS04InvalidCounterpartyChannel",
+    \* ICS04_SendPacket outcomes:
+    "ICS04PacketSendOK",
+    "ICS04InvalidPacketCounterparty",
+    "ICS04LowPacketHeight",
+    \* ICS04_RecvPacket outcomes:
+    "ICS04PacketRecvOK",
+    "ICS04InvalidPacketSequence",
+    "ICS04PacketAlreadyReceived",
+    \* ICS04_AcknowledgePacket outcomes:
+    "ICS04PacketAckOK",
+    "ICS04PacketCommitmentNotFound",
+    \* ICS04_TimeoutPacket outcomes:
+    "ICS04PacketTimeoutOK",
+    "ICS04PacketTimeoutNotReached"
 }
 
 (***************************** Specification *********************************)
@@ -121,7 +368,7 @@ UpdateClient(chainId, clientId, clientHeight) ==
     LET result == ICS02_UpdateClient(clients, clientId, clientHeight) IN
     \* update the chain
     LET updatedChain == [chain EXCEPT
-        !.height = UpdateChainHeight(@, result.outcome, "ICS03CreateOK"),
+        !.height = UpdateChainHeight(@, result.outcome, "ICS02UpdateOK"),
         !.clients = result.clients
     ] IN
     \* update `chains`, set the `action` and its `actionOutcome`
@@ -133,6 +380,275 @@ UpdateClient(chainId, clientId, clientHeight) ==
         clientHeight |-> clientHeight])
     /\ actionOutcome' = result.outcome
 
+ConnectionOpenInit(chainId, clientId, counterpartyClientId) ==
+    LET chain == chains[chainId] IN
+    LET result == ICS03_ConnectionOpenInit(
+        chain,
+        clientId,
+        counterpartyClientId
+    ) IN
+    \* update the chain
+    LET updatedChain == [chain EXCEPT
+        !.height = UpdateChainHeight(@, result.outcome, "ICS03ConnectionOpenInitOK"),
+        !.connections = result.connections,
+        !.connectionIdCounter = result.connectionIdCounter
+    ] IN
+    \* update `chains`, set the `action` and its `actionOutcome`
+    /\ chains' = [chains EXCEPT ![chainId] = updatedChain]
+    /\ action' = AsAction([
+        type |-> "ICS03ConnectionOpenInit",
+        chainId |-> chainId,
+        clientId |-> clientId,
+        counterpartyClientId |-> counterpartyClientId])
+    /\ actionOutcome' = result.outcome
+
+ConnectionOpenTry(
+    chainId,
+    previousConnectionId,
+    clientId,
+    clientState,
+    counterpartyClientId,
+    counterpartyConnectionId
+) ==
+    LET chain == chains[chainId] IN
+    LET result == ICS03_ConnectionOpenTry(
+        chain,
+        previousConnectionId,
+        clientId,
+        clientState,
+        counterpartyClientId,
+        counterpartyConnectionId
+    ) IN
+    \* update the chain
+    LET updatedChain == [chain EXCEPT
+        !.height = UpdateChainHeight(@, result.outcome, "ICS03ConnectionOpenTryOK"),
+        !.connections = result.connections,
+        !.connectionIdCounter = result.connectionIdCounter
+    ] IN
+    \* update `chains`, set the `action` and its `actionOutcome`
+    /\ chains' = [chains EXCEPT ![chainId] = updatedChain]
+    /\ action' = AsAction([
+        type |-> "ICS03ConnectionOpenTry",
+        chainId |-> chainId,
+        previousConnectionId |-> previousConnectionId,
+        clientId |-> clientId,
+        clientState |-> clientState,
+        counterpartyClientId |-> counterpartyClientId,
+        counterpartyConnectionId |-> counterpartyConnectionId])
+    /\ actionOutcome' = result.outcome
+
+ConnectionOpenAck(
+    chainId,
+    connectionId,
+    clientState,
+    counterpartyConnectionId
+) ==
+    LET chain == chains[chainId] IN
+    LET result == ICS03_ConnectionOpenAck(
+        chain,
+        connectionId,
+        clientState,
+        counterpartyConnectionId
+    ) IN
+    \* update the chain
+    LET updatedChain == [chain EXCEPT
+        !.height = UpdateChainHeight(@, result.outcome, "ICS03ConnectionOpenAckOK"),
+        !.connections = result.connections
+    ] IN
+    \* update `chains`, set the `action` and its `actionOutcome`
+    /\ chains' = [chains EXCEPT ![chainId] = updatedChain]
+    /\ action' = AsAction([
+        type |-> "ICS03ConnectionOpenAck",
+        chainId |-> chainId,
+        connectionId |-> connectionId,
+        clientState |-> clientState,
+        counterpartyConnectionId |-> counterpartyConnectionId])
+    /\ actionOutcome' = result.outcome
+
+ConnectionOpenConfirm(chainId, connectionId) ==
+    LET chain == chains[chainId] IN
+    LET result == ICS03_ConnectionOpenConfirm(
+        chain,
+        connectionId
+    ) IN
+    \* update the chain
+    LET updatedChain == [chain EXCEPT
+        !.height = UpdateChainHeight(@, result.outcome, "ICS03ConnectionOpenConfirmOK"),
+        !.connections = result.connections
+    ] IN
+    \* update `chains`, set the `action` and its `actionOutcome`
+    /\ chains' = [chains EXCEPT ![chainId] = updatedChain]
+    /\ action' = AsAction([
+        type |-> "ICS03ConnectionOpenConfirm",
+        chainId |-> chainId,
+        connectionId |-> connectionId])
+    /\ actionOutcome' = result.outcome
+
+ChannelOpenInit(chainId, connectionId, order) ==
+    LET chain == chains[chainId] IN
+    LET result == ICS04_ChannelOpenInit(chain, connectionId, order) IN
+    \* update the chain
+    LET updatedChain == [result.chain EXCEPT
+        !.height = UpdateChainHeight(@, result.outcome, "ICS04ChannelOpenInitOK")
+    ] IN
+    \* update `chains`, set the `action` and its `actionOutcome`
+    /\ chains' = [chains EXCEPT ![chainId] = updatedChain]
+    /\ action' = AsAction([
+        type |-> "ICS04ChannelOpenInit",
+        chainId |-> chainId,
+        connectionId |-> connectionId,
+        order |-> order])
+    /\ actionOutcome' = result.outcome
+
+ChannelOpenTry(chainId, connectionId, order, counterpartyChannelId) ==
+    LET chain == chains[chainId] IN
+    LET result == ICS04_ChannelOpenTry(
+        chain,
+        connectionId,
+        order,
+        counterpartyChannelId
+    ) IN
+    \* update the chain
+    LET updatedChain == [result.chain EXCEPT
+        !.height = UpdateChainHeight(@, result.outcome, "ICS04ChannelOpenTryOK")
+    ] IN
+    \* update `chains`, set the `action` and its `actionOutcome`
+    /\ chains' = [chains EXCEPT ![chainId] = updatedChain]
+    /\ action' = AsAction([
+        type |-> "ICS04ChannelOpenTry",
+        chainId |-> chainId,
+        connectionId |-> connectionId,
+        order |-> order,
+        counterpartyChannelId |-> counterpartyChannelId])
+    /\ actionOutcome' = result.outcome
+
+ChannelOpenAck(chainId, channelId, counterpartyChannelId) ==
+    LET chain == chains[chainId] IN
+    LET result == ICS04_ChannelOpenAck(
+        chain,
+        channelId,
+        counterpartyChannelId
+    ) IN
+    \* update the chain
+    LET updatedChain == [result.chain EXCEPT
+        !.height = UpdateChainHeight(@, result.outcome, "ICS04ChannelOpenAckOK")
+    ] IN
+    \* update `chains`, set the `action` and its `actionOutcome`
+    /\ chains' = [chains EXCEPT ![chainId] = updatedChain]
+    /\ action' = AsAction([
+        type |-> "ICS04ChannelOpenAck",
+        chainId |-> chainId,
+        channelId |-> channelId,
+        counterpartyChannelId |-> counterpartyChannelId])
+    /\ actionOutcome' = result.outcome
+
+ChannelOpenConfirm(chainId, channelId) ==
+    LET chain == chains[chainId] IN
+    LET result == ICS04_ChannelOpenConfirm(chain, channelId) IN
+    \* update the chain
+    LET updatedChain == [result.chain EXCEPT
+        !.height = UpdateChainHeight(@, result.outcome, "ICS04ChannelOpenConfirmOK")
+    ] IN
+    \* update `chains`, set the `action` and its `actionOutcome`
+    /\ chains' = [chains EXCEPT ![chainId] = updatedChain]
+    /\ action' = AsAction([
+        type |-> "ICS04ChannelOpenConfirm",
+        chainId |-> chainId,
+        channelId |-> channelId])
+    /\ actionOutcome' = result.outcome
+
+ChannelCloseInit(chainId, channelId) ==
+    LET chain == chains[chainId] IN
+    LET result == ICS04_ChannelCloseInit(chain, channelId) IN
+    \* update the chain
+    LET updatedChain == [result.chain EXCEPT
+        !.height = UpdateChainHeight(@, result.outcome, "ICS04ChannelCloseInitOK")
+    ] IN
+    \* update `chains`, set the `action` and its `actionOutcome`
+    /\ chains' = [chains EXCEPT ![chainId] = updatedChain]
+    /\ action' = AsAction([
+        type |-> "ICS04ChannelCloseInit",
+        chainId |-> chainId,
+        channelId |-> channelId])
+    /\ actionOutcome' = result.outcome
+
+ChannelCloseConfirm(chainId, channelId) ==
+    LET chain == chains[chainId] IN
+    LET result == ICS04_ChannelCloseConfirm(chain, channelId) IN
+    \* update the chain
+    LET updatedChain == [result.chain EXCEPT
+        !.height = UpdateChainHeight(@, result.outcome, "ICS04ChannelCloseConfirmOK")
+    ] IN
+    \* update `chains`, set the `action` and its `actionOutcome`
+    /\ chains' = [chains EXCEPT ![chainId] = updatedChain]
+    /\ action' = AsAction([
+        type |-> "ICS04ChannelCloseConfirm",
+        chainId |-> chainId,
+        channelId |-> channelId])
+    /\ actionOutcome' = result.outcome
+
+SendPacket(chainId, channelId, timeoutHeight) ==
+    LET chain == chains[chainId] IN
+    LET result == ICS04_SendPacket(chain, channelId, timeoutHeight) IN
+    \* update the chain
+    LET updatedChain == [result.chain EXCEPT
+        !.height = UpdateChainHeight(@, result.outcome, "ICS04PacketSendOK")
+    ] IN
+    \* update `chains`, set the `action` and its `actionOutcome`
+    /\ chains' = [chains EXCEPT ![chainId] = updatedChain]
+    /\ action' = AsAction([
+        type |-> "ICS04SendPacket",
+        chainId |-> chainId,
+        channelId |-> channelId,
+        timeoutHeight |-> timeoutHeight])
+    /\ actionOutcome' = result.outcome
+
+RecvPacket(chainId, packet) ==
+    LET chain == chains[chainId] IN
+    LET result == ICS04_RecvPacket(chain, packet) IN
+    \* update the chain
+    LET updatedChain == [result.chain EXCEPT
+        !.height = UpdateChainHeight(@, result.outcome, "ICS04PacketRecvOK")
+    ] IN
+    \* update `chains`, set the `action` and its `actionOutcome`
+    /\ chains' = [chains EXCEPT ![chainId] = updatedChain]
+    /\ action' = AsAction([
+        type |-> "ICS04RecvPacket",
+        chainId |-> chainId,
+        packet |-> packet])
+    /\ actionOutcome' = result.outcome
+
+AcknowledgePacket(chainId, packet) ==
+    LET chain == chains[chainId] IN
+    LET result == ICS04_AcknowledgePacket(chain, packet) IN
+    \* update the chain
+    LET updatedChain == [result.chain EXCEPT
+        !.height = UpdateChainHeight(@, result.outcome, "ICS04PacketAckOK")
+    ] IN
+    \* update `chains`, set the `action` and its `actionOutcome`
+    /\ chains' = [chains EXCEPT ![chainId] = updatedChain]
+    /\ action' = AsAction([
+        type |-> "ICS04AcknowledgePacket",
+        chainId |-> chainId,
+        packet |-> packet])
+    /\ actionOutcome' = result.outcome
+
+TimeoutPacket(chainId, packet, nextSequenceRecv) ==
+    LET chain == chains[chainId] IN
+    LET result == ICS04_TimeoutPacket(chain, packet, nextSequenceRecv) IN
+    \* update the chain
+    LET updatedChain == [result.chain EXCEPT
+        !.height = UpdateChainHeight(@, result.outcome, "ICS04PacketTimeoutOK")
+    ] IN
+    \* update `chains`, set the `action` and its `actionOutcome`
+    /\ chains' = [chains EXCEPT ![chainId] = updatedChain]
+    /\ action' = AsAction([
+        type |-> "ICS04TimeoutPacket",
+        chainId |-> chainId,
+        packet |-> packet,
+        nextSequenceRecv |-> nextSequenceRecv])
+    /\ actionOutcome' = result.outcome
+
 CreateClientAction ==
     \* select a chain id
     \E chainId \in ChainIds:
@@ -154,16 +670,215 @@ UpdateClientAction ==
     \E clientHeight \in ClientHeights:
         UpdateClient(chainId, clientId, clientHeight)
 
+ConnectionOpenInitAction ==
+    \* select a chain id
+    \E chainId \in ChainIds:
+    \* select a client id (which may not exist)
+    \E clientId \in ClientIds:
+    \* select a counterparty client id
+    \E counterpartyClientId \in ClientIds:
+        \* only create connection if the model constant `MaxConnectionsPerChain`
+        \* allows it
+        IF chains[chainId].connectionIdCounter \in ConnectionIds THEN
+            ConnectionOpenInit(chainId, clientId, counterpartyClientId)
+        ELSE
+            UNCHANGED vars
+
+ConnectionOpenTryAction ==
+    \* select a chain id
+    \E chainId \in ChainIds:
+    \* select an existing connection id, if any (which may not exist)
+    \E previousConnectionId \in ConnectionIds \union {ConnectionIdNone}:
+    \* select a client id (which may not exist)
+    \E clientId \in ClientIds:
+    \* select a height of the consensus state of the chain claimed by the
+    \* client of the counterparty
+    \E clientState \in ClientHeights:
+    \* select a counterparty client id
+    \E counterpartyClientId \in ClientIds:
+    \* select a counterparty connection id
+    \E counterpartyConnectionId \in ConnectionIds:
+        \* only create connection if the model constant `MaxConnectionsPerChain`
+        \* allows it
+        IF \/ previousConnectionId /= ConnectionIdNone
+           \/ chains[chainId].connectionIdCounter \in ConnectionIds THEN
+            ConnectionOpenTry(
+                chainId,
+                previousConnectionId,
+                clientId,
+                clientState,
+                counterpartyClientId,
+                counterpartyConnectionId
+            )
+        ELSE
+            UNCHANGED vars
+
+ConnectionOpenAckAction ==
+    \* select a chain id
+    \E chainId \in ChainIds:
+    \* select a connection id (which may not exist)
+    \E connectionId \in ConnectionIds:
+    \* select a height of the consensus state of the chain claimed by the
+    \* client of the counterparty
+    \E clientState \in ClientHeights:
+    \* select a counterparty connection id, if any
+    \E counterpartyConnectionId \in ConnectionIds \union {ConnectionIdNone}:
+        ConnectionOpenAck(
+            chainId,
+            connectionId,
+            clientState,
+            counterpartyConnectionId
+        )
+
+ConnectionOpenConfirmAction ==
+    \* select a chain id
+    \E chainId \in ChainIds:
+    \* select a connection id (which may not exist)
+    \E connectionId \in ConnectionIds:
+        ConnectionOpenConfirm(chainId, connectionId)
+
+ChannelOpenInitAction ==
+    \* select a chain id
+    \E chainId \in ChainIds:
+    \* select a connection id (which may not exist)
+    \E connectionId \in ConnectionIds:
+    \* select a channel order
+    \E order \in ChannelOrders:
+        \* only create channel if the model constant `MaxChannelsPerChain`
+        \* allows it
+        IF chains[chainId].channelIdCounter \in ChannelIds THEN
+            ChannelOpenInit(chainId, connectionId, order)
+        ELSE
+            UNCHANGED vars
+
+ChannelOpenTryAction ==
+    \* select a chain id
+    \E chainId \in ChainIds:
+    \* select a connection id (which may not exist)
+    \E connectionId \in ConnectionIds:
+    \* select a channel order
+    \E order \in ChannelOrders:
+    \* select a counterparty channel id
+    \E counterpartyChannelId \in ChannelIds:
+        \* only create channel if the model constant `MaxChannelsPerChain`
+        \* allows it
+        IF chains[chainId].channelIdCounter \in ChannelIds THEN
+            ChannelOpenTry(chainId, connectionId, order, counterpartyChannelId)
+        ELSE
+            UNCHANGED vars
+
+ChannelOpenAckAction ==
+    \* select a chain id
+    \E chainId \in ChainIds:
+    \* select a channel id (which may not exist)
+    \E channelId \in ChannelIds:
+    \* select a counterparty channel id
+    \E counterpartyChannelId \in ChannelIds:
+        ChannelOpenAck(chainId, channelId, counterpartyChannelId)
+
+ChannelOpenConfirmAction ==
+    \* select a chain id
+    \E chainId \in ChainIds:
+    \* select a channel id (which may not exist)
+    \E channelId \in ChannelIds:
+        ChannelOpenConfirm(chainId, channelId)
+
+ChannelCloseInitAction ==
+    \* select a chain id
+    \E chainId \in ChainIds:
+    \* select a channel id (which may not exist)
+    \E channelId \in ChannelIds:
+        ChannelCloseInit(chainId, channelId)
+
+ChannelCloseConfirmAction ==
+    \* select a chain id
+    \E chainId \in ChainIds:
+    \* select a channel id (which may not exist)
+    \E channelId \in ChannelIds:
+        ChannelCloseConfirm(chainId, channelId)
+
+SendPacketAction ==
+    \* select a chain id
+    \E chainId \in ChainIds:
+    \* select a channel id (which may not exist)
+    \E channelId \in ChannelIds:
+    \* select a timeout height for the packet
+    \E timeoutHeight \in TimeoutHeights:
+        \* only send packet if the model constant `MaxPacketSequence` allows it
+        IF chains[chainId].channels[channelId].nextSendSeq <= MaxPacketSequence
+        THEN
+            SendPacket(chainId, channelId, timeoutHeight)
+        ELSE
+            UNCHANGED vars
+
+RecvPacketAction ==
+    \* select a chain id
+    \E chainId \in ChainIds:
+    \* select a counterparty chain id
+    \E counterpartyChainId \in ChainIds \ {chainId}:
+    \* select a packet sent by the counterparty chain and not yet acknowledged
+    \* nor timed out
+    \E packet \in chains[counterpartyChainId].packetCommitments:
+        RecvPacket(chainId, packet)
+
+AcknowledgePacketAction ==
+    \* select a chain id
+    \E chainId \in ChainIds:
+    \* select a counterparty chain id
+    \E counterpartyChainId \in ChainIds \ {chainId}:
+    \* select a packet received by the counterparty chain
+    \E packet \in chains[counterpartyChainId].packetReceipts:
+        AcknowledgePacket(chainId, packet)
+
+TimeoutPacketAction ==
+    \* select a chain id
+    \E chainId \in ChainIds:
+    \* select a counterparty chain id
+    \E counterpartyChainId \in ChainIds \ {chainId}:
+    \* select a packet sent by the chain (which may already have been
+    \* acknowledged or timed out)
+    \E packet \in ICS04_SentPackets(chains[chainId]):
+        \* the relayer proves the next sequence to be received by the
+        \* destination channel of the packet
+        LET destChannel ==
+            chains[counterpartyChainId].channels[packet.destChannelId] IN
+        TimeoutPacket(chainId, packet, destChannel.nextRecvSeq)
+
 Init ==
     \* create a client with none values
     LET clientNone == [
         height |-> HeightNone
     ] IN
+    \* create a connection with none values
+    LET connectionNone == [
+        state |-> "Uninit",
+        clientId |-> ClientIdNone,
+        counterpartyClientId |-> ClientIdNone,
+        counterpartyConnectionId |-> ConnectionIdNone
+    ] IN
+    \* create a channel with none values
+    LET channelNone == [
+        state |-> "Uninit",
+        order |-> ChannelOrderNone,
+        connectionId |-> ConnectionIdNone,
+        counterpartyChannelId |-> ChannelIdNone,
+        nextSendSeq |-> 0,
+        nextRecvSeq |-> 0,
+        nextAckSeq |-> 0
+    ] IN
     \* create an empty chain
     LET emptyChain == [
         height |-> 0,
         clients |-> [clientId \in ClientIds |-> clientNone],
-        clientIdCounter |-> 0
+        clientIdCounter |-> 0,
+        connections |-> [connectionId \in ConnectionIds |-> connectionNone],
+        connectionIdCounter |-> 0,
+        channels |-> [channelId \in ChannelIds |-> channelNone],
+        channelIdCounter |-> 0,
+        packetCommitments |-> AsPacketSet({}),
+        packetReceipts |-> AsPacketSet({}),
+        acknowledgedPackets |-> AsPacketSet({}),
+        timedOutPackets |-> AsPacketSet({})
     ] IN
     /\ chains = [chainId \in ChainIds |-> emptyChain]
     /\ action = AsAction([type |-> "None"])
@@ -172,6 +887,20 @@ Init ==
 Next ==
     \/ CreateClientAction
     \/ UpdateClientAction
+    \/ ConnectionOpenInitAction
+    \/ ConnectionOpenTryAction
+    \/ ConnectionOpenAckAction
+    \/ ConnectionOpenConfirmAction
+    \/ ChannelOpenInitAction
+    \/ ChannelOpenTryAction
+    \/ ChannelOpenAckAction
+    \/ ChannelOpenConfirmAction
+    \/ ChannelCloseInitAction
+    \/ ChannelCloseConfirmAction
+    \/ SendPacketAction
+    \/ RecvPacketAction
+    \/ AcknowledgePacketAction
+    \/ TimeoutPacketAction
     \/ UNCHANGED vars
 
 (******************************** Invariants *********************************)
@@ -185,4 +914,30 @@ TypeOK ==
 ModelNeverErrors ==
     actionOutcome /= "ModelError"
 
+\* no packet is both acknowledged and timed out
+NoPacketAcknowledgedAndTimedOut ==
+    \A chainId \in ChainIds:
+        LET chain == chains[chainId] IN
+        chain.acknowledgedPackets \intersect chain.timedOutPackets =
+            AsPacketSet({})
+
+\* the sequences of the packets sent, received and acknowledged on ordered
+\* channels never skip
+OrderedSequencesNeverSkip ==
+    \A chainId \in ChainIds:
+    \A channelId \in ChannelIds:
+        LET chain == chains[chainId] IN
+        LET channel == chain.channels[channelId] IN
+        LET Sequences(packets) == {packet.sequence: packet \in packets} IN
+        LET sent == {packet \in ICS04_SentPackets(chain):
+            packet.sourceChannelId = channelId} IN
+        LET received == {packet \in chain.packetReceipts:
+            packet.destChannelId = channelId} IN
+        LET acknowledged == {packet \in chain.acknowledgedPackets:
+            packet.sourceChannelId = channelId} IN
+        channel.order = "Ordered" =>
+            /\ Sequences(sent) = 1..(channel.nextSendSeq - 1)
+            /\ Sequences(received) = 1..(channel.nextRecvSeq - 1)
+            /\ Sequences(acknowledged) = 1..(channel.nextAckSeq - 1)
+
 ===============================================================================
@@ -8,5 +8,11 @@ ClientIdNone == -1
 HeightNone == -1
 \* if a connection identifier is not set then it is -1
 ConnectionIdNone == -1
+\* if a channel identifier is not set then it is -1
+ChannelIdNone == -1
+\* if the order of a channel is not set then it is "None"
+ChannelOrderNone == "None"
+\* if the timeout height of a packet is 0 then the packet does not time out
+TimeoutHeightNone == 0
 
 ===============================================================================
@@ -14,9 +14,157 @@ ICS02ClientNotFoundTest ==
 ICS02HeaderVerificationFailureTest ==
     /\ actionOutcome = "ICS02HeaderVerificationFailure"
 
+ICS03ConnectionOpenInitOKTest ==
+    /\ actionOutcome = "ICS03ConnectionOpenInitOK"
+
+ICS03MissingClientTest ==
+    /\ actionOutcome = "ICS03MissingClient"
+
+ICS03ConnectionOpenTryOKTest ==
+    /\ actionOutcome = "ICS03ConnectionOpenTryOK"
+
+ICS03InvalidConsensusHeightTest ==
+    /\ actionOutcome = "ICS03InvalidConsensusHeight"
+
+ICS03StaleConsensusHeightTest ==
+    /\ actionOutcome = "ICS03StaleConsensusHeight"
+
+ICS03ConnectionNotFoundTest ==
+    /\ actionOutcome = "ICS03ConnectionNotFound"
+
+ICS03ConnectionMismatchTest ==
+    /\ actionOutcome = "ICS03ConnectionMismatch"
+
+ICS03MissingLocalConsensusStateTest ==
+    /\ actionOutcome = "ICS03MissingLocalConsensusState"
+
+ICS03ConnectionOpenAckOKTest ==
+    /\ actionOutcome = "ICS03ConnectionOpenAckOK"
+
+ICS03UninitializedConnectionTest ==
+    /\ actionOutcome = "ICS03UninitializedConnection"
+
+ICS03InvalidCounterpartyTest ==
+    /\ actionOutcome = "ICS03InvalidCounterparty"
+
+ICS03ConnectionOpenConfirmOKTest ==
+    /\ actionOutcome = "ICS03ConnectionOpenConfirmOK"
+
+ICS04ChannelOpenInitOKTest ==
+    /\ actionOutcome = "ICS04ChannelOpenInitOK"
+
+ICS04MissingConnectionTest ==
+    /\ actionOutcome = "ICS04MissingConnection"
+
+ICS04ChannelOpenTryOKTest ==
+    /\ actionOutcome = "ICS04ChannelOpenTryOK"
+
+ICS04ConnectionNotOpenTest ==
+    /\ actionOutcome = "ICS04ConnectionNotOpen"
+
+ICS04ChannelOpenAckOKTest ==
+    /\ actionOutcome = "ICS04ChannelOpenAckOK"
+
+ICS04ChannelNotFoundTest ==
+    /\ actionOutcome = "ICS04ChannelNotFound"
+
+ICS04InvalidChannelStateTest ==
+    /\ actionOutcome = "ICS04InvalidChannelState"
+
+ICS04ChannelOpenConfirmOKTest ==
+    /\ actionOutcome = "ICS04ChannelOpenConfirmOK"
+
+ICS04ChannelCloseInitOKTest ==
+    /\ actionOutcome = "ICS04ChannelCloseInitOK"
+
+ICS04ChannelCloseConfirmOKTest ==
+    /\ actionOutcome = "ICS04ChannelCloseConfirmOK"
+
+ICS04ChannelAlreadyClosedTest ==
+    /\ actionOutcome = "ICS04ChannelAlreadyClosed"
+
+ICS04InvalidCounterpartyChannelTest ==
+    /\ actionOutcome = "ICS04InvalidCounterpartyChannel"
+
+ICS04PacketSendOKTest ==
+    /\ actionOutcome = "ICS04PacketSendOK"
+
+ICS04InvalidPacketCounterpartyTest ==
+    /\ actionOutcome = "ICS04InvalidPacketCounterparty"
+
+ICS04LowPacketHeightTest ==
+    /\ actionOutcome = "ICS04LowPacketHeight"
+
+ICS04PacketRecvOKTest ==
+    /\ actionOutcome = "ICS04PacketRecvOK"
+
+ICS04InvalidPacketSequenceTest ==
+    /\ actionOutcome = "ICS04InvalidPacketSequence"
+
+ICS04PacketAlreadyReceivedTest ==
+    /\ actionOutcome = "ICS04PacketAlreadyReceived"
+
+ICS04PacketAckOKTest ==
+    /\ actionOutcome = "ICS04PacketAckOK"
+
+ICS04UnorderedPacketAckOKTest ==
+    /\ actionOutcome = "ICS04PacketAckOK"
+    /\ LET channelId == action.packet.sourceChannelId IN
+       chains[action.chainId].channels[channelId].order = "Unordered"
+
+ICS04PacketCommitmentNotFoundTest ==
+    /\ actionOutcome = "ICS04PacketCommitmentNotFound"
+
+ICS04PacketTimeoutOKTest ==
+    /\ actionOutcome = "ICS04PacketTimeoutOK"
+
+ICS04UnorderedPacketTimeoutOKTest ==
+    /\ actionOutcome = "ICS04PacketTimeoutOK"
+    /\ LET channelId == action.packet.sourceChannelId IN
+       chains[action.chainId].channels[channelId].order = "Unordered"
+
+ICS04PacketTimeoutNotReachedTest ==
+    /\ actionOutcome = "ICS04PacketTimeoutNotReached"
+
 ICS02CreateOKTestNeg == ~ICS02CreateOKTest
 ICS02UpdateOKTestNeg == ~ICS02UpdateOKTest
 ICS02ClientNotFoundTestNeg == ~ICS02ClientNotFoundTest
 ICS02HeaderVerificationFailureTestNeg == ~ICS02HeaderVerificationFailureTest
+ICS03ConnectionOpenInitOKTestNeg == ~ICS03ConnectionOpenInitOKTest
+ICS03MissingClientTestNeg == ~ICS03MissingClientTest
+ICS03ConnectionOpenTryOKTestNeg == ~ICS03ConnectionOpenTryOKTest
+ICS03InvalidConsensusHeightTestNeg == ~ICS03InvalidConsensusHeightTest
+ICS03StaleConsensusHeightTestNeg == ~ICS03StaleConsensusHeightTest
+ICS03ConnectionNotFoundTestNeg == ~ICS03ConnectionNotFoundTest
+ICS03ConnectionMismatchTestNeg == ~ICS03ConnectionMismatchTest
+ICS03MissingLocalConsensusStateTestNeg == ~ICS03MissingLocalConsensusStateTest
+ICS03ConnectionOpenAckOKTestNeg == ~ICS03ConnectionOpenAckOKTest
+ICS03UninitializedConnectionTestNeg == ~ICS03UninitializedConnectionTest
+ICS03InvalidCounterpartyTestNeg == ~ICS03InvalidCounterpartyTest
+ICS03ConnectionOpenConfirmOKTestNeg == ~ICS03ConnectionOpenConfirmOKTest
+ICS04ChannelOpenInitOKTestNeg == ~ICS04ChannelOpenInitOKTest
+ICS04MissingConnectionTestNeg == ~ICS04MissingConnectionTest
+ICS04ChannelOpenTryOKTestNeg == ~ICS04ChannelOpenTryOKTest
+ICS04ConnectionNotOpenTestNeg == ~ICS04ConnectionNotOpenTest
+ICS04ChannelOpenAckOKTestNeg == ~ICS04ChannelOpenAckOKTest
+ICS04ChannelNotFoundTestNeg == ~ICS04ChannelNotFoundTest
+ICS04InvalidChannelStateTestNeg == ~ICS04InvalidChannelStateTest
+ICS04ChannelOpenConfirmOKTestNeg == ~ICS04ChannelOpenConfirmOKTest
+ICS04ChannelCloseInitOKTestNeg == ~ICS04ChannelCloseInitOKTest
+ICS04ChannelCloseConfirmOKTestNeg == ~ICS04ChannelCloseConfirmOKTest
+ICS04ChannelAlreadyClosedTestNeg == ~ICS04ChannelAlreadyClosedTest
+ICS04InvalidCounterpartyChannelTestNeg == ~ICS04InvalidCounterpartyChannelTest
+ICS04PacketSendOKTestNeg == ~ICS04PacketSendOKTest
+ICS04InvalidPacketCounterpartyTestNeg == ~ICS04InvalidPacketCounterpartyTest
+ICS04LowPacketHeightTestNeg == ~ICS04LowPacketHeightTest
+ICS04PacketRecvOKTestNeg == ~ICS04PacketRecvOKTest
+ICS04InvalidPacketSequenceTestNeg == ~ICS04InvalidPacketSequenceTest
+ICS04PacketAlreadyReceivedTestNeg == ~ICS04PacketAlreadyReceivedTest
+ICS04PacketAckOKTestNeg == ~ICS04PacketAckOKTest
+ICS04UnorderedPacketAckOKTestNeg == ~ICS04UnorderedPacketAckOKTest
+ICS04PacketCommitmentNotFoundTestNeg == ~ICS04PacketCommitmentNotFoundTest
+ICS04PacketTimeoutOKTestNeg == ~ICS04PacketTimeoutOKTest
+ICS04UnorderedPacketTimeoutOKTestNeg == ~ICS04UnorderedPacketTimeoutOKTest
+ICS04PacketTimeoutNotReachedTestNeg == ~ICS04PacketTimeoutNotReachedTest
 
 ===============================================================================
@@ -0,0 +1,245 @@
+------------------------------- MODULE ICS03 ----------------------------------
+
+EXTENDS Integers, FiniteSets, IBCDefinitions, ICS02
+
+\* retrieves `connectionId`'s data
+ICS03_GetConnection(connections, connectionId) ==
+    connections[connectionId]
+
+\* check if `connectionId` exists
+ICS03_ConnectionExists(connections, connectionId) ==
+    ICS03_GetConnection(connections, connectionId).state /= "Uninit"
+
+\* update `connectionId`'s data
+ICS03_SetConnection(connections, connectionId, connection) ==
+    [connections EXCEPT ![connectionId] = connection]
+
+\* check the height of the consensus state of the chain claimed by the client
+\* of its counterparty (the height of `clientState`), which can neither be
+\* higher than the height of the chain, nor older than its (single block)
+\* history
+ICS03_CheckConsensusHeight(chainHeight, clientState) ==
+    IF clientState > chainHeight THEN
+        "ICS03InvalidConsensusHeight"
+    ELSE IF clientState < chainHeight - 1 THEN
+        "ICS03StaleConsensusHeight"
+    ELSE
+        "OK"
+
+\* the chain only keeps its latest consensus state, which the consensus state
+\* claimed by the client of its counterparty must then be
+ICS03_HasConsensusState(chainHeight, clientState) ==
+    clientState = chainHeight
+
+ICS03_ConnectionOpenInit(
+    chain,
+    clientId,
+    counterpartyClientId
+) ==
+    LET connections == chain.connections IN
+    LET connectionIdCounter == chain.connectionIdCounter IN
+    \* check if the client exists
+    IF ~ICS02_ClientExists(chain.clients, clientId) THEN
+        \* if the client does not exist, then set an error outcome
+        [
+            connections |-> connections,
+            connectionIdCounter |-> connectionIdCounter,
+            outcome |-> "ICS03MissingClient"
+        ]
+    \* check if the connection exists (it shouldn't)
+    ELSE IF ICS03_ConnectionExists(connections, connectionIdCounter) THEN
+        \* if the connection to be created already exists,
+        \* then there's an error in the model
+        [
+            connections |-> connections,
+            connectionIdCounter |-> connectionIdCounter,
+            outcome |-> "ModelError"
+        ]
+    ELSE
+        \* if it doesn't, create it; the connection identifier of the
+        \* counterparty is not known yet
+        LET connection == [
+            state |-> "Init",
+            clientId |-> clientId,
+            counterpartyClientId |-> counterpartyClientId,
+            counterpartyConnectionId |-> ConnectionIdNone
+        ] IN
+        \* return result with updated state
+        [
+            connections |->
+                ICS03_SetConnection(connections, connectionIdCounter, connection),
+            connectionIdCounter |-> connectionIdCounter + 1,
+            outcome |-> "ICS03ConnectionOpenInitOK"
+        ]
+
+ICS03_ConnectionOpenTry(
+    chain,
+    previousConnectionId,
+    clientId,
+    clientState,
+    counterpartyClientId,
+    counterpartyConnectionId
+) ==
+    LET connections == chain.connections IN
+    LET connectionIdCounter == chain.connectionIdCounter IN
+    LET heightOutcome == ICS03_CheckConsensusHeight(chain.height, clientState) IN
+    \* check the height of the consensus state claimed by the counterparty
+    IF heightOutcome /= "OK" THEN
+        [
+            connections |-> connections,
+            connectionIdCounter |-> connectionIdCounter,
+            outcome |-> heightOutcome
+        ]
+    \* check if the previous connection, if any, exists
+    ELSE IF /\ previousConnectionId /= ConnectionIdNone
+            /\ ~ICS03_ConnectionExists(connections, previousConnectionId) THEN
+        [
+            connections |-> connections,
+            connectionIdCounter |-> connectionIdCounter,
+            outcome |-> "ICS03ConnectionNotFound"
+        ]
+    \* check if the previous connection, if any, matches the one being tried
+    ELSE IF /\ previousConnectionId /= ConnectionIdNone
+            /\ LET connection ==
+                    ICS03_GetConnection(connections, previousConnectionId) IN
+               ~(
+                   /\ connection.state = "Init"
+                   /\ connection.clientId = clientId
+                   /\ connection.counterpartyClientId = counterpartyClientId
+                   /\ connection.counterpartyConnectionId =
+                        counterpartyConnectionId
+               ) THEN
+        [
+            connections |-> connections,
+            connectionIdCounter |-> connectionIdCounter,
+            outcome |-> "ICS03ConnectionMismatch"
+        ]
+    \* check if the client exists
+    ELSE IF ~ICS02_ClientExists(chain.clients, clientId) THEN
+        [
+            connections |-> connections,
+            connectionIdCounter |-> connectionIdCounter,
+            outcome |-> "ICS03MissingClient"
+        ]
+    \* check if the chain has the consensus state claimed by the counterparty
+    ELSE IF ~ICS03_HasConsensusState(chain.height, clientState) THEN
+        [
+            connections |-> connections,
+            connectionIdCounter |-> connectionIdCounter,
+            outcome |-> "ICS03MissingLocalConsensusState"
+        ]
+    ELSE
+        \* if there's no previous connection, then create a new one
+        LET connectionId ==
+            IF previousConnectionId = ConnectionIdNone THEN
+                connectionIdCounter
+            ELSE
+                previousConnectionId
+        IN
+        LET connection == [
+            state |-> "TryOpen",
+            clientId |-> clientId,
+            counterpartyClientId |-> counterpartyClientId,
+            counterpartyConnectionId |-> counterpartyConnectionId
+        ] IN
+        \* return result with updated state
+        [
+            connections |->
+                ICS03_SetConnection(connections, connectionId, connection),
+            connectionIdCounter |->
+                IF previousConnectionId = ConnectionIdNone THEN
+                    connectionIdCounter + 1
+                ELSE
+                    connectionIdCounter,
+            outcome |-> "ICS03ConnectionOpenTryOK"
+        ]
+
+ICS03_ConnectionOpenAck(
+    chain,
+    connectionId,
+    clientState,
+    counterpartyConnectionId
+) ==
+    LET connections == chain.connections IN
+    LET heightOutcome == ICS03_CheckConsensusHeight(chain.height, clientState) IN
+    \* check the height of the consensus state claimed by the counterparty
+    IF heightOutcome /= "OK" THEN
+        [
+            connections |-> connections,
+            outcome |-> heightOutcome
+        ]
+    \* check if the connection exists
+    ELSE IF ~ICS03_ConnectionExists(connections, connectionId) THEN
+        [
+            connections |-> connections,
+            outcome |-> "ICS03UninitializedConnection"
+        ]
+    ELSE
+        LET connection == ICS03_GetConnection(connections, connectionId) IN
+        \* check if the connection is either `Init` or `TryOpen`, and if the
+        \* connection identifier of the counterparty, if given, matches the
+        \* one of the connection
+        IF \/ connection.state \notin {"Init", "TryOpen"}
+           \/ /\ counterpartyConnectionId /= ConnectionIdNone
+              /\ counterpartyConnectionId /= connection.counterpartyConnectionId
+        THEN
+            [
+                connections |-> connections,
+                outcome |-> "ICS03ConnectionMismatch"
+            ]
+        \* check if the connection identifier of the counterparty is known,
+        \* which it is not if the connection was initialized on this chain
+        ELSE IF connection.counterpartyConnectionId = ConnectionIdNone THEN
+            [
+                connections |-> connections,
+                outcome |-> "ICS03InvalidCounterparty"
+            ]
+        \* check if the chain has the consensus state claimed by the
+        \* counterparty
+        ELSE IF ~ICS03_HasConsensusState(chain.height, clientState) THEN
+            [
+                connections |-> connections,
+                outcome |-> "ICS03MissingLocalConsensusState"
+            ]
+        ELSE
+            LET updatedConnection == [connection EXCEPT
+                !.state = "Open"
+            ] IN
+            \* return result with updated state
+            [
+                connections |->
+                    ICS03_SetConnection(connections, connectionId, updatedConnection),
+                outcome |-> "ICS03ConnectionOpenAckOK"
+            ]
+
+ICS03_ConnectionOpenConfirm(
+    chain,
+    connectionId
+) ==
+    LET connections == chain.connections IN
+    \* check if the connection exists
+    IF ~ICS03_ConnectionExists(connections, connectionId) THEN
+        [
+            connections |-> connections,
+            outcome |-> "ICS03UninitializedConnection"
+        ]
+    ELSE
+        LET connection == ICS03_GetConnection(connections, connectionId) IN
+        \* check if the connection is `TryOpen`
+        IF connection.state /= "TryOpen" THEN
+            [
+                connections |-> connections,
+                outcome |-> "ICS03ConnectionMismatch"
+            ]
+        ELSE
+            LET updatedConnection == [connection EXCEPT
+                !.state = "Open"
+            ] IN
+            \* return result with updated state
+            [
+                connections |->
+                    ICS03_SetConnection(connections, connectionId, updatedConnection),
+                outcome |-> "ICS03ConnectionOpenConfirmOK"
+            ]
+
+===============================================================================
@@ -0,0 +1,548 @@
+------------------------------- MODULE ICS04 ----------------------------------
+
+EXTENDS Integers, FiniteSets, IBCDefinitions, ICS02, ICS03
+
+\* retrieves `channelId`'s data
+ICS04_GetChannel(channels, channelId) ==
+    channels[channelId]
+
+\* check if `channelId` exists
+ICS04_ChannelExists(channels, channelId) ==
+    ICS04_GetChannel(channels, channelId).state /= "Uninit"
+
+\* update `channelId`'s data
+ICS04_SetChannel(channels, channelId, channel) ==
+    [channels EXCEPT ![channelId] = channel]
+
+\* check if the connection of `channel` is open
+ICS04_ConnectionIsOpen(chain, channel) ==
+    /\ ICS03_ConnectionExists(chain.connections, channel.connectionId)
+    /\ ICS03_GetConnection(chain.connections, channel.connectionId).state = "Open"
+
+\* the height of the client of the connection of `channel`, which is the height
+\* of the proofs of the counterparty chain state
+ICS04_ClientHeight(chain, channel) ==
+    LET connection ==
+        ICS03_GetConnection(chain.connections, channel.connectionId) IN
+    ICS02_GetClient(chain.clients, connection.clientId).height
+
+\* check if a packet with `timeoutHeight` has timed out at `height` (of the
+\* receiving chain)
+ICS04_TimedOut(timeoutHeight, height) ==
+    /\ timeoutHeight /= TimeoutHeightNone
+    /\ timeoutHeight <= height
+
+\* the packets sent by the chain, which may since have been acknowledged or
+\* timed out
+ICS04_SentPackets(chain) ==
+    chain.packetCommitments \union
+    chain.acknowledgedPackets \union
+    chain.timedOutPackets
+
+ICS04_ChannelOpenInit(
+    chain,
+    connectionId,
+    order
+) ==
+    LET channels == chain.channels IN
+    LET channelIdCounter == chain.channelIdCounter IN
+    \* check if the connection exists
+    IF ~ICS03_ConnectionExists(chain.connections, connectionId) THEN
+        [
+            chain |-> chain,
+            outcome |-> "ICS04MissingConnection"
+        ]
+    \* check if the channel exists (it shouldn't)
+    ELSE IF ICS04_ChannelExists(channels, channelIdCounter) THEN
+        \* if the channel to be created already exists,
+        \* then there's an error in the model
+        [
+            chain |-> chain,
+            outcome |-> "ModelError"
+        ]
+    ELSE
+        \* if it doesn't, create it; the channel identifier of the
+        \* counterparty is not known yet
+        LET channel == [
+            state |-> "Init",
+            order |-> order,
+            connectionId |-> connectionId,
+            counterpartyChannelId |-> ChannelIdNone,
+            nextSendSeq |-> 1,
+            nextRecvSeq |-> 1,
+            nextAckSeq |-> 1
+        ] IN
+        \* return result with updated state
+        [
+            chain |-> [chain EXCEPT
+                !.channels = ICS04_SetChannel(channels, channelIdCounter, channel),
+                !.channelIdCounter = channelIdCounter + 1
+            ],
+            outcome |-> "ICS04ChannelOpenInitOK"
+        ]
+
+\* the crossing hellos, where the channel was initialized on both chains, are
+\* not modelled: `ChannelOpenTry` always creates a new channel
+ICS04_ChannelOpenTry(
+    chain,
+    connectionId,
+    order,
+    counterpartyChannelId
+) ==
+    LET channels == chain.channels IN
+    LET channelIdCounter == chain.channelIdCounter IN
+    \* check if the connection exists
+    IF ~ICS03_ConnectionExists(chain.connections, connectionId) THEN
+        [
+            chain |-> chain,
+            outcome |-> "ICS04MissingConnection"
+        ]
+    \* check if the connection is open
+    ELSE IF ICS03_GetConnection(chain.connections, connectionId).state /= "Open"
+    THEN
+        [
+            chain |-> chain,
+            outcome |-> "ICS04ConnectionNotOpen"
+        ]
+    \* check if the channel exists (it shouldn't)
+    ELSE IF ICS04_ChannelExists(channels, channelIdCounter) THEN
+        [
+            chain |-> chain,
+            outcome |-> "ModelError"
+        ]
+    ELSE
+        LET channel == [
+            state |-> "TryOpen",
+            order |-> order,
+            connectionId |-> connectionId,
+            counterpartyChannelId |-> counterpartyChannelId,
+            nextSendSeq |-> 1,
+            nextRecvSeq |-> 1,
+            nextAckSeq |-> 1
+        ] IN
+        \* return result with updated state
+        [
+            chain |-> [chain EXCEPT
+                !.channels = ICS04_SetChannel(channels, channelIdCounter, channel),
+                !.channelIdCounter = channelIdCounter + 1
+            ],
+            outcome |-> "ICS04ChannelOpenTryOK"
+        ]
+
+ICS04_ChannelOpenAck(
+    chain,
+    channelId,
+    counterpartyChannelId
+) ==
+    LET channels == chain.channels IN
+    \* check if the channel exists
+    IF ~ICS04_ChannelExists(channels, channelId) THEN
+        [
+            chain |-> chain,
+            outcome |-> "ICS04ChannelNotFound"
+        ]
+    ELSE
+        LET channel == ICS04_GetChannel(channels, channelId) IN
+        \* check if the channel is either `Init` or `TryOpen`
+        IF channel.state \notin {"Init", "TryOpen"} THEN
+            [
+                chain |-> chain,
+                outcome |-> "ICS04InvalidChannelState"
+            ]
+        \* check if the connection of the channel is open
+        ELSE IF ~ICS04_ConnectionIsOpen(chain, channel) THEN
+            [
+                chain |-> chain,
+                outcome |-> "ICS04ConnectionNotOpen"
+            ]
+        ELSE
+            \* the channel identifier of the counterparty is now known
+            LET updatedChannel == [channel EXCEPT
+                !.state = "Open",
+                !.counterpartyChannelId = counterpartyChannelId
+            ] IN
+            \* return result with updated state
+            [
+                chain |-> [chain EXCEPT
+                    !.channels = ICS04_SetChannel(channels, channelId, updatedChannel)
+                ],
+                outcome |-> "ICS04ChannelOpenAckOK"
+            ]
+
+ICS04_ChannelOpenConfirm(
+    chain,
+    channelId
+) ==
+    LET channels == chain.channels IN
+    \* check if the channel exists
+    IF ~ICS04_ChannelExists(channels, channelId) THEN
+        [
+            chain |-> chain,
+            outcome |-> "ICS04ChannelNotFound"
+        ]
+    ELSE
+        LET channel == ICS04_GetChannel(channels, channelId) IN
+        \* check if the channel is `TryOpen`
+        IF channel.state /= "TryOpen" THEN
+            [
+                chain |-> chain,
+                outcome |-> "ICS04InvalidChannelState"
+            ]
+        \* check if the connection of the channel is open
+        ELSE IF ~ICS04_ConnectionIsOpen(chain, channel) THEN
+            [
+                chain |-> chain,
+                outcome |-> "ICS04ConnectionNotOpen"
+            ]
+        ELSE
+            LET updatedChannel == [channel EXCEPT
+                !.state = "Open"
+            ] IN
+            \* return result with updated state
+            [
+                chain |-> [chain EXCEPT
+                    !.channels = ICS04_SetChannel(channels, channelId, updatedChannel)
+                ],
+                outcome |-> "ICS04ChannelOpenConfirmOK"
+            ]
+
+ICS04_ChannelCloseInit(
+    chain,
+    channelId
+) ==
+    LET channels == chain.channels IN
+    \* check if the channel exists
+    IF ~ICS04_ChannelExists(channels, channelId) THEN
+        [
+            chain |-> chain,
+            outcome |-> "ICS04ChannelNotFound"
+        ]
+    ELSE
+        LET channel == ICS04_GetChannel(channels, channelId) IN
+        \* check if the channel is not closed yet
+        IF channel.state = "Closed" THEN
+            [
+                chain |-> chain,
+                outcome |-> "ICS04InvalidChannelState"
+            ]
+        \* check if the connection of the channel is open
+        ELSE IF ~ICS04_ConnectionIsOpen(chain, channel) THEN
+            [
+                chain |-> chain,
+                outcome |-> "ICS04ConnectionNotOpen"
+            ]
+        ELSE
+            LET updatedChannel == [channel EXCEPT
+                !.state = "Closed"
+            ] IN
+            \* return result with updated state
+            [
+                chain |-> [chain EXCEPT
+                    !.channels = ICS04_SetChannel(channels, channelId, updatedChannel)
+                ],
+                outcome |-> "ICS04ChannelCloseInitOK"
+            ]
+
+ICS04_ChannelCloseConfirm(
+    chain,
+    channelId
+) ==
+    LET channels == chain.channels IN
+    \* check if the channel exists
+    IF ~ICS04_ChannelExists(channels, channelId) THEN
+        [
+            chain |-> chain,
+            outcome |-> "ICS04ChannelNotFound"
+        ]
+    ELSE
+        LET channel == ICS04_GetChannel(channels, channelId) IN
+        \* check if the channel is not closed yet
+        IF channel.state = "Closed" THEN
+            [
+                chain |-> chain,
+                outcome |-> "ICS04ChannelAlreadyClosed"
+            ]
+        \* check if the connection of the channel is open
+        ELSE IF ~ICS04_ConnectionIsOpen(chain, channel) THEN
+            [
+                chain |-> chain,
+                outcome |-> "ICS04ConnectionNotOpen"
+            ]
+        \* check if the channel identifier of the counterparty is known, which
+        \* it is not if the channel was initialized on this chain and not
+        \* acknowledged yet, so that its channel end cannot be verified
+        ELSE IF channel.counterpartyChannelId = ChannelIdNone THEN
+            [
+                chain |-> chain,
+                outcome |-> "ICS04InvalidCounterpartyChannel"
+            ]
+        ELSE
+            LET updatedChannel == [channel EXCEPT
+                !.state = "Closed"
+            ] IN
+            \* return result with updated state
+            [
+                chain |-> [chain EXCEPT
+                    !.channels = ICS04_SetChannel(channels, channelId, updatedChannel)
+                ],
+                outcome |-> "ICS04ChannelCloseConfirmOK"
+            ]
+
+\* the packet sent is the next one on the channel, to the counterparty of the
+\* channel
+ICS04_SendPacket(
+    chain,
+    channelId,
+    timeoutHeight
+) ==
+    LET channels == chain.channels IN
+    \* check if the channel exists
+    IF ~ICS04_ChannelExists(channels, channelId) THEN
+        [
+            chain |-> chain,
+            outcome |-> "ICS04ChannelNotFound"
+        ]
+    ELSE
+        LET channel == ICS04_GetChannel(channels, channelId) IN
+        \* check if the channel is not closed
+        IF channel.state = "Closed" THEN
+            [
+                chain |-> chain,
+                outcome |-> "ICS04ChannelAlreadyClosed"
+            ]
+        \* check if the channel identifier of the counterparty, to which the
+        \* packet is sent, is known
+        ELSE IF channel.counterpartyChannelId = ChannelIdNone THEN
+            [
+                chain |-> chain,
+                outcome |-> "ICS04InvalidPacketCounterparty"
+            ]
+        \* check if the packet has not already timed out, according to the
+        \* client of the counterparty chain
+        ELSE IF ICS04_TimedOut(timeoutHeight, ICS04_ClientHeight(chain, channel))
+        THEN
+            [
+                chain |-> chain,
+                outcome |-> "ICS04LowPacketHeight"
+            ]
+        ELSE
+            LET packet == [
+                sourceChannelId |-> channelId,
+                destChannelId |-> channel.counterpartyChannelId,
+                sequence |-> channel.nextSendSeq,
+                timeoutHeight |-> timeoutHeight
+            ] IN
+            LET updatedChannel == [channel EXCEPT
+                !.nextSendSeq = @ + 1
+            ] IN
+            \* return result with updated state
+            [
+                chain |-> [chain EXCEPT
+                    !.channels = ICS04_SetChannel(channels, channelId, updatedChannel),
+                    !.packetCommitments = @ \union {packet}
+                ],
+                outcome |-> "ICS04PacketSendOK"
+            ]
+
+ICS04_RecvPacket(
+    chain,
+    packet
+) ==
+    LET channels == chain.channels IN
+    \* check if the destination channel exists
+    IF ~ICS04_ChannelExists(channels, packet.destChannelId) THEN
+        [
+            chain |-> chain,
+            outcome |-> "ICS04ChannelNotFound"
+        ]
+    ELSE
+        LET channel == ICS04_GetChannel(channels, packet.destChannelId) IN
+        \* check if the channel is open
+        IF channel.state /= "Open" THEN
+            [
+                chain |-> chain,
+                outcome |-> "ICS04InvalidChannelState"
+            ]
+        \* check if the packet comes from the counterparty of the channel
+        ELSE IF channel.counterpartyChannelId /= packet.sourceChannelId THEN
+            [
+                chain |-> chain,
+                outcome |-> "ICS04InvalidPacketCounterparty"
+            ]
+        \* check if the connection of the channel is open
+        ELSE IF ~ICS04_ConnectionIsOpen(chain, channel) THEN
+            [
+                chain |-> chain,
+                outcome |-> "ICS04ConnectionNotOpen"
+            ]
+        \* check if the packet has not timed out on this chain
+        ELSE IF ICS04_TimedOut(packet.timeoutHeight, chain.height) THEN
+            [
+                chain |-> chain,
+                outcome |-> "ICS04LowPacketHeight"
+            ]
+        \* on ordered channels, check if the packet is the next one
+        ELSE IF /\ channel.order = "Ordered"
+                /\ packet.sequence /= channel.nextRecvSeq THEN
+            [
+                chain |-> chain,
+                outcome |-> "ICS04InvalidPacketSequence"
+            ]
+        \* on unordered channels, check if the packet was not received yet
+        ELSE IF /\ channel.order = "Unordered"
+                /\ packet \in chain.packetReceipts THEN
+            [
+                chain |-> chain,
+                outcome |-> "ICS04PacketAlreadyReceived"
+            ]
+        ELSE
+            LET updatedChannel ==
+                IF channel.order = "Ordered" THEN
+                    [channel EXCEPT !.nextRecvSeq = @ + 1]
+                ELSE
+                    channel
+            IN
+            \* return result with updated state
+            [
+                chain |-> [chain EXCEPT
+                    !.channels =
+                        ICS04_SetChannel(channels, packet.destChannelId, updatedChannel),
+                    !.packetReceipts = @ \union {packet}
+                ],
+                outcome |-> "ICS04PacketRecvOK"
+            ]
+
+ICS04_AcknowledgePacket(
+    chain,
+    packet
+) ==
+    LET channels == chain.channels IN
+    \* check if the source channel exists
+    IF ~ICS04_ChannelExists(channels, packet.sourceChannelId) THEN
+        [
+            chain |-> chain,
+            outcome |-> "ICS04ChannelNotFound"
+        ]
+    ELSE
+        LET channel == ICS04_GetChannel(channels, packet.sourceChannelId) IN
+        \* check if the channel is open
+        IF channel.state /= "Open" THEN
+            [
+                chain |-> chain,
+                outcome |-> "ICS04InvalidChannelState"
+            ]
+        \* check if the packet was sent to the counterparty of the channel
+        ELSE IF channel.counterpartyChannelId /= packet.destChannelId THEN
+            [
+                chain |-> chain,
+                outcome |-> "ICS04InvalidPacketCounterparty"
+            ]
+        \* check if the connection of the channel is open
+        ELSE IF ~ICS04_ConnectionIsOpen(chain, channel) THEN
+            [
+                chain |-> chain,
+                outcome |-> "ICS04ConnectionNotOpen"
+            ]
+        \* check if the packet was sent, and neither acknowledged nor timed out
+        ELSE IF packet \notin chain.packetCommitments THEN
+            [
+                chain |-> chain,
+                outcome |-> "ICS04PacketCommitmentNotFound"
+            ]
+        \* on ordered channels, check if the packet is the next one
+        ELSE IF /\ channel.order = "Ordered"
+                /\ packet.sequence /= channel.nextAckSeq THEN
+            [
+                chain |-> chain,
+                outcome |-> "ICS04InvalidPacketSequence"
+            ]
+        ELSE
+            LET updatedChannel ==
+                IF channel.order = "Ordered" THEN
+                    [channel EXCEPT !.nextAckSeq = @ + 1]
+                ELSE
+                    channel
+            IN
+            \* return result with updated state
+            [
+                chain |-> [chain EXCEPT
+                    !.channels =
+                        ICS04_SetChannel(channels, packet.sourceChannelId, updatedChannel),
+                    !.packetCommitments = @ \ {packet},
+                    !.acknowledgedPackets = @ \union {packet}
+                ],
+                outcome |-> "ICS04PacketAckOK"
+            ]
+
+\* `nextSequenceRecv` is the next sequence to be received by the counterparty
+\* channel, which only matters on ordered channels; the proof that the packet
+\* was not received is not verified by the mock client
+ICS04_TimeoutPacket(
+    chain,
+    packet,
+    nextSequenceRecv
+) ==
+    LET channels == chain.channels IN
+    \* check if the source channel exists
+    IF ~ICS04_ChannelExists(channels, packet.sourceChannelId) THEN
+        [
+            chain |-> chain,
+            outcome |-> "ICS04ChannelNotFound"
+        ]
+    ELSE
+        LET channel == ICS04_GetChannel(channels, packet.sourceChannelId) IN
+        \* check if the channel is open
+        IF channel.state /= "Open" THEN
+            [
+                chain |-> chain,
+                outcome |-> "ICS04InvalidChannelState"
+            ]
+        \* check if the packet was sent to the counterparty of the channel
+        ELSE IF channel.counterpartyChannelId /= packet.destChannelId THEN
+            [
+                chain |-> chain,
+                outcome |-> "ICS04InvalidPacketCounterparty"
+            ]
+        \* check if the packet has timed out on the counterparty chain,
+        \* according to its client
+        ELSE IF ~ICS04_TimedOut(
+                    packet.timeoutHeight,
+                    ICS04_ClientHeight(chain, channel)
+                ) THEN
+            [
+                chain |-> chain,
+                outcome |-> "ICS04PacketTimeoutNotReached"
+            ]
+        \* check if the packet was sent, and neither acknowledged nor timed out
+        ELSE IF packet \notin chain.packetCommitments THEN
+            [
+                chain |-> chain,
+                outcome |-> "ICS04PacketCommitmentNotFound"
+            ]
+        \* on ordered channels, check if the packet was not received yet
+        ELSE IF /\ channel.order = "Ordered"
+                /\ packet.sequence < nextSequenceRecv THEN
+            [
+                chain |-> chain,
+                outcome |-> "ICS04InvalidPacketSequence"
+            ]
+        ELSE
+            \* a timeout closes ordered channels
+            LET updatedChannel ==
+                IF channel.order = "Ordered" THEN
+                    [channel EXCEPT !.state = "Closed"]
+                ELSE
+                    channel
+            IN
+            \* return result with updated state
+            [
+                chain |-> [chain EXCEPT
+                    !.channels =
+                        ICS04_SetChannel(channels, packet.sourceChannelId, updatedChannel),
+                    !.packetCommitments = @ \ {packet},
+                    !.timedOutPackets = @ \union {packet}
+                ],
+                outcome |-> "ICS04PacketTimeoutOK"
+            ]
+
+===============================================================================
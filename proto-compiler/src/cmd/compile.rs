@@ -89,6 +89,24 @@ impl CompileCmd {
             .extern_path(".tendermint", "::tendermint_proto")
             .compile(&protos, &includes)
             .unwrap();
+
+        // Recompile the IBC core protos with the servers too, so that hosts written in Rust can
+        // serve the queries relayers rely on. This is not done for all protos, as the server of
+        // the Cosmos SDK tx `Service` clashes with the tower `Service` trait.
+        let core_protos: Vec<PathBuf> = protos
+            .iter()
+            .filter(|proto| proto.starts_with(format!("{}/proto/ibc/core", sdk_dir.display())))
+            .cloned()
+            .collect();
+
+        tonic_build::configure()
+            .build_client(true)
+            .build_server(true)
+            .format(false)
+            .out_dir(out_dir)
+            .extern_path(".tendermint", "::tendermint_proto")
+            .compile(&core_protos, &includes)
+            .unwrap();
     }
 
     fn copy_generated_files(from_dir: &Path, to_dir: &Path) {
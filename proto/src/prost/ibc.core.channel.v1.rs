@@ -379,7 +379,7 @@ pub struct MsgAcknowledgement {
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MsgAcknowledgementResponse {
 }
-# [doc = r" Generated client implementations."] pub mod msg_client { # ! [allow (unused_variables , dead_code , missing_docs)] use tonic :: codegen :: * ; # [doc = " Msg defines the ibc/channel Msg service."] pub struct MsgClient < T > { inner : tonic :: client :: Grpc < T > , } impl MsgClient < tonic :: transport :: Channel > { # [doc = r" Attempt to create a new client by connecting to a given endpoint."] pub async fn connect < D > (dst : D) -> Result < Self , tonic :: transport :: Error > where D : std :: convert :: TryInto < tonic :: transport :: Endpoint > , D :: Error : Into < StdError > , { let conn = tonic :: transport :: Endpoint :: new (dst) ? . connect () . await ? ; Ok (Self :: new (conn)) } } impl < T > MsgClient < T > where T : tonic :: client :: GrpcService < tonic :: body :: BoxBody > , T :: ResponseBody : Body + HttpBody + Send + 'static , T :: Error : Into < StdError > , < T :: ResponseBody as HttpBody > :: Error : Into < StdError > + Send , { pub fn new (inner : T) -> Self { let inner = tonic :: client :: Grpc :: new (inner) ; Self { inner } } pub fn with_interceptor (inner : T , interceptor : impl Into < tonic :: Interceptor >) -> Self { let inner = tonic :: client :: Grpc :: with_interceptor (inner , interceptor) ; Self { inner } } # [doc = " ChannelOpenInit defines a rpc handler method for MsgChannelOpenInit."] pub async fn channel_open_init (& mut self , request : impl tonic :: IntoRequest < super :: MsgChannelOpenInit > ,) -> Result < tonic :: Response < super :: MsgChannelOpenInitResponse > , tonic :: Status > { self . inner . ready () . await . map_err (| e | { tonic :: Status :: new (tonic :: Code :: Unknown , format ! ("Service was not ready: {}" , e . into ())) }) ? ; let codec = tonic :: codec :: ProstCodec :: default () ; let path = http :: uri :: PathAndQuery :: from_static ("/ibc.core.channel.v1.Msg/ChannelOpenInit") ; self . inner . unary (request . into_request () , path , codec) . await } # [doc = " ChannelOpenTry defines a rpc handler method for MsgChannelOpenTry."] pub async fn channel_open_try (& mut self , request : impl tonic :: IntoRequest < super :: MsgChannelOpenTry > ,) -> Result < tonic :: Response < super :: MsgChannelOpenTryResponse > , tonic :: Status > { self . inner . ready () . await . map_err (| e | { tonic :: Status :: new (tonic :: Code :: Unknown , format ! ("Service was not ready: {}" , e . into ())) }) ? ; let codec = tonic :: codec :: ProstCodec :: default () ; let path = http :: uri :: PathAndQuery :: from_static ("/ibc.core.channel.v1.Msg/ChannelOpenTry") ; self . inner . unary (request . into_request () , path , codec) . await } # [doc = " ChannelOpenAck defines a rpc handler method for MsgChannelOpenAck."] pub async fn channel_open_ack (& mut self , request : impl tonic :: IntoRequest < super :: MsgChannelOpenAck > ,) -> Result < tonic :: Response < super :: MsgChannelOpenAckResponse > , tonic :: Status > { self . inner . ready () . await . map_err (| e | { tonic :: Status :: new (tonic :: Code :: Unknown , format ! ("Service was not ready: {}" , e . into ())) }) ? ; let codec = tonic :: codec :: ProstCodec :: default () ; let path = http :: uri :: PathAndQuery :: from_static ("/ibc.core.channel.v1.Msg/ChannelOpenAck") ; self . inner . unary (request . into_request () , path , codec) . await } # [doc = " ChannelOpenConfirm defines a rpc handler method for MsgChannelOpenConfirm."] pub async fn channel_open_confirm (& mut self , request : impl tonic :: IntoRequest < super :: MsgChannelOpenConfirm > ,) -> Result < tonic :: Response < super :: MsgChannelOpenConfirmResponse > , tonic :: Status > { self . inner . ready () . await . map_err (| e | { tonic :: Status :: new (tonic :: Code :: Unknown , format ! ("Service was not ready: {}" , e . into ())) }) ? ; let codec = tonic :: codec :: ProstCodec :: default () ; let path = http :: uri :: PathAndQuery :: from_static ("/ibc.core.channel.v1.Msg/ChannelOpenConfirm") ; self . inner . unary (request . into_request () , path , codec) . await } # [doc = " ChannelCloseInit defines a rpc handler method for MsgChannelCloseInit."] pub async fn channel_close_init (& mut self , request : impl tonic :: IntoRequest < super :: MsgChannelCloseInit > ,) -> Result < tonic :: Response < super :: MsgChannelCloseInitResponse > , tonic :: Status > { self . inner . ready () . await . map_err (| e | { tonic :: Status :: new (tonic :: Code :: Unknown , format ! ("Service was not ready: {}" , e . into ())) }) ? ; let codec = tonic :: codec :: ProstCodec :: default () ; let path = http :: uri :: PathAndQuery :: from_static ("/ibc.core.channel.v1.Msg/ChannelCloseInit") ; self . inner . unary (request . into_request () , path , codec) . await } # [doc = " ChannelCloseConfirm defines a rpc handler method for MsgChannelCloseConfirm."] pub async fn channel_close_confirm (& mut self , request : impl tonic :: IntoRequest < super :: MsgChannelCloseConfirm > ,) -> Result < tonic :: Response < super :: MsgChannelCloseConfirmResponse > , tonic :: Status > { self . inner . ready () . await . map_err (| e | { tonic :: Status :: new (tonic :: Code :: Unknown , format ! ("Service was not ready: {}" , e . into ())) }) ? ; let codec = tonic :: codec :: ProstCodec :: default () ; let path = http :: uri :: PathAndQuery :: from_static ("/ibc.core.channel.v1.Msg/ChannelCloseConfirm") ; self . inner . unary (request . into_request () , path , codec) . await } # [doc = " RecvPacket defines a rpc handler method for MsgRecvPacket."] pub async fn recv_packet (& mut self , request : impl tonic :: IntoRequest < super :: MsgRecvPacket > ,) -> Result < tonic :: Response < super :: MsgRecvPacketResponse > , tonic :: Status > { self . inner . ready () . await . map_err (| e | { tonic :: Status :: new (tonic :: Code :: Unknown , format ! ("Service was not ready: {}" , e . into ())) }) ? ; let codec = tonic :: codec :: ProstCodec :: default () ; let path = http :: uri :: PathAndQuery :: from_static ("/ibc.core.channel.v1.Msg/RecvPacket") ; self . inner . unary (request . into_request () , path , codec) . await } # [doc = " Timeout defines a rpc handler method for MsgTimeout."] pub async fn timeout (& mut self , request : impl tonic :: IntoRequest < super :: MsgTimeout > ,) -> Result < tonic :: Response < super :: MsgTimeoutResponse > , tonic :: Status > { self . inner . ready () . await . map_err (| e | { tonic :: Status :: new (tonic :: Code :: Unknown , format ! ("Service was not ready: {}" , e . into ())) }) ? ; let codec = tonic :: codec :: ProstCodec :: default () ; let path = http :: uri :: PathAndQuery :: from_static ("/ibc.core.channel.v1.Msg/Timeout") ; self . inner . unary (request . into_request () , path , codec) . await } # [doc = " TimeoutOnClose defines a rpc handler method for MsgTimeoutOnClose."] pub async fn timeout_on_close (& mut self , request : impl tonic :: IntoRequest < super :: MsgTimeoutOnClose > ,) -> Result < tonic :: Response < super :: MsgTimeoutOnCloseResponse > , tonic :: Status > { self . inner . ready () . await . map_err (| e | { tonic :: Status :: new (tonic :: Code :: Unknown , format ! ("Service was not ready: {}" , e . into ())) }) ? ; let codec = tonic :: codec :: ProstCodec :: default () ; let path = http :: uri :: PathAndQuery :: from_static ("/ibc.core.channel.v1.Msg/TimeoutOnClose") ; self . inner . unary (request . into_request () , path , codec) . await } # [doc = " Acknowledgement defines a rpc handler method for MsgAcknowledgement."] pub async fn acknowledgement (& mut self , request : impl tonic :: IntoRequest < super :: MsgAcknowledgement > ,) -> Result < tonic :: Response < super :: MsgAcknowledgementResponse > , tonic :: Status > { self . inner . ready () . await . map_err (| e | { tonic :: Status :: new (tonic :: Code :: Unknown , format ! ("Service was not ready: {}" , e . into ())) }) ? ; let codec = tonic :: codec :: ProstCodec :: default () ; let path = http :: uri :: PathAndQuery :: from_static ("/ibc.core.channel.v1.Msg/Acknowledgement") ; self . inner . unary (request . into_request () , path , codec) . await } } impl < T : Clone > Clone for MsgClient < T > { fn clone (& self) -> Self { Self { inner : self . inner . clone () , } } } impl < T > std :: fmt :: Debug for MsgClient < T > { fn fmt (& self , f : & mut std :: fmt :: Formatter < '_ >) -> std :: fmt :: Result { write ! (f , "MsgClient {{ ... }}") } } }/// QueryChannelRequest is the request type for the Query/Channel RPC method
+# [doc = r" Generated client implementations."] pub mod msg_client { # ! [allow (unused_variables , dead_code , missing_docs)] use tonic :: codegen :: * ; # [doc = " Msg defines the ibc/channel Msg service."] pub struct MsgClient < T > { inner : tonic :: client :: Grpc < T > , } impl MsgClient < tonic :: transport :: Channel > { # [doc = r" Attempt to create a new client by connecting to a given endpoint."] pub async fn connect < D > (dst : D) -> Result < Self , tonic :: transport :: Error > where D : std :: convert :: TryInto < tonic :: transport :: Endpoint > , D :: Error : Into < StdError > , { let conn = tonic :: transport :: Endpoint :: new (dst) ? . connect () . await ? ; Ok (Self :: new (conn)) } } impl < T > MsgClient < T > where T : tonic :: client :: GrpcService < tonic :: body :: BoxBody > , T :: ResponseBody : Body + HttpBody + Send + 'static , T :: Error : Into < StdError > , < T :: ResponseBody as HttpBody > :: Error : Into < StdError > + Send , { pub fn new (inner : T) -> Self { let inner = tonic :: client :: Grpc :: new (inner) ; Self { inner } } pub fn with_interceptor (inner : T , interceptor : impl Into < tonic :: Interceptor >) -> Self { let inner = tonic :: client :: Grpc :: with_interceptor (inner , interceptor) ; Self { inner } } # [doc = " ChannelOpenInit defines a rpc handler method for MsgChannelOpenInit."] pub async fn channel_open_init (& mut self , request : impl tonic :: IntoRequest < super :: MsgChannelOpenInit > ,) -> Result < tonic :: Response < super :: MsgChannelOpenInitResponse > , tonic :: Status > { self . inner . ready () . await . map_err (| e | { tonic :: Status :: new (tonic :: Code :: Unknown , format ! ("Service was not ready: {}" , e . into ())) }) ? ; let codec = tonic :: codec :: ProstCodec :: default () ; let path = http :: uri :: PathAndQuery :: from_static ("/ibc.core.channel.v1.Msg/ChannelOpenInit") ; self . inner . unary (request . into_request () , path , codec) . await } # [doc = " ChannelOpenTry defines a rpc handler method for MsgChannelOpenTry."] pub async fn channel_open_try (& mut self , request : impl tonic :: IntoRequest < super :: MsgChannelOpenTry > ,) -> Result < tonic :: Response < super :: MsgChannelOpenTryResponse > , tonic :: Status > { self . inner . ready () . await . map_err (| e | { tonic :: Status :: new (tonic :: Code :: Unknown , format ! ("Service was not ready: {}" , e . into ())) }) ? ; let codec = tonic :: codec :: ProstCodec :: default () ; let path = http :: uri :: PathAndQuery :: from_static ("/ibc.core.channel.v1.Msg/ChannelOpenTry") ; self . inner . unary (request . into_request () , path , codec) . await } # [doc = " ChannelOpenAck defines a rpc handler method for MsgChannelOpenAck."] pub async fn channel_open_ack (& mut self , request : impl tonic :: IntoRequest < super :: MsgChannelOpenAck > ,) -> Result < tonic :: Response < super :: MsgChannelOpenAckResponse > , tonic :: Status > { self . inner . ready () . await . map_err (| e | { tonic :: Status :: new (tonic :: Code :: Unknown , format ! ("Service was not ready: {}" , e . into ())) }) ? ; let codec = tonic :: codec :: ProstCodec :: default () ; let path = http :: uri :: PathAndQuery :: from_static ("/ibc.core.channel.v1.Msg/ChannelOpenAck") ; self . inner . unary (request . into_request () , path , codec) . await } # [doc = " ChannelOpenConfirm defines a rpc handler method for MsgChannelOpenConfirm."] pub async fn channel_open_confirm (& mut self , request : impl tonic :: IntoRequest < super :: MsgChannelOpenConfirm > ,) -> Result < tonic :: Response < super :: MsgChannelOpenConfirmResponse > , tonic :: Status > { self . inner . ready () . await . map_err (| e | { tonic :: Status :: new (tonic :: Code :: Unknown , format ! ("Service was not ready: {}" , e . into ())) }) ? ; let codec = tonic :: codec :: ProstCodec :: default () ; let path = http :: uri :: PathAndQuery :: from_static ("/ibc.core.channel.v1.Msg/ChannelOpenConfirm") ; self . inner . unary (request . into_request () , path , codec) . await } # [doc = " ChannelCloseInit defines a rpc handler method for MsgChannelCloseInit."] pub async fn channel_close_init (& mut self , request : impl tonic :: IntoRequest < super :: MsgChannelCloseInit > ,) -> Result < tonic :: Response < super :: MsgChannelCloseInitResponse > , tonic :: Status > { self . inner . ready () . await . map_err (| e | { tonic :: Status :: new (tonic :: Code :: Unknown , format ! ("Service was not ready: {}" , e . into ())) }) ? ; let codec = tonic :: codec :: ProstCodec :: default () ; let path = http :: uri :: PathAndQuery :: from_static ("/ibc.core.channel.v1.Msg/ChannelCloseInit") ; self . inner . unary (request . into_request () , path , codec) . await } # [doc = " ChannelCloseConfirm defines a rpc handler method for MsgChannelCloseConfirm."] pub async fn channel_close_confirm (& mut self , request : impl tonic :: IntoRequest < super :: MsgChannelCloseConfirm > ,) -> Result < tonic :: Response < super :: MsgChannelCloseConfirmResponse > , tonic :: Status > { self . inner . ready () . await . map_err (| e | { tonic :: Status :: new (tonic :: Code :: Unknown , format ! ("Service was not ready: {}" , e . into ())) }) ? ; let codec = tonic :: codec :: ProstCodec :: default () ; let path = http :: uri :: PathAndQuery :: from_static ("/ibc.core.channel.v1.Msg/ChannelCloseConfirm") ; self . inner . unary (request . into_request () , path , codec) . await } # [doc = " RecvPacket defines a rpc handler method for MsgRecvPacket."] pub async fn recv_packet (& mut self , request : impl tonic :: IntoRequest < super :: MsgRecvPacket > ,) -> Result < tonic :: Response < super :: MsgRecvPacketResponse > , tonic :: Status > { self . inner . ready () . await . map_err (| e | { tonic :: Status :: new (tonic :: Code :: Unknown , format ! ("Service was not ready: {}" , e . into ())) }) ? ; let codec = tonic :: codec :: ProstCodec :: default () ; let path = http :: uri :: PathAndQuery :: from_static ("/ibc.core.channel.v1.Msg/RecvPacket") ; self . inner . unary (request . into_request () , path , codec) . await } # [doc = " Timeout defines a rpc handler method for MsgTimeout."] pub async fn timeout (& mut self , request : impl tonic :: IntoRequest < super :: MsgTimeout > ,) -> Result < tonic :: Response < super :: MsgTimeoutResponse > , tonic :: Status > { self . inner . ready () . await . map_err (| e | { tonic :: Status :: new (tonic :: Code :: Unknown , format ! ("Service was not ready: {}" , e . into ())) }) ? ; let codec = tonic :: codec :: ProstCodec :: default () ; let path = http :: uri :: PathAndQuery :: from_static ("/ibc.core.channel.v1.Msg/Timeout") ; self . inner . unary (request . into_request () , path , codec) . await } # [doc = " TimeoutOnClose defines a rpc handler method for MsgTimeoutOnClose."] pub async fn timeout_on_close (& mut self , request : impl tonic :: IntoRequest < super :: MsgTimeoutOnClose > ,) -> Result < tonic :: Response < super :: MsgTimeoutOnCloseResponse > , tonic :: Status > { self . inner . ready () . await . map_err (| e | { tonic :: Status :: new (tonic :: Code :: Unknown , format ! ("Service was not ready: {}" , e . into ())) }) ? ; let codec = tonic :: codec :: ProstCodec :: default () ; let path = http :: uri :: PathAndQuery :: from_static ("/ibc.core.channel.v1.Msg/TimeoutOnClose") ; self . inner . unary (request . into_request () , path , codec) . await } # [doc = " Acknowledgement defines a rpc handler method for MsgAcknowledgement."] pub async fn acknowledgement (& mut self , request : impl tonic :: IntoRequest < super :: MsgAcknowledgement > ,) -> Result < tonic :: Response < super :: MsgAcknowledgementResponse > , tonic :: Status > { self . inner . ready () . await . map_err (| e | { tonic :: Status :: new (tonic :: Code :: Unknown , format ! ("Service was not ready: {}" , e . into ())) }) ? ; let codec = tonic :: codec :: ProstCodec :: default () ; let path = http :: uri :: PathAndQuery :: from_static ("/ibc.core.channel.v1.Msg/Acknowledgement") ; self . inner . unary (request . into_request () , path , codec) . await } } impl < T : Clone > Clone for MsgClient < T > { fn clone (& self) -> Self { Self { inner : self . inner . clone () , } } } impl < T > std :: fmt :: Debug for MsgClient < T > { fn fmt (& self , f : & mut std :: fmt :: Formatter < '_ >) -> std :: fmt :: Result { write ! (f , "MsgClient {{ ... }}") } } }# [doc = r" Generated server implementations."] pub mod msg_server { # ! [allow (unused_variables , dead_code , missing_docs)] use tonic :: codegen :: * ; # [doc = "Generated trait containing gRPC methods that should be implemented for use with MsgServer."] # [async_trait] pub trait Msg : Send + Sync + 'static { # [doc = " ChannelOpenInit defines a rpc handler method for MsgChannelOpenInit."] async fn channel_open_init (& self , request : tonic :: Request < super :: MsgChannelOpenInit >) -> Result < tonic :: Response < super :: MsgChannelOpenInitResponse > , tonic :: Status > ; # [doc = " ChannelOpenTry defines a rpc handler method for MsgChannelOpenTry."] async fn channel_open_try (& self , request : tonic :: Request < super :: MsgChannelOpenTry >) -> Result < tonic :: Response < super :: MsgChannelOpenTryResponse > , tonic :: Status > ; # [doc = " ChannelOpenAck defines a rpc handler method for MsgChannelOpenAck."] async fn channel_open_ack (& self , request : tonic :: Request < super :: MsgChannelOpenAck >) -> Result < tonic :: Response < super :: MsgChannelOpenAckResponse > , tonic :: Status > ; # [doc = " ChannelOpenConfirm defines a rpc handler method for MsgChannelOpenConfirm."] async fn channel_open_confirm (& self , request : tonic :: Request < super :: MsgChannelOpenConfirm >) -> Result < tonic :: Response < super :: MsgChannelOpenConfirmResponse > , tonic :: Status > ; # [doc = " ChannelCloseInit defines a rpc handler method for MsgChannelCloseInit."] async fn channel_close_init (& self , request : tonic :: Request < super :: MsgChannelCloseInit >) -> Result < tonic :: Response < super :: MsgChannelCloseInitResponse > , tonic :: Status > ; # [doc = " ChannelCloseConfirm defines a rpc handler method for MsgChannelCloseConfirm."] async fn channel_close_confirm (& self , request : tonic :: Request < super :: MsgChannelCloseConfirm >) -> Result < tonic :: Response < super :: MsgChannelCloseConfirmResponse > , tonic :: Status > ; # [doc = " RecvPacket defines a rpc handler method for MsgRecvPacket."] async fn recv_packet (& self , request : tonic :: Request < super :: MsgRecvPacket >) -> Result < tonic :: Response < super :: MsgRecvPacketResponse > , tonic :: Status > ; # [doc = " Timeout defines a rpc handler method for MsgTimeout."] async fn timeout (& self , request : tonic :: Request < super :: MsgTimeout >) -> Result < tonic :: Response < super :: MsgTimeoutResponse > , tonic :: Status > ; # [doc = " TimeoutOnClose defines a rpc handler method for MsgTimeoutOnClose."] async fn timeout_on_close (& self , request : tonic :: Request < super :: MsgTimeoutOnClose >) -> Result < tonic :: Response < super :: MsgTimeoutOnCloseResponse > , tonic :: Status > ; # [doc = " Acknowledgement defines a rpc handler method for MsgAcknowledgement."] async fn acknowledgement (& self , request : tonic :: Request < super :: MsgAcknowledgement >) -> Result < tonic :: Response < super :: MsgAcknowledgementResponse > , tonic :: Status > ; } # [doc = " Msg defines the ibc/channel Msg service."] # [derive (Debug)] pub struct MsgServer < T : Msg > { inner : _Inner < T > , } struct _Inner < T > (Arc < T > , Option < tonic :: Interceptor >) ; impl < T : Msg > MsgServer < T > { pub fn new (inner : T) -> Self { let inner = Arc :: new (inner) ; let inner = _Inner (inner , None) ; Self { inner } } pub fn with_interceptor (inner : T , interceptor : impl Into < tonic :: Interceptor >) -> Self { let inner = Arc :: new (inner) ; let inner = _Inner (inner , Some (interceptor . into ())) ; Self { inner } } } impl < T , B > Service < http :: Request < B >> for MsgServer < T > where T : Msg , B : HttpBody + Send + Sync + 'static , B :: Error : Into < StdError > + Send + 'static , { type Response = http :: Response < tonic :: body :: BoxBody > ; type Error = Never ; type Future = BoxFuture < Self :: Response , Self :: Error > ; fn poll_ready (& mut self , _cx : & mut Context < '_ >) -> Poll < Result < () , Self :: Error >> { Poll :: Ready (Ok (())) } fn call (& mut self , req : http :: Request < B >) -> Self :: Future { let inner = self . inner . clone () ; match req . uri () . path () { "/ibc.core.channel.v1.Msg/ChannelOpenInit" => { # [allow (non_camel_case_types)] struct ChannelOpenInitSvc < T : Msg > (pub Arc < T >) ; impl < T : Msg > tonic :: server :: UnaryService < super :: MsgChannelOpenInit > for ChannelOpenInitSvc < T > { type Response = super :: MsgChannelOpenInitResponse ; type Future = BoxFuture < tonic :: Response < Self :: Response > , tonic :: Status > ; fn call (& mut self , request : tonic :: Request < super :: MsgChannelOpenInit >) -> Self :: Future { let inner = self . 0 . clone () ; let fut = async move { (* inner) . channel_open_init (request) . await } ; Box :: pin (fut) } } let inner = self . inner . clone () ; let fut = async move { let interceptor = inner . 1 . clone () ; let inner = inner . 0 ; let method = ChannelOpenInitSvc (inner) ; let codec = tonic :: codec :: ProstCodec :: default () ; let mut grpc = if let Some (interceptor) = interceptor { tonic :: server :: Grpc :: with_interceptor (codec , interceptor) } else { tonic :: server :: Grpc :: new (codec) } ; let res = grpc . unary (method , req) . await ; Ok (res) } ; Box :: pin (fut) } "/ibc.core.channel.v1.Msg/ChannelOpenTry" => { # [allow (non_camel_case_types)] struct ChannelOpenTrySvc < T : Msg > (pub Arc < T >) ; impl < T : Msg > tonic :: server :: UnaryService < super :: MsgChannelOpenTry > for ChannelOpenTrySvc < T > { type Response = super :: MsgChannelOpenTryResponse ; type Future = BoxFuture < tonic :: Response < Self :: Response > , tonic :: Status > ; fn call (& mut self , request : tonic :: Request < super :: MsgChannelOpenTry >) -> Self :: Future { let inner = self . 0 . clone () ; let fut = async move { (* inner) . channel_open_try (request) . await } ; Box :: pin (fut) } } let inner = self . inner . clone () ; let fut = async move { let interceptor = inner . 1 . clone () ; let inner = inner . 0 ; let method = ChannelOpenTrySvc (inner) ; let codec = tonic :: codec :: ProstCodec :: default () ; let mut grpc = if let Some (interceptor) = interceptor { tonic :: server :: Grpc :: with_interceptor (codec , interceptor) } else { tonic :: server :: Grpc :: new (codec) } ; let res = grpc . unary (method , req) . await ; Ok (res) } ; Box :: pin (fut) } "/ibc.core.channel.v1.Msg/ChannelOpenAck" => { # [allow (non_camel_case_types)] struct ChannelOpenAckSvc < T : Msg > (pub Arc < T >) ; impl < T : Msg > tonic :: server :: UnaryService < super :: MsgChannelOpenAck > for ChannelOpenAckSvc < T > { type Response = super :: MsgChannelOpenAckResponse ; type Future = BoxFuture < tonic :: Response < Self :: Response > , tonic :: Status > ; fn call (& mut self , request : tonic :: Request < super :: MsgChannelOpenAck >) -> Self :: Future { let inner = self . 0 . clone () ; let fut = async move { (* inner) . channel_open_ack (request) . await } ; Box :: pin (fut) } } let inner = self . inner . clone () ; let fut = async move { let interceptor = inner . 1 . clone () ; let inner = inner . 0 ; let method = ChannelOpenAckSvc (inner) ; let codec = tonic :: codec :: ProstCodec :: default () ; let mut grpc = if let Some (interceptor) = interceptor { tonic :: server :: Grpc :: with_interceptor (codec , interceptor) } else { tonic :: server :: Grpc :: new (codec) } ; let res = grpc . unary (method , req) . await ; Ok (res) } ; Box :: pin (fut) } "/ibc.core.channel.v1.Msg/ChannelOpenConfirm" => { # [allow (non_camel_case_types)] struct ChannelOpenConfirmSvc < T : Msg > (pub Arc < T >) ; impl < T : Msg > tonic :: server :: UnaryService < super :: MsgChannelOpenConfirm > for ChannelOpenConfirmSvc < T > { type Response = super :: MsgChannelOpenConfirmResponse ; type Future = BoxFuture < tonic :: Response < Self :: Response > , tonic :: Status > ; fn call (& mut self , request : tonic :: Request < super :: MsgChannelOpenConfirm >) -> Self :: Future { let inner = self . 0 . clone () ; let fut = async move { (* inner) . channel_open_confirm (request) . await } ; Box :: pin (fut) } } let inner = self . inner . clone () ; let fut = async move { let interceptor = inner . 1 . clone () ; let inner = inner . 0 ; let method = ChannelOpenConfirmSvc (inner) ; let codec = tonic :: codec :: ProstCodec :: default () ; let mut grpc = if let Some (interceptor) = interceptor { tonic :: server :: Grpc :: with_interceptor (codec , interceptor) } else { tonic :: server :: Grpc :: new (codec) } ; let res = grpc . unary (method , req) . await ; Ok (res) } ; Box :: pin (fut) } "/ibc.core.channel.v1.Msg/ChannelCloseInit" => { # [allow (non_camel_case_types)] struct ChannelCloseInitSvc < T : Msg > (pub Arc < T >) ; impl < T : Msg > tonic :: server :: UnaryService < super :: MsgChannelCloseInit > for ChannelCloseInitSvc < T > { type Response = super :: MsgChannelCloseInitResponse ; type Future = BoxFuture < tonic :: Response < Self :: Response > , tonic :: Status > ; fn call (& mut self , request : tonic :: Request < super :: MsgChannelCloseInit >) -> Self :: Future { let inner = self . 0 . clone () ; let fut = async move { (* inner) . channel_close_init (request) . await } ; Box :: pin (fut) } } let inner = self . inner . clone () ; let fut = async move { let interceptor = inner . 1 . clone () ; let inner = inner . 0 ; let method = ChannelCloseInitSvc (inner) ; let codec = tonic :: codec :: ProstCodec :: default () ; let mut grpc = if let Some (interceptor) = interceptor { tonic :: server :: Grpc :: with_interceptor (codec , interceptor) } else { tonic :: server :: Grpc :: new (codec) } ; let res = grpc . unary (method , req) . await ; Ok (res) } ; Box :: pin (fut) } "/ibc.core.channel.v1.Msg/ChannelCloseConfirm" => { # [allow (non_camel_case_types)] struct ChannelCloseConfirmSvc < T : Msg > (pub Arc < T >) ; impl < T : Msg > tonic :: server :: UnaryService < super :: MsgChannelCloseConfirm > for ChannelCloseConfirmSvc < T > { type Response = super :: MsgChannelCloseConfirmResponse ; type Future = BoxFuture < tonic :: Response < Self :: Response > , tonic :: Status > ; fn call (& mut self , request : tonic :: Request < super :: MsgChannelCloseConfirm >) -> Self :: Future { let inner = self . 0 . clone () ; let fut = async move { (* inner) . channel_close_confirm (request) . await } ; Box :: pin (fut) } } let inner = self . inner . clone () ; let fut = async move { let interceptor = inner . 1 . clone () ; let inner = inner . 0 ; let method = ChannelCloseConfirmSvc (inner) ; let codec = tonic :: codec :: ProstCodec :: default () ; let mut grpc = if let Some (interceptor) = interceptor { tonic :: server :: Grpc :: with_interceptor (codec , interceptor) } else { tonic :: server :: Grpc :: new (codec) } ; let res = grpc . unary (method , req) . await ; Ok (res) } ; Box :: pin (fut) } "/ibc.core.channel.v1.Msg/RecvPacket" => { # [allow (non_camel_case_types)] struct RecvPacketSvc < T : Msg > (pub Arc < T >) ; impl < T : Msg > tonic :: server :: UnaryService < super :: MsgRecvPacket > for RecvPacketSvc < T > { type Response = super :: MsgRecvPacketResponse ; type Future = BoxFuture < tonic :: Response < Self :: Response > , tonic :: Status > ; fn call (& mut self , request : tonic :: Request < super :: MsgRecvPacket >) -> Self :: Future { let inner = self . 0 . clone () ; let fut = async move { (* inner) . recv_packet (request) . await } ; Box :: pin (fut) } } let inner = self . inner . clone () ; let fut = async move { let interceptor = inner . 1 . clone () ; let inner = inner . 0 ; let method = RecvPacketSvc (inner) ; let codec = tonic :: codec :: ProstCodec :: default () ; let mut grpc = if let Some (interceptor) = interceptor { tonic :: server :: Grpc :: with_interceptor (codec , interceptor) } else { tonic :: server :: Grpc :: new (codec) } ; let res = grpc . unary (method , req) . await ; Ok (res) } ; Box :: pin (fut) } "/ibc.core.channel.v1.Msg/Timeout" => { # [allow (non_camel_case_types)] struct TimeoutSvc < T : Msg > (pub Arc < T >) ; impl < T : Msg > tonic :: server :: UnaryService < super :: MsgTimeout > for TimeoutSvc < T > { type Response = super :: MsgTimeoutResponse ; type Future = BoxFuture < tonic :: Response < Self :: Response > , tonic :: Status > ; fn call (& mut self , request : tonic :: Request < super :: MsgTimeout >) -> Self :: Future { let inner = self . 0 . clone () ; let fut = async move { (* inner) . timeout (request) . await } ; Box :: pin (fut) } } let inner = self . inner . clone () ; let fut = async move { let interceptor = inner . 1 . clone () ; let inner = inner . 0 ; let method = TimeoutSvc (inner) ; let codec = tonic :: codec :: ProstCodec :: default () ; let mut grpc = if let Some (interceptor) = interceptor { tonic :: server :: Grpc :: with_interceptor (codec , interceptor) } else { tonic :: server :: Grpc :: new (codec) } ; let res = grpc . unary (method , req) . await ; Ok (res) } ; Box :: pin (fut) } "/ibc.core.channel.v1.Msg/TimeoutOnClose" => { # [allow (non_camel_case_types)] struct TimeoutOnCloseSvc < T : Msg > (pub Arc < T >) ; impl < T : Msg > tonic :: server :: UnaryService < super :: MsgTimeoutOnClose > for TimeoutOnCloseSvc < T > { type Response = super :: MsgTimeoutOnCloseResponse ; type Future = BoxFuture < tonic :: Response < Self :: Response > , tonic :: Status > ; fn call (& mut self , request : tonic :: Request < super :: MsgTimeoutOnClose >) -> Self :: Future { let inner = self . 0 . clone () ; let fut = async move { (* inner) . timeout_on_close (request) . await } ; Box :: pin (fut) } } let inner = self . inner . clone () ; let fut = async move { let interceptor = inner . 1 . clone () ; let inner = inner . 0 ; let method = TimeoutOnCloseSvc (inner) ; let codec = tonic :: codec :: ProstCodec :: default () ; let mut grpc = if let Some (interceptor) = interceptor { tonic :: server :: Grpc :: with_interceptor (codec , interceptor) } else { tonic :: server :: Grpc :: new (codec) } ; let res = grpc . unary (method , req) . await ; Ok (res) } ; Box :: pin (fut) } "/ibc.core.channel.v1.Msg/Acknowledgement" => { # [allow (non_camel_case_types)] struct AcknowledgementSvc < T : Msg > (pub Arc < T >) ; impl < T : Msg > tonic :: server :: UnaryService < super :: MsgAcknowledgement > for AcknowledgementSvc < T > { type Response = super :: MsgAcknowledgementResponse ; type Future = BoxFuture < tonic :: Response < Self :: Response > , tonic :: Status > ; fn call (& mut self , request : tonic :: Request < super :: MsgAcknowledgement >) -> Self :: Future { let inner = self . 0 . clone () ; let fut = async move { (* inner) . acknowledgement (request) . await } ; Box :: pin (fut) } } let inner = self . inner . clone () ; let fut = async move { let interceptor = inner . 1 . clone () ; let inner = inner . 0 ; let method = AcknowledgementSvc (inner) ; let codec = tonic :: codec :: ProstCodec :: default () ; let mut grpc = if let Some (interceptor) = interceptor { tonic :: server :: Grpc :: with_interceptor (codec , interceptor) } else { tonic :: server :: Grpc :: new (codec) } ; let res = grpc . unary (method , req) . await ; Ok (res) } ; Box :: pin (fut) } _ => Box :: pin (async move { Ok (http :: Response :: builder () . status (200) . header ("grpc-status" , "12") . header ("content-type" , "application/grpc") . body (tonic :: body :: BoxBody :: empty ()) . unwrap ()) }) , } } } impl < T : Msg > Clone for MsgServer < T > { fn clone (& self) -> Self { let inner = self . inner . clone () ; Self { inner } } } impl < T : Msg > Clone for _Inner < T > { fn clone (& self) -> Self { Self (self . 0 . clone () , self . 1 . clone ()) } } impl < T : std :: fmt :: Debug > std :: fmt :: Debug for _Inner < T > { fn fmt (& self , f : & mut std :: fmt :: Formatter < '_ >) -> std :: fmt :: Result { write ! (f , "{:?}" , self . 0) } } impl < T : Msg > tonic :: transport :: NamedService for MsgServer < T > { const NAME : & 'static str = "ibc.core.channel.v1.Msg" ; } }/// QueryChannelRequest is the request type for the Query/Channel RPC method
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryChannelRequest {
     /// port unique identifier
@@ -724,4 +724,4 @@ pub struct QueryNextSequenceReceiveResponse {
     #[prost(message, optional, tag="3")]
     pub proof_height: ::std::option::Option<super::super::client::v1::Height>,
 }
-# [doc = r" Generated client implementations."] pub mod query_client { # ! [allow (unused_variables , dead_code , missing_docs)] use tonic :: codegen :: * ; # [doc = " Query provides defines the gRPC querier service"] pub struct QueryClient < T > { inner : tonic :: client :: Grpc < T > , } impl QueryClient < tonic :: transport :: Channel > { # [doc = r" Attempt to create a new client by connecting to a given endpoint."] pub async fn connect < D > (dst : D) -> Result < Self , tonic :: transport :: Error > where D : std :: convert :: TryInto < tonic :: transport :: Endpoint > , D :: Error : Into < StdError > , { let conn = tonic :: transport :: Endpoint :: new (dst) ? . connect () . await ? ; Ok (Self :: new (conn)) } } impl < T > QueryClient < T > where T : tonic :: client :: GrpcService < tonic :: body :: BoxBody > , T :: ResponseBody : Body + HttpBody + Send + 'static , T :: Error : Into < StdError > , < T :: ResponseBody as HttpBody > :: Error : Into < StdError > + Send , { pub fn new (inner : T) -> Self { let inner = tonic :: client :: Grpc :: new (inner) ; Self { inner } } pub fn with_interceptor (inner : T , interceptor : impl Into < tonic :: Interceptor >) -> Self { let inner = tonic :: client :: Grpc :: with_interceptor (inner , interceptor) ; Self { inner } } # [doc = " Channel queries an IBC Channel."] pub async fn channel (& mut self , request : impl tonic :: IntoRequest < super :: QueryChannelRequest > ,) -> Result < tonic :: Response < super :: QueryChannelResponse > , tonic :: Status > { self . inner . ready () . await . map_err (| e | { tonic :: Status :: new (tonic :: Code :: Unknown , format ! ("Service was not ready: {}" , e . into ())) }) ? ; let codec = tonic :: codec :: ProstCodec :: default () ; let path = http :: uri :: PathAndQuery :: from_static ("/ibc.core.channel.v1.Query/Channel") ; self . inner . unary (request . into_request () , path , codec) . await } # [doc = " Channels queries all the IBC channels of a chain."] pub async fn channels (& mut self , request : impl tonic :: IntoRequest < super :: QueryChannelsRequest > ,) -> Result < tonic :: Response < super :: QueryChannelsResponse > , tonic :: Status > { self . inner . ready () . await . map_err (| e | { tonic :: Status :: new (tonic :: Code :: Unknown , format ! ("Service was not ready: {}" , e . into ())) }) ? ; let codec = tonic :: codec :: ProstCodec :: default () ; let path = http :: uri :: PathAndQuery :: from_static ("/ibc.core.channel.v1.Query/Channels") ; self . inner . unary (request . into_request () , path , codec) . await } # [doc = " ConnectionChannels queries all the channels associated with a connection"] # [doc = " end."] pub async fn connection_channels (& mut self , request : impl tonic :: IntoRequest < super :: QueryConnectionChannelsRequest > ,) -> Result < tonic :: Response < super :: QueryConnectionChannelsResponse > , tonic :: Status > { self . inner . ready () . await . map_err (| e | { tonic :: Status :: new (tonic :: Code :: Unknown , format ! ("Service was not ready: {}" , e . into ())) }) ? ; let codec = tonic :: codec :: ProstCodec :: default () ; let path = http :: uri :: PathAndQuery :: from_static ("/ibc.core.channel.v1.Query/ConnectionChannels") ; self . inner . unary (request . into_request () , path , codec) . await } # [doc = " ChannelClientState queries for the client state for the channel associated"] # [doc = " with the provided channel identifiers."] pub async fn channel_client_state (& mut self , request : impl tonic :: IntoRequest < super :: QueryChannelClientStateRequest > ,) -> Result < tonic :: Response < super :: QueryChannelClientStateResponse > , tonic :: Status > { self . inner . ready () . await . map_err (| e | { tonic :: Status :: new (tonic :: Code :: Unknown , format ! ("Service was not ready: {}" , e . into ())) }) ? ; let codec = tonic :: codec :: ProstCodec :: default () ; let path = http :: uri :: PathAndQuery :: from_static ("/ibc.core.channel.v1.Query/ChannelClientState") ; self . inner . unary (request . into_request () , path , codec) . await } # [doc = " ChannelConsensusState queries for the consensus state for the channel"] # [doc = " associated with the provided channel identifiers."] pub async fn channel_consensus_state (& mut self , request : impl tonic :: IntoRequest < super :: QueryChannelConsensusStateRequest > ,) -> Result < tonic :: Response < super :: QueryChannelConsensusStateResponse > , tonic :: Status > { self . inner . ready () . await . map_err (| e | { tonic :: Status :: new (tonic :: Code :: Unknown , format ! ("Service was not ready: {}" , e . into ())) }) ? ; let codec = tonic :: codec :: ProstCodec :: default () ; let path = http :: uri :: PathAndQuery :: from_static ("/ibc.core.channel.v1.Query/ChannelConsensusState") ; self . inner . unary (request . into_request () , path , codec) . await } # [doc = " PacketCommitment queries a stored packet commitment hash."] pub async fn packet_commitment (& mut self , request : impl tonic :: IntoRequest < super :: QueryPacketCommitmentRequest > ,) -> Result < tonic :: Response < super :: QueryPacketCommitmentResponse > , tonic :: Status > { self . inner . ready () . await . map_err (| e | { tonic :: Status :: new (tonic :: Code :: Unknown , format ! ("Service was not ready: {}" , e . into ())) }) ? ; let codec = tonic :: codec :: ProstCodec :: default () ; let path = http :: uri :: PathAndQuery :: from_static ("/ibc.core.channel.v1.Query/PacketCommitment") ; self . inner . unary (request . into_request () , path , codec) . await } # [doc = " PacketCommitments returns all the packet commitments hashes associated"] # [doc = " with a channel."] pub async fn packet_commitments (& mut self , request : impl tonic :: IntoRequest < super :: QueryPacketCommitmentsRequest > ,) -> Result < tonic :: Response < super :: QueryPacketCommitmentsResponse > , tonic :: Status > { self . inner . ready () . await . map_err (| e | { tonic :: Status :: new (tonic :: Code :: Unknown , format ! ("Service was not ready: {}" , e . into ())) }) ? ; let codec = tonic :: codec :: ProstCodec :: default () ; let path = http :: uri :: PathAndQuery :: from_static ("/ibc.core.channel.v1.Query/PacketCommitments") ; self . inner . unary (request . into_request () , path , codec) . await } # [doc = " PacketReceipt queries if a given packet sequence has been received on the queried chain"] pub async fn packet_receipt (& mut self , request : impl tonic :: IntoRequest < super :: QueryPacketReceiptRequest > ,) -> Result < tonic :: Response < super :: QueryPacketReceiptResponse > , tonic :: Status > { self . inner . ready () . await . map_err (| e | { tonic :: Status :: new (tonic :: Code :: Unknown , format ! ("Service was not ready: {}" , e . into ())) }) ? ; let codec = tonic :: codec :: ProstCodec :: default () ; let path = http :: uri :: PathAndQuery :: from_static ("/ibc.core.channel.v1.Query/PacketReceipt") ; self . inner . unary (request . into_request () , path , codec) . await } # [doc = " PacketAcknowledgement queries a stored packet acknowledgement hash."] pub async fn packet_acknowledgement (& mut self , request : impl tonic :: IntoRequest < super :: QueryPacketAcknowledgementRequest > ,) -> Result < tonic :: Response < super :: QueryPacketAcknowledgementResponse > , tonic :: Status > { self . inner . ready () . await . map_err (| e | { tonic :: Status :: new (tonic :: Code :: Unknown , format ! ("Service was not ready: {}" , e . into ())) }) ? ; let codec = tonic :: codec :: ProstCodec :: default () ; let path = http :: uri :: PathAndQuery :: from_static ("/ibc.core.channel.v1.Query/PacketAcknowledgement") ; self . inner . unary (request . into_request () , path , codec) . await } # [doc = " PacketAcknowledgements returns all the packet acknowledgements associated"] # [doc = " with a channel."] pub async fn packet_acknowledgements (& mut self , request : impl tonic :: IntoRequest < super :: QueryPacketAcknowledgementsRequest > ,) -> Result < tonic :: Response < super :: QueryPacketAcknowledgementsResponse > , tonic :: Status > { self . inner . ready () . await . map_err (| e | { tonic :: Status :: new (tonic :: Code :: Unknown , format ! ("Service was not ready: {}" , e . into ())) }) ? ; let codec = tonic :: codec :: ProstCodec :: default () ; let path = http :: uri :: PathAndQuery :: from_static ("/ibc.core.channel.v1.Query/PacketAcknowledgements") ; self . inner . unary (request . into_request () , path , codec) . await } # [doc = " UnreceivedPackets returns all the unreceived IBC packets associated with a"] # [doc = " channel and sequences."] pub async fn unreceived_packets (& mut self , request : impl tonic :: IntoRequest < super :: QueryUnreceivedPacketsRequest > ,) -> Result < tonic :: Response < super :: QueryUnreceivedPacketsResponse > , tonic :: Status > { self . inner . ready () . await . map_err (| e | { tonic :: Status :: new (tonic :: Code :: Unknown , format ! ("Service was not ready: {}" , e . into ())) }) ? ; let codec = tonic :: codec :: ProstCodec :: default () ; let path = http :: uri :: PathAndQuery :: from_static ("/ibc.core.channel.v1.Query/UnreceivedPackets") ; self . inner . unary (request . into_request () , path , codec) . await } # [doc = " UnreceivedAcks returns all the unreceived IBC acknowledgements associated with a"] # [doc = " channel and sequences."] pub async fn unreceived_acks (& mut self , request : impl tonic :: IntoRequest < super :: QueryUnreceivedAcksRequest > ,) -> Result < tonic :: Response < super :: QueryUnreceivedAcksResponse > , tonic :: Status > { self . inner . ready () . await . map_err (| e | { tonic :: Status :: new (tonic :: Code :: Unknown , format ! ("Service was not ready: {}" , e . into ())) }) ? ; let codec = tonic :: codec :: ProstCodec :: default () ; let path = http :: uri :: PathAndQuery :: from_static ("/ibc.core.channel.v1.Query/UnreceivedAcks") ; self . inner . unary (request . into_request () , path , codec) . await } # [doc = " NextSequenceReceive returns the next receive sequence for a given channel."] pub async fn next_sequence_receive (& mut self , request : impl tonic :: IntoRequest < super :: QueryNextSequenceReceiveRequest > ,) -> Result < tonic :: Response < super :: QueryNextSequenceReceiveResponse > , tonic :: Status > { self . inner . ready () . await . map_err (| e | { tonic :: Status :: new (tonic :: Code :: Unknown , format ! ("Service was not ready: {}" , e . into ())) }) ? ; let codec = tonic :: codec :: ProstCodec :: default () ; let path = http :: uri :: PathAndQuery :: from_static ("/ibc.core.channel.v1.Query/NextSequenceReceive") ; self . inner . unary (request . into_request () , path , codec) . await } } impl < T : Clone > Clone for QueryClient < T > { fn clone (& self) -> Self { Self { inner : self . inner . clone () , } } } impl < T > std :: fmt :: Debug for QueryClient < T > { fn fmt (& self , f : & mut std :: fmt :: Formatter < '_ >) -> std :: fmt :: Result { write ! (f , "QueryClient {{ ... }}") } } }
\ No newline at end of file
+# [doc = r" Generated client implementations."] pub mod query_client { # ! [allow (unused_variables , dead_code , missing_docs)] use tonic :: codegen :: * ; # [doc = " Query provides defines the gRPC querier service"] pub struct QueryClient < T > { inner : tonic :: client :: Grpc < T > , } impl QueryClient < tonic :: transport :: Channel > { # [doc = r" Attempt to create a new client by connecting to a given endpoint."] pub async fn connect < D > (dst : D) -> Result < Self , tonic :: transport :: Error > where D : std :: convert :: TryInto < tonic :: transport :: Endpoint > , D :: Error : Into < StdError > , { let conn = tonic :: transport :: Endpoint :: new (dst) ? . connect () . await ? ; Ok (Self :: new (conn)) } } impl < T > QueryClient < T > where T : tonic :: client :: GrpcService < tonic :: body :: BoxBody > , T :: ResponseBody : Body + HttpBody + Send + 'static , T :: Error : Into < StdError > , < T :: ResponseBody as HttpBody > :: Error : Into < StdError > + Send , { pub fn new (inner : T) -> Self { let inner = tonic :: client :: Grpc :: new (inner) ; Self { inner } } pub fn with_interceptor (inner : T , interceptor : impl Into < tonic :: Interceptor >) -> Self { let inner = tonic :: client :: Grpc :: with_interceptor (inner , interceptor) ; Self { inner } } # [doc = " Channel queries an IBC Channel."] pub async fn channel (& mut self , request : impl tonic :: IntoRequest < super :: QueryChannelRequest > ,) -> Result < tonic :: Response < super :: QueryChannelResponse > , tonic :: Status > { self . inner . ready () . await . map_err (| e | { tonic :: Status :: new (tonic :: Code :: Unknown , format ! ("Service was not ready: {}" , e . into ())) }) ? ; let codec = tonic :: codec :: ProstCodec :: default () ; let path = http :: uri :: PathAndQuery :: from_static ("/ibc.core.channel.v1.Query/Channel") ; self . inner . unary (request . into_request () , path , codec) . await } # [doc = " Channels queries all the IBC channels of a chain."] pub async fn channels (& mut self , request : impl tonic :: IntoRequest < super :: QueryChannelsRequest > ,) -> Result < tonic :: Response < super :: QueryChannelsResponse > , tonic :: Status > { self . inner . ready () . await . map_err (| e | { tonic :: Status :: new (tonic :: Code :: Unknown , format ! ("Service was not ready: {}" , e . into ())) }) ? ; let codec = tonic :: codec :: ProstCodec :: default () ; let path = http :: uri :: PathAndQuery :: from_static ("/ibc.core.channel.v1.Query/Channels") ; self . inner . unary (request . into_request () , path , codec) . await } # [doc = " ConnectionChannels queries all the channels associated with a connection"] # [doc = " end."] pub async fn connection_channels (& mut self , request : impl tonic :: IntoRequest < super :: QueryConnectionChannelsRequest > ,) -> Result < tonic :: Response < super :: QueryConnectionChannelsResponse > , tonic :: Status > { self . inner . ready () . await . map_err (| e | { tonic :: Status :: new (tonic :: Code :: Unknown , format ! ("Service was not ready: {}" , e . into ())) }) ? ; let codec = tonic :: codec :: ProstCodec :: default () ; let path = http :: uri :: PathAndQuery :: from_static ("/ibc.core.channel.v1.Query/ConnectionChannels") ; self . inner . unary (request . into_request () , path , codec) . await } # [doc = " ChannelClientState queries for the client state for the channel associated"] # [doc = " with the provided channel identifiers."] pub async fn channel_client_state (& mut self , request : impl tonic :: IntoRequest < super :: QueryChannelClientStateRequest > ,) -> Result < tonic :: Response < super :: QueryChannelClientStateResponse > , tonic :: Status > { self . inner . ready () . await . map_err (| e | { tonic :: Status :: new (tonic :: Code :: Unknown , format ! ("Service was not ready: {}" , e . into ())) }) ? ; let codec = tonic :: codec :: ProstCodec :: default () ; let path = http :: uri :: PathAndQuery :: from_static ("/ibc.core.channel.v1.Query/ChannelClientState") ; self . inner . unary (request . into_request () , path , codec) . await } # [doc = " ChannelConsensusState queries for the consensus state for the channel"] # [doc = " associated with the provided channel identifiers."] pub async fn channel_consensus_state (& mut self , request : impl tonic :: IntoRequest < super :: QueryChannelConsensusStateRequest > ,) -> Result < tonic :: Response < super :: QueryChannelConsensusStateResponse > , tonic :: Status > { self . inner . ready () . await . map_err (| e | { tonic :: Status :: new (tonic :: Code :: Unknown , format ! ("Service was not ready: {}" , e . into ())) }) ? ; let codec = tonic :: codec :: ProstCodec :: default () ; let path = http :: uri :: PathAndQuery :: from_static ("/ibc.core.channel.v1.Query/ChannelConsensusState") ; self . inner . unary (request . into_request () , path , codec) . await } # [doc = " PacketCommitment queries a stored packet commitment hash."] pub async fn packet_commitment (& mut self , request : impl tonic :: IntoRequest < super :: QueryPacketCommitmentRequest > ,) -> Result < tonic :: Response < super :: QueryPacketCommitmentResponse > , tonic :: Status > { self . inner . ready () . await . map_err (| e | { tonic :: Status :: new (tonic :: Code :: Unknown , format ! ("Service was not ready: {}" , e . into ())) }) ? ; let codec = tonic :: codec :: ProstCodec :: default () ; let path = http :: uri :: PathAndQuery :: from_static ("/ibc.core.channel.v1.Query/PacketCommitment") ; self . inner . unary (request . into_request () , path , codec) . await } # [doc = " PacketCommitments returns all the packet commitments hashes associated"] # [doc = " with a channel."] pub async fn packet_commitments (& mut self , request : impl tonic :: IntoRequest < super :: QueryPacketCommitmentsRequest > ,) -> Result < tonic :: Response < super :: QueryPacketCommitmentsResponse > , tonic :: Status > { self . inner . ready () . await . map_err (| e | { tonic :: Status :: new (tonic :: Code :: Unknown , format ! ("Service was not ready: {}" , e . into ())) }) ? ; let codec = tonic :: codec :: ProstCodec :: default () ; let path = http :: uri :: PathAndQuery :: from_static ("/ibc.core.channel.v1.Query/PacketCommitments") ; self . inner . unary (request . into_request () , path , codec) . await } # [doc = " PacketReceipt queries if a given packet sequence has been received on the queried chain"] pub async fn packet_receipt (& mut self , request : impl tonic :: IntoRequest < super :: QueryPacketReceiptRequest > ,) -> Result < tonic :: Response < super :: QueryPacketReceiptResponse > , tonic :: Status > { self . inner . ready () . await . map_err (| e | { tonic :: Status :: new (tonic :: Code :: Unknown , format ! ("Service was not ready: {}" , e . into ())) }) ? ; let codec = tonic :: codec :: ProstCodec :: default () ; let path = http :: uri :: PathAndQuery :: from_static ("/ibc.core.channel.v1.Query/PacketReceipt") ; self . inner . unary (request . into_request () , path , codec) . await } # [doc = " PacketAcknowledgement queries a stored packet acknowledgement hash."] pub async fn packet_acknowledgement (& mut self , request : impl tonic :: IntoRequest < super :: QueryPacketAcknowledgementRequest > ,) -> Result < tonic :: Response < super :: QueryPacketAcknowledgementResponse > , tonic :: Status > { self . inner . ready () . await . map_err (| e | { tonic :: Status :: new (tonic :: Code :: Unknown , format ! ("Service was not ready: {}" , e . into ())) }) ? ; let codec = tonic :: codec :: ProstCodec :: default () ; let path = http :: uri :: PathAndQuery :: from_static ("/ibc.core.channel.v1.Query/PacketAcknowledgement") ; self . inner . unary (request . into_request () , path , codec) . await } # [doc = " PacketAcknowledgements returns all the packet acknowledgements associated"] # [doc = " with a channel."] pub async fn packet_acknowledgements (& mut self , request : impl tonic :: IntoRequest < super :: QueryPacketAcknowledgementsRequest > ,) -> Result < tonic :: Response < super :: QueryPacketAcknowledgementsResponse > , tonic :: Status > { self . inner . ready () . await . map_err (| e | { tonic :: Status :: new (tonic :: Code :: Unknown , format ! ("Service was not ready: {}" , e . into ())) }) ? ; let codec = tonic :: codec :: ProstCodec :: default () ; let path = http :: uri :: PathAndQuery :: from_static ("/ibc.core.channel.v1.Query/PacketAcknowledgements") ; self . inner . unary (request . into_request () , path , codec) . await } # [doc = " UnreceivedPackets returns all the unreceived IBC packets associated with a"] # [doc = " channel and sequences."] pub async fn unreceived_packets (& mut self , request : impl tonic :: IntoRequest < super :: QueryUnreceivedPacketsRequest > ,) -> Result < tonic :: Response < super :: QueryUnreceivedPacketsResponse > , tonic :: Status > { self . inner . ready () . await . map_err (| e | { tonic :: Status :: new (tonic :: Code :: Unknown , format ! ("Service was not ready: {}" , e . into ())) }) ? ; let codec = tonic :: codec :: ProstCodec :: default () ; let path = http :: uri :: PathAndQuery :: from_static ("/ibc.core.channel.v1.Query/UnreceivedPackets") ; self . inner . unary (request . into_request () , path , codec) . await } # [doc = " UnreceivedAcks returns all the unreceived IBC acknowledgements associated with a"] # [doc = " channel and sequences."] pub async fn unreceived_acks (& mut self , request : impl tonic :: IntoRequest < super :: QueryUnreceivedAcksRequest > ,) -> Result < tonic :: Response < super :: QueryUnreceivedAcksResponse > , tonic :: Status > { self . inner . ready () . await . map_err (| e | { tonic :: Status :: new (tonic :: Code :: Unknown , format ! ("Service was not ready: {}" , e . into ())) }) ? ; let codec = tonic :: codec :: ProstCodec :: default () ; let path = http :: uri :: PathAndQuery :: from_static ("/ibc.core.channel.v1.Query/UnreceivedAcks") ; self . inner . unary (request . into_request () , path , codec) . await } # [doc = " NextSequenceReceive returns the next receive sequence for a given channel."] pub async fn next_sequence_receive (& mut self , request : impl tonic :: IntoRequest < super :: QueryNextSequenceReceiveRequest > ,) -> Result < tonic :: Response < super :: QueryNextSequenceReceiveResponse > , tonic :: Status > { self . inner . ready () . await . map_err (| e | { tonic :: Status :: new (tonic :: Code :: Unknown , format ! ("Service was not ready: {}" , e . into ())) }) ? ; let codec = tonic :: codec :: ProstCodec :: default () ; let path = http :: uri :: PathAndQuery :: from_static ("/ibc.core.channel.v1.Query/NextSequenceReceive") ; self . inner . unary (request . into_request () , path , codec) . await } } impl < T : Clone > Clone for QueryClient < T > { fn clone (& self) -> Self { Self { inner : self . inner . clone () , } } } impl < T > std :: fmt :: Debug for QueryClient < T > { fn fmt (& self , f : & mut std :: fmt :: Formatter < '_ >) -> std :: fmt :: Result { write ! (f , "QueryClient {{ ... }}") } } }# [doc = r" Generated server implementations."] pub mod query_server { # ! [allow (unused_variables , dead_code , missing_docs)] use tonic :: codegen :: * ; # [doc = "Generated trait containing gRPC methods that should be implemented for use with QueryServer."] # [async_trait] pub trait Query : Send + Sync + 'static { # [doc = " Channel queries an IBC Channel."] async fn channel (& self , request : tonic :: Request < super :: QueryChannelRequest >) -> Result < tonic :: Response < super :: QueryChannelResponse > , tonic :: Status > ; # [doc = " Channels queries all the IBC channels of a chain."] async fn channels (& self , request : tonic :: Request < super :: QueryChannelsRequest >) -> Result < tonic :: Response < super :: QueryChannelsResponse > , tonic :: Status > ; # [doc = " ConnectionChannels queries all the channels associated with a connection"] # [doc = " end."] async fn connection_channels (& self , request : tonic :: Request < super :: QueryConnectionChannelsRequest >) -> Result < tonic :: Response < super :: QueryConnectionChannelsResponse > , tonic :: Status > ; # [doc = " ChannelClientState queries for the client state for the channel associated"] # [doc = " with the provided channel identifiers."] async fn channel_client_state (& self , request : tonic :: Request < super :: QueryChannelClientStateRequest >) -> Result < tonic :: Response < super :: QueryChannelClientStateResponse > , tonic :: Status > ; # [doc = " ChannelConsensusState queries for the consensus state for the channel"] # [doc = " associated with the provided channel identifiers."] async fn channel_consensus_state (& self , request : tonic :: Request < super :: QueryChannelConsensusStateRequest >) -> Result < tonic :: Response < super :: QueryChannelConsensusStateResponse > , tonic :: Status > ; # [doc = " PacketCommitment queries a stored packet commitment hash."] async fn packet_commitment (& self , request : tonic :: Request < super :: QueryPacketCommitmentRequest >) -> Result < tonic :: Response < super :: QueryPacketCommitmentResponse > , tonic :: Status > ; # [doc = " PacketCommitments returns all the packet commitments hashes associated"] # [doc = " with a channel."] async fn packet_commitments (& self , request : tonic :: Request < super :: QueryPacketCommitmentsRequest >) -> Result < tonic :: Response < super :: QueryPacketCommitmentsResponse > , tonic :: Status > ; # [doc = " PacketReceipt queries if a given packet sequence has been received on the queried chain"] async fn packet_receipt (& self , request : tonic :: Request < super :: QueryPacketReceiptRequest >) -> Result < tonic :: Response < super :: QueryPacketReceiptResponse > , tonic :: Status > ; # [doc = " PacketAcknowledgement queries a stored packet acknowledgement hash."] async fn packet_acknowledgement (& self , request : tonic :: Request < super :: QueryPacketAcknowledgementRequest >) -> Result < tonic :: Response < super :: QueryPacketAcknowledgementResponse > , tonic :: Status > ; # [doc = " PacketAcknowledgements returns all the packet acknowledgements associated"] # [doc = " with a channel."] async fn packet_acknowledgements (& self , request : tonic :: Request < super :: QueryPacketAcknowledgementsRequest >) -> Result < tonic :: Response < super :: QueryPacketAcknowledgementsResponse > , tonic :: Status > ; # [doc = " UnreceivedPackets returns all the unreceived IBC packets associated with a"] # [doc = " channel and sequences."] async fn unreceived_packets (& self , request : tonic :: Request < super :: QueryUnreceivedPacketsRequest >) -> Result < tonic :: Response < super :: QueryUnreceivedPacketsResponse > , tonic :: Status > ; # [doc = " UnreceivedAcks returns all the unreceived IBC acknowledgements associated with a"] # [doc = " channel and sequences."] async fn unreceived_acks (& self , request : tonic :: Request < super :: QueryUnreceivedAcksRequest >) -> Result < tonic :: Response < super :: QueryUnreceivedAcksResponse > , tonic :: Status > ; # [doc = " NextSequenceReceive returns the next receive sequence for a given channel."] async fn next_sequence_receive (& self , request : tonic :: Request < super :: QueryNextSequenceReceiveRequest >) -> Result < tonic :: Response < super :: QueryNextSequenceReceiveResponse > , tonic :: Status > ; } # [doc = " Query provides defines the gRPC querier service"] # [derive (Debug)] pub struct QueryServer < T : Query > { inner : _Inner < T > , } struct _Inner < T > (Arc < T > , Option < tonic :: Interceptor >) ; impl < T : Query > QueryServer < T > { pub fn new (inner : T) -> Self { let inner = Arc :: new (inner) ; let inner = _Inner (inner , None) ; Self { inner } } pub fn with_interceptor (inner : T , interceptor : impl Into < tonic :: Interceptor >) -> Self { let inner = Arc :: new (inner) ; let inner = _Inner (inner , Some (interceptor . into ())) ; Self { inner } } } impl < T , B > Service < http :: Request < B >> for QueryServer < T > where T : Query , B : HttpBody + Send + Sync + 'static , B :: Error : Into < StdError > + Send + 'static , { type Response = http :: Response < tonic :: body :: BoxBody > ; type Error = Never ; type Future = BoxFuture < Self :: Response , Self :: Error > ; fn poll_ready (& mut self , _cx : & mut Context < '_ >) -> Poll < Result < () , Self :: Error >> { Poll :: Ready (Ok (())) } fn call (& mut self , req : http :: Request < B >) -> Self :: Future { let inner = self . inner . clone () ; match req . uri () . path () { "/ibc.core.channel.v1.Query/Channel" => { # [allow (non_camel_case_types)] struct ChannelSvc < T : Query > (pub Arc < T >) ; impl < T : Query > tonic :: server :: UnaryService < super :: QueryChannelRequest > for ChannelSvc < T > { type Response = super :: QueryChannelResponse ; type Future = BoxFuture < tonic :: Response < Self :: Response > , tonic :: Status > ; fn call (& mut self , request : tonic :: Request < super :: QueryChannelRequest >) -> Self :: Future { let inner = self . 0 . clone () ; let fut = async move { (* inner) . channel (request) . await } ; Box :: pin (fut) } } let inner = self . inner . clone () ; let fut = async move { let interceptor = inner . 1 . clone () ; let inner = inner . 0 ; let method = ChannelSvc (inner) ; let codec = tonic :: codec :: ProstCodec :: default () ; let mut grpc = if let Some (interceptor) = interceptor { tonic :: server :: Grpc :: with_interceptor (codec , interceptor) } else { tonic :: server :: Grpc :: new (codec) } ; let res = grpc . unary (method , req) . await ; Ok (res) } ; Box :: pin (fut) } "/ibc.core.channel.v1.Query/Channels" => { # [allow (non_camel_case_types)] struct ChannelsSvc < T : Query > (pub Arc < T >) ; impl < T : Query > tonic :: server :: UnaryService < super :: QueryChannelsRequest > for ChannelsSvc < T > { type Response = super :: QueryChannelsResponse ; type Future = BoxFuture < tonic :: Response < Self :: Response > , tonic :: Status > ; fn call (& mut self , request : tonic :: Request < super :: QueryChannelsRequest >) -> Self :: Future { let inner = self . 0 . clone () ; let fut = async move { (* inner) . channels (request) . await } ; Box :: pin (fut) } } let inner = self . inner . clone () ; let fut = async move { let interceptor = inner . 1 . clone () ; let inner = inner . 0 ; let method = ChannelsSvc (inner) ; let codec = tonic :: codec :: ProstCodec :: default () ; let mut grpc = if let Some (interceptor) = interceptor { tonic :: server :: Grpc :: with_interceptor (codec , interceptor) } else { tonic :: server :: Grpc :: new (codec) } ; let res = grpc . unary (method , req) . await ; Ok (res) } ; Box :: pin (fut) } "/ibc.core.channel.v1.Query/ConnectionChannels" => { # [allow (non_camel_case_types)] struct ConnectionChannelsSvc < T : Query > (pub Arc < T >) ; impl < T : Query > tonic :: server :: UnaryService < super :: QueryConnectionChannelsRequest > for ConnectionChannelsSvc < T > { type Response = super :: QueryConnectionChannelsResponse ; type Future = BoxFuture < tonic :: Response < Self :: Response > , tonic :: Status > ; fn call (& mut self , request : tonic :: Request < super :: QueryConnectionChannelsRequest >) -> Self :: Future { let inner = self . 0 . clone () ; let fut = async move { (* inner) . connection_channels (request) . await } ; Box :: pin (fut) } } let inner = self . inner . clone () ; let fut = async move { let interceptor = inner . 1 . clone () ; let inner = inner . 0 ; let method = ConnectionChannelsSvc (inner) ; let codec = tonic :: codec :: ProstCodec :: default () ; let mut grpc = if let Some (interceptor) = interceptor { tonic :: server :: Grpc :: with_interceptor (codec , interceptor) } else { tonic :: server :: Grpc :: new (codec) } ; let res = grpc . unary (method , req) . await ; Ok (res) } ; Box :: pin (fut) } "/ibc.core.channel.v1.Query/ChannelClientState" => { # [allow (non_camel_case_types)] struct ChannelClientStateSvc < T : Query > (pub Arc < T >) ; impl < T : Query > tonic :: server :: UnaryService < super :: QueryChannelClientStateRequest > for ChannelClientStateSvc < T > { type Response = super :: QueryChannelClientStateResponse ; type Future = BoxFuture < tonic :: Response < Self :: Response > , tonic :: Status > ; fn call (& mut self , request : tonic :: Request < super :: QueryChannelClientStateRequest >) -> Self :: Future { let inner = self . 0 . clone () ; let fut = async move { (* inner) . channel_client_state (request) . await } ; Box :: pin (fut) } } let inner = self . inner . clone () ; let fut = async move { let interceptor = inner . 1 . clone () ; let inner = inner . 0 ; let method = ChannelClientStateSvc (inner) ; let codec = tonic :: codec :: ProstCodec :: default () ; let mut grpc = if let Some (interceptor) = interceptor { tonic :: server :: Grpc :: with_interceptor (codec , interceptor) } else { tonic :: server :: Grpc :: new (codec) } ; let res = grpc . unary (method , req) . await ; Ok (res) } ; Box :: pin (fut) } "/ibc.core.channel.v1.Query/ChannelConsensusState" => { # [allow (non_camel_case_types)] struct ChannelConsensusStateSvc < T : Query > (pub Arc < T >) ; impl < T : Query > tonic :: server :: UnaryService < super :: QueryChannelConsensusStateRequest > for ChannelConsensusStateSvc < T > { type Response = super :: QueryChannelConsensusStateResponse ; type Future = BoxFuture < tonic :: Response < Self :: Response > , tonic :: Status > ; fn call (& mut self , request : tonic :: Request < super :: QueryChannelConsensusStateRequest >) -> Self :: Future { let inner = self . 0 . clone () ; let fut = async move { (* inner) . channel_consensus_state (request) . await } ; Box :: pin (fut) } } let inner = self . inner . clone () ; let fut = async move { let interceptor = inner . 1 . clone () ; let inner = inner . 0 ; let method = ChannelConsensusStateSvc (inner) ; let codec = tonic :: codec :: ProstCodec :: default () ; let mut grpc = if let Some (interceptor) = interceptor { tonic :: server :: Grpc :: with_interceptor (codec , interceptor) } else { tonic :: server :: Grpc :: new (codec) } ; let res = grpc . unary (method , req) . await ; Ok (res) } ; Box :: pin (fut) } "/ibc.core.channel.v1.Query/PacketCommitment" => { # [allow (non_camel_case_types)] struct PacketCommitmentSvc < T : Query > (pub Arc < T >) ; impl < T : Query > tonic :: server :: UnaryService < super :: QueryPacketCommitmentRequest > for PacketCommitmentSvc < T > { type Response = super :: QueryPacketCommitmentResponse ; type Future = BoxFuture < tonic :: Response < Self :: Response > , tonic :: Status > ; fn call (& mut self , request : tonic :: Request < super :: QueryPacketCommitmentRequest >) -> Self :: Future { let inner = self . 0 . clone () ; let fut = async move { (* inner) . packet_commitment (request) . await } ; Box :: pin (fut) } } let inner = self . inner . clone () ; let fut = async move { let interceptor = inner . 1 . clone () ; let inner = inner . 0 ; let method = PacketCommitmentSvc (inner) ; let codec = tonic :: codec :: ProstCodec :: default () ; let mut grpc = if let Some (interceptor) = interceptor { tonic :: server :: Grpc :: with_interceptor (codec , interceptor) } else { tonic :: server :: Grpc :: new (codec) } ; let res = grpc . unary (method , req) . await ; Ok (res) } ; Box :: pin (fut) } "/ibc.core.channel.v1.Query/PacketCommitments" => { # [allow (non_camel_case_types)] struct PacketCommitmentsSvc < T : Query > (pub Arc < T >) ; impl < T : Query > tonic :: server :: UnaryService < super :: QueryPacketCommitmentsRequest > for PacketCommitmentsSvc < T > { type Response = super :: QueryPacketCommitmentsResponse ; type Future = BoxFuture < tonic :: Response < Self :: Response > , tonic :: Status > ; fn call (& mut self , request : tonic :: Request < super :: QueryPacketCommitmentsRequest >) -> Self :: Future { let inner = self . 0 . clone () ; let fut = async move { (* inner) . packet_commitments (request) . await } ; Box :: pin (fut) } } let inner = self . inner . clone () ; let fut = async move { let interceptor = inner . 1 . clone () ; let inner = inner . 0 ; let method = PacketCommitmentsSvc (inner) ; let codec = tonic :: codec :: ProstCodec :: default () ; let mut grpc = if let Some (interceptor) = interceptor { tonic :: server :: Grpc :: with_interceptor (codec , interceptor) } else { tonic :: server :: Grpc :: new (codec) } ; let res = grpc . unary (method , req) . await ; Ok (res) } ; Box :: pin (fut) } "/ibc.core.channel.v1.Query/PacketReceipt" => { # [allow (non_camel_case_types)] struct PacketReceiptSvc < T : Query > (pub Arc < T >) ; impl < T : Query > tonic :: server :: UnaryService < super :: QueryPacketReceiptRequest > for PacketReceiptSvc < T > { type Response = super :: QueryPacketReceiptResponse ; type Future = BoxFuture < tonic :: Response < Self :: Response > , tonic :: Status > ; fn call (& mut self , request : tonic :: Request < super :: QueryPacketReceiptRequest >) -> Self :: Future { let inner = self . 0 . clone () ; let fut = async move { (* inner) . packet_receipt (request) . await } ; Box :: pin (fut) } } let inner = self . inner . clone () ; let fut = async move { let interceptor = inner . 1 . clone () ; let inner = inner . 0 ; let method = PacketReceiptSvc (inner) ; let codec = tonic :: codec :: ProstCodec :: default () ; let mut grpc = if let Some (interceptor) = interceptor { tonic :: server :: Grpc :: with_interceptor (codec , interceptor) } else { tonic :: server :: Grpc :: new (codec) } ; let res = grpc . unary (method , req) . await ; Ok (res) } ; Box :: pin (fut) } "/ibc.core.channel.v1.Query/PacketAcknowledgement" => { # [allow (non_camel_case_types)] struct PacketAcknowledgementSvc < T : Query > (pub Arc < T >) ; impl < T : Query > tonic :: server :: UnaryService < super :: QueryPacketAcknowledgementRequest > for PacketAcknowledgementSvc < T > { type Response = super :: QueryPacketAcknowledgementResponse ; type Future = BoxFuture < tonic :: Response < Self :: Response > , tonic :: Status > ; fn call (& mut self , request : tonic :: Request < super :: QueryPacketAcknowledgementRequest >) -> Self :: Future { let inner = self . 0 . clone () ; let fut = async move { (* inner) . packet_acknowledgement (request) . await } ; Box :: pin (fut) } } let inner = self . inner . clone () ; let fut = async move { let interceptor = inner . 1 . clone () ; let inner = inner . 0 ; let method = PacketAcknowledgementSvc (inner) ; let codec = tonic :: codec :: ProstCodec :: default () ; let mut grpc = if let Some (interceptor) = interceptor { tonic :: server :: Grpc :: with_interceptor (codec , interceptor) } else { tonic :: server :: Grpc :: new (codec) } ; let res = grpc . unary (method , req) . await ; Ok (res) } ; Box :: pin (fut) } "/ibc.core.channel.v1.Query/PacketAcknowledgements" => { # [allow (non_camel_case_types)] struct PacketAcknowledgementsSvc < T : Query > (pub Arc < T >) ; impl < T : Query > tonic :: server :: UnaryService < super :: QueryPacketAcknowledgementsRequest > for PacketAcknowledgementsSvc < T > { type Response = super :: QueryPacketAcknowledgementsResponse ; type Future = BoxFuture < tonic :: Response < Self :: Response > , tonic :: Status > ; fn call (& mut self , request : tonic :: Request < super :: QueryPacketAcknowledgementsRequest >) -> Self :: Future { let inner = self . 0 . clone () ; let fut = async move { (* inner) . packet_acknowledgements (request) . await } ; Box :: pin (fut) } } let inner = self . inner . clone () ; let fut = async move { let interceptor = inner . 1 . clone () ; let inner = inner . 0 ; let method = PacketAcknowledgementsSvc (inner) ; let codec = tonic :: codec :: ProstCodec :: default () ; let mut grpc = if let Some (interceptor) = interceptor { tonic :: server :: Grpc :: with_interceptor (codec , interceptor) } else { tonic :: server :: Grpc :: new (codec) } ; let res = grpc . unary (method , req) . await ; Ok (res) } ; Box :: pin (fut) } "/ibc.core.channel.v1.Query/UnreceivedPackets" => { # [allow (non_camel_case_types)] struct UnreceivedPacketsSvc < T : Query > (pub Arc < T >) ; impl < T : Query > tonic :: server :: UnaryService < super :: QueryUnreceivedPacketsRequest > for UnreceivedPacketsSvc < T > { type Response = super :: QueryUnreceivedPacketsResponse ; type Future = BoxFuture < tonic :: Response < Self :: Response > , tonic :: Status > ; fn call (& mut self , request : tonic :: Request < super :: QueryUnreceivedPacketsRequest >) -> Self :: Future { let inner = self . 0 . clone () ; let fut = async move { (* inner) . unreceived_packets (request) . await } ; Box :: pin (fut) } } let inner = self . inner . clone () ; let fut = async move { let interceptor = inner . 1 . clone () ; let inner = inner . 0 ; let method = UnreceivedPacketsSvc (inner) ; let codec = tonic :: codec :: ProstCodec :: default () ; let mut grpc = if let Some (interceptor) = interceptor { tonic :: server :: Grpc :: with_interceptor (codec , interceptor) } else { tonic :: server :: Grpc :: new (codec) } ; let res = grpc . unary (method , req) . await ; Ok (res) } ; Box :: pin (fut) } "/ibc.core.channel.v1.Query/UnreceivedAcks" => { # [allow (non_camel_case_types)] struct UnreceivedAcksSvc < T : Query > (pub Arc < T >) ; impl < T : Query > tonic :: server :: UnaryService < super :: QueryUnreceivedAcksRequest > for UnreceivedAcksSvc < T > { type Response = super :: QueryUnreceivedAcksResponse ; type Future = BoxFuture < tonic :: Response < Self :: Response > , tonic :: Status > ; fn call (& mut self , request : tonic :: Request < super :: QueryUnreceivedAcksRequest >) -> Self :: Future { let inner = self . 0 . clone () ; let fut = async move { (* inner) . unreceived_acks (request) . await } ; Box :: pin (fut) } } let inner = self . inner . clone () ; let fut = async move { let interceptor = inner . 1 . clone () ; let inner = inner . 0 ; let method = UnreceivedAcksSvc (inner) ; let codec = tonic :: codec :: ProstCodec :: default () ; let mut grpc = if let Some (interceptor) = interceptor { tonic :: server :: Grpc :: with_interceptor (codec , interceptor) } else { tonic :: server :: Grpc :: new (codec) } ; let res = grpc . unary (method , req) . await ; Ok (res) } ; Box :: pin (fut) } "/ibc.core.channel.v1.Query/NextSequenceReceive" => { # [allow (non_camel_case_types)] struct NextSequenceReceiveSvc < T : Query > (pub Arc < T >) ; impl < T : Query > tonic :: server :: UnaryService < super :: QueryNextSequenceReceiveRequest > for NextSequenceReceiveSvc < T > { type Response = super :: QueryNextSequenceReceiveResponse ; type Future = BoxFuture < tonic :: Response < Self :: Response > , tonic :: Status > ; fn call (& mut self , request : tonic :: Request < super :: QueryNextSequenceReceiveRequest >) -> Self :: Future { let inner = self . 0 . clone () ; let fut = async move { (* inner) . next_sequence_receive (request) . await } ; Box :: pin (fut) } } let inner = self . inner . clone () ; let fut = async move { let interceptor = inner . 1 . clone () ; let inner = inner . 0 ; let method = NextSequenceReceiveSvc (inner) ; let codec = tonic :: codec :: ProstCodec :: default () ; let mut grpc = if let Some (interceptor) = interceptor { tonic :: server :: Grpc :: with_interceptor (codec , interceptor) } else { tonic :: server :: Grpc :: new (codec) } ; let res = grpc . unary (method , req) . await ; Ok (res) } ; Box :: pin (fut) } _ => Box :: pin (async move { Ok (http :: Response :: builder () . status (200) . header ("grpc-status" , "12") . header ("content-type" , "application/grpc") . body (tonic :: body :: BoxBody :: empty ()) . unwrap ()) }) , } } } impl < T : Query > Clone for QueryServer < T > { fn clone (& self) -> Self { let inner = self . inner . clone () ; Self { inner } } } impl < T : Query > Clone for _Inner < T > { fn clone (& self) -> Self { Self (self . 0 . clone () , self . 1 . clone ()) } } impl < T : std :: fmt :: Debug > std :: fmt :: Debug for _Inner < T > { fn fmt (& self , f : & mut std :: fmt :: Formatter < '_ >) -> std :: fmt :: Result { write ! (f , "{:?}" , self . 0) } } impl < T : Query > tonic :: transport :: NamedService for QueryServer < T > { const NAME : & 'static str = "ibc.core.channel.v1.Query" ; } }
\ No newline at end of file
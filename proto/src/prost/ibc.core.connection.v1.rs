@@ -227,7 +227,7 @@ pub struct MsgConnectionOpenConfirm {
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MsgConnectionOpenConfirmResponse {
 }
-# [doc = r" Generated client implementations."] pub mod msg_client { # ! [allow (unused_variables , dead_code , missing_docs)] use tonic :: codegen :: * ; # [doc = " Msg defines the ibc/connection Msg service."] pub struct MsgClient < T > { inner : tonic :: client :: Grpc < T > , } impl MsgClient < tonic :: transport :: Channel > { # [doc = r" Attempt to create a new client by connecting to a given endpoint."] pub async fn connect < D > (dst : D) -> Result < Self , tonic :: transport :: Error > where D : std :: convert :: TryInto < tonic :: transport :: Endpoint > , D :: Error : Into < StdError > , { let conn = tonic :: transport :: Endpoint :: new (dst) ? . connect () . await ? ; Ok (Self :: new (conn)) } } impl < T > MsgClient < T > where T : tonic :: client :: GrpcService < tonic :: body :: BoxBody > , T :: ResponseBody : Body + HttpBody + Send + 'static , T :: Error : Into < StdError > , < T :: ResponseBody as HttpBody > :: Error : Into < StdError > + Send , { pub fn new (inner : T) -> Self { let inner = tonic :: client :: Grpc :: new (inner) ; Self { inner } } pub fn with_interceptor (inner : T , interceptor : impl Into < tonic :: Interceptor >) -> Self { let inner = tonic :: client :: Grpc :: with_interceptor (inner , interceptor) ; Self { inner } } # [doc = " ConnectionOpenInit defines a rpc handler method for MsgConnectionOpenInit."] pub async fn connection_open_init (& mut self , request : impl tonic :: IntoRequest < super :: MsgConnectionOpenInit > ,) -> Result < tonic :: Response < super :: MsgConnectionOpenInitResponse > , tonic :: Status > { self . inner . ready () . await . map_err (| e | { tonic :: Status :: new (tonic :: Code :: Unknown , format ! ("Service was not ready: {}" , e . into ())) }) ? ; let codec = tonic :: codec :: ProstCodec :: default () ; let path = http :: uri :: PathAndQuery :: from_static ("/ibc.core.connection.v1.Msg/ConnectionOpenInit") ; self . inner . unary (request . into_request () , path , codec) . await } # [doc = " ConnectionOpenTry defines a rpc handler method for MsgConnectionOpenTry."] pub async fn connection_open_try (& mut self , request : impl tonic :: IntoRequest < super :: MsgConnectionOpenTry > ,) -> Result < tonic :: Response < super :: MsgConnectionOpenTryResponse > , tonic :: Status > { self . inner . ready () . await . map_err (| e | { tonic :: Status :: new (tonic :: Code :: Unknown , format ! ("Service was not ready: {}" , e . into ())) }) ? ; let codec = tonic :: codec :: ProstCodec :: default () ; let path = http :: uri :: PathAndQuery :: from_static ("/ibc.core.connection.v1.Msg/ConnectionOpenTry") ; self . inner . unary (request . into_request () , path , codec) . await } # [doc = " ConnectionOpenAck defines a rpc handler method for MsgConnectionOpenAck."] pub async fn connection_open_ack (& mut self , request : impl tonic :: IntoRequest < super :: MsgConnectionOpenAck > ,) -> Result < tonic :: Response < super :: MsgConnectionOpenAckResponse > , tonic :: Status > { self . inner . ready () . await . map_err (| e | { tonic :: Status :: new (tonic :: Code :: Unknown , format ! ("Service was not ready: {}" , e . into ())) }) ? ; let codec = tonic :: codec :: ProstCodec :: default () ; let path = http :: uri :: PathAndQuery :: from_static ("/ibc.core.connection.v1.Msg/ConnectionOpenAck") ; self . inner . unary (request . into_request () , path , codec) . await } # [doc = " ConnectionOpenConfirm defines a rpc handler method for MsgConnectionOpenConfirm."] pub async fn connection_open_confirm (& mut self , request : impl tonic :: IntoRequest < super :: MsgConnectionOpenConfirm > ,) -> Result < tonic :: Response < super :: MsgConnectionOpenConfirmResponse > , tonic :: Status > { self . inner . ready () . await . map_err (| e | { tonic :: Status :: new (tonic :: Code :: Unknown , format ! ("Service was not ready: {}" , e . into ())) }) ? ; let codec = tonic :: codec :: ProstCodec :: default () ; let path = http :: uri :: PathAndQuery :: from_static ("/ibc.core.connection.v1.Msg/ConnectionOpenConfirm") ; self . inner . unary (request . into_request () , path , codec) . await } } impl < T : Clone > Clone for MsgClient < T > { fn clone (& self) -> Self { Self { inner : self . inner . clone () , } } } impl < T > std :: fmt :: Debug for MsgClient < T > { fn fmt (& self , f : & mut std :: fmt :: Formatter < '_ >) -> std :: fmt :: Result { write ! (f , "MsgClient {{ ... }}") } } }/// QueryConnectionRequest is the request type for the Query/Connection RPC
+# [doc = r" Generated client implementations."] pub mod msg_client { # ! [allow (unused_variables , dead_code , missing_docs)] use tonic :: codegen :: * ; # [doc = " Msg defines the ibc/connection Msg service."] pub struct MsgClient < T > { inner : tonic :: client :: Grpc < T > , } impl MsgClient < tonic :: transport :: Channel > { # [doc = r" Attempt to create a new client by connecting to a given endpoint."] pub async fn connect < D > (dst : D) -> Result < Self , tonic :: transport :: Error > where D : std :: convert :: TryInto < tonic :: transport :: Endpoint > , D :: Error : Into < StdError > , { let conn = tonic :: transport :: Endpoint :: new (dst) ? . connect () . await ? ; Ok (Self :: new (conn)) } } impl < T > MsgClient < T > where T : tonic :: client :: GrpcService < tonic :: body :: BoxBody > , T :: ResponseBody : Body + HttpBody + Send + 'static , T :: Error : Into < StdError > , < T :: ResponseBody as HttpBody > :: Error : Into < StdError > + Send , { pub fn new (inner : T) -> Self { let inner = tonic :: client :: Grpc :: new (inner) ; Self { inner } } pub fn with_interceptor (inner : T , interceptor : impl Into < tonic :: Interceptor >) -> Self { let inner = tonic :: client :: Grpc :: with_interceptor (inner , interceptor) ; Self { inner } } # [doc = " ConnectionOpenInit defines a rpc handler method for MsgConnectionOpenInit."] pub async fn connection_open_init (& mut self , request : impl tonic :: IntoRequest < super :: MsgConnectionOpenInit > ,) -> Result < tonic :: Response < super :: MsgConnectionOpenInitResponse > , tonic :: Status > { self . inner . ready () . await . map_err (| e | { tonic :: Status :: new (tonic :: Code :: Unknown , format ! ("Service was not ready: {}" , e . into ())) }) ? ; let codec = tonic :: codec :: ProstCodec :: default () ; let path = http :: uri :: PathAndQuery :: from_static ("/ibc.core.connection.v1.Msg/ConnectionOpenInit") ; self . inner . unary (request . into_request () , path , codec) . await } # [doc = " ConnectionOpenTry defines a rpc handler method for MsgConnectionOpenTry."] pub async fn connection_open_try (& mut self , request : impl tonic :: IntoRequest < super :: MsgConnectionOpenTry > ,) -> Result < tonic :: Response < super :: MsgConnectionOpenTryResponse > , tonic :: Status > { self . inner . ready () . await . map_err (| e | { tonic :: Status :: new (tonic :: Code :: Unknown , format ! ("Service was not ready: {}" , e . into ())) }) ? ; let codec = tonic :: codec :: ProstCodec :: default () ; let path = http :: uri :: PathAndQuery :: from_static ("/ibc.core.connection.v1.Msg/ConnectionOpenTry") ; self . inner . unary (request . into_request () , path , codec) . await } # [doc = " ConnectionOpenAck defines a rpc handler method for MsgConnectionOpenAck."] pub async fn connection_open_ack (& mut self , request : impl tonic :: IntoRequest < super :: MsgConnectionOpenAck > ,) -> Result < tonic :: Response < super :: MsgConnectionOpenAckResponse > , tonic :: Status > { self . inner . ready () . await . map_err (| e | { tonic :: Status :: new (tonic :: Code :: Unknown , format ! ("Service was not ready: {}" , e . into ())) }) ? ; let codec = tonic :: codec :: ProstCodec :: default () ; let path = http :: uri :: PathAndQuery :: from_static ("/ibc.core.connection.v1.Msg/ConnectionOpenAck") ; self . inner . unary (request . into_request () , path , codec) . await } # [doc = " ConnectionOpenConfirm defines a rpc handler method for MsgConnectionOpenConfirm."] pub async fn connection_open_confirm (& mut self , request : impl tonic :: IntoRequest < super :: MsgConnectionOpenConfirm > ,) -> Result < tonic :: Response < super :: MsgConnectionOpenConfirmResponse > , tonic :: Status > { self . inner . ready () . await . map_err (| e | { tonic :: Status :: new (tonic :: Code :: Unknown , format ! ("Service was not ready: {}" , e . into ())) }) ? ; let codec = tonic :: codec :: ProstCodec :: default () ; let path = http :: uri :: PathAndQuery :: from_static ("/ibc.core.connection.v1.Msg/ConnectionOpenConfirm") ; self . inner . unary (request . into_request () , path , codec) . await } } impl < T : Clone > Clone for MsgClient < T > { fn clone (& self) -> Self { Self { inner : self . inner . clone () , } } } impl < T > std :: fmt :: Debug for MsgClient < T > { fn fmt (& self , f : & mut std :: fmt :: Formatter < '_ >) -> std :: fmt :: Result { write ! (f , "MsgClient {{ ... }}") } } }# [doc = r" Generated server implementations."] pub mod msg_server { # ! [allow (unused_variables , dead_code , missing_docs)] use tonic :: codegen :: * ; # [doc = "Generated trait containing gRPC methods that should be implemented for use with MsgServer."] # [async_trait] pub trait Msg : Send + Sync + 'static { # [doc = " ConnectionOpenInit defines a rpc handler method for MsgConnectionOpenInit."] async fn connection_open_init (& self , request : tonic :: Request < super :: MsgConnectionOpenInit >) -> Result < tonic :: Response < super :: MsgConnectionOpenInitResponse > , tonic :: Status > ; # [doc = " ConnectionOpenTry defines a rpc handler method for MsgConnectionOpenTry."] async fn connection_open_try (& self , request : tonic :: Request < super :: MsgConnectionOpenTry >) -> Result < tonic :: Response < super :: MsgConnectionOpenTryResponse > , tonic :: Status > ; # [doc = " ConnectionOpenAck defines a rpc handler method for MsgConnectionOpenAck."] async fn connection_open_ack (& self , request : tonic :: Request < super :: MsgConnectionOpenAck >) -> Result < tonic :: Response < super :: MsgConnectionOpenAckResponse > , tonic :: Status > ; # [doc = " ConnectionOpenConfirm defines a rpc handler method for MsgConnectionOpenConfirm."] async fn connection_open_confirm (& self , request : tonic :: Request < super :: MsgConnectionOpenConfirm >) -> Result < tonic :: Response < super :: MsgConnectionOpenConfirmResponse > , tonic :: Status > ; } # [doc = " Msg defines the ibc/connection Msg service."] # [derive (Debug)] pub struct MsgServer < T : Msg > { inner : _Inner < T > , } struct _Inner < T > (Arc < T > , Option < tonic :: Interceptor >) ; impl < T : Msg > MsgServer < T > { pub fn new (inner : T) -> Self { let inner = Arc :: new (inner) ; let inner = _Inner (inner , None) ; Self { inner } } pub fn with_interceptor (inner : T , interceptor : impl Into < tonic :: Interceptor >) -> Self { let inner = Arc :: new (inner) ; let inner = _Inner (inner , Some (interceptor . into ())) ; Self { inner } } } impl < T , B > Service < http :: Request < B >> for MsgServer < T > where T : Msg , B : HttpBody + Send + Sync + 'static , B :: Error : Into < StdError > + Send + 'static , { type Response = http :: Response < tonic :: body :: BoxBody > ; type Error = Never ; type Future = BoxFuture < Self :: Response , Self :: Error > ; fn poll_ready (& mut self , _cx : & mut Context < '_ >) -> Poll < Result < () , Self :: Error >> { Poll :: Ready (Ok (())) } fn call (& mut self , req : http :: Request < B >) -> Self :: Future { let inner = self . inner . clone () ; match req . uri () . path () { "/ibc.core.connection.v1.Msg/ConnectionOpenInit" => { # [allow (non_camel_case_types)] struct ConnectionOpenInitSvc < T : Msg > (pub Arc < T >) ; impl < T : Msg > tonic :: server :: UnaryService < super :: MsgConnectionOpenInit > for ConnectionOpenInitSvc < T > { type Response = super :: MsgConnectionOpenInitResponse ; type Future = BoxFuture < tonic :: Response < Self :: Response > , tonic :: Status > ; fn call (& mut self , request : tonic :: Request < super :: MsgConnectionOpenInit >) -> Self :: Future { let inner = self . 0 . clone () ; let fut = async move { (* inner) . connection_open_init (request) . await } ; Box :: pin (fut) } } let inner = self . inner . clone () ; let fut = async move { let interceptor = inner . 1 . clone () ; let inner = inner . 0 ; let method = ConnectionOpenInitSvc (inner) ; let codec = tonic :: codec :: ProstCodec :: default () ; let mut grpc = if let Some (interceptor) = interceptor { tonic :: server :: Grpc :: with_interceptor (codec , interceptor) } else { tonic :: server :: Grpc :: new (codec) } ; let res = grpc . unary (method , req) . await ; Ok (res) } ; Box :: pin (fut) } "/ibc.core.connection.v1.Msg/ConnectionOpenTry" => { # [allow (non_camel_case_types)] struct ConnectionOpenTrySvc < T : Msg > (pub Arc < T >) ; impl < T : Msg > tonic :: server :: UnaryService < super :: MsgConnectionOpenTry > for ConnectionOpenTrySvc < T > { type Response = super :: MsgConnectionOpenTryResponse ; type Future = BoxFuture < tonic :: Response < Self :: Response > , tonic :: Status > ; fn call (& mut self , request : tonic :: Request < super :: MsgConnectionOpenTry >) -> Self :: Future { let inner = self . 0 . clone () ; let fut = async move { (* inner) . connection_open_try (request) . await } ; Box :: pin (fut) } } let inner = self . inner . clone () ; let fut = async move { let interceptor = inner . 1 . clone () ; let inner = inner . 0 ; let method = ConnectionOpenTrySvc (inner) ; let codec = tonic :: codec :: ProstCodec :: default () ; let mut grpc = if let Some (interceptor) = interceptor { tonic :: server :: Grpc :: with_interceptor (codec , interceptor) } else { tonic :: server :: Grpc :: new (codec) } ; let res = grpc . unary (method , req) . await ; Ok (res) } ; Box :: pin (fut) } "/ibc.core.connection.v1.Msg/ConnectionOpenAck" => { # [allow (non_camel_case_types)] struct ConnectionOpenAckSvc < T : Msg > (pub Arc < T >) ; impl < T : Msg > tonic :: server :: UnaryService < super :: MsgConnectionOpenAck > for ConnectionOpenAckSvc < T > { type Response = super :: MsgConnectionOpenAckResponse ; type Future = BoxFuture < tonic :: Response < Self :: Response > , tonic :: Status > ; fn call (& mut self , request : tonic :: Request < super :: MsgConnectionOpenAck >) -> Self :: Future { let inner = self . 0 . clone () ; let fut = async move { (* inner) . connection_open_ack (request) . await } ; Box :: pin (fut) } } let inner = self . inner . clone () ; let fut = async move { let interceptor = inner . 1 . clone () ; let inner = inner . 0 ; let method = ConnectionOpenAckSvc (inner) ; let codec = tonic :: codec :: ProstCodec :: default () ; let mut grpc = if let Some (interceptor) = interceptor { tonic :: server :: Grpc :: with_interceptor (codec , interceptor) } else { tonic :: server :: Grpc :: new (codec) } ; let res = grpc . unary (method , req) . await ; Ok (res) } ; Box :: pin (fut) } "/ibc.core.connection.v1.Msg/ConnectionOpenConfirm" => { # [allow (non_camel_case_types)] struct ConnectionOpenConfirmSvc < T : Msg > (pub Arc < T >) ; impl < T : Msg > tonic :: server :: UnaryService < super :: MsgConnectionOpenConfirm > for ConnectionOpenConfirmSvc < T > { type Response = super :: MsgConnectionOpenConfirmResponse ; type Future = BoxFuture < tonic :: Response < Self :: Response > , tonic :: Status > ; fn call (& mut self , request : tonic :: Request < super :: MsgConnectionOpenConfirm >) -> Self :: Future { let inner = self . 0 . clone () ; let fut = async move { (* inner) . connection_open_confirm (request) . await } ; Box :: pin (fut) } } let inner = self . inner . clone () ; let fut = async move { let interceptor = inner . 1 . clone () ; let inner = inner . 0 ; let method = ConnectionOpenConfirmSvc (inner) ; let codec = tonic :: codec :: ProstCodec :: default () ; let mut grpc = if let Some (interceptor) = interceptor { tonic :: server :: Grpc :: with_interceptor (codec , interceptor) } else { tonic :: server :: Grpc :: new (codec) } ; let res = grpc . unary (method , req) . await ; Ok (res) } ; Box :: pin (fut) } _ => Box :: pin (async move { Ok (http :: Response :: builder () . status (200) . header ("grpc-status" , "12") . header ("content-type" , "application/grpc") . body (tonic :: body :: BoxBody :: empty ()) . unwrap ()) }) , } } } impl < T : Msg > Clone for MsgServer < T > { fn clone (& self) -> Self { let inner = self . inner . clone () ; Self { inner } } } impl < T : Msg > Clone for _Inner < T > { fn clone (& self) -> Self { Self (self . 0 . clone () , self . 1 . clone ()) } } impl < T : std :: fmt :: Debug > std :: fmt :: Debug for _Inner < T > { fn fmt (& self , f : & mut std :: fmt :: Formatter < '_ >) -> std :: fmt :: Result { write ! (f , "{:?}" , self . 0) } } impl < T : Msg > tonic :: transport :: NamedService for MsgServer < T > { const NAME : & 'static str = "ibc.core.connection.v1.Msg" ; } }/// QueryConnectionRequest is the request type for the Query/Connection RPC
 /// method
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryConnectionRequest {
@@ -344,4 +344,4 @@ pub struct QueryConnectionConsensusStateResponse {
     #[prost(message, optional, tag="4")]
     pub proof_height: ::std::option::Option<super::super::client::v1::Height>,
 }
-# [doc = r" Generated client implementations."] pub mod query_client { # ! [allow (unused_variables , dead_code , missing_docs)] use tonic :: codegen :: * ; # [doc = " Query provides defines the gRPC querier service"] pub struct QueryClient < T > { inner : tonic :: client :: Grpc < T > , } impl QueryClient < tonic :: transport :: Channel > { # [doc = r" Attempt to create a new client by connecting to a given endpoint."] pub async fn connect < D > (dst : D) -> Result < Self , tonic :: transport :: Error > where D : std :: convert :: TryInto < tonic :: transport :: Endpoint > , D :: Error : Into < StdError > , { let conn = tonic :: transport :: Endpoint :: new (dst) ? . connect () . await ? ; Ok (Self :: new (conn)) } } impl < T > QueryClient < T > where T : tonic :: client :: GrpcService < tonic :: body :: BoxBody > , T :: ResponseBody : Body + HttpBody + Send + 'static , T :: Error : Into < StdError > , < T :: ResponseBody as HttpBody > :: Error : Into < StdError > + Send , { pub fn new (inner : T) -> Self { let inner = tonic :: client :: Grpc :: new (inner) ; Self { inner } } pub fn with_interceptor (inner : T , interceptor : impl Into < tonic :: Interceptor >) -> Self { let inner = tonic :: client :: Grpc :: with_interceptor (inner , interceptor) ; Self { inner } } # [doc = " Connection queries an IBC connection end."] pub async fn connection (& mut self , request : impl tonic :: IntoRequest < super :: QueryConnectionRequest > ,) -> Result < tonic :: Response < super :: QueryConnectionResponse > , tonic :: Status > { self . inner . ready () . await . map_err (| e | { tonic :: Status :: new (tonic :: Code :: Unknown , format ! ("Service was not ready: {}" , e . into ())) }) ? ; let codec = tonic :: codec :: ProstCodec :: default () ; let path = http :: uri :: PathAndQuery :: from_static ("/ibc.core.connection.v1.Query/Connection") ; self . inner . unary (request . into_request () , path , codec) . await } # [doc = " Connections queries all the IBC connections of a chain."] pub async fn connections (& mut self , request : impl tonic :: IntoRequest < super :: QueryConnectionsRequest > ,) -> Result < tonic :: Response < super :: QueryConnectionsResponse > , tonic :: Status > { self . inner . ready () . await . map_err (| e | { tonic :: Status :: new (tonic :: Code :: Unknown , format ! ("Service was not ready: {}" , e . into ())) }) ? ; let codec = tonic :: codec :: ProstCodec :: default () ; let path = http :: uri :: PathAndQuery :: from_static ("/ibc.core.connection.v1.Query/Connections") ; self . inner . unary (request . into_request () , path , codec) . await } # [doc = " ClientConnections queries the connection paths associated with a client"] # [doc = " state."] pub async fn client_connections (& mut self , request : impl tonic :: IntoRequest < super :: QueryClientConnectionsRequest > ,) -> Result < tonic :: Response < super :: QueryClientConnectionsResponse > , tonic :: Status > { self . inner . ready () . await . map_err (| e | { tonic :: Status :: new (tonic :: Code :: Unknown , format ! ("Service was not ready: {}" , e . into ())) }) ? ; let codec = tonic :: codec :: ProstCodec :: default () ; let path = http :: uri :: PathAndQuery :: from_static ("/ibc.core.connection.v1.Query/ClientConnections") ; self . inner . unary (request . into_request () , path , codec) . await } # [doc = " ConnectionClientState queries the client state associated with the"] # [doc = " connection."] pub async fn connection_client_state (& mut self , request : impl tonic :: IntoRequest < super :: QueryConnectionClientStateRequest > ,) -> Result < tonic :: Response < super :: QueryConnectionClientStateResponse > , tonic :: Status > { self . inner . ready () . await . map_err (| e | { tonic :: Status :: new (tonic :: Code :: Unknown , format ! ("Service was not ready: {}" , e . into ())) }) ? ; let codec = tonic :: codec :: ProstCodec :: default () ; let path = http :: uri :: PathAndQuery :: from_static ("/ibc.core.connection.v1.Query/ConnectionClientState") ; self . inner . unary (request . into_request () , path , codec) . await } # [doc = " ConnectionConsensusState queries the consensus state associated with the"] # [doc = " connection."] pub async fn connection_consensus_state (& mut self , request : impl tonic :: IntoRequest < super :: QueryConnectionConsensusStateRequest > ,) -> Result < tonic :: Response < super :: QueryConnectionConsensusStateResponse > , tonic :: Status > { self . inner . ready () . await . map_err (| e | { tonic :: Status :: new (tonic :: Code :: Unknown , format ! ("Service was not ready: {}" , e . into ())) }) ? ; let codec = tonic :: codec :: ProstCodec :: default () ; let path = http :: uri :: PathAndQuery :: from_static ("/ibc.core.connection.v1.Query/ConnectionConsensusState") ; self . inner . unary (request . into_request () , path , codec) . await } } impl < T : Clone > Clone for QueryClient < T > { fn clone (& self) -> Self { Self { inner : self . inner . clone () , } } } impl < T > std :: fmt :: Debug for QueryClient < T > { fn fmt (& self , f : & mut std :: fmt :: Formatter < '_ >) -> std :: fmt :: Result { write ! (f , "QueryClient {{ ... }}") } } }
\ No newline at end of file
+# [doc = r" Generated client implementations."] pub mod query_client { # ! [allow (unused_variables , dead_code , missing_docs)] use tonic :: codegen :: * ; # [doc = " Query provides defines the gRPC querier service"] pub struct QueryClient < T > { inner : tonic :: client :: Grpc < T > , } impl QueryClient < tonic :: transport :: Channel > { # [doc = r" Attempt to create a new client by connecting to a given endpoint."] pub async fn connect < D > (dst : D) -> Result < Self , tonic :: transport :: Error > where D : std :: convert :: TryInto < tonic :: transport :: Endpoint > , D :: Error : Into < StdError > , { let conn = tonic :: transport :: Endpoint :: new (dst) ? . connect () . await ? ; Ok (Self :: new (conn)) } } impl < T > QueryClient < T > where T : tonic :: client :: GrpcService < tonic :: body :: BoxBody > , T :: ResponseBody : Body + HttpBody + Send + 'static , T :: Error : Into < StdError > , < T :: ResponseBody as HttpBody > :: Error : Into < StdError > + Send , { pub fn new (inner : T) -> Self { let inner = tonic :: client :: Grpc :: new (inner) ; Self { inner } } pub fn with_interceptor (inner : T , interceptor : impl Into < tonic :: Interceptor >) -> Self { let inner = tonic :: client :: Grpc :: with_interceptor (inner , interceptor) ; Self { inner } } # [doc = " Connection queries an IBC connection end."] pub async fn connection (& mut self , request : impl tonic :: IntoRequest < super :: QueryConnectionRequest > ,) -> Result < tonic :: Response < super :: QueryConnectionResponse > , tonic :: Status > { self . inner . ready () . await . map_err (| e | { tonic :: Status :: new (tonic :: Code :: Unknown , format ! ("Service was not ready: {}" , e . into ())) }) ? ; let codec = tonic :: codec :: ProstCodec :: default () ; let path = http :: uri :: PathAndQuery :: from_static ("/ibc.core.connection.v1.Query/Connection") ; self . inner . unary (request . into_request () , path , codec) . await } # [doc = " Connections queries all the IBC connections of a chain."] pub async fn connections (& mut self , request : impl tonic :: IntoRequest < super :: QueryConnectionsRequest > ,) -> Result < tonic :: Response < super :: QueryConnectionsResponse > , tonic :: Status > { self . inner . ready () . await . map_err (| e | { tonic :: Status :: new (tonic :: Code :: Unknown , format ! ("Service was not ready: {}" , e . into ())) }) ? ; let codec = tonic :: codec :: ProstCodec :: default () ; let path = http :: uri :: PathAndQuery :: from_static ("/ibc.core.connection.v1.Query/Connections") ; self . inner . unary (request . into_request () , path , codec) . await } # [doc = " ClientConnections queries the connection paths associated with a client"] # [doc = " state."] pub async fn client_connections (& mut self , request : impl tonic :: IntoRequest < super :: QueryClientConnectionsRequest > ,) -> Result < tonic :: Response < super :: QueryClientConnectionsResponse > , tonic :: Status > { self . inner . ready () . await . map_err (| e | { tonic :: Status :: new (tonic :: Code :: Unknown , format ! ("Service was not ready: {}" , e . into ())) }) ? ; let codec = tonic :: codec :: ProstCodec :: default () ; let path = http :: uri :: PathAndQuery :: from_static ("/ibc.core.connection.v1.Query/ClientConnections") ; self . inner . unary (request . into_request () , path , codec) . await } # [doc = " ConnectionClientState queries the client state associated with the"] # [doc = " connection."] pub async fn connection_client_state (& mut self , request : impl tonic :: IntoRequest < super :: QueryConnectionClientStateRequest > ,) -> Result < tonic :: Response < super :: QueryConnectionClientStateResponse > , tonic :: Status > { self . inner . ready () . await . map_err (| e | { tonic :: Status :: new (tonic :: Code :: Unknown , format ! ("Service was not ready: {}" , e . into ())) }) ? ; let codec = tonic :: codec :: ProstCodec :: default () ; let path = http :: uri :: PathAndQuery :: from_static ("/ibc.core.connection.v1.Query/ConnectionClientState") ; self . inner . unary (request . into_request () , path , codec) . await } # [doc = " ConnectionConsensusState queries the consensus state associated with the"] # [doc = " connection."] pub async fn connection_consensus_state (& mut self , request : impl tonic :: IntoRequest < super :: QueryConnectionConsensusStateRequest > ,) -> Result < tonic :: Response < super :: QueryConnectionConsensusStateResponse > , tonic :: Status > { self . inner . ready () . await . map_err (| e | { tonic :: Status :: new (tonic :: Code :: Unknown , format ! ("Service was not ready: {}" , e . into ())) }) ? ; let codec = tonic :: codec :: ProstCodec :: default () ; let path = http :: uri :: PathAndQuery :: from_static ("/ibc.core.connection.v1.Query/ConnectionConsensusState") ; self . inner . unary (request . into_request () , path , codec) . await } } impl < T : Clone > Clone for QueryClient < T > { fn clone (& self) -> Self { Self { inner : self . inner . clone () , } } } impl < T > std :: fmt :: Debug for QueryClient < T > { fn fmt (& self , f : & mut std :: fmt :: Formatter < '_ >) -> std :: fmt :: Result { write ! (f , "QueryClient {{ ... }}") } } }# [doc = r" Generated server implementations."] pub mod query_server { # ! [allow (unused_variables , dead_code , missing_docs)] use tonic :: codegen :: * ; # [doc = "Generated trait containing gRPC methods that should be implemented for use with QueryServer."] # [async_trait] pub trait Query : Send + Sync + 'static { # [doc = " Connection queries an IBC connection end."] async fn connection (& self , request : tonic :: Request < super :: QueryConnectionRequest >) -> Result < tonic :: Response < super :: QueryConnectionResponse > , tonic :: Status > ; # [doc = " Connections queries all the IBC connections of a chain."] async fn connections (& self , request : tonic :: Request < super :: QueryConnectionsRequest >) -> Result < tonic :: Response < super :: QueryConnectionsResponse > , tonic :: Status > ; # [doc = " ClientConnections queries the connection paths associated with a client"] # [doc = " state."] async fn client_connections (& self , request : tonic :: Request < super :: QueryClientConnectionsRequest >) -> Result < tonic :: Response < super :: QueryClientConnectionsResponse > , tonic :: Status > ; # [doc = " ConnectionClientState queries the client state associated with the"] # [doc = " connection."] async fn connection_client_state (& self , request : tonic :: Request < super :: QueryConnectionClientStateRequest >) -> Result < tonic :: Response < super :: QueryConnectionClientStateResponse > , tonic :: Status > ; # [doc = " ConnectionConsensusState queries the consensus state associated with the"] # [doc = " connection."] async fn connection_consensus_state (& self , request : tonic :: Request < super :: QueryConnectionConsensusStateRequest >) -> Result < tonic :: Response < super :: QueryConnectionConsensusStateResponse > , tonic :: Status > ; } # [doc = " Query provides defines the gRPC querier service"] # [derive (Debug)] pub struct QueryServer < T : Query > { inner : _Inner < T > , } struct _Inner < T > (Arc < T > , Option < tonic :: Interceptor >) ; impl < T : Query > QueryServer < T > { pub fn new (inner : T) -> Self { let inner = Arc :: new (inner) ; let inner = _Inner (inner , None) ; Self { inner } } pub fn with_interceptor (inner : T , interceptor : impl Into < tonic :: Interceptor >) -> Self { let inner = Arc :: new (inner) ; let inner = _Inner (inner , Some (interceptor . into ())) ; Self { inner } } } impl < T , B > Service < http :: Request < B >> for QueryServer < T > where T : Query , B : HttpBody + Send + Sync + 'static , B :: Error : Into < StdError > + Send + 'static , { type Response = http :: Response < tonic :: body :: BoxBody > ; type Error = Never ; type Future = BoxFuture < Self :: Response , Self :: Error > ; fn poll_ready (& mut self , _cx : & mut Context < '_ >) -> Poll < Result < () , Self :: Error >> { Poll :: Ready (Ok (())) } fn call (& mut self , req : http :: Request < B >) -> Self :: Future { let inner = self . inner . clone () ; match req . uri () . path () { "/ibc.core.connection.v1.Query/Connection" => { # [allow (non_camel_case_types)] struct ConnectionSvc < T : Query > (pub Arc < T >) ; impl < T : Query > tonic :: server :: UnaryService < super :: QueryConnectionRequest > for ConnectionSvc < T > { type Response = super :: QueryConnectionResponse ; type Future = BoxFuture < tonic :: Response < Self :: Response > , tonic :: Status > ; fn call (& mut self , request : tonic :: Request < super :: QueryConnectionRequest >) -> Self :: Future { let inner = self . 0 . clone () ; let fut = async move { (* inner) . connection (request) . await } ; Box :: pin (fut) } } let inner = self . inner . clone () ; let fut = async move { let interceptor = inner . 1 . clone () ; let inner = inner . 0 ; let method = ConnectionSvc (inner) ; let codec = tonic :: codec :: ProstCodec :: default () ; let mut grpc = if let Some (interceptor) = interceptor { tonic :: server :: Grpc :: with_interceptor (codec , interceptor) } else { tonic :: server :: Grpc :: new (codec) } ; let res = grpc . unary (method , req) . await ; Ok (res) } ; Box :: pin (fut) } "/ibc.core.connection.v1.Query/Connections" => { # [allow (non_camel_case_types)] struct ConnectionsSvc < T : Query > (pub Arc < T >) ; impl < T : Query > tonic :: server :: UnaryService < super :: QueryConnectionsRequest > for ConnectionsSvc < T > { type Response = super :: QueryConnectionsResponse ; type Future = BoxFuture < tonic :: Response < Self :: Response > , tonic :: Status > ; fn call (& mut self , request : tonic :: Request < super :: QueryConnectionsRequest >) -> Self :: Future { let inner = self . 0 . clone () ; let fut = async move { (* inner) . connections (request) . await } ; Box :: pin (fut) } } let inner = self . inner . clone () ; let fut = async move { let interceptor = inner . 1 . clone () ; let inner = inner . 0 ; let method = ConnectionsSvc (inner) ; let codec = tonic :: codec :: ProstCodec :: default () ; let mut grpc = if let Some (interceptor) = interceptor { tonic :: server :: Grpc :: with_interceptor (codec , interceptor) } else { tonic :: server :: Grpc :: new (codec) } ; let res = grpc . unary (method , req) . await ; Ok (res) } ; Box :: pin (fut) } "/ibc.core.connection.v1.Query/ClientConnections" => { # [allow (non_camel_case_types)] struct ClientConnectionsSvc < T : Query > (pub Arc < T >) ; impl < T : Query > tonic :: server :: UnaryService < super :: QueryClientConnectionsRequest > for ClientConnectionsSvc < T > { type Response = super :: QueryClientConnectionsResponse ; type Future = BoxFuture < tonic :: Response < Self :: Response > , tonic :: Status > ; fn call (& mut self , request : tonic :: Request < super :: QueryClientConnectionsRequest >) -> Self :: Future { let inner = self . 0 . clone () ; let fut = async move { (* inner) . client_connections (request) . await } ; Box :: pin (fut) } } let inner = self . inner . clone () ; let fut = async move { let interceptor = inner . 1 . clone () ; let inner = inner . 0 ; let method = ClientConnectionsSvc (inner) ; let codec = tonic :: codec :: ProstCodec :: default () ; let mut grpc = if let Some (interceptor) = interceptor { tonic :: server :: Grpc :: with_interceptor (codec , interceptor) } else { tonic :: server :: Grpc :: new (codec) } ; let res = grpc . unary (method , req) . await ; Ok (res) } ; Box :: pin (fut) } "/ibc.core.connection.v1.Query/ConnectionClientState" => { # [allow (non_camel_case_types)] struct ConnectionClientStateSvc < T : Query > (pub Arc < T >) ; impl < T : Query > tonic :: server :: UnaryService < super :: QueryConnectionClientStateRequest > for ConnectionClientStateSvc < T > { type Response = super :: QueryConnectionClientStateResponse ; type Future = BoxFuture < tonic :: Response < Self :: Response > , tonic :: Status > ; fn call (& mut self , request : tonic :: Request < super :: QueryConnectionClientStateRequest >) -> Self :: Future { let inner = self . 0 . clone () ; let fut = async move { (* inner) . connection_client_state (request) . await } ; Box :: pin (fut) } } let inner = self . inner . clone () ; let fut = async move { let interceptor = inner . 1 . clone () ; let inner = inner . 0 ; let method = ConnectionClientStateSvc (inner) ; let codec = tonic :: codec :: ProstCodec :: default () ; let mut grpc = if let Some (interceptor) = interceptor { tonic :: server :: Grpc :: with_interceptor (codec , interceptor) } else { tonic :: server :: Grpc :: new (codec) } ; let res = grpc . unary (method , req) . await ; Ok (res) } ; Box :: pin (fut) } "/ibc.core.connection.v1.Query/ConnectionConsensusState" => { # [allow (non_camel_case_types)] struct ConnectionConsensusStateSvc < T : Query > (pub Arc < T >) ; impl < T : Query > tonic :: server :: UnaryService < super :: QueryConnectionConsensusStateRequest > for ConnectionConsensusStateSvc < T > { type Response = super :: QueryConnectionConsensusStateResponse ; type Future = BoxFuture < tonic :: Response < Self :: Response > , tonic :: Status > ; fn call (& mut self , request : tonic :: Request < super :: QueryConnectionConsensusStateRequest >) -> Self :: Future { let inner = self . 0 . clone () ; let fut = async move { (* inner) . connection_consensus_state (request) . await } ; Box :: pin (fut) } } let inner = self . inner . clone () ; let fut = async move { let interceptor = inner . 1 . clone () ; let inner = inner . 0 ; let method = ConnectionConsensusStateSvc (inner) ; let codec = tonic :: codec :: ProstCodec :: default () ; let mut grpc = if let Some (interceptor) = interceptor { tonic :: server :: Grpc :: with_interceptor (codec , interceptor) } else { tonic :: server :: Grpc :: new (codec) } ; let res = grpc . unary (method , req) . await ; Ok (res) } ; Box :: pin (fut) } _ => Box :: pin (async move { Ok (http :: Response :: builder () . status (200) . header ("grpc-status" , "12") . header ("content-type" , "application/grpc") . body (tonic :: body :: BoxBody :: empty ()) . unwrap ()) }) , } } } impl < T : Query > Clone for QueryServer < T > { fn clone (& self) -> Self { let inner = self . inner . clone () ; Self { inner } } } impl < T : Query > Clone for _Inner < T > { fn clone (& self) -> Self { Self (self . 0 . clone () , self . 1 . clone ()) } } impl < T : std :: fmt :: Debug > std :: fmt :: Debug for _Inner < T > { fn fmt (& self , f : & mut std :: fmt :: Formatter < '_ >) -> std :: fmt :: Result { write ! (f , "{:?}" , self . 0) } } impl < T : Query > tonic :: transport :: NamedService for QueryServer < T > { const NAME : & 'static str = "ibc.core.connection.v1.Query" ; } }
\ No newline at end of file
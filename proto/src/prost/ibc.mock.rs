@@ -2,14 +2,27 @@
 pub struct Header {
     #[prost(message, optional, tag="1")]
     pub height: ::std::option::Option<super::core::client::v1::Height>,
+    #[prost(uint64, tag="2")]
+    pub timestamp: u64,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ClientState {
     #[prost(message, optional, tag="1")]
     pub header: ::std::option::Option<Header>,
+    #[prost(message, optional, tag="2")]
+    pub frozen_height: ::std::option::Option<super::core::client::v1::Height>,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ConsensusState {
     #[prost(message, optional, tag="1")]
     pub header: ::std::option::Option<Header>,
 }
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Misbehaviour {
+    #[prost(string, tag="1")]
+    pub client_id: std::string::String,
+    #[prost(message, optional, tag="2")]
+    pub header1: ::std::option::Option<Header>,
+    #[prost(message, optional, tag="3")]
+    pub header2: ::std::option::Option<Header>,
+}
@@ -13,8 +13,8 @@ use crate::commands::channel::ChannelCmds;
 use crate::config::Config;
 
 use self::{
-    keys::KeysCmd, light::LightCmd, listen::ListenCmd, query::QueryCmd, start::StartCmd, tx::TxCmd,
-    version::VersionCmd,
+    keys::KeysCmd, light::LightCmd, listen::ListenCmd, misbehaviour::MisbehaviourCmd,
+    query::QueryCmd, start::StartCmd, tx::TxCmd, version::VersionCmd,
 };
 
 mod channel;
@@ -23,6 +23,7 @@ mod config;
 mod keys;
 mod light;
 mod listen;
+mod misbehaviour;
 mod query;
 mod start;
 mod tx;
@@ -73,6 +74,10 @@ pub enum CliCmd {
     #[options(help = "Listen to and display IBC events emitted by a chain")]
     Listen(ListenCmd),
 
+    /// The `misbehaviour` subcommand
+    #[options(help = "Listen to client update IBC events and handle misbehaviour")]
+    Misbehaviour(MisbehaviourCmd),
+
     /// The `version` subcommand
     #[options(help = "Display version information")]
     Version(VersionCmd),
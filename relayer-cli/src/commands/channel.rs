@@ -1,4 +1,6 @@
 //! `channel` subcommand
+use std::time::Duration;
+
 use abscissa_core::{Command, Help, Options, Runnable};
 
 use ibc::ics04_channel::channel::Order;
@@ -38,6 +40,13 @@ pub struct ChannelHandshakeCommand {
 
     #[options(help = "the channel order", short = "o")]
     ordering: Order,
+
+    #[options(
+        help = "delay period of the new connection, in seconds",
+        short = "d",
+        meta = "SECONDS"
+    )]
+    delay: u64,
 }
 
 impl Runnable for ChannelHandshakeCommand {
@@ -65,6 +74,7 @@ impl Runnable for ChannelHandshakeCommand {
                 a_port: self.src_port_id.clone(),
                 b_port: self.dst_port_id.clone(),
             },
+            Duration::from_secs(self.delay),
         );
 
         match res {
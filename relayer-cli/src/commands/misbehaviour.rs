@@ -0,0 +1,55 @@
+use abscissa_core::{Command, Options, Runnable};
+
+use ibc::ics24_host::identifier::{ChainId, ClientId};
+use ibc_relayer::foreign_client::ForeignClient;
+
+use crate::application::app_config;
+use crate::commands::cli_utils::ChainHandlePair;
+use crate::conclude::Output;
+use crate::prelude::*;
+
+#[derive(Clone, Command, Debug, Options)]
+pub struct MisbehaviourCmd {
+    #[options(free, required, help = "identifier of the chain hosting the client")]
+    dst_chain_id: ChainId,
+
+    #[options(free, required, help = "identifier of the chain tracked by the client")]
+    src_chain_id: ChainId,
+
+    #[options(
+        free,
+        required,
+        help = "identifier of the client to be monitored on the destination chain"
+    )]
+    dst_client_id: ClientId,
+}
+
+/// Sample to run this command:
+///     `hermes misbehaviour ibc-0 ibc-1 07-tendermint-0`
+impl Runnable for MisbehaviourCmd {
+    fn run(&self) {
+        let config = app_config();
+
+        let chains = match ChainHandlePair::spawn(&config, &self.src_chain_id, &self.dst_chain_id) {
+            Ok(chains) => chains,
+            Err(e) => return Output::error(format!("{}", e)).exit(),
+        };
+
+        let client = ForeignClient {
+            dst_chain: chains.dst,
+            src_chain: chains.src,
+            id: self.dst_client_id.clone(),
+        };
+
+        info!(
+            "Monitoring client {} on {:?}",
+            client.id(),
+            self.dst_chain_id
+        );
+
+        match client.monitor_misbehaviour() {
+            Ok(()) => Output::success(()).exit(),
+            Err(e) => Output::error(format!("{}", e)).exit(),
+        }
+    }
+}
@@ -49,16 +49,19 @@ impl Runnable for StartCmd {
             }
             (None, None) => {
                 let ordering = Order::default(); // TODO - add to config
-                let relay_paths = &config
-                    .clone()
-                    .relay_paths(&self.src_chain_id, &self.dst_chain_id);
+                let connection = config.find_connection(&self.src_chain_id, &self.dst_chain_id);
 
-                match relay_paths {
-                    Some(paths) => {
+                match connection.and_then(|c| c.paths.as_ref().map(|paths| (c, paths))) {
+                    Some((connection, paths)) => {
                         info!("Start relayer on {:?}", self);
                         // Relay for a single channel, first on the connection between the two chains
-                        match relay_on_new_link(chains.src, chains.dst, ordering, paths[0].clone())
-                        {
+                        match relay_on_new_link(
+                            chains.src,
+                            chains.dst,
+                            ordering,
+                            paths[0].clone(),
+                            connection.delay,
+                        ) {
                             Ok(()) => Output::success(()).exit(),
                             Err(e) => Output::error(e.to_string()).exit(),
                         }
@@ -101,3 +101,52 @@ impl Runnable for TxUpdateClientCmd {
         }
     }
 }
+
+#[derive(Clone, Command, Debug, Options)]
+pub struct TxUpgradeClientCmd {
+    #[options(free, required, help = "identifier of the destination chain")]
+    dst_chain_id: ChainId,
+
+    #[options(free, required, help = "identifier of the source chain")]
+    src_chain_id: ChainId,
+
+    #[options(
+        free,
+        required,
+        help = "identifier of the client to be upgraded on destination chain"
+    )]
+    dst_client_id: ClientId,
+}
+
+/// Sample to run this tx:
+///     `hermes tx raw upgrade-client ibc-0 ibc-1 07-tendermint-0`
+impl Runnable for TxUpgradeClientCmd {
+    fn run(&self) {
+        let config = app_config();
+
+        let spawn_options = SpawnOptions::override_store_config(StoreConfig::memory());
+        let chains = match ChainHandlePair::spawn_with(
+            spawn_options,
+            &config,
+            &self.src_chain_id,
+            &self.dst_chain_id,
+        ) {
+            Ok(chains) => chains,
+            Err(e) => return Output::error(format!("{}", e)).exit(),
+        };
+
+        let client = ForeignClient {
+            dst_chain: chains.dst,
+            src_chain: chains.src,
+            id: self.dst_client_id.clone(),
+        };
+
+        let res: Result<Vec<IBCEvent>, Error> =
+            client.upgrade().map_err(|e| Kind::Tx.context(e).into());
+
+        match res {
+            Ok(events) => Output::success(events).exit(),
+            Err(e) => Output::error(format!("{}", e)).exit(),
+        }
+    }
+}
@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use abscissa_core::{Command, Options, Runnable};
 
 use ibc::events::IBCEvent;
@@ -52,6 +54,12 @@ pub struct TxRawConnInitCmd {
 
     #[options(free, required, help = "identifier of the source client")]
     src_client_id: ClientId,
+
+    #[options(
+        help = "delay period of the new connection, in seconds",
+        meta = "SECONDS"
+    )]
+    delay: u64,
 }
 
 impl Runnable for TxRawConnInitCmd {
@@ -72,6 +80,7 @@ impl Runnable for TxRawConnInitCmd {
                         self.dst_client_id.clone(),
                         ConnectionId::default(),
                     ),
+                    delay_period: Duration::from_secs(self.delay),
                 }
             }
         );
@@ -119,6 +128,8 @@ impl Runnable for TxRawConnTryCmd {
                         self.dst_client_id.clone(),
                         ConnectionId::default(),
                     ),
+                    // The delay period is only set when the connection is initialized.
+                    delay_period: Duration::default(),
                 }
             }
         );
@@ -174,6 +185,7 @@ impl Runnable for TxRawConnAckCmd {
                         self.dst_client_id.clone(),
                         self.dst_conn_id.clone(),
                     ),
+                    delay_period: Duration::default(),
                 }
             }
         );
@@ -229,6 +241,7 @@ impl Runnable for TxRawConnConfirmCmd {
                         self.dst_client_id.clone(),
                         self.dst_conn_id.clone(),
                     ),
+                    delay_period: Duration::default(),
                 }
             }
         );
@@ -21,6 +21,7 @@ use ibc::ics23_commitment::commitment::{CommitmentPrefix, CommitmentProofBytes};
 use ibc::ics24_host::identifier::{ChainId, ChannelId, ClientId, ConnectionId, PortId};
 use ibc::ics24_host::Path;
 use ibc::proofs::{ConsensusProof, Proofs};
+use ibc::timestamp::Timestamp;
 use ibc::Height as ICSHeight;
 use ibc_proto::ibc::core::channel::v1::{
     PacketState, QueryChannelsRequest, QueryConnectionChannelsRequest,
@@ -54,6 +55,13 @@ pub struct QueryResponse {
     pub height: Height,
 }
 
+/// The latest height of a chain and the time of the block at that height
+#[derive(Clone, Debug, PartialEq)]
+pub struct StatusResponse {
+    pub height: ICSHeight,
+    pub timestamp: Timestamp,
+}
+
 /// Packet query options
 #[derive(Debug)]
 pub struct QueryPacketOptions {
@@ -132,6 +140,9 @@ pub trait Chain: Sized {
     /// Query the latest height the chain is at
     fn query_latest_height(&self) -> Result<ICSHeight, Error>;
 
+    /// Query the latest height the chain is at, along with the time of the block at that height
+    fn query_status(&self) -> Result<StatusResponse, Error>;
+
     fn query_client_state(
         &self,
         client_id: &ClientId,
@@ -204,6 +215,20 @@ pub trait Chain: Sized {
         height: ICSHeight,
     ) -> Result<(Self::ConsensusState, MerkleProof), Error>;
 
+    /// Query the client state which the chain committed to, ahead of an upgrade planned at
+    /// `height`, for the clients of the upgraded chain.
+    fn query_upgraded_client_state(
+        &self,
+        height: ICSHeight,
+    ) -> Result<(Self::ClientState, MerkleProof), Error>;
+
+    /// Query the consensus state which the chain committed to, ahead of an upgrade planned at
+    /// `height`, for the clients of the upgraded chain.
+    fn query_upgraded_consensus_state(
+        &self,
+        height: ICSHeight,
+    ) -> Result<(Self::ConsensusState, MerkleProof), Error>;
+
     fn proven_channel(
         &self,
         port_id: &PortId,
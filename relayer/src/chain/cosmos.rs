@@ -34,7 +34,7 @@ use ibc::ics23_commitment::merkle::convert_tm_to_ics_merkle_proof;
 use ibc::ics24_host::identifier::{ChainId, ChannelId, ClientId, ConnectionId};
 use ibc::ics24_host::Path::ClientConsensusState as ClientConsensusPath;
 use ibc::ics24_host::Path::ClientState as ClientStatePath;
-use ibc::ics24_host::{Path, IBC_QUERY_PATH};
+use ibc::ics24_host::{ClientUpgradePath, Path, IBC_QUERY_PATH, SDK_UPGRADE_QUERY_PATH};
 use ibc::Height as ICSHeight;
 // Support for GRPC
 use ibc_proto::cosmos::auth::v1beta1::{BaseAccount, QueryAccountRequest};
@@ -50,7 +50,7 @@ use ibc_proto::ibc::core::client::v1::QueryClientStatesRequest;
 use ibc_proto::ibc::core::commitment::v1::MerkleProof;
 use ibc_proto::ibc::core::connection::v1::QueryConnectionsRequest;
 
-use crate::chain::QueryResponse;
+use crate::chain::{QueryResponse, StatusResponse};
 use crate::config::ChainConfig;
 use crate::error::{Error, Kind};
 use crate::event::monitor::{EventBatch, EventMonitor};
@@ -123,6 +123,25 @@ impl CosmosSDKChain {
             .consensus_params)
     }
 
+    /// Perform a provable query of the store of the upgrade module, for an upgrade planned at
+    /// `height`. The chain halts at that height, so the query is made in the state committed to
+    /// by the header at `height`, i.e. the one after the previous block.
+    fn query_upgrade_store(
+        &self,
+        data: ClientUpgradePath,
+        height: ICSHeight,
+    ) -> Result<QueryResponse, Error> {
+        let path = TendermintABCIPath::from_str(SDK_UPGRADE_QUERY_PATH).unwrap();
+
+        let prev_height = height
+            .decrement()
+            .map_err(|e| Kind::InvalidHeight.context(e))?;
+        let prev_height = Height::try_from(prev_height.revision_height)
+            .map_err(|e| Kind::InvalidHeight.context(e))?;
+
+        self.block_on(abci_query(self, path, data.to_string(), prev_height, true))
+    }
+
     /// Run a future to completion on the Tokio runtime.
     fn block_on<F: Future>(&self, f: F) -> F::Output {
         crate::time!("block_on");
@@ -381,6 +400,13 @@ impl Chain for CosmosSDKChain {
     fn query_latest_height(&self) -> Result<ICSHeight, Error> {
         crate::time!("query_latest_height");
 
+        Ok(self.query_status()?.height)
+    }
+
+    /// Query the latest height and block time of the chain via a RPC query
+    fn query_status(&self) -> Result<StatusResponse, Error> {
+        crate::time!("query_status");
+
         let status = self
             .block_on(self.rpc_client().status())
             .map_err(|e| Kind::Rpc(self.config.rpc_addr.clone()).context(e))?;
@@ -394,9 +420,12 @@ impl Chain for CosmosSDKChain {
             );
         }
 
-        Ok(ICSHeight {
-            revision_number: ChainId::chain_version(status.node_info.network.as_str()),
-            revision_height: u64::from(status.sync_info.latest_block_height),
+        Ok(StatusResponse {
+            height: ICSHeight {
+                revision_number: ChainId::chain_version(status.node_info.network.as_str()),
+                revision_height: u64::from(status.sync_info.latest_block_height),
+            },
+            timestamp: status.sync_info.latest_block_time.into(),
         })
     }
 
@@ -493,6 +522,65 @@ impl Chain for CosmosSDKChain {
         ))
     }
 
+    fn query_upgraded_client_state(
+        &self,
+        height: ICSHeight,
+    ) -> Result<(Self::ClientState, MerkleProof), Error> {
+        crate::time!("query_upgraded_client_state");
+
+        let res = self
+            .query_upgrade_store(
+                ClientUpgradePath::UpgradedClientState(height.revision_height),
+                height,
+            )
+            .map_err(|e| Kind::Query("upgraded client state".into()).context(e))?;
+
+        let client_state = AnyClientState::decode_vec(&res.value)
+            .map_err(|e| Kind::Query("upgraded client state".into()).context(e))?;
+
+        let client_state =
+            downcast!(client_state => AnyClientState::Tendermint).ok_or_else(|| {
+                Kind::Query("upgraded client state".into()).context("unexpected client state type")
+            })?;
+
+        Ok((
+            client_state,
+            res.proof.ok_or_else(|| {
+                Kind::Query("upgraded client state".into()).context("empty proof".to_string())
+            })?,
+        ))
+    }
+
+    fn query_upgraded_consensus_state(
+        &self,
+        height: ICSHeight,
+    ) -> Result<(Self::ConsensusState, MerkleProof), Error> {
+        crate::time!("query_upgraded_consensus_state");
+
+        let res = self
+            .query_upgrade_store(
+                ClientUpgradePath::UpgradedClientConsensusState(height.revision_height),
+                height,
+            )
+            .map_err(|e| Kind::Query("upgraded consensus state".into()).context(e))?;
+
+        let consensus_state = AnyConsensusState::decode_vec(&res.value)
+            .map_err(|e| Kind::Query("upgraded consensus state".into()).context(e))?;
+
+        let consensus_state = downcast!(consensus_state => AnyConsensusState::Tendermint)
+            .ok_or_else(|| {
+                Kind::Query("upgraded consensus state".into())
+                    .context("unexpected client consensus type")
+            })?;
+
+        Ok((
+            consensus_state,
+            res.proof.ok_or_else(|| {
+                Kind::Query("upgraded consensus state".into()).context("empty proof".to_string())
+            })?,
+        ))
+    }
+
     fn build_consensus_state(
         &self,
         light_block: Self::LightBlock,
@@ -1015,3 +1103,303 @@ pub fn tx_result_to_event(response: Response) -> Result<Vec<IBCEvent>, anomaly::
     }
     Ok(result)
 }
+
+#[cfg(test)]
+mod tests {
+    use std::net::{TcpListener, TcpStream};
+    use std::str::FromStr;
+    use std::sync::{Arc, RwLock};
+    use std::thread;
+    use std::time::Duration;
+
+    use tendermint_rpc::HttpClient;
+    use tokio::runtime::Runtime as TokioRuntime;
+    use tonic::transport::Server;
+
+    use ibc::grpc::channel::ChannelQueryService;
+    use ibc::grpc::client::ClientQueryService;
+    use ibc::grpc::connection::ConnectionQueryService;
+    use ibc::ics03_connection::connection::{
+        ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
+    };
+    use ibc::ics03_connection::version::get_compatible_versions;
+    use ibc::ics04_channel::channel::{
+        ChannelEnd, Counterparty as ChannelCounterparty, Order, State as ChannelState,
+    };
+    use ibc::ics04_channel::commitment::{AcknowledgementCommitment, PacketCommitment};
+    use ibc::ics04_channel::context::ChannelKeeper;
+    use ibc::ics04_channel::packet::{Receipt, Sequence};
+    use ibc::ics24_host::identifier::{ChainId, ChannelId, ClientId, ConnectionId, PortId};
+    use ibc::mock::context::MockContext;
+    use ibc::mock::host::HostType;
+    use ibc::Height;
+    use ibc_proto::cosmos::base::query::v1beta1::PageRequest;
+    use ibc_proto::ibc::core::channel::v1::query_client::QueryClient as ChannelQueryClient;
+    use ibc_proto::ibc::core::channel::v1::query_server::QueryServer as ChannelQueryServer;
+    use ibc_proto::ibc::core::channel::v1::{
+        QueryChannelsRequest, QueryConnectionChannelsRequest, QueryPacketAcknowledgementsRequest,
+        QueryPacketCommitmentsRequest, QueryPacketReceiptRequest, QueryUnreceivedAcksRequest,
+        QueryUnreceivedPacketsRequest,
+    };
+    use ibc_proto::ibc::core::client::v1::query_server::QueryServer as ClientQueryServer;
+    use ibc_proto::ibc::core::client::v1::QueryClientStatesRequest;
+    use ibc_proto::ibc::core::connection::v1::query_server::QueryServer as ConnectionQueryServer;
+    use ibc_proto::ibc::core::connection::v1::QueryConnectionsRequest;
+
+    use crate::chain::cosmos::CosmosSDKChain;
+    use crate::chain::mock::test_utils::get_basic_chain_config;
+    use crate::chain::Chain;
+    use crate::keyring::store::{KeyRing, KeyRingOperations, StoreBackend};
+
+    /// Returns a host chain context with a client, two connections of this client, three
+    /// channels (an ordered one and an unordered one on the first connection) and a few packets
+    /// sent and received, at height 1-6.
+    fn host_context(port_id: &PortId) -> MockContext {
+        let client_id = ClientId::from_str("07-tendermint-0").unwrap();
+        let connection_end = ConnectionEnd::new(
+            ConnectionState::Open,
+            client_id.clone(),
+            ConnectionCounterparty::new(
+                client_id.clone(),
+                Some(ConnectionId::default()),
+                Default::default(),
+            ),
+            get_compatible_versions(),
+            0,
+        );
+        let channel_end = |ordering, conn_id: &str| {
+            ChannelEnd::new(
+                ChannelState::Open,
+                ordering,
+                ChannelCounterparty::new(port_id.clone(), Some(ChannelId::default())),
+                vec![ConnectionId::from_str(conn_id).unwrap()],
+                "ics20-1".to_string(),
+            )
+        };
+        let channel_id = |id: &str| ChannelId::from_str(id).unwrap();
+
+        let mut ctx = MockContext::new(
+            ChainId::new("mockgaia".to_string(), 1),
+            HostType::Mock,
+            5,
+            Height::new(1, 5),
+        )
+        .with_client(&client_id, Height::new(1, 2))
+        .with_connection(
+            ConnectionId::from_str("connection-0").unwrap(),
+            connection_end.clone(),
+        )
+        .with_connection(
+            ConnectionId::from_str("connection-1").unwrap(),
+            connection_end,
+        )
+        .with_channel_init(
+            port_id.clone(),
+            channel_id("channel-0"),
+            channel_end(Order::Ordered, "connection-0"),
+        )
+        .with_channel_init(
+            port_id.clone(),
+            channel_id("channel-1"),
+            channel_end(Order::Unordered, "connection-0"),
+        )
+        .with_channel_init(
+            port_id.clone(),
+            channel_id("channel-2"),
+            channel_end(Order::Unordered, "connection-1"),
+        )
+        .with_recv_sequence(port_id.clone(), channel_id("channel-0"), 3.into())
+        .with_packet_receipt(
+            port_id.clone(),
+            channel_id("channel-1"),
+            2.into(),
+            Receipt::Ok,
+        );
+
+        for sequence in 1..=2 {
+            ctx = ctx.with_packet_commitment(
+                port_id.clone(),
+                channel_id("channel-0"),
+                Sequence::from(sequence),
+                PacketCommitment::from(vec![sequence as u8]),
+            );
+        }
+        ctx.store_packet_acknowledgement(
+            (port_id.clone(), channel_id("channel-0"), 1.into()),
+            AcknowledgementCommitment::from(vec![1]),
+        )
+        .unwrap();
+
+        // Commit the state, such that the servers can prove it.
+        ctx.advance_host_chain_height();
+        ctx
+    }
+
+    /// Serves the queries of the IBC modules over the given context, in the background on the
+    /// runtime, and returns the address of the server.
+    fn serve(rt: &TokioRuntime, ctx: MockContext) -> String {
+        let addr = TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap();
+
+        let ctx = Arc::new(RwLock::new(ctx));
+        rt.spawn(
+            Server::builder()
+                .add_service(ClientQueryServer::new(ClientQueryService::new(ctx.clone())))
+                .add_service(ConnectionQueryServer::new(ConnectionQueryService::new(
+                    ctx.clone(),
+                )))
+                .add_service(ChannelQueryServer::new(ChannelQueryService::new(ctx)))
+                .serve(addr),
+        );
+
+        // Wait for the server to listen.
+        while TcpStream::connect(addr).is_err() {
+            thread::sleep(Duration::from_millis(10));
+        }
+
+        format!("http://{}", addr)
+    }
+
+    /// Points the queries of the relayer to the gRPC servers of the modules, running in-process
+    /// over a mock host chain.
+    #[test]
+    fn query_modules_servers() {
+        let port_id = PortId::from_str("transfer").unwrap();
+        let rt = Arc::new(TokioRuntime::new().unwrap());
+        let grpc_addr = serve(&rt, host_context(&port_id));
+
+        let mut config = get_basic_chain_config("mockgaia-1");
+        config.grpc_addr = grpc_addr;
+        let chain = CosmosSDKChain {
+            rpc_client: HttpClient::new(config.rpc_addr.clone()).unwrap(),
+            keybase: KeyRing::init(StoreBackend::Memory, config.clone()).unwrap(),
+            config,
+            rt,
+        };
+
+        let clients = chain
+            .query_clients(QueryClientStatesRequest { pagination: None })
+            .unwrap();
+        assert_eq!(
+            clients,
+            vec![ClientId::from_str("07-tendermint-0").unwrap()]
+        );
+
+        let connections = chain
+            .query_connections(QueryConnectionsRequest { pagination: None })
+            .unwrap();
+        assert_eq!(
+            connections.0,
+            vec![
+                ConnectionId::from_str("connection-0").unwrap(),
+                ConnectionId::from_str("connection-1").unwrap()
+            ]
+        );
+
+        let connections = chain
+            .query_connections(QueryConnectionsRequest {
+                pagination: Some(PageRequest {
+                    offset: 1,
+                    limit: 1,
+                    ..Default::default()
+                }),
+            })
+            .unwrap();
+        assert_eq!(
+            connections.0,
+            vec![ConnectionId::from_str("connection-1").unwrap()]
+        );
+
+        let channel_ids = |ids: &[&str]| -> Vec<ChannelId> {
+            ids.iter()
+                .map(|id| ChannelId::from_str(id).unwrap())
+                .collect()
+        };
+
+        let channels = chain
+            .query_channels(QueryChannelsRequest { pagination: None })
+            .unwrap();
+        assert_eq!(
+            channels,
+            channel_ids(&["channel-0", "channel-1", "channel-2"])
+        );
+
+        let channels = chain
+            .query_connection_channels(QueryConnectionChannelsRequest {
+                connection: "connection-0".to_string(),
+                pagination: None,
+            })
+            .unwrap();
+        assert_eq!(channels, channel_ids(&["channel-0", "channel-1"]));
+
+        let (commitments, height) = chain
+            .query_packet_commitments(QueryPacketCommitmentsRequest {
+                port_id: port_id.to_string(),
+                channel_id: "channel-0".to_string(),
+                pagination: None,
+            })
+            .unwrap();
+        let sequences: Vec<u64> = commitments.iter().map(|c| c.sequence).collect();
+        assert_eq!(sequences, vec![1, 2]);
+        assert_eq!(commitments[1].data, vec![2]);
+        assert_eq!(height, Height::new(1, 6));
+
+        // On the ordered channel, the packets from the next sequence to receive are unreceived.
+        let unreceived = chain
+            .query_unreceived_packets(QueryUnreceivedPacketsRequest {
+                port_id: port_id.to_string(),
+                channel_id: "channel-0".to_string(),
+                packet_commitment_sequences: vec![1, 2, 3, 4],
+            })
+            .unwrap();
+        assert_eq!(unreceived, vec![3, 4]);
+
+        // On the unordered channel, the packets without receipt are unreceived.
+        let unreceived = chain
+            .query_unreceived_packets(QueryUnreceivedPacketsRequest {
+                port_id: port_id.to_string(),
+                channel_id: "channel-1".to_string(),
+                packet_commitment_sequences: vec![1, 2, 3],
+            })
+            .unwrap();
+        assert_eq!(unreceived, vec![1, 3]);
+
+        let (acks, _) = chain
+            .query_packet_acknowledgements(QueryPacketAcknowledgementsRequest {
+                port_id: port_id.to_string(),
+                channel_id: "channel-0".to_string(),
+                pagination: None,
+            })
+            .unwrap();
+        let sequences: Vec<u64> = acks.iter().map(|a| a.sequence).collect();
+        assert_eq!(sequences, vec![1]);
+
+        // The acknowledgements of the packets whose commitment is still stored are unreceived.
+        let unreceived = chain
+            .query_unreceived_acknowledgements(QueryUnreceivedAcksRequest {
+                port_id: port_id.to_string(),
+                channel_id: "channel-0".to_string(),
+                packet_ack_sequences: vec![1, 2, 3],
+            })
+            .unwrap();
+        assert_eq!(unreceived, vec![1, 2]);
+
+        // The responses carry the proofs of the state of the host, present or absent.
+        let mut client = chain
+            .block_on(ChannelQueryClient::connect(chain.config.grpc_addr.clone()))
+            .unwrap();
+        let response = chain
+            .block_on(client.packet_receipt(QueryPacketReceiptRequest {
+                port_id: port_id.to_string(),
+                channel_id: "channel-1".to_string(),
+                sequence: 1,
+            }))
+            .unwrap()
+            .into_inner();
+        assert!(!response.received);
+        assert!(!response.proof.is_empty());
+        assert_eq!(response.proof_height, Some(Height::new(1, 6).into()));
+    }
+}
@@ -11,7 +11,8 @@ use ibc::ics04_channel::packet::{PacketMsgType, Sequence};
 use ibc::ics24_host::{identifier::ChainId, identifier::ClientId, Path};
 use ibc::{
     events::IBCEvent,
-    ics02_client::client_def::{AnyClientState, AnyConsensusState, AnyHeader},
+    ics02_client::client_def::{AnyClientState, AnyConsensusState, AnyHeader, AnyMisbehaviour},
+    ics02_client::events::UpdateClient,
     ics03_connection::connection::ConnectionEnd,
     ics03_connection::version::Version,
     ics04_channel::channel::{ChannelEnd, QueryPacketEventDataRequest},
@@ -30,7 +31,7 @@ use crate::connection::ConnectionMsgType;
 use crate::keyring::store::KeyEntry;
 use crate::{error::Error, event::monitor::EventBatch};
 
-use super::QueryResponse;
+use super::{QueryResponse, StatusResponse};
 
 mod prod;
 
@@ -89,6 +90,10 @@ pub enum ChainRequest {
         reply_to: ReplyTo<Height>,
     },
 
+    QueryStatus {
+        reply_to: ReplyTo<StatusResponse>,
+    },
+
     BuildHeader {
         trusted_height: Height,
         target_height: Height,
@@ -100,6 +105,11 @@ pub enum ChainRequest {
         reply_to: ReplyTo<AnyClientState>,
     },
 
+    BuildMisbehaviour {
+        update_event: UpdateClient,
+        reply_to: ReplyTo<Option<AnyMisbehaviour>>,
+    },
+
     BuildConsensusState {
         height: Height,
         reply_to: ReplyTo<AnyConsensusState>,
@@ -146,6 +156,16 @@ pub enum ChainRequest {
         reply_to: ReplyTo<(AnyClientState, MerkleProof)>,
     },
 
+    QueryUpgradedClientState {
+        height: Height,
+        reply_to: ReplyTo<(AnyClientState, MerkleProof)>,
+    },
+
+    QueryUpgradedConsensusState {
+        height: Height,
+        reply_to: ReplyTo<(AnyConsensusState, MerkleProof)>,
+    },
+
     ProvenConnection {
         connection_id: ConnectionId,
         height: Height,
@@ -224,6 +244,8 @@ pub trait ChainHandle: DynClone + Send + Sync + Debug {
 
     fn query_latest_height(&self) -> Result<Height, Error>;
 
+    fn query_status(&self) -> Result<StatusResponse, Error>;
+
     fn query_client_state(
         &self,
         client_id: &ClientId,
@@ -259,6 +281,20 @@ pub trait ChainHandle: DynClone + Send + Sync + Debug {
         height: Height,
     ) -> Result<(ConnectionEnd, MerkleProof), Error>;
 
+    /// Queries the client state which the chain committed to, ahead of an upgrade planned at
+    /// `height`, for the clients of the upgraded chain.
+    fn query_upgraded_client_state(
+        &self,
+        height: Height,
+    ) -> Result<(AnyClientState, MerkleProof), Error>;
+
+    /// Queries the consensus state which the chain committed to, ahead of an upgrade planned at
+    /// `height`, for the clients of the upgraded chain.
+    fn query_upgraded_consensus_state(
+        &self,
+        height: Height,
+    ) -> Result<(AnyConsensusState, MerkleProof), Error>;
+
     fn proven_client_consensus(
         &self,
         client_id: &ClientId,
@@ -278,6 +314,14 @@ pub trait ChainHandle: DynClone + Send + Sync + Debug {
     /// Constructs a consensus state at the given height
     fn build_consensus_state(&self, height: Height) -> Result<AnyConsensusState, Error>;
 
+    /// Checks the header of an update of a client of this chain (on some other chain) against the
+    /// header verified by the light client of this chain, and constructs the evidence of
+    /// misbehaviour if the two conflict.
+    fn build_misbehaviour(
+        &self,
+        update_event: UpdateClient,
+    ) -> Result<Option<AnyMisbehaviour>, Error>;
+
     fn build_connection_proofs_and_client_state(
         &self,
         message_type: ConnectionMsgType,
@@ -9,7 +9,8 @@ use ibc_proto::ibc::core::channel::v1::{
 
 use ibc::{
     events::IBCEvent,
-    ics02_client::client_def::{AnyClientState, AnyConsensusState, AnyHeader},
+    ics02_client::client_def::{AnyClientState, AnyConsensusState, AnyHeader, AnyMisbehaviour},
+    ics02_client::events::UpdateClient,
     ics03_connection::connection::ConnectionEnd,
     ics03_connection::version::Version,
     ics04_channel::channel::{ChannelEnd, QueryPacketEventDataRequest},
@@ -28,7 +29,7 @@ use tendermint::account::Id as AccountId;
 use super::{reply_channel, ChainHandle, ChainRequest, ReplyTo, Subscription};
 
 use crate::{
-    chain::QueryResponse,
+    chain::{QueryResponse, StatusResponse},
     connection::ConnectionMsgType,
     error::{Error, Kind},
     keyring::store::KeyEntry,
@@ -121,6 +122,10 @@ impl ChainHandle for ProdChainHandle {
         self.send(|reply_to| ChainRequest::QueryLatestHeight { reply_to })
     }
 
+    fn query_status(&self) -> Result<StatusResponse, Error> {
+        self.send(|reply_to| ChainRequest::QueryStatus { reply_to })
+    }
+
     fn query_client_state(
         &self,
         client_id: &ClientId,
@@ -179,6 +184,20 @@ impl ChainHandle for ProdChainHandle {
         })
     }
 
+    fn query_upgraded_client_state(
+        &self,
+        height: Height,
+    ) -> Result<(AnyClientState, MerkleProof), Error> {
+        self.send(|reply_to| ChainRequest::QueryUpgradedClientState { height, reply_to })
+    }
+
+    fn query_upgraded_consensus_state(
+        &self,
+        height: Height,
+    ) -> Result<(AnyConsensusState, MerkleProof), Error> {
+        self.send(|reply_to| ChainRequest::QueryUpgradedConsensusState { height, reply_to })
+    }
+
     fn proven_connection(
         &self,
         connection_id: &ConnectionId,
@@ -225,6 +244,16 @@ impl ChainHandle for ProdChainHandle {
         self.send(|reply_to| ChainRequest::BuildConsensusState { height, reply_to })
     }
 
+    fn build_misbehaviour(
+        &self,
+        update_event: UpdateClient,
+    ) -> Result<Option<AnyMisbehaviour>, Error> {
+        self.send(|reply_to| ChainRequest::BuildMisbehaviour {
+            update_event,
+            reply_to,
+        })
+    }
+
     fn build_connection_proofs_and_client_state(
         &self,
         message_type: ConnectionMsgType,
@@ -12,6 +12,7 @@ use tokio::runtime::Runtime;
 use ibc::downcast;
 use ibc::events::IBCEvent;
 use ibc::ics02_client::client_def::AnyClientState;
+use ibc::ics02_client::context::ClientReader;
 use ibc::ics03_connection::raw::ConnectionIds;
 use ibc::ics04_channel::channel::QueryPacketEventDataRequest;
 use ibc::ics07_tendermint::client_state::ClientState as TendermintClientState;
@@ -34,7 +35,7 @@ use ibc_proto::ibc::core::client::v1::QueryClientStatesRequest;
 use ibc_proto::ibc::core::commitment::v1::MerkleProof;
 use ibc_proto::ibc::core::connection::v1::QueryConnectionsRequest;
 
-use crate::chain::{Chain, QueryResponse};
+use crate::chain::{Chain, QueryResponse, StatusResponse};
 use crate::config::ChainConfig;
 use crate::error::{Error, Kind};
 use crate::event::monitor::EventBatch;
@@ -166,6 +167,13 @@ impl Chain for MockChain {
         Ok(self.context.query_latest_height())
     }
 
+    fn query_status(&self) -> Result<StatusResponse, Error> {
+        Ok(StatusResponse {
+            height: self.context.query_latest_height(),
+            timestamp: ClientReader::host_timestamp(&self.context).into(),
+        })
+    }
+
     fn query_client_state(
         &self,
         client_id: &ClientId,
@@ -203,6 +211,20 @@ impl Chain for MockChain {
         unimplemented!()
     }
 
+    fn query_upgraded_client_state(
+        &self,
+        _height: Height,
+    ) -> Result<(Self::ClientState, MerkleProof), Error> {
+        unimplemented!()
+    }
+
+    fn query_upgraded_consensus_state(
+        &self,
+        _height: Height,
+    ) -> Result<(Self::ConsensusState, MerkleProof), Error> {
+        unimplemented!()
+    }
+
     fn query_packet_commitments(
         &self,
         _request: QueryPacketCommitmentsRequest,
@@ -13,7 +13,8 @@ use ibc_proto::ibc::core::commitment::v1::MerkleProof;
 use ibc::{
     events::IBCEvent,
     ics02_client::{
-        client_def::{AnyClientState, AnyConsensusState, AnyHeader},
+        client_def::{AnyClientState, AnyConsensusState, AnyHeader, AnyMisbehaviour},
+        events::UpdateClient,
         header::Header,
         state::{ClientState, ConsensusState},
     },
@@ -43,7 +44,7 @@ use crate::{
 
 use super::{
     handle::{ChainHandle, ChainRequest, ProdChainHandle, ReplyTo, Subscription},
-    Chain, QueryResponse,
+    Chain, QueryResponse, StatusResponse,
 };
 use ibc::ics04_channel::packet::{PacketMsgType, Sequence};
 
@@ -208,6 +209,10 @@ impl<C: Chain + Send + 'static> ChainRuntime<C> {
                             self.build_consensus_state(height, reply_to)?
                         }
 
+                        Ok(ChainRequest::BuildMisbehaviour { update_event, reply_to }) => {
+                            self.build_misbehaviour(update_event, reply_to)?
+                        }
+
                         Ok(ChainRequest::BuildConnectionProofsAndClientState { message_type, connection_id, client_id, height, reply_to }) => {
                             self.build_connection_proofs_and_client_state(message_type, connection_id, client_id, height, reply_to)?
                         },
@@ -220,6 +225,10 @@ impl<C: Chain + Send + 'static> ChainRuntime<C> {
                             self.query_latest_height(reply_to)?
                         }
 
+                        Ok(ChainRequest::QueryStatus { reply_to }) => {
+                            self.query_status(reply_to)?
+                        }
+
                         Ok(ChainRequest::QueryClientState { client_id, height, reply_to }) => {
                             self.query_client_state(client_id, height, reply_to)?
                         },
@@ -244,6 +253,14 @@ impl<C: Chain + Send + 'static> ChainRuntime<C> {
                             self.proven_client_state(client_id, height, reply_to)?
                         },
 
+                        Ok(ChainRequest::QueryUpgradedClientState { height, reply_to }) => {
+                            self.query_upgraded_client_state(height, reply_to)?
+                        },
+
+                        Ok(ChainRequest::QueryUpgradedConsensusState { height, reply_to }) => {
+                            self.query_upgraded_consensus_state(height, reply_to)?
+                        },
+
                         Ok(ChainRequest::ProvenConnection { connection_id, height, reply_to }) => {
                             self.proven_connection(connection_id, height, reply_to)?
                         },
@@ -348,6 +365,16 @@ impl<C: Chain + Send + 'static> ChainRuntime<C> {
         Ok(())
     }
 
+    fn query_status(&self, reply_to: ReplyTo<StatusResponse>) -> Result<(), Error> {
+        let status = self.chain.query_status();
+
+        reply_to
+            .send(status)
+            .map_err(|e| Kind::Channel.context(e))?;
+
+        Ok(())
+    }
+
     fn get_minimal_set(
         &self,
         _from: Height,
@@ -455,6 +482,21 @@ impl<C: Chain + Send + 'static> ChainRuntime<C> {
         Ok(())
     }
 
+    /// Checks the header of a client update event for misbehaviour of this chain
+    fn build_misbehaviour(
+        &self,
+        update_event: UpdateClient,
+        reply_to: ReplyTo<Option<AnyMisbehaviour>>,
+    ) -> Result<(), Error> {
+        let misbehaviour = self.light_client.check_misbehaviour(&update_event);
+
+        reply_to
+            .send(misbehaviour)
+            .map_err(|e| Kind::Channel.context(e))?;
+
+        Ok(())
+    }
+
     fn build_connection_proofs_and_client_state(
         &self,
         message_type: ConnectionMsgType,
@@ -567,6 +609,40 @@ impl<C: Chain + Send + 'static> ChainRuntime<C> {
         Ok(())
     }
 
+    fn query_upgraded_client_state(
+        &self,
+        height: Height,
+        reply_to: ReplyTo<(AnyClientState, MerkleProof)>,
+    ) -> Result<(), Error> {
+        let result = self
+            .chain
+            .query_upgraded_client_state(height)
+            .map(|(cs, mp)| (cs.wrap_any(), mp));
+
+        reply_to
+            .send(result)
+            .map_err(|e| Kind::Channel.context(e))?;
+
+        Ok(())
+    }
+
+    fn query_upgraded_consensus_state(
+        &self,
+        height: Height,
+        reply_to: ReplyTo<(AnyConsensusState, MerkleProof)>,
+    ) -> Result<(), Error> {
+        let result = self
+            .chain
+            .query_upgraded_consensus_state(height)
+            .map(|(cs, mp)| (cs.wrap_any(), mp));
+
+        reply_to
+            .send(result)
+            .map_err(|e| Kind::Channel.context(e))?;
+
+        Ok(())
+    }
+
     fn proven_connection(
         &self,
         connection_id: ConnectionId,
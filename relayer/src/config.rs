@@ -58,16 +58,15 @@ impl Config {
     pub fn find_chain_mut(&mut self, id: &ChainId) -> Option<&mut ChainConfig> {
         self.chains.iter_mut().find(|c| c.id == *id)
     }
+    pub fn find_connection(&self, src_chain: &ChainId, dst_chain: &ChainId) -> Option<&Connection> {
+        self.connections.as_ref()?.iter().find(|c| {
+            c.a_chain == *src_chain && c.b_chain == *dst_chain
+                || c.a_chain == *dst_chain && c.b_chain == *src_chain
+        })
+    }
+
     pub fn relay_paths(&self, src_chain: &ChainId, dst_chain: &ChainId) -> Option<Vec<RelayPath>> {
-        self.connections
-            .as_ref()?
-            .iter()
-            .find(|c| {
-                c.a_chain == *src_chain && c.b_chain == *dst_chain
-                    || c.a_chain == *dst_chain && c.b_chain == *src_chain
-            })?
-            .paths
-            .clone()
+        self.find_connection(src_chain, dst_chain)?.paths.clone()
     }
 }
 
@@ -158,6 +157,9 @@ impl ChainConfig {
 pub struct Connection {
     pub a_chain: ChainId,
     pub b_chain: ChainId,
+    /// The delay period of the connection that the relayer opens between the two chains.
+    #[serde(default, with = "humantime_serde")]
+    pub delay: Duration,
     pub paths: Option<Vec<RelayPath>>,
 }
 
@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use prost_types::Any;
 use thiserror::Error;
 use tracing::error;
@@ -68,14 +70,18 @@ impl ConnectionSide {
 pub struct Connection {
     pub a_side: ConnectionSide,
     pub b_side: ConnectionSide,
+    /// The delay period of the connection, which is set when the connection is initialized.
+    pub delay_period: Duration,
 }
 
 impl Connection {
     /// Create a new connection, ensuring that the handshake has succeeded and the two connection
-    /// ends exist on each side.
+    /// ends exist on each side. Packet proofs over the connection are accepted only once
+    /// `delay_period` has elapsed since the consensus state they are verified against is stored.
     pub fn new(
         a_client: ForeignClient,
         b_client: ForeignClient,
+        delay_period: Duration,
     ) -> Result<Connection, ConnectionError> {
         // Validate that the two clients serve the same two chains
         if a_client.src_chain().id().ne(&b_client.dst_chain().id()) {
@@ -104,6 +110,7 @@ impl Connection {
                 b_client.id().clone(),
                 Default::default(),
             ),
+            delay_period,
         };
         c.handshake()?;
 
@@ -138,6 +145,7 @@ impl Connection {
         Connection {
             a_side: self.b_side.clone(),
             b_side: self.a_side.clone(),
+            delay_period: self.delay_period,
         }
     }
 
@@ -362,7 +370,7 @@ impl Connection {
             client_id: self.dst_client_id().clone(),
             counterparty,
             version,
-            delay_period: 0,
+            delay_period: self.delay_period.as_nanos() as u64,
             signer,
         };
 
@@ -473,7 +481,8 @@ impl Connection {
             counterparty,
             counterparty_versions,
             proofs,
-            delay_period: 0,
+            // The delay period must match the one of the counterparty connection end.
+            delay_period: src_connection.delay_period(),
             signer,
         };
 
@@ -156,6 +156,9 @@ pub enum Kind {
 
     #[error("the input header is not recognized as a header for this chain")]
     InvalidInputHeader,
+
+    #[error("Failed to check for misbehaviour of chain {0}")]
+    Misbehaviour(ChainId),
 }
 
 impl Kind {
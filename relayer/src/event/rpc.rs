@@ -66,6 +66,12 @@ pub fn build_event(mut object: RawObject) -> Result<IBCEvent, BoxError> {
         "update_client" => Ok(IBCEvent::from(ClientEvents::UpdateClient::try_from(
             object,
         )?)),
+        "client_misbehaviour" => Ok(IBCEvent::from(ClientEvents::ClientMisbehavior::try_from(
+            object,
+        )?)),
+        "upgrade_client" => Ok(IBCEvent::from(ClientEvents::UpgradeClient::try_from(
+            object,
+        )?)),
 
         // Connection events
         "connection_open_init" => Ok(IBCEvent::from(ConnectionEvents::OpenInit::try_from(
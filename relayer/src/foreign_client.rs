@@ -1,19 +1,24 @@
 use prost_types::Any;
 use std::{thread, time::Duration};
 use thiserror::Error;
-use tracing::{error, info};
+use tracing::{error, info, warn};
 
 use ibc::events::IBCEvent;
+use ibc::ics02_client::events::UpdateClient;
 use ibc::ics02_client::header::Header;
 use ibc::ics02_client::msgs::create_client::MsgCreateAnyClient;
+use ibc::ics02_client::msgs::misbehaviour::MsgSubmitAnyMisbehaviour;
 use ibc::ics02_client::msgs::update_client::MsgUpdateAnyClient;
+use ibc::ics02_client::msgs::upgrade_client::MsgUpgradeAnyClient;
 use ibc::ics02_client::state::ClientState;
 use ibc::ics02_client::state::ConsensusState;
 use ibc::ics24_host::identifier::ClientId;
 use ibc::tx_msg::Msg;
 use ibc::Height;
 use ibc_proto::ibc::core::client::v1::MsgCreateClient as RawMsgCreateClient;
+use ibc_proto::ibc::core::client::v1::MsgSubmitMisbehaviour as RawMsgSubmitMisbehaviour;
 use ibc_proto::ibc::core::client::v1::MsgUpdateClient as RawMsgUpdateClient;
+use ibc_proto::ibc::core::client::v1::MsgUpgradeClient as RawMsgUpgradeClient;
 
 use crate::chain::handle::ChainHandle;
 
@@ -24,6 +29,12 @@ pub enum ForeignClientError {
 
     #[error("error raised while updating client: {0}")]
     ClientUpdate(String),
+
+    #[error("error raised while checking for misbehaviour: {0}")]
+    Misbehaviour(String),
+
+    #[error("error raised while upgrading client: {0}")]
+    ClientUpgrade(String),
 }
 
 #[derive(Clone, Debug)]
@@ -257,6 +268,199 @@ impl ForeignClient {
 
         Ok(())
     }
+
+    /// Upgrades this client after a planned upgrade of the source chain, which halts at the
+    /// upgrade height. The client is first updated to that height, if needed, so that it can
+    /// verify the proofs of the upgraded client and consensus states that the source chain
+    /// committed to. Returns the events of the upgrade on the destination chain.
+    pub fn upgrade(&self) -> Result<Vec<IBCEvent>, ForeignClientError> {
+        let src_height = self.src_chain.query_latest_height().map_err(|e| {
+            ForeignClientError::ClientUpgrade(format!(
+                "failed while querying src chain ({}) for latest height: {}",
+                self.src_chain.id(),
+                e
+            ))
+        })?;
+
+        let client_height = self
+            .dst_chain
+            .query_client_state(&self.id, Height::default())
+            .map_err(|e| {
+                ForeignClientError::ClientUpgrade(format!(
+                    "failed querying client state on dst chain {} with error: {}",
+                    self.id, e
+                ))
+            })?
+            .latest_height();
+
+        let mut msgs = if client_height < src_height {
+            self.build_update_client(src_height)?
+        } else {
+            vec![]
+        };
+
+        let (client_state, proof_upgrade_client) = self
+            .src_chain
+            .query_upgraded_client_state(src_height)
+            .map_err(|e| {
+                ForeignClientError::ClientUpgrade(format!(
+                    "failed querying src chain ({}) for the upgraded client state: {}",
+                    self.src_chain.id(),
+                    e
+                ))
+            })?;
+
+        let (consensus_state, proof_upgrade_consensus_state) = self
+            .src_chain
+            .query_upgraded_consensus_state(src_height)
+            .map_err(|e| {
+                ForeignClientError::ClientUpgrade(format!(
+                    "failed querying src chain ({}) for the upgraded consensus state: {}",
+                    self.src_chain.id(),
+                    e
+                ))
+            })?;
+
+        let signer = self.dst_chain.get_signer().map_err(|e| {
+            ForeignClientError::ClientUpgrade(format!(
+                "failed getting signer for dst chain ({}) with error: {}",
+                self.dst_chain.id(),
+                e
+            ))
+        })?;
+
+        let msg_upgrade = MsgUpgradeAnyClient::new(
+            self.id.clone(),
+            client_state,
+            consensus_state,
+            proof_upgrade_client.into(),
+            proof_upgrade_consensus_state.into(),
+            signer,
+        )
+        .map_err(|e| {
+            ForeignClientError::ClientUpgrade(format!("failed building the upgrade message: {}", e))
+        })?;
+        msgs.push(msg_upgrade.to_any::<RawMsgUpgradeClient>());
+
+        self.dst_chain.send_msgs(msgs).map_err(|e| {
+            ForeignClientError::ClientUpgrade(format!(
+                "failed sending message to dst chain ({}) with err: {}",
+                self.dst_chain.id(),
+                e
+            ))
+        })
+    }
+
+    /// Checks the header of an `update` of this client for misbehaviour of the source chain, by
+    /// comparing it with the header that the light client of the source chain verifies at the
+    /// same height. If the two conflict, submits the evidence to the destination chain, which
+    /// freezes the client, and returns the resulting events.
+    pub fn detect_misbehaviour_and_submit_evidence(
+        &self,
+        update: &UpdateClient,
+    ) -> Result<Option<Vec<IBCEvent>>, ForeignClientError> {
+        if update.client_id() != &self.id {
+            return Err(ForeignClientError::Misbehaviour(format!(
+                "the update event is for client {}, not {}",
+                update.client_id(),
+                self.id
+            )));
+        }
+
+        // There is nothing left to protect if the client is already frozen.
+        let client_state = self
+            .dst_chain
+            .query_client_state(&self.id, Height::zero())
+            .map_err(|e| {
+                ForeignClientError::Misbehaviour(format!(
+                    "failed querying client state on dst chain ({}) with error: {}",
+                    self.dst_chain.id(),
+                    e
+                ))
+            })?;
+        if client_state.is_frozen() {
+            return Ok(None);
+        }
+
+        let misbehaviour = match self
+            .src_chain
+            .build_misbehaviour(update.clone())
+            .map_err(|e| {
+                ForeignClientError::Misbehaviour(format!(
+                    "failed building misbehaviour evidence from src chain ({}) with error: {}",
+                    self.src_chain.id(),
+                    e
+                ))
+            })? {
+            Some(misbehaviour) => misbehaviour,
+            None => return Ok(None),
+        };
+
+        let signer = self.dst_chain.get_signer().map_err(|e| {
+            ForeignClientError::Misbehaviour(format!(
+                "failed getting signer for dst chain ({}) with error: {}",
+                self.dst_chain.id(),
+                e
+            ))
+        })?;
+        let msg = MsgSubmitAnyMisbehaviour {
+            client_id: self.id.clone(),
+            misbehaviour,
+            signer,
+        };
+
+        let events = self
+            .dst_chain
+            .send_msgs(vec![msg.to_any::<RawMsgSubmitMisbehaviour>()])
+            .map_err(|e| {
+                ForeignClientError::Misbehaviour(format!(
+                    "failed sending evidence to dst chain ({}) with err: {}",
+                    self.dst_chain.id(),
+                    e
+                ))
+            })?;
+
+        Ok(Some(events))
+    }
+
+    /// Listens to the events of the destination chain, and checks every update of this client
+    /// for misbehaviour of the source chain. Returns once evidence of misbehaviour has been
+    /// submitted, or when the destination chain stops sending events.
+    pub fn monitor_misbehaviour(&self) -> Result<(), ForeignClientError> {
+        let subscription = self.dst_chain.subscribe().map_err(|e| {
+            ForeignClientError::Misbehaviour(format!(
+                "failed subscribing to the events of dst chain ({}) with error: {}",
+                self.dst_chain.id(),
+                e
+            ))
+        })?;
+
+        for batch in subscription.iter() {
+            for event in batch.events.iter() {
+                let update = match event {
+                    IBCEvent::UpdateClient(update) if update.client_id() == &self.id => update,
+                    _ => continue,
+                };
+
+                match self.detect_misbehaviour_and_submit_evidence(update) {
+                    Ok(Some(events)) => {
+                        warn!(
+                            "Client id {:?} on {:?} frozen upon misbehaviour of {:?}: {:?}",
+                            self.id,
+                            self.dst_chain.id(),
+                            self.src_chain.id(),
+                            events
+                        );
+                        return Ok(());
+                    }
+                    Ok(None) => {}
+                    Err(e) => error!("Failed misbehaviour check {:?}: {}", self.id, e),
+                }
+            }
+        }
+
+        Ok(())
+    }
 }
 
 pub fn extract_client_id(event: &IBCEvent) -> Result<&ClientId, ForeignClientError> {
@@ -275,10 +479,24 @@ pub fn extract_client_id(event: &IBCEvent) -> Result<&ClientId, ForeignClientErr
 #[cfg(test)]
 mod test {
     use std::str::FromStr;
+    use std::time::{SystemTime, UNIX_EPOCH};
 
+    use tendermint_testgen::{
+        Commit as TestgenCommit, Generator, Header as TestgenHeader,
+        LightBlock as TestgenLightBlock, Validator as TestgenValidator,
+    };
+
+    use ibc::downcast;
     use ibc::events::IBCEvent;
+    use ibc::ics02_client::client_def::AnyHeader;
+    use ibc::ics02_client::msgs::update_client::MsgUpdateAnyClient;
+    use ibc::ics02_client::state::ClientState;
+    use ibc::ics07_tendermint::header::Header as TendermintHeader;
     use ibc::ics24_host::identifier::ClientId;
+    use ibc::test_utils::get_dummy_account_id;
+    use ibc::tx_msg::Msg;
     use ibc::Height;
+    use ibc_proto::ibc::core::client::v1::MsgUpdateClient as RawMsgUpdateClient;
 
     use crate::chain::mock::test_utils::get_basic_chain_config;
     use crate::chain::mock::MockChain;
@@ -538,4 +756,102 @@ mod test {
             );
         }
     }
+
+    /// Tests for `ForeignClient::detect_misbehaviour_and_submit_evidence()`.
+    #[test]
+    fn foreign_client_misbehaviour() {
+        let a_cfg = get_basic_chain_config("chain_a");
+        let b_cfg = get_basic_chain_config("chain_b");
+
+        let (a_chain, _) = ChainRuntime::<MockChain>::spawn(a_cfg).unwrap();
+        let (b_chain, _) = ChainRuntime::<MockChain>::spawn(b_cfg).unwrap();
+
+        // Instantiate the foreign clients on the two chains. This also advances chain b, so that
+        // the client on chain a can be updated.
+        let client_on_a = ForeignClient::new(a_chain.clone(), b_chain.clone()).unwrap();
+        let client_on_b = ForeignClient::new(b_chain.clone(), a_chain.clone()).unwrap();
+
+        // An update with the headers of chain b is no evidence of misbehaviour.
+        let update = match client_on_a.build_update_client_and_send().unwrap() {
+            IBCEvent::UpdateClient(update) => update,
+            event => panic!("unexpected event {:?}", event),
+        };
+        let res = client_on_a.detect_misbehaviour_and_submit_evidence(&update);
+        assert!(
+            matches!(res, Ok(None)),
+            "misbehaviour detected in a valid update: {:?}",
+            res
+        );
+
+        // Advance chain b, and update the client on chain a with a header that conflicts with
+        // the one chain b produced. It is signed by the validators of chain b, but with another
+        // timestamp.
+        client_on_b.update().unwrap();
+        let trusted_height = update.consensus_height();
+        let target_height = b_chain.query_latest_height().unwrap();
+        let header = downcast!(
+            b_chain.build_header(trusted_height, target_height).unwrap() => AnyHeader::Tendermint
+        )
+        .unwrap();
+
+        let time = SystemTime::from(header.signed_header.header.time)
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        let validators = [
+            TestgenValidator::new("1").voting_power(50),
+            TestgenValidator::new("2").voting_power(50),
+        ];
+        let testgen_header = TestgenHeader::new(&validators)
+            .height(target_height.revision_height)
+            .chain_id(b_chain.id().as_str())
+            .next_validators(&validators)
+            .time(time + 1);
+        let commit = TestgenCommit::new(testgen_header.clone(), 1);
+        let light_block = TestgenLightBlock::new(testgen_header, commit)
+            .generate()
+            .unwrap();
+        let forged_header = TendermintHeader {
+            signed_header: light_block.signed_header,
+            ..header
+        };
+
+        let msg = MsgUpdateAnyClient {
+            client_id: client_on_a.id().clone(),
+            header: AnyHeader::Tendermint(forged_header),
+            signer: get_dummy_account_id(),
+        };
+        let forged_update = match a_chain
+            .send_msgs(vec![msg.to_any::<RawMsgUpdateClient>()])
+            .unwrap()
+            .pop()
+        {
+            Some(IBCEvent::UpdateClient(update)) => update,
+            event => panic!("unexpected event {:?}", event),
+        };
+
+        // The conflicting update is detected, and the client on chain a gets frozen.
+        let res = client_on_a.detect_misbehaviour_and_submit_evidence(&forged_update);
+        match res {
+            Ok(Some(events)) => assert!(
+                matches!(events.last(), Some(IBCEvent::ClientMisbehavior(_))),
+                "unexpected events {:?}",
+                events
+            ),
+            _ => panic!("misbehaviour was not detected: {:?}", res),
+        }
+
+        let client_state = a_chain
+            .query_client_state(client_on_a.id(), Height::zero())
+            .unwrap();
+        assert!(client_state.is_frozen());
+
+        // A frozen client can be neither updated, nor frozen again.
+        client_on_b.update().unwrap();
+        assert!(client_on_a.update().is_err());
+        assert!(matches!(
+            client_on_a.detect_misbehaviour_and_submit_evidence(&forged_update),
+            Ok(None)
+        ));
+    }
 }
@@ -1,3 +1,6 @@
+use ibc::ics02_client::client_def::AnyMisbehaviour;
+use ibc::ics02_client::events::UpdateClient;
+
 use crate::chain::Chain;
 use crate::error;
 
@@ -29,4 +32,12 @@ pub trait LightClient<C: Chain>: Send + Sync {
         latest_client_state_height: ibc::Height,
         target_height: ibc::Height,
     ) -> Result<Vec<ibc::Height>, error::Error>;
+
+    /// Checks the header with which a client of this chain was updated, as reported by the
+    /// `update` event, against the header that this light client verifies at the same height.
+    /// Returns the evidence of misbehaviour if the two headers conflict, and `None` otherwise.
+    fn check_misbehaviour(
+        &self,
+        update: &UpdateClient,
+    ) -> Result<Option<AnyMisbehaviour>, error::Error>;
 }
@@ -1,6 +1,16 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+
 use crate::chain::mock::MockChain;
 use crate::chain::Chain;
-use crate::error::Error;
+use crate::error::{Error, Kind};
+use ibc::downcast;
+use ibc::ics02_client::client_def::{AnyHeader, AnyMisbehaviour};
+use ibc::ics02_client::events::UpdateClient;
+use ibc::ics02_client::header::Header;
+use ibc::ics02_client::misbehaviour::Misbehaviour;
+use ibc::ics07_tendermint::header::Header as TmHeader;
+use ibc::ics07_tendermint::misbehaviour::Misbehaviour as TmMisbehaviour;
 use ibc::ics24_host::identifier::ChainId;
 use ibc::mock::host::HostBlock;
 use ibc::Height;
@@ -8,18 +18,30 @@ use ibc::Height;
 /// A light client serving a mock chain.
 pub struct LightClient {
     chain_id: ChainId,
+
+    /// The light blocks served so far. Synthetic blocks are timestamped upon generation, so
+    /// blocks are kept around in order to always serve the same block at a given height.
+    light_blocks: Mutex<HashMap<Height, <MockChain as Chain>::LightBlock>>,
 }
 
 impl LightClient {
     pub fn new(chain: &MockChain) -> LightClient {
         LightClient {
             chain_id: chain.id().clone(),
+            light_blocks: Mutex::new(HashMap::new()),
         }
     }
 
     /// Returns a LightBlock at the requested height `h`.
     fn light_block(&self, h: Height) -> <MockChain as Chain>::LightBlock {
-        HostBlock::generate_tm_block(self.chain_id.clone(), h.revision_height)
+        let chain_id = self.chain_id.clone();
+
+        self.light_blocks
+            .lock()
+            .unwrap()
+            .entry(h)
+            .or_insert_with(|| HostBlock::generate_tm_block(chain_id, h.revision_height))
+            .clone()
     }
 }
 
@@ -44,4 +66,37 @@ impl super::LightClient<MockChain> for LightClient {
     ) -> Result<Vec<Height>, Error> {
         unimplemented!()
     }
+
+    fn check_misbehaviour(&self, update: &UpdateClient) -> Result<Option<AnyMisbehaviour>, Error> {
+        let update_header = update
+            .header
+            .clone()
+            .and_then(|header| downcast!(*header => AnyHeader::Tendermint))
+            .ok_or_else(|| {
+                Kind::Misbehaviour(self.chain_id.clone())
+                    .context("missing Tendermint header in the update client event")
+            })?;
+
+        let target = self.light_block(update_header.height());
+        if target.signed_header.header.hash() == update_header.signed_header.header.hash() {
+            return Ok(None);
+        }
+
+        let trusted = self.light_block(update_header.trusted_height);
+        let target_header = TmHeader {
+            signed_header: target.signed_header,
+            validator_set: target.validators,
+            trusted_height: update_header.trusted_height,
+            trusted_validator_set: trusted.next_validators,
+        };
+
+        Ok(Some(
+            TmMisbehaviour {
+                client_id: update.client_id().clone(),
+                header1: update_header,
+                header2: target_header,
+            }
+            .wrap_any(),
+        ))
+    }
 }
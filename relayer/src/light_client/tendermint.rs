@@ -11,6 +11,13 @@ use crate::{
     config::{ChainConfig, LightClientConfig, StoreConfig},
     error,
 };
+use ibc::downcast;
+use ibc::ics02_client::client_def::{AnyHeader, AnyMisbehaviour};
+use ibc::ics02_client::events::UpdateClient;
+use ibc::ics02_client::header::Header;
+use ibc::ics02_client::misbehaviour::Misbehaviour;
+use ibc::ics07_tendermint::header::Header as TmHeader;
+use ibc::ics07_tendermint::misbehaviour::Misbehaviour as TmMisbehaviour;
 use ibc::ics24_host::identifier::ChainId;
 
 pub struct LightClient {
@@ -53,6 +60,45 @@ impl super::LightClient<CosmosSDKChain> for LightClient {
     ) -> Result<Vec<ibc::Height>, error::Error> {
         todo!()
     }
+
+    fn check_misbehaviour(
+        &self,
+        update: &UpdateClient,
+    ) -> Result<Option<AnyMisbehaviour>, error::Error> {
+        let update_header = update
+            .header
+            .clone()
+            .and_then(|header| downcast!(*header => AnyHeader::Tendermint))
+            .ok_or_else(|| {
+                error::Kind::Misbehaviour(self.chain_id.clone())
+                    .context("missing Tendermint header in the update client event")
+            })?;
+
+        // The header that this light client trusts at the height of the update.
+        let target = self.verify_to_target(update_header.height())?;
+        if target.signed_header.header.hash() == update_header.signed_header.header.hash() {
+            return Ok(None);
+        }
+
+        // The headers conflict. Build the evidence such that the client can verify both headers
+        // against the consensus state with which it verified the update.
+        let trusted = self.verify_to_target(update_header.trusted_height)?;
+        let target_header = TmHeader {
+            signed_header: target.signed_header,
+            validator_set: target.validators,
+            trusted_height: update_header.trusted_height,
+            trusted_validator_set: trusted.next_validators,
+        };
+
+        Ok(Some(
+            TmMisbehaviour {
+                client_id: update.client_id().clone(),
+                header1: update_header,
+                header2: target_header,
+            }
+            .wrap_any(),
+        ))
+    }
 }
 
 impl LightClient {
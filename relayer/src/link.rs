@@ -12,12 +12,14 @@ use ibc::{
     events::{IBCEvent, IBCEventType},
     ics03_connection::connection::State as ConnectionState,
     ics04_channel::channel::{QueryPacketEventDataRequest, State as ChannelState},
+    ics04_channel::commitment::{compute_ack_commitment, compute_packet_commitment},
     ics04_channel::events::{SendPacket, WriteAcknowledgement},
     ics04_channel::msgs::acknowledgement::MsgAcknowledgement,
     ics04_channel::msgs::recv_packet::MsgRecvPacket,
     ics04_channel::msgs::timeout::MsgTimeout,
     ics04_channel::packet::{Packet, PacketMsgType, Sequence},
     ics24_host::identifier::{ChainId, ChannelId, ClientId, ConnectionId, PortId},
+    timestamp::Timestamp,
     tx_msg::Msg,
     Height,
 };
@@ -62,6 +64,12 @@ pub enum LinkError {
 
     #[error("exhausted max number of retries:")]
     RetryError,
+
+    #[error("packet commitment on chain {0} for sequence {1} does not match the relayed packet")]
+    PacketCommitmentMismatch(ChainId, Sequence),
+
+    #[error("acknowledgement commitment on chain {0} for sequence {1} does not match the relayed acknowledgement")]
+    AckCommitmentMismatch(ChainId, Sequence),
 }
 
 pub struct RelayPath {
@@ -72,10 +80,14 @@ pub struct RelayPath {
     all_events: Vec<IBCEvent>,
     src_height: Height,
     dst_height: Height,
+    /// The time of the block at `dst_height` on the destination chain.
+    dst_timestamp: Timestamp,
     dst_msgs_input_events: Vec<IBCEvent>,
     src_msgs_input_events: Vec<IBCEvent>,
     packet_msgs: Vec<Any>,
     timeout_msgs: Vec<Any>,
+    /// The delay period of the connection underlying the channel.
+    delay_period: Duration,
 }
 
 impl RelayPath {
@@ -84,6 +96,12 @@ impl RelayPath {
         dst_chain: Box<dyn ChainHandle>,
         channel: Channel,
     ) -> Result<Self, LinkError> {
+        let delay_period = Duration::from_nanos(
+            src_chain
+                .query_connection(channel.src_connection_id(), Height::zero())?
+                .delay_period(),
+        );
+
         Ok(RelayPath {
             src_chain: src_chain.clone(),
             dst_chain: dst_chain.clone(),
@@ -92,10 +110,12 @@ impl RelayPath {
             all_events: vec![],
             src_height: Height::zero(),
             dst_height: Height::zero(),
+            dst_timestamp: Timestamp::none(),
             dst_msgs_input_events: vec![],
             src_msgs_input_events: vec![],
             packet_msgs: vec![],
             timeout_msgs: vec![],
+            delay_period,
         })
     }
 
@@ -324,6 +344,19 @@ impl RelayPath {
         Ok(())
     }
 
+    /// Records the latest height of the destination chain and the time of the block at that
+    /// height, against which packets are checked for timeouts.
+    fn update_dst_status(&mut self) -> Result<(), LinkError> {
+        let status = self
+            .dst_chain
+            .query_status()
+            .map_err(|e| LinkError::QueryError(self.dst_chain.id(), e))?;
+
+        self.dst_height = status.height;
+        self.dst_timestamp = status.timestamp;
+        Ok(())
+    }
+
     fn reset_buffers(&mut self) {
         self.dst_msgs_input_events = vec![];
         self.src_msgs_input_events = vec![];
@@ -346,10 +379,7 @@ impl RelayPath {
             for _i in 0..MAX_ITER {
                 self.reset_buffers();
 
-                self.dst_height = self
-                    .dst_chain
-                    .query_latest_height()
-                    .map_err(|e| LinkError::QueryError(self.dst_chain.id(), e))?;
+                self.update_dst_status()?;
 
                 for event in self.all_events.clone() {
                     println!("{} => {:?}", self.src_chain.id(), event);
@@ -380,7 +410,7 @@ impl RelayPath {
         self.all_events = vec![];
         if !self.packet_msgs.is_empty() {
             let update_height = self.src_height.increment();
-            let mut msgs_to_send = vec![];
+            let mut update_msgs = vec![];
 
             // Check if a consensus state at update_height exists on destination chain already
             if self
@@ -388,19 +418,19 @@ impl RelayPath {
                 .proven_client_consensus(self.dst_client_id(), update_height, Height::zero())
                 .is_err()
             {
-                msgs_to_send = self.build_update_client_on_dst(update_height)?;
+                update_msgs = self.build_update_client_on_dst(update_height)?;
                 info!("sending update client at height {:?}", update_height,);
             }
 
-            msgs_to_send.append(&mut self.packet_msgs);
+            let msgs = std::mem::take(&mut self.packet_msgs);
 
             info!(
                 "sending {} messages to {}",
-                msgs_to_send.len(),
+                update_msgs.len() + msgs.len(),
                 self.dst_chain.id()
             );
 
-            dst_tx_events = self.dst_chain.send_msgs(msgs_to_send)?;
+            dst_tx_events = self.send_msgs_after_delay(self.dst_chain(), update_msgs, msgs)?;
             info!("result {:?}\n", dst_tx_events);
 
             let ev = dst_tx_events
@@ -416,16 +446,16 @@ impl RelayPath {
 
         if !self.timeout_msgs.is_empty() {
             let update_height = self.dst_height.increment();
-            let mut msgs_to_send = self.build_update_client_on_src(update_height)?;
-            msgs_to_send.append(&mut self.timeout_msgs);
+            let update_msgs = self.build_update_client_on_src(update_height)?;
+            let msgs = std::mem::take(&mut self.timeout_msgs);
             info!(
                 "sending {:?} messages to {}, update client at height {:?}",
-                msgs_to_send.len(),
+                update_msgs.len() + msgs.len(),
                 self.src_chain.id(),
                 update_height,
             );
 
-            src_tx_events = self.src_chain.send_msgs(msgs_to_send)?;
+            src_tx_events = self.send_msgs_after_delay(self.src_chain(), update_msgs, msgs)?;
             info!("result {:?}\n", src_tx_events);
 
             let ev = src_tx_events
@@ -442,6 +472,47 @@ impl RelayPath {
         Ok((dst_tx_events, src_tx_events))
     }
 
+    /// Submits the client updates in `update_msgs` and then the packet messages in `msgs` to
+    /// `chain`. The proofs in the packet messages are only accepted once the delay period of the
+    /// connection has elapsed since the client updates, so in case of a delay the updates are
+    /// submitted on their own, and the packet messages once the delay has elapsed, both in time
+    /// and in blocks of `chain`.
+    fn send_msgs_after_delay(
+        &self,
+        chain: Box<dyn ChainHandle>,
+        mut update_msgs: Vec<Any>,
+        mut msgs: Vec<Any>,
+    ) -> Result<Vec<IBCEvent>, LinkError> {
+        if self.delay_period.as_nanos() == 0 || update_msgs.is_empty() {
+            update_msgs.append(&mut msgs);
+            return Ok(chain.send_msgs(update_msgs)?);
+        }
+
+        let mut events = chain.send_msgs(update_msgs)?;
+        if events
+            .iter()
+            .any(|event| matches!(event, IBCEvent::ChainError(_)))
+        {
+            return Ok(events);
+        }
+
+        let update_height = chain.query_latest_height()?;
+        info!(
+            "waiting {:?} for the connection delay to elapse on {}",
+            self.delay_period,
+            chain.id()
+        );
+        thread::sleep(self.delay_period);
+
+        // The delay must elapse in blocks as well, so at least one more block is required.
+        while chain.query_latest_height()? <= update_height {
+            thread::sleep(Duration::from_millis(100));
+        }
+
+        events.append(&mut chain.send_msgs(msgs)?);
+        Ok(events)
+    }
+
     fn target_height_and_send_packet_events(&mut self) -> Result<(), LinkError> {
         // Query packet commitments on source chain that have not been acknowledged
         let pc_request = QueryPacketCommitmentsRequest {
@@ -584,10 +655,7 @@ impl RelayPath {
         // Get the events for the send packets on source chain that have not been received on
         // destination chain (i.e. ack was not seen on source chain)
         self.target_height_and_send_packet_events()?;
-        self.dst_height = self
-            .dst_chain
-            .query_latest_height()
-            .map_err(|e| LinkError::QueryError(self.dst_chain.id(), e))?;
+        self.update_dst_status()?;
 
         for event in self.all_events.iter_mut() {
             event.set_height(&self.src_height);
@@ -603,10 +671,7 @@ impl RelayPath {
         // Get the sequences of packets that have been acknowledged on destination chain but still
         // have commitments on source chain (i.e. ack was not seen on source chain)
         self.target_height_and_write_ack_events()?;
-        self.dst_height = self
-            .dst_chain
-            .query_latest_height()
-            .map_err(|e| LinkError::QueryError(self.dst_chain.id(), e))?;
+        self.update_dst_status()?;
 
         for event in self.all_events.iter_mut() {
             event.set_height(&self.src_height);
@@ -627,7 +692,7 @@ impl RelayPath {
             ))
         })?;
 
-        let (_, proofs) = self
+        let (commitment, proofs) = self
             .src_chain
             .build_packet_proofs(
                 PacketMsgType::Recv,
@@ -638,6 +703,20 @@ impl RelayPath {
             )
             .map_err(|e| LinkError::PacketProofsConstructor(self.src_chain.id(), e))?;
 
+        // The commitment proven on the source chain must be the one of the relayed packet. A missing
+        // commitment is left for the destination chain to reject.
+        let expected = compute_packet_commitment(
+            &packet.data,
+            packet.timeout_height,
+            packet.timeout_timestamp,
+        );
+        if !commitment.is_empty() && commitment != expected.as_bytes() {
+            return Err(LinkError::PacketCommitmentMismatch(
+                self.src_chain.id(),
+                packet.sequence,
+            ));
+        }
+
         let msg = MsgRecvPacket::new(packet.clone(), proofs.clone(), signer).map_err(|e| {
             LinkError::Failed(format!(
                 "error while building the recv packet for src channel {} due to error {}",
@@ -670,7 +749,7 @@ impl RelayPath {
         })?;
 
         let packet = event.packet.clone();
-        let (_, proofs) = self
+        let (ack_commitment, proofs) = self
             .src_chain
             .build_packet_proofs(
                 PacketMsgType::Ack,
@@ -681,6 +760,15 @@ impl RelayPath {
             )
             .map_err(|e| LinkError::PacketProofsConstructor(self.src_chain.id(), e))?;
 
+        if !ack_commitment.is_empty()
+            && ack_commitment != compute_ack_commitment(&event.ack).as_bytes()
+        {
+            return Err(LinkError::AckCommitmentMismatch(
+                self.src_chain.id(),
+                packet.sequence,
+            ));
+        }
+
         let msg =
             MsgAcknowledgement::new(packet.clone(), event.ack.clone(), proofs.clone(), signer)
                 .map_err(|e| {
@@ -804,14 +892,14 @@ impl RelayPath {
                 None,
                 Some(self.build_timeout_on_close_packet(&event.packet, self.dst_height)?),
             ))
-        } else if packet.timeout_height != Height::zero() && packet.timeout_height < self.dst_height
+        } else if packet.timed_out(self.dst_height) || packet.timestamp_expired(&self.dst_timestamp)
         {
+            // The destination chain can no longer receive the packet: prove its non-receipt at
+            // the latest height, whose block time (or height) is past the packet timeout.
             Ok((
                 None,
                 Some(self.build_timeout_packet(&event.packet, self.dst_height)?),
             ))
-        // } else if packet.timeout_timestamp != 0 && packet.timeout_timestamp < dst_chain.query_time() {
-        //     TODO - add query to get the current chain time
         } else {
             Ok((
                 Some(self.build_recv_packet(&event.packet, event_height)?),
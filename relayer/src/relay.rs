@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use anomaly::BoxError;
 use tracing::info;
 
@@ -18,9 +20,11 @@ pub fn relay_on_new_link(
     b_chain_handle: Box<dyn ChainHandle>,
     ordering: Order,
     path: RelayPath,
+    delay_period: Duration,
 ) -> Result<(), BoxError> {
     // Setup the clients, connection and channel
-    let channel = connect_with_new_channel(a_chain_handle, b_chain_handle, ordering, path)?;
+    let channel =
+        connect_with_new_channel(a_chain_handle, b_chain_handle, ordering, path, delay_period)?;
 
     let mut link = Link::new(channel)?;
     link.relay()?;
@@ -46,6 +50,7 @@ pub fn connect_with_new_channel(
     b_chain_handle: Box<dyn ChainHandle>,
     ordering: Order,
     path: RelayPath,
+    delay_period: Duration,
 ) -> Result<Channel, BoxError> {
     info!("\nChannel Relay Loop\n");
 
@@ -54,7 +59,7 @@ pub fn connect_with_new_channel(
     let client_on_b = ForeignClient::new(b_chain_handle.clone(), a_chain_handle.clone())?;
 
     // Setup the connection between the two chains
-    let connection = Connection::new(client_on_a, client_on_b)?;
+    let connection = Connection::new(client_on_a, client_on_b, delay_period)?;
 
     // Setup the channel over the connection
     Ok(Channel::new(
@@ -6,6 +6,7 @@ use crate::config::ChainConfig;
 use crate::error::Error;
 use ibc::events::IBCEvent;
 use ibc::ics24_host::identifier::{ChannelId, PortId};
+use ibc::timestamp::Timestamp;
 use ibc::tx_msg::Msg;
 use ibc::{
     application::ics20_fungible_token_transfer::msgs::transfer::MsgTransfer,
@@ -66,7 +67,7 @@ pub fn build_and_send_transfer_messages(
         sender,
         receiver,
         timeout_height: latest_height.add(opts.height_offset),
-        timeout_timestamp: 0,
+        timeout_timestamp: Timestamp::none(),
     };
 
     let raw_msg = msg.to_any::<RawMsgTransfer>();